@@ -0,0 +1,176 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Detection of the blocks whose tracing runtime is missing.
+//!
+//! Tracing a block requires the tracing runtime of its runtime version to be in the
+//! `--wasm-runtime-overrides` directory. Otherwise the block is replayed by the on-chain runtime,
+//! which refuses to trace with an error that can't be told apart from other runtime errors once
+//! it reaches the client, so the runtime version is reported instead.
+//!
+//! Tracing runtimes can also be kept in a separate directory, from which the missing ones are
+//! copied to the overrides directory when first needed. Substrate only loads the overrides when
+//! the node starts, so they are used once the node restarts.
+
+use serde::Serialize;
+use std::{fmt, path::PathBuf};
+
+/// Name of the tracing runtime of a runtime version, as published with the releases.
+pub fn tracing_runtime_file_name(spec_name: &str, spec_version: u32) -> String {
+	format!(
+		"{}-runtime-{}-substitute-tracing.wasm",
+		spec_name, spec_version
+	)
+}
+
+/// Directory of tracing runtimes the missing overrides are copied from.
+#[derive(Clone, Debug)]
+pub struct TracingRuntimeSource {
+	/// Directory of the tracing runtimes, named either as published with the releases or after
+	/// their spec version (`<spec_version>.wasm`).
+	pub source_dir: PathBuf,
+	/// The `--wasm-runtime-overrides` directory of the node.
+	pub overrides_dir: PathBuf,
+}
+
+impl TracingRuntimeSource {
+	/// Copy the tracing runtime of the given version to the overrides directory, returning
+	/// whether it is there.
+	pub fn stage(&self, spec_name: &str, spec_version: u32) -> bool {
+		let file_name = tracing_runtime_file_name(spec_name, spec_version);
+		let target = self.overrides_dir.join(&file_name);
+		if target.exists() {
+			return true;
+		}
+
+		let source = [
+			self.source_dir.join(&file_name),
+			self.source_dir.join(format!("{}.wasm", spec_version)),
+		]
+		.into_iter()
+		.find(|path| path.is_file());
+		let Some(source) = source else {
+			return false;
+		};
+
+		match std::fs::copy(&source, &target) {
+			Ok(_) => {
+				log::info!(
+					target: "tracing",
+					"Copied tracing runtime {source:?} to {target:?}, restart the node to use it",
+				);
+				true
+			}
+			Err(err) => {
+				log::warn!(
+					target: "tracing",
+					"Failed to copy tracing runtime {source:?} to {target:?} : {err:?}",
+				);
+				false
+			}
+		}
+	}
+}
+
+/// The runtime of a traced block has no tracing runtime override.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingTracingRuntime {
+	pub spec_name: String,
+	pub spec_version: u32,
+	/// Name of the tracing runtime expected in the overrides directory.
+	pub file_name: String,
+	/// Whether the tracing runtime was copied to the overrides directory, to be used once the
+	/// node restarts.
+	pub staged: bool,
+}
+
+impl MissingTracingRuntime {
+	/// Describe the missing tracing runtime of the given runtime version, copying it to the
+	/// overrides directory if `source` has it.
+	pub fn new(spec_name: &str, spec_version: u32, source: Option<&TracingRuntimeSource>) -> Self {
+		Self {
+			spec_name: spec_name.to_string(),
+			spec_version,
+			file_name: tracing_runtime_file_name(spec_name, spec_version),
+			staged: source.map_or(false, |source| source.stage(spec_name, spec_version)),
+		}
+	}
+}
+
+impl fmt::Display for MissingTracingRuntime {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.staged {
+			write!(
+				f,
+				"Tracing runtime {} was added to the wasm runtime overrides, restart the node to \
+				trace blocks of {} runtime {}",
+				self.file_name, self.spec_name, self.spec_version
+			)
+		} else {
+			write!(
+				f,
+				"Missing tracing runtime for {} runtime {}, add {} to the wasm runtime overrides \
+				and restart the node",
+				self.spec_name, self.spec_version, self.file_name
+			)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn temp_dir(name: &str) -> PathBuf {
+		let dir = std::env::temp_dir().join(format!(
+			"moonbeam-tracing-overrides-{}-{}",
+			name,
+			std::process::id()
+		));
+		let _ = std::fs::remove_dir_all(&dir);
+		std::fs::create_dir_all(&dir).expect("temp dir is writable");
+		dir
+	}
+
+	#[test]
+	fn missing_runtime_is_copied_from_source() {
+		let source = TracingRuntimeSource {
+			source_dir: temp_dir("source"),
+			overrides_dir: temp_dir("overrides"),
+		};
+		std::fs::write(source.source_dir.join("2500.wasm"), b"wasm").expect("writable");
+
+		let missing = MissingTracingRuntime::new("moonbase", 2500, Some(&source));
+		assert!(missing.staged);
+		assert_eq!(
+			std::fs::read(
+				source
+					.overrides_dir
+					.join("moonbase-runtime-2500-substitute-tracing.wasm")
+			)
+			.expect("runtime was copied"),
+			b"wasm"
+		);
+
+		let missing = MissingTracingRuntime::new("moonbase", 2600, Some(&source));
+		assert!(!missing.staged);
+		assert_eq!(
+			missing.file_name,
+			"moonbase-runtime-2600-substitute-tracing.wasm"
+		);
+	}
+}
@@ -0,0 +1,218 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Permit pool shared by the "debug" and "trace" modules to bound the number of concurrent
+//! tracing tasks.
+//!
+//! Unlike a plain semaphore, waiting requests are not served in arrival order: a freed permit goes
+//! to the waiting request of the highest priority kind, so a single `debug_traceTransaction`
+//! doesn't wait behind the hundreds of blocks of a `trace_filter` scan. Each kind can also be
+//! limited to a number of permits, keeping the remaining ones available to the other kinds.
+//!
+//! RPC handlers don't know which connection a request comes from, so the limits apply to all the
+//! requests of a given kind.
+
+use parking_lot::Mutex;
+use std::{collections::VecDeque, sync::Arc};
+use tokio::sync::oneshot;
+
+/// Kind of request a tracing permit is acquired for, from the highest priority to the lowest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RequestKind {
+	/// `debug_traceTransaction`, replaying a single transaction.
+	Transaction = 0,
+	/// `debug_traceBlockByNumber` and `debug_traceBlockByHash`, replaying a whole block.
+	Block = 1,
+	/// `trace_filter`, replaying every block of a range.
+	Filter = 2,
+}
+
+impl RequestKind {
+	const ALL: [RequestKind; 3] = [
+		RequestKind::Transaction,
+		RequestKind::Block,
+		RequestKind::Filter,
+	];
+}
+
+struct PoolState {
+	available: usize,
+	limits: [usize; 3],
+	in_use: [usize; 3],
+	waiters: [VecDeque<oneshot::Sender<TracingPermit>>; 3],
+}
+
+/// Pool of permits to run tracing tasks.
+pub struct TracingPermits {
+	max_permits: usize,
+	state: Mutex<PoolState>,
+}
+
+impl TracingPermits {
+	/// Create a pool of `max_permits` permits. A kind of request absent from `limits` can use
+	/// all of them.
+	pub fn new(max_permits: usize, limits: &[(RequestKind, usize)]) -> Arc<Self> {
+		let mut kind_limits = [max_permits; 3];
+		for (kind, limit) in limits {
+			kind_limits[*kind as usize] = (*limit).min(max_permits);
+		}
+
+		Arc::new(Self {
+			max_permits,
+			state: Mutex::new(PoolState {
+				available: max_permits,
+				limits: kind_limits,
+				in_use: [0; 3],
+				waiters: Default::default(),
+			}),
+		})
+	}
+
+	/// Total number of permits of the pool.
+	pub fn max_permits(&self) -> usize {
+		self.max_permits
+	}
+
+	/// Wait for a permit for a request of the given kind. The permit is released when dropped.
+	pub async fn acquire(self: &Arc<Self>, kind: RequestKind) -> TracingPermit {
+		let receiver = {
+			let mut state = self.state.lock();
+			let index = kind as usize;
+			// Forget the requests that stopped waiting.
+			state.waiters[index].retain(|waiter| !waiter.is_closed());
+
+			if state.available > 0
+				&& state.in_use[index] < state.limits[index]
+				&& state.waiters[index].is_empty()
+			{
+				state.available -= 1;
+				state.in_use[index] += 1;
+				return TracingPermit {
+					pool: Some(Arc::clone(self)),
+					kind,
+				};
+			}
+
+			let (sender, receiver) = oneshot::channel();
+			state.waiters[index].push_back(sender);
+			receiver
+		};
+
+		receiver
+			.await
+			.expect("Waiters are only dropped once served, and the pool outlives them; qed")
+	}
+
+	/// Hand the available permits to the waiting requests, by priority then arrival order.
+	fn dispatch(self: &Arc<Self>, state: &mut PoolState) {
+		for kind in RequestKind::ALL {
+			let index = kind as usize;
+			while state.available > 0 && state.in_use[index] < state.limits[index] {
+				let waiter = match state.waiters[index].pop_front() {
+					Some(waiter) => waiter,
+					None => break,
+				};
+
+				state.available -= 1;
+				state.in_use[index] += 1;
+				let permit = TracingPermit {
+					pool: Some(Arc::clone(self)),
+					kind,
+				};
+				// The request stopped waiting: take the permit back without releasing it, as the
+				// state is already locked.
+				if let Err(mut permit) = waiter.send(permit) {
+					permit.pool = None;
+					state.available += 1;
+					state.in_use[index] -= 1;
+				}
+			}
+		}
+	}
+}
+
+/// A permit to run a tracing task, released when dropped.
+pub struct TracingPermit {
+	pool: Option<Arc<TracingPermits>>,
+	kind: RequestKind,
+}
+
+impl Drop for TracingPermit {
+	fn drop(&mut self) {
+		if let Some(pool) = self.pool.take() {
+			let mut state = pool.state.lock();
+			state.available += 1;
+			state.in_use[self.kind as usize] -= 1;
+			pool.dispatch(&mut state);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use futures::{executor::block_on, FutureExt};
+
+	#[test]
+	fn freed_permit_goes_to_highest_priority() {
+		let pool = TracingPermits::new(1, &[]);
+		let permit = block_on(pool.acquire(RequestKind::Filter));
+
+		let mut filter = Box::pin(pool.acquire(RequestKind::Filter));
+		let mut block = Box::pin(pool.acquire(RequestKind::Block));
+		let mut transaction = Box::pin(pool.acquire(RequestKind::Transaction));
+		assert!((&mut filter).now_or_never().is_none());
+		assert!((&mut block).now_or_never().is_none());
+		assert!((&mut transaction).now_or_never().is_none());
+
+		drop(permit);
+		assert!((&mut filter).now_or_never().is_none());
+		assert!((&mut block).now_or_never().is_none());
+		let permit = (&mut transaction).now_or_never().expect("permit was freed");
+
+		drop(permit);
+		assert!((&mut filter).now_or_never().is_none());
+		let permit = (&mut block).now_or_never().expect("permit was freed");
+
+		drop(permit);
+		assert!((&mut filter).now_or_never().is_some());
+	}
+
+	#[test]
+	fn kind_limit_keeps_permits_for_other_kinds() {
+		let pool = TracingPermits::new(2, &[(RequestKind::Filter, 1)]);
+		let _filter = block_on(pool.acquire(RequestKind::Filter));
+
+		assert!(pool.acquire(RequestKind::Filter).now_or_never().is_none());
+		assert!(pool
+			.acquire(RequestKind::Transaction)
+			.now_or_never()
+			.is_some());
+	}
+
+	#[test]
+	fn cancelled_request_does_not_hold_permit() {
+		let pool = TracingPermits::new(1, &[]);
+		let permit = block_on(pool.acquire(RequestKind::Transaction));
+
+		let mut cancelled = Box::pin(pool.acquire(RequestKind::Transaction));
+		assert!((&mut cancelled).now_or_never().is_none());
+		drop(cancelled);
+
+		drop(permit);
+		assert!(pool.acquire(RequestKind::Block).now_or_never().is_some());
+	}
+}
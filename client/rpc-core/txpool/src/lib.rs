@@ -19,7 +19,9 @@ use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 
 mod types;
 
-pub use crate::types::{Get as GetT, Summary, Transaction, TransactionMap, TxPoolResult};
+pub use crate::types::{
+	Get as GetT, StuckTransaction, Summary, Transaction, TransactionMap, TxPoolResult,
+};
 
 #[rpc(server)]
 pub trait TxPool {
@@ -31,4 +33,9 @@ pub trait TxPool {
 
 	#[method(name = "txpool_status")]
 	fn status(&self) -> RpcResult<TxPoolResult<U256>>;
+
+	/// Queued transactions which can't be executed because of a gap in the nonces of their
+	/// sender, along with the first missing nonce.
+	#[method(name = "txpool_inspectStuck")]
+	fn inspect_stuck(&self) -> RpcResult<TransactionMap<StuckTransaction>>;
 }
@@ -16,6 +16,7 @@
 
 mod content;
 mod inspect;
+mod stuck;
 
 use ethereum::TransactionV2 as EthereumTransaction;
 use ethereum_types::{H160, H256, U256};
@@ -24,6 +25,7 @@ use std::collections::HashMap;
 
 pub use self::content::Transaction;
 pub use self::inspect::Summary;
+pub use self::stuck::StuckTransaction;
 
 pub type TransactionMap<T> = HashMap<H160, HashMap<U256, T>>;
 
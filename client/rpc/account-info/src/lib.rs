@@ -0,0 +1,153 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC exposing the Substrate-level state of the account of an Ethereum address, as returned by
+//! the `AccountInfoApi` runtime API.
+//!
+//! It allows wallets to explain why part of the balance returned by `eth_getBalance` cannot be
+//! transferred.
+
+use account_info_runtime_api::{AccountInfoApi, AccountMeta, BalanceReserveInfo, LockReasons};
+use fc_rpc::internal_err;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H160, U256};
+use sp_runtime::{codec::Codec, traits::Block as BlockT};
+use std::{marker::PhantomData, sync::Arc};
+
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Reasons {
+	Fee,
+	Misc,
+	All,
+}
+
+/// A lock on the free balance of an account.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceLock {
+	pub id: Bytes,
+	pub amount: U256,
+	pub reasons: Reasons,
+}
+
+/// A named reserve or a hold, its identifier being SCALE encoded.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceReserve {
+	pub id: Bytes,
+	pub amount: U256,
+}
+
+/// Substrate-level state of the account of an Ethereum address.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfo {
+	pub nonce: u64,
+	pub consumers: u32,
+	pub providers: u32,
+	pub sufficients: u32,
+	pub free: U256,
+	pub reserved: U256,
+	/// Part of the free balance that cannot be transferred.
+	pub frozen: U256,
+	/// Part of the free balance that can be transferred.
+	pub transferable: U256,
+	pub locks: Vec<BalanceLock>,
+	pub reserves: Vec<BalanceReserve>,
+	pub holds: Vec<BalanceReserve>,
+}
+
+impl<Index: Into<u64>, Balance: Into<U256>> From<AccountMeta<Index, Balance>> for AccountInfo {
+	fn from(meta: AccountMeta<Index, Balance>) -> Self {
+		let reserve = |reserve: BalanceReserveInfo<Balance>| BalanceReserve {
+			id: reserve.id.into(),
+			amount: reserve.amount.into(),
+		};
+
+		Self {
+			nonce: meta.nonce.into(),
+			consumers: meta.consumers,
+			providers: meta.providers,
+			sufficients: meta.sufficients,
+			free: meta.free.into(),
+			reserved: meta.reserved.into(),
+			frozen: meta.frozen.into(),
+			transferable: meta.transferable.into(),
+			locks: meta
+				.locks
+				.into_iter()
+				.map(|lock| BalanceLock {
+					id: lock.id.to_vec().into(),
+					amount: lock.amount.into(),
+					reasons: match lock.reasons {
+						LockReasons::Fee => Reasons::Fee,
+						LockReasons::Misc => Reasons::Misc,
+						LockReasons::All => Reasons::All,
+					},
+				})
+				.collect(),
+			reserves: meta.reserves.into_iter().map(reserve).collect(),
+			holds: meta.holds.into_iter().map(reserve).collect(),
+		}
+	}
+}
+
+/// An RPC endpoint to retrieve the Substrate-level state of accounts in Moonbeam
+#[rpc(server)]
+pub trait MoonbeamAccountInfoApi<BlockHash> {
+	/// Returns the reference counters, balances, locks, reserves and holds of the account of an
+	/// Ethereum address as of the given block (the best block by default).
+	#[method(name = "moon_accountMeta")]
+	fn account_meta(&self, address: H160, at: Option<BlockHash>) -> RpcResult<AccountInfo>;
+}
+
+pub struct MoonbeamAccountInfo<B: BlockT, C, Index, Balance> {
+	pub client: Arc<C>,
+	_phdata: PhantomData<(B, Index, Balance)>,
+}
+
+impl<B: BlockT, C, Index, Balance> MoonbeamAccountInfo<B, C, Index, Balance> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_phdata: Default::default(),
+		}
+	}
+}
+
+impl<B, C, Index, Balance> MoonbeamAccountInfoApiServer<B::Hash>
+	for MoonbeamAccountInfo<B, C, Index, Balance>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + Send + Sync + 'static,
+	C::Api: AccountInfoApi<B, Index, Balance>,
+	Index: Codec + Into<u64> + Send + Sync + 'static,
+	Balance: Codec + Into<U256> + Send + Sync + 'static,
+{
+	fn account_meta(&self, address: H160, at: Option<B::Hash>) -> RpcResult<AccountInfo> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client
+			.runtime_api()
+			.account_meta(at, address)
+			.map(Into::into)
+			.map_err(|e| internal_err(format!("Runtime api access error: {:?}", e)))
+	}
+}
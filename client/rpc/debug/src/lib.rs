@@ -14,20 +14,27 @@
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 use futures::StreamExt;
-use jsonrpsee::core::{async_trait, RpcResult};
+use jsonrpsee::{
+	core::{async_trait, Error as RpcError, RpcResult},
+	types::error::{CallError, ErrorObject},
+};
 pub use moonbeam_rpc_core_debug::{DebugServer, TraceParams};
 
-use tokio::{
-	self,
-	sync::{oneshot, Semaphore},
-};
+use tokio::{self, sync::oneshot};
 
 use ethereum_types::H256;
 use fc_rpc::{frontier_backend_client, internal_err, OverrideHandle};
 use fp_rpc::EthereumRuntimeRPCApi;
-use moonbeam_client_evm_tracing::{formatters::ResponseFormatter, types::single};
+use moonbeam_client_evm_tracing::{
+	formatters::ResponseFormatter,
+	overrides::{MissingTracingRuntime, TracingRuntimeSource},
+	permits::{RequestKind, TracingPermits},
+	types::single,
+};
 use moonbeam_rpc_core_types::{RequestBlockId, RequestBlockTag};
 use moonbeam_rpc_primitives_debug::{DebugRuntimeApi, TracerInput};
+use parity_scale_codec::Encode;
+use parking_lot::Mutex;
 use sc_client_api::backend::{Backend, StateBackend, StorageProvider};
 use sc_utils::mpsc::TracingUnboundedSender;
 use sp_api::{ApiExt, BlockId, Core, HeaderT, ProvideRuntimeApi};
@@ -36,19 +43,28 @@ use sp_blockchain::{
 	Backend as BlockchainBackend, Error as BlockChainError, HeaderBackend, HeaderMetadata,
 };
 use sp_runtime::traits::{BlakeTwo256, Block as BlockT, UniqueSaturatedInto};
-use std::{future::Future, marker::PhantomData, sync::Arc};
+use std::{collections::VecDeque, future::Future, marker::PhantomData, sync::Arc};
 
 pub enum RequesterInput {
 	Transaction(H256),
 	Block(RequestBlockId),
 }
 
+#[derive(Clone)]
 pub enum Response {
 	Single(single::TransactionTrace),
 	Block(Vec<single::TransactionTrace>),
 }
 
 pub type Responder = oneshot::Sender<RpcResult<Response>>;
+/// `debug_traceBlock` requests currently being replayed, with the responders of all the
+/// identical requests received in the meantime.
+type PendingBlockRequests = Vec<((RequestBlockId, Option<TraceParams>), Vec<Responder>)>;
+/// Maximum number of blocks kept in the `BlockTraceCache`.
+const BLOCK_TRACE_CACHE_SIZE: usize = 16;
+/// EIP-1474 "resource unavailable" error code, returned when the tracing runtime of the block is
+/// missing.
+const RESOURCE_UNAVAILABLE_CODE: i32 = -32002;
 pub type DebugRequester =
 	TracingUnboundedSender<((RequesterInput, Option<TraceParams>), Responder)>;
 
@@ -56,6 +72,47 @@ pub struct Debug {
 	pub requester: DebugRequester,
 }
 
+/// Call traces of the latest blocks replayed for `debug_traceTransaction`, by ethereum block hash.
+///
+/// The cache holds at most `BLOCK_TRACE_CACHE_SIZE` blocks, whose traces use at most
+/// `max_memory_usage` bytes in total. Blocks whose traces don't fit in the budget are not cached.
+#[derive(Default)]
+struct BlockTraceCache {
+	blocks: VecDeque<(H256, Vec<single::TransactionTrace>, usize)>,
+	memory_usage: usize,
+}
+
+impl BlockTraceCache {
+	fn get(&self, block_hash: H256) -> Option<&Vec<single::TransactionTrace>> {
+		self.blocks
+			.iter()
+			.find(|(hash, _, _)| *hash == block_hash)
+			.map(|(_, traces, _)| traces)
+	}
+
+	fn insert(
+		&mut self,
+		block_hash: H256,
+		traces: Vec<single::TransactionTrace>,
+		max_memory_usage: usize,
+	) {
+		let size = traces.encoded_size();
+		if size > max_memory_usage || self.get(block_hash).is_some() {
+			return;
+		}
+		while self.blocks.len() >= BLOCK_TRACE_CACHE_SIZE
+			|| self.memory_usage + size > max_memory_usage
+		{
+			match self.blocks.pop_front() {
+				Some((_, _, evicted)) => self.memory_usage -= evicted,
+				None => break,
+			}
+		}
+		self.memory_usage += size;
+		self.blocks.push_back((block_hash, traces, size));
+	}
+}
+
 impl Debug {
 	pub fn new(requester: DebugRequester) -> Self {
 		Self { requester }
@@ -138,19 +195,22 @@ where
 	C::Api: ApiExt<B>,
 {
 	/// Task spawned at service level that listens for messages on the rpc channel and spawns
-	/// blocking tasks using a permit pool.
+	/// blocking tasks using a permit pool, transaction requests being served before block ones.
 	pub fn task(
 		client: Arc<C>,
 		backend: Arc<BE>,
 		frontier_backend: Arc<dyn fc_db::BackendReader<B> + Send + Sync>,
-		permit_pool: Arc<Semaphore>,
+		permit_pool: Arc<TracingPermits>,
 		overrides: Arc<OverrideHandle<B>>,
 		raw_max_memory_usage: usize,
+		tracing_runtimes: Option<Arc<TracingRuntimeSource>>,
 	) -> (impl Future<Output = ()>, DebugRequester) {
 		let (tx, mut rx): (DebugRequester, _) =
 			sc_utils::mpsc::tracing_unbounded("debug-requester", 100_000);
 
 		let fut = async move {
+			let pending_block_requests: Arc<Mutex<PendingBlockRequests>> = Default::default();
+			let block_trace_cache: Arc<Mutex<BlockTraceCache>> = Default::default();
 			loop {
 				match rx.next().await {
 					Some((
@@ -162,11 +222,14 @@ where
 						let frontier_backend = frontier_backend.clone();
 						let permit_pool = permit_pool.clone();
 						let overrides = overrides.clone();
+						let block_trace_cache = block_trace_cache.clone();
+						let tracing_runtimes = tracing_runtimes.clone();
 
 						tokio::task::spawn(async move {
 							let _ = response_tx.send(
 								async {
-									let _permit = permit_pool.acquire().await;
+									let _permit =
+										permit_pool.acquire(RequestKind::Transaction).await;
 									tokio::task::spawn_blocking(move || {
 										Self::handle_transaction_request(
 											client.clone(),
@@ -176,6 +239,8 @@ where
 											params,
 											overrides.clone(),
 											raw_max_memory_usage,
+											block_trace_cache,
+											tracing_runtimes,
 										)
 									})
 									.await
@@ -191,37 +256,69 @@ where
 						});
 					}
 					Some(((RequesterInput::Block(request_block_id), params), response_tx)) => {
+						// Identical requests received while the block is being replayed share
+						// the result of that replay.
+						let key = (request_block_id, params.clone());
+						{
+							let mut pending = pending_block_requests.lock();
+							if let Some((_, responders)) = pending
+								.iter_mut()
+								.find(|(pending_key, _)| *pending_key == key)
+							{
+								responders.push(response_tx);
+								continue;
+							}
+							pending.push((key.clone(), vec![response_tx]));
+						}
+
+						let pending_block_requests = pending_block_requests.clone();
 						let client = client.clone();
 						let backend = backend.clone();
 						let frontier_backend = frontier_backend.clone();
 						let permit_pool = permit_pool.clone();
 						let overrides = overrides.clone();
+						let tracing_runtimes = tracing_runtimes.clone();
 
 						tokio::task::spawn(async move {
-							let _ = response_tx.send(
-								async {
-									let _permit = permit_pool.acquire().await;
-
-									tokio::task::spawn_blocking(move || {
-										Self::handle_block_request(
-											client.clone(),
-											backend.clone(),
-											frontier_backend.clone(),
-											request_block_id,
-											params,
-											overrides.clone(),
-										)
-									})
-									.await
-									.map_err(|e| {
-										internal_err(format!(
-											"Internal error on spawned task : {:?}",
-											e
-										))
-									})?
-								}
-								.await,
-							);
+							let result = async {
+								let _permit = permit_pool.acquire(RequestKind::Block).await;
+
+								tokio::task::spawn_blocking(move || {
+									Self::handle_block_request(
+										client.clone(),
+										backend.clone(),
+										frontier_backend.clone(),
+										request_block_id,
+										params,
+										overrides.clone(),
+										tracing_runtimes,
+									)
+								})
+								.await
+								.map_err(|e| {
+									internal_err(format!(
+										"Internal error on spawned task : {:?}",
+										e
+									))
+								})?
+							}
+							.await;
+
+							let responders = {
+								let mut pending = pending_block_requests.lock();
+								pending
+									.iter()
+									.position(|(pending_key, _)| *pending_key == key)
+									.map(|index| pending.swap_remove(index).1)
+									.unwrap_or_default()
+							};
+							for response_tx in responders {
+								// Rpc errors can't be cloned, they are all internal errors.
+								let _ = response_tx.send(match &result {
+									Ok(response) => Ok(response.clone()),
+									Err(e) => Err(internal_err(e.to_string())),
+								});
+							}
 						});
 					}
 					_ => {}
@@ -231,6 +328,30 @@ where
 		(fut, tx)
 	}
 
+	/// Error of a runtime refusing to trace, as the on-chain runtimes do. The runtime errors are
+	/// opaque to the client, so the version of the runtime is reported instead.
+	fn missing_tracing_runtime(
+		api: &C::Api,
+		at: B::Hash,
+		tracing_runtimes: Option<Arc<TracingRuntimeSource>>,
+	) -> RpcError {
+		let version = match api.version(at) {
+			Ok(version) => version,
+			Err(e) => return internal_err(format!("Runtime api access error: {:?}", e)),
+		};
+		let missing = MissingTracingRuntime::new(
+			&version.spec_name,
+			version.spec_version,
+			tracing_runtimes.as_deref(),
+		);
+
+		RpcError::Call(CallError::Custom(ErrorObject::owned(
+			RESOURCE_UNAVAILABLE_CODE,
+			missing.to_string(),
+			Some(missing),
+		)))
+	}
+
 	fn handle_params(params: Option<TraceParams>) -> RpcResult<(TracerInput, single::TraceType)> {
 		// Set trace input and type
 		match params {
@@ -286,6 +407,7 @@ where
 		request_block_id: RequestBlockId,
 		params: Option<TraceParams>,
 		overrides: Arc<OverrideHandle<B>>,
+		tracing_runtimes: Option<Arc<TracingRuntimeSource>>,
 	) -> RpcResult<Response> {
 		let (tracer_input, trace_type) = Self::handle_params(params)?;
 
@@ -319,7 +441,7 @@ where
 		let blockchain = backend.blockchain();
 		// Get the header I want to work with.
 		let Ok(hash) = client.expect_block_hash_from_id(&reference_id) else {
-			return Err(internal_err("Block header not found"))
+			return Err(internal_err("Block header not found"));
 		};
 		let header = match client.header(hash) {
 			Ok(Some(h)) => h,
@@ -372,11 +494,8 @@ where
 						reference_id, e
 					))
 				})?
-				.map_err(|e| {
-					internal_err(format!(
-						"Internal runtime error when replaying block {} : {:?}",
-						reference_id, e
-					))
+				.map_err(|_| {
+					Self::missing_tracing_runtime(&api, parent_block_hash, tracing_runtimes.clone())
 				})?;
 			Ok(moonbeam_rpc_primitives_debug::Response::Block)
 		};
@@ -407,6 +526,54 @@ where
 		};
 	}
 
+	/// Returns the call trace of the transaction at `index` in the given ethereum block, replaying
+	/// the whole block if its traces are not cached yet.
+	fn trace_transaction_from_block(
+		client: Arc<C>,
+		backend: Arc<BE>,
+		frontier_backend: Arc<dyn fc_db::BackendReader<B> + Send + Sync>,
+		block_hash: H256,
+		index: usize,
+		params: Option<TraceParams>,
+		overrides: Arc<OverrideHandle<B>>,
+		raw_max_memory_usage: usize,
+		block_trace_cache: Arc<Mutex<BlockTraceCache>>,
+		tracing_runtimes: Option<Arc<TracingRuntimeSource>>,
+	) -> RpcResult<Response> {
+		let cached = block_trace_cache
+			.lock()
+			.get(block_hash)
+			.map(|traces| traces.get(index).cloned());
+
+		let trace = match cached {
+			Some(trace) => trace,
+			None => {
+				let traces = match Self::handle_block_request(
+					client,
+					backend,
+					frontier_backend,
+					RequestBlockId::Hash(block_hash),
+					params,
+					overrides,
+					tracing_runtimes,
+				)? {
+					Response::Block(traces) => traces,
+					Response::Single(_) => unreachable!(),
+				};
+				let trace = traces.get(index).cloned();
+
+				block_trace_cache
+					.lock()
+					.insert(block_hash, traces, raw_max_memory_usage);
+				trace
+			}
+		};
+
+		trace
+			.map(Response::Single)
+			.ok_or_else(|| internal_err("Transaction not found in the block trace"))
+	}
+
 	/// Replays a transaction in the Runtime at a given block height.
 	///
 	/// In order to succesfully reproduce the result of the original transaction we need a correct
@@ -422,8 +589,10 @@ where
 		params: Option<TraceParams>,
 		overrides: Arc<OverrideHandle<B>>,
 		raw_max_memory_usage: usize,
+		block_trace_cache: Arc<Mutex<BlockTraceCache>>,
+		tracing_runtimes: Option<Arc<TracingRuntimeSource>>,
 	) -> RpcResult<Response> {
-		let (tracer_input, trace_type) = Self::handle_params(params)?;
+		let (tracer_input, trace_type) = Self::handle_params(params.clone())?;
 
 		let (hash, index) =
 			match futures::executor::block_on(frontier_backend_client::load_transactions::<B, C>(
@@ -437,6 +606,24 @@ where
 				Err(e) => return Err(e),
 			};
 
+		// Call traces of a whole block are produced by a single replay, so the transactions of a
+		// block are all served from that replay instead of re-executing the block up to each of
+		// them.
+		if matches!(tracer_input, TracerInput::CallTracer) {
+			return Self::trace_transaction_from_block(
+				client,
+				backend,
+				frontier_backend,
+				hash,
+				index,
+				params,
+				overrides,
+				raw_max_memory_usage,
+				block_trace_cache,
+				tracing_runtimes,
+			);
+		}
+
 		let reference_id =
 			match futures::executor::block_on(frontier_backend_client::load_hash::<B, C>(
 				client.as_ref(),
@@ -453,7 +640,7 @@ where
 		let blockchain = backend.blockchain();
 		// Get the header I want to work with.
 		let Ok(reference_hash) = client.expect_block_hash_from_id(&reference_id) else {
-			return Err(internal_err("Block header not found"))
+			return Err(internal_err("Block header not found"));
 		};
 		let header = match client.header(reference_hash) {
 			Ok(Some(h)) => h,
@@ -511,7 +698,13 @@ where
 									trace_api_version, e
 								))
 							})?
-							.map_err(|e| internal_err(format!("DispatchError: {:?}", e)))?;
+							.map_err(|_| {
+								Self::missing_tracing_runtime(
+									&api,
+									parent_block_hash,
+									tracing_runtimes.clone(),
+								)
+							})?;
 					} else {
 						// Pre-london update, legacy transactions.
 						let _result = match transaction {
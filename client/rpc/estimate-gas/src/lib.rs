@@ -0,0 +1,137 @@
+// Copyright 2019-2023 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! `eth_estimateGas` implementation accepting the geth state override set.
+//!
+//! `eth_call` already applies a state override set (balance, nonce, code, state and state diff
+//! of any account), but the Frontier `eth_estimateGas` doesn't take one. When overrides are
+//! provided, the estimation is a binary search over `eth_call` executions with the same
+//! overrides, as geth does. Requests without overrides are forwarded to the Frontier
+//! implementation.
+
+use ethereum_types::{H160, U256};
+use fc_rpc::EstimateGasAdapter;
+use fc_rpc_core::types::{BlockNumber, Bytes, CallRequest, CallStateOverride, RichBlock};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	RpcModule,
+};
+use std::{collections::BTreeMap, marker::PhantomData};
+
+/// Gas used by a plain transfer, below which no call can succeed.
+const MIN_GAS_LIMIT: u64 = 21_000;
+
+#[rpc(server)]
+pub trait EthEstimateGasApi {
+	/// Estimate the gas needed by a call, optionally against an overridden state.
+	#[method(name = "eth_estimateGas")]
+	async fn estimate_gas(
+		&self,
+		request: CallRequest,
+		number: Option<BlockNumber>,
+		state_overrides: Option<BTreeMap<H160, CallStateOverride>>,
+	) -> RpcResult<U256>;
+}
+
+pub struct EthEstimateGas<EGA> {
+	/// Module serving the Frontier `eth_call`, `eth_estimateGas` and `eth_getBlockByNumber`.
+	eth: RpcModule<()>,
+	_marker: PhantomData<EGA>,
+}
+
+impl<EGA> EthEstimateGas<EGA> {
+	pub fn new(eth: RpcModule<()>) -> Self {
+		Self {
+			eth,
+			_marker: PhantomData,
+		}
+	}
+
+	/// Whether the call succeeds with the given gas limit.
+	async fn succeeds(
+		&self,
+		request: &CallRequest,
+		gas: U256,
+		number: &Option<BlockNumber>,
+		state_overrides: &BTreeMap<H160, CallStateOverride>,
+	) -> RpcResult<()> {
+		let request = CallRequest {
+			gas: Some(gas),
+			..request.clone()
+		};
+		self.eth
+			.call::<_, Bytes>("eth_call", (request, number.clone(), state_overrides))
+			.await?;
+		Ok(())
+	}
+
+	/// Gas limit of the block the estimation is made against.
+	async fn block_gas_limit(&self, number: &Option<BlockNumber>) -> RpcResult<U256> {
+		let number = number.clone().unwrap_or(BlockNumber::Latest);
+		let block: Option<RichBlock> = self
+			.eth
+			.call("eth_getBlockByNumber", (number, false))
+			.await?;
+
+		block
+			.map(|block| block.inner.header.gas_limit)
+			.ok_or_else(|| fc_rpc::internal_err("block not found"))
+	}
+}
+
+#[async_trait]
+impl<EGA> EthEstimateGasApiServer for EthEstimateGas<EGA>
+where
+	EGA: EstimateGasAdapter + Send + Sync + 'static,
+{
+	async fn estimate_gas(
+		&self,
+		request: CallRequest,
+		number: Option<BlockNumber>,
+		state_overrides: Option<BTreeMap<H160, CallStateOverride>>,
+	) -> RpcResult<U256> {
+		let state_overrides = match state_overrides {
+			Some(state_overrides) if !state_overrides.is_empty() => state_overrides,
+			_ => return Ok(self.eth.call("eth_estimateGas", (request, number)).await?),
+		};
+
+		let request = EGA::adapt_request(request);
+		let mut highest = match request.gas {
+			Some(gas) => gas,
+			None => self.block_gas_limit(&number).await?,
+		};
+
+		// A call failing with the highest gas limit can't be estimated, its error is returned.
+		self.succeeds(&request, highest, &number, &state_overrides)
+			.await?;
+
+		let mut lowest = U256::from(MIN_GAS_LIMIT - 1);
+		while lowest + 1 < highest {
+			let middle = (lowest + highest) / 2;
+			match self
+				.succeeds(&request, middle, &number, &state_overrides)
+				.await
+			{
+				Ok(()) => highest = middle,
+				Err(_) => lowest = middle,
+			}
+		}
+
+		log::debug!(target: "estimate-gas", "estimated {} with state overrides", highest);
+		Ok(highest)
+	}
+}
@@ -0,0 +1,212 @@
+// Copyright 2019-2023 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! `eth_call` implementation accepting geth's `blockOverrides`.
+//!
+//! Calls without block overrides are forwarded to the Frontier implementation, which supports
+//! the state override set. Calls with block overrides are executed through `EthCallApi`, which
+//! applies the overridden number, timestamp and base fee in the runtime before the call.
+
+use eth_call_runtime_api::EthCallApi as EthCallRuntimeApi;
+use ethereum_types::{H160, H256, U256, U64};
+use fc_rpc::{frontier_backend_client, internal_err};
+use fc_rpc_core::types::{BlockNumber, Bytes, CallRequest, CallStateOverride};
+use fp_evm::ExitReason;
+use jsonrpsee::{
+	core::{async_trait, Error as RpcError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject, INVALID_PARAMS_CODE},
+	RpcModule,
+};
+use serde::Deserialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use std::{collections::BTreeMap, sync::Arc};
+
+/// Error code of reverted calls, as returned by geth.
+const EXECUTION_REVERTED_CODE: i32 = 3;
+
+/// Block context overrides of an `eth_call`.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct BlockOverrides {
+	pub number: Option<U256>,
+	pub time: Option<U64>,
+	pub base_fee: Option<U256>,
+}
+
+impl From<BlockOverrides> for eth_call_runtime_api::BlockOverrides {
+	fn from(overrides: BlockOverrides) -> Self {
+		Self {
+			number: overrides.number,
+			time: overrides.time.map(|time| time.low_u64()),
+			base_fee: overrides.base_fee,
+		}
+	}
+}
+
+fn invalid_params(message: &str) -> RpcError {
+	RpcError::Call(CallError::Custom(ErrorObject::owned(
+		INVALID_PARAMS_CODE,
+		message,
+		None::<()>,
+	)))
+}
+
+fn execution_reverted(data: Vec<u8>) -> RpcError {
+	RpcError::Call(CallError::Custom(ErrorObject::owned(
+		EXECUTION_REVERTED_CODE,
+		"execution reverted",
+		Some(Bytes(data)),
+	)))
+}
+
+#[rpc(server)]
+pub trait EthCallApi {
+	/// Executes a call, optionally against an overridden state and block context.
+	#[method(name = "eth_call")]
+	async fn call(
+		&self,
+		request: CallRequest,
+		number: Option<BlockNumber>,
+		state_overrides: Option<BTreeMap<H160, CallStateOverride>>,
+		block_overrides: Option<BlockOverrides>,
+	) -> RpcResult<Bytes>;
+}
+
+pub struct EthCall<B: BlockT, C> {
+	client: Arc<C>,
+	backend: Arc<dyn fc_db::BackendReader<B> + Send + Sync>,
+	/// Module serving the Frontier `eth_call`.
+	eth: RpcModule<()>,
+}
+
+impl<B: BlockT, C> EthCall<B, C> {
+	pub fn new(
+		client: Arc<C>,
+		backend: Arc<dyn fc_db::BackendReader<B> + Send + Sync>,
+		eth: RpcModule<()>,
+	) -> Self {
+		Self {
+			client,
+			backend,
+			eth,
+		}
+	}
+}
+
+impl<B, C> EthCall<B, C>
+where
+	B: BlockT<Hash = H256>,
+	C: HeaderBackend<B> + Send + Sync + 'static,
+{
+	/// Hash of the block the call is executed on top of.
+	async fn block_hash(&self, number: Option<BlockNumber>) -> RpcResult<B::Hash> {
+		let info = self.client.info();
+		let hash = match number.unwrap_or(BlockNumber::Latest) {
+			BlockNumber::Num(number) => {
+				let number = number
+					.try_into()
+					.map_err(|_| invalid_params("block number out of range"))?;
+				self.client
+					.hash(number)
+					.map_err(|err| internal_err(format!("{:?}", err)))?
+			}
+			BlockNumber::Earliest => Some(info.genesis_hash),
+			BlockNumber::Latest | BlockNumber::Pending => Some(info.best_hash),
+			BlockNumber::Safe | BlockNumber::Finalized => Some(info.finalized_hash),
+			BlockNumber::Hash { hash, .. } => {
+				frontier_backend_client::load_hash::<B, C>(
+					self.client.as_ref(),
+					self.backend.as_ref(),
+					hash,
+				)
+				.await?
+			}
+		};
+
+		hash.ok_or_else(|| internal_err("block not found"))
+	}
+}
+
+#[async_trait]
+impl<B, C> EthCallApiServer for EthCall<B, C>
+where
+	B: BlockT<Hash = H256>,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + Send + Sync + 'static,
+	C::Api: EthCallRuntimeApi<B>,
+{
+	async fn call(
+		&self,
+		request: CallRequest,
+		number: Option<BlockNumber>,
+		state_overrides: Option<BTreeMap<H160, CallStateOverride>>,
+		block_overrides: Option<BlockOverrides>,
+	) -> RpcResult<Bytes> {
+		let Some(block_overrides) = block_overrides else {
+			return Ok(self
+				.eth
+				.call("eth_call", (request, number, state_overrides))
+				.await?);
+		};
+		if state_overrides.map_or(false, |overrides| !overrides.is_empty()) {
+			return Err(invalid_params(
+				"block overrides can't be combined with state overrides",
+			));
+		}
+		let Some(to) = request.to else {
+			return Err(invalid_params(
+				"block overrides aren't supported for contract creations",
+			));
+		};
+
+		let hash = self.block_hash(number).await?;
+		let access_list = request.access_list.map(|access_list| {
+			access_list
+				.into_iter()
+				.map(|item| (item.address, item.storage_keys))
+				.collect()
+		});
+
+		let info = self
+			.client
+			.runtime_api()
+			.call_with_block_overrides(
+				hash,
+				request.from.unwrap_or_default(),
+				to,
+				request.data.map(|data| data.into_vec()).unwrap_or_default(),
+				request.value.unwrap_or_default(),
+				request.gas,
+				// Legacy requests only set a gas price, which then acts as the maximum fee.
+				request.max_fee_per_gas.or(request.gas_price),
+				request.max_priority_fee_per_gas,
+				request.nonce,
+				access_list,
+				block_overrides.into(),
+			)
+			.map_err(|err| internal_err(format!("runtime api access error: {:?}", err)))?
+			.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))?;
+
+		match info.exit_reason {
+			ExitReason::Succeed(_) => Ok(Bytes(info.value)),
+			ExitReason::Revert(_) => Err(execution_reverted(info.value)),
+			ExitReason::Error(err) => Err(internal_err(format!("evm error: {:?}", err))),
+			ExitReason::Fatal(err) => Err(internal_err(format!("evm fatal: {:?}", err))),
+		}
+	}
+}
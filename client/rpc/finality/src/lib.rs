@@ -13,15 +13,75 @@
 
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
-use fc_rpc::frontier_backend_client::{self, is_canon};
-use jsonrpsee::{core::RpcResult, proc_macros::rpc};
-use sp_core::H256;
+use ethereum::BlockV2 as EthereumBlock;
+use ethereum_types::{Bloom, H64};
+use fc_rpc::{
+	frontier_backend_client::{self, is_canon},
+	OverrideHandle,
+};
+use futures::{stream, FutureExt, StreamExt};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::SubscriptionResult, SubscriptionSink};
+use sc_client_api::{
+	backend::{Backend, StorageProvider},
+	client::BlockchainEvents,
+};
+use sc_rpc::SubscriptionTaskExecutor;
+use serde::Serialize;
+use sp_core::{Bytes, H160, H256, U256};
 use std::{marker::PhantomData, sync::Arc};
 //TODO ideally we wouldn't depend on BlockId here. Can we change frontier
 // so it's load_hash helper returns an H256 instead of wrapping it in a BlockId?
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block;
 
+/// Ethereum header of a block finalized by the relay chain.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinalizedHead {
+	pub hash: H256,
+	pub parent_hash: H256,
+	#[serde(rename = "sha3Uncles")]
+	pub uncles_hash: H256,
+	pub miner: H160,
+	pub state_root: H256,
+	pub transactions_root: H256,
+	pub receipts_root: H256,
+	pub logs_bloom: Bloom,
+	pub difficulty: U256,
+	pub number: U256,
+	pub gas_limit: U256,
+	pub gas_used: U256,
+	pub timestamp: U256,
+	pub extra_data: Bytes,
+	pub mix_hash: H256,
+	pub nonce: H64,
+}
+
+impl From<EthereumBlock> for FinalizedHead {
+	fn from(block: EthereumBlock) -> Self {
+		let header = block.header;
+
+		Self {
+			hash: header.hash(),
+			parent_hash: header.parent_hash,
+			uncles_hash: header.ommers_hash,
+			miner: header.beneficiary,
+			state_root: header.state_root,
+			transactions_root: header.transactions_root,
+			receipts_root: header.receipts_root,
+			logs_bloom: header.logs_bloom,
+			difficulty: header.difficulty,
+			number: header.number,
+			gas_limit: header.gas_limit,
+			gas_used: header.gas_used,
+			timestamp: header.timestamp.into(),
+			extra_data: Bytes(header.extra_data),
+			mix_hash: header.mix_hash,
+			nonce: header.nonce,
+		}
+	}
+}
+
 /// An RPC endpoint to check for finality of blocks and transactions in Moonbeam
 #[rpc(server)]
 #[async_trait::async_trait]
@@ -35,28 +95,48 @@ pub trait MoonbeamFinalityApi {
 	/// Returns false if the transaction is not found
 	#[method(name = "moon_isTxFinalized")]
 	fn is_tx_finalized(&self, tx_hash: H256) -> RpcResult<bool>;
+
+	/// Streams the Ethereum header of each block once it is finalized by the relay chain, in
+	/// order. Blocks finalized together are all reported, oldest first.
+	#[subscription(
+		name = "moon_subscribeFinalizedHeads" => "moon_finalizedHead",
+		unsubscribe = "moon_unsubscribeFinalizedHeads",
+		item = FinalizedHead
+	)]
+	fn subscribe_finalized_heads(&self);
 }
 
-pub struct MoonbeamFinality<B: Block, C> {
+pub struct MoonbeamFinality<B: Block, C, BE> {
 	pub backend: Arc<dyn fc_db::BackendReader<B> + Send + Sync>,
 	pub client: Arc<C>,
-	_phdata: PhantomData<B>,
+	pub overrides: Arc<OverrideHandle<B>>,
+	pub executor: SubscriptionTaskExecutor,
+	_phdata: PhantomData<(B, BE)>,
 }
 
-impl<B: Block, C> MoonbeamFinality<B, C> {
-	pub fn new(client: Arc<C>, backend: Arc<dyn fc_db::BackendReader<B> + Send + Sync>) -> Self {
+impl<B: Block, C, BE> MoonbeamFinality<B, C, BE> {
+	pub fn new(
+		client: Arc<C>,
+		backend: Arc<dyn fc_db::BackendReader<B> + Send + Sync>,
+		overrides: Arc<OverrideHandle<B>>,
+		executor: SubscriptionTaskExecutor,
+	) -> Self {
 		Self {
 			backend,
 			client,
+			overrides,
+			executor,
 			_phdata: Default::default(),
 		}
 	}
 }
 
-impl<B, C> MoonbeamFinalityApiServer for MoonbeamFinality<B, C>
+impl<B, C, BE> MoonbeamFinalityApiServer for MoonbeamFinality<B, C, BE>
 where
 	B: Block<Hash = H256>,
-	C: HeaderBackend<B> + Send + Sync + 'static,
+	C: HeaderBackend<B> + StorageProvider<B, BE> + BlockchainEvents<B>,
+	C: Send + Sync + 'static,
+	BE: Backend<B> + 'static,
 {
 	fn is_block_finalized(&self, raw_hash: H256) -> RpcResult<bool> {
 		let client = self.client.clone();
@@ -78,6 +158,59 @@ where
 			Ok(false)
 		}
 	}
+
+	fn subscribe_finalized_heads(&self, sink: SubscriptionSink) -> SubscriptionResult {
+		let client = self.client.clone();
+		let overrides = self.overrides.clone();
+		let notifications = self
+			.client
+			.finality_notification_stream()
+			.map(move |notification| {
+				// The blocks implicitly finalized by this notification come before its own block.
+				let heads: Vec<FinalizedHead> = notification
+					.tree_route
+					.iter()
+					.chain(std::iter::once(&notification.hash))
+					.filter_map(|hash| {
+						finalized_head::<B, C, BE>(client.as_ref(), overrides.as_ref(), *hash)
+					})
+					.collect();
+
+				stream::iter(heads)
+			})
+			.flatten();
+
+		let fut = async move {
+			sink.pipe_from_stream(notifications).await;
+		};
+		self.executor.spawn(
+			"moonbeam-finalized-heads-subscription",
+			Some("rpc"),
+			fut.boxed(),
+		);
+
+		Ok(())
+	}
+}
+
+/// Ethereum header of a Substrate block, if it has Ethereum data.
+fn finalized_head<B, C, BE>(
+	client: &C,
+	overrides: &OverrideHandle<B>,
+	substrate_hash: B::Hash,
+) -> Option<FinalizedHead>
+where
+	B: Block<Hash = H256>,
+	C: HeaderBackend<B> + StorageProvider<B, BE>,
+	BE: Backend<B>,
+{
+	let schema = fc_storage::onchain_storage_schema::<B, C, BE>(client, substrate_hash);
+	overrides
+		.schemas
+		.get(&schema)
+		.unwrap_or(&overrides.fallback)
+		.current_block(substrate_hash)
+		.map(FinalizedHead::from)
 }
 
 fn is_block_finalized_inner<B: Block<Hash = H256>, C: HeaderBackend<B> + 'static>(
@@ -0,0 +1,134 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC providing the state needed to wake the contracts hibernated by pallet-evm-hibernation.
+//!
+//! The storage of a hibernated contract is no longer part of the state, it is read from the
+//! state of the block preceding its hibernation. This requires a node which did not prune that
+//! state (an archive node).
+
+use evm_hibernation_runtime_api::{storage_commitment, EvmHibernationApi};
+use fc_rpc::internal_err;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H160, H256};
+use sp_runtime::traits::{
+	Block as BlockT, Header as HeaderT, One, Saturating, UniqueSaturatedInto,
+};
+use std::{marker::PhantomData, sync::Arc};
+
+/// State of a hibernated contract, to be provided to `EvmHibernation::wake_contract`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HibernatedContractState {
+	/// Block in which the contract was hibernated.
+	pub hibernated_at: u64,
+	/// Keccak256 hash of the contract code.
+	pub code_hash: H256,
+	/// Commitment to the contract storage.
+	pub storage_root: H256,
+	/// Code of the contract.
+	pub code: Bytes,
+	/// Storage entries of the contract, sorted by key.
+	pub storage: Vec<(H256, H256)>,
+}
+
+/// An RPC endpoint to retrieve the state of hibernated contracts in Moonbeam
+#[rpc(server)]
+pub trait MoonbeamHibernationApi<BlockHash> {
+	/// Returns the code and storage of a contract hibernated as of the given block (the best
+	/// block by default), which must be provided to wake it.
+	/// Returns null if the contract is not hibernated.
+	#[method(name = "moon_hibernatedContractState")]
+	fn hibernated_contract_state(
+		&self,
+		contract: H160,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<HibernatedContractState>>;
+}
+
+pub struct MoonbeamHibernation<B: BlockT, C> {
+	pub client: Arc<C>,
+	_phdata: PhantomData<B>,
+}
+
+impl<B: BlockT, C> MoonbeamHibernation<B, C> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_phdata: Default::default(),
+		}
+	}
+}
+
+impl<B, C> MoonbeamHibernationApiServer<B::Hash> for MoonbeamHibernation<B, C>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + Send + Sync + 'static,
+	C::Api: EvmHibernationApi<B, <B::Header as HeaderT>::Number>,
+{
+	fn hibernated_contract_state(
+		&self,
+		contract: H160,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<HibernatedContractState>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let hibernated = match api
+			.hibernated_contract(at, contract)
+			.map_err(|e| internal_err(format!("Runtime api access error: {:?}", e)))?
+		{
+			Some(hibernated) => hibernated,
+			None => return Ok(None),
+		};
+
+		// The state at the end of the parent block is the last one containing the storage.
+		let parent_number = hibernated.hibernated_at.saturating_sub(One::one());
+		let parent_hash = self
+			.client
+			.hash(parent_number)
+			.map_err(|e| internal_err(format!("Failed to retrieve block hash: {:?}", e)))?
+			.ok_or_else(|| internal_err("Block preceding the hibernation not found"))?;
+
+		let state = api.contract_state(parent_hash, contract).map_err(|e| {
+			internal_err(format!(
+				"Failed to retrieve the contract state, it may have been pruned: {:?}",
+				e
+			))
+		})?;
+
+		// The contract may have been modified by another contract in the block of its
+		// hibernation, in which case the state cannot be retrieved.
+		if H256(sp_core::hashing::keccak_256(&state.code)) != hibernated.code_hash
+			|| storage_commitment(&state.storage) != hibernated.storage_root
+		{
+			return Err(internal_err(
+				"Contract state before the hibernation does not match the commitment",
+			));
+		}
+
+		Ok(Some(HibernatedContractState {
+			hibernated_at: hibernated.hibernated_at.unique_saturated_into(),
+			code_hash: hibernated.code_hash,
+			storage_root: hibernated.storage_root,
+			code: state.code.into(),
+			storage: state.storage,
+		}))
+	}
+}
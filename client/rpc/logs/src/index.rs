@@ -0,0 +1,227 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Bloom index of the Ethereum logs, by sections of `SECTION_SIZE` blocks.
+//!
+//! Each section bloom is the union of the logs blooms of its blocks, so a section whose bloom
+//! doesn't match a filter can't contain any matching log and is skipped as a whole.
+//! Only finalized blocks are indexed: a retracted block would otherwise leave its bloom in its
+//! section while the block replacing it is never indexed.
+
+use ethereum_types::Bloom;
+use fc_rpc::{OverrideHandle, StorageOverride};
+use futures::StreamExt;
+use sc_client_api::{
+	backend::{AuxStore, Backend, StorageProvider},
+	client::BlockchainEvents,
+};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::{marker::PhantomData, sync::Arc};
+
+/// Number of blocks covered by each bloom of the index.
+pub const SECTION_SIZE: u32 = 1024;
+
+/// Maximum number of blocks indexed before writing the index to the aux store.
+const BATCH_SIZE: u32 = 1024;
+
+const SECTION_KEY_PREFIX: &[u8] = b"moonbeam_log_bloom_section";
+const SYNCED_KEY: &[u8] = b"moonbeam_log_bloom_synced";
+
+fn section_key(section: u32) -> Vec<u8> {
+	let mut key = SECTION_KEY_PREFIX.to_vec();
+	key.extend_from_slice(&section.to_be_bytes());
+	key
+}
+
+/// Returns the storage override matching the Ethereum storage schema of the given block.
+pub(crate) fn storage_override<'a, B, C, BE>(
+	client: &C,
+	overrides: &'a OverrideHandle<B>,
+	hash: B::Hash,
+) -> &'a dyn StorageOverride<B>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + StorageProvider<B, BE>,
+	BE: Backend<B>,
+{
+	let schema = fc_storage::onchain_storage_schema::<B, C, BE>(client, hash);
+	overrides
+		.schemas
+		.get(&schema)
+		.unwrap_or(&overrides.fallback)
+		.as_ref()
+}
+
+/// Read access to the bloom index, stored in the client aux store.
+pub struct LogBloomIndex<B, C> {
+	client: Arc<C>,
+	_phdata: PhantomData<B>,
+}
+
+impl<B, C> Clone for LogBloomIndex<B, C> {
+	fn clone(&self) -> Self {
+		Self::new(self.client.clone())
+	}
+}
+
+impl<B, C> LogBloomIndex<B, C>
+where
+	B: BlockT,
+	C: AuxStore,
+{
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_phdata: Default::default(),
+		}
+	}
+
+	/// Highest block such that all the blocks up to it are indexed.
+	pub fn synced(&self) -> Option<u32> {
+		self.client
+			.get_aux(SYNCED_KEY)
+			.ok()
+			.flatten()
+			.and_then(|bytes| bytes.try_into().ok())
+			.map(u32::from_be_bytes)
+	}
+
+	/// Union of the logs blooms of the indexed blocks of a section.
+	pub fn section_bloom(&self, section: u32) -> Option<Bloom> {
+		self.client
+			.get_aux(&section_key(section))
+			.ok()
+			.flatten()
+			.filter(|bytes| bytes.len() == 256)
+			.map(|bytes| Bloom::from_slice(&bytes))
+	}
+
+	/// Returns the section of `number` and its bloom if the whole section is indexed.
+	pub fn indexed_section(&self, number: u32, synced: Option<u32>) -> Option<(u32, Bloom)> {
+		let section = number / SECTION_SIZE;
+		let section_end = section.saturating_mul(SECTION_SIZE) + (SECTION_SIZE - 1);
+		if synced? < section_end {
+			return None;
+		}
+		self.section_bloom(section).map(|bloom| (section, bloom))
+	}
+}
+
+/// Background task indexing the finalized blocks.
+pub struct LogBloomIndexWorker<B: BlockT, C, BE> {
+	client: Arc<C>,
+	overrides: Arc<OverrideHandle<B>>,
+	index: LogBloomIndex<B, C>,
+	_phdata: PhantomData<BE>,
+}
+
+impl<B, C, BE> LogBloomIndexWorker<B, C, BE>
+where
+	B: BlockT,
+	B::Header: HeaderT<Number = u32>,
+	C: AuxStore + BlockchainEvents<B> + HeaderBackend<B> + StorageProvider<B, BE>,
+	C: Send + Sync + 'static,
+	BE: Backend<B> + 'static,
+{
+	pub fn new(client: Arc<C>, overrides: Arc<OverrideHandle<B>>) -> Self {
+		Self {
+			index: LogBloomIndex::new(client.clone()),
+			client,
+			overrides,
+			_phdata: Default::default(),
+		}
+	}
+
+	/// Indexes the finalized blocks, then follows the finality notifications.
+	pub async fn run(self) {
+		let mut finality_notifications = self.client.finality_notification_stream();
+		loop {
+			match self.index_batch() {
+				// There might be more blocks to index.
+				Ok(true) => continue,
+				Ok(false) => (),
+				Err(e) => log::warn!(target: "log-bloom-index", "{}", e),
+			}
+
+			if finality_notifications.next().await.is_none() {
+				break;
+			}
+		}
+	}
+
+	/// Indexes up to `BATCH_SIZE` finalized blocks. Returns whether any block has been indexed.
+	fn index_batch(&self) -> Result<bool, String> {
+		let finalized = self.client.info().finalized_number;
+		let from = self
+			.index
+			.synced()
+			.map_or(0, |synced| synced.saturating_add(1));
+		if from > finalized {
+			return Ok(false);
+		}
+		let to = finalized.min(from.saturating_add(BATCH_SIZE - 1));
+
+		let mut sections: Vec<(u32, Bloom)> = vec![];
+		for number in from..=to {
+			let section = number / SECTION_SIZE;
+			let bloom = self.block_bloom(number)?;
+			match sections.last_mut() {
+				Some((last, section_bloom)) if *last == section => {
+					section_bloom.accrue_bloom(&bloom)
+				}
+				_ => {
+					let mut section_bloom = self.index.section_bloom(section).unwrap_or_default();
+					section_bloom.accrue_bloom(&bloom);
+					sections.push((section, section_bloom));
+				}
+			}
+		}
+
+		let keys: Vec<_> = sections
+			.iter()
+			.map(|(section, _)| section_key(*section))
+			.collect();
+		let synced = to.to_be_bytes();
+		let mut insert: Vec<(&[u8], &[u8])> = keys
+			.iter()
+			.zip(sections.iter())
+			.map(|(key, (_, bloom))| (&key[..], bloom.as_bytes()))
+			.collect();
+		insert.push((SYNCED_KEY, &synced[..]));
+		self.client
+			.insert_aux(&insert, &[])
+			.map_err(|e| format!("Failed to write the log bloom index : {:?}", e))?;
+
+		log::debug!(target: "log-bloom-index", "Indexed blocks {}..={}", from, to);
+		Ok(true)
+	}
+
+	/// Logs bloom of a canonical block. Blocks without Ethereum data have an empty bloom.
+	fn block_bloom(&self, number: u32) -> Result<Bloom, String> {
+		let hash = self
+			.client
+			.hash(number)
+			.map_err(|e| format!("Failed to fetch hash of block {} : {:?}", number, e))?
+			.ok_or_else(|| format!("Block {} not found", number))?;
+		Ok(
+			storage_override::<B, C, BE>(self.client.as_ref(), &self.overrides, hash)
+				.current_block(hash)
+				.map(|block| block.header.logs_bloom)
+				.unwrap_or_default(),
+		)
+	}
+}
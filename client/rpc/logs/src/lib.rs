@@ -0,0 +1,387 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! `eth_getLogs` implementation skipping the block ranges whose bloom doesn't match the filter,
+//! using the index maintained by `LogBloomIndexWorker`.
+//!
+//! Queries are bounded by an optional maximum block range and a maximum number of results.
+//! Going over a limit returns an EIP-1474 "limit exceeded" error describing the limit, allowing
+//! clients to split their query instead of waiting for a timeout.
+//!
+//! The crate also serves `eth_subscribe`, to support `logs` subscriptions emitting the logs of
+//! finalized blocks only.
+
+use ethereum_types::{BloomInput, H160, H256, U256};
+use fc_rpc::{frontier_backend_client, internal_err, OverrideHandle};
+use fc_rpc_core::types::{BlockNumber, Filter, Log, VariadicValue};
+use jsonrpsee::{
+	core::{async_trait, Error as RpcError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject, INVALID_PARAMS_CODE},
+};
+use sc_client_api::backend::{AuxStore, Backend, StorageProvider};
+use serde::Serialize;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::{marker::PhantomData, sync::Arc};
+
+mod index;
+mod pubsub;
+pub use index::{LogBloomIndex, LogBloomIndexWorker, SECTION_SIZE};
+pub use pubsub::{EthLogsPubSub, EthLogsPubSubApiServer, Finality, Kind};
+
+/// EIP-1474 "limit exceeded" error code.
+const LIMIT_EXCEEDED_CODE: i32 = -32005;
+
+/// Limits of the `eth_getLogs` queries.
+#[derive(Clone, Copy, Debug)]
+pub struct EthLogsConfig {
+	/// Maximum number of blocks a query can cover. `0` means unlimited.
+	pub max_block_range: u32,
+	/// Maximum number of logs a query can return.
+	pub max_results: u32,
+	/// Number of recent blocks whose logs are retained when the frontier mappings are pruned.
+	/// Older blocks are rejected instead of returning incomplete results.
+	pub retained_blocks: Option<u32>,
+}
+
+/// Data of the "limit exceeded" errors.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LimitExceeded {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_block_range: Option<u32>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_results: Option<u32>,
+}
+
+fn limit_exceeded(message: String, data: LimitExceeded) -> RpcError {
+	RpcError::Call(CallError::Custom(ErrorObject::owned(
+		LIMIT_EXCEEDED_CODE,
+		message,
+		Some(data),
+	)))
+}
+
+fn invalid_params(message: &str) -> RpcError {
+	RpcError::Call(CallError::Custom(ErrorObject::owned(
+		INVALID_PARAMS_CODE,
+		message,
+		None::<()>,
+	)))
+}
+
+/// Addresses and topics of a filter. An empty list matches anything.
+struct LogFilter {
+	addresses: Vec<H160>,
+	/// Alternatives for each topic position.
+	topics: Vec<Vec<H256>>,
+}
+
+impl LogFilter {
+	fn new(filter: &Filter) -> Self {
+		let addresses = match &filter.address {
+			Some(VariadicValue::Single(address)) => vec![*address],
+			Some(VariadicValue::Multiple(addresses)) => addresses.clone(),
+			_ => vec![],
+		};
+		let positions = match &filter.topics {
+			Some(VariadicValue::Single(position)) => vec![position.clone()],
+			Some(VariadicValue::Multiple(positions)) => positions.clone(),
+			_ => vec![],
+		};
+		let topics = positions
+			.into_iter()
+			.map(|position| match position {
+				Some(VariadicValue::Single(Some(topic))) => vec![topic],
+				// A null alternative matches any topic.
+				Some(VariadicValue::Multiple(topics)) if topics.iter().all(Option::is_some) => {
+					topics.into_iter().flatten().collect()
+				}
+				_ => vec![],
+			})
+			.collect();
+
+		Self { addresses, topics }
+	}
+
+	/// A filter matching any log.
+	fn any() -> Self {
+		Self {
+			addresses: vec![],
+			topics: vec![],
+		}
+	}
+
+	fn matches_bloom(&self, bloom: &ethereum_types::Bloom) -> bool {
+		let contains = |bytes: &[u8]| bloom.contains_input(BloomInput::Raw(bytes));
+
+		(self.addresses.is_empty() || self.addresses.iter().any(|a| contains(a.as_bytes())))
+			&& self.topics.iter().all(|alternatives| {
+				alternatives.is_empty() || alternatives.iter().any(|t| contains(t.as_bytes()))
+			})
+	}
+
+	fn matches_log(&self, log: &ethereum::Log) -> bool {
+		(self.addresses.is_empty() || self.addresses.contains(&log.address))
+			&& self
+				.topics
+				.iter()
+				.enumerate()
+				.all(|(position, alternatives)| {
+					alternatives.is_empty()
+						|| log
+							.topics
+							.get(position)
+							.map_or(false, |topic| alternatives.contains(topic))
+				})
+	}
+}
+
+/// Appends the logs of a block matching the filter.
+fn block_logs<B, C, BE>(
+	client: &C,
+	overrides: &OverrideHandle<B>,
+	hash: B::Hash,
+	filter: &LogFilter,
+	logs: &mut Vec<Log>,
+) where
+	B: BlockT<Hash = H256>,
+	C: HeaderBackend<B> + StorageProvider<B, BE>,
+	BE: Backend<B>,
+{
+	let storage = index::storage_override::<B, C, BE>(client, overrides, hash);
+	let Some(block) = storage.current_block(hash) else {
+		return;
+	};
+	if !filter.matches_bloom(&block.header.logs_bloom) {
+		return;
+	}
+
+	let block_hash = block.header.hash();
+	let statuses = storage
+		.current_transaction_statuses(hash)
+		.unwrap_or_default();
+	let mut log_index = 0u32;
+	for status in statuses {
+		for (transaction_log_index, log) in status.logs.iter().enumerate() {
+			if filter.matches_log(log) {
+				logs.push(Log {
+					address: log.address,
+					topics: log.topics.clone(),
+					data: log.data.clone().into(),
+					block_hash: Some(block_hash),
+					block_number: Some(block.header.number),
+					transaction_hash: Some(status.transaction_hash),
+					transaction_index: Some(U256::from(status.transaction_index)),
+					log_index: Some(U256::from(log_index)),
+					transaction_log_index: Some(U256::from(transaction_log_index)),
+					removed: false,
+				});
+			}
+			log_index += 1;
+		}
+	}
+}
+
+/// An `eth_getLogs` endpoint accelerated by a bloom index
+#[rpc(server)]
+pub trait EthLogsApi {
+	/// Returns the logs matching the given filter.
+	#[method(name = "eth_getLogs")]
+	async fn logs(&self, filter: Filter) -> RpcResult<Vec<Log>>;
+}
+
+pub struct EthLogs<B: BlockT, C, BE> {
+	client: Arc<C>,
+	backend: Arc<dyn fc_db::BackendReader<B> + Send + Sync>,
+	overrides: Arc<OverrideHandle<B>>,
+	index: LogBloomIndex<B, C>,
+	config: EthLogsConfig,
+	_phdata: PhantomData<BE>,
+}
+
+impl<B: BlockT, C, BE> EthLogs<B, C, BE>
+where
+	C: AuxStore,
+{
+	pub fn new(
+		client: Arc<C>,
+		backend: Arc<dyn fc_db::BackendReader<B> + Send + Sync>,
+		overrides: Arc<OverrideHandle<B>>,
+		config: EthLogsConfig,
+	) -> Self {
+		Self {
+			index: LogBloomIndex::new(client.clone()),
+			client,
+			backend,
+			overrides,
+			config,
+			_phdata: Default::default(),
+		}
+	}
+}
+
+impl<B, C, BE> EthLogs<B, C, BE>
+where
+	B: BlockT<Hash = H256>,
+	B::Header: HeaderT<Number = u32>,
+	C: AuxStore + HeaderBackend<B> + StorageProvider<B, BE>,
+	C: Send + Sync + 'static,
+	BE: Backend<B> + 'static,
+{
+	/// Converts a block of the filter range to a height, capped by the best block.
+	fn block_number(&self, number: Option<BlockNumber>) -> RpcResult<u32> {
+		let info = self.client.info();
+		Ok(match number.unwrap_or(BlockNumber::Latest) {
+			BlockNumber::Num(number) => number.min(info.best_number.into()) as u32,
+			BlockNumber::Earliest => 0,
+			BlockNumber::Latest | BlockNumber::Pending => info.best_number,
+			BlockNumber::Safe | BlockNumber::Finalized => info.finalized_number,
+			BlockNumber::Hash { .. } => {
+				return Err(invalid_params(
+					"block hashes are not supported in a range, use `blockHash` instead",
+				))
+			}
+		})
+	}
+
+	/// Fails if the logs of the given block may have been pruned.
+	fn check_retained(&self, number: u32) -> RpcResult<()> {
+		if let Some(retained_blocks) = self.config.retained_blocks {
+			let oldest = self
+				.client
+				.info()
+				.best_number
+				.saturating_sub(retained_blocks);
+			if number < oldest {
+				return Err(invalid_params(&format!(
+					"logs of the blocks before {} are pruned",
+					oldest
+				)));
+			}
+		}
+		Ok(())
+	}
+
+	fn check_results(&self, logs: &[Log]) -> RpcResult<()> {
+		if logs.len() > self.config.max_results as usize {
+			return Err(limit_exceeded(
+				format!(
+					"query returned more than {} results",
+					self.config.max_results
+				),
+				LimitExceeded {
+					max_block_range: None,
+					max_results: Some(self.config.max_results),
+				},
+			));
+		}
+		Ok(())
+	}
+}
+
+#[async_trait]
+impl<B, C, BE> EthLogsApiServer for EthLogs<B, C, BE>
+where
+	B: BlockT<Hash = H256>,
+	B::Header: HeaderT<Number = u32>,
+	C: AuxStore + HeaderBackend<B> + StorageProvider<B, BE>,
+	C: Send + Sync + 'static,
+	BE: Backend<B> + 'static,
+{
+	async fn logs(&self, filter: Filter) -> RpcResult<Vec<Log>> {
+		let log_filter = LogFilter::new(&filter);
+		let mut logs = vec![];
+
+		if let Some(eth_hash) = filter.block_hash {
+			let hash = frontier_backend_client::load_hash::<B, C>(
+				self.client.as_ref(),
+				self.backend.as_ref(),
+				eth_hash,
+			)
+			.await?
+			.ok_or_else(|| internal_err("Block hash not found"))?;
+			let number = self
+				.client
+				.number(hash)
+				.map_err(|e| internal_err(format!("{:?}", e)))?
+				.ok_or_else(|| internal_err("Block hash not found"))?;
+			self.check_retained(number)?;
+			block_logs::<B, C, BE>(
+				self.client.as_ref(),
+				&self.overrides,
+				hash,
+				&log_filter,
+				&mut logs,
+			);
+			self.check_results(&logs)?;
+			return Ok(logs);
+		}
+
+		let from = self.block_number(filter.from_block)?;
+		let to = self.block_number(filter.to_block)?;
+		if from > to {
+			return Ok(logs);
+		}
+		self.check_retained(from)?;
+		let max_block_range = self.config.max_block_range;
+		if max_block_range > 0 && to - from >= max_block_range {
+			return Err(limit_exceeded(
+				format!("query exceeds max block range {}", max_block_range),
+				LimitExceeded {
+					max_block_range: Some(max_block_range),
+					max_results: None,
+				},
+			));
+		}
+
+		let synced = self.index.synced();
+		let mut checked_section = None;
+		let mut number = from as u64;
+		while number <= to as u64 {
+			// Skip the whole section if it is indexed and can't contain a matching log.
+			let section = number as u32 / SECTION_SIZE;
+			if checked_section != Some(section) {
+				checked_section = Some(section);
+				if let Some((_, bloom)) = self.index.indexed_section(number as u32, synced) {
+					if !log_filter.matches_bloom(&bloom) {
+						number = (section as u64 + 1) * SECTION_SIZE as u64;
+						continue;
+					}
+				}
+			}
+
+			let hash = self
+				.client
+				.hash(number as u32)
+				.map_err(|e| internal_err(format!("{:?}", e)))?
+				.ok_or_else(|| internal_err(format!("Block {} not found", number)))?;
+			block_logs::<B, C, BE>(
+				self.client.as_ref(),
+				&self.overrides,
+				hash,
+				&log_filter,
+				&mut logs,
+			);
+			self.check_results(&logs)?;
+
+			number += 1;
+		}
+
+		Ok(logs)
+	}
+}
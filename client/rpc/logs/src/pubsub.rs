@@ -0,0 +1,237 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! `eth_subscribe` implementation accepting a `finality` parameter on `logs` subscriptions.
+//!
+//! With `finality: "finalized"`, the logs of a block are only emitted once the block is finalized
+//! by the relay chain, so they are never retracted by a reorg. Other subscriptions, including
+//! `logs` subscriptions with `finality: "best"` or without finality, are forwarded to the
+//! Frontier implementation.
+
+use crate::{block_logs, LogFilter};
+use ethereum_types::H256;
+use fc_rpc::OverrideHandle;
+use fc_rpc_core::types::Filter;
+use futures::{stream, FutureExt, StreamExt};
+use jsonrpsee::{
+	proc_macros::rpc,
+	types::{
+		error::{ErrorObject, INTERNAL_ERROR_CODE, INVALID_PARAMS_CODE},
+		SubscriptionResult,
+	},
+	RpcModule, SubscriptionSink,
+};
+use sc_client_api::{
+	backend::{Backend, StorageProvider},
+	client::BlockchainEvents,
+};
+use sc_rpc::SubscriptionTaskExecutor;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use std::{marker::PhantomData, sync::Arc};
+
+/// Kind of an `eth_subscribe` subscription.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Kind {
+	NewHeads,
+	Logs,
+	NewPendingTransactions,
+	Syncing,
+}
+
+/// Blocks whose logs are emitted by a `logs` subscription.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Finality {
+	/// Blocks finalized by the relay chain.
+	Finalized,
+	/// New best blocks, as they are imported.
+	#[default]
+	Best,
+}
+
+/// An `eth_subscribe` endpoint supporting finalized `logs` subscriptions
+#[rpc(server)]
+pub trait EthLogsPubSubApi {
+	/// Subscribes to new Ethereum data. `logs` subscriptions accept a `finality` parameter,
+	/// either "best" (default) or "finalized".
+	#[subscription(
+		name = "eth_subscribe" => "eth_subscription",
+		unsubscribe = "eth_unsubscribe",
+		item = Value
+	)]
+	fn subscribe(&self, kind: Kind, params: Option<Value>);
+}
+
+pub struct EthLogsPubSub<B: BlockT, C, BE> {
+	client: Arc<C>,
+	overrides: Arc<OverrideHandle<B>>,
+	executor: SubscriptionTaskExecutor,
+	/// Module serving the Frontier `eth_subscribe`.
+	pubsub: RpcModule<()>,
+	_phdata: PhantomData<BE>,
+}
+
+impl<B: BlockT, C, BE> EthLogsPubSub<B, C, BE> {
+	pub fn new(
+		client: Arc<C>,
+		overrides: Arc<OverrideHandle<B>>,
+		executor: SubscriptionTaskExecutor,
+		pubsub: RpcModule<()>,
+	) -> Self {
+		Self {
+			client,
+			overrides,
+			executor,
+			pubsub,
+			_phdata: Default::default(),
+		}
+	}
+}
+
+impl<B, C, BE> EthLogsPubSub<B, C, BE>
+where
+	B: BlockT<Hash = H256>,
+	C: HeaderBackend<B> + StorageProvider<B, BE> + BlockchainEvents<B>,
+	C: Send + Sync + 'static,
+	BE: Backend<B> + 'static,
+{
+	/// Emits the logs matching the filter of each block finalized by the relay chain.
+	fn subscribe_finalized_logs(&self, sink: SubscriptionSink, filter: LogFilter) {
+		let client = self.client.clone();
+		let overrides = self.overrides.clone();
+		let logs = self
+			.client
+			.finality_notification_stream()
+			.map(move |notification| {
+				let mut logs = vec![];
+				// The blocks implicitly finalized by this notification come before its own block.
+				for hash in notification
+					.tree_route
+					.iter()
+					.chain(std::iter::once(&notification.hash))
+				{
+					block_logs::<B, C, BE>(
+						client.as_ref(),
+						overrides.as_ref(),
+						*hash,
+						&filter,
+						&mut logs,
+					);
+				}
+
+				stream::iter(logs)
+			})
+			.flatten();
+
+		let fut = async move {
+			sink.pipe_from_stream(logs).await;
+		};
+		self.executor.spawn(
+			"moonbeam-finalized-logs-subscription",
+			Some("rpc"),
+			fut.boxed(),
+		);
+	}
+
+	/// Forwards the subscription to the Frontier implementation.
+	fn forward(&self, mut sink: SubscriptionSink, kind: Kind, params: Option<Value>) {
+		let pubsub = self.pubsub.clone();
+
+		let fut = async move {
+			match pubsub.subscribe("eth_subscribe", (kind, params)).await {
+				Ok(subscription) => {
+					let items = stream::unfold(subscription, |mut subscription| async move {
+						match subscription.next::<Value>().await {
+							Some(Ok((item, _))) => Some((item, subscription)),
+							_ => None,
+						}
+					});
+					sink.pipe_from_stream(items).await;
+				}
+				Err(e) => {
+					let _ = sink.reject(ErrorObject::owned(
+						INTERNAL_ERROR_CODE,
+						e.to_string(),
+						None::<()>,
+					));
+				}
+			}
+		};
+		self.executor
+			.spawn("moonbeam-eth-pubsub-subscription", Some("rpc"), fut.boxed());
+	}
+}
+
+impl<B, C, BE> EthLogsPubSubApiServer for EthLogsPubSub<B, C, BE>
+where
+	B: BlockT<Hash = H256>,
+	C: HeaderBackend<B> + StorageProvider<B, BE> + BlockchainEvents<B>,
+	C: Send + Sync + 'static,
+	BE: Backend<B> + 'static,
+{
+	fn subscribe(
+		&self,
+		mut sink: SubscriptionSink,
+		kind: Kind,
+		mut params: Option<Value>,
+	) -> SubscriptionResult {
+		let finality = params
+			.as_mut()
+			.filter(|_| kind == Kind::Logs)
+			.and_then(Value::as_object_mut)
+			.and_then(|params| params.remove("finality"))
+			.map(serde_json::from_value::<Finality>)
+			.transpose();
+		let finality = match finality {
+			Ok(finality) => finality.unwrap_or_default(),
+			Err(_) => {
+				sink.reject(ErrorObject::owned(
+					INVALID_PARAMS_CODE,
+					"finality must be \"finalized\" or \"best\"",
+					None::<()>,
+				))?;
+				return Ok(());
+			}
+		};
+
+		if finality == Finality::Best {
+			self.forward(sink, kind, params);
+			return Ok(());
+		}
+
+		let filter = match params {
+			None | Some(Value::Null) => LogFilter::any(),
+			Some(params) => match serde_json::from_value::<Filter>(params) {
+				Ok(filter) => LogFilter::new(&filter),
+				Err(e) => {
+					sink.reject(ErrorObject::owned(
+						INVALID_PARAMS_CODE,
+						format!("Invalid logs filter: {}", e),
+						None::<()>,
+					))?;
+					return Ok(());
+				}
+			},
+		};
+		self.subscribe_finalized_logs(sink, filter);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,266 @@
+// Copyright 2019-2023 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! `eth_sendRawTransaction` implementation rejecting unsupported transaction types.
+//!
+//! Frontier fails to decode the transactions of an EIP-2718 type it doesn't know with a generic
+//! error. Transactions are checked here against the types listed by `TransactionTypesApi` at the
+//! best block, so that unsupported types (e.g. EIP-4844 blob or EIP-7702 set code transactions)
+//! are rejected with an explicit error before reaching the transaction pool. Supported
+//! transactions are forwarded to the Frontier implementation.
+//!
+//! Transactions are also checked against the `TxPoolPolicy`: a transaction replacing a pooled
+//! transaction of the same sender and nonce must raise its fees by a minimum percentage, and the
+//! number of transactions an account can queue behind a nonce gap is limited.
+
+use ethereum::EnvelopedDecodable;
+use ethereum_types::{H256, U256, U64};
+use fc_rpc::internal_err;
+use fc_rpc_core::types::Bytes;
+use fp_rpc::EthereumRuntimeRPCApi;
+use jsonrpsee::{
+	core::{async_trait, Error as RpcError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject, INVALID_PARAMS_CODE},
+	RpcModule,
+};
+use moonbeam_rpc_txpool::{
+	nonces_by_sender, transaction_nonce, transaction_sender, TransactionV2, TxPool,
+	TxPoolRuntimeApi,
+};
+use sc_transaction_pool::ChainApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+use transaction_types_runtime_api::{TransactionTypesApi, LEGACY_TRANSACTION_TYPE};
+
+/// Types supported by the runtimes predating `TransactionTypesApi`.
+const DEFAULT_SUPPORTED_TYPES: [u8; 3] = [LEGACY_TRANSACTION_TYPE, 1, 2];
+
+/// Largest EIP-2718 transaction type. Legacy transactions start with the prefix of an RLP list.
+const MAX_TRANSACTION_TYPE: u8 = 0x7f;
+const RLP_LIST_PREFIX: u8 = 0xc0;
+
+/// EIP-1474 "transaction rejected" error code.
+const TRANSACTION_REJECTED_CODE: i32 = -32003;
+
+/// Replacement and queueing rules of the transactions submitted to the pool.
+#[derive(Clone, Copy, Debug)]
+pub struct TxPoolPolicy {
+	/// Minimum increase, in percent, of the fees of a transaction replacing a pooled transaction
+	/// of the same sender and nonce.
+	pub price_bump: u32,
+	/// Maximum number of transactions of an account queued behind a nonce gap. `0` means
+	/// unlimited.
+	pub max_queued_per_account: u32,
+}
+
+fn invalid_params(message: String) -> RpcError {
+	RpcError::Call(CallError::Custom(ErrorObject::owned(
+		INVALID_PARAMS_CODE,
+		message,
+		None::<()>,
+	)))
+}
+
+fn transaction_rejected(message: String) -> RpcError {
+	RpcError::Call(CallError::Custom(ErrorObject::owned(
+		TRANSACTION_REJECTED_CODE,
+		message,
+		None::<()>,
+	)))
+}
+
+/// Maximum fee per gas and priority fee per gas of a transaction.
+fn transaction_fees(transaction: &TransactionV2) -> (U256, U256) {
+	match transaction {
+		TransactionV2::Legacy(t) => (t.gas_price, t.gas_price),
+		TransactionV2::EIP2930(t) => (t.gas_price, t.gas_price),
+		TransactionV2::EIP1559(t) => (t.max_fee_per_gas, t.max_priority_fee_per_gas),
+	}
+}
+
+/// EIP-2718 type of an encoded transaction, `None` if the encoding is invalid.
+pub fn transaction_type(bytes: &[u8]) -> Option<u8> {
+	match *bytes.first()? {
+		first if first >= RLP_LIST_PREFIX => Some(LEGACY_TRANSACTION_TYPE),
+		// Type 0 is reserved for legacy transactions, which have no envelope.
+		first if first > 0 && first <= MAX_TRANSACTION_TYPE => Some(first),
+		_ => None,
+	}
+}
+
+fn transaction_type_name(transaction_type: u8) -> &'static str {
+	match transaction_type {
+		LEGACY_TRANSACTION_TYPE => "legacy",
+		1 => "EIP-2930 access list",
+		2 => "EIP-1559 dynamic fee",
+		3 => "EIP-4844 blob",
+		4 => "EIP-7702 set code",
+		_ => "unknown",
+	}
+}
+
+#[rpc(server)]
+pub trait EthSendRawTransactionApi {
+	/// Submits an encoded transaction, if its type is supported by the runtime.
+	#[method(name = "eth_sendRawTransaction")]
+	async fn send_raw_transaction(&self, bytes: Bytes) -> RpcResult<H256>;
+
+	/// EIP-2718 types of the transactions supported at the best block, `0x0` standing for
+	/// legacy transactions.
+	#[method(name = "moon_supportedTransactionTypes")]
+	fn supported_transaction_types(&self) -> RpcResult<Vec<U64>>;
+}
+
+pub struct EthSendRawTransaction<B: BlockT, C, A: ChainApi> {
+	client: Arc<C>,
+	/// Module serving the Frontier `eth_sendRawTransaction`.
+	eth: RpcModule<()>,
+	txpool: TxPool<B, C, A>,
+	policy: TxPoolPolicy,
+}
+
+impl<B: BlockT, C, A: ChainApi> EthSendRawTransaction<B, C, A> {
+	pub fn new(
+		client: Arc<C>,
+		eth: RpcModule<()>,
+		txpool: TxPool<B, C, A>,
+		policy: TxPoolPolicy,
+	) -> Self {
+		Self {
+			client,
+			eth,
+			txpool,
+			policy,
+		}
+	}
+}
+
+impl<B, C, A> EthSendRawTransaction<B, C, A>
+where
+	B: BlockT<Hash = H256> + Send + Sync + 'static,
+	C: ProvideRuntimeApi<B>,
+	C: HeaderMetadata<B, Error = BlockChainError> + HeaderBackend<B>,
+	C: Send + Sync + 'static,
+	A: ChainApi<Block = B> + 'static,
+	C::Api: TransactionTypesApi<B> + TxPoolRuntimeApi<B> + EthereumRuntimeRPCApi<B>,
+{
+	/// Transaction types supported by the runtime of the best block.
+	fn supported_types(&self) -> RpcResult<Vec<u8>> {
+		let hash = self.client.info().best_hash;
+		let api = self.client.runtime_api();
+
+		let has_api = api
+			.has_api::<dyn TransactionTypesApi<B>>(hash)
+			.map_err(|err| internal_err(format!("runtime api access error: {:?}", err)))?;
+		if !has_api {
+			return Ok(DEFAULT_SUPPORTED_TYPES.to_vec());
+		}
+
+		api.supported_transaction_types(hash)
+			.map_err(|err| internal_err(format!("runtime api access error: {:?}", err)))
+	}
+
+	/// Checks the replacement and queueing rules against the transactions in the pool.
+	fn check_pool_policy(&self, transaction: &TransactionV2) -> RpcResult<()> {
+		let sender = transaction_sender(transaction);
+		let nonce = transaction_nonce(transaction);
+		let pooled = self.txpool.ethereum_transactions()?;
+
+		let replaced = pooled
+			.ready
+			.iter()
+			.chain(pooled.future.iter())
+			.find(|txn| transaction_nonce(txn) == nonce && transaction_sender(txn) == sender);
+		if let Some(replaced) = replaced {
+			let bump = |fee: U256| {
+				fee.saturating_mul(U256::from(100u32.saturating_add(self.policy.price_bump)))
+					/ U256::from(100u32)
+			};
+			let (max_fee, priority_fee) = transaction_fees(transaction);
+			let (replaced_max_fee, replaced_priority_fee) = transaction_fees(replaced);
+			if max_fee < bump(replaced_max_fee) || priority_fee < bump(replaced_priority_fee) {
+				return Err(transaction_rejected(format!(
+					"replacement transaction underpriced, fees must be raised by at least {}%",
+					self.policy.price_bump
+				)));
+			}
+			return Ok(());
+		}
+
+		let max_queued = self.policy.max_queued_per_account;
+		if max_queued == 0 {
+			return Ok(());
+		}
+		let ready_nonces = nonces_by_sender(&pooled.ready);
+		let next_nonce = self.txpool.next_nonce(sender, ready_nonces.get(&sender))?;
+		if nonce > next_nonce {
+			let queued = pooled
+				.future
+				.iter()
+				.filter(|txn| transaction_sender(txn) == sender)
+				.count();
+			if queued >= max_queued as usize {
+				return Err(transaction_rejected(format!(
+					"account {:?} has {} transactions queued behind missing nonce {}, limit is {}",
+					sender, queued, next_nonce, max_queued
+				)));
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[async_trait]
+impl<B, C, A> EthSendRawTransactionApiServer for EthSendRawTransaction<B, C, A>
+where
+	B: BlockT<Hash = H256> + Send + Sync + 'static,
+	C: ProvideRuntimeApi<B>,
+	C: HeaderMetadata<B, Error = BlockChainError> + HeaderBackend<B>,
+	C: Send + Sync + 'static,
+	A: ChainApi<Block = B> + 'static,
+	C::Api: TransactionTypesApi<B> + TxPoolRuntimeApi<B> + EthereumRuntimeRPCApi<B>,
+{
+	async fn send_raw_transaction(&self, bytes: Bytes) -> RpcResult<H256> {
+		let transaction_type = transaction_type(&bytes.0)
+			.ok_or_else(|| invalid_params("invalid transaction encoding".into()))?;
+
+		if !self.supported_types()?.contains(&transaction_type) {
+			return Err(invalid_params(format!(
+				"transaction type {:#x} ({}) not supported",
+				transaction_type,
+				transaction_type_name(transaction_type)
+			)));
+		}
+
+		let transaction = TransactionV2::decode(&bytes.0)
+			.map_err(|_| invalid_params("invalid transaction encoding".into()))?;
+		self.check_pool_policy(&transaction)?;
+
+		Ok(self.eth.call("eth_sendRawTransaction", (bytes,)).await?)
+	}
+
+	fn supported_transaction_types(&self) -> RpcResult<Vec<U64>> {
+		Ok(self
+			.supported_types()?
+			.into_iter()
+			.map(|transaction_type| U64::from(transaction_type))
+			.collect())
+	}
+}
@@ -23,15 +23,22 @@
 //! - A main `CacheTask` managing the cache and the communication between tasks.
 //! - For each traced block an async task responsible to wait for a permit, spawn a blocking
 //!   task and waiting for the result, then send it to the main `CacheTask`.
+//!
+//! Traces of blocks no longer used by any batch are kept in the stores of the `store` module,
+//! within the configured memory and disk budgets, so following requests don't replay them.
 
 use futures::{select, stream::FuturesUnordered, FutureExt, StreamExt};
 use std::{collections::BTreeMap, future::Future, marker::PhantomData, sync::Arc, time::Duration};
 use tokio::{
-	sync::{mpsc, oneshot, Semaphore},
+	sync::{mpsc, oneshot},
 	time::sleep,
 };
 use tracing::{instrument, Instrument};
 
+mod store;
+use store::MemoryTraceStore;
+pub use store::{DiskTraceStore, TraceStoreConfig};
+
 use sc_client_api::backend::{Backend, StateBackend, StorageProvider};
 use sc_utils::mpsc::TracingUnboundedSender;
 use sp_api::{ApiExt, Core, HeaderT, ProvideRuntimeApi};
@@ -50,6 +57,8 @@ use fp_rpc::EthereumRuntimeRPCApi;
 
 use moonbeam_client_evm_tracing::{
 	formatters::ResponseFormatter,
+	overrides::{MissingTracingRuntime, TracingRuntimeSource},
+	permits::{RequestKind, TracingPermits},
 	types::block::{self, TransactionTrace},
 };
 pub use moonbeam_rpc_core_trace::{FilterRequest, TraceServer};
@@ -396,7 +405,7 @@ enum CacheBlockState {
 /// main cache task.
 enum BlockingTaskMessage {
 	/// Notify the tracing for this block has started as the blocking task got a permit from
-	/// the semaphore. This is used to prevent the deletion of a cache entry for a block that has
+	/// the permit pool. This is used to prevent the deletion of a cache entry for a block that has
 	/// started being traced.
 	Started { block_hash: H256 },
 	/// The tracing is finished and the result is send to the main task.
@@ -410,10 +419,13 @@ enum BlockingTaskMessage {
 pub struct CacheTask<B, C, BE> {
 	client: Arc<C>,
 	backend: Arc<BE>,
-	blocking_permits: Arc<Semaphore>,
+	blocking_permits: Arc<TracingPermits>,
 	cached_blocks: BTreeMap<H256, CacheBlock>,
 	batches: BTreeMap<u64, Vec<H256>>,
 	next_batch_id: u64,
+	memory_store: MemoryTraceStore,
+	disk_store: Option<Arc<DiskTraceStore>>,
+	tracing_runtimes: Option<Arc<TracingRuntimeSource>>,
 	metrics: Option<Metrics>,
 	_phantom: PhantomData<B>,
 }
@@ -441,10 +453,23 @@ where
 		client: Arc<C>,
 		backend: Arc<BE>,
 		cache_duration: Duration,
-		blocking_permits: Arc<Semaphore>,
+		store_config: TraceStoreConfig,
+		blocking_permits: Arc<TracingPermits>,
 		overrides: Arc<OverrideHandle<B>>,
+		tracing_runtimes: Option<Arc<TracingRuntimeSource>>,
 		prometheus: Option<PrometheusRegistry>,
 	) -> (impl Future<Output = ()>, CacheRequester) {
+		// Failing to open the disk store only disables it, tracing still works from memory.
+		let disk_store = store_config.disk_path.and_then(|path| {
+			match DiskTraceStore::open(path.clone(), store_config.disk_budget) {
+				Ok(store) => Some(Arc::new(store)),
+				Err(err) => {
+					log::error!(target: "tracing", "Failed to open trace store {path:?} : {err:?}");
+					None
+				}
+			}
+		});
+
 		// Communication with the outside world :
 		let (requester_tx, mut requester_rx) =
 			sc_utils::mpsc::tracing_unbounded("trace-filter-cache", 100_000);
@@ -454,8 +479,7 @@ where
 			// The following variables are polled by the select! macro, and thus cannot be
 			// part of Self without introducing borrowing issues.
 			let mut batch_expirations = FuturesUnordered::new();
-			let (blocking_tx, mut blocking_rx) =
-				mpsc::channel(blocking_permits.available_permits() * 2);
+			let (blocking_tx, mut blocking_rx) = mpsc::channel(blocking_permits.max_permits() * 2);
 			let metrics = if let Some(registry) = prometheus {
 				match Metrics::register(&registry) {
 					Ok(metrics) => Some(metrics),
@@ -477,6 +501,9 @@ where
 				cached_blocks: BTreeMap::new(),
 				batches: BTreeMap::new(),
 				next_batch_id: 0,
+				memory_store: MemoryTraceStore::new(store_config.memory_budget),
+				disk_store,
+				tracing_runtimes,
 				metrics,
 				_phantom: Default::default(),
 			};
@@ -550,6 +577,17 @@ where
 					block_cache.active_batch_count
 				);
 			}
+			// It has been traced by an expired batch and is still in the memory store.
+			else if let Some(traces) = self.memory_store.take(&block) {
+				tracing::trace!("Memory store hit for block {}.", block);
+				self.cached_blocks.insert(
+					block,
+					CacheBlock {
+						active_batch_count: 1,
+						state: CacheBlockState::Cached { traces: Ok(traces) },
+					},
+				);
+			}
 			// Otherwise we need to queue this block for tracing.
 			else {
 				tracing::trace!("Cache miss for block {}, pooling it for tracing.", block);
@@ -560,6 +598,8 @@ where
 				let backend = Arc::clone(&self.backend);
 				let blocking_tx = blocking_tx.clone();
 				let overrides = overrides.clone();
+				let disk_store = self.disk_store.clone();
+				let tracing_runtimes = self.tracing_runtimes.clone();
 
 				// Spawn all block caching asynchronously.
 				// It will wait to obtain a permit, then spawn a blocking task.
@@ -573,7 +613,7 @@ where
 							tracing::trace!("Tracing of the block has been cancelled.");
 								return;
 							},
-							permit = blocking_permits.acquire().fuse() => permit,
+							permit = blocking_permits.acquire(RequestKind::Filter).fuse() => permit,
 						);
 
 						// Warn the main task that block tracing as started, and
@@ -587,7 +627,14 @@ where
 						// Perform block tracing in a tokio blocking task.
 						let result = async {
 							tokio::task::spawn_blocking(move || {
-								Self::cache_block(client, backend, block, overrides.clone())
+								Self::load_or_cache_block(
+									client,
+									backend,
+									disk_store,
+									block,
+									overrides.clone(),
+									tracing_runtimes,
+								)
 							})
 							.await
 							.map_err(|e| {
@@ -779,19 +826,56 @@ where
 				}
 
 				if remove {
-					let _ = self.cached_blocks.remove(&block);
+					// Successful traces are kept in the memory store for later batches.
+					if let Some(CacheBlock {
+						state: CacheBlockState::Cached { traces: Ok(traces) },
+						..
+					}) = self.cached_blocks.remove(&block)
+					{
+						self.memory_store.insert(block, traces);
+					}
 				}
 			}
 		}
 	}
 
+	/// (In blocking task) Read the block traces from the disk store, or trace the block and
+	/// write the result to the disk store.
+	#[instrument(skip(client, backend, disk_store, overrides, tracing_runtimes))]
+	fn load_or_cache_block(
+		client: Arc<C>,
+		backend: Arc<BE>,
+		disk_store: Option<Arc<DiskTraceStore>>,
+		substrate_hash: H256,
+		overrides: Arc<OverrideHandle<B>>,
+		tracing_runtimes: Option<Arc<TracingRuntimeSource>>,
+	) -> TxsTraceRes {
+		let Some(disk_store) = disk_store else {
+			return Self::cache_block(client, backend, substrate_hash, overrides, tracing_runtimes);
+		};
+
+		if let Some(traces) = disk_store.load(&substrate_hash) {
+			tracing::trace!("Disk store hit for block {}.", substrate_hash);
+			return Ok(traces);
+		}
+
+		let result =
+			Self::cache_block(client, backend, substrate_hash, overrides, tracing_runtimes);
+		if let Ok(traces) = &result {
+			disk_store.store(&substrate_hash, traces);
+		}
+		result
+	}
+
 	/// (In blocking task) Use the Runtime API to trace the block.
-	#[instrument(skip(client, backend, overrides))]
-	fn cache_block(
+	/// Also used by the offline trace export of the node.
+	#[instrument(skip(client, backend, overrides, tracing_runtimes))]
+	pub fn cache_block(
 		client: Arc<C>,
 		backend: Arc<BE>,
 		substrate_hash: H256,
 		overrides: Arc<OverrideHandle<B>>,
+		tracing_runtimes: Option<Arc<TracingRuntimeSource>>,
 	) -> TxsTraceRes {
 		// Get Subtrate block data.
 		let api = client.runtime_api();
@@ -860,10 +944,20 @@ where
 						height,
 						e
 					);
-					format!(
-						"Internal runtime error when replaying block {} : {:?}",
-						height, e
-					)
+					// The on-chain runtimes refuse to trace, report which tracing runtime is
+					// missing.
+					match api.version(substrate_parent_hash) {
+						Ok(version) => MissingTracingRuntime::new(
+							&version.spec_name,
+							version.spec_version,
+							tracing_runtimes.as_deref(),
+						)
+						.to_string(),
+						Err(_) => format!(
+							"Internal runtime error when replaying block {} : {:?}",
+							height, e
+						),
+					}
 				})?;
 			Ok(moonbeam_rpc_primitives_debug::Response::Block)
 		};
@@ -0,0 +1,253 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Long-lived storage for block traces that outlive the batches which requested them.
+//!
+//! Blocks are traced at most once while they are kept by the `CacheTask` batches. Once the
+//! last batch using a block expires, its traces are moved to a `MemoryTraceStore` bounded by a
+//! byte budget. When a disk path is configured, every successfully traced block is also written
+//! to a `DiskTraceStore`, so the traces survive memory eviction and node restarts.
+//!
+//! Traces are keyed by Substrate block hash, which identifies the replayed state unambiguously:
+//! an entry never needs to be invalidated, only evicted.
+
+use ethereum_types::H256;
+use moonbeam_client_evm_tracing::types::block::TransactionTrace;
+use parity_scale_codec::{Decode, Encode};
+use parking_lot::Mutex;
+use std::{collections::BTreeMap, fs, io, path::PathBuf, str::FromStr};
+
+/// Extension of the files written by the `DiskTraceStore`.
+const TRACE_FILE_EXTENSION: &str = "trace";
+
+/// Budgets of the trace stores.
+#[derive(Clone, Debug, Default)]
+pub struct TraceStoreConfig {
+	/// Size in bytes of the traces kept in memory once their batches expired.
+	/// `0` disables the memory store.
+	pub memory_budget: usize,
+	/// Directory in which traces are persisted. `None` disables the disk store.
+	pub disk_path: Option<PathBuf>,
+	/// Size in bytes of the traces kept on disk.
+	pub disk_budget: u64,
+}
+
+/// Recency index shared by both stores: entries are evicted least recently used first.
+struct LruIndex<V> {
+	entries: BTreeMap<H256, (V, u64, u64)>,
+	order: BTreeMap<u64, H256>,
+	used: u64,
+	next_tick: u64,
+}
+
+impl<V> LruIndex<V> {
+	fn new() -> Self {
+		Self {
+			entries: BTreeMap::new(),
+			order: BTreeMap::new(),
+			used: 0,
+			next_tick: 0,
+		}
+	}
+
+	fn insert(&mut self, block: H256, value: V, size: u64) {
+		self.remove(&block);
+		let tick = self.next_tick;
+		self.next_tick = self.next_tick.saturating_add(1);
+		self.entries.insert(block, (value, size, tick));
+		self.order.insert(tick, block);
+		self.used = self.used.saturating_add(size);
+	}
+
+	fn touch(&mut self, block: &H256) {
+		if let Some((_, _, tick)) = self.entries.get_mut(block) {
+			self.order.remove(tick);
+			*tick = self.next_tick;
+			self.order.insert(*tick, *block);
+			self.next_tick = self.next_tick.saturating_add(1);
+		}
+	}
+
+	fn remove(&mut self, block: &H256) -> Option<V> {
+		let (value, size, tick) = self.entries.remove(block)?;
+		self.order.remove(&tick);
+		self.used = self.used.saturating_sub(size);
+		Some(value)
+	}
+
+	/// Removes the least recently used entry.
+	fn pop_oldest(&mut self) -> Option<(H256, V)> {
+		let (_, block) = self.order.pop_first()?;
+		let (value, size, _) = self.entries.remove(&block)?;
+		self.used = self.used.saturating_sub(size);
+		Some((block, value))
+	}
+}
+
+/// In-memory store of block traces, bounded by their SCALE encoded size.
+/// Owned by the `CacheTask` and thus not synchronized.
+pub(crate) struct MemoryTraceStore {
+	budget: u64,
+	index: LruIndex<Vec<TransactionTrace>>,
+}
+
+impl MemoryTraceStore {
+	pub(crate) fn new(budget: usize) -> Self {
+		Self {
+			budget: budget as u64,
+			index: LruIndex::new(),
+		}
+	}
+
+	/// Keeps the traces of a block, evicting the least recently used blocks if the budget is
+	/// exceeded. Blocks larger than the whole budget are not kept.
+	pub(crate) fn insert(&mut self, block: H256, traces: Vec<TransactionTrace>) {
+		let size = traces.encoded_size() as u64;
+		if size > self.budget {
+			return;
+		}
+
+		self.index.insert(block, traces, size);
+		while self.index.used > self.budget {
+			if self.index.pop_oldest().is_none() {
+				break;
+			}
+		}
+	}
+
+	/// Takes the traces of a block out of the store. They are expected to be inserted back when
+	/// no longer used by any batch.
+	pub(crate) fn take(&mut self, block: &H256) -> Option<Vec<TransactionTrace>> {
+		self.index.remove(block)
+	}
+}
+
+/// On-disk store of block traces, one SCALE encoded file per block.
+/// Accessed from the blocking tracing tasks, hence the internal lock.
+pub struct DiskTraceStore {
+	path: PathBuf,
+	budget: u64,
+	index: Mutex<LruIndex<()>>,
+}
+
+impl DiskTraceStore {
+	/// Opens the store in the given directory, creating it if needed. Traces written by a
+	/// previous run are indexed by modification time and pruned to fit the budget.
+	pub fn open(path: PathBuf, budget: u64) -> io::Result<Self> {
+		fs::create_dir_all(&path)?;
+
+		let mut existing = vec![];
+		for entry in fs::read_dir(&path)? {
+			let entry = entry?;
+			let file_path = entry.path();
+			if file_path.extension().and_then(|e| e.to_str()) != Some(TRACE_FILE_EXTENSION) {
+				continue;
+			}
+			let Some(block) = file_path
+				.file_stem()
+				.and_then(|s| s.to_str())
+				.and_then(|s| H256::from_str(s).ok())
+			else {
+				continue;
+			};
+			let metadata = entry.metadata()?;
+			existing.push((metadata.modified()?, block, metadata.len()));
+		}
+		existing.sort();
+
+		let store = Self {
+			path,
+			budget,
+			index: Mutex::new(LruIndex::new()),
+		};
+		{
+			let mut index = store.index.lock();
+			for (_, block, size) in existing {
+				index.insert(block, (), size);
+			}
+			store.prune(&mut index);
+		}
+
+		Ok(store)
+	}
+
+	fn file_path(&self, block: &H256) -> PathBuf {
+		self.path
+			.join(format!("{:x}.{}", block, TRACE_FILE_EXTENSION))
+	}
+
+	/// Removes least recently used files until the store fits its budget.
+	fn prune(&self, index: &mut LruIndex<()>) {
+		while index.used > self.budget {
+			match index.pop_oldest() {
+				Some((block, ())) => {
+					let _ = fs::remove_file(self.file_path(&block));
+				}
+				None => break,
+			}
+		}
+	}
+
+	/// Reads the traces of a block, if stored.
+	/// A file that can't be read or decoded is discarded.
+	pub fn load(&self, block: &H256) -> Option<Vec<TransactionTrace>> {
+		let mut index = self.index.lock();
+		if !index.entries.contains_key(block) {
+			return None;
+		}
+
+		let file_path = self.file_path(block);
+		match fs::read(&file_path)
+			.ok()
+			.and_then(|bytes| Vec::<TransactionTrace>::decode(&mut &bytes[..]).ok())
+		{
+			Some(traces) => {
+				index.touch(block);
+				Some(traces)
+			}
+			None => {
+				tracing::warn!("Discarding unreadable trace file {:?}", file_path);
+				index.remove(block);
+				let _ = fs::remove_file(file_path);
+				None
+			}
+		}
+	}
+
+	/// Writes the traces of a block, evicting the least recently used blocks if the budget is
+	/// exceeded. Blocks larger than the whole budget are not stored.
+	pub fn store(&self, block: &H256, traces: &[TransactionTrace]) {
+		let bytes = traces.encode();
+		let size = bytes.len() as u64;
+		if size > self.budget {
+			return;
+		}
+
+		// Write to a temporary file first so a crash never leaves a truncated trace file behind.
+		let file_path = self.file_path(block);
+		let tmp_path = file_path.with_extension("tmp");
+		if let Err(e) = fs::write(&tmp_path, bytes).and_then(|_| fs::rename(&tmp_path, &file_path))
+		{
+			tracing::warn!("Failed to write trace file {:?} : {:?}", file_path, e);
+			let _ = fs::remove_file(tmp_path);
+			return;
+		}
+
+		let mut index = self.index.lock();
+		index.insert(*block, (), size);
+		self.prune(&mut index);
+	}
+}
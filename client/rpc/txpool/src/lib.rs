@@ -16,9 +16,10 @@
 
 use ethereum_types::{H160, H256, U256};
 use fc_rpc::{internal_err, public_key};
+use fp_rpc::EthereumRuntimeRPCApi;
 use jsonrpsee::core::RpcResult;
 pub use moonbeam_rpc_core_txpool::{
-	GetT, Summary, Transaction, TransactionMap, TxPoolResult, TxPoolServer,
+	GetT, StuckTransaction, Summary, Transaction, TransactionMap, TxPoolResult, TxPoolServer,
 };
 use sc_transaction_pool::{ChainApi, Pool};
 use sc_transaction_pool_api::InPoolTransaction;
@@ -27,13 +28,42 @@ use sha3::{Digest, Keccak256};
 use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 use sp_runtime::traits::Block as BlockT;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::{marker::PhantomData, sync::Arc};
 
-use moonbeam_rpc_primitives_txpool::{
+pub use moonbeam_rpc_primitives_txpool::{
 	Transaction as TransactionV2, TxPoolResponse, TxPoolRuntimeApi,
 };
 
+/// Sender of a transaction, the zero address if its signature is invalid.
+pub fn transaction_sender(txn: &TransactionV2) -> H160 {
+	match public_key(txn) {
+		Ok(pk) => H160::from(H256::from_slice(Keccak256::digest(&pk).as_slice())),
+		Err(_e) => H160::default(),
+	}
+}
+
+/// Nonce of a transaction.
+pub fn transaction_nonce(txn: &TransactionV2) -> U256 {
+	match txn {
+		TransactionV2::Legacy(t) => t.nonce,
+		TransactionV2::EIP2930(t) => t.nonce,
+		TransactionV2::EIP1559(t) => t.nonce,
+	}
+}
+
+/// Nonces of the transactions of each sender.
+pub fn nonces_by_sender(txns: &[TransactionV2]) -> HashMap<H160, BTreeSet<U256>> {
+	let mut nonces = HashMap::<H160, BTreeSet<U256>>::new();
+	for txn in txns {
+		nonces
+			.entry(transaction_sender(txn))
+			.or_default()
+			.insert(transaction_nonce(txn));
+	}
+	nonces
+}
+
 pub struct TxPool<B: BlockT, C, A: ChainApi> {
 	client: Arc<C>,
 	graph: Arc<Pool<A>>,
@@ -47,14 +77,11 @@ where
 	C: Send + Sync + 'static,
 	B: BlockT<Hash = H256> + Send + Sync + 'static,
 	A: ChainApi<Block = B> + 'static,
-	C::Api: TxPoolRuntimeApi<B>,
+	C::Api: TxPoolRuntimeApi<B> + EthereumRuntimeRPCApi<B>,
 {
 	/// Use the transaction graph interface to get the extrinsics currently in the ready and future
-	/// queues.
-	fn map_build<T>(&self) -> RpcResult<TxPoolResult<TransactionMap<T>>>
-	where
-		T: GetT + Serialize,
-	{
+	/// queues, and match them against ethereum transactions.
+	pub fn ethereum_transactions(&self) -> RpcResult<TxPoolResponse> {
 		// Collect transactions in the ready validated pool.
 		let txs_ready = self
 			.graph
@@ -83,7 +110,7 @@ where
 					"failed to retrieve Runtime Api version".to_string(),
 				));
 			};
-		let ethereum_txns: TxPoolResponse = if api_version == 1 {
+		Ok(if api_version == 1 {
 			#[allow(deprecated)]
 			let res = api.extrinsic_filter_before_version_2(best_block, txs_ready, txs_future)
 				.map_err(|err| {
@@ -106,20 +133,43 @@ where
 				.map_err(|err| {
 					internal_err(format!("fetch runtime extrinsic filter failed: {:?}", err))
 				})?
-		};
+		})
+	}
+
+	/// Next nonce of an account that can be executed, following its chain nonce and its
+	/// `ready_nonces`, the nonces of its transactions in the ready queue.
+	pub fn next_nonce(
+		&self,
+		address: H160,
+		ready_nonces: Option<&BTreeSet<U256>>,
+	) -> RpcResult<U256> {
+		let best_block = self.client.info().best_hash;
+		let mut nonce = self
+			.client
+			.runtime_api()
+			.account_basic(best_block, address)
+			.map_err(|err| internal_err(format!("fetch runtime account basic failed: {:?}", err)))?
+			.nonce;
+		if let Some(ready_nonces) = ready_nonces {
+			while ready_nonces.contains(&nonce) {
+				nonce = nonce.saturating_add(U256::one());
+			}
+		}
+		Ok(nonce)
+	}
+
+	/// Build the `T` response of the ethereum transactions of the ready and future queues.
+	fn map_build<T>(&self) -> RpcResult<TxPoolResult<TransactionMap<T>>>
+	where
+		T: GetT + Serialize,
+	{
+		let ethereum_txns = self.ethereum_transactions()?;
 		// Build the T response.
 		let mut pending = TransactionMap::<T>::new();
 		for txn in ethereum_txns.ready.iter() {
 			let hash = txn.hash();
-			let nonce = match txn {
-				TransactionV2::Legacy(t) => t.nonce,
-				TransactionV2::EIP2930(t) => t.nonce,
-				TransactionV2::EIP1559(t) => t.nonce,
-			};
-			let from_address = match public_key(txn) {
-				Ok(pk) => H160::from(H256::from_slice(Keccak256::digest(&pk).as_slice())),
-				Err(_e) => H160::default(),
-			};
+			let nonce = transaction_nonce(txn);
+			let from_address = transaction_sender(txn);
 			pending
 				.entry(from_address)
 				.or_insert_with(HashMap::new)
@@ -128,15 +178,8 @@ where
 		let mut queued = TransactionMap::<T>::new();
 		for txn in ethereum_txns.future.iter() {
 			let hash = txn.hash();
-			let nonce = match txn {
-				TransactionV2::Legacy(t) => t.nonce,
-				TransactionV2::EIP2930(t) => t.nonce,
-				TransactionV2::EIP1559(t) => t.nonce,
-			};
-			let from_address = match public_key(txn) {
-				Ok(pk) => H160::from(H256::from_slice(Keccak256::digest(&pk).as_slice())),
-				Err(_e) => H160::default(),
-			};
+			let nonce = transaction_nonce(txn);
+			let from_address = transaction_sender(txn);
 			queued
 				.entry(from_address)
 				.or_insert_with(HashMap::new)
@@ -163,7 +206,7 @@ where
 	C: Send + Sync + 'static,
 	B: BlockT<Hash = H256> + Send + Sync + 'static,
 	A: ChainApi<Block = B> + 'static,
-	C::Api: TxPoolRuntimeApi<B>,
+	C::Api: TxPoolRuntimeApi<B> + EthereumRuntimeRPCApi<B>,
 {
 	fn content(&self) -> RpcResult<TxPoolResult<TransactionMap<Transaction>>> {
 		self.map_build::<Transaction>()
@@ -180,6 +223,41 @@ where
 			queued: U256::from(status.future),
 		})
 	}
+
+	fn inspect_stuck(&self) -> RpcResult<TransactionMap<StuckTransaction>> {
+		let ethereum_txns = self.ethereum_transactions()?;
+		let ready_nonces = nonces_by_sender(&ethereum_txns.ready);
+
+		let mut next_nonces = HashMap::<H160, U256>::new();
+		let mut stuck = TransactionMap::<StuckTransaction>::new();
+		for txn in ethereum_txns.future.iter() {
+			let from_address = transaction_sender(txn);
+			let missing_nonce = match next_nonces.get(&from_address) {
+				Some(nonce) => *nonce,
+				None => {
+					let nonce = self.next_nonce(from_address, ready_nonces.get(&from_address))?;
+					next_nonces.insert(from_address, nonce);
+					nonce
+				}
+			};
+
+			let nonce = transaction_nonce(txn);
+			if nonce > missing_nonce {
+				stuck
+					.entry(from_address)
+					.or_insert_with(HashMap::new)
+					.insert(
+						nonce,
+						StuckTransaction {
+							hash: txn.hash(),
+							nonce,
+							missing_nonce,
+						},
+					);
+			}
+		}
+		Ok(stuck)
+	}
 }
 
 impl<B: BlockT, C, A: ChainApi> Clone for TxPool<B, C, A> {
@@ -0,0 +1,249 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Task submitting the pooled user operations in bundle transactions.
+
+use crate::{
+	entry_point::{self, AggregatedUserOperations},
+	PooledUserOperation, UserOperation, UserOperationPool,
+};
+use ethereum::{EIP1559Transaction, EIP1559TransactionMessage, TransactionAction, TransactionV2};
+use fp_evm::ExitReason;
+use fp_rpc::{ConvertTransactionRuntimeApi, EthereumRuntimeRPCApi};
+use futures::StreamExt;
+use libsecp256k1::{Message, PublicKey, SecretKey};
+use sc_client_api::BlockchainEvents;
+use sc_transaction_pool_api::{TransactionPool, TransactionSource};
+use sp_api::ProvideRuntimeApi;
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::{collections::BTreeMap, marker::PhantomData, sync::Arc};
+
+const LOG_TARGET: &str = "bundler";
+
+/// Submits, on each new best block, the pooled user operations of each EntryPoint in a bundle
+/// transaction signed by the bundler account.
+pub struct Bundler<B: BlockT, C, P> {
+	client: Arc<C>,
+	transaction_pool: Arc<P>,
+	pool: Arc<UserOperationPool>,
+	key: SecretKey,
+	address: H160,
+	entry_points: Vec<H160>,
+	max_bundle_size: usize,
+	next_nonce: U256,
+	_phdata: PhantomData<B>,
+}
+
+impl<B, C, P> Bundler<B, C, P>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + BlockchainEvents<B> + Send + Sync + 'static,
+	C::Api: EthereumRuntimeRPCApi<B> + ConvertTransactionRuntimeApi<B>,
+	P: TransactionPool<Block = B> + 'static,
+{
+	pub fn new(
+		client: Arc<C>,
+		transaction_pool: Arc<P>,
+		pool: Arc<UserOperationPool>,
+		key: H256,
+		entry_points: Vec<H160>,
+		max_bundle_size: u32,
+	) -> Result<Self, String> {
+		let key = SecretKey::parse(key.as_fixed_bytes())
+			.map_err(|_| "Invalid bundler private key".to_string())?;
+		let public = PublicKey::from_secret_key(&key);
+		let address = H160::from(H256::from(keccak_256(&public.serialize()[1..])));
+
+		Ok(Self {
+			client,
+			transaction_pool,
+			pool,
+			key,
+			address,
+			entry_points,
+			max_bundle_size: max_bundle_size as usize,
+			next_nonce: U256::zero(),
+			_phdata: Default::default(),
+		})
+	}
+
+	pub async fn run(mut self) {
+		log::info!(
+			target: LOG_TARGET,
+			"Bundling user operations as {:?} for EntryPoints {:?}",
+			self.address,
+			self.entry_points
+		);
+
+		let mut import_notifications = self.client.import_notification_stream();
+		while let Some(notification) = import_notifications.next().await {
+			if !notification.is_new_best || self.pool.is_empty() {
+				continue;
+			}
+
+			for entry_point in self.entry_points.clone() {
+				if let Err(e) = self.bundle(notification.hash, entry_point).await {
+					log::warn!(
+						target: LOG_TARGET,
+						"Failed to bundle user operations for {:?}: {}",
+						entry_point,
+						e
+					);
+				}
+			}
+		}
+	}
+
+	/// Submits a bundle of the pooled user operations of the EntryPoint.
+	async fn bundle(&mut self, at: B::Hash, entry_point: H160) -> Result<(), String> {
+		let pooled = self.pool.take(entry_point, self.max_bundle_size);
+		if pooled.is_empty() {
+			return Ok(());
+		}
+
+		let gas_price = self
+			.client
+			.runtime_api()
+			.gas_price(at)
+			.map_err(|e| format!("Runtime api access error: {:?}", e))?;
+
+		// The user operations are validated again against the new state, dropping the ones
+		// which became invalid (e.g. their nonce has been used).
+		let mut user_ops = Vec::new();
+		let mut aggregated: BTreeMap<H160, Vec<UserOperation>> = BTreeMap::new();
+		for pooled in pooled {
+			// The bundler would pay more gas than it is refunded, try again later.
+			if pooled.user_op.max_fee_per_gas < gas_price {
+				let _ = self.pool.insert(pooled);
+				continue;
+			}
+
+			let PooledUserOperation { user_op, hash, .. } = pooled;
+			match entry_point::simulate_validation(&*self.client, at, entry_point, &user_op) {
+				Ok(validation) if !validation.sig_failed => match validation.aggregator {
+					Some(aggregator) => aggregated.entry(aggregator).or_default().push(user_op),
+					None => user_ops.push(user_op),
+				},
+				_ => log::debug!(target: LOG_TARGET, "Dropping invalid user operation {:?}", hash),
+			}
+		}
+
+		let count = user_ops.len() + aggregated.values().map(Vec::len).sum::<usize>();
+		if count == 0 {
+			return Ok(());
+		}
+
+		let input = if aggregated.is_empty() {
+			entry_point::handle_ops(user_ops, self.address)
+		} else {
+			let mut ops_per_aggregator = aggregated
+				.into_iter()
+				.map(|(aggregator, user_ops)| {
+					entry_point::aggregate(&*self.client, at, entry_point, aggregator, user_ops)
+				})
+				.collect::<Result<Vec<_>, _>>()
+				.map_err(|e| e.to_string())?;
+			// User operations without aggregator are handled along.
+			if !user_ops.is_empty() {
+				ops_per_aggregator.push(AggregatedUserOperations {
+					user_ops,
+					aggregator: H160::zero(),
+					signature: Vec::new(),
+				});
+			}
+			entry_point::handle_aggregated_ops(ops_per_aggregator, self.address)
+		};
+
+		let (exit_reason, _, used_gas) = entry_point::call(
+			&*self.client,
+			at,
+			self.address,
+			entry_point,
+			input.clone(),
+			true,
+		)
+		.map_err(|e| e.to_string())?;
+		if !matches!(exit_reason, ExitReason::Succeed(_)) {
+			return Err(format!("Bundle simulation failed: {:?}", exit_reason));
+		}
+
+		let extrinsic = {
+			let api = self.client.runtime_api();
+			let chain_id = api
+				.chain_id(at)
+				.map_err(|e| format!("Runtime api access error: {:?}", e))?;
+			let account = api
+				.account_basic(at, self.address)
+				.map_err(|e| format!("Runtime api access error: {:?}", e))?;
+			// The previous bundle may still be in the transaction pool.
+			let nonce = account.nonce.max(self.next_nonce);
+
+			let transaction = self.sign(EIP1559TransactionMessage {
+				chain_id,
+				nonce,
+				max_priority_fee_per_gas: U256::zero(),
+				max_fee_per_gas: gas_price,
+				gas_limit: used_gas + used_gas / 10,
+				action: TransactionAction::Call(entry_point),
+				value: U256::zero(),
+				input,
+				access_list: Vec::new(),
+			})?;
+			self.next_nonce = nonce + 1;
+
+			api.convert_transaction(at, transaction)
+				.map_err(|e| format!("Runtime api access error: {:?}", e))?
+		};
+
+		self.transaction_pool
+			.submit_one(&BlockId::Hash(at), TransactionSource::Local, extrinsic)
+			.await
+			.map_err(|e| format!("Failed to submit the bundle: {}", e))?;
+
+		log::info!(
+			target: LOG_TARGET,
+			"Submitted a bundle of {} user operations to {:?}",
+			count,
+			entry_point
+		);
+
+		Ok(())
+	}
+
+	fn sign(&self, message: EIP1559TransactionMessage) -> Result<TransactionV2, String> {
+		let signing_message = Message::parse_slice(message.hash().as_bytes())
+			.map_err(|_| "Invalid signing message".to_string())?;
+		let (signature, recovery_id) = libsecp256k1::sign(&signing_message, &self.key);
+		let rs = signature.serialize();
+
+		Ok(TransactionV2::EIP1559(EIP1559Transaction {
+			chain_id: message.chain_id,
+			nonce: message.nonce,
+			max_priority_fee_per_gas: message.max_priority_fee_per_gas,
+			max_fee_per_gas: message.max_fee_per_gas,
+			gas_limit: message.gas_limit,
+			action: message.action,
+			value: message.value,
+			input: message.input,
+			access_list: message.access_list,
+			odd_y_parity: recovery_id.serialize() != 0,
+			r: H256::from_slice(&rs[0..32]),
+			s: H256::from_slice(&rs[32..64]),
+		}))
+	}
+}
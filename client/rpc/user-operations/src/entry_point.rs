@@ -0,0 +1,278 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! ABI of the ERC-4337 (v0.6) EntryPoint and aggregator contracts, and the simulations the
+//! bundler runs against them.
+
+use crate::UserOperation;
+use fc_rpc::internal_err;
+use fp_evm::ExitReason;
+use fp_rpc::EthereumRuntimeRPCApi;
+use jsonrpsee::core::Error as RpcError;
+use pallet_evm_precompile_signature_aggregator::UserOperation as SolidityUserOperation;
+use precompile_utils::prelude::*;
+use sp_api::ProvideRuntimeApi;
+use sp_core::{H160, U256};
+use sp_runtime::traits::Block as BlockT;
+
+/// Selector of `simulateValidation(UserOperation)`.
+const SELECTOR_SIMULATE_VALIDATION: u32 = 0xee219423;
+/// Selector of `handleOps(UserOperation[],address)`.
+const SELECTOR_HANDLE_OPS: u32 = 0x1fad948c;
+/// Selector of `handleAggregatedOps(UserOpsPerAggregator[],address)`.
+const SELECTOR_HANDLE_AGGREGATED_OPS: u32 = 0x4b1d7cf5;
+/// Selector of the `ValidationResult` error returned by `simulateValidation`.
+const SELECTOR_VALIDATION_RESULT: [u8; 4] = [0xe0, 0xcf, 0xf0, 0x5f];
+/// Selector of the `ValidationResultWithAggregation` error returned by `simulateValidation`.
+const SELECTOR_VALIDATION_RESULT_WITH_AGGREGATION: [u8; 4] = [0xfa, 0xec, 0xb4, 0xe4];
+/// Selector of the `FailedOp(uint256,string)` error.
+const SELECTOR_FAILED_OP: [u8; 4] = [0x22, 0x02, 0x66, 0xb6];
+/// Selector of the aggregator `aggregateSignatures(UserOperation[])`.
+const SELECTOR_AGGREGATE_SIGNATURES: u32 = 0x275e2d79;
+/// Selector of the aggregator `validateUserOpSignature(UserOperation)`.
+const SELECTOR_VALIDATE_USER_OP_SIGNATURE: u32 = 0x64c530cd;
+
+/// Gas limit of the simulations, below the block gas limit of the Moonbeam runtimes.
+pub const SIMULATION_GAS_LIMIT: u64 = 10_000_000;
+
+#[derive(solidity::Codec)]
+struct ReturnInfo {
+	pre_op_gas: U256,
+	prefund: U256,
+	sig_failed: bool,
+	valid_after: U256,
+	valid_until: U256,
+	paymaster_context: UnboundedBytes,
+}
+
+#[derive(solidity::Codec)]
+struct StakeInfo {
+	stake: U256,
+	unstake_delay_sec: U256,
+}
+
+#[derive(solidity::Codec)]
+struct AggregatorStakeInfo {
+	aggregator: Address,
+	stake_info: StakeInfo,
+}
+
+#[derive(solidity::Codec)]
+struct UserOpsPerAggregator {
+	user_ops: Vec<SolidityUserOperation>,
+	aggregator: Address,
+	signature: UnboundedBytes,
+}
+
+/// Outcome of `simulateValidation`.
+pub struct Validation {
+	/// Gas used by the validation, including the pre-verification gas.
+	pub pre_op_gas: U256,
+	/// Whether the account or paymaster reported an invalid signature.
+	pub sig_failed: bool,
+	/// Aggregator of the user operation signature, if any.
+	pub aggregator: Option<H160>,
+}
+
+/// User operations of a bundle sharing the same signature aggregator.
+pub struct AggregatedUserOperations {
+	pub user_ops: Vec<UserOperation>,
+	pub aggregator: H160,
+	pub signature: Vec<u8>,
+}
+
+/// Call data of `handleOps`.
+pub fn handle_ops(user_ops: Vec<UserOperation>, beneficiary: H160) -> Vec<u8> {
+	let user_ops: Vec<SolidityUserOperation> = user_ops.into_iter().map(Into::into).collect();
+	solidity::encode_with_selector(SELECTOR_HANDLE_OPS, (user_ops, Address(beneficiary)))
+}
+
+/// Call data of `handleAggregatedOps`.
+pub fn handle_aggregated_ops(
+	ops_per_aggregator: Vec<AggregatedUserOperations>,
+	beneficiary: H160,
+) -> Vec<u8> {
+	let ops_per_aggregator: Vec<UserOpsPerAggregator> = ops_per_aggregator
+		.into_iter()
+		.map(|ops| UserOpsPerAggregator {
+			user_ops: ops.user_ops.into_iter().map(Into::into).collect(),
+			aggregator: Address(ops.aggregator),
+			signature: ops.signature.into(),
+		})
+		.collect();
+	solidity::encode_with_selector(
+		SELECTOR_HANDLE_AGGREGATED_OPS,
+		(ops_per_aggregator, Address(beneficiary)),
+	)
+}
+
+/// Calls a contract without modifying the state, returning the exit reason and output.
+pub fn call<B, C>(
+	client: &C,
+	at: B::Hash,
+	from: H160,
+	to: H160,
+	data: Vec<u8>,
+	estimate: bool,
+) -> Result<(ExitReason, Vec<u8>, U256), RpcError>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B>,
+	C::Api: EthereumRuntimeRPCApi<B>,
+{
+	let info = client
+		.runtime_api()
+		.call(
+			at,
+			from,
+			to,
+			data,
+			U256::zero(),
+			U256::from(SIMULATION_GAS_LIMIT),
+			None,
+			None,
+			None,
+			estimate,
+			None,
+		)
+		.map_err(|e| internal_err(format!("Runtime api access error: {:?}", e)))?
+		.map_err(|e| internal_err(format!("Execution fatal: {:?}", e)))?;
+
+	Ok((info.exit_reason, info.value, info.used_gas.standard))
+}
+
+/// Runs `simulateValidation` for the user operation, failing if the EntryPoint rejects it.
+/// Invalid signatures are reported by the account without failing the validation.
+pub fn simulate_validation<B, C>(
+	client: &C,
+	at: B::Hash,
+	entry_point: H160,
+	user_op: &UserOperation,
+) -> Result<Validation, RpcError>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B>,
+	C::Api: EthereumRuntimeRPCApi<B>,
+{
+	let data = solidity::encode_with_selector(
+		SELECTOR_SIMULATE_VALIDATION,
+		SolidityUserOperation::from(user_op.clone()),
+	);
+	let (exit_reason, output, _) = call(client, at, H160::zero(), entry_point, data, false)?;
+
+	// `simulateValidation` always reverts, with the validation result as revert reason.
+	if !matches!(exit_reason, ExitReason::Revert(_)) || output.len() < 4 {
+		return Err(internal_err("Unexpected outcome of simulateValidation"));
+	}
+
+	let (selector, payload) = output.split_at(4);
+	let (return_info, aggregator) = match selector {
+		s if s == SELECTOR_VALIDATION_RESULT => {
+			let (return_info, _, _, _) =
+				solidity::decode_arguments::<(ReturnInfo, StakeInfo, StakeInfo, StakeInfo)>(
+					payload,
+				)
+				.map_err(|_| internal_err("Invalid ValidationResult"))?;
+			(return_info, None)
+		}
+		s if s == SELECTOR_VALIDATION_RESULT_WITH_AGGREGATION => {
+			let (return_info, _, _, _, aggregator_info) = solidity::decode_arguments::<(
+				ReturnInfo,
+				StakeInfo,
+				StakeInfo,
+				StakeInfo,
+				AggregatorStakeInfo,
+			)>(payload)
+			.map_err(|_| internal_err("Invalid ValidationResultWithAggregation"))?;
+			(return_info, Some(aggregator_info.aggregator.0))
+		}
+		s if s == SELECTOR_FAILED_OP => {
+			let reason = solidity::decode_arguments::<(U256, UnboundedString)>(payload)
+				.ok()
+				.and_then(|(_, reason)| String::try_from(reason).ok())
+				.unwrap_or_default();
+			return Err(internal_err(format!("User operation rejected: {}", reason)));
+		}
+		_ => return Err(internal_err("User operation rejected")),
+	};
+
+	Ok(Validation {
+		pre_op_gas: return_info.pre_op_gas,
+		sig_failed: return_info.sig_failed,
+		aggregator,
+	})
+}
+
+/// Aggregates the signatures of user operations sharing the aggregator, returning the user
+/// operations with the signature to submit along the aggregated signature.
+pub fn aggregate<B, C>(
+	client: &C,
+	at: B::Hash,
+	entry_point: H160,
+	aggregator: H160,
+	user_ops: Vec<UserOperation>,
+) -> Result<AggregatedUserOperations, RpcError>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B>,
+	C::Api: EthereumRuntimeRPCApi<B>,
+{
+	let solidity_ops: Vec<SolidityUserOperation> =
+		user_ops.iter().cloned().map(Into::into).collect();
+	let data = solidity::encode_with_selector(SELECTOR_AGGREGATE_SIGNATURES, (solidity_ops,));
+	let signature = call_returning_bytes(client, at, entry_point, aggregator, data)?;
+
+	let user_ops = user_ops
+		.into_iter()
+		.map(|mut user_op| {
+			let data = solidity::encode_with_selector(
+				SELECTOR_VALIDATE_USER_OP_SIGNATURE,
+				SolidityUserOperation::from(user_op.clone()),
+			);
+			user_op.signature =
+				call_returning_bytes(client, at, entry_point, aggregator, data)?.into();
+			Ok(user_op)
+		})
+		.collect::<Result<_, RpcError>>()?;
+
+	Ok(AggregatedUserOperations {
+		user_ops,
+		aggregator,
+		signature,
+	})
+}
+
+fn call_returning_bytes<B, C>(
+	client: &C,
+	at: B::Hash,
+	from: H160,
+	to: H160,
+	data: Vec<u8>,
+) -> Result<Vec<u8>, RpcError>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B>,
+	C::Api: EthereumRuntimeRPCApi<B>,
+{
+	match call(client, at, from, to, data, false)? {
+		(ExitReason::Succeed(_), output, _) => {
+			let (bytes,) = solidity::decode_return_value::<(UnboundedBytes,)>(&output)
+				.map_err(|_| internal_err("Invalid aggregator output"))?;
+			Ok(bytes.into())
+		}
+		_ => Err(internal_err("Aggregator call failed")),
+	}
+}
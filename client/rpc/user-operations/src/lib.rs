@@ -0,0 +1,253 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! An ERC-4337 bundler embedded in the node.
+//!
+//! User operations submitted through `eth_sendUserOperation` are validated against their
+//! EntryPoint (`simulateValidation`) and kept in a pool. On each new best block, the bundler
+//! submits the pooled user operations of each EntryPoint in a `handleOps` (or
+//! `handleAggregatedOps`) transaction signed by the bundler account, which is refunded by the
+//! EntryPoint.
+
+mod bundler;
+mod entry_point;
+mod pool;
+
+pub use bundler::Bundler;
+pub use pool::{PoolError, PooledUserOperation, UserOperationPool};
+
+use entry_point::simulate_validation;
+use fc_rpc::internal_err;
+use fp_evm::ExitReason;
+use fp_rpc::EthereumRuntimeRPCApi;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use pallet_evm_precompile_signature_aggregator::UserOperation as SolidityUserOperation;
+use precompile_utils::prelude::*;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H160, H256, U256};
+use sp_runtime::traits::Block as BlockT;
+use std::{marker::PhantomData, sync::Arc};
+
+/// Gas paid by a bundle transaction regardless of its content.
+const BUNDLE_OVERHEAD: u64 = 21_000;
+/// Gas used by the EntryPoint for each user operation of a bundle.
+const USER_OP_OVERHEAD: u64 = 18_300;
+/// Verification gas limit of the user operations simulated to estimate their gas.
+const ESTIMATION_VERIFICATION_GAS_LIMIT: u64 = 5_000_000;
+
+/// ERC-4337 (v0.6) user operation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOperation {
+	pub sender: H160,
+	pub nonce: U256,
+	pub init_code: Bytes,
+	pub call_data: Bytes,
+	pub call_gas_limit: U256,
+	pub verification_gas_limit: U256,
+	pub pre_verification_gas: U256,
+	pub max_fee_per_gas: U256,
+	pub max_priority_fee_per_gas: U256,
+	pub paymaster_and_data: Bytes,
+	pub signature: Bytes,
+}
+
+impl UserOperation {
+	/// Hash of the user operation, as computed by `EntryPoint.getUserOpHash`.
+	pub fn hash(&self, entry_point: H160, chain_id: u64) -> H256 {
+		SolidityUserOperation::from(self.clone()).hash(entry_point, chain_id)
+	}
+
+	/// Gas not accounted by the EntryPoint: the share of the bundle overhead and the call
+	/// data cost of the user operation.
+	pub fn pre_verification_gas(&self) -> U256 {
+		let encoded = solidity::encode_arguments(SolidityUserOperation::from(self.clone()));
+		let call_data_gas: u64 = encoded
+			.iter()
+			.map(|byte| if *byte == 0 { 4 } else { 16 })
+			.sum();
+
+		U256::from(BUNDLE_OVERHEAD + USER_OP_OVERHEAD + call_data_gas)
+	}
+}
+
+impl From<UserOperation> for SolidityUserOperation {
+	fn from(user_op: UserOperation) -> Self {
+		SolidityUserOperation {
+			sender: Address(user_op.sender),
+			nonce: user_op.nonce,
+			init_code: user_op.init_code.0.into(),
+			call_data: user_op.call_data.0.into(),
+			call_gas_limit: user_op.call_gas_limit,
+			verification_gas_limit: user_op.verification_gas_limit,
+			pre_verification_gas: user_op.pre_verification_gas,
+			max_fee_per_gas: user_op.max_fee_per_gas,
+			max_priority_fee_per_gas: user_op.max_priority_fee_per_gas,
+			paymaster_and_data: user_op.paymaster_and_data.0.into(),
+			signature: user_op.signature.0.into(),
+		}
+	}
+}
+
+/// Gas limits of a user operation.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOperationGasEstimation {
+	pub pre_verification_gas: U256,
+	pub verification_gas_limit: U256,
+	pub call_gas_limit: U256,
+}
+
+/// ERC-4337 bundler RPC endpoints
+#[rpc(server)]
+pub trait UserOperationsApi {
+	/// Validates a user operation and adds it to the pool of the bundler.
+	/// Returns the hash of the user operation.
+	#[method(name = "eth_sendUserOperation")]
+	fn send_user_operation(&self, user_op: UserOperation, entry_point: H160) -> RpcResult<H256>;
+
+	/// Estimates the gas limits of a user operation. Its signature is not checked, but the
+	/// account must report an invalid signature instead of reverting.
+	#[method(name = "eth_estimateUserOperationGas")]
+	fn estimate_user_operation_gas(
+		&self,
+		user_op: UserOperation,
+		entry_point: H160,
+	) -> RpcResult<UserOperationGasEstimation>;
+
+	/// Returns the EntryPoint contracts supported by the bundler.
+	#[method(name = "eth_supportedEntryPoints")]
+	fn supported_entry_points(&self) -> RpcResult<Vec<H160>>;
+}
+
+pub struct UserOperations<B: BlockT, C> {
+	client: Arc<C>,
+	pool: Arc<UserOperationPool>,
+	entry_points: Vec<H160>,
+	_phdata: PhantomData<B>,
+}
+
+impl<B: BlockT, C> UserOperations<B, C> {
+	pub fn new(client: Arc<C>, pool: Arc<UserOperationPool>, entry_points: Vec<H160>) -> Self {
+		Self {
+			client,
+			pool,
+			entry_points,
+			_phdata: Default::default(),
+		}
+	}
+
+	fn ensure_supported(&self, entry_point: H160) -> RpcResult<()> {
+		if !self.entry_points.contains(&entry_point) {
+			return Err(internal_err(format!(
+				"EntryPoint {:?} is not supported",
+				entry_point
+			)));
+		}
+		Ok(())
+	}
+}
+
+impl<B, C> UserOperationsApiServer for UserOperations<B, C>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + Send + Sync + 'static,
+	C::Api: EthereumRuntimeRPCApi<B>,
+{
+	fn send_user_operation(&self, user_op: UserOperation, entry_point: H160) -> RpcResult<H256> {
+		self.ensure_supported(entry_point)?;
+		let at = self.client.info().best_hash;
+
+		let validation = simulate_validation(&*self.client, at, entry_point, &user_op)?;
+		if validation.sig_failed {
+			return Err(internal_err("Invalid user operation signature"));
+		}
+
+		let chain_id = self
+			.client
+			.runtime_api()
+			.chain_id(at)
+			.map_err(|e| internal_err(format!("Runtime api access error: {:?}", e)))?;
+		let hash = user_op.hash(entry_point, chain_id);
+
+		self.pool
+			.insert(PooledUserOperation {
+				user_op,
+				entry_point,
+				hash,
+				aggregator: validation.aggregator,
+			})
+			.map_err(|e| match e {
+				PoolError::Full => internal_err("User operation pool is full"),
+				PoolError::ReplacementUnderpriced => {
+					internal_err("Replacement user operation underpriced")
+				}
+			})?;
+
+		Ok(hash)
+	}
+
+	fn estimate_user_operation_gas(
+		&self,
+		user_op: UserOperation,
+		entry_point: H160,
+	) -> RpcResult<UserOperationGasEstimation> {
+		self.ensure_supported(entry_point)?;
+		let at = self.client.info().best_hash;
+
+		let pre_verification_gas = user_op.pre_verification_gas();
+
+		// Fees are cleared for the simulation not to require a deposit.
+		let simulated = UserOperation {
+			pre_verification_gas,
+			verification_gas_limit: ESTIMATION_VERIFICATION_GAS_LIMIT.into(),
+			call_gas_limit: U256::zero(),
+			max_fee_per_gas: U256::zero(),
+			max_priority_fee_per_gas: U256::zero(),
+			..user_op.clone()
+		};
+		let validation = simulate_validation(&*self.client, at, entry_point, &simulated)?;
+		let verification_gas_limit = validation.pre_op_gas.saturating_sub(pre_verification_gas);
+
+		// The account is called by the EntryPoint.
+		let (exit_reason, _, call_gas_limit) = entry_point::call(
+			&*self.client,
+			at,
+			entry_point,
+			user_op.sender,
+			user_op.call_data.0,
+			true,
+		)?;
+		if !matches!(exit_reason, ExitReason::Succeed(_)) {
+			return Err(internal_err(format!(
+				"Execution of the call data failed: {:?}",
+				exit_reason
+			)));
+		}
+
+		Ok(UserOperationGasEstimation {
+			pre_verification_gas,
+			verification_gas_limit,
+			call_gas_limit,
+		})
+	}
+
+	fn supported_entry_points(&self) -> RpcResult<Vec<H160>> {
+		Ok(self.entry_points.clone())
+	}
+}
@@ -0,0 +1,135 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Pool of the validated user operations waiting to be bundled.
+
+use crate::UserOperation;
+use parking_lot::Mutex;
+use sp_core::{H160, H256, U256};
+use std::collections::BTreeMap;
+
+/// Maximum number of user operations in the pool.
+pub const MAX_POOL_SIZE: usize = 4096;
+/// Minimum fee increase, in percent, for a user operation to replace another one with the
+/// same sender and nonce.
+pub const REPLACEMENT_FEE_BUMP: u32 = 10;
+
+/// A validated user operation.
+#[derive(Clone, Debug)]
+pub struct PooledUserOperation {
+	pub user_op: UserOperation,
+	pub entry_point: H160,
+	pub hash: H256,
+	/// Aggregator of the user operation signature, if any.
+	pub aggregator: Option<H160>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PoolError {
+	/// The pool is full.
+	Full,
+	/// A user operation with the same sender and nonce has a higher or close fee.
+	ReplacementUnderpriced,
+}
+
+/// User operations indexed by hash, shared by the RPC and the bundler.
+#[derive(Default)]
+pub struct UserOperationPool {
+	user_ops: Mutex<BTreeMap<H256, PooledUserOperation>>,
+}
+
+impl UserOperationPool {
+	pub fn new() -> Self {
+		Default::default()
+	}
+
+	/// Adds a user operation, replacing the one with the same sender and nonce if it pays a
+	/// higher fee.
+	pub fn insert(&self, pooled: PooledUserOperation) -> Result<(), PoolError> {
+		let mut user_ops = self.user_ops.lock();
+
+		let replaced = user_ops
+			.values()
+			.find(|other| {
+				other.entry_point == pooled.entry_point
+					&& other.user_op.sender == pooled.user_op.sender
+					&& other.user_op.nonce == pooled.user_op.nonce
+			})
+			.map(|other| (other.hash, other.user_op.clone()));
+
+		match replaced {
+			Some((hash, other)) => {
+				let bumped = |fee: U256| fee + fee * REPLACEMENT_FEE_BUMP / 100;
+				if pooled.user_op.max_fee_per_gas < bumped(other.max_fee_per_gas)
+					|| pooled.user_op.max_priority_fee_per_gas
+						< bumped(other.max_priority_fee_per_gas)
+				{
+					return Err(PoolError::ReplacementUnderpriced);
+				}
+				user_ops.remove(&hash);
+			}
+			None if user_ops.len() >= MAX_POOL_SIZE => return Err(PoolError::Full),
+			None => (),
+		}
+
+		user_ops.insert(pooled.hash, pooled);
+		Ok(())
+	}
+
+	/// Removes and returns up to `max` user operations for the EntryPoint, by decreasing
+	/// priority fee. A bundle contains at most one user operation per sender.
+	pub fn take(&self, entry_point: H160, max: usize) -> Vec<PooledUserOperation> {
+		let mut user_ops = self.user_ops.lock();
+
+		// Only the lowest nonce of each sender can be included.
+		let mut lowest_nonces: BTreeMap<H160, &PooledUserOperation> = BTreeMap::new();
+		for pooled in user_ops.values().filter(|p| p.entry_point == entry_point) {
+			lowest_nonces
+				.entry(pooled.user_op.sender)
+				.and_modify(|lowest| {
+					if pooled.user_op.nonce < lowest.user_op.nonce {
+						*lowest = pooled;
+					}
+				})
+				.or_insert(pooled);
+		}
+
+		let mut candidates: Vec<&PooledUserOperation> = lowest_nonces.into_values().collect();
+		candidates.sort_by(|a, b| {
+			b.user_op
+				.max_priority_fee_per_gas
+				.cmp(&a.user_op.max_priority_fee_per_gas)
+		});
+		let selected: Vec<H256> = candidates
+			.into_iter()
+			.take(max)
+			.map(|pooled| pooled.hash)
+			.collect();
+
+		selected
+			.iter()
+			.filter_map(|hash| user_ops.remove(hash))
+			.collect()
+	}
+
+	pub fn len(&self) -> usize {
+		self.user_ops.lock().len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.user_ops.lock().is_empty()
+	}
+}
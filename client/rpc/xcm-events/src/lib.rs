@@ -0,0 +1,175 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC subscription streaming the XCM messages processed and sent in each new best block, as
+//! decoded by the `XcmEventsApi` runtime API.
+//!
+//! Locations are provided in the form used by the XCM precompiles, the ABI encoding of a
+//! `Multilocation { uint8 parents; bytes[] interior; }`, so they can be decoded by Ethereum
+//! tooling.
+
+use futures::{future, stream, FutureExt, StreamExt};
+use jsonrpsee::{proc_macros::rpc, types::SubscriptionResult, SubscriptionSink};
+use precompile_utils::solidity;
+use sc_client_api::client::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_core::{Bytes, H160, H256, U256};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto};
+use std::{marker::PhantomData, sync::Arc};
+use xcm_events_runtime_api::{XcmAssetTransfer, XcmEvent, XcmEventDirection, XcmEventsApi};
+
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+	Inbound,
+	Outbound,
+}
+
+/// Fungible asset transferred by a message.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XcmAsset {
+	/// ABI encoded location of the asset.
+	pub location: Bytes,
+	/// Address of the ERC-20 interface of the asset, if it has one.
+	pub address: Option<H160>,
+	pub amount: U256,
+}
+
+/// An XCM message processed or sent in a block.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XcmEventNotification {
+	pub block_hash: H256,
+	pub block_number: u64,
+	pub direction: Direction,
+	pub message_hash: Option<H256>,
+	/// ABI encoded origin of the message, when known.
+	pub origin: Option<Bytes>,
+	/// ABI encoded destination of the message, when known.
+	pub destination: Option<Bytes>,
+	pub assets: Vec<XcmAsset>,
+	pub success: bool,
+}
+
+impl XcmEventNotification {
+	fn new(block_hash: H256, block_number: u64, event: XcmEvent) -> Self {
+		let encode_location = |location| Bytes(solidity::encode_arguments(location));
+
+		Self {
+			block_hash,
+			block_number,
+			direction: match event.direction {
+				XcmEventDirection::Inbound => Direction::Inbound,
+				XcmEventDirection::Outbound => Direction::Outbound,
+			},
+			message_hash: event.message_hash,
+			origin: event.origin.map(encode_location),
+			destination: event.destination.map(encode_location),
+			assets: event
+				.assets
+				.into_iter()
+				.map(
+					|XcmAssetTransfer {
+					     location,
+					     erc20_address,
+					     amount,
+					 }| XcmAsset {
+						location: encode_location(location),
+						address: erc20_address,
+						amount: amount.into(),
+					},
+				)
+				.collect(),
+			success: event.success,
+		}
+	}
+}
+
+/// An RPC subscription to the XCM messages of Moonbeam
+#[rpc(server)]
+pub trait MoonbeamXcmEventsApi {
+	/// Streams the XCM messages processed or sent in each new best block.
+	#[subscription(
+		name = "moon_subscribeXcmEvents" => "moon_xcmEvent",
+		unsubscribe = "moon_unsubscribeXcmEvents",
+		item = XcmEventNotification
+	)]
+	fn subscribe_xcm_events(&self);
+}
+
+pub struct MoonbeamXcmEvents<B: BlockT, C> {
+	pub client: Arc<C>,
+	pub executor: SubscriptionTaskExecutor,
+	_phdata: PhantomData<B>,
+}
+
+impl<B: BlockT, C> MoonbeamXcmEvents<B, C> {
+	pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
+		Self {
+			client,
+			executor,
+			_phdata: Default::default(),
+		}
+	}
+}
+
+impl<B, C> MoonbeamXcmEventsApiServer for MoonbeamXcmEvents<B, C>
+where
+	B: BlockT<Hash = H256>,
+	C: ProvideRuntimeApi<B> + BlockchainEvents<B>,
+	C: Send + Sync + 'static,
+	C::Api: XcmEventsApi<B>,
+{
+	fn subscribe_xcm_events(&self, sink: SubscriptionSink) -> SubscriptionResult {
+		let client = self.client.clone();
+		let notifications =
+			self.client
+				.import_notification_stream()
+				.filter(|notification| future::ready(notification.is_new_best))
+				.map(move |notification| {
+					let block_hash = notification.hash;
+					let block_number: u64 = (*notification.header.number()).unique_saturated_into();
+					let events = client
+						.runtime_api()
+						.xcm_events(block_hash)
+						.unwrap_or_else(|e| {
+							log::warn!(
+								target: "xcm-events-rpc",
+								"Failed to decode the XCM events of block {:?} : {:?}",
+								block_hash,
+								e
+							);
+							vec![]
+						});
+
+					stream::iter(events.into_iter().map(move |event| {
+						XcmEventNotification::new(block_hash, block_number, event)
+					}))
+				})
+				.flatten();
+
+		let fut = async move {
+			sink.pipe_from_stream(notifications).await;
+		};
+		self.executor
+			.spawn("moonbeam-xcm-events-subscription", Some("rpc"), fut.boxed());
+
+		Ok(())
+	}
+}
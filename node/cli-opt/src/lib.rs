@@ -13,7 +13,8 @@
 
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
-use std::str::FromStr;
+use primitive_types::H160;
+use std::{path::PathBuf, str::FromStr};
 
 pub mod account_key;
 
@@ -69,6 +70,40 @@ impl FromStr for EthApi {
 	}
 }
 
+/// A `<method>=<value>` pair targeting a single RPC method, or a family of methods when the
+/// method name ends with `*` (e.g. `debug_*=5`).
+#[derive(Debug, PartialEq, Clone)]
+pub struct RpcMethodLimit {
+	pub method: String,
+	pub value: u32,
+}
+
+impl RpcMethodLimit {
+	/// Whether this limit applies to the given method name.
+	pub fn matches(&self, method: &str) -> bool {
+		match self.method.strip_suffix('*') {
+			Some(prefix) => method.starts_with(prefix),
+			None => method == self.method,
+		}
+	}
+}
+
+impl FromStr for RpcMethodLimit {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (method, value) = s
+			.split_once('=')
+			.ok_or_else(|| format!("`{}` is not a `<method>=<value>` pair", s))?;
+		let value = u32::from_str(value)
+			.map_err(|_| format!("`{}` is not a valid value for `{}`", value, method))?;
+		Ok(Self {
+			method: method.to_string(),
+			value,
+		})
+	}
+}
+
 /// Available frontier backend types.
 #[derive(Debug, Copy, Clone, Default, clap::ValueEnum)]
 pub enum FrontierBackendType {
@@ -79,6 +114,16 @@ pub enum FrontierBackendType {
 	Sql,
 }
 
+/// Available formats of the exported Ethereum traces.
+#[derive(Debug, Copy, Clone, Default, PartialEq, clap::ValueEnum)]
+pub enum TraceExportFormat {
+	/// One JSON object per line, as returned by `trace_filter`.
+	#[default]
+	Json,
+	/// Apache Parquet, one row per call frame.
+	Parquet,
+}
+
 /// Defines the frontier backend configuration.
 pub enum FrontierBackendConfig {
 	KeyValue,
@@ -96,16 +141,42 @@ impl Default for FrontierBackendConfig {
 	}
 }
 
+/// Configuration of the ERC-4337 bundler.
+#[derive(Debug, Clone)]
+pub struct BundlerConfig {
+	/// File holding the hex encoded private key of the account submitting the bundles.
+	pub key_file: PathBuf,
+	/// EntryPoint contracts the user operations can be submitted to.
+	pub entry_points: Vec<H160>,
+	/// Maximum number of user operations per bundle.
+	pub max_bundle_size: u32,
+}
+
 pub struct RpcConfig {
 	pub ethapi: Vec<EthApi>,
 	pub ethapi_max_permits: u32,
+	pub ethapi_max_block_permits: Option<u32>,
+	pub ethapi_max_filter_permits: Option<u32>,
 	pub ethapi_trace_max_count: u32,
 	pub ethapi_trace_cache_duration: u64,
+	pub ethapi_trace_cache_memory: usize,
+	pub ethapi_trace_cache_disk_path: Option<PathBuf>,
+	pub ethapi_trace_cache_disk_budget: u64,
+	pub ethapi_tracing_runtimes_dir: Option<PathBuf>,
+	pub wasm_runtime_overrides: Option<PathBuf>,
 	pub eth_log_block_cache: usize,
 	pub eth_statuses_cache: usize,
 	pub fee_history_limit: u64,
 	pub max_past_logs: u32,
+	pub eth_log_max_block_range: u32,
+	pub txpool_price_bump: u32,
+	pub txpool_max_queued_per_account: u32,
+	pub rpc_method_rate_limits: Vec<RpcMethodLimit>,
+	pub rpc_method_costs: Vec<RpcMethodLimit>,
+	pub rpc_heavy_budget: u32,
 	pub relay_chain_rpc_urls: Vec<url::Url>,
 	pub tracing_raw_max_memory_usage: usize,
 	pub frontier_backend_config: FrontierBackendConfig,
+	pub frontier_retained_blocks: Option<u32>,
+	pub bundler: Option<BundlerConfig>,
 }
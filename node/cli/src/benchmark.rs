@@ -0,0 +1,192 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarking presets, running the curated list of pallet benchmarks of a runtime and writing
+//! the resulting weight files directly into the runtime weights directory.
+
+use clap::Parser;
+use frame_benchmarking_cli::PalletCmd;
+use std::path::PathBuf;
+
+/// Pallets benchmarked for all the runtimes, as listed in their `define_benchmarks!`.
+const COMMON_PALLETS: &[&str] = &[
+	"pallet_utility",
+	"pallet_timestamp",
+	"pallet_balances",
+	"pallet_evm",
+	"pallet_assets",
+	"pallet_collective",
+	"pallet_parachain_staking",
+	"pallet_scheduler",
+	"pallet_democracy",
+	"pallet_treasury",
+	"pallet_author_inherent",
+	"pallet_author_slot_filter",
+	"pallet_crowdloan_rewards",
+	"pallet_author_mapping",
+	"pallet_proxy",
+	"pallet_identity",
+	"cumulus_pallet_xcmp_queue",
+	"pallet_xcm",
+	"pallet_asset_manager",
+	"pallet_xc20_issuance",
+	"pallet_xcm_filter",
+	"pallet_evm_hibernation",
+	"pallet_erc20_xcm_bridge",
+	"pallet_xcm_transactor",
+	"pallet_moonbeam_orbiters",
+	"pallet_author_filter_schedule",
+	"pallet_fee_split",
+	"pallet_precompile_benchmarks",
+	"pallet_randomness",
+	"pallet_conviction_voting",
+	"pallet_referenda",
+	"pallet_preimage",
+	"pallet_whitelist",
+	"pallet_multisig",
+	"moonbeam_xcm_benchmarks::weights::generic",
+	"moonbeam_xcm_benchmarks::weights::erc20",
+];
+
+/// Pallets only benchmarked for moonbase.
+const MOONBASE_PALLETS: &[&str] = &[
+	"pallet_sudo",
+	"pallet_evm_deployer_filter",
+	"pallet_evm_contract_metadata",
+	"pallet_evm_operators",
+	"pallet_evm_sponsorship",
+];
+
+/// Runtimes which can be benchmarked.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum BenchmarkRuntime {
+	#[default]
+	Moonbase,
+	Moonriver,
+	Moonbeam,
+}
+
+impl BenchmarkRuntime {
+	/// Development chain spec the benchmarks are run against.
+	pub fn dev_chain(&self) -> &'static str {
+		match self {
+			BenchmarkRuntime::Moonbase => "moonbase-dev",
+			BenchmarkRuntime::Moonriver => "moonriver-dev",
+			BenchmarkRuntime::Moonbeam => "moonbeam-dev",
+		}
+	}
+
+	/// Curated list of the pallets benchmarked for this runtime.
+	pub fn pallets(&self) -> Vec<&'static str> {
+		let mut pallets = COMMON_PALLETS.to_vec();
+		if *self == BenchmarkRuntime::Moonbase {
+			pallets.extend_from_slice(MOONBASE_PALLETS);
+		}
+		pallets
+	}
+}
+
+/// Benchmark all the pallets of a runtime, writing their weight files into the runtime weights
+/// directory.
+#[derive(Debug, Parser)]
+pub struct MoonbeamAllBenchmarkCmd {
+	/// Runtime to benchmark.
+	#[clap(long, value_enum, default_value_t)]
+	pub runtime: BenchmarkRuntime,
+
+	/// Only benchmark the given pallets (comma separated), instead of all the pallets of the
+	/// runtime.
+	#[clap(long, value_delimiter = ',')]
+	pub pallets: Vec<String>,
+
+	/// Select how many samples we should take across the variable components.
+	#[clap(long, default_value_t = 50)]
+	pub steps: u32,
+
+	/// Select how many repetitions of this benchmark should run from within the wasm.
+	#[clap(long, default_value_t = 20)]
+	pub repeat: u32,
+
+	/// License header prepended to the weight files.
+	#[clap(long, default_value = "./file_header.txt")]
+	pub header: PathBuf,
+
+	/// Directory the weight files are written into.
+	#[clap(long, default_value = "./runtime/common/src/weights")]
+	pub output_dir: PathBuf,
+
+	/// Only check that the benchmarks run, with a reduced number of steps and repetitions.
+	/// No weight file is written.
+	#[clap(long)]
+	pub check: bool,
+}
+
+impl MoonbeamAllBenchmarkCmd {
+	/// Pallets to benchmark, with the `benchmark pallet` command of each of them.
+	pub fn pallet_cmds(&self) -> Result<Vec<(String, PalletCmd)>, String> {
+		let pallets = if self.pallets.is_empty() {
+			self.runtime
+				.pallets()
+				.into_iter()
+				.map(ToString::to_string)
+				.collect()
+		} else {
+			self.pallets.clone()
+		};
+
+		pallets
+			.into_iter()
+			.map(|pallet| {
+				let pallet_cmd = PalletCmd::try_parse_from(self.pallet_args(&pallet))
+					.map_err(|e| format!("Invalid benchmark parameters for {}: {}", pallet, e))?;
+				Ok((pallet, pallet_cmd))
+			})
+			.collect()
+	}
+
+	/// Arguments of the `benchmark pallet` command of a pallet.
+	fn pallet_args(&self, pallet: &str) -> Vec<String> {
+		let (steps, repeat) = if self.check {
+			(2, 1)
+		} else {
+			(self.steps, self.repeat)
+		};
+
+		let mut args: Vec<String> = vec![
+			"pallet".into(),
+			format!("--chain={}", self.runtime.dev_chain()),
+			format!("--pallet={}", pallet),
+			"--extrinsic=*".into(),
+			format!("--steps={}", steps),
+			format!("--repeat={}", repeat),
+			"--wasm-execution=compiled".into(),
+		];
+		if !self.check {
+			args.push(format!("--header={}", self.header.display()));
+			args.push(format!(
+				"--output={}",
+				self.output_dir.join(weight_file_name(pallet)).display()
+			));
+		}
+
+		args
+	}
+}
+
+/// Name of the weight file of a pallet, e.g. `pallet_foo::bar` is written into `pallet_foo_bar.rs`.
+pub fn weight_file_name(pallet: &str) -> String {
+	format!("{}.rs", pallet.replace("::", "_"))
+}
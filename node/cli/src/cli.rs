@@ -19,10 +19,15 @@
 //! This module defines the Moonbeam node's Command Line Interface (CLI)
 //! It is built using clap and inherits behavior from Substrate's sc_cli crate.
 
+use crate::{benchmark::MoonbeamAllBenchmarkCmd, nimbus_key::RotateNimbusKeyCmd};
 use clap::Parser;
-use moonbeam_cli_opt::{account_key::GenerateAccountKey, EthApi, FrontierBackendType, Sealing};
+use moonbeam_cli_opt::{
+	account_key::GenerateAccountKey, EthApi, FrontierBackendType, RpcMethodLimit, Sealing,
+	TraceExportFormat,
+};
 use moonbeam_service::chain_spec;
 use sc_cli::{Error as CliError, SubstrateCli};
+use sp_core::H160;
 use std::path::PathBuf;
 
 /// Sub-commands supported by the collator.
@@ -39,8 +44,9 @@ pub enum Subcommand {
 	/// Build a chain specification.
 	BuildSpec(BuildSpecCommand),
 
-	/// Validate blocks.
-	CheckBlock(sc_cli::CheckBlockCmd),
+	/// Re-execute blocks on top of the state of their parent and compare the results with the
+	/// imported blocks.
+	CheckBlock(CheckBlockCmd),
 
 	/// Export blocks.
 	ExportBlocks(sc_cli::ExportBlocksCmd),
@@ -52,15 +58,27 @@ pub enum Subcommand {
 	ImportBlocks(sc_cli::ImportBlocksCmd),
 
 	/// Remove the whole chain.
-	PurgeChain(cumulus_client_cli::PurgeChainCmd),
+	PurgeChain(PurgeChainCmd),
 
 	/// Revert the chain to a previous state.
 	Revert(sc_cli::RevertCmd),
 
+	/// Rebuild the frontier mapping database for a range of blocks.
+	#[clap(name = "frontier-backfill")]
+	FrontierBackfill(FrontierBackfillCmd),
+
+	/// Export the Ethereum traces of a range of blocks into a file.
+	#[clap(name = "export-traces")]
+	ExportTraces(ExportTracesCmd),
+
+	/// Export or import a snapshot of the parachain and frontier databases.
+	#[clap(subcommand)]
+	Snapshot(SnapshotCmd),
+
 	/// Sub-commands concerned with benchmarking.
 	/// The pallet benchmarking moved to the `pallet` sub-command.
 	#[clap(subcommand)]
-	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
+	Benchmark(BenchmarkSubcommand),
 
 	/// Try some command against runtime state.
 	#[cfg(feature = "try-runtime")]
@@ -75,6 +93,18 @@ pub enum Subcommand {
 	Key(KeyCmd),
 }
 
+/// Benchmarking sub-commands: the Substrate ones and the Moonbeam presets.
+#[derive(Debug, clap::Subcommand)]
+pub enum BenchmarkSubcommand {
+	#[clap(flatten)]
+	Substrate(frame_benchmarking_cli::BenchmarkCmd),
+
+	/// Benchmark all the pallets of a runtime, writing their weight files into the runtime
+	/// weights directory.
+	#[clap(name = "moonbeam-all")]
+	MoonbeamAll(MoonbeamAllBenchmarkCmd),
+}
+
 #[derive(Debug, Parser)]
 pub struct BuildSpecCommand {
 	#[clap(flatten)]
@@ -111,6 +141,198 @@ pub struct ExportGenesisStateCommand {
 	pub chain: Option<String>,
 }
 
+/// Command for removing the chain databases.
+/// Without options, the parachain, relay chain and frontier databases are all removed.
+#[derive(Debug, Parser)]
+pub struct PurgeChainCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub base: cumulus_client_cli::PurgeChainCmd,
+
+	/// Only delete the frontier databases, which are rebuilt from the parachain database.
+	#[clap(long, conflicts_with_all = ["parachain", "relaychain", "keep_frontier"])]
+	pub frontier: bool,
+
+	/// Keep the frontier databases when deleting the parachain database.
+	#[clap(long)]
+	pub keep_frontier: bool,
+}
+
+impl sc_cli::CliConfiguration for PurgeChainCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		self.base.shared_params()
+	}
+
+	fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+		self.base.database_params()
+	}
+}
+
+/// Command for checking the consistency of imported blocks.
+/// Each block is re-executed on top of the state of its parent, and its state root, extrinsics
+/// root and Ethereum receipts are compared with the imported ones.
+#[derive(Debug, Parser)]
+pub struct CheckBlockCmd {
+	/// First block to check.
+	#[clap(value_parser)]
+	pub from: u32,
+
+	/// Last block to check. Defaults to the first one.
+	#[clap(long)]
+	pub to: Option<u32>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: sc_cli::ImportParams,
+}
+
+impl sc_cli::CliConfiguration for CheckBlockCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&sc_cli::ImportParams> {
+		Some(&self.import_params)
+	}
+}
+
+/// Command for exporting the Ethereum traces of a range of blocks.
+/// The blocks are replayed with the tracing runtimes given by `--wasm-runtime-overrides`.
+#[derive(Debug, Parser)]
+pub struct ExportTracesCmd {
+	/// First block to trace.
+	#[clap(long)]
+	pub from: u32,
+
+	/// Last block to trace. Defaults to the best block.
+	#[clap(long)]
+	pub to: Option<u32>,
+
+	/// Format of the exported traces.
+	#[clap(long, value_enum, ignore_case = true, default_value_t = TraceExportFormat::default())]
+	pub format: TraceExportFormat,
+
+	/// Output file.
+	#[clap(long)]
+	pub output: PathBuf,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: sc_cli::ImportParams,
+}
+
+impl sc_cli::CliConfiguration for ExportTracesCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&sc_cli::ImportParams> {
+		Some(&self.import_params)
+	}
+}
+
+/// Snapshot sub-commands.
+#[derive(Debug, clap::Subcommand)]
+pub enum SnapshotCmd {
+	/// Archive the parachain and frontier databases of the stopped node.
+	Export(ExportSnapshotCmd),
+
+	/// Restore the parachain and frontier databases from an archive.
+	Import(ImportSnapshotCmd),
+}
+
+/// Command for exporting a snapshot of the databases.
+#[derive(Debug, Parser)]
+pub struct ExportSnapshotCmd {
+	/// Output file.
+	#[clap(long)]
+	pub output: PathBuf,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: sc_cli::DatabaseParams,
+}
+
+impl sc_cli::CliConfiguration for ExportSnapshotCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+/// Command for importing a snapshot of the databases.
+/// The databases of the node must have been purged beforehand.
+#[derive(Debug, Parser)]
+pub struct ImportSnapshotCmd {
+	/// Snapshot archive.
+	#[clap(long)]
+	pub input: PathBuf,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: sc_cli::DatabaseParams,
+}
+
+impl sc_cli::CliConfiguration for ImportSnapshotCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+/// Command for rebuilding the frontier mapping database.
+/// The frontier backend is selected with the `--frontier-backend-type` node option.
+#[derive(Debug, Parser)]
+pub struct FrontierBackfillCmd {
+	/// First block to index.
+	#[clap(long)]
+	pub from: u32,
+
+	/// Last block to index. Defaults to the best block.
+	#[clap(long)]
+	pub to: Option<u32>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: sc_cli::DatabaseParams,
+}
+
+impl sc_cli::CliConfiguration for FrontierBackfillCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
 /// Command for exporting the genesis wasm file.
 #[derive(Debug, Parser)]
 pub struct ExportGenesisWasmCommand {
@@ -156,6 +378,16 @@ pub struct RunCmd {
 	#[clap(long, default_value = "10")]
 	pub ethapi_max_permits: u32,
 
+	/// Maximum number of tracing tasks `debug_traceBlockByNumber` and `debug_traceBlockByHash`
+	/// requests can run concurrently, out of `--ethapi-max-permits`. Defaults to all of them.
+	#[clap(long)]
+	pub ethapi_max_block_permits: Option<u32>,
+
+	/// Maximum number of tracing tasks `trace_filter` requests can run concurrently, out of
+	/// `--ethapi-max-permits`. Defaults to half of them, keeping the others for `debug_*` requests.
+	#[clap(long)]
+	pub ethapi_max_filter_permits: Option<u32>,
+
 	/// Maximum number of trace entries a single request of `trace_filter` is allowed to return.
 	/// A request asking for more or an unbounded one going over this limit will both return an
 	/// error.
@@ -167,6 +399,27 @@ pub struct RunCmd {
 	#[clap(long, default_value = "300")]
 	pub ethapi_trace_cache_duration: u64,
 
+	/// Size in bytes of the memory kept for `trace_filter` block traces once their cache
+	/// duration expired. Set to 0 to disable.
+	#[clap(long, default_value = "100000000")]
+	pub ethapi_trace_cache_memory: usize,
+
+	/// Directory in which `trace_filter` block traces are persisted, allowing them to be reused
+	/// after being evicted from memory or after a restart. Disabled if not provided.
+	#[clap(long)]
+	pub ethapi_trace_cache_disk_path: Option<PathBuf>,
+
+	/// Size in bytes of the `trace_filter` block traces persisted on disk.
+	#[clap(long, default_value = "1000000000")]
+	pub ethapi_trace_cache_disk_budget: u64,
+
+	/// Directory containing the tracing runtimes, named
+	/// `<spec_name>-runtime-<spec_version>-substitute-tracing.wasm` or `<spec_version>.wasm`.
+	/// When a block is traced with a runtime missing from `--wasm-runtime-overrides`, it is
+	/// copied there from this directory, and used after the next restart.
+	#[clap(long)]
+	pub ethapi_tracing_runtimes_dir: Option<PathBuf>,
+
 	/// Size in bytes of the LRU cache for block data.
 	#[clap(long, default_value = "300000000")]
 	pub eth_log_block_cache: usize,
@@ -196,8 +449,14 @@ pub struct RunCmd {
 	#[arg(long, default_value = "209715200")]
 	pub frontier_sql_backend_cache_size: u64,
 
+	/// Number of recent blocks whose logs and transactions are kept in the frontier SQL backend.
+	/// Older ones are pruned in the background. Keeps everything when not set.
+	#[arg(long)]
+	pub frontier_retained_blocks: Option<u32>,
+
 	/// Size in bytes of data a raw tracing request is allowed to use.
-	/// Bound the size of memory, stack and storage data.
+	/// Bound the size of memory, stack and storage data, and of the call traces cached for
+	/// `debug_traceTransaction`.
 	#[clap(long, default_value = "20000000")]
 	pub tracing_raw_max_memory_usage: usize,
 
@@ -205,6 +464,49 @@ pub struct RunCmd {
 	#[clap(long, default_value = "10000")]
 	pub max_past_logs: u32,
 
+	/// Maximum number of blocks a logs query can cover. Set to 0 for no limit.
+	#[clap(long, default_value = "0")]
+	pub eth_log_max_block_range: u32,
+
+	/// Minimum fee increase, in percent, for an Ethereum transaction to replace a pooled
+	/// transaction of the same sender and nonce.
+	#[clap(long, default_value = "10")]
+	pub txpool_price_bump: u32,
+
+	/// Maximum number of Ethereum transactions of an account queued behind a nonce gap in the
+	/// transaction pool. Set to 0 for no limit.
+	#[clap(long, default_value = "64")]
+	pub txpool_max_queued_per_account: u32,
+
+	/// Maximum number of calls per second accepted for an RPC method, as `<method>=<limit>`.
+	/// A trailing `*` applies the limit to every method sharing the prefix (e.g. `debug_*=5`).
+	#[clap(long, value_delimiter = ',')]
+	pub rpc_method_rate_limit: Vec<RpcMethodLimit>,
+
+	/// Execution cost of an RPC method, as `<method>=<cost>`, overriding the built-in costs of
+	/// `trace_*`, `debug_*` and `eth_getLogs` charged against `--rpc-heavy-budget`.
+	#[clap(long, value_delimiter = ',')]
+	pub rpc_method_cost: Vec<RpcMethodLimit>,
+
+	/// Cost units per second shared by all the costly RPC methods. Set to 0 for no limit.
+	/// Up to the cost of the costliest method can be spent at once, even if it exceeds the budget.
+	#[clap(long, default_value = "0")]
+	pub rpc_heavy_budget: u32,
+
+	/// File holding the hex encoded private key of the account submitting bundles of ERC-4337
+	/// user operations.
+	/// Enables the `eth_sendUserOperation` and `eth_estimateUserOperationGas` RPC methods.
+	#[clap(long, requires = "bundler_entry_point")]
+	pub bundler_key_file: Option<PathBuf>,
+
+	/// EntryPoint contracts the bundler accepts user operations for.
+	#[clap(long, value_delimiter = ',')]
+	pub bundler_entry_point: Vec<H160>,
+
+	/// Maximum number of user operations per bundle.
+	#[clap(long, default_value = "16")]
+	pub bundler_max_bundle_size: u32,
+
 	/// Force using Moonbase native runtime.
 	#[clap(long = "force-moonbase")]
 	pub force_moonbase: bool,
@@ -237,14 +539,28 @@ impl RunCmd {
 		moonbeam_cli_opt::RpcConfig {
 			ethapi: self.ethapi.clone(),
 			ethapi_max_permits: self.ethapi_max_permits,
+			ethapi_max_block_permits: self.ethapi_max_block_permits,
+			ethapi_max_filter_permits: self.ethapi_max_filter_permits,
 			ethapi_trace_max_count: self.ethapi_trace_max_count,
 			ethapi_trace_cache_duration: self.ethapi_trace_cache_duration,
+			ethapi_trace_cache_memory: self.ethapi_trace_cache_memory,
+			ethapi_trace_cache_disk_path: self.ethapi_trace_cache_disk_path.clone(),
+			ethapi_trace_cache_disk_budget: self.ethapi_trace_cache_disk_budget,
+			ethapi_tracing_runtimes_dir: self.ethapi_tracing_runtimes_dir.clone(),
+			wasm_runtime_overrides: self.base.base.import_params.wasm_runtime_overrides.clone(),
 			eth_log_block_cache: self.eth_log_block_cache,
 			eth_statuses_cache: self.eth_statuses_cache,
 			fee_history_limit: self.fee_history_limit,
 			max_past_logs: self.max_past_logs,
+			eth_log_max_block_range: self.eth_log_max_block_range,
+			txpool_price_bump: self.txpool_price_bump,
+			txpool_max_queued_per_account: self.txpool_max_queued_per_account,
+			rpc_method_rate_limits: self.rpc_method_rate_limit.clone(),
+			rpc_method_costs: self.rpc_method_cost.clone(),
+			rpc_heavy_budget: self.rpc_heavy_budget,
 			relay_chain_rpc_urls: self.base.relay_chain_rpc_urls.clone(),
 			tracing_raw_max_memory_usage: self.tracing_raw_max_memory_usage,
+			frontier_retained_blocks: self.frontier_retained_blocks,
 			frontier_backend_config: match self.frontier_backend_type {
 				FrontierBackendType::KeyValue => moonbeam_cli_opt::FrontierBackendConfig::KeyValue,
 				FrontierBackendType::Sql => moonbeam_cli_opt::FrontierBackendConfig::Sql {
@@ -254,6 +570,13 @@ impl RunCmd {
 					cache_size: self.frontier_sql_backend_cache_size,
 				},
 			},
+			bundler: self.bundler_key_file.clone().map(|key_file| {
+				moonbeam_cli_opt::BundlerConfig {
+					key_file,
+					entry_points: self.bundler_entry_point.clone(),
+					max_bundle_size: self.bundler_max_bundle_size,
+				}
+			}),
 		}
 	}
 }
@@ -272,6 +595,10 @@ pub enum KeyCmd {
 	BaseCli(sc_cli::KeySubcommand),
 	/// Generate an Ethereum account.
 	GenerateAccountKey(GenerateAccountKey),
+	/// Generate new nimbus and VRF keys in the keystore, optionally registering them in the
+	/// author mapping.
+	#[clap(name = "rotate-nimbus")]
+	RotateNimbus(RotateNimbusKeyCmd),
 }
 
 impl KeyCmd {
@@ -283,6 +610,7 @@ impl KeyCmd {
 				cmd.run();
 				Ok(())
 			}
+			KeyCmd::RotateNimbus(cmd) => cmd.run(cli),
 		}
 	}
 }
@@ -16,7 +16,10 @@
 
 //! This module constructs and executes the appropriate service components for the given subcommand
 
-use crate::cli::{Cli, RelayChainCli, RunCmd, Subcommand};
+use crate::{
+	benchmark::BenchmarkRuntime,
+	cli::{BenchmarkSubcommand, Cli, RelayChainCli, RunCmd, SnapshotCmd, Subcommand},
+};
 use cumulus_client_cli::{extract_genesis_wasm, generate_genesis_block};
 use cumulus_primitives_core::ParaId;
 use frame_benchmarking_cli::BenchmarkCmd;
@@ -218,6 +221,32 @@ fn validate_trace_environment(cli: &Cli) -> Result<()> {
 	Ok(())
 }
 
+/// Removes the frontier databases of the node, whichever backend they were created with.
+fn purge_frontier_databases(
+	cmd: &sc_cli::PurgeChainCmd,
+	config: &sc_service::Configuration,
+) -> Result<()> {
+	let mut paths = match config.database {
+		DatabaseSource::RocksDb { .. } => vec![frontier_database_dir(config, "db")],
+		DatabaseSource::ParityDb { .. } => vec![frontier_database_dir(config, "paritydb")],
+		DatabaseSource::Auto { .. } => vec![
+			frontier_database_dir(config, "db"),
+			frontier_database_dir(config, "paritydb"),
+		],
+		_ => return Err(format!("Cannot purge `{:?}` database", config.database).into()),
+	};
+	paths.push(frontier_database_dir(config, "sql"));
+
+	for path in paths.into_iter().filter(|path| path.exists()) {
+		// Only the path of the database source is used to remove it.
+		cmd.run(DatabaseSource::RocksDb {
+			path,
+			cache_size: 0,
+		})?;
+	}
+	Ok(())
+}
+
 /// Parse command line arguments into service configuration.
 pub fn run() -> Result<()> {
 	let mut cli = Cli::from_args();
@@ -270,12 +299,69 @@ pub fn run() -> Result<()> {
 		}
 		Some(Subcommand::CheckBlock(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
+			let chain_spec = &runner.config().chain_spec;
 			let rpc_config = cli.run.new_rpc_config();
-			runner.async_run(|mut config| {
-				let (client, _, import_queue, task_manager) =
-					moonbeam_service::new_chain_ops(&mut config, &rpc_config)?;
-				Ok((cmd.run(client, import_queue), task_manager))
-			})
+			match chain_spec {
+				#[cfg(feature = "moonriver-native")]
+				spec if spec.is_moonriver() => runner.async_run(|mut config| {
+					let params = moonbeam_service::new_partial::<
+						moonbeam_service::moonriver_runtime::RuntimeApi,
+						moonbeam_service::MoonriverExecutor,
+					>(&mut config, &rpc_config, false)?;
+
+					Ok((
+						async move {
+							moonbeam_service::block_check::check_blocks(
+								params.client,
+								cmd.from,
+								cmd.to,
+							)
+							.map_err(Into::into)
+						},
+						params.task_manager,
+					))
+				}),
+				#[cfg(feature = "moonbeam-native")]
+				spec if spec.is_moonbeam() => runner.async_run(|mut config| {
+					let params = moonbeam_service::new_partial::<
+						moonbeam_service::moonbeam_runtime::RuntimeApi,
+						moonbeam_service::MoonbeamExecutor,
+					>(&mut config, &rpc_config, false)?;
+
+					Ok((
+						async move {
+							moonbeam_service::block_check::check_blocks(
+								params.client,
+								cmd.from,
+								cmd.to,
+							)
+							.map_err(Into::into)
+						},
+						params.task_manager,
+					))
+				}),
+				#[cfg(feature = "moonbase-native")]
+				_ => runner.async_run(|mut config| {
+					let params = moonbeam_service::new_partial::<
+						moonbeam_service::moonbase_runtime::RuntimeApi,
+						moonbeam_service::MoonbaseExecutor,
+					>(&mut config, &rpc_config, false)?;
+
+					Ok((
+						async move {
+							moonbeam_service::block_check::check_blocks(
+								params.client,
+								cmd.from,
+								cmd.to,
+							)
+							.map_err(Into::into)
+						},
+						params.task_manager,
+					))
+				}),
+				#[cfg(not(feature = "moonbase-native"))]
+				_ => panic!("invalid chain spec"),
+			}
 		}
 		Some(Subcommand::ExportBlocks(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
@@ -313,24 +399,18 @@ pub fn run() -> Result<()> {
 				let relay_chain_id = extension.map(|e| e.relay_chain.as_str());
 				let dev_service = cli.run.dev_service || relay_chain_id == Some("dev-service");
 
-				// Remove Frontier offchain db
-				let frontier_database_config = match config.database {
-					DatabaseSource::RocksDb { .. } => DatabaseSource::RocksDb {
-						path: frontier_database_dir(&config, "db"),
-						cache_size: 0,
-					},
-					DatabaseSource::ParityDb { .. } => DatabaseSource::ParityDb {
-						path: frontier_database_dir(&config, "paritydb"),
-					},
-					_ => {
-						return Err(format!("Cannot purge `{:?}` database", config.database).into())
-					}
-				};
-				cmd.base.run(frontier_database_config)?;
+				// The frontier databases are derived from the parachain one, they are removed with
+				// it unless asked otherwise.
+				if cmd.frontier || (!cmd.base.relaychain && !cmd.keep_frontier) {
+					purge_frontier_databases(&cmd.base.base, &config)?;
+				}
+				if cmd.frontier {
+					return Ok(());
+				}
 
 				if dev_service {
 					// base refers to the encapsulated "regular" sc_cli::PurgeChain command
-					return cmd.base.run(config.database);
+					return cmd.base.base.run(config.database);
 				}
 
 				let polkadot_cli = RelayChainCli::new(
@@ -347,7 +427,7 @@ pub fn run() -> Result<()> {
 				)
 				.map_err(|err| format!("Relay chain argument error: {}", err))?;
 
-				cmd.run(config, polkadot_config)
+				cmd.base.run(config, polkadot_config)
 			})
 		}
 		Some(Subcommand::Revert(cmd)) => {
@@ -395,6 +475,180 @@ pub fn run() -> Result<()> {
 				_ => panic!("invalid chain spec"),
 			}
 		}
+		Some(Subcommand::FrontierBackfill(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			let chain_spec = &runner.config().chain_spec;
+			let rpc_config = cli.run.new_rpc_config();
+			match chain_spec {
+				#[cfg(feature = "moonriver-native")]
+				spec if spec.is_moonriver() => runner.async_run(|mut config| {
+					let params = moonbeam_service::new_partial::<
+						moonbeam_service::moonriver_runtime::RuntimeApi,
+						moonbeam_service::MoonriverExecutor,
+					>(&mut config, &rpc_config, false)?;
+					let (_, _, _, _, frontier_backend, _) = params.other;
+
+					Ok((
+						async move {
+							moonbeam_service::frontier_backfill::frontier_backfill(
+								params.client,
+								frontier_backend,
+								cmd.from,
+								cmd.to,
+							)
+							.await
+							.map_err(Into::into)
+						},
+						params.task_manager,
+					))
+				}),
+				#[cfg(feature = "moonbeam-native")]
+				spec if spec.is_moonbeam() => runner.async_run(|mut config| {
+					let params = moonbeam_service::new_partial::<
+						moonbeam_service::moonbeam_runtime::RuntimeApi,
+						moonbeam_service::MoonbeamExecutor,
+					>(&mut config, &rpc_config, false)?;
+					let (_, _, _, _, frontier_backend, _) = params.other;
+
+					Ok((
+						async move {
+							moonbeam_service::frontier_backfill::frontier_backfill(
+								params.client,
+								frontier_backend,
+								cmd.from,
+								cmd.to,
+							)
+							.await
+							.map_err(Into::into)
+						},
+						params.task_manager,
+					))
+				}),
+				#[cfg(feature = "moonbase-native")]
+				_ => runner.async_run(|mut config| {
+					let params = moonbeam_service::new_partial::<
+						moonbeam_service::moonbase_runtime::RuntimeApi,
+						moonbeam_service::MoonbaseExecutor,
+					>(&mut config, &rpc_config, false)?;
+					let (_, _, _, _, frontier_backend, _) = params.other;
+
+					Ok((
+						async move {
+							moonbeam_service::frontier_backfill::frontier_backfill(
+								params.client,
+								frontier_backend,
+								cmd.from,
+								cmd.to,
+							)
+							.await
+							.map_err(Into::into)
+						},
+						params.task_manager,
+					))
+				}),
+				#[cfg(not(feature = "moonbase-native"))]
+				_ => panic!("invalid chain spec"),
+			}
+		}
+		Some(Subcommand::ExportTraces(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			let chain_spec = &runner.config().chain_spec;
+			let rpc_config = cli.run.new_rpc_config();
+			match chain_spec {
+				#[cfg(feature = "moonriver-native")]
+				spec if spec.is_moonriver() => runner.async_run(|mut config| {
+					let params = moonbeam_service::new_partial::<
+						moonbeam_service::moonriver_runtime::RuntimeApi,
+						moonbeam_service::MoonriverExecutor,
+					>(&mut config, &rpc_config, false)?;
+
+					Ok((
+						async move {
+							moonbeam_service::trace_export::export_traces(
+								params.client,
+								params.backend,
+								cmd.from,
+								cmd.to,
+								cmd.format,
+								&cmd.output,
+							)
+							.map_err(Into::into)
+						},
+						params.task_manager,
+					))
+				}),
+				#[cfg(feature = "moonbeam-native")]
+				spec if spec.is_moonbeam() => runner.async_run(|mut config| {
+					let params = moonbeam_service::new_partial::<
+						moonbeam_service::moonbeam_runtime::RuntimeApi,
+						moonbeam_service::MoonbeamExecutor,
+					>(&mut config, &rpc_config, false)?;
+
+					Ok((
+						async move {
+							moonbeam_service::trace_export::export_traces(
+								params.client,
+								params.backend,
+								cmd.from,
+								cmd.to,
+								cmd.format,
+								&cmd.output,
+							)
+							.map_err(Into::into)
+						},
+						params.task_manager,
+					))
+				}),
+				#[cfg(feature = "moonbase-native")]
+				_ => runner.async_run(|mut config| {
+					let params = moonbeam_service::new_partial::<
+						moonbeam_service::moonbase_runtime::RuntimeApi,
+						moonbeam_service::MoonbaseExecutor,
+					>(&mut config, &rpc_config, false)?;
+
+					Ok((
+						async move {
+							moonbeam_service::trace_export::export_traces(
+								params.client,
+								params.backend,
+								cmd.from,
+								cmd.to,
+								cmd.format,
+								&cmd.output,
+							)
+							.map_err(Into::into)
+						},
+						params.task_manager,
+					))
+				}),
+				#[cfg(not(feature = "moonbase-native"))]
+				_ => panic!("invalid chain spec"),
+			}
+		}
+		Some(Subcommand::Snapshot(SnapshotCmd::Export(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+			let rpc_config = cli.run.new_rpc_config();
+			runner.sync_run(|mut config| {
+				// The databases are only opened to find the finalized block, and are closed
+				// before being archived.
+				let manifest = {
+					let (client, _, _, _) =
+						moonbeam_service::new_chain_ops(&mut config, &rpc_config)?;
+					moonbeam_service::snapshot::SnapshotManifest::new(&config, client.as_ref())?
+				};
+				moonbeam_service::snapshot::export_snapshot(&config, &manifest, &cmd.output)
+					.map_err(Into::into)
+			})
+		}
+		Some(Subcommand::Snapshot(SnapshotCmd::Import(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+			let rpc_config = cli.run.new_rpc_config();
+			runner.sync_run(|mut config| {
+				let manifest = moonbeam_service::snapshot::import_snapshot(&config, &cmd.input)?;
+				let (client, _, _, _) = moonbeam_service::new_chain_ops(&mut config, &rpc_config)?;
+				manifest.verify(client.as_ref()).map_err(Into::into)
+			})
+		}
 		Some(Subcommand::ExportGenesisState(params)) => {
 			let mut builder = sc_cli::LoggerBuilder::new("");
 			builder.with_profiling(sc_tracing::TracingReceiver::Log, "");
@@ -479,7 +733,60 @@ pub fn run() -> Result<()> {
 
 			Ok(())
 		}
-		Some(Subcommand::Benchmark(cmd)) => {
+		Some(Subcommand::Benchmark(BenchmarkSubcommand::MoonbeamAll(cmd))) => {
+			if !cfg!(feature = "runtime-benchmarks") {
+				return Err("Benchmarking wasn't enabled when building the node. \
+				You can enable it with `--features runtime-benchmarks`."
+					.into());
+			}
+
+			let pallet_cmds = cmd.pallet_cmds()?;
+			let Some((_, first_cmd)) = pallet_cmds.first() else {
+				return Err("No pallet to benchmark".into());
+			};
+			// The runner is only used to set up the logger and the tokio runtime, each pallet is
+			// benchmarked with a configuration of its own.
+			let runner = cli.create_runner(first_cmd)?;
+			let tokio_handle = runner.config().tokio_handle.clone();
+
+			let mut failed = Vec::new();
+			for (pallet, pallet_cmd) in pallet_cmds.iter() {
+				info!("Benchmarking {} for {:?}", pallet, cmd.runtime);
+				let config = pallet_cmd.create_configuration(&cli, tokio_handle.clone())?;
+				let result = match cmd.runtime {
+					#[cfg(feature = "moonriver-native")]
+					BenchmarkRuntime::Moonriver => pallet_cmd
+						.run::<moonbeam_service::moonriver_runtime::Block, moonbeam_service::MoonriverExecutor>(
+							config,
+						),
+					#[cfg(feature = "moonbeam-native")]
+					BenchmarkRuntime::Moonbeam => pallet_cmd
+						.run::<moonbeam_service::moonbeam_runtime::Block, moonbeam_service::MoonbeamExecutor>(
+							config,
+						),
+					#[cfg(feature = "moonbase-native")]
+					BenchmarkRuntime::Moonbase => pallet_cmd
+						.run::<moonbeam_service::moonbase_runtime::Block, moonbeam_service::MoonbaseExecutor>(
+							config,
+						),
+					#[allow(unreachable_patterns)]
+					_ => Err("Runtime not included in the node build".into()),
+				};
+
+				// Keep benchmarking the other pallets, failures are reported at the end.
+				if let Err(e) = result {
+					warn!("Failed to benchmark {}: {}", pallet, e);
+					failed.push(pallet.clone());
+				}
+			}
+
+			if failed.is_empty() {
+				Ok(())
+			} else {
+				Err(format!("Failed to benchmark: {}", failed.join(", ")).into())
+			}
+		}
+		Some(Subcommand::Benchmark(BenchmarkSubcommand::Substrate(cmd))) => {
 			let runner = cli.create_runner(cmd)?;
 
 			// Switch on the concrete benchmark sub-command
@@ -756,12 +1063,14 @@ pub fn run() -> Result<()> {
 
 					return match &config.chain_spec {
 						#[cfg(feature = "moonriver-native")]
-						spec if spec.is_moonriver() => moonbeam_service::new_dev::<
-							moonbeam_service::moonriver_runtime::RuntimeApi,
-							moonbeam_service::MoonriverExecutor,
-						>(config, author_id, cli.run.sealing, rpc_config, hwbench)
-						.await
-						.map_err(Into::into),
+						spec if spec.is_moonriver() => {
+							moonbeam_service::new_dev::<
+								moonbeam_service::moonriver_runtime::RuntimeApi,
+								moonbeam_service::MoonriverExecutor,
+							>(config, author_id, cli.run.sealing, rpc_config, hwbench)
+							.await
+							.map_err(Into::into)
+						}
 						#[cfg(feature = "moonbeam-native")]
 						spec if spec.is_moonbeam() => moonbeam_service::new_dev::<
 							moonbeam_service::moonbeam_runtime::RuntimeApi,
@@ -13,8 +13,12 @@
 
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+mod benchmark;
 mod cli;
 mod command;
+mod nimbus_key;
+pub use benchmark::*;
 pub use cli::*;
 pub use command::*;
+pub use nimbus_key::*;
 pub use sc_cli::{Error, Result};
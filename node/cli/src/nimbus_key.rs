@@ -0,0 +1,235 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Collator key rotation: generates new nimbus and VRF keys in the keystore of the node and
+//! registers them with the `set_keys` call of pallet-author-mapping.
+
+use clap::Parser;
+use ethereum::{
+	LegacyTransaction, LegacyTransactionMessage, TransactionAction, TransactionSignature,
+};
+use jsonrpsee::{
+	core::{client::ClientT, params::ArrayParams, DeserializeOwned},
+	http_client::{HttpClient, HttpClientBuilder},
+	rpc_params,
+};
+use moonbeam_service::IdentifyVariant;
+use nimbus_primitives::NIMBUS_KEY_ID;
+use parity_scale_codec::Encode;
+use sc_cli::{Error, KeystoreParams, SharedParams, SubstrateCli};
+use sc_keystore::LocalKeystore;
+use sc_service::config::{BasePath, KeystoreConfig};
+use session_keys_primitives::VRF_KEY_ID;
+use sp_core::{H160, H256, U256};
+use sp_keystore::Keystore;
+use std::path::PathBuf;
+
+/// Address of the author mapping precompile.
+const AUTHOR_MAPPING_PRECOMPILE: u64 = 2055;
+
+/// Selector of `setKeys(bytes)` in the author mapping precompile.
+const SET_KEYS_SELECTOR: [u8; 4] = [0xf1, 0xec, 0x91, 0x9c];
+
+/// Index of `set_keys` in the calls of pallet-author-mapping.
+const SET_KEYS_CALL_INDEX: u8 = 4;
+
+/// Generate new nimbus and VRF keys in the keystore and optionally register them in the author
+/// mapping of the collator account.
+#[derive(Debug, Parser)]
+pub struct RotateNimbusKeyCmd {
+	/// Print the author-mapping `set_keys` call registering the new keys.
+	#[clap(long)]
+	pub register: bool,
+
+	/// Submit the `set_keys` call through the author mapping precompile, signed with the
+	/// Ethereum private key (hex encoded) of the collator account read from the given file.
+	#[clap(long, value_name = "PATH", requires = "register")]
+	pub submit_key_file: Option<PathBuf>,
+
+	/// Ethereum RPC endpoint of a node of the chain the call is submitted to.
+	#[clap(long, value_name = "URL", default_value = "http://127.0.0.1:9944")]
+	pub rpc_url: String,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub keystore_params: KeystoreParams,
+}
+
+impl RotateNimbusKeyCmd {
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<(), Error> {
+		let base_path = self
+			.shared_params
+			.base_path()?
+			.unwrap_or_else(|| BasePath::from_project("", "", &C::executable_name()));
+		let chain_id = self.shared_params.chain_id(self.shared_params.is_dev());
+		let chain_spec = cli.load_spec(&chain_id)?;
+		let config_dir = base_path.config_dir(chain_spec.id());
+
+		let keystore = match self.keystore_params.keystore_config(&config_dir)? {
+			KeystoreConfig::Path { path, password } => {
+				LocalKeystore::open(path, password).map_err(|_| Error::KeystoreOperation)?
+			}
+			_ => unreachable!("keystore_config always returns path and password; qed"),
+		};
+
+		// The previous keys are kept, such that the collator can still author blocks until the
+		// new ones are registered.
+		let nimbus_id = keystore
+			.sr25519_generate_new(NIMBUS_KEY_ID, None)
+			.map_err(|_| Error::KeystoreOperation)?;
+		let vrf_id = keystore
+			.sr25519_generate_new(VRF_KEY_ID, None)
+			.map_err(|_| Error::KeystoreOperation)?;
+
+		println!("Nimbus id:    0x{}", hex::encode(nimbus_id));
+		println!("VRF id:       0x{}", hex::encode(vrf_id));
+
+		if !self.register {
+			return Ok(());
+		}
+
+		let keys = [nimbus_id.as_ref(), vrf_id.as_ref()].concat();
+		let author_mapping_index: u8 = if chain_spec.is_moonbase() { 21 } else { 23 };
+		let call = (author_mapping_index, SET_KEYS_CALL_INDEX, keys.clone()).encode();
+
+		println!("Keys:         0x{}", hex::encode(&keys));
+		println!("Call:         0x{}", hex::encode(call));
+
+		if let Some(path) = &self.submit_key_file {
+			let secret = read_secret_key(path)?;
+			let transaction_hash = tokio::runtime::Builder::new_current_thread()
+				.enable_all()
+				.build()?
+				.block_on(submit_set_keys(&self.rpc_url, &secret, &keys))?;
+
+			println!("Transaction:  {:?}", transaction_hash);
+		}
+
+		Ok(())
+	}
+}
+
+fn read_secret_key(path: &PathBuf) -> Result<libsecp256k1::SecretKey, Error> {
+	let content = std::fs::read_to_string(path)?;
+	let bytes = hex::decode(content.trim().trim_start_matches("0x"))
+		.map_err(|_| Error::Input("Private key is not valid hex".into()))?;
+
+	libsecp256k1::SecretKey::parse_slice(&bytes)
+		.map_err(|_| Error::Input("Invalid private key".into()))
+}
+
+/// Calldata of `setKeys(bytes)` in the author mapping precompile.
+fn set_keys_input(keys: &[u8]) -> Vec<u8> {
+	let padded_len = (keys.len() + 31) / 32 * 32;
+
+	let mut input = SET_KEYS_SELECTOR.to_vec();
+	input.extend_from_slice(&H256::from_low_u64_be(32).0);
+	input.extend_from_slice(&H256::from_low_u64_be(keys.len() as u64).0);
+	input.extend_from_slice(keys);
+	input.resize(input.len() + padded_len - keys.len(), 0);
+	input
+}
+
+/// Send a transaction calling `setKeys(keys)` on the author mapping precompile, signed with
+/// `secret`, returning its hash.
+async fn submit_set_keys(
+	url: &str,
+	secret: &libsecp256k1::SecretKey,
+	keys: &[u8],
+) -> Result<H256, Error> {
+	let client = HttpClientBuilder::default()
+		.build(url)
+		.map_err(|e| Error::Application(Box::new(e)))?;
+
+	let public_key = &libsecp256k1::PublicKey::from_secret_key(secret).serialize()[1..65];
+	let from = H160::from(H256::from(sp_io::hashing::keccak_256(public_key)));
+	let to = H160::from_low_u64_be(AUTHOR_MAPPING_PRECOMPILE);
+	let input = set_keys_input(keys);
+
+	let chain_id: U256 = request(&client, "eth_chainId", rpc_params![]).await?;
+	let nonce: U256 = request(
+		&client,
+		"eth_getTransactionCount",
+		rpc_params![from, "pending"],
+	)
+	.await?;
+	let gas_price: U256 = request(&client, "eth_gasPrice", rpc_params![]).await?;
+	let gas_limit: U256 = request(
+		&client,
+		"eth_estimateGas",
+		rpc_params![serde_json::json!({
+			"from": from,
+			"to": to,
+			"data": format!("0x{}", hex::encode(&input)),
+		})],
+	)
+	.await?;
+
+	let chain_id = chain_id.low_u64();
+	let message = LegacyTransactionMessage {
+		nonce,
+		gas_price,
+		gas_limit,
+		action: TransactionAction::Call(to),
+		value: U256::zero(),
+		input,
+		chain_id: Some(chain_id),
+	};
+
+	let (signature, recovery_id) = libsecp256k1::sign(
+		&libsecp256k1::Message::parse(message.hash().as_fixed_bytes()),
+		secret,
+	);
+	let signature = signature.serialize();
+	let signature = TransactionSignature::new(
+		recovery_id.serialize() as u64 + 35 + chain_id * 2,
+		H256::from_slice(&signature[0..32]),
+		H256::from_slice(&signature[32..64]),
+	)
+	.ok_or_else(|| Error::Input("Invalid transaction signature".into()))?;
+
+	let transaction = LegacyTransaction {
+		nonce: message.nonce,
+		gas_price: message.gas_price,
+		gas_limit: message.gas_limit,
+		action: message.action,
+		value: message.value,
+		input: message.input,
+		signature,
+	};
+
+	request(
+		&client,
+		"eth_sendRawTransaction",
+		rpc_params![format!("0x{}", hex::encode(rlp::encode(&transaction)))],
+	)
+	.await
+}
+
+async fn request<R: DeserializeOwned>(
+	client: &HttpClient,
+	method: &str,
+	params: ArrayParams,
+) -> Result<R, Error> {
+	client
+		.request(method, params)
+		.await
+		.map_err(|e| Error::Application(Box::new(e)))
+}
@@ -0,0 +1,206 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Re-executes canonical blocks on top of the state of their parent and compares the result
+//! with the imported blocks.
+//!
+//! This allows to verify the integrity of the database of a node, or to investigate a consensus
+//! fault, by pinpointing the blocks whose state root, extrinsics root or Ethereum receipts
+//! differ from the ones computed by the runtime.
+
+use crate::{Block, BlockNumber};
+use fp_rpc::EthereumRuntimeRPCApi;
+use sc_client_api::{
+	backend::{Backend, StateBackend, StorageProvider},
+	BlockBackend,
+};
+use sp_api::{Core, ProvideRuntimeApi};
+use sp_block_builder::BlockBuilder;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{BlakeTwo256, Header as HeaderT};
+use std::sync::Arc;
+
+/// Number of blocks between two progress logs.
+const LOG_INTERVAL: BlockNumber = 1_000;
+
+/// Re-execute the canonical blocks `from..=to`, `to` defaulting to `from`, and log the
+/// differences with the imported blocks. Fails if any block is inconsistent.
+pub fn check_blocks<C, BE>(
+	client: Arc<C>,
+	from: BlockNumber,
+	to: Option<BlockNumber>,
+) -> Result<(), String>
+where
+	C: ProvideRuntimeApi<Block> + StorageProvider<Block, BE> + HeaderBackend<Block>,
+	C: BlockBackend<Block> + Send + Sync + 'static,
+	C::Api: BlockBuilder<Block> + EthereumRuntimeRPCApi<Block>,
+	BE: Backend<Block> + 'static,
+	BE::State: StateBackend<BlakeTwo256>,
+{
+	let best_number = client.info().best_number;
+	let to = to.unwrap_or(from);
+	if from == 0 || from > to {
+		return Err(format!("Invalid block range {}..={}", from, to));
+	}
+	if to > best_number {
+		return Err(format!(
+			"Block {} is above the best block ({})",
+			to, best_number
+		));
+	}
+
+	let overrides = crate::rpc::overrides_handle(client.clone());
+
+	let mut inconsistent_blocks = 0u32;
+	for number in from..=to {
+		let differences = check_block(&client, &overrides, number)?;
+		if !differences.is_empty() {
+			inconsistent_blocks += 1;
+			for difference in differences {
+				log::error!(target: "check-block", "Block {}: {}", number, difference);
+			}
+		}
+
+		if (number - from + 1) % LOG_INTERVAL == 0 {
+			log::info!(
+				target: "check-block",
+				"Checked blocks {}..={} ({} remaining)",
+				from,
+				number,
+				to - number
+			);
+		}
+	}
+
+	if inconsistent_blocks > 0 {
+		return Err(format!(
+			"{} inconsistent block(s) in {}..={}",
+			inconsistent_blocks, from, to
+		));
+	}
+
+	log::info!(target: "check-block", "Blocks {}..={} are consistent", from, to);
+
+	Ok(())
+}
+
+/// Re-execute a canonical block, returning the differences with the imported block.
+fn check_block<C, BE>(
+	client: &Arc<C>,
+	overrides: &fc_rpc::OverrideHandle<Block>,
+	number: BlockNumber,
+) -> Result<Vec<String>, String>
+where
+	C: ProvideRuntimeApi<Block> + StorageProvider<Block, BE> + HeaderBackend<Block>,
+	C: BlockBackend<Block> + Send + Sync + 'static,
+	C::Api: BlockBuilder<Block> + EthereumRuntimeRPCApi<Block>,
+	BE: Backend<Block> + 'static,
+	BE::State: StateBackend<BlakeTwo256>,
+{
+	let hash = client
+		.hash(number)
+		.map_err(|e| format!("Failed to fetch hash of block {} : {:?}", number, e))?
+		.ok_or_else(|| format!("Block {} not found", number))?;
+	let header = client
+		.header(hash)
+		.map_err(|e| format!("Failed to fetch header of block {} : {:?}", number, e))?
+		.ok_or_else(|| format!("Header of block {} not found", number))?;
+	let extrinsics = client
+		.block_body(hash)
+		.map_err(|e| format!("Failed to fetch body of block {} : {:?}", number, e))?
+		.ok_or_else(|| format!("Body of block {} not found", number))?;
+	let parent_hash = *header.parent_hash();
+
+	// The seal is removed by the import queue before the block is executed.
+	let mut pre_header = header.clone();
+	pre_header
+		.digest_mut()
+		.logs
+		.retain(|item| item.as_seal().is_none());
+
+	// The changes made by the runtime calls are kept in the overlay of the api instance.
+	let api = client.runtime_api();
+	api.initialize_block(parent_hash, &pre_header)
+		.map_err(|e| format!("Failed to initialize block {} : {:?}", number, e))?;
+
+	let mut differences = Vec::new();
+	for (index, extrinsic) in extrinsics.into_iter().enumerate() {
+		let result = api
+			.apply_extrinsic(parent_hash, extrinsic)
+			.map_err(|e| format!("Failed to apply extrinsic {}-{} : {:?}", number, index, e))?;
+		if let Err(e) = result {
+			differences.push(format!("extrinsic {} is invalid: {:?}", index, e));
+		}
+	}
+
+	let computed_header = api
+		.finalize_block(parent_hash)
+		.map_err(|e| format!("Failed to finalize block {} : {:?}", number, e))?;
+	let (computed_block, computed_receipts, _) = api.current_all(parent_hash).map_err(|e| {
+		format!(
+			"Failed to fetch Ethereum data of block {} : {:?}",
+			number, e
+		)
+	})?;
+
+	if computed_header.state_root() != header.state_root() {
+		differences.push(format!(
+			"state root {:?}, expected {:?}",
+			computed_header.state_root(),
+			header.state_root()
+		));
+	}
+	if computed_header.extrinsics_root() != header.extrinsics_root() {
+		differences.push(format!(
+			"extrinsics root {:?}, expected {:?}",
+			computed_header.extrinsics_root(),
+			header.extrinsics_root()
+		));
+	}
+
+	let schema = fc_storage::onchain_storage_schema::<Block, C, BE>(client.as_ref(), hash);
+	let Some(storage) = overrides.schemas.get(&schema) else {
+		return Err(format!("No storage override for block {}", number));
+	};
+	let receipts_root = |block: Option<ethereum::BlockV2>| block.map(|b| b.header.receipts_root);
+
+	let computed_receipts_root = receipts_root(computed_block);
+	let expected_receipts_root = receipts_root(storage.current_block(hash));
+	if computed_receipts_root != expected_receipts_root {
+		differences.push(format!(
+			"EVM receipts root {:?}, expected {:?}",
+			computed_receipts_root, expected_receipts_root
+		));
+	}
+
+	let computed_receipts = computed_receipts.unwrap_or_default();
+	let expected_receipts = storage.current_receipts(hash).unwrap_or_default();
+	if computed_receipts.len() != expected_receipts.len() {
+		differences.push(format!(
+			"{} EVM receipts, expected {}",
+			computed_receipts.len(),
+			expected_receipts.len()
+		));
+	} else if let Some(index) = computed_receipts
+		.iter()
+		.zip(expected_receipts.iter())
+		.position(|(computed, expected)| computed != expected)
+	{
+		differences.push(format!("EVM receipt {} differs", index));
+	}
+
+	Ok(differences)
+}
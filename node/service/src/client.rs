@@ -41,11 +41,16 @@ pub trait RuntimeApiCollection:
 	+ sp_session::SessionKeys<Block>
 	+ fp_rpc::ConvertTransactionRuntimeApi<Block>
 	+ fp_rpc::EthereumRuntimeRPCApi<Block>
+	+ eth_call_runtime_api::EthCallApi<Block>
 	+ moonbeam_rpc_primitives_debug::DebugRuntimeApi<Block>
 	+ moonbeam_rpc_primitives_txpool::TxPoolRuntimeApi<Block>
 	+ nimbus_primitives::NimbusApi<Block>
 	+ cumulus_primitives_core::CollectCollationInfo<Block>
 	+ session_keys_primitives::VrfApi<Block>
+	+ evm_hibernation_runtime_api::EvmHibernationApi<Block, BlockNumber>
+	+ account_info_runtime_api::AccountInfoApi<Block, Index, Balance>
+	+ xcm_events_runtime_api::XcmEventsApi<Block>
+	+ transaction_types_runtime_api::TransactionTypesApi<Block>
 where
 	<Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
@@ -63,11 +68,16 @@ where
 		+ sp_session::SessionKeys<Block>
 		+ fp_rpc::ConvertTransactionRuntimeApi<Block>
 		+ fp_rpc::EthereumRuntimeRPCApi<Block>
+		+ eth_call_runtime_api::EthCallApi<Block>
 		+ moonbeam_rpc_primitives_debug::DebugRuntimeApi<Block>
 		+ moonbeam_rpc_primitives_txpool::TxPoolRuntimeApi<Block>
 		+ nimbus_primitives::NimbusApi<Block>
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
-		+ session_keys_primitives::VrfApi<Block>,
+		+ session_keys_primitives::VrfApi<Block>
+		+ evm_hibernation_runtime_api::EvmHibernationApi<Block, BlockNumber>
+		+ account_info_runtime_api::AccountInfoApi<Block, Index, Balance>
+		+ xcm_events_runtime_api::XcmEventsApi<Block>
+		+ transaction_types_runtime_api::TransactionTypesApi<Block>,
 	<Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
 }
@@ -0,0 +1,104 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Rebuilds the frontier mapping database for a range of canonical blocks.
+//!
+//! The mapping sync worker only indexes blocks as they are imported. This allows to recover the
+//! mappings of a corrupted or partially synced frontier database, or to populate a freshly
+//! created SQL backend without re-syncing the whole chain.
+
+use crate::{Block, BlockNumber};
+use sc_client_api::backend::{Backend, StateBackend, StorageProvider};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::BlakeTwo256;
+use std::sync::Arc;
+
+/// Number of blocks between two progress logs.
+const LOG_INTERVAL: BlockNumber = 1_000;
+
+/// Index the Ethereum data of the canonical blocks `from..=to` in the given frontier backend,
+/// `to` defaulting to the best block. Blocks already indexed are overwritten with the same data.
+pub async fn frontier_backfill<C, BE>(
+	client: Arc<C>,
+	frontier_backend: fc_db::Backend<Block>,
+	from: BlockNumber,
+	to: Option<BlockNumber>,
+) -> Result<(), String>
+where
+	C: ProvideRuntimeApi<Block> + StorageProvider<Block, BE> + HeaderBackend<Block>,
+	C: Send + Sync + 'static,
+	C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
+	BE: Backend<Block> + 'static,
+	BE::State: StateBackend<BlakeTwo256>,
+{
+	let best_number = client.info().best_number;
+	let to = to.unwrap_or(best_number);
+	if from > to {
+		return Err(format!("Invalid block range {}..={}", from, to));
+	}
+	if to > best_number {
+		return Err(format!(
+			"Block {} is above the best block ({})",
+			to, best_number
+		));
+	}
+
+	let overrides = crate::rpc::overrides_handle(client.clone());
+
+	for number in from..=to {
+		let hash = client
+			.hash(number)
+			.map_err(|e| format!("Failed to fetch hash of block {} : {:?}", number, e))?
+			.ok_or_else(|| format!("Block {} not found", number))?;
+
+		match &frontier_backend {
+			fc_db::Backend::KeyValue(backend) => {
+				let header = client
+					.header(hash)
+					.map_err(|e| format!("Failed to fetch header of block {} : {:?}", number, e))?
+					.ok_or_else(|| format!("Header of block {} not found", number))?;
+				fc_mapping_sync::kv::sync_block(
+					client.as_ref(),
+					overrides.clone(),
+					backend,
+					&header,
+				)?;
+			}
+			fc_db::Backend::Sql(backend) => {
+				backend
+					.insert_block_metadata(client.clone(), hash)
+					.await
+					.map_err(|e| format!("Failed to index block {} : {:?}", number, e))?;
+				backend.index_block_logs(client.clone(), hash).await;
+			}
+		}
+
+		if (number - from + 1) % LOG_INTERVAL == 0 {
+			log::info!(
+				target: "frontier-backfill",
+				"Indexed blocks {}..={} ({} remaining)",
+				from,
+				number,
+				to - number
+			);
+		}
+	}
+
+	log::info!(target: "frontier-backfill", "Indexed blocks {}..={}", from, to);
+
+	Ok(())
+}
@@ -0,0 +1,98 @@
+// Copyright 2019-2023 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Prunes the ancient blocks from the frontier SQL backend.
+//!
+//! The logs and transactions of the blocks older than the retention window are deleted as blocks
+//! get finalized. Block mappings are kept: they are small and the sync worker relies on them to
+//! find the blocks it still has to index.
+
+use futures::StreamExt;
+use sc_client_api::client::BlockchainEvents;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::sync::Arc;
+
+/// Number of finalized blocks between two prunings.
+const PRUNING_INTERVAL: u32 = 100;
+
+pub struct FrontierPruningWorker<B: BlockT, C> {
+	client: Arc<C>,
+	backend: Arc<fc_db::sql::Backend<B>>,
+	retained_blocks: u32,
+}
+
+impl<B, C> FrontierPruningWorker<B, C>
+where
+	B: BlockT,
+	B::Header: HeaderT<Number = u32>,
+	C: BlockchainEvents<B>,
+{
+	pub fn new(client: Arc<C>, backend: Arc<fc_db::sql::Backend<B>>, retained_blocks: u32) -> Self {
+		Self {
+			client,
+			backend,
+			retained_blocks,
+		}
+	}
+
+	pub async fn run(self) {
+		let mut notifications = self.client.finality_notification_stream();
+		let mut pruned_below = 0;
+
+		while let Some(notification) = notifications.next().await {
+			let prune_below = notification
+				.header
+				.number()
+				.saturating_sub(self.retained_blocks);
+			if prune_below < pruned_below.saturating_add(PRUNING_INTERVAL) {
+				continue;
+			}
+
+			match self.prune(prune_below).await {
+				Ok(()) => {
+					log::debug!(
+						target: "frontier-pruning",
+						"Pruned logs and transactions below block {}",
+						prune_below
+					);
+					pruned_below = prune_below;
+				}
+				Err(e) => log::warn!(
+					target: "frontier-pruning",
+					"Failed to prune logs and transactions below block {} : {:?}",
+					prune_below,
+					e
+				),
+			}
+		}
+	}
+
+	/// Deletes the logs and transactions of the blocks below `number`.
+	async fn prune(&self, number: u32) -> Result<(), sqlx::Error> {
+		let mut tx = self.backend.pool().begin().await?;
+		for table in ["logs", "transactions"] {
+			sqlx::query(&format!(
+				"DELETE FROM {} WHERE substrate_block_hash IN \
+				(SELECT substrate_block_hash FROM blocks WHERE block_number < ?)",
+				table
+			))
+			.bind(number as i64)
+			.execute(&mut *tx)
+			.await?;
+		}
+		tx.commit().await
+	}
+}
@@ -0,0 +1,147 @@
+// Copyright 2019-2023 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Reconstructs the frontier mappings of the blocks imported by a warp or fast sync.
+//!
+//! The mapping sync worker indexes a few blocks per imported block, which takes days to catch
+//! up with the whole history on a node that didn't execute it. The ethereum block and transaction
+//! hashes are also available in the post-runtime digest of the headers though, so once the state
+//! sync and the block history download are complete, the mappings are rebuilt from the headers
+//! alone, in parallel batches.
+
+use futures::StreamExt;
+use sc_network_sync::SyncingService;
+use sp_blockchain::HeaderBackend;
+use sp_consensus::SyncOracle;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::{ops::RangeInclusive, sync::Arc, time::Duration};
+
+/// Number of blocks indexed by a single task.
+const BATCH_SIZE: u32 = 10_000;
+/// Delay between two checks of the sync status.
+const SYNC_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Waits for the node to be synced, then indexes the ethereum hashes of all the blocks found in
+/// their digest.
+pub async fn reconstruct_mappings<B, C>(
+	client: Arc<C>,
+	backend: Arc<fc_db::kv::Backend<B>>,
+	sync: Arc<SyncingService<B>>,
+) where
+	B: BlockT,
+	B::Header: HeaderT<Number = u32>,
+	C: HeaderBackend<B> + Send + Sync + 'static,
+{
+	// Headers below the warp sync target are downloaded once the state is synced.
+	while sync.is_major_syncing() || client.info().block_gap.is_some() {
+		tokio::time::sleep(SYNC_POLL_INTERVAL).await;
+	}
+
+	let best_number = client.info().best_number;
+	log::info!(
+		target: "frontier-reconstruction",
+		"Reconstructing the frontier mappings of blocks 1..={}",
+		best_number
+	);
+
+	let parallelism = std::thread::available_parallelism()
+		.map(|parallelism| parallelism.get())
+		.unwrap_or(1);
+	let batches = (1..=best_number)
+		.step_by(BATCH_SIZE as usize)
+		.map(|from| from..=from.saturating_add(BATCH_SIZE - 1).min(best_number));
+
+	futures::stream::iter(batches)
+		.map(|batch| {
+			let client = client.clone();
+			let backend = backend.clone();
+			tokio::task::spawn_blocking(move || {
+				let result = index_headers(client.as_ref(), backend.as_ref(), batch.clone());
+				(batch, result)
+			})
+		})
+		.buffer_unordered(parallelism)
+		.for_each(|result| async move {
+			match result {
+				Ok((batch, Ok(()))) => log::debug!(
+					target: "frontier-reconstruction",
+					"Indexed blocks {:?}",
+					batch
+				),
+				Ok((batch, Err(e))) => log::warn!(
+					target: "frontier-reconstruction",
+					"Failed to index blocks {:?}, left to the mapping sync worker : {}",
+					batch,
+					e
+				),
+				Err(e) => log::warn!(
+					target: "frontier-reconstruction",
+					"Reconstruction task failed : {:?}",
+					e
+				),
+			}
+		})
+		.await;
+
+	log::info!(
+		target: "frontier-reconstruction",
+		"Reconstructed the frontier mappings of blocks 1..={}",
+		best_number
+	);
+}
+
+/// Writes the mapping of the blocks of `range` that are not indexed yet, using their digest.
+fn index_headers<B, C>(
+	client: &C,
+	backend: &fc_db::kv::Backend<B>,
+	range: RangeInclusive<u32>,
+) -> Result<(), String>
+where
+	B: BlockT,
+	B::Header: HeaderT<Number = u32>,
+	C: HeaderBackend<B>,
+{
+	for number in range {
+		let hash = client
+			.hash(number)
+			.map_err(|e| format!("Failed to fetch hash of block {} : {:?}", number, e))?
+			.ok_or_else(|| format!("Block {} not found", number))?;
+		if backend.mapping().is_synced(&hash)? {
+			continue;
+		}
+		let header = client
+			.header(hash)
+			.map_err(|e| format!("Failed to fetch header of block {} : {:?}", number, e))?
+			.ok_or_else(|| format!("Header of block {} not found", number))?;
+
+		match fp_consensus::find_log(header.digest()) {
+			Ok(fp_consensus::Log::Post(fp_consensus::PostLog::Hashes(hashes))) => {
+				backend
+					.mapping()
+					.write_hashes(fc_db::kv::MappingCommitment {
+						block_hash: hash,
+						ethereum_block_hash: hashes.block_hash,
+						ethereum_transaction_hashes: hashes.transaction_hashes,
+					})?;
+			}
+			Err(fp_consensus::FindLogError::NotFound) => backend.mapping().write_none(hash)?,
+			// Other logs need the state of the block, they are left to the mapping sync worker.
+			_ => {}
+		}
+	}
+
+	Ok(())
+}
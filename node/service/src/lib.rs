@@ -22,7 +22,14 @@
 //! Full Service: A complete parachain node including the pool, rpc, network, embedded relay chain
 //! Dev Service: A leaner service without the relay chain backing.
 
+pub mod block_check;
+pub mod frontier_backfill;
+pub mod frontier_pruning;
+pub mod frontier_reconstruction;
 pub mod rpc;
+pub mod runtime_metrics;
+pub mod snapshot;
+pub mod trace_export;
 
 use cumulus_client_cli::CollatorOptions;
 use cumulus_client_consensus_common::{ParachainBlockImport, ParachainConsensus};
@@ -60,7 +67,10 @@ use sc_consensus::ImportQueue;
 use sc_executor::{
 	HeapAllocStrategy, NativeElseWasmExecutor, WasmExecutor, DEFAULT_HEAP_ALLOC_STRATEGY,
 };
-use sc_network::{config::FullNetworkConfiguration, NetworkBlock, NetworkService};
+use sc_network::{
+	config::{FullNetworkConfiguration, SyncMode},
+	NetworkBlock, NetworkService,
+};
 use sc_service::config::PrometheusConfig;
 use sc_service::{
 	error::Error as ServiceError, ChainSpec, Configuration, PartialComponents, TFullBackend,
@@ -285,6 +295,9 @@ where
 				},
 			)?)
 		}
+		FrontierBackendConfig::KeyValue if rpc_config.frontier_retained_blocks.is_some() => {
+			return Err("Pruning the frontier mappings requires the sql backend".to_string())
+		}
 		FrontierBackendConfig::Sql {
 			pool_size,
 			num_ops_timeout,
@@ -704,11 +717,59 @@ where
 			overrides: overrides.clone(),
 			fee_history_limit,
 			fee_history_cache: fee_history_cache.clone(),
+			frontier_retained_blocks: rpc_config.frontier_retained_blocks,
 		},
 		sync_service.clone(),
 		pubsub_notification_sinks.clone(),
 	);
 
+	// The SQL frontier backend indexes the logs itself.
+	let log_bloom_index = matches!(frontier_backend, fc_db::Backend::KeyValue(_));
+	if log_bloom_index && parachain_config.rpc_addr.is_some() {
+		rpc::spawn_log_bloom_index_task::<_, _, FullBackend>(
+			&task_manager,
+			client.clone(),
+			overrides.clone(),
+		);
+	}
+
+	// A warp or fast synced node didn't execute the history, its mappings are rebuilt from the
+	// block headers instead of waiting for the mapping sync worker.
+	if !matches!(parachain_config.network.sync_mode, SyncMode::Full) {
+		if let fc_db::Backend::KeyValue(b) = frontier_backend.clone() {
+			task_manager.spawn_handle().spawn(
+				"frontier-mapping-reconstruction",
+				Some("frontier"),
+				frontier_reconstruction::reconstruct_mappings(
+					client.clone(),
+					Arc::new(b),
+					sync_service.clone(),
+				),
+			);
+		}
+	}
+
+	runtime_metrics::spawn_runtime_metrics_task(
+		&task_manager,
+		client.clone(),
+		overrides.clone(),
+		prometheus_registry.as_ref(),
+	)?;
+
+	let user_operations = match &rpc_config.bundler {
+		Some(bundler_config) => {
+			let user_operation_pool = rpc::spawn_bundler_task(
+				&task_manager,
+				client.clone(),
+				transaction_pool.clone(),
+				bundler_config,
+			)
+			.map_err(sc_service::Error::Other)?;
+			Some((user_operation_pool, bundler_config.entry_points.clone()))
+		}
+		None => None,
+	};
+
 	let ethapi_cmd = rpc_config.ethapi.clone();
 	let tracing_requesters =
 		if ethapi_cmd.contains(&EthApiCmd::Debug) || ethapi_cmd.contains(&EthApiCmd::Trace) {
@@ -724,6 +785,7 @@ where
 					overrides: overrides.clone(),
 					fee_history_limit,
 					fee_history_cache: fee_history_cache.clone(),
+					frontier_retained_blocks: rpc_config.frontier_retained_blocks,
 				},
 			)
 		} else {
@@ -751,10 +813,18 @@ where
 		let backend = backend.clone();
 		let ethapi_cmd = ethapi_cmd.clone();
 		let max_past_logs = rpc_config.max_past_logs;
+		let max_block_range = rpc_config.eth_log_max_block_range;
+		let txpool_policy = moonbeam_send_raw_transaction_rpc::TxPoolPolicy {
+			price_bump: rpc_config.txpool_price_bump,
+			max_queued_per_account: rpc_config.txpool_max_queued_per_account,
+		};
+		let retained_blocks = rpc_config.frontier_retained_blocks;
+		let rate_limiter = Arc::new(rpc::rate_limit::RpcRateLimiter::new(&rpc_config));
 		let overrides = overrides.clone();
 		let fee_history_cache = fee_history_cache.clone();
 		let block_data_cache = block_data_cache.clone();
 		let pubsub_notification_sinks = pubsub_notification_sinks.clone();
+		let user_operations = user_operations.clone();
 
 		move |deny_unsafe, subscription_task_executor| {
 			let deps = rpc::FullDeps {
@@ -768,10 +838,14 @@ where
 					fc_db::Backend::KeyValue(b) => Arc::new(b),
 					fc_db::Backend::Sql(b) => Arc::new(b),
 				},
+				log_bloom_index,
 				graph: pool.pool().clone(),
 				pool: pool.clone(),
 				is_authority: collator,
 				max_past_logs,
+				max_block_range,
+				txpool_policy,
+				retained_blocks,
 				fee_history_limit,
 				fee_history_cache: fee_history_cache.clone(),
 				network: network.clone(),
@@ -780,6 +854,8 @@ where
 				block_data_cache: block_data_cache.clone(),
 				overrides: overrides.clone(),
 				forced_parent_hashes: None,
+				rate_limiter: rate_limiter.clone(),
+				user_operations: user_operations.clone(),
 			};
 			if ethapi_cmd.contains(&EthApiCmd::Debug) || ethapi_cmd.contains(&EthApiCmd::Trace) {
 				rpc::create_full(
@@ -1220,10 +1296,43 @@ where
 			overrides: overrides.clone(),
 			fee_history_limit,
 			fee_history_cache: fee_history_cache.clone(),
+			frontier_retained_blocks: rpc_config.frontier_retained_blocks,
 		},
 		sync_service.clone(),
 		pubsub_notification_sinks.clone(),
 	);
+
+	// The SQL frontier backend indexes the logs itself.
+	let log_bloom_index = matches!(frontier_backend, fc_db::Backend::KeyValue(_));
+	if log_bloom_index && config.rpc_addr.is_some() {
+		rpc::spawn_log_bloom_index_task::<_, _, FullBackend>(
+			&task_manager,
+			client.clone(),
+			overrides.clone(),
+		);
+	}
+
+	runtime_metrics::spawn_runtime_metrics_task(
+		&task_manager,
+		client.clone(),
+		overrides.clone(),
+		prometheus_registry.as_ref(),
+	)?;
+
+	let user_operations = match &rpc_config.bundler {
+		Some(bundler_config) => {
+			let user_operation_pool = rpc::spawn_bundler_task(
+				&task_manager,
+				client.clone(),
+				transaction_pool.clone(),
+				bundler_config,
+			)
+			.map_err(sc_service::Error::Other)?;
+			Some((user_operation_pool, bundler_config.entry_points.clone()))
+		}
+		None => None,
+	};
+
 	let ethapi_cmd = rpc_config.ethapi.clone();
 	let tracing_requesters =
 		if ethapi_cmd.contains(&EthApiCmd::Debug) || ethapi_cmd.contains(&EthApiCmd::Trace) {
@@ -1239,6 +1348,7 @@ where
 					overrides: overrides.clone(),
 					fee_history_limit,
 					fee_history_cache: fee_history_cache.clone(),
+					frontier_retained_blocks: rpc_config.frontier_retained_blocks,
 				},
 			)
 		} else {
@@ -1264,10 +1374,18 @@ where
 		let sync = sync_service.clone();
 		let ethapi_cmd = ethapi_cmd.clone();
 		let max_past_logs = rpc_config.max_past_logs;
+		let max_block_range = rpc_config.eth_log_max_block_range;
+		let txpool_policy = moonbeam_send_raw_transaction_rpc::TxPoolPolicy {
+			price_bump: rpc_config.txpool_price_bump,
+			max_queued_per_account: rpc_config.txpool_max_queued_per_account,
+		};
+		let retained_blocks = rpc_config.frontier_retained_blocks;
+		let rate_limiter = Arc::new(rpc::rate_limit::RpcRateLimiter::new(&rpc_config));
 		let overrides = overrides.clone();
 		let fee_history_cache = fee_history_cache.clone();
 		let block_data_cache = block_data_cache.clone();
 		let pubsub_notification_sinks = pubsub_notification_sinks.clone();
+		let user_operations = user_operations.clone();
 
 		move |deny_unsafe, subscription_task_executor| {
 			let deps = rpc::FullDeps {
@@ -1281,10 +1399,14 @@ where
 					fc_db::Backend::KeyValue(b) => Arc::new(b),
 					fc_db::Backend::Sql(b) => Arc::new(b),
 				},
+				log_bloom_index,
 				graph: pool.pool().clone(),
 				pool: pool.clone(),
 				is_authority: collator,
 				max_past_logs,
+				max_block_range,
+				txpool_policy,
+				retained_blocks,
 				fee_history_limit,
 				fee_history_cache: fee_history_cache.clone(),
 				network: network.clone(),
@@ -1293,6 +1415,8 @@ where
 				overrides: overrides.clone(),
 				block_data_cache: block_data_cache.clone(),
 				forced_parent_hashes: None,
+				rate_limiter: rate_limiter.clone(),
+				user_operations: user_operations.clone(),
 			};
 
 			if ethapi_cmd.contains(&EthApiCmd::Debug) || ethapi_cmd.contains(&EthApiCmd::Trace) {
@@ -16,14 +16,15 @@
 
 //! A collection of node-specific RPC extensions and related background tasks.
 
+pub mod rate_limit;
 pub mod tracing;
 
-use std::{sync::Arc, time::Duration};
+use std::{str::FromStr, sync::Arc, time::Duration};
 
 use fp_rpc::EthereumRuntimeRPCApi;
 use sp_block_builder::BlockBuilder;
 
-use crate::client::RuntimeApiCollection;
+use crate::{client::RuntimeApiCollection, frontier_pruning::FrontierPruningWorker};
 use cumulus_primitives_core::ParaId;
 use fc_mapping_sync::{kv::MappingSyncWorker, SyncStrategy};
 use fc_rpc::{
@@ -34,8 +35,11 @@ use fc_rpc_core::types::{CallRequest, FeeHistoryCache, FilterPool};
 use fp_storage::EthereumStorageSchema;
 use futures::StreamExt;
 use jsonrpsee::RpcModule;
-use moonbeam_cli_opt::EthApi as EthApiCmd;
-use moonbeam_core_primitives::{Block, Hash};
+use moonbeam_cli_opt::{BundlerConfig, EthApi as EthApiCmd};
+use moonbeam_core_primitives::{Balance, Block, Hash, Index};
+use moonbeam_logs_rpc::LogBloomIndexWorker;
+use moonbeam_send_raw_transaction_rpc::TxPoolPolicy;
+use moonbeam_user_operations_rpc::{Bundler, UserOperationPool};
 use sc_client_api::{
 	backend::{AuxStore, Backend, StateBackend, StorageProvider},
 	client::BlockchainEvents,
@@ -53,7 +57,7 @@ use sp_api::{CallApiAt, HeaderT, ProvideRuntimeApi};
 use sp_blockchain::{
 	Backend as BlockchainBackend, Error as BlockChainError, HeaderBackend, HeaderMetadata,
 };
-use sp_core::H256;
+use sp_core::{H160, H256};
 use sp_runtime::traits::{BlakeTwo256, Block as BlockT};
 use std::collections::BTreeMap;
 
@@ -113,12 +117,21 @@ pub struct FullDeps<C, P, A: ChainApi, BE> {
 	pub ethapi_cmd: Vec<EthApiCmd>,
 	/// Frontier Backend.
 	pub frontier_backend: Arc<dyn fc_db::BackendReader<Block> + Send + Sync>,
+	/// Whether `eth_getLogs` is served from the log bloom index. It is not maintained for the
+	/// SQL backend, which indexes the logs itself and is queried by Frontier's `eth_getLogs`.
+	pub log_bloom_index: bool,
 	/// Backend.
 	pub backend: Arc<BE>,
 	/// Manual seal command sink
 	pub command_sink: Option<futures::channel::mpsc::Sender<EngineCommand<Hash>>>,
 	/// Maximum number of logs in a query.
 	pub max_past_logs: u32,
+	/// Maximum number of blocks covered by a logs query (0 for unlimited).
+	pub max_block_range: u32,
+	/// Number of recent blocks whose logs are retained, if the frontier mappings are pruned.
+	pub retained_blocks: Option<u32>,
+	/// Replacement and queueing rules of the Ethereum transactions submitted to the pool.
+	pub txpool_policy: TxPoolPolicy,
 	/// Maximum fee history cache size.
 	pub fee_history_limit: u64,
 	/// Fee history cache.
@@ -131,6 +144,10 @@ pub struct FullDeps<C, P, A: ChainApi, BE> {
 	pub block_data_cache: Arc<EthBlockDataCacheTask<Block>>,
 	/// Mandated parent hashes for a given block hash.
 	pub forced_parent_hashes: Option<BTreeMap<H256, H256>>,
+	/// Rate limits of the RPC methods.
+	pub rate_limiter: Arc<rate_limit::RpcRateLimiter>,
+	/// Pool of the ERC-4337 bundler and its supported EntryPoints, if the bundler is enabled.
+	pub user_operations: Option<(Arc<UserOperationPool>, Vec<H160>)>,
 }
 
 pub struct TracingConfig {
@@ -195,10 +212,22 @@ where
 		NetApiServer, Web3, Web3ApiServer,
 	};
 	use manual_xcm_rpc::{ManualXcm, ManualXcmApiServer};
+	use moonbeam_account_info_rpc::{MoonbeamAccountInfo, MoonbeamAccountInfoApiServer};
+	use moonbeam_estimate_gas_rpc::{EthEstimateGas, EthEstimateGasApiServer};
+	use moonbeam_eth_call_rpc::{EthCall, EthCallApiServer};
 	use moonbeam_finality_rpc::{MoonbeamFinality, MoonbeamFinalityApiServer};
+	use moonbeam_hibernation_rpc::{MoonbeamHibernation, MoonbeamHibernationApiServer};
+	use moonbeam_logs_rpc::{
+		EthLogs, EthLogsApiServer, EthLogsConfig, EthLogsPubSub, EthLogsPubSubApiServer,
+	};
 	use moonbeam_rpc_debug::{Debug, DebugServer};
 	use moonbeam_rpc_trace::{Trace, TraceServer};
 	use moonbeam_rpc_txpool::{TxPool, TxPoolServer};
+	use moonbeam_send_raw_transaction_rpc::{
+		EthSendRawTransaction, EthSendRawTransactionApiServer,
+	};
+	use moonbeam_user_operations_rpc::{UserOperations, UserOperationsApiServer};
+	use moonbeam_xcm_events_rpc::{MoonbeamXcmEvents, MoonbeamXcmEventsApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -215,14 +244,20 @@ where
 		ethapi_cmd,
 		command_sink,
 		frontier_backend,
+		log_bloom_index,
 		backend: _,
 		max_past_logs,
+		max_block_range,
+		retained_blocks,
+		txpool_policy,
 		fee_history_limit,
 		fee_history_cache,
 		xcm_senders,
 		overrides,
 		block_data_cache,
 		forced_parent_hashes,
+		rate_limiter,
+		user_operations,
 	} = deps;
 
 	io.merge(System::new(Arc::clone(&client), Arc::clone(&pool), deny_unsafe).into_rpc())?;
@@ -242,7 +277,8 @@ where
 	}
 	let convert_transaction: Option<Never> = None;
 
-	io.merge(
+	let mut eth = RpcModule::new(());
+	eth.merge(
 		Eth::new(
 			Arc::clone(&client),
 			Arc::clone(&pool),
@@ -262,17 +298,57 @@ where
 		.replace_config::<MoonbeamEthConfig<C, BE>>()
 		.into_rpc(),
 	)?;
+	// `eth_estimateGas` is served by `EthEstimateGas`, which supports the state override set,
+	// and `eth_call` by `EthCall`, which supports block overrides. Both rely on a copy of the
+	// Frontier methods to execute the calls.
+	// `eth_sendRawTransaction` is served by `EthSendRawTransaction`, which rejects the transaction
+	// types unsupported by the runtime with an explicit error, and enforces the replacement and
+	// queueing rules of `txpool_policy`.
+	let estimate_gas = EthEstimateGas::<MoonbeamEGA>::new(eth.clone());
+	let call = EthCall::new(client.clone(), frontier_backend.clone(), eth.clone());
+	let send_raw_transaction = EthSendRawTransaction::new(
+		client.clone(),
+		eth.clone(),
+		TxPool::new(client.clone(), graph.clone()),
+		txpool_policy,
+	);
+	eth.remove_method("eth_estimateGas");
+	eth.remove_method("eth_call");
+	eth.remove_method("eth_sendRawTransaction");
+	io.merge(eth)?;
+	io.merge(estimate_gas.into_rpc())?;
+	io.merge(call.into_rpc())?;
+	io.merge(send_raw_transaction.into_rpc())?;
 
 	if let Some(filter_pool) = filter_pool {
+		let mut eth_filter = EthFilter::new(
+			client.clone(),
+			frontier_backend.clone(),
+			fc_rpc::TxPool::new(client.clone(), graph.clone()),
+			filter_pool,
+			500_usize, // max stored filters
+			max_past_logs,
+			block_data_cache,
+		)
+		.into_rpc();
+		// `eth_getLogs` is served by `EthLogs`, which makes use of the log bloom index.
+		if log_bloom_index {
+			eth_filter.remove_method("eth_getLogs");
+		}
+		io.merge(eth_filter)?;
+	}
+
+	if log_bloom_index {
 		io.merge(
-			EthFilter::new(
+			EthLogs::new(
 				client.clone(),
 				frontier_backend.clone(),
-				fc_rpc::TxPool::new(client.clone(), graph.clone()),
-				filter_pool,
-				500_usize, // max stored filters
-				max_past_logs,
-				block_data_cache,
+				overrides.clone(),
+				EthLogsConfig {
+					max_block_range,
+					max_results: max_past_logs,
+					retained_blocks,
+				},
 			)
 			.into_rpc(),
 		)?;
@@ -290,13 +366,25 @@ where
 
 	io.merge(Web3::new(Arc::clone(&client)).into_rpc())?;
 	io.merge(
-		EthPubSub::new(
-			pool,
+		MoonbeamXcmEvents::new(Arc::clone(&client), subscription_task_executor.clone()).into_rpc(),
+	)?;
+	// `eth_subscribe` is served by `EthLogsPubSub`, which supports finalized `logs` subscriptions
+	// and forwards the other subscriptions to the Frontier implementation.
+	let pubsub = EthPubSub::new(
+		pool,
+		Arc::clone(&client),
+		sync.clone(),
+		subscription_task_executor.clone(),
+		overrides.clone(),
+		pubsub_notification_sinks.clone(),
+	)
+	.into_rpc();
+	io.merge(
+		EthLogsPubSub::new(
 			Arc::clone(&client),
-			sync.clone(),
-			subscription_task_executor,
-			overrides,
-			pubsub_notification_sinks.clone(),
+			overrides.clone(),
+			subscription_task_executor.clone(),
+			pubsub,
 		)
 		.into_rpc(),
 	)?;
@@ -304,7 +392,23 @@ where
 		io.merge(TxPool::new(Arc::clone(&client), graph).into_rpc())?;
 	}
 
-	io.merge(MoonbeamFinality::new(client.clone(), frontier_backend.clone()).into_rpc())?;
+	io.merge(
+		MoonbeamFinality::new(
+			client.clone(),
+			frontier_backend.clone(),
+			overrides,
+			subscription_task_executor,
+		)
+		.into_rpc(),
+	)?;
+	io.merge(MoonbeamHibernation::new(client.clone()).into_rpc())?;
+	io.merge(MoonbeamAccountInfo::<Block, _, Index, Balance>::new(client.clone()).into_rpc())?;
+
+	if let Some((user_operation_pool, entry_points)) = user_operations {
+		io.merge(
+			UserOperations::new(client.clone(), user_operation_pool, entry_points).into_rpc(),
+		)?;
+	}
 
 	if let Some(command_sink) = command_sink {
 		io.merge(
@@ -341,7 +445,7 @@ where
 		}
 	}
 
-	Ok(io)
+	Ok(rate_limiter.wrap(io)?)
 }
 
 pub struct SpawnTasksParams<'a, B: BlockT, C, BE> {
@@ -353,6 +457,7 @@ pub struct SpawnTasksParams<'a, B: BlockT, C, BE> {
 	pub overrides: Arc<OverrideHandle<B>>,
 	pub fee_history_limit: u64,
 	pub fee_history_cache: FeeHistoryCache,
+	pub frontier_retained_blocks: Option<u32>,
 }
 
 /// Spawn the tasks that are required to run Moonbeam.
@@ -367,7 +472,7 @@ pub fn spawn_essential_tasks<B, C, BE>(
 ) where
 	C: ProvideRuntimeApi<B> + BlockOf,
 	C: HeaderBackend<B> + HeaderMetadata<B, Error = BlockChainError> + 'static,
-	C: BlockchainEvents<B> + StorageProvider<B, BE>,
+	C: BlockchainEvents<B> + StorageProvider<B, BE> + AuxStore,
 	C: Send + Sync + 'static,
 	C::Api: EthereumRuntimeRPCApi<B>,
 	C::Api: BlockBuilder<B>,
@@ -400,13 +505,22 @@ pub fn spawn_essential_tasks<B, C, BE>(
 			);
 		}
 		fc_db::Backend::Sql(b) => {
+			let b = Arc::new(b);
+			if let Some(retained_blocks) = params.frontier_retained_blocks {
+				params.task_manager.spawn_handle().spawn(
+					"frontier-pruning-worker",
+					Some("frontier"),
+					FrontierPruningWorker::new(params.client.clone(), b.clone(), retained_blocks)
+						.run(),
+				);
+			}
 			params.task_manager.spawn_essential_handle().spawn_blocking(
 				"frontier-mapping-sync-worker",
 				Some("frontier"),
 				fc_mapping_sync::sql::SyncWorker::run(
 					params.client.clone(),
 					params.substrate_backend.clone(),
-					Arc::new(b),
+					b,
 					params.client.import_notification_stream(),
 					fc_mapping_sync::sql::SyncWorkerConfig {
 						read_notification_timeout: Duration::from_secs(10),
@@ -448,3 +562,57 @@ pub fn spawn_essential_tasks<B, C, BE>(
 		),
 	);
 }
+
+/// Spawn the worker maintaining the log bloom index used by `eth_getLogs`. Only needed by the
+/// RPC nodes using the key-value frontier backend.
+pub fn spawn_log_bloom_index_task<B, C, BE>(
+	task_manager: &TaskManager,
+	client: Arc<C>,
+	overrides: Arc<OverrideHandle<B>>,
+) where
+	B: BlockT,
+	B::Header: HeaderT<Number = u32>,
+	C: AuxStore + BlockchainEvents<B> + HeaderBackend<B> + StorageProvider<B, BE>,
+	C: Send + Sync + 'static,
+	BE: Backend<B> + 'static,
+{
+	task_manager.spawn_essential_handle().spawn_blocking(
+		"log-bloom-index-worker",
+		Some("frontier"),
+		LogBloomIndexWorker::<B, C, BE>::new(client, overrides).run(),
+	);
+}
+
+/// Spawn the ERC-4337 bundler, returning the pool of user operations it submits.
+pub fn spawn_bundler_task<C, P>(
+	task_manager: &TaskManager,
+	client: Arc<C>,
+	transaction_pool: Arc<P>,
+	config: &BundlerConfig,
+) -> Result<Arc<UserOperationPool>, String>
+where
+	C: ProvideRuntimeApi<Block> + BlockchainEvents<Block> + Send + Sync + 'static,
+	C::Api: EthereumRuntimeRPCApi<Block> + fp_rpc::ConvertTransactionRuntimeApi<Block>,
+	P: TransactionPool<Block = Block> + 'static,
+{
+	let key = std::fs::read_to_string(&config.key_file)
+		.map_err(|e| format!("Cannot read the bundler key file: {e}"))?;
+	let key = H256::from_str(key.trim().trim_start_matches("0x"))
+		.map_err(|_| "The bundler key file must hold a hex encoded private key".to_string())?;
+
+	let user_operation_pool = Arc::new(UserOperationPool::new());
+	let bundler = Bundler::new(
+		client,
+		transaction_pool,
+		user_operation_pool.clone(),
+		key,
+		config.entry_points.clone(),
+		config.max_bundle_size,
+	)?;
+
+	task_manager
+		.spawn_handle()
+		.spawn("erc4337-bundler", Some("bundler"), bundler.run());
+
+	Ok(user_operation_pool)
+}
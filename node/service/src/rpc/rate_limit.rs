@@ -0,0 +1,256 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Rate limiting of the RPC methods.
+//!
+//! Every limited method is re-registered in front of the original module: the call is first
+//! checked against the per-method token bucket and, for costly methods, against a budget of
+//! cost units shared by all of them, before being forwarded to the original handler.
+
+use super::*;
+
+use jsonrpsee::{
+	core::{server::rpc_module::MethodCallback, traits::ToRpcParams, Error as JsonRpseeError},
+	types::error::{CallError, ErrorObject},
+};
+use moonbeam_cli_opt::RpcMethodLimit;
+use parking_lot::Mutex;
+use serde_json::value::RawValue;
+use std::{collections::HashMap, time::Instant};
+
+/// EIP-1474 "limit exceeded" error code.
+const LIMIT_EXCEEDED_CODE: i32 = -32005;
+
+/// Built-in execution costs of the heavy methods, in order of precedence.
+const DEFAULT_METHOD_COSTS: &[(&str, u32)] = &[
+	("trace_filter", 20),
+	("debug_traceBlockByNumber", 20),
+	("debug_traceBlockByHash", 20),
+	("trace_*", 10),
+	("debug_*", 10),
+	("eth_getLogs", 5),
+];
+
+/// A bucket refilled continuously at `rate` tokens per second, holding at most `capacity`
+/// tokens.
+struct TokenBucket {
+	rate: f64,
+	capacity: f64,
+	tokens: f64,
+	last_refill: Instant,
+}
+
+impl TokenBucket {
+	fn new(rate: u32, capacity: u32) -> Self {
+		Self {
+			rate: rate as f64,
+			capacity: capacity as f64,
+			tokens: capacity as f64,
+			last_refill: Instant::now(),
+		}
+	}
+
+	fn try_take(&mut self, amount: u32) -> bool {
+		let now = Instant::now();
+		let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+		self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+		self.last_refill = now;
+
+		if self.tokens < amount as f64 {
+			return false;
+		}
+		self.tokens -= amount as f64;
+		true
+	}
+}
+
+/// Limits enforced on the methods of a node's RPC module.
+pub struct RpcRateLimiter {
+	rate_limits: Vec<RpcMethodLimit>,
+	costs: Vec<RpcMethodLimit>,
+	method_buckets: Mutex<HashMap<&'static str, TokenBucket>>,
+	heavy_budget: Option<Mutex<TokenBucket>>,
+}
+
+impl RpcRateLimiter {
+	pub fn new(rpc_config: &moonbeam_cli_opt::RpcConfig) -> Self {
+		let costs: Vec<RpcMethodLimit> = rpc_config
+			.rpc_method_costs
+			.iter()
+			.cloned()
+			.chain(
+				DEFAULT_METHOD_COSTS
+					.iter()
+					.map(|(method, value)| RpcMethodLimit {
+						method: method.to_string(),
+						value: *value,
+					}),
+			)
+			.collect();
+
+		// A budget below the cost of a method would reject that method forever, so the budget
+		// can always hold at least one call of the costliest method.
+		let heavy_budget = rpc_config.rpc_heavy_budget;
+		let heavy_capacity = costs
+			.iter()
+			.map(|cost| cost.value)
+			.max()
+			.unwrap_or_default()
+			.max(heavy_budget);
+
+		Self {
+			rate_limits: rpc_config.rpc_method_rate_limits.clone(),
+			costs,
+			method_buckets: Mutex::new(HashMap::new()),
+			heavy_budget: (heavy_budget > 0)
+				.then(|| Mutex::new(TokenBucket::new(heavy_budget, heavy_capacity))),
+		}
+	}
+
+	fn rate_limit(&self, method: &str) -> Option<u32> {
+		self.rate_limits
+			.iter()
+			.find(|limit| limit.matches(method))
+			.map(|limit| limit.value)
+	}
+
+	fn cost(&self, method: &str) -> Option<u32> {
+		self.heavy_budget.as_ref()?;
+		self.costs
+			.iter()
+			.find(|cost| cost.matches(method))
+			.map(|cost| cost.value)
+	}
+
+	fn is_limited(&self, method: &str) -> bool {
+		self.rate_limit(method).is_some() || self.cost(method).is_some()
+	}
+
+	/// Accounts for a call to `method`, failing if any of its limits is exhausted.
+	fn acquire(&self, method: &'static str) -> Result<(), JsonRpseeError> {
+		if let Some(rate) = self.rate_limit(method) {
+			let mut buckets = self.method_buckets.lock();
+			let bucket = buckets
+				.entry(method)
+				.or_insert_with(|| TokenBucket::new(rate, rate));
+			if !bucket.try_take(1) {
+				return Err(limit_exceeded("rate limit exceeded", method));
+			}
+		}
+
+		if let (Some(cost), Some(budget)) = (self.cost(method), self.heavy_budget.as_ref()) {
+			if !budget.lock().try_take(cost) {
+				return Err(limit_exceeded("execution budget exceeded", method));
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Puts the limited method calls of `module` behind this limiter.
+	pub fn wrap(self: Arc<Self>, module: RpcModule<()>) -> Result<RpcModule<()>, JsonRpseeError> {
+		let limited: Vec<&'static str> = module
+			.method_names()
+			.filter(|method| {
+				matches!(
+					module.method(method),
+					Some(MethodCallback::Sync(_) | MethodCallback::Async(_))
+				) && self.is_limited(method)
+			})
+			.collect();
+		if limited.is_empty() {
+			return Ok(module);
+		}
+
+		let inner = Arc::new(module.clone());
+		let mut unlimited = module;
+		for method in &limited {
+			unlimited.remove_method(method);
+		}
+
+		let mut io = RpcModule::new(());
+		io.merge(unlimited)?;
+		for method in limited {
+			let inner = inner.clone();
+			let limiter = self.clone();
+			io.register_async_method(method, move |params, _| {
+				let inner = inner.clone();
+				let limiter = limiter.clone();
+				async move {
+					limiter.acquire(method)?;
+					inner
+						.call::<_, serde_json::Value>(
+							method,
+							RawParams(params.as_str().map(str::to_owned)),
+						)
+						.await
+				}
+			})?;
+		}
+
+		Ok(io)
+	}
+}
+
+/// Forwards the raw parameters of a call untouched.
+struct RawParams(Option<String>);
+
+impl ToRpcParams for RawParams {
+	fn to_rpc_params(self) -> Result<Option<Box<RawValue>>, serde_json::Error> {
+		self.0.map(RawValue::from_string).transpose()
+	}
+}
+
+fn limit_exceeded(message: &str, method: &str) -> JsonRpseeError {
+	JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+		LIMIT_EXCEEDED_CODE,
+		message,
+		Some(method),
+	)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn token_bucket_rejects_calls_above_rate() {
+		let mut bucket = TokenBucket::new(3, 3);
+		assert!(bucket.try_take(2));
+		assert!(bucket.try_take(1));
+		assert!(!bucket.try_take(1));
+	}
+
+	#[test]
+	fn token_bucket_capacity_can_exceed_rate() {
+		// A budget of 5 units per second must still let a call costing 20 units through.
+		let mut bucket = TokenBucket::new(5, 20);
+		assert!(bucket.try_take(20));
+		assert!(!bucket.try_take(20));
+	}
+
+	#[test]
+	fn method_limit_matches_prefixes() {
+		let limit: RpcMethodLimit = "debug_*=5".parse().unwrap();
+		assert!(limit.matches("debug_traceTransaction"));
+		assert!(!limit.matches("trace_filter"));
+
+		let limit: RpcMethodLimit = "eth_getLogs=2".parse().unwrap();
+		assert!(limit.matches("eth_getLogs"));
+		assert!(!limit.matches("eth_getLogsX"));
+		assert!("eth_getLogs".parse::<RpcMethodLimit>().is_err());
+	}
+}
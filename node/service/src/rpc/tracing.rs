@@ -16,10 +16,15 @@
 
 use super::*;
 
+use moonbeam_client_evm_tracing::{
+	overrides::TracingRuntimeSource,
+	permits::{RequestKind, TracingPermits},
+};
 use moonbeam_rpc_debug::{DebugHandler, DebugRequester};
-use moonbeam_rpc_trace::{CacheRequester as TraceFilterCacheRequester, CacheTask};
+use moonbeam_rpc_trace::{
+	CacheRequester as TraceFilterCacheRequester, CacheTask, TraceStoreConfig,
+};
 use substrate_prometheus_endpoint::Registry as PrometheusRegistry;
-use tokio::sync::Semaphore;
 
 #[derive(Clone)]
 pub struct RpcRequesters {
@@ -46,7 +51,31 @@ where
 	BE: Backend<B> + 'static,
 	BE::State: StateBackend<BlakeTwo256>,
 {
-	let permit_pool = Arc::new(Semaphore::new(rpc_config.ethapi_max_permits as usize));
+	let max_permits = rpc_config.ethapi_max_permits as usize;
+	let mut kind_limits = vec![(
+		RequestKind::Filter,
+		rpc_config
+			.ethapi_max_filter_permits
+			.map(|limit| limit as usize)
+			.unwrap_or(max_permits / 2)
+			.max(1),
+	)];
+	if let Some(limit) = rpc_config.ethapi_max_block_permits {
+		kind_limits.push((RequestKind::Block, (limit as usize).max(1)));
+	}
+	let permit_pool = TracingPermits::new(max_permits, &kind_limits);
+
+	// Missing tracing runtimes can only be staged if both directories are known.
+	let tracing_runtimes = rpc_config
+		.ethapi_tracing_runtimes_dir
+		.clone()
+		.zip(rpc_config.wasm_runtime_overrides.clone())
+		.map(|(source_dir, overrides_dir)| {
+			Arc::new(TracingRuntimeSource {
+				source_dir,
+				overrides_dir,
+			})
+		});
 
 	let (trace_filter_task, trace_filter_requester) =
 		if rpc_config.ethapi.contains(&EthApiCmd::Trace) {
@@ -54,8 +83,14 @@ where
 				Arc::clone(&params.client),
 				Arc::clone(&params.substrate_backend),
 				Duration::from_secs(rpc_config.ethapi_trace_cache_duration),
+				TraceStoreConfig {
+					memory_budget: rpc_config.ethapi_trace_cache_memory,
+					disk_path: rpc_config.ethapi_trace_cache_disk_path.clone(),
+					disk_budget: rpc_config.ethapi_trace_cache_disk_budget,
+				},
 				Arc::clone(&permit_pool),
 				Arc::clone(&params.overrides),
+				tracing_runtimes.clone(),
 				prometheus,
 			);
 			(Some(trace_filter_task), Some(trace_filter_requester))
@@ -74,6 +109,7 @@ where
 			Arc::clone(&permit_pool),
 			Arc::clone(&params.overrides),
 			rpc_config.tracing_raw_max_memory_usage,
+			tracing_runtimes,
 		);
 		(Some(debug_task), Some(debug_requester))
 	} else {
@@ -0,0 +1,308 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Prometheus metrics about the runtime activity of the chain: direct calls to the precompiles,
+//! the XCMP queues depth, the outcome of GMP precompile calls and the resources used by blocks.
+
+use std::sync::Arc;
+
+use fc_rpc::{OverrideHandle, StorageOverride};
+use futures::StreamExt;
+use moonbeam_core_primitives::{Block, Hash};
+use parity_scale_codec::Decode;
+use sc_client_api::{backend::Backend, client::BlockchainEvents, StorageProvider};
+use sc_service::TaskManager;
+use sp_blockchain::HeaderBackend;
+use sp_core::{blake2_128, storage::StorageKey, twox_128, H160, U256};
+use substrate_prometheus_endpoint::{
+	register, CounterVec, Gauge, GaugeVec, Opts, PrometheusError, Registry, U64,
+};
+
+/// Address of the GMP precompile.
+const GMP_PRECOMPILE_ADDRESS: u64 = 2070;
+
+/// Dummy code stored by the runtimes at the address of each of their precompiles.
+const PRECOMPILE_CODE: [u8; 5] = [0x60, 0x00, 0x60, 0x00, 0xfd];
+
+/// Address prefixes of the precompile sets, labelled by kind to keep the number of series
+/// bounded.
+const PRECOMPILE_SET_PREFIXES: &[([u8; 4], &str)] = &[
+	([0xff, 0xff, 0xff, 0xff], "foreign_asset"),
+	([0xff, 0xff, 0xff, 0xfe], "local_asset"),
+	([0xff, 0xff, 0xff, 0xfd], "nft_collection"),
+	([0xff, 0xff, 0xff, 0xfc], "price_feed"),
+];
+
+/// Mirror of `cumulus_pallet_xcmp_queue::InboundChannelDetails`, whose fields are private.
+#[derive(Decode)]
+struct InboundChannelDetails {
+	_sender: u32,
+	_state: u8,
+	message_metadata: Vec<(u32, u8)>,
+}
+
+/// Mirror of `cumulus_pallet_xcmp_queue::OutboundChannelDetails`, whose fields are private.
+#[derive(Decode)]
+struct OutboundChannelDetails {
+	_recipient: u32,
+	_state: u8,
+	_signals_exist: bool,
+	first_index: u16,
+	last_index: u16,
+}
+
+/// Mirror of `sp_weights::Weight`, whose fields are private.
+#[derive(Decode)]
+struct Weight {
+	#[codec(compact)]
+	ref_time: u64,
+	#[codec(compact)]
+	proof_size: u64,
+}
+
+/// Mirror of `frame_system::ConsumedWeight`.
+#[derive(Decode)]
+struct ConsumedWeight {
+	normal: Weight,
+	_operational: Weight,
+	_mandatory: Weight,
+}
+
+pub struct RuntimeMetrics {
+	precompile_calls: CounterVec<U64>,
+	precompile_gas: CounterVec<U64>,
+	xcmp_queue_depth: GaugeVec<U64>,
+	gmp_calls: CounterVec<U64>,
+	block_gas_used: Gauge<U64>,
+	block_normal_weight: GaugeVec<U64>,
+}
+
+impl RuntimeMetrics {
+	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			precompile_calls: register(
+				CounterVec::new(
+					Opts::new(
+						"precompile_calls_total",
+						"Number of transactions calling a precompile",
+					),
+					&["precompile"],
+				)?,
+				registry,
+			)?,
+			precompile_gas: register(
+				CounterVec::new(
+					Opts::new(
+						"precompile_gas_total",
+						"Gas used by the transactions calling a precompile",
+					),
+					&["precompile"],
+				)?,
+				registry,
+			)?,
+			xcmp_queue_depth: register(
+				GaugeVec::new(
+					Opts::new(
+						"xcmp_queue_depth",
+						"Number of XCMP messages (inbound) or pages (outbound) waiting in the queue",
+					),
+					&["direction"],
+				)?,
+				registry,
+			)?,
+			gmp_calls: register(
+				CounterVec::new(
+					Opts::new(
+						"gmp_precompile_calls_total",
+						"Number of transactions calling the GMP precompile, by outcome",
+					),
+					&["result"],
+				)?,
+				registry,
+			)?,
+			block_gas_used: register(
+				Gauge::new("block_gas_used", "Gas used by the ethereum transactions of the block")?,
+				registry,
+			)?,
+			block_normal_weight: register(
+				GaugeVec::new(
+					Opts::new(
+						"block_normal_weight",
+						"Weight consumed by the normal dispatch class of the block, by dimension",
+					),
+					&["dimension"],
+				)?,
+				registry,
+			)?,
+		})
+	}
+
+	fn note_block<C, BE>(&self, client: &C, overrides: &OverrideHandle<Block>, hash: Hash)
+	where
+		C: HeaderBackend<Block> + StorageProvider<Block, BE>,
+		BE: Backend<Block>,
+	{
+		let schema = fc_storage::onchain_storage_schema::<Block, C, BE>(client, hash);
+		let storage_override: &dyn StorageOverride<Block> = overrides
+			.schemas
+			.get(&schema)
+			.unwrap_or(&overrides.fallback)
+			.as_ref();
+
+		if let (Some(statuses), Some(receipts)) = (
+			storage_override.current_transaction_statuses(hash),
+			storage_override.current_receipts(hash),
+		) {
+			let mut cumulative_gas = U256::zero();
+			for (status, receipt) in statuses.iter().zip(receipts) {
+				let receipt = match receipt {
+					ethereum::ReceiptV3::Legacy(d)
+					| ethereum::ReceiptV3::EIP2930(d)
+					| ethereum::ReceiptV3::EIP1559(d) => d,
+				};
+				let used_gas = receipt.used_gas.saturating_sub(cumulative_gas);
+				cumulative_gas = receipt.used_gas;
+
+				let Some(label) = status.to.and_then(|to| precompile_label(client, hash, to))
+				else {
+					continue;
+				};
+				self.precompile_calls.with_label_values(&[&label]).inc();
+				self.precompile_gas
+					.with_label_values(&[&label])
+					.inc_by(used_gas.low_u64());
+
+				if status.to == Some(H160::from_low_u64_be(GMP_PRECOMPILE_ADDRESS)) {
+					let result = if receipt.status_code == 1 {
+						"success"
+					} else {
+						"failure"
+					};
+					self.gmp_calls.with_label_values(&[result]).inc();
+				}
+			}
+			self.block_gas_used.set(cumulative_gas.low_u64());
+		}
+
+		// Storage-heavy blocks can run out of proof size well before ref time.
+		if let Some(weight) =
+			read_storage::<_, _, ConsumedWeight>(client, hash, b"System", b"BlockWeight")
+		{
+			self.block_normal_weight
+				.with_label_values(&["ref_time"])
+				.set(weight.normal.ref_time);
+			self.block_normal_weight
+				.with_label_values(&["proof_size"])
+				.set(weight.normal.proof_size);
+		}
+
+		let inbound: Vec<InboundChannelDetails> =
+			read_storage(client, hash, b"XcmpQueue", b"InboundXcmpStatus").unwrap_or_default();
+		self.xcmp_queue_depth.with_label_values(&["inbound"]).set(
+			inbound
+				.iter()
+				.map(|channel| channel.message_metadata.len() as u64)
+				.sum(),
+		);
+
+		let outbound: Vec<OutboundChannelDetails> =
+			read_storage(client, hash, b"XcmpQueue", b"OutboundXcmpStatus").unwrap_or_default();
+		self.xcmp_queue_depth.with_label_values(&["outbound"]).set(
+			outbound
+				.iter()
+				.map(|channel| channel.last_index.saturating_sub(channel.first_index) as u64)
+				.sum(),
+		);
+	}
+}
+
+/// Label of the precompile at `address`, if any. Precompile sets are grouped by kind, and
+/// other addresses are only labelled if the runtime registered a precompile there, so that the
+/// number of series stays bounded.
+fn precompile_label<C, BE>(client: &C, hash: Hash, address: H160) -> Option<String>
+where
+	C: StorageProvider<Block, BE>,
+	BE: Backend<Block>,
+{
+	if let Some((_, label)) = PRECOMPILE_SET_PREFIXES
+		.iter()
+		.find(|(prefix, _)| address.as_bytes().starts_with(prefix))
+	{
+		return Some(label.to_string());
+	}
+	// Precompiles outside of the sets all live below address 0x10000.
+	if address.as_bytes()[..18].iter().any(|byte| *byte != 0) {
+		return None;
+	}
+
+	let key = [
+		&twox_128(b"EVM")[..],
+		&twox_128(b"AccountCodes"),
+		&blake2_128(address.as_bytes()),
+		address.as_bytes(),
+	]
+	.concat();
+	let code = client.storage(hash, &StorageKey(key)).ok()??;
+	let code = Vec::<u8>::decode(&mut &code.0[..]).ok()?;
+
+	(code == PRECOMPILE_CODE).then(|| format!("{:?}", address))
+}
+
+fn read_storage<C, BE, T: Decode>(
+	client: &C,
+	hash: Hash,
+	pallet: &[u8],
+	storage: &[u8],
+) -> Option<T>
+where
+	C: StorageProvider<Block, BE>,
+	BE: Backend<Block>,
+{
+	let key = [twox_128(pallet), twox_128(storage)].concat();
+	let data = client.storage(hash, &StorageKey(key)).ok()??;
+	T::decode(&mut &data.0[..]).ok()
+}
+
+/// Spawn the task updating the runtime metrics on every new best block.
+pub fn spawn_runtime_metrics_task<C, BE>(
+	task_manager: &TaskManager,
+	client: Arc<C>,
+	overrides: Arc<OverrideHandle<Block>>,
+	registry: Option<&Registry>,
+) -> Result<(), PrometheusError>
+where
+	C: HeaderBackend<Block> + StorageProvider<Block, BE> + BlockchainEvents<Block>,
+	C: Send + Sync + 'static,
+	BE: Backend<Block> + 'static,
+{
+	let Some(registry) = registry else {
+		return Ok(());
+	};
+	let metrics = RuntimeMetrics::register(registry)?;
+
+	task_manager
+		.spawn_handle()
+		.spawn("runtime-metrics", Some("metrics"), async move {
+			let mut notifications = client.import_notification_stream();
+			while let Some(notification) = notifications.next().await {
+				if notification.is_new_best {
+					metrics.note_block(client.as_ref(), &overrides, notification.hash);
+				}
+			}
+		});
+
+	Ok(())
+}
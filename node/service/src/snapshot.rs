@@ -0,0 +1,264 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Snapshot archives of the parachain and frontier databases of a node.
+//!
+//! A snapshot is a zstd compressed tar archive of the database directories of a stopped node,
+//! starting with a manifest recording the chain and the finalized block at the time of the
+//! export. Restoring it on another machine provisions a node (e.g. an additional RPC replica)
+//! which resumes syncing from that block instead of from genesis.
+
+use crate::{Block, BlockNumber};
+use fc_db::DatabaseSource;
+use sc_service::Configuration;
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use std::{
+	fs::File,
+	io::Read,
+	path::{Path, PathBuf},
+};
+
+/// Name of the manifest entry, the first one of the archive.
+const MANIFEST_ENTRY: &str = "snapshot.json";
+/// Name of the frontier databases directory, relative to the chain directory.
+const FRONTIER_DIR: &str = "frontier";
+/// Compression level of the archive.
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// Description of the content of a snapshot archive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotManifest {
+	/// Id of the chain spec.
+	pub chain_id: String,
+	pub genesis_hash: H256,
+	/// Finalized block when the snapshot was exported.
+	pub finalized_number: BlockNumber,
+	pub finalized_hash: H256,
+	/// Kind of parachain database (`rocksdb` or `paritydb`).
+	pub database: String,
+	/// Path of the parachain database, relative to the chain directory.
+	pub database_path: PathBuf,
+}
+
+impl SnapshotManifest {
+	/// Describe the databases of the node, whose client is given.
+	pub fn new<C>(config: &Configuration, client: &C) -> Result<Self, String>
+	where
+		C: HeaderBackend<Block>,
+	{
+		let (database, path) = database_dir(&config.database)?;
+		let info = client.info();
+
+		Ok(Self {
+			chain_id: config.chain_spec.id().to_string(),
+			genesis_hash: info.genesis_hash,
+			finalized_number: info.finalized_number,
+			finalized_hash: info.finalized_hash,
+			database: database.to_string(),
+			database_path: path
+				.strip_prefix(chain_dir(config))
+				.map_err(|_| format!("Database {} is not in the chain directory", path.display()))?
+				.to_path_buf(),
+		})
+	}
+
+	/// Check that the restored databases, whose client is given, match the manifest.
+	pub fn verify<C>(&self, client: &C) -> Result<(), String>
+	where
+		C: HeaderBackend<Block>,
+	{
+		if client.info().genesis_hash != self.genesis_hash {
+			return Err(format!(
+				"Restored database has genesis {:?}, expected {:?}",
+				client.info().genesis_hash,
+				self.genesis_hash
+			));
+		}
+
+		let finalized_hash = client
+			.hash(self.finalized_number)
+			.map_err(|e| format!("Failed to fetch restored finalized block : {:?}", e))?;
+		if finalized_hash != Some(self.finalized_hash) {
+			return Err(format!(
+				"Restored database is missing finalized block {} ({:?})",
+				self.finalized_number, self.finalized_hash
+			));
+		}
+
+		Ok(())
+	}
+}
+
+/// Archive the parachain and frontier databases of the node into `output`. The node must be
+/// stopped and the databases closed.
+pub fn export_snapshot(
+	config: &Configuration,
+	manifest: &SnapshotManifest,
+	output: &Path,
+) -> Result<(), String> {
+	let chain_dir = chain_dir(config);
+	let write_error = |e| format!("Failed to write {} : {:?}", output.display(), e);
+
+	let file = File::create(output).map_err(write_error)?;
+	let encoder = zstd::Encoder::new(file, COMPRESSION_LEVEL)
+		.map_err(write_error)?
+		.auto_finish();
+	let mut archive = tar::Builder::new(encoder);
+
+	let manifest_bytes = serde_json::to_vec_pretty(manifest)
+		.map_err(|e| format!("Failed to encode snapshot manifest : {:?}", e))?;
+	let mut header = tar::Header::new_gnu();
+	header.set_size(manifest_bytes.len() as u64);
+	header.set_mode(0o644);
+	header.set_cksum();
+	archive
+		.append_data(&mut header, MANIFEST_ENTRY, manifest_bytes.as_slice())
+		.map_err(write_error)?;
+
+	archive
+		.append_dir_all(
+			&manifest.database_path,
+			chain_dir.join(&manifest.database_path),
+		)
+		.map_err(write_error)?;
+	let frontier_dir = chain_dir.join(FRONTIER_DIR);
+	if frontier_dir.exists() {
+		archive
+			.append_dir_all(FRONTIER_DIR, frontier_dir)
+			.map_err(write_error)?;
+	}
+
+	archive.into_inner().map_err(write_error)?;
+
+	log::info!(
+		target: "snapshot",
+		"Exported snapshot of block {} ({:?}) into {}",
+		manifest.finalized_number,
+		manifest.finalized_hash,
+		output.display()
+	);
+
+	Ok(())
+}
+
+/// Restore the parachain and frontier databases of the snapshot `input`, returning its
+/// manifest. The databases of the node must not exist yet.
+pub fn import_snapshot(config: &Configuration, input: &Path) -> Result<SnapshotManifest, String> {
+	let chain_dir = chain_dir(config);
+	let read_error = |e| format!("Failed to read {} : {:?}", input.display(), e);
+
+	let file = File::open(input).map_err(read_error)?;
+	let decoder = zstd::Decoder::new(file).map_err(read_error)?;
+	let mut archive = tar::Archive::new(decoder);
+	let mut entries = archive.entries().map_err(read_error)?;
+
+	let mut manifest_entry = entries
+		.next()
+		.ok_or_else(|| format!("{} is empty", input.display()))?
+		.map_err(read_error)?;
+	if manifest_entry.path().map_err(read_error)?.as_ref() != Path::new(MANIFEST_ENTRY) {
+		return Err(format!("{} is not a snapshot", input.display()));
+	}
+	let mut manifest_bytes = Vec::new();
+	manifest_entry
+		.read_to_end(&mut manifest_bytes)
+		.map_err(read_error)?;
+	let manifest: SnapshotManifest = serde_json::from_slice(&manifest_bytes)
+		.map_err(|e| format!("Invalid snapshot manifest : {:?}", e))?;
+
+	if manifest.chain_id != config.chain_spec.id() {
+		return Err(format!(
+			"Snapshot is for chain {}, not {}",
+			manifest.chain_id,
+			config.chain_spec.id()
+		));
+	}
+	let (database, path) = match &config.database {
+		// The restored database is picked by the automatic detection of the node.
+		DatabaseSource::Auto {
+			rocksdb_path,
+			paritydb_path,
+			..
+		} => match manifest.database.as_str() {
+			"rocksdb" => ("rocksdb", rocksdb_path.as_path()),
+			_ => ("paritydb", paritydb_path.as_path()),
+		},
+		source => database_dir(source)?,
+	};
+	if manifest.database != database || chain_dir.join(&manifest.database_path) != path {
+		return Err(format!(
+			"Snapshot contains a {} database at {}, the node uses a {} database at {}",
+			manifest.database,
+			chain_dir.join(&manifest.database_path).display(),
+			database,
+			path.display()
+		));
+	}
+	for dir in [path.to_path_buf(), chain_dir.join(FRONTIER_DIR)] {
+		if dir.exists() {
+			return Err(format!(
+				"{} already exists, purge the chain before importing a snapshot",
+				dir.display()
+			));
+		}
+	}
+
+	for entry in entries {
+		let mut entry = entry.map_err(read_error)?;
+		// Entries escaping the chain directory are rejected by `unpack_in`.
+		if !entry.unpack_in(&chain_dir).map_err(read_error)? {
+			return Err(format!("{} contains an invalid path", input.display()));
+		}
+	}
+
+	log::info!(
+		target: "snapshot",
+		"Imported snapshot of block {} ({:?}) into {}",
+		manifest.finalized_number,
+		manifest.finalized_hash,
+		chain_dir.display()
+	);
+
+	Ok(manifest)
+}
+
+/// Directory of the databases of the chain.
+fn chain_dir(config: &Configuration) -> PathBuf {
+	config.base_path.config_dir(config.chain_spec.id())
+}
+
+/// Kind and path of the parachain database.
+fn database_dir(source: &DatabaseSource) -> Result<(&'static str, &Path), String> {
+	match source {
+		DatabaseSource::RocksDb { path, .. } => Ok(("rocksdb", path)),
+		DatabaseSource::ParityDb { path } => Ok(("paritydb", path)),
+		DatabaseSource::Auto {
+			rocksdb_path,
+			paritydb_path,
+			..
+		} => {
+			if rocksdb_path.exists() {
+				Ok(("rocksdb", rocksdb_path))
+			} else {
+				Ok(("paritydb", paritydb_path))
+			}
+		}
+		_ => Err(format!("Cannot snapshot `{:?}` database", source)),
+	}
+}
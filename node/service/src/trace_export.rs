@@ -0,0 +1,381 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Exports the Ethereum traces of a range of canonical blocks into a file.
+//!
+//! The blocks are replayed with the tracing runtimes, as for `trace_filter`, which allows to
+//! build trace datasets offline instead of querying the RPC endpoints of a live node.
+
+use crate::{Block, BlockNumber};
+use arrow_array::{
+	builder::{
+		ArrayBuilder, BinaryBuilder, ListBuilder, StringBuilder, UInt32Builder, UInt64Builder,
+	},
+	ArrayRef, RecordBatch,
+};
+use arrow_schema::{DataType, Field, Schema};
+use fp_rpc::EthereumRuntimeRPCApi;
+use moonbeam_cli_opt::TraceExportFormat;
+use moonbeam_client_evm_tracing::types::{
+	block::{
+		TransactionTrace, TransactionTraceAction, TransactionTraceOutput, TransactionTraceResult,
+	},
+	CallType,
+};
+use moonbeam_rpc_primitives_debug::DebugRuntimeApi;
+use moonbeam_rpc_trace::CacheTask;
+use parquet::{arrow::ArrowWriter, basic::Compression, file::properties::WriterProperties};
+use sc_client_api::backend::{Backend, StateBackend, StorageProvider};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_block_builder::BlockBuilder;
+use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_core::{H160, U256};
+use sp_runtime::traits::BlakeTwo256;
+use std::{
+	fs::File,
+	io::{BufWriter, Write},
+	path::Path,
+	sync::Arc,
+};
+
+/// Number of blocks between two progress logs.
+const LOG_INTERVAL: BlockNumber = 1_000;
+/// Number of traces written at once in a parquet row group.
+const ROW_GROUP_SIZE: usize = 100_000;
+
+/// Trace the canonical blocks `from..=to`, `to` defaulting to the best block, and write their
+/// traces into `output`.
+pub fn export_traces<C, BE>(
+	client: Arc<C>,
+	backend: Arc<BE>,
+	from: BlockNumber,
+	to: Option<BlockNumber>,
+	format: TraceExportFormat,
+	output: &Path,
+) -> Result<(), String>
+where
+	BE: Backend<Block> + 'static,
+	BE::State: StateBackend<BlakeTwo256>,
+	C: ProvideRuntimeApi<Block> + StorageProvider<Block, BE>,
+	C: HeaderMetadata<Block, Error = BlockChainError> + HeaderBackend<Block>,
+	C: Send + Sync + 'static,
+	C::Api: BlockBuilder<Block>,
+	C::Api: DebugRuntimeApi<Block>,
+	C::Api: EthereumRuntimeRPCApi<Block>,
+	C::Api: ApiExt<Block>,
+{
+	let best_number = client.info().best_number;
+	let to = to.unwrap_or(best_number);
+	if from == 0 || from > to {
+		return Err(format!("Invalid block range {}..={}", from, to));
+	}
+	if to > best_number {
+		return Err(format!(
+			"Block {} is above the best block ({})",
+			to, best_number
+		));
+	}
+
+	let overrides = crate::rpc::overrides_handle(client.clone());
+	let file = File::create(output)
+		.map_err(|e| format!("Failed to create {} : {:?}", output.display(), e))?;
+	let mut writer = TraceWriter::new(format, file)?;
+
+	let mut trace_count = 0usize;
+	for number in from..=to {
+		let hash = client
+			.hash(number)
+			.map_err(|e| format!("Failed to fetch hash of block {} : {:?}", number, e))?
+			.ok_or_else(|| format!("Block {} not found", number))?;
+
+		let traces = CacheTask::<Block, C, BE>::cache_block(
+			client.clone(),
+			backend.clone(),
+			hash,
+			overrides.clone(),
+			None,
+		)
+		.map_err(|e| format!("Failed to trace block {} : {}", number, e))?;
+		trace_count += traces.len();
+		writer.write(traces)?;
+
+		if (number - from + 1) % LOG_INTERVAL == 0 {
+			log::info!(
+				target: "trace-export",
+				"Traced blocks {}..={} ({} remaining)",
+				from,
+				number,
+				to - number
+			);
+		}
+	}
+
+	writer.finish()?;
+
+	log::info!(
+		target: "trace-export",
+		"Exported {} traces of blocks {}..={} into {}",
+		trace_count,
+		from,
+		to,
+		output.display()
+	);
+
+	Ok(())
+}
+
+enum TraceWriter {
+	Json(BufWriter<File>),
+	Parquet(ArrowWriter<File>, TraceColumns),
+}
+
+impl TraceWriter {
+	fn new(format: TraceExportFormat, file: File) -> Result<Self, String> {
+		Ok(match format {
+			TraceExportFormat::Json => Self::Json(BufWriter::new(file)),
+			TraceExportFormat::Parquet => {
+				let properties = WriterProperties::builder()
+					.set_compression(Compression::SNAPPY)
+					.build();
+				let writer = ArrowWriter::try_new(file, Arc::new(trace_schema()), Some(properties))
+					.map_err(|e| format!("Failed to create parquet writer : {:?}", e))?;
+				Self::Parquet(writer, TraceColumns::default())
+			}
+		})
+	}
+
+	fn write(&mut self, traces: Vec<TransactionTrace>) -> Result<(), String> {
+		match self {
+			Self::Json(writer) => {
+				for trace in traces {
+					serde_json::to_writer(&mut *writer, &trace)
+						.map_err(|e| format!("Failed to write trace : {:?}", e))?;
+					writeln!(writer).map_err(|e| format!("Failed to write trace : {:?}", e))?;
+				}
+			}
+			Self::Parquet(writer, columns) => {
+				for trace in traces {
+					columns.push(trace);
+				}
+				if columns.len() >= ROW_GROUP_SIZE {
+					writer
+						.write(&columns.finish()?)
+						.map_err(|e| format!("Failed to write traces : {:?}", e))?;
+				}
+			}
+		}
+		Ok(())
+	}
+
+	fn finish(self) -> Result<(), String> {
+		match self {
+			Self::Json(mut writer) => writer
+				.flush()
+				.map_err(|e| format!("Failed to write traces : {:?}", e)),
+			Self::Parquet(mut writer, mut columns) => {
+				if columns.len() > 0 {
+					writer
+						.write(&columns.finish()?)
+						.map_err(|e| format!("Failed to write traces : {:?}", e))?;
+				}
+				writer
+					.close()
+					.map(|_| ())
+					.map_err(|e| format!("Failed to write traces : {:?}", e))
+			}
+		}
+	}
+}
+
+/// Schema of the parquet export, one row per call frame.
+fn trace_schema() -> Schema {
+	let trace_address = Field::new("item", DataType::UInt32, true);
+
+	Schema::new(vec![
+		Field::new("block_number", DataType::UInt32, false),
+		Field::new("block_hash", DataType::Utf8, false),
+		Field::new("transaction_hash", DataType::Utf8, false),
+		Field::new("transaction_position", DataType::UInt32, false),
+		Field::new(
+			"trace_address",
+			DataType::List(Arc::new(trace_address)),
+			false,
+		),
+		Field::new("subtraces", DataType::UInt32, false),
+		Field::new("type", DataType::Utf8, false),
+		Field::new("call_type", DataType::Utf8, true),
+		Field::new("from", DataType::Utf8, true),
+		Field::new("to", DataType::Utf8, true),
+		Field::new("value", DataType::Utf8, true),
+		Field::new("gas", DataType::UInt64, true),
+		Field::new("gas_used", DataType::UInt64, true),
+		Field::new("input", DataType::Binary, true),
+		Field::new("output", DataType::Binary, true),
+		Field::new("error", DataType::Utf8, true),
+	])
+}
+
+/// Columns of the traces not yet written, following [`trace_schema`].
+#[derive(Default)]
+struct TraceColumns {
+	block_number: UInt32Builder,
+	block_hash: StringBuilder,
+	transaction_hash: StringBuilder,
+	transaction_position: UInt32Builder,
+	trace_address: ListBuilder<UInt32Builder>,
+	subtraces: UInt32Builder,
+	kind: StringBuilder,
+	call_type: StringBuilder,
+	from: StringBuilder,
+	to: StringBuilder,
+	value: StringBuilder,
+	gas: UInt64Builder,
+	gas_used: UInt64Builder,
+	input: BinaryBuilder,
+	output: BinaryBuilder,
+	error: StringBuilder,
+}
+
+impl TraceColumns {
+	fn len(&self) -> usize {
+		self.block_number.len()
+	}
+
+	fn push(&mut self, trace: TransactionTrace) {
+		self.block_number.append_value(trace.block_number);
+		self.block_hash
+			.append_value(format!("{:?}", trace.block_hash));
+		self.transaction_hash
+			.append_value(format!("{:?}", trace.transaction_hash));
+		self.transaction_position
+			.append_value(trace.transaction_position);
+		self.trace_address
+			.append_value(trace.trace_address.into_iter().map(Some));
+		self.subtraces.append_value(trace.subtraces);
+
+		let mut created = None;
+		match trace.output {
+			TransactionTraceOutput::Result(TransactionTraceResult::Call { gas_used, output }) => {
+				self.gas_used.append_value(saturated_u64(gas_used));
+				self.output.append_value(output);
+				self.error.append_null();
+			}
+			TransactionTraceOutput::Result(TransactionTraceResult::Create {
+				address,
+				code,
+				gas_used,
+			}) => {
+				created = Some(address);
+				self.gas_used.append_value(saturated_u64(gas_used));
+				self.output.append_value(code);
+				self.error.append_null();
+			}
+			TransactionTraceOutput::Result(TransactionTraceResult::Suicide) => {
+				self.gas_used.append_null();
+				self.output.append_null();
+				self.error.append_null();
+			}
+			TransactionTraceOutput::Error(error) => {
+				self.gas_used.append_null();
+				self.output.append_null();
+				self.error.append_value(String::from_utf8_lossy(&error));
+			}
+		}
+
+		match trace.action {
+			TransactionTraceAction::Call {
+				call_type,
+				from,
+				gas,
+				input,
+				to,
+				value,
+			} => {
+				self.kind.append_value("call");
+				self.call_type.append_value(match call_type {
+					CallType::Call => "call",
+					CallType::CallCode => "callcode",
+					CallType::DelegateCall => "delegatecall",
+					CallType::StaticCall => "staticcall",
+				});
+				self.append_frame(from, Some(to), value, Some(gas), Some(input));
+			}
+			TransactionTraceAction::Create {
+				from,
+				gas,
+				init,
+				value,
+				..
+			} => {
+				self.kind.append_value("create");
+				self.call_type.append_null();
+				self.append_frame(from, created, value, Some(gas), Some(init));
+			}
+			TransactionTraceAction::Suicide {
+				address,
+				balance,
+				refund_address,
+			} => {
+				self.kind.append_value("suicide");
+				self.call_type.append_null();
+				self.append_frame(address, Some(refund_address), balance, None, None);
+			}
+		}
+	}
+
+	fn append_frame(
+		&mut self,
+		from: H160,
+		to: Option<H160>,
+		value: U256,
+		gas: Option<U256>,
+		input: Option<Vec<u8>>,
+	) {
+		self.from.append_value(format!("{:?}", from));
+		self.to.append_option(to.map(|to| format!("{:?}", to)));
+		self.value.append_value(value.to_string());
+		self.gas.append_option(gas.map(saturated_u64));
+		self.input.append_option(input);
+	}
+
+	fn finish(&mut self) -> Result<RecordBatch, String> {
+		let columns: Vec<ArrayRef> = vec![
+			Arc::new(self.block_number.finish()),
+			Arc::new(self.block_hash.finish()),
+			Arc::new(self.transaction_hash.finish()),
+			Arc::new(self.transaction_position.finish()),
+			Arc::new(self.trace_address.finish()),
+			Arc::new(self.subtraces.finish()),
+			Arc::new(self.kind.finish()),
+			Arc::new(self.call_type.finish()),
+			Arc::new(self.from.finish()),
+			Arc::new(self.to.finish()),
+			Arc::new(self.value.finish()),
+			Arc::new(self.gas.finish()),
+			Arc::new(self.gas_used.finish()),
+			Arc::new(self.input.finish()),
+			Arc::new(self.output.finish()),
+			Arc::new(self.error.finish()),
+		];
+
+		RecordBatch::try_new(Arc::new(trace_schema()), columns)
+			.map_err(|e| format!("Failed to build trace batch : {:?}", e))
+	}
+}
+
+fn saturated_u64(value: U256) -> u64 {
+	value.try_into().unwrap_or(u64::MAX)
+}
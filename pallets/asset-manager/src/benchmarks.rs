@@ -20,6 +20,7 @@ use crate::{pallet::LocalAssetIdCreator, Call, Config, DepositBalanceOf, Pallet}
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::traits::{Currency, Get};
 use frame_system::RawOrigin;
+use sp_std::vec::Vec;
 use xcm::latest::prelude::*;
 
 ///RLocal asset deposit amount
@@ -203,6 +204,83 @@ benchmarks! {
 		assert!(Pallet::<T>::asset_type_units_per_second(&asset_type_to_be_removed).is_none());
 		assert!(!Pallet::<T>::supported_fee_payment_assets().contains(&asset_type_to_be_removed));
 	}
+
+	migrate_existing_asset_type {
+		// We make it dependent on the number of existing assets already
+		let x in 5..100;
+		for i in 0..x {
+			let asset_type:  T::ForeignAssetType = MultiLocation::new(0, X1(GeneralIndex(i as u128))).into();
+			let metadata = T::AssetRegistrarMetadata::default();
+			let amount = 1u32.into();
+			Pallet::<T>::register_foreign_asset(
+				RawOrigin::Root.into(),
+				asset_type.clone(),
+				metadata,
+				amount,
+				true
+			)?;
+			Pallet::<T>::set_asset_units_per_second(RawOrigin::Root.into(), asset_type.clone(), 1, i)?;
+		}
+
+		let new_asset_type = T::ForeignAssetType::default();
+		let asset_type_to_be_migrated: T::ForeignAssetType = MultiLocation::new(
+			0,
+			X1(GeneralIndex((x-1) as u128))
+		).into();
+		let asset_id_to_be_migrated = asset_type_to_be_migrated.clone().into();
+	}: _(RawOrigin::Root, asset_id_to_be_migrated, new_asset_type.clone(), x)
+	verify {
+		assert_eq!(Pallet::<T>::asset_id_type(asset_id_to_be_migrated), Some(new_asset_type.clone()));
+		assert_eq!(Pallet::<T>::asset_type_units_per_second(&new_asset_type), Some(1));
+		assert!(Pallet::<T>::asset_type_alias(&asset_type_to_be_migrated).is_some());
+	}
+
+	retire_asset_type_alias {
+		// Worst case is the alias supports fee payment with a full list of supported assets
+		for i in 0..100 {
+			let asset_type:  T::ForeignAssetType = MultiLocation::new(0, X1(GeneralIndex(i as u128))).into();
+			let metadata = T::AssetRegistrarMetadata::default();
+			let amount = 1u32.into();
+			Pallet::<T>::register_foreign_asset(
+				RawOrigin::Root.into(),
+				asset_type.clone(),
+				metadata,
+				amount,
+				true
+			)?;
+			Pallet::<T>::set_asset_units_per_second(RawOrigin::Root.into(), asset_type.clone(), 1, i)?;
+		}
+
+		let new_asset_type = T::ForeignAssetType::default();
+		let asset_type_to_be_retired: T::ForeignAssetType = MultiLocation::new(
+			0,
+			X1(GeneralIndex(99u128))
+		).into();
+		let asset_id: T::AssetId = asset_type_to_be_retired.clone().into();
+		Pallet::<T>::migrate_existing_asset_type(
+			RawOrigin::Root.into(),
+			asset_id,
+			new_asset_type,
+			100
+		)?;
+	}: {
+		Pallet::<T>::retire_asset_type_alias(asset_type_to_be_retired.clone());
+	}
+	verify {
+		assert!(Pallet::<T>::asset_type_id(&asset_type_to_be_retired).is_none());
+		assert!(!Pallet::<T>::supported_fee_payment_assets().contains(&asset_type_to_be_retired));
+	}
+
+	set_fee_asset_preference {
+		// We make it dependent on the number of preferred assets
+		let x in 1..100;
+		let preference: Vec<T::ForeignAssetType> = (0..x)
+			.map(|i| MultiLocation::new(0, X1(GeneralIndex(i as u128))).into())
+			.collect();
+	}: _(RawOrigin::Root, preference.clone())
+	verify {
+		assert_eq!(Pallet::<T>::fee_asset_preference(), preference);
+	}
 }
 
 #[cfg(test)]
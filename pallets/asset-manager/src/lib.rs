@@ -29,7 +29,7 @@
 //! which holds a mapping between assetId and assetInfo, i.e., the asset creator (from which
 //! we take the deposit) and the deposit amount itself.
 //!
-//! This pallet has eight extrinsics: register_foreign_asset, which registers a foreign
+//! This pallet has ten extrinsics: register_foreign_asset, which registers a foreign
 //! asset in this pallet and creates the asset as dictated by the AssetRegistrar trait.
 //! set_asset_units_per_second: which sets the unit per second that should be charged for
 //! a particular asset.
@@ -40,6 +40,11 @@
 //! register_local_asset: which creates a local asset with a specific owner
 //! destroy_foreign_asset: which destroys a foreign asset and all its associated data
 //! destroy_local_asset: which destroys a local asset and all its associated data
+//! migrate_existing_asset_type: which changes the AssetType of an asset while keeping the
+//! previous AssetType as an alias of the asset for AssetTypeAliasPeriod blocks. Aliases are
+//! tracked in AssetTypeAlias and retired automatically in on_initialize.
+//! set_fee_asset_preference: which sets the order (stored in FeeAssetPreference) in which assets
+//! are used to pay for xcm execution when an incoming message holds several of them.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -66,7 +71,7 @@ pub mod pallet {
 	};
 	use frame_system::pallet_prelude::*;
 	use parity_scale_codec::HasCompact;
-	use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned};
+	use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, One, Saturating};
 	use sp_std::vec::Vec;
 
 	#[pallet::pallet]
@@ -158,6 +163,9 @@ pub mod pallet {
 		fn get_units_per_second(asset_type: T::ForeignAssetType) -> Option<u128> {
 			AssetTypeUnitsPerSecond::<T>::get(asset_type)
 		}
+		fn fee_asset_preference() -> Vec<T::ForeignAssetType> {
+			FeeAssetPreference::<T>::get()
+		}
 		#[cfg(feature = "runtime-benchmarks")]
 		fn set_units_per_second(asset_type: T::ForeignAssetType, fee_per_second: u128) {
 			// Grab supported assets
@@ -208,6 +216,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type LocalAssetDeposit: Get<DepositBalanceOf<Self>>;
 
+		/// Number of blocks during which the previous asset type of a migrated foreign asset
+		/// keeps resolving to the asset, so that in-flight XCM messages can still be processed.
+		#[pallet::constant]
+		type AssetTypeAliasPeriod: Get<BlockNumberFor<Self>>;
+
 		type WeightInfo: WeightInfo;
 	}
 
@@ -222,6 +235,7 @@ pub mod pallet {
 		ErrorDestroyingAsset,
 		NotSufficientDeposit,
 		NonExistentLocalAsset,
+		DuplicateFeeAsset,
 	}
 
 	#[pallet::event]
@@ -263,6 +277,23 @@ pub mod pallet {
 		},
 		/// Removed all information related to an assetId and destroyed asset
 		LocalAssetDestroyed { asset_id: T::AssetId },
+		/// Changed the xcm type mapping for a given asset id, the previous asset type is kept
+		/// as an alias until the given block
+		ForeignAssetTypeMigrationStarted {
+			asset_id: T::AssetId,
+			previous_asset_type: T::ForeignAssetType,
+			new_asset_type: T::ForeignAssetType,
+			retire_at: BlockNumberFor<T>,
+		},
+		/// The previous asset type of a migrated asset does not resolve to it anymore
+		ForeignAssetTypeAliasRetired {
+			asset_id: T::AssetId,
+			asset_type: T::ForeignAssetType,
+		},
+		/// Changed the order in which assets are used to pay for xcm execution
+		FeeAssetPreferenceChanged {
+			preference: Vec<T::ForeignAssetType>,
+		},
 	}
 
 	/// Mapping from an asset id to asset type.
@@ -314,6 +345,38 @@ pub mod pallet {
 	pub type SupportedFeePaymentAssets<T: Config> =
 		StorageValue<_, Vec<T::ForeignAssetType>, ValueQuery>;
 
+	/// Ordered list of the asset types used to pay for xcm execution when several supported fee
+	/// payment assets are available, the most preferred first.
+	/// Assets not in the list are only used when none of the preferred ones is available.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_asset_preference)]
+	pub type FeeAssetPreference<T: Config> = StorageValue<_, Vec<T::ForeignAssetType>, ValueQuery>;
+
+	/// Previous asset types of migrated foreign assets.
+	/// Maps the previous asset type to the asset id it still resolves to, and the block at
+	/// which the alias is retired.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_type_alias)]
+	pub type AssetTypeAlias<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ForeignAssetType, (T::AssetId, BlockNumberFor<T>)>;
+
+	/// Asset type aliases that need to be retired at a given block.
+	#[pallet::storage]
+	pub type AssetTypeAliasRetirements<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, Vec<T::ForeignAssetType>, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			for asset_type in AssetTypeAliasRetirements::<T>::take(n) {
+				Self::retire_asset_type_alias(asset_type);
+				weight = weight.saturating_add(T::WeightInfo::retire_asset_type_alias());
+			}
+			weight
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Register new asset with the asset manager
@@ -545,14 +608,6 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::LocalAssetModifierOrigin::ensure_origin(origin)?;
 
-			// Get the deposit amount
-			let deposit = T::LocalAssetDeposit::get();
-
-			// Verify we can reserve
-			T::Currency::can_reserve(&creator, deposit)
-				.then(|| true)
-				.ok_or(Error::<T>::NotSufficientDeposit)?;
-
 			// Read Local Asset Counter
 			let mut local_asset_counter = LocalAssetCounter::<T>::get();
 
@@ -565,36 +620,10 @@ pub mod pallet {
 				.checked_add(1)
 				.ok_or(Error::<T>::LocalAssetLimitReached)?;
 
-			// Create local asset
-			T::AssetRegistrar::create_local_asset(
-				asset_id,
-				creator.clone(),
-				min_balance,
-				is_sufficient,
-				owner.clone(),
-			)
-			.map_err(|_| Error::<T>::ErrorCreatingAsset)?;
-
-			// Reserve the deposit, we verified we can do this
-			T::Currency::reserve(&creator, deposit)?;
-
-			// Update assetInfo
-			LocalAssetDeposit::<T>::insert(
-				asset_id,
-				AssetInfo {
-					creator: creator.clone(),
-					deposit,
-				},
-			);
+			Self::do_register_local_asset(asset_id, creator, owner, is_sufficient, min_balance)?;
 
 			// Update local asset counter
 			LocalAssetCounter::<T>::put(local_asset_counter);
-
-			Self::deposit_event(Event::LocalAssetRegistered {
-				asset_id,
-				creator,
-				owner,
-			});
 			Ok(())
 		}
 
@@ -683,6 +712,96 @@ pub mod pallet {
 			Self::deposit_event(Event::LocalAssetDestroyed { asset_id });
 			Ok(())
 		}
+
+		/// Change the xcm type mapping for a given assetId, keeping the previous assetType as
+		/// an alias of the asset for AssetTypeAliasPeriod blocks.
+		/// During that period both asset types resolve to the asset (and can be used to pay
+		/// for xcm execution if the previous one could), while the asset id resolves to the
+		/// new asset type.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::migrate_existing_asset_type(*num_assets_weight_hint))]
+		pub fn migrate_existing_asset_type(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			new_asset_type: T::ForeignAssetType,
+			num_assets_weight_hint: u32,
+		) -> DispatchResult {
+			T::ForeignAssetModifierOrigin::ensure_origin(origin)?;
+
+			// Grab supported assets
+			let mut supported_assets = SupportedFeePaymentAssets::<T>::get();
+
+			ensure!(
+				num_assets_weight_hint >= (supported_assets.len() as u32),
+				Error::<T>::TooLowNumAssetsWeightHint
+			);
+
+			let previous_asset_type =
+				AssetIdType::<T>::get(&asset_id).ok_or(Error::<T>::AssetDoesNotExist)?;
+
+			// The new asset type cannot already point to an asset (or be an alias)
+			ensure!(
+				AssetTypeId::<T>::get(&new_asset_type).is_none(),
+				Error::<T>::AssetAlreadyExists
+			);
+
+			// Insert new asset type info, the previous AssetTypeId entry is kept
+			AssetIdType::<T>::insert(&asset_id, &new_asset_type);
+			AssetTypeId::<T>::insert(&new_asset_type, &asset_id);
+
+			// The new asset type can pay for fees at the same rate as the previous one.
+			// The previous one remains supported until the alias is retired
+			if let Some(units) = AssetTypeUnitsPerSecond::<T>::get(&previous_asset_type) {
+				// Only if the new asset is not supported we need to push it
+				if let Err(index) = supported_assets.binary_search(&new_asset_type) {
+					supported_assets.insert(index, new_asset_type.clone());
+				}
+
+				// Insert supported fee payment assets
+				SupportedFeePaymentAssets::<T>::put(supported_assets);
+				AssetTypeUnitsPerSecond::<T>::insert(&new_asset_type, units);
+			}
+
+			// Schedule the retirement of the previous asset type
+			let retire_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::AssetTypeAliasPeriod::get().max(One::one()));
+			AssetTypeAlias::<T>::insert(&previous_asset_type, (asset_id, retire_at));
+			AssetTypeAliasRetirements::<T>::append(retire_at, previous_asset_type.clone());
+
+			Self::deposit_event(Event::ForeignAssetTypeMigrationStarted {
+				asset_id,
+				previous_asset_type,
+				new_asset_type,
+				retire_at,
+			});
+			Ok(())
+		}
+
+		/// Set the order in which assets are used to pay for xcm execution when an incoming
+		/// message holds several supported fee payment assets, the most preferred first.
+		/// An empty list restores the default behavior of using the first asset of the message.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_fee_asset_preference(preference.len() as u32))]
+		pub fn set_fee_asset_preference(
+			origin: OriginFor<T>,
+			preference: Vec<T::ForeignAssetType>,
+		) -> DispatchResult {
+			T::ForeignAssetModifierOrigin::ensure_origin(origin)?;
+
+			// Each asset can only be listed once
+			let mut sorted = preference.clone();
+			sorted.sort();
+			sorted.dedup();
+			ensure!(
+				sorted.len() == preference.len(),
+				Error::<T>::DuplicateFeeAsset
+			);
+
+			FeeAssetPreference::<T>::put(&preference);
+
+			Self::deposit_event(Event::FeeAssetPreferenceChanged { preference });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -690,5 +809,84 @@ pub mod pallet {
 		pub fn account_id() -> T::AccountId {
 			PALLET_ID.into_account_truncating()
 		}
+
+		/// Register a new local asset with the given assetId, reserving the deposit from
+		/// `creator`. Callers are responsible for deriving assetIds which do not collide with
+		/// the ones created from the local asset counter.
+		pub fn do_register_local_asset(
+			asset_id: T::AssetId,
+			creator: T::AccountId,
+			owner: T::AccountId,
+			is_sufficient: bool,
+			min_balance: T::Balance,
+		) -> DispatchResult {
+			// Ensure such an assetId does not exist
+			ensure!(
+				LocalAssetDeposit::<T>::get(&asset_id).is_none(),
+				Error::<T>::AssetAlreadyExists
+			);
+
+			// Get the deposit amount
+			let deposit = T::LocalAssetDeposit::get();
+
+			// Verify we can reserve
+			T::Currency::can_reserve(&creator, deposit)
+				.then(|| true)
+				.ok_or(Error::<T>::NotSufficientDeposit)?;
+
+			// Create local asset
+			T::AssetRegistrar::create_local_asset(
+				asset_id,
+				creator.clone(),
+				min_balance,
+				is_sufficient,
+				owner.clone(),
+			)
+			.map_err(|_| Error::<T>::ErrorCreatingAsset)?;
+
+			// Reserve the deposit, we verified we can do this
+			T::Currency::reserve(&creator, deposit)?;
+
+			// Update assetInfo
+			LocalAssetDeposit::<T>::insert(
+				asset_id,
+				AssetInfo {
+					creator: creator.clone(),
+					deposit,
+				},
+			);
+
+			Self::deposit_event(Event::LocalAssetRegistered {
+				asset_id,
+				creator,
+				owner,
+			});
+			Ok(())
+		}
+
+		/// Stop resolving a previous asset type to its migrated asset
+		pub(crate) fn retire_asset_type_alias(asset_type: T::ForeignAssetType) {
+			if let Some((asset_id, _)) = AssetTypeAlias::<T>::take(&asset_type) {
+				// Only remove the mapping if it was not re-assigned in the meantime
+				if AssetTypeId::<T>::get(&asset_type) == Some(asset_id)
+					&& AssetIdType::<T>::get(&asset_id).as_ref() != Some(&asset_type)
+				{
+					AssetTypeId::<T>::remove(&asset_type);
+					AssetTypeUnitsPerSecond::<T>::remove(&asset_type);
+
+					// Only if the old asset is supported we need to remove it
+					let mut supported_assets = SupportedFeePaymentAssets::<T>::get();
+					if let Ok(index) = supported_assets.binary_search(&asset_type) {
+						supported_assets.remove(index);
+						SupportedFeePaymentAssets::<T>::put(supported_assets);
+					}
+				}
+
+				Self::deposit_event(Event::ForeignAssetTypeAliasRetired {
+					asset_id,
+					asset_type,
+				});
+			}
+		}
 	}
 }
@@ -19,7 +19,10 @@ use crate as pallet_asset_manager;
 use parity_scale_codec::{Decode, Encode};
 
 use frame_support::{
-	construct_runtime, parameter_types, traits::Everything, weights::Weight, RuntimeDebug,
+	construct_runtime, parameter_types,
+	traits::{Everything, Hooks},
+	weights::Weight,
+	RuntimeDebug,
 };
 use frame_system::EnsureRoot;
 use scale_info::TypeInfo;
@@ -199,6 +202,7 @@ impl pallet_asset_manager::LocalAssetIdCreator<Test> for MockLocalAssetIdCreator
 
 parameter_types! {
 	pub const LocalAssetDeposit: u64 = 1;
+	pub const AssetTypeAliasPeriod: u32 = 10;
 }
 
 impl Config for Test {
@@ -213,6 +217,7 @@ impl Config for Test {
 	type LocalAssetIdCreator = MockLocalAssetIdCreator;
 	type Currency = Balances;
 	type LocalAssetDeposit = LocalAssetDeposit;
+	type AssetTypeAliasPeriod = AssetTypeAliasPeriod;
 	type WeightInfo = ();
 }
 
@@ -265,3 +270,10 @@ pub(crate) fn events() -> Vec<super::Event<Test>> {
 pub fn expect_events(e: Vec<super::Event<Test>>) {
 	assert_eq!(events(), e);
 }
+
+pub(crate) fn roll_to(n: BlockNumber) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		AssetManager::on_initialize(System::block_number());
+	}
+}
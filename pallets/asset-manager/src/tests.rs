@@ -531,6 +531,33 @@ fn test_destroy_foreign_asset_also_removes_everything() {
 	});
 }
 
+#[test]
+fn registering_local_with_existing_asset_id_fails() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(AssetManager::do_register_local_asset(
+				5u128, 1u64, 1u64, false, 1u64,
+			));
+
+			assert_noop!(
+				AssetManager::do_register_local_asset(5u128, 1u64, 1u64, false, 1u64),
+				Error::<Test>::AssetAlreadyExists
+			);
+
+			// The counter is only used by registrations through the extrinsic
+			assert_eq!(AssetManager::local_asset_counter(), 0);
+			assert_eq!(
+				AssetManager::local_asset_deposit(5u128),
+				Some(AssetInfo {
+					creator: 1,
+					deposit: 1
+				})
+			);
+		});
+}
+
 #[test]
 fn test_destroy_local_asset_works() {
 	ExtBuilder::default()
@@ -568,3 +595,229 @@ fn test_destroy_local_asset_works() {
 			]);
 		});
 }
+
+#[test]
+fn test_root_can_migrate_asset_type_with_alias() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetManager::register_foreign_asset(
+			RuntimeOrigin::root(),
+			MockAssetType::MockAsset(1),
+			0u32.into(),
+			1u32.into(),
+			true
+		));
+
+		assert_ok!(AssetManager::set_asset_units_per_second(
+			RuntimeOrigin::root(),
+			MockAssetType::MockAsset(1),
+			200u128.into(),
+			0
+		));
+
+		assert_ok!(AssetManager::migrate_existing_asset_type(
+			RuntimeOrigin::root(),
+			1,
+			MockAssetType::MockAsset(2),
+			1
+		));
+
+		// The asset id resolves to the new asset type
+		assert_eq!(
+			AssetManager::asset_id_type(1).unwrap(),
+			MockAssetType::MockAsset(2)
+		);
+
+		// Both asset types resolve to the asset id and can pay for fees
+		assert_eq!(
+			AssetManager::asset_type_id(MockAssetType::MockAsset(1)).unwrap(),
+			1
+		);
+		assert_eq!(
+			AssetManager::asset_type_id(MockAssetType::MockAsset(2)).unwrap(),
+			1
+		);
+		assert_eq!(
+			AssetManager::asset_type_units_per_second(MockAssetType::MockAsset(1)).unwrap(),
+			200
+		);
+		assert_eq!(
+			AssetManager::asset_type_units_per_second(MockAssetType::MockAsset(2)).unwrap(),
+			200
+		);
+		assert_eq!(
+			AssetManager::supported_fee_payment_assets(),
+			vec![MockAssetType::MockAsset(1), MockAssetType::MockAsset(2)]
+		);
+		assert_eq!(
+			AssetManager::asset_type_alias(MockAssetType::MockAsset(1)),
+			Some((1, 11))
+		);
+
+		// The alias is still there right before the end of the period
+		roll_to(10);
+		assert_eq!(
+			AssetManager::asset_type_id(MockAssetType::MockAsset(1)).unwrap(),
+			1
+		);
+
+		// The alias is retired at the end of the period
+		roll_to(11);
+		assert!(AssetManager::asset_type_id(MockAssetType::MockAsset(1)).is_none());
+		assert!(AssetManager::asset_type_units_per_second(MockAssetType::MockAsset(1)).is_none());
+		assert!(AssetManager::asset_type_alias(MockAssetType::MockAsset(1)).is_none());
+		assert_eq!(
+			AssetManager::supported_fee_payment_assets(),
+			vec![MockAssetType::MockAsset(2)]
+		);
+		assert_eq!(
+			AssetManager::asset_type_id(MockAssetType::MockAsset(2)).unwrap(),
+			1
+		);
+
+		expect_events(vec![
+			crate::Event::ForeignAssetRegistered {
+				asset_id: 1,
+				asset: MockAssetType::MockAsset(1),
+				metadata: 0,
+			},
+			crate::Event::UnitsPerSecondChanged {
+				asset_type: MockAssetType::MockAsset(1),
+				units_per_second: 200,
+			},
+			crate::Event::ForeignAssetTypeMigrationStarted {
+				asset_id: 1,
+				previous_asset_type: MockAssetType::MockAsset(1),
+				new_asset_type: MockAssetType::MockAsset(2),
+				retire_at: 11,
+			},
+			crate::Event::ForeignAssetTypeAliasRetired {
+				asset_id: 1,
+				asset_type: MockAssetType::MockAsset(1),
+			},
+		])
+	});
+}
+
+#[test]
+fn test_migrate_asset_type_to_existing_asset_type_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetManager::register_foreign_asset(
+			RuntimeOrigin::root(),
+			MockAssetType::MockAsset(1),
+			0u32.into(),
+			1u32.into(),
+			true
+		));
+		assert_ok!(AssetManager::register_foreign_asset(
+			RuntimeOrigin::root(),
+			MockAssetType::MockAsset(2),
+			0u32.into(),
+			1u32.into(),
+			true
+		));
+
+		assert_noop!(
+			AssetManager::migrate_existing_asset_type(
+				RuntimeOrigin::root(),
+				1,
+				MockAssetType::MockAsset(2),
+				1
+			),
+			Error::<Test>::AssetAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn test_alias_retirement_does_not_remove_reassigned_asset_type() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetManager::register_foreign_asset(
+			RuntimeOrigin::root(),
+			MockAssetType::MockAsset(1),
+			0u32.into(),
+			1u32.into(),
+			true
+		));
+
+		assert_ok!(AssetManager::migrate_existing_asset_type(
+			RuntimeOrigin::root(),
+			1,
+			MockAssetType::MockAsset(2),
+			1
+		));
+
+		// Governance moves the asset back to its original asset type during the alias period
+		assert_ok!(AssetManager::change_existing_asset_type(
+			RuntimeOrigin::root(),
+			1,
+			MockAssetType::MockAsset(1),
+			1
+		));
+
+		roll_to(11);
+
+		// The original asset type is in use again and must not be retired
+		assert_eq!(
+			AssetManager::asset_id_type(1).unwrap(),
+			MockAssetType::MockAsset(1)
+		);
+		assert_eq!(
+			AssetManager::asset_type_id(MockAssetType::MockAsset(1)).unwrap(),
+			1
+		);
+		assert!(AssetManager::asset_type_alias(MockAssetType::MockAsset(1)).is_none());
+	});
+}
+
+#[test]
+fn test_regular_user_cannot_migrate_asset_type() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetManager::migrate_existing_asset_type(
+				RuntimeOrigin::signed(1),
+				1,
+				MockAssetType::MockAsset(2),
+				1
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn test_set_fee_asset_preference() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetManager::set_fee_asset_preference(
+			RuntimeOrigin::root(),
+			vec![MockAssetType::MockAsset(2), MockAssetType::MockAsset(1)]
+		));
+
+		assert_eq!(
+			AssetManager::fee_asset_preference(),
+			vec![MockAssetType::MockAsset(2), MockAssetType::MockAsset(1)]
+		);
+		assert_eq!(
+			<AssetManager as xcm_primitives::UnitsToWeightRatio<_>>::fee_asset_preference(),
+			vec![MockAssetType::MockAsset(2), MockAssetType::MockAsset(1)]
+		);
+
+		expect_events(vec![crate::Event::FeeAssetPreferenceChanged {
+			preference: vec![MockAssetType::MockAsset(2), MockAssetType::MockAsset(1)],
+		}]);
+
+		// Assets cannot be listed twice
+		assert_noop!(
+			AssetManager::set_fee_asset_preference(
+				RuntimeOrigin::root(),
+				vec![MockAssetType::MockAsset(1), MockAssetType::MockAsset(1)]
+			),
+			Error::<Test>::DuplicateFeeAsset
+		);
+
+		// Only the foreign asset modifier origin can set the preference
+		assert_noop!(
+			AssetManager::set_fee_asset_preference(RuntimeOrigin::signed(1), vec![]),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
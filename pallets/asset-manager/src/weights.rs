@@ -58,6 +58,9 @@ pub trait WeightInfo {
 	fn remove_supported_asset(x: u32, ) -> Weight;
 	fn register_local_asset() -> Weight;
 	fn remove_existing_asset_type(x: u32, ) -> Weight;
+	fn migrate_existing_asset_type(x: u32, ) -> Weight;
+	fn retire_asset_type_alias() -> Weight;
+	fn set_fee_asset_preference(x: u32, ) -> Weight;
 }
 
 /// Weights for pallet_asset_manager using the Substrate node and recommended hardware.
@@ -177,6 +180,53 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_parts(0, 40).saturating_mul(x.into()))
 	}
+	/// Storage: AssetManager SupportedFeePaymentAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager SupportedFeePaymentAssets (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdType (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeId (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeUnitsPerSecond (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeAliasRetirements (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAliasRetirements (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeAlias (r:0 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAlias (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[5, 100]`.
+	fn migrate_existing_asset_type(x: u32, ) -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(96_000_000, 14262)
+			// Standard Error: 3_412
+			.saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 60).saturating_mul(x.into()))
+	}
+	/// Storage: AssetManager AssetTypeAlias (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAlias (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeId (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager SupportedFeePaymentAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager SupportedFeePaymentAssets (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeUnitsPerSecond (r:0 w:1)
+	/// Proof Skipped: AssetManager AssetTypeUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	fn retire_asset_type_alias() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(79_000_000, 17042)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}	/// Storage: AssetManager FeeAssetPreference (r:0 w:1)
+	/// Proof Skipped: AssetManager FeeAssetPreference (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[1, 100]`.
+	fn set_fee_asset_preference(x: u32, ) -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(48_000_000, 0)
+			// Standard Error: 1_374
+			.saturating_add(Weight::from_parts(1_600_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -295,4 +345,51 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_parts(0, 40).saturating_mul(x.into()))
 	}
-}
\ No newline at end of file
+	/// Storage: AssetManager SupportedFeePaymentAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager SupportedFeePaymentAssets (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdType (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeId (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeUnitsPerSecond (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeAliasRetirements (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAliasRetirements (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeAlias (r:0 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAlias (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[5, 100]`.
+	fn migrate_existing_asset_type(x: u32, ) -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(96_000_000, 14262)
+			// Standard Error: 3_412
+			.saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 60).saturating_mul(x.into()))
+	}
+	/// Storage: AssetManager AssetTypeAlias (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAlias (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeId (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager SupportedFeePaymentAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager SupportedFeePaymentAssets (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeUnitsPerSecond (r:0 w:1)
+	/// Proof Skipped: AssetManager AssetTypeUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	fn retire_asset_type_alias() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(79_000_000, 17042)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}	/// Storage: AssetManager FeeAssetPreference (r:0 w:1)
+	/// Proof Skipped: AssetManager FeeAssetPreference (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[1, 100]`.
+	fn set_fee_asset_preference(x: u32, ) -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(48_000_000, 0)
+			// Standard Error: 1_374
+			.saturating_add(Weight::from_parts(1_600_000, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,107 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+
+use crate::{
+	Call, Config, Pallet, RoundSeed, ScheduledEligibility, ScheduledEligibilityChange,
+	SeedRefreshPending,
+};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::{Get, Hooks};
+use frame_system::RawOrigin;
+use pallet_author_slot_filter::EligibilityValue;
+
+benchmarks! {
+	schedule_eligibility {
+		let activation_round = T::CurrentRound::get() + T::MinActivationDelay::get();
+	}: _(RawOrigin::Root, EligibilityValue::new_unchecked(10), activation_round)
+	verify {
+		assert!(Pallet::<T>::scheduled_eligibility().is_some());
+	}
+
+	cancel_scheduled_eligibility {
+		ScheduledEligibilityChange::<T>::put(ScheduledEligibility {
+			value: EligibilityValue::new_unchecked(10),
+			activation_round: 10,
+		});
+	}: _(RawOrigin::Root)
+	verify {
+		assert!(Pallet::<T>::scheduled_eligibility().is_none());
+	}
+
+	activate_eligibility {
+		ScheduledEligibilityChange::<T>::put(ScheduledEligibility {
+			value: EligibilityValue::new_unchecked(10),
+			activation_round: 1,
+		});
+	}: {
+		Pallet::<T>::on_new_round(1);
+	}
+	verify {
+		assert!(Pallet::<T>::scheduled_eligibility().is_none());
+	}
+
+	refresh_round_seed {
+		SeedRefreshPending::<T>::put(true);
+	}: {
+		Pallet::<T>::on_finalize(1u32.into());
+	}
+	verify {
+		assert!(RoundSeed::<T>::get().is_some());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::ExtBuilder;
+	use crate::Pallet;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn bench_schedule_eligibility() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_schedule_eligibility());
+		});
+	}
+
+	#[test]
+	fn bench_cancel_scheduled_eligibility() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_cancel_scheduled_eligibility());
+		});
+	}
+
+	#[test]
+	fn bench_activate_eligibility() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_activate_eligibility());
+		});
+	}
+
+	#[test]
+	fn bench_refresh_round_seed() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_refresh_round_seed());
+		});
+	}
+}
+
+impl_benchmark_test_suite!(
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
@@ -0,0 +1,272 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Author filter schedule pallet
+//!
+//! Governance companion of `pallet-author-slot-filter`. Changing the eligibility ratio of the
+//! author filter takes effect immediately, which can reshuffle the eligible authors in the middle
+//! of a round. This pallet lets governance schedule the new eligibility ratio for a future round
+//! instead: the change is applied when that round starts, which the runtime notifies through
+//! [`Pallet::on_new_round`].
+//!
+//! The pallet is also the randomness source of the author filter, and lets each runtime select
+//! where the filter entropy comes from:
+//! - [`RandomnessSourceKind::LocalVrf`]: the local VRF output of the block author.
+//! - [`RandomnessSourceKind::RelayBabe`]: the BABE epoch randomness of the relay chain, captured
+//!   once at the beginning of each round so the subset of eligible authors can only change at round
+//!   boundaries. The local VRF output is used until the first relay seed is captured.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::pallet;
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_support::traits::Randomness;
+	use frame_system::pallet_prelude::*;
+	use pallet_author_slot_filter::EligibilityValue;
+	use sp_runtime::traits::Hash;
+	use sp_std::vec::Vec;
+
+	/// Staking round index.
+	pub type RoundIndex = u32;
+
+	/// Where the author filter takes its randomness from.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum RandomnessSourceKind {
+		/// BABE randomness of the relay chain, refreshed at the beginning of each round.
+		RelayBabe,
+		/// VRF output of the local block author.
+		LocalVrf,
+	}
+
+	/// An eligibility ratio waiting for its activation round.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct ScheduledEligibility {
+		/// The eligibility ratio to apply.
+		pub value: EligibilityValue,
+		/// The round at the beginning of which the eligibility ratio is applied.
+		pub activation_round: RoundIndex,
+	}
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_author_slot_filter::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that is allowed to schedule eligibility changes.
+		type ScheduleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The current staking round.
+		type CurrentRound: Get<RoundIndex>;
+
+		/// Minimum number of rounds between the current round and the activation round of an
+		/// eligibility change.
+		#[pallet::constant]
+		type MinActivationDelay: Get<RoundIndex>;
+
+		/// The randomness source used by the author filter in this runtime.
+		#[pallet::constant]
+		type FilterRandomness: Get<RandomnessSourceKind>;
+
+		/// BABE randomness of the previous relay chain epoch. Only read during block finalization,
+		/// once the relay chain state proof of the block is available.
+		type RelayEpochRandomness: Get<Option<Self::Hash>>;
+
+		/// Local VRF randomness.
+		type LocalRandomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The eligibility change waiting for its activation round, if any.
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_eligibility)]
+	pub type ScheduledEligibilityChange<T: Config> =
+		StorageValue<_, ScheduledEligibility, OptionQuery>;
+
+	/// Relay chain randomness of the current round, with the block it was captured at.
+	#[pallet::storage]
+	#[pallet::getter(fn round_seed)]
+	pub type RoundSeed<T: Config> = StorageValue<_, (T::Hash, BlockNumberFor<T>), OptionQuery>;
+
+	/// Whether the relay chain randomness must be captured at the end of the current block.
+	#[pallet::storage]
+	pub(crate) type SeedRefreshPending<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The activation round is closer than the minimum activation delay.
+		ActivationRoundTooSoon,
+		/// There is no scheduled eligibility change.
+		NoScheduledEligibility,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An eligibility change was scheduled, replacing any previously scheduled one.
+		EligibilityScheduled {
+			value: EligibilityValue,
+			activation_round: RoundIndex,
+		},
+		/// The scheduled eligibility change was cancelled.
+		EligibilityScheduleCancelled {
+			value: EligibilityValue,
+			activation_round: RoundIndex,
+		},
+		/// The scheduled eligibility change was applied at the beginning of the round.
+		EligibilityActivated {
+			value: EligibilityValue,
+			round: RoundIndex,
+		},
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// Read of `SeedRefreshPending` in `on_finalize`, the refresh itself is accounted in
+			// `on_new_round`.
+			T::DbWeight::get().reads(1)
+		}
+
+		fn on_finalize(n: BlockNumberFor<T>) {
+			if SeedRefreshPending::<T>::take() {
+				if let Some(seed) = T::RelayEpochRandomness::get() {
+					RoundSeed::<T>::put((seed, n));
+				}
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Schedule a new eligibility ratio for the author filter, applied at the beginning of
+		/// `activation_round`. Replaces any previously scheduled change.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_eligibility())]
+		pub fn schedule_eligibility(
+			origin: OriginFor<T>,
+			value: EligibilityValue,
+			activation_round: RoundIndex,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				activation_round
+					>= T::CurrentRound::get().saturating_add(T::MinActivationDelay::get()),
+				Error::<T>::ActivationRoundTooSoon
+			);
+
+			ScheduledEligibilityChange::<T>::put(ScheduledEligibility {
+				value: value.clone(),
+				activation_round,
+			});
+
+			Self::deposit_event(Event::EligibilityScheduled {
+				value,
+				activation_round,
+			});
+			Ok(())
+		}
+
+		/// Cancel the scheduled eligibility change.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_scheduled_eligibility())]
+		pub fn cancel_scheduled_eligibility(origin: OriginFor<T>) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin)?;
+
+			let ScheduledEligibility {
+				value,
+				activation_round,
+			} = ScheduledEligibilityChange::<T>::take().ok_or(Error::<T>::NoScheduledEligibility)?;
+
+			Self::deposit_event(Event::EligibilityScheduleCancelled {
+				value,
+				activation_round,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Apply the eligibility change scheduled for this round, and request a new relay chain
+		/// seed when the author filter uses the relay chain randomness.
+		///
+		/// Must be called by the runtime at the beginning of each staking round.
+		pub fn on_new_round(round: RoundIndex) -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+
+			if let Some(scheduled) = ScheduledEligibilityChange::<T>::get() {
+				if scheduled.activation_round <= round {
+					ScheduledEligibilityChange::<T>::kill();
+					if pallet_author_slot_filter::Pallet::<T>::set_eligible(
+						frame_system::RawOrigin::Root.into(),
+						scheduled.value.clone(),
+					)
+					.is_ok()
+					{
+						Self::deposit_event(Event::EligibilityActivated {
+							value: scheduled.value,
+							round,
+						});
+					}
+					weight =
+						weight.saturating_add(<T as Config>::WeightInfo::activate_eligibility());
+				}
+			}
+
+			if T::FilterRandomness::get() == RandomnessSourceKind::RelayBabe {
+				SeedRefreshPending::<T>::put(true);
+				weight = weight.saturating_add(<T as Config>::WeightInfo::refresh_round_seed());
+			}
+
+			weight
+		}
+	}
+
+	impl<T: Config> Randomness<T::Hash, BlockNumberFor<T>> for Pallet<T> {
+		fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+			if T::FilterRandomness::get() == RandomnessSourceKind::RelayBabe {
+				if let Some((seed, known_since)) = RoundSeed::<T>::get() {
+					let mut input: Vec<u8> = seed.as_ref().to_vec();
+					input.extend_from_slice(subject);
+					return (T::Hashing::hash(&input), known_since);
+				}
+			}
+			T::LocalRandomness::random(subject)
+		}
+	}
+}
@@ -0,0 +1,164 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate as pallet_author_filter_schedule;
+use crate::{RandomnessSourceKind, RoundIndex};
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, Everything, Randomness},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+
+pub type AccountId = u64;
+pub type BlockNumber = u32;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		AuthorFilter: pallet_author_slot_filter::{Pallet, Call, Storage, Event},
+		AuthorFilterSchedule: pallet_author_filter_schedule::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Header = sp_runtime::generic::Header<BlockNumber, BlakeTwo256>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub PotentialAuthors: Vec<AccountId> = vec![1, 2, 3, 4];
+}
+
+impl pallet_author_slot_filter::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RandomnessSource = AuthorFilterSchedule;
+	type PotentialAuthors = PotentialAuthors;
+	type WeightInfo = ();
+}
+
+pub(crate) const LOCAL_VRF_OUTPUT: H256 = H256([0x22; 32]);
+pub(crate) const RELAY_EPOCH_RANDOMNESS: H256 = H256([0x11; 32]);
+
+/// Local randomness that only depends on the block, to tell it apart from the relay seed.
+pub struct LocalVrfRandomness;
+impl Randomness<H256, BlockNumber> for LocalVrfRandomness {
+	fn random(_subject: &[u8]) -> (H256, BlockNumber) {
+		(LOCAL_VRF_OUTPUT, System::block_number())
+	}
+}
+
+parameter_types! {
+	pub static CurrentRound: RoundIndex = 1;
+	pub static FilterRandomness: RandomnessSourceKind = RandomnessSourceKind::LocalVrf;
+	pub static RelayEpochRandomness: Option<H256> = Some(RELAY_EPOCH_RANDOMNESS);
+	pub const MinActivationDelay: RoundIndex = 2;
+}
+
+impl pallet_author_filter_schedule::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type CurrentRound = CurrentRound;
+	type MinActivationDelay = MinActivationDelay;
+	type FilterRandomness = FilterRandomness;
+	type RelayEpochRandomness = RelayEpochRandomness;
+	type LocalRandomness = LocalVrfRandomness;
+	type WeightInfo = ();
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder {
+	relay_randomness: bool,
+}
+
+impl ExtBuilder {
+	/// Use the relay chain randomness as the author filter randomness source.
+	pub(crate) fn with_relay_randomness(mut self) -> Self {
+		self.relay_randomness = true;
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::default()
+			.build_storage::<Test>()
+			.expect("Frame system builds valid default genesis config");
+
+		FilterRandomness::set(if self.relay_randomness {
+			RandomnessSourceKind::RelayBabe
+		} else {
+			RandomnessSourceKind::LocalVrf
+		});
+		CurrentRound::set(1);
+		RelayEpochRandomness::set(Some(RELAY_EPOCH_RANDOMNESS));
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+pub(crate) fn events() -> Vec<pallet_author_filter_schedule::Event<Test>> {
+	System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| {
+			if let RuntimeEvent::AuthorFilterSchedule(inner) = e {
+				Some(inner)
+			} else {
+				None
+			}
+		})
+		.collect::<Vec<_>>()
+}
+
+pub fn expect_events(e: Vec<pallet_author_filter_schedule::Event<Test>>) {
+	assert_eq!(events(), e);
+}
@@ -0,0 +1,234 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.use crate::mock::*;
+
+use crate::{Error, Event, Pallet, ScheduledEligibility};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Hooks, Randomness},
+};
+use pallet_author_slot_filter::EligibilityValue;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	DispatchError,
+};
+
+fn eligibility(value: u32) -> EligibilityValue {
+	EligibilityValue::new_unchecked(value)
+}
+
+#[test]
+fn schedule_eligibility_requires_schedule_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuthorFilterSchedule::schedule_eligibility(
+				RuntimeOrigin::signed(1),
+				eligibility(10),
+				3
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AuthorFilterSchedule::cancel_scheduled_eligibility(RuntimeOrigin::signed(1)),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn schedule_eligibility_enforces_the_activation_delay() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Current round is 1 and the minimum activation delay is 2 rounds
+		assert_noop!(
+			AuthorFilterSchedule::schedule_eligibility(RuntimeOrigin::root(), eligibility(10), 2),
+			Error::<Test>::ActivationRoundTooSoon
+		);
+		assert_ok!(AuthorFilterSchedule::schedule_eligibility(
+			RuntimeOrigin::root(),
+			eligibility(10),
+			3
+		));
+
+		assert_eq!(
+			Pallet::<Test>::scheduled_eligibility(),
+			Some(ScheduledEligibility {
+				value: eligibility(10),
+				activation_round: 3,
+			})
+		);
+		expect_events(vec![Event::EligibilityScheduled {
+			value: eligibility(10),
+			activation_round: 3,
+		}]);
+	});
+}
+
+#[test]
+fn scheduling_replaces_the_previous_change() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AuthorFilterSchedule::schedule_eligibility(
+			RuntimeOrigin::root(),
+			eligibility(10),
+			3
+		));
+		assert_ok!(AuthorFilterSchedule::schedule_eligibility(
+			RuntimeOrigin::root(),
+			eligibility(20),
+			5
+		));
+
+		assert_eq!(
+			Pallet::<Test>::scheduled_eligibility(),
+			Some(ScheduledEligibility {
+				value: eligibility(20),
+				activation_round: 5,
+			})
+		);
+	});
+}
+
+#[test]
+fn cancel_scheduled_eligibility_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuthorFilterSchedule::cancel_scheduled_eligibility(RuntimeOrigin::root()),
+			Error::<Test>::NoScheduledEligibility
+		);
+
+		assert_ok!(AuthorFilterSchedule::schedule_eligibility(
+			RuntimeOrigin::root(),
+			eligibility(10),
+			3
+		));
+		assert_ok!(AuthorFilterSchedule::cancel_scheduled_eligibility(
+			RuntimeOrigin::root()
+		));
+
+		assert_eq!(Pallet::<Test>::scheduled_eligibility(), None);
+		expect_events(vec![
+			Event::EligibilityScheduled {
+				value: eligibility(10),
+				activation_round: 3,
+			},
+			Event::EligibilityScheduleCancelled {
+				value: eligibility(10),
+				activation_round: 3,
+			},
+		]);
+	});
+}
+
+#[test]
+fn eligibility_is_only_applied_at_the_activation_round() {
+	ExtBuilder::default().build().execute_with(|| {
+		let initial = AuthorFilter::eligible_count();
+		assert_ok!(AuthorFilterSchedule::schedule_eligibility(
+			RuntimeOrigin::root(),
+			eligibility(10),
+			3
+		));
+
+		// The current round is not affected
+		AuthorFilterSchedule::on_new_round(2);
+		assert_eq!(AuthorFilter::eligible_count(), initial);
+		assert!(Pallet::<Test>::scheduled_eligibility().is_some());
+
+		AuthorFilterSchedule::on_new_round(3);
+		assert_eq!(AuthorFilter::eligible_count(), eligibility(10));
+		assert_eq!(Pallet::<Test>::scheduled_eligibility(), None);
+		expect_events(vec![
+			Event::EligibilityScheduled {
+				value: eligibility(10),
+				activation_round: 3,
+			},
+			Event::EligibilityActivated {
+				value: eligibility(10),
+				round: 3,
+			},
+		]);
+	});
+}
+
+#[test]
+fn local_vrf_source_uses_the_local_randomness() {
+	ExtBuilder::default().build().execute_with(|| {
+		AuthorFilterSchedule::on_new_round(2);
+		AuthorFilterSchedule::on_finalize(1);
+
+		assert_eq!(Pallet::<Test>::round_seed(), None);
+		assert_eq!(
+			<AuthorFilterSchedule as Randomness<H256, BlockNumber>>::random(b"subject"),
+			(LOCAL_VRF_OUTPUT, 1)
+		);
+	});
+}
+
+#[test]
+fn relay_babe_source_uses_the_round_seed() {
+	ExtBuilder::default()
+		.with_relay_randomness()
+		.build()
+		.execute_with(|| {
+			// No relay seed captured yet, the local randomness is used
+			assert_eq!(
+				<AuthorFilterSchedule as Randomness<H256, BlockNumber>>::random(b"subject"),
+				(LOCAL_VRF_OUTPUT, 1)
+			);
+
+			// The seed is only captured at the end of the first block of the round
+			AuthorFilterSchedule::on_new_round(2);
+			assert_eq!(Pallet::<Test>::round_seed(), None);
+			AuthorFilterSchedule::on_finalize(1);
+			assert_eq!(
+				Pallet::<Test>::round_seed(),
+				Some((RELAY_EPOCH_RANDOMNESS, 1))
+			);
+
+			let expected =
+				BlakeTwo256::hash(&[RELAY_EPOCH_RANDOMNESS.as_bytes(), b"subject"].concat());
+			assert_eq!(
+				<AuthorFilterSchedule as Randomness<H256, BlockNumber>>::random(b"subject"),
+				(expected, 1)
+			);
+		});
+}
+
+#[test]
+fn round_seed_is_kept_for_the_whole_round() {
+	ExtBuilder::default()
+		.with_relay_randomness()
+		.build()
+		.execute_with(|| {
+			AuthorFilterSchedule::on_new_round(2);
+			AuthorFilterSchedule::on_finalize(1);
+
+			// Later blocks of the round do not refresh the seed
+			RelayEpochRandomness::set(Some(H256::repeat_byte(0x33)));
+			System::set_block_number(2);
+			AuthorFilterSchedule::on_finalize(2);
+			assert_eq!(
+				Pallet::<Test>::round_seed(),
+				Some((RELAY_EPOCH_RANDOMNESS, 1))
+			);
+
+			AuthorFilterSchedule::on_new_round(3);
+			AuthorFilterSchedule::on_finalize(2);
+			assert_eq!(
+				Pallet::<Test>::round_seed(),
+				Some((H256::repeat_byte(0x33), 2))
+			);
+		});
+}
@@ -0,0 +1,119 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_author_filter_schedule`
+//!
+//! These weights have not been benchmarked yet. They are hand-written, deliberately
+//! conservative estimates whose proof sizes follow the storage bounds; regenerate them
+//! with `moonbeam benchmark pallet` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_author_filter_schedule.
+pub trait WeightInfo {
+	fn schedule_eligibility() -> Weight;
+	fn cancel_scheduled_eligibility() -> Weight;
+	fn activate_eligibility() -> Weight;
+	fn refresh_round_seed() -> Weight;
+}
+
+/// Weights for pallet_author_filter_schedule using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Round (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AuthorFilterSchedule ScheduledEligibilityChange (r:0 w:1)
+	/// Proof Skipped: AuthorFilterSchedule ScheduledEligibilityChange (max_values: Some(1), max_size: None, mode: Measured)
+	fn schedule_eligibility() -> Weight {
+		Weight::from_parts(21_000_000, 1527)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AuthorFilterSchedule ScheduledEligibilityChange (r:1 w:1)
+	/// Proof Skipped: AuthorFilterSchedule ScheduledEligibilityChange (max_values: Some(1), max_size: None, mode: Measured)
+	fn cancel_scheduled_eligibility() -> Weight {
+		Weight::from_parts(22_000_000, 1530)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AuthorFilterSchedule ScheduledEligibilityChange (r:0 w:1)
+	/// Proof Skipped: AuthorFilterSchedule ScheduledEligibilityChange (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AuthorFilter EligibleCount (r:0 w:1)
+	/// Proof Skipped: AuthorFilter EligibleCount (max_values: Some(1), max_size: None, mode: Measured)
+	fn activate_eligibility() -> Weight {
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AuthorFilterSchedule SeedRefreshPending (r:1 w:1)
+	/// Proof Skipped: AuthorFilterSchedule SeedRefreshPending (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem ValidationData (r:1 w:0)
+	/// Proof Skipped: ParachainSystem ValidationData (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem RelayStateProof (r:1 w:0)
+	/// Proof Skipped: ParachainSystem RelayStateProof (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AuthorFilterSchedule RoundSeed (r:0 w:1)
+	/// Proof Skipped: AuthorFilterSchedule RoundSeed (max_values: Some(1), max_size: None, mode: Measured)
+	fn refresh_round_seed() -> Weight {
+		Weight::from_parts(45_000_000, 3317)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Round (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AuthorFilterSchedule ScheduledEligibilityChange (r:0 w:1)
+	/// Proof Skipped: AuthorFilterSchedule ScheduledEligibilityChange (max_values: Some(1), max_size: None, mode: Measured)
+	fn schedule_eligibility() -> Weight {
+		Weight::from_parts(21_000_000, 1527)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AuthorFilterSchedule ScheduledEligibilityChange (r:1 w:1)
+	/// Proof Skipped: AuthorFilterSchedule ScheduledEligibilityChange (max_values: Some(1), max_size: None, mode: Measured)
+	fn cancel_scheduled_eligibility() -> Weight {
+		Weight::from_parts(22_000_000, 1530)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AuthorFilterSchedule ScheduledEligibilityChange (r:0 w:1)
+	/// Proof Skipped: AuthorFilterSchedule ScheduledEligibilityChange (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AuthorFilter EligibleCount (r:0 w:1)
+	/// Proof Skipped: AuthorFilter EligibleCount (max_values: Some(1), max_size: None, mode: Measured)
+	fn activate_eligibility() -> Weight {
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: AuthorFilterSchedule SeedRefreshPending (r:1 w:1)
+	/// Proof Skipped: AuthorFilterSchedule SeedRefreshPending (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem ValidationData (r:1 w:0)
+	/// Proof Skipped: ParachainSystem ValidationData (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem RelayStateProof (r:1 w:0)
+	/// Proof Skipped: ParachainSystem RelayStateProof (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AuthorFilterSchedule RoundSeed (r:0 w:1)
+	/// Proof Skipped: AuthorFilterSchedule RoundSeed (max_values: Some(1), max_size: None, mode: Measured)
+	fn refresh_round_seed() -> Weight {
+		Weight::from_parts(45_000_000, 3317)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
@@ -0,0 +1,153 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+
+use crate::{BalanceOf, Call, Config, DelegationMigration, DelegationRule, Pallet};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{Currency, Get, Polling, StorePreimage};
+use frame_system::RawOrigin;
+use pallet_democracy::{AccountVote, Conviction, Vote, VoteThreshold};
+use parity_scale_codec::Encode;
+use sp_runtime::traits::{Bounded, StaticLookup, Zero};
+
+/// Fund an account and return the balance it can vote or delegate with.
+fn funded<T: Config>(who: &T::AccountId) -> BalanceOf<T> {
+	let balance = BalanceOf::<T>::max_value() / 100u32.into();
+	<T as pallet_democracy::Config>::Currency::make_free_balance_be(who, balance);
+	balance / 10u32.into()
+}
+
+/// Start a democracy referendum, the tallies of which are updated when votes are removed.
+fn start_referendum<T: Config>(n: u32) -> pallet_democracy::ReferendumIndex {
+	let call: <T as frame_system::Config>::RuntimeCall =
+		frame_system::Call::remark { remark: n.encode() }.into();
+	let proposal = <T as pallet_democracy::Config>::Preimages::bound(call)
+		.expect("remark fits in a bounded call");
+	pallet_democracy::Pallet::<T>::internal_start_referendum(
+		proposal,
+		VoteThreshold::SuperMajorityApprove,
+		0u32.into(),
+	)
+}
+
+fn set_rule<T: Config>(rule: DelegationMigration<T>) {
+	DelegationRule::<T>::put(rule);
+}
+
+benchmarks! {
+	set_delegation_rule {
+	}: _(RawOrigin::Root, Some(DelegationMigration::Release))
+	verify {
+		assert_eq!(Pallet::<T>::delegation_rule(), Some(DelegationMigration::Release));
+	}
+
+	migrate_direct_votes {
+		let v in 0 .. <T as pallet_democracy::Config>::MaxVotes::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let voter: T::AccountId = account("voter", 0, 0);
+		let balance = funded::<T>(&voter);
+		for n in 0..v {
+			let index = start_referendum::<T>(n);
+			pallet_democracy::Pallet::<T>::vote(
+				RawOrigin::Signed(voter.clone()).into(),
+				index,
+				AccountVote::Standard {
+					vote: Vote { aye: true, conviction: Conviction::Locked1x },
+					balance,
+				},
+			)?;
+		}
+		if v == 0 {
+			// Only the lock of past votes is left
+			<T as pallet_democracy::Config>::Currency::set_lock(
+				crate::DEMOCRACY_ID,
+				&voter,
+				balance,
+				frame_support::traits::WithdrawReasons::all(),
+			);
+			pallet_democracy::VotingOf::<T>::mutate(&voter, |voting| {
+				if let pallet_democracy::Voting::Direct { prior, .. } = voting {
+					prior.accumulate(1u32.into(), balance);
+				}
+			});
+		}
+		set_rule::<T>(DelegationMigration::Release);
+	}: migrate_democracy_lock(RawOrigin::Signed(caller), voter.clone())
+	verify {
+		assert!(pallet_democracy::VotingOf::<T>::get(&voter).locked_balance().is_zero());
+	}
+
+	migrate_delegation {
+		let t in 0 .. T::MaxTracks::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let delegator: T::AccountId = account("delegator", 0, 0);
+		let target: T::AccountId = account("target", 0, 0);
+		let balance = funded::<T>(&delegator);
+		pallet_democracy::Pallet::<T>::delegate(
+			RawOrigin::Signed(delegator.clone()).into(),
+			T::Lookup::unlookup(target),
+			Conviction::Locked1x,
+			balance,
+		)?;
+		let tracks: sp_std::vec::Vec<_> = <T as pallet_conviction_voting::Config>::Polls::classes()
+			.into_iter()
+			.take(t as usize)
+			.collect();
+		set_rule::<T>(DelegationMigration::Convert(
+			tracks.try_into().expect("at most MaxTracks tracks"),
+		));
+	}: migrate_democracy_lock(RawOrigin::Signed(caller), delegator.clone())
+	verify {
+		assert!(pallet_democracy::VotingOf::<T>::get(&delegator).locked_balance().is_zero());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::ExtBuilder;
+	use crate::Pallet;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn bench_set_delegation_rule() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_set_delegation_rule());
+		});
+	}
+
+	#[test]
+	fn bench_migrate_direct_votes() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_migrate_direct_votes());
+		});
+	}
+
+	#[test]
+	fn bench_migrate_delegation() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_migrate_delegation());
+		});
+	}
+}
+
+impl_benchmark_test_suite!(
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
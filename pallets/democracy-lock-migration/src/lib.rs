@@ -0,0 +1,261 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Democracy lock migration pallet
+//!
+//! Governance has moved from pallet_democracy to OpenGov (pallet_referenda and
+//! pallet_conviction_voting). Accounts which voted or delegated with pallet_democracy may still
+//! have part of their balance locked by it, and unlocking requires interacting with a pallet which
+//! is no longer used.
+//!
+//! Once governance has set the [`DelegationRule`], anyone can migrate the democracy state of an
+//! account with [`Pallet::migrate_democracy_lock`]:
+//! - its democracy votes are removed, updating the tally of referenda still ongoing,
+//! - its democracy delegation is removed, and re-created with the same target, conviction and
+//!   balance on the conviction voting tracks chosen by governance, if any,
+//! - its remaining democracy lock (e.g. the conviction lock of past votes) is released.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::pallet;
+
+/// Identifier of the balance lock of pallet_democracy.
+pub const DEMOCRACY_ID: frame_support::traits::LockIdentifier = *b"democrac";
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Currency, LockableCurrency, Polling, UnfilteredDispatchable},
+	};
+	use frame_system::pallet_prelude::*;
+	use pallet_democracy::Voting;
+	use sp_runtime::traits::{SaturatedConversion, StaticLookup, Zero};
+
+	pub type BalanceOf<T> = <<T as pallet_democracy::Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	pub type ClassOf<T> = <<T as pallet_conviction_voting::Config>::Polls as Polling<
+		pallet_conviction_voting::TallyOf<T>,
+	>>::Class;
+
+	/// What happens to the democracy delegation of an account when it is migrated.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		TypeInfo,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub enum DelegationMigration<T: Config> {
+		/// The delegation is removed.
+		Release,
+		/// The delegation is re-created with the same target, conviction and balance on each of
+		/// the given conviction voting tracks.
+		Convert(BoundedVec<ClassOf<T>, T::MaxTracks>),
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + pallet_democracy::Config + pallet_conviction_voting::Config
+	{
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that is allowed to set the delegation rule.
+		type MigrationManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of conviction voting tracks a democracy delegation can be converted to.
+		#[pallet::constant]
+		type MaxTracks: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// What happens to democracy delegations when they are migrated. Migrations are disabled when
+	/// not set.
+	#[pallet::storage]
+	#[pallet::getter(fn delegation_rule)]
+	pub type DelegationRule<T: Config> = StorageValue<_, DelegationMigration<T>, OptionQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Migrations are disabled until governance sets the delegation rule.
+		MigrationDisabled,
+		/// The account has no democracy lock.
+		NothingToMigrate,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The delegation rule was set, `None` disables migrations.
+		DelegationRuleSet {
+			rule: Option<DelegationMigration<T>>,
+		},
+		/// The democracy state of an account was migrated.
+		DemocracyLockMigrated {
+			who: T::AccountId,
+			/// Balance that was locked by pallet_democracy.
+			unlocked: BalanceOf<T>,
+			/// Number of conviction voting tracks the delegation was re-created on.
+			delegated_tracks: u32,
+		},
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set what happens to democracy delegations when they are migrated. `None` disables
+		/// migrations.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_delegation_rule())]
+		pub fn set_delegation_rule(
+			origin: OriginFor<T>,
+			rule: Option<DelegationMigration<T>>,
+		) -> DispatchResult {
+			T::MigrationManagerOrigin::ensure_origin(origin)?;
+
+			DelegationRule::<T>::set(rule.clone());
+
+			Self::deposit_event(Event::DelegationRuleSet { rule });
+			Ok(())
+		}
+
+		/// Remove the democracy votes, delegation and lock of an account, converting the
+		/// delegation according to the delegation rule. Can be called by anyone.
+		#[pallet::call_index(1)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::migrate_direct_votes(
+				<T as pallet_democracy::Config>::MaxVotes::get()
+			)
+			.max(<T as Config>::WeightInfo::migrate_delegation(T::MaxTracks::get()))
+		)]
+		pub fn migrate_democracy_lock(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let rule = DelegationRule::<T>::get().ok_or(Error::<T>::MigrationDisabled)?;
+
+			let voting = pallet_democracy::VotingOf::<T>::get(&who);
+			let unlocked = voting.locked_balance();
+			ensure!(!unlocked.is_zero(), Error::<T>::NothingToMigrate);
+
+			let who_origin: OriginFor<T> = frame_system::RawOrigin::Signed(who.clone()).into();
+
+			// Remove the votes and delegation through pallet_democracy, such that the tallies of
+			// ongoing referenda and the delegations of the target are updated.
+			let (actual_weight, delegated_tracks) = match voting {
+				Voting::Direct { votes, .. } => {
+					let vote_count = votes.len() as u32;
+					for (index, _) in votes {
+						pallet_democracy::Pallet::<T>::remove_vote(who_origin.clone(), index)?;
+					}
+
+					(
+						<T as Config>::WeightInfo::migrate_direct_votes(vote_count),
+						0,
+					)
+				}
+				Voting::Delegating {
+					balance,
+					target,
+					conviction,
+					..
+				} => {
+					pallet_democracy::Pallet::<T>::undelegate(who_origin.clone())
+						.map_err(|e| e.error)?;
+
+					let tracks = match rule {
+						DelegationMigration::Release => Default::default(),
+						DelegationMigration::Convert(tracks) => tracks,
+					};
+					let track_count = tracks.len() as u32;
+
+					let mut delegated_tracks = 0;
+					for class in tracks {
+						let call = pallet_conviction_voting::Call::<T>::delegate {
+							class,
+							to: T::Lookup::unlookup(target.clone()),
+							conviction: Self::convert_conviction(conviction),
+							balance: balance.saturated_into::<u128>().saturated_into(),
+						};
+
+						// A track on which the account already votes or delegates is skipped,
+						// such that it doesn't prevent the democracy lock from being released.
+						if call.dispatch_bypass_filter(who_origin.clone()).is_ok() {
+							delegated_tracks += 1;
+						}
+					}
+
+					(
+						<T as Config>::WeightInfo::migrate_delegation(track_count),
+						delegated_tracks,
+					)
+				}
+			};
+
+			// Release the prior lock, which pallet_democracy would otherwise lock again.
+			pallet_democracy::VotingOf::<T>::mutate(&who, |voting| {
+				if let Voting::Direct { prior, .. } = voting {
+					*prior = Default::default();
+				}
+			});
+			<T as pallet_democracy::Config>::Currency::remove_lock(DEMOCRACY_ID, &who);
+
+			Self::deposit_event(Event::DemocracyLockMigrated {
+				who,
+				unlocked,
+				delegated_tracks,
+			});
+
+			Ok(Some(actual_weight).into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn convert_conviction(
+			conviction: pallet_democracy::Conviction,
+		) -> pallet_conviction_voting::Conviction {
+			// Both pallets define the same convictions.
+			u8::from(conviction).try_into().unwrap_or_default()
+		}
+	}
+}
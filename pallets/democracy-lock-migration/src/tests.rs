@@ -0,0 +1,312 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{DelegationMigration, Error, Event, DEMOCRACY_ID};
+use frame_support::{assert_noop, assert_ok, traits::StorePreimage};
+use pallet_democracy::{AccountVote, Conviction, ReferendumIndex, Vote, VoteThreshold, Voting};
+use sp_runtime::DispatchError;
+
+fn start_referendum() -> ReferendumIndex {
+	let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	Democracy::internal_start_referendum(
+		Preimage::bound(call).unwrap(),
+		VoteThreshold::SuperMajorityApprove,
+		0,
+	)
+}
+
+fn vote(who: AccountId, index: ReferendumIndex, balance: Balance) {
+	assert_ok!(Democracy::vote(
+		RuntimeOrigin::signed(who),
+		index,
+		AccountVote::Standard {
+			vote: Vote {
+				aye: true,
+				conviction: Conviction::Locked1x,
+			},
+			balance,
+		}
+	));
+}
+
+fn set_rule(rule: DelegationMigration<Test>) {
+	assert_ok!(DemocracyLockMigration::set_delegation_rule(
+		RuntimeOrigin::root(),
+		Some(rule)
+	));
+}
+
+fn democracy_lock(who: AccountId) -> Option<Balance> {
+	pallet_balances::Locks::<Test>::get(who)
+		.iter()
+		.find(|lock| lock.id == DEMOCRACY_ID)
+		.map(|lock| lock.amount)
+}
+
+fn convert(tracks: Vec<u8>) -> DelegationMigration<Test> {
+	DelegationMigration::Convert(tracks.try_into().unwrap())
+}
+
+#[test]
+fn set_delegation_rule_requires_manager_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DemocracyLockMigration::set_delegation_rule(
+				RuntimeOrigin::signed(ALICE),
+				Some(DelegationMigration::Release)
+			),
+			DispatchError::BadOrigin
+		);
+
+		set_rule(DelegationMigration::Release);
+		assert_eq!(
+			DemocracyLockMigration::delegation_rule(),
+			Some(DelegationMigration::Release)
+		);
+		assert_eq!(
+			events(),
+			vec![Event::DelegationRuleSet {
+				rule: Some(DelegationMigration::Release)
+			}]
+		);
+	})
+}
+
+#[test]
+fn migration_is_disabled_without_rule() {
+	ExtBuilder::default().build().execute_with(|| {
+		vote(ALICE, start_referendum(), 100);
+
+		assert_noop!(
+			DemocracyLockMigration::migrate_democracy_lock(RuntimeOrigin::signed(BOB), ALICE),
+			Error::<Test>::MigrationDisabled
+		);
+
+		set_rule(DelegationMigration::Release);
+		assert_ok!(DemocracyLockMigration::set_delegation_rule(
+			RuntimeOrigin::root(),
+			None
+		));
+		assert_noop!(
+			DemocracyLockMigration::migrate_democracy_lock(RuntimeOrigin::signed(BOB), ALICE),
+			Error::<Test>::MigrationDisabled
+		);
+	})
+}
+
+#[test]
+fn account_without_lock_cannot_be_migrated() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_rule(DelegationMigration::Release);
+
+		assert_noop!(
+			DemocracyLockMigration::migrate_democracy_lock(RuntimeOrigin::signed(BOB), ALICE),
+			Error::<Test>::NothingToMigrate
+		);
+	})
+}
+
+#[test]
+fn direct_votes_are_removed_and_lock_released() {
+	ExtBuilder::default().build().execute_with(|| {
+		let first = start_referendum();
+		let second = start_referendum();
+		vote(ALICE, first, 100);
+		vote(ALICE, second, 200);
+		vote(BOB, second, 50);
+		assert_eq!(democracy_lock(ALICE), Some(200));
+		set_rule(DelegationMigration::Release);
+
+		assert_ok!(DemocracyLockMigration::migrate_democracy_lock(
+			RuntimeOrigin::signed(BOB),
+			ALICE
+		));
+
+		assert_eq!(democracy_lock(ALICE), None);
+		assert_eq!(
+			pallet_democracy::VotingOf::<Test>::get(ALICE).locked_balance(),
+			0
+		);
+		// Tallies of ongoing referenda no longer count the votes
+		assert_eq!(Democracy::referendum_status(first).unwrap().tally.ayes, 0);
+		assert_eq!(Democracy::referendum_status(second).unwrap().tally.ayes, 50);
+		assert_eq!(democracy_lock(BOB), Some(50));
+		assert_eq!(
+			events().last(),
+			Some(&Event::DemocracyLockMigrated {
+				who: ALICE,
+				unlocked: 200,
+				delegated_tracks: 0,
+			})
+		);
+	})
+}
+
+#[test]
+fn lock_of_past_votes_is_released() {
+	ExtBuilder::default().build().execute_with(|| {
+		let index = start_referendum();
+		vote(ALICE, index, 100);
+		roll_to(VotingPeriod::get() + 2);
+
+		// Removing the vote of a finished referendum keeps its conviction lock
+		assert_ok!(Democracy::remove_vote(RuntimeOrigin::signed(ALICE), index));
+		assert_eq!(democracy_lock(ALICE), Some(100));
+		set_rule(DelegationMigration::Release);
+
+		assert_ok!(DemocracyLockMigration::migrate_democracy_lock(
+			RuntimeOrigin::signed(BOB),
+			ALICE
+		));
+
+		assert_eq!(democracy_lock(ALICE), None);
+		assert_eq!(
+			pallet_democracy::VotingOf::<Test>::get(ALICE).locked_balance(),
+			0
+		);
+		// Unlocking through pallet_democracy doesn't lock the balance again
+		assert_ok!(Democracy::unlock(RuntimeOrigin::signed(ALICE), ALICE));
+		assert_eq!(democracy_lock(ALICE), None);
+	})
+}
+
+#[test]
+fn delegation_is_released() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Democracy::delegate(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			Conviction::Locked2x,
+			100
+		));
+		set_rule(DelegationMigration::Release);
+
+		assert_ok!(DemocracyLockMigration::migrate_democracy_lock(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE
+		));
+
+		assert_eq!(democracy_lock(ALICE), None);
+		assert!(matches!(
+			pallet_democracy::VotingOf::<Test>::get(ALICE),
+			Voting::Direct { .. }
+		));
+		match pallet_democracy::VotingOf::<Test>::get(BOB) {
+			Voting::Direct { delegations, .. } => assert_eq!(delegations.votes, 0),
+			_ => panic!("expected BOB to vote directly"),
+		}
+		assert_eq!(
+			pallet_conviction_voting::VotingFor::<Test>::get(ALICE, 0),
+			Default::default()
+		);
+		assert_eq!(
+			events().last(),
+			Some(&Event::DemocracyLockMigrated {
+				who: ALICE,
+				unlocked: 100,
+				delegated_tracks: 0,
+			})
+		);
+	})
+}
+
+#[test]
+fn delegation_is_converted_to_tracks() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Democracy::delegate(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			Conviction::Locked2x,
+			100
+		));
+		set_rule(convert(vec![0, 2]));
+
+		assert_ok!(DemocracyLockMigration::migrate_democracy_lock(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE
+		));
+
+		assert_eq!(democracy_lock(ALICE), None);
+		for class in [0, 2] {
+			match pallet_conviction_voting::VotingFor::<Test>::get(ALICE, class) {
+				pallet_conviction_voting::Voting::Delegating(delegating) => {
+					assert_eq!(delegating.target, BOB);
+					assert_eq!(
+						delegating.conviction,
+						pallet_conviction_voting::Conviction::Locked2x
+					);
+					assert_eq!(delegating.balance, 100);
+				}
+				_ => panic!("expected a delegation on track {}", class),
+			}
+		}
+		assert_eq!(
+			pallet_conviction_voting::VotingFor::<Test>::get(ALICE, 1),
+			Default::default()
+		);
+		assert_eq!(
+			events().last(),
+			Some(&Event::DemocracyLockMigrated {
+				who: ALICE,
+				unlocked: 100,
+				delegated_tracks: 2,
+			})
+		);
+	})
+}
+
+#[test]
+fn tracks_already_delegated_are_skipped() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Democracy::delegate(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			Conviction::Locked2x,
+			100
+		));
+		assert_ok!(ConvictionVoting::delegate(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			CHARLIE,
+			pallet_conviction_voting::Conviction::Locked1x,
+			50
+		));
+		set_rule(convert(vec![0, 1]));
+
+		assert_ok!(DemocracyLockMigration::migrate_democracy_lock(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE
+		));
+
+		// The existing delegation is kept, and the democracy lock is released anyway
+		assert_eq!(democracy_lock(ALICE), None);
+		match pallet_conviction_voting::VotingFor::<Test>::get(ALICE, 0) {
+			pallet_conviction_voting::Voting::Delegating(delegating) => {
+				assert_eq!(delegating.target, CHARLIE);
+			}
+			_ => panic!("expected a delegation on track 0"),
+		}
+		assert_eq!(
+			events().last(),
+			Some(&Event::DemocracyLockMigrated {
+				who: ALICE,
+				unlocked: 100,
+				delegated_tracks: 1,
+			})
+		);
+	})
+}
@@ -0,0 +1,148 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_democracy_lock_migration`
+//!
+//! These weights have not been benchmarked yet. They are hand-written, deliberately
+//! conservative estimates whose proof sizes follow the storage bounds; regenerate them
+//! with `moonbeam benchmark pallet` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_democracy_lock_migration.
+pub trait WeightInfo {
+	fn set_delegation_rule() -> Weight;
+	fn migrate_direct_votes(v: u32, ) -> Weight;
+	fn migrate_delegation(t: u32, ) -> Weight;
+}
+
+/// Weights for pallet_democracy_lock_migration using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: DemocracyLockMigration DelegationRule (r:0 w:1)
+	/// Proof: DemocracyLockMigration DelegationRule (max_values: Some(1), max_size: Some(18), added: 513, mode: MaxEncodedLen)
+	fn set_delegation_rule() -> Weight {
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DemocracyLockMigration DelegationRule (r:1 w:0)
+	/// Proof: DemocracyLockMigration DelegationRule (max_values: Some(1), max_size: Some(18), added: 513, mode: MaxEncodedLen)
+	/// Storage: Democracy VotingOf (r:1 w:1)
+	/// Proof: Democracy VotingOf (max_values: None, max_size: Some(4138), added: 6613, mode: MaxEncodedLen)
+	/// Storage: Democracy ReferendumInfoOf (r:{v} w:{v})
+	/// Proof: Democracy ReferendumInfoOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1287), added: 3762, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// The range of component `v` is `[0, 100]`.
+	fn migrate_direct_votes(v: u32, ) -> Weight {
+		Weight::from_parts(83_000_000, 7603)
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2676).saturating_mul(v.into()))
+	}
+	/// Storage: DemocracyLockMigration DelegationRule (r:1 w:0)
+	/// Proof: DemocracyLockMigration DelegationRule (max_values: Some(1), max_size: Some(18), added: 513, mode: MaxEncodedLen)
+	/// Storage: Democracy VotingOf (r:2 w:2)
+	/// Proof: Democracy VotingOf (max_values: None, max_size: Some(4138), added: 6613, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting VotingFor (r:{t} w:{t})
+	/// Proof: ConvictionVoting VotingFor (max_values: None, max_size: Some(13211), added: 15686, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting ClassLocksFor (r:1 w:1)
+	/// Proof: ConvictionVoting ClassLocksFor (max_values: None, max_size: Some(5162), added: 7637, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1287), added: 3762, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// The range of component `t` is `[0, 16]`.
+	fn migrate_delegation(t: u32, ) -> Weight {
+		Weight::from_parts(130_000_000, 14216)
+			.saturating_add(Weight::from_parts(63_000_000, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 15686).saturating_mul(t.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: DemocracyLockMigration DelegationRule (r:0 w:1)
+	/// Proof: DemocracyLockMigration DelegationRule (max_values: Some(1), max_size: Some(18), added: 513, mode: MaxEncodedLen)
+	fn set_delegation_rule() -> Weight {
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DemocracyLockMigration DelegationRule (r:1 w:0)
+	/// Proof: DemocracyLockMigration DelegationRule (max_values: Some(1), max_size: Some(18), added: 513, mode: MaxEncodedLen)
+	/// Storage: Democracy VotingOf (r:1 w:1)
+	/// Proof: Democracy VotingOf (max_values: None, max_size: Some(4138), added: 6613, mode: MaxEncodedLen)
+	/// Storage: Democracy ReferendumInfoOf (r:{v} w:{v})
+	/// Proof: Democracy ReferendumInfoOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1287), added: 3762, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// The range of component `v` is `[0, 100]`.
+	fn migrate_direct_votes(v: u32, ) -> Weight {
+		Weight::from_parts(83_000_000, 7603)
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2676).saturating_mul(v.into()))
+	}
+	/// Storage: DemocracyLockMigration DelegationRule (r:1 w:0)
+	/// Proof: DemocracyLockMigration DelegationRule (max_values: Some(1), max_size: Some(18), added: 513, mode: MaxEncodedLen)
+	/// Storage: Democracy VotingOf (r:2 w:2)
+	/// Proof: Democracy VotingOf (max_values: None, max_size: Some(4138), added: 6613, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting VotingFor (r:{t} w:{t})
+	/// Proof: ConvictionVoting VotingFor (max_values: None, max_size: Some(13211), added: 15686, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting ClassLocksFor (r:1 w:1)
+	/// Proof: ConvictionVoting ClassLocksFor (max_values: None, max_size: Some(5162), added: 7637, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1287), added: 3762, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// The range of component `t` is `[0, 16]`.
+	fn migrate_delegation(t: u32, ) -> Weight {
+		Weight::from_parts(130_000_000, 14216)
+			.saturating_add(Weight::from_parts(63_000_000, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 15686).saturating_mul(t.into()))
+	}
+}
@@ -0,0 +1,104 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+
+use crate::{recover_signer, signing_hash, Config, Pallet};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::{Currency, Get};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::Encode;
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{One, Saturating, UniqueSaturatedInto};
+use sp_std::vec::Vec;
+
+const SIGNER_KEY: [u8; 32] = [1u8; 32];
+
+fn address_of(secret: &[u8; 32]) -> H160 {
+	let secret = libsecp256k1::SecretKey::parse(secret).expect("valid secret key");
+	let public_key = &libsecp256k1::PublicKey::from_secret_key(&secret).serialize()[1..65];
+	H160::from(H256::from(sp_io::hashing::keccak_256(public_key)))
+}
+
+fn sign(secret: &[u8; 32], message: &[u8; 32]) -> [u8; 65] {
+	let secret = libsecp256k1::SecretKey::parse(secret).expect("valid secret key");
+	let (signature, recovery_id) =
+		libsecp256k1::sign(&libsecp256k1::Message::parse(message), &secret);
+
+	let mut bytes = [0u8; 65];
+	bytes[0..64].copy_from_slice(&signature.serialize());
+	bytes[64] = recovery_id.serialize();
+	bytes
+}
+
+benchmarks! {
+	// The call filter of the runtime may not allow remarks, so the overhead of the extrinsic
+	// (signature recovery, fee and nonce) is measured without dispatching the call, whose weight
+	// is added to the weight of the extrinsic.
+	dispatch_signed {
+		let signer = address_of(&SIGNER_KEY);
+		let who = T::AddressMapping::into_account_id(signer);
+		let call: <T as Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: Vec::new() }.into();
+		let nonce = frame_system::Pallet::<T>::account_nonce(&who);
+		let message = signing_hash(
+			T::ChainId::get(),
+			&call.encode(),
+			U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(nonce)),
+			U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(
+				frame_system::Pallet::<T>::block_number(),
+			)),
+		);
+		let signature = sign(&SIGNER_KEY, &message);
+
+		T::Currency::make_free_balance_be(
+			&who,
+			Pallet::<T>::fee(&call).saturating_add(T::Currency::minimum_balance()),
+		);
+	}: {
+		assert_eq!(recover_signer(&signature, &message), Some(signer));
+		assert!(Pallet::<T>::charge_fee(&who, &call).is_ok());
+		frame_system::Pallet::<T>::inc_account_nonce(&who);
+	}
+	verify {
+		assert_eq!(
+			frame_system::Pallet::<T>::account_nonce(&who),
+			nonce.saturating_add(One::one())
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::ExtBuilder;
+	use crate::Pallet;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn bench_dispatch_signed() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_dispatch_signed());
+		});
+	}
+}
+
+impl_benchmark_test_suite!(
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
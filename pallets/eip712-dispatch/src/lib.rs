@@ -0,0 +1,376 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # EIP-712 dispatch pallet
+//!
+//! This pallet lets accounts whose key only lives in an Ethereum wallet (e.g. MetaMask) submit
+//! Substrate extrinsics, such as claiming crowdloan rewards or staking, without importing their
+//! key into a Substrate wallet.
+//!
+//! Ethereum wallets cannot sign SCALE encoded extrinsics, but they can sign EIP-712 typed data.
+//! The account signs a `SubstrateCall` message committing to the SCALE encoded call, its account
+//! nonce and a deadline block (see [`signing_hash`]). Anyone can submit the call and the signature
+//! through the unsigned [`Pallet::dispatch_signed`] extrinsic, which is validated like a signed
+//! extrinsic: the call must pass [`Config::CallFilter`], the signature must be valid, the nonce
+//! must be the nonce of the account and the account must be able to pay the fee.
+//!
+//! The call is dispatched with the account as signed origin. The account pays the fee a signed
+//! extrinsic with the same call would pay, without tip, and its nonce is incremented whether or
+//! not the call succeeds.
+//!
+//! The extrinsic is unsigned because the account has no key able to sign it: the signature of a
+//! Substrate extrinsic covers its SCALE encoded payload, which an Ethereum wallet can only sign as
+//! an opaque message, and a signed extension would still need such a signature. The checks the
+//! signed extensions perform for signed extrinsics are therefore done by the pallet:
+//! [`Config::ChainId`](pallet_evm::Config::ChainId) in the EIP-712 domain replaces the genesis
+//! check, the deadline replaces the mortality, and the nonce and the fee are checked both when the
+//! call enters the transaction pool and before it is included in a block. A call is only included
+//! with the current nonce of its signer and if the signer can pay the fee, so every call included
+//! in a block pays for its blockspace.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::pallet;
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_std::vec::Vec;
+
+/// EIP-712 type of the domain of the signed calls.
+pub const EIP712_DOMAIN_TYPE: &[u8] = b"EIP712Domain(string name,string version,uint256 chainId)";
+/// EIP-712 type of the signed calls, `call` being the SCALE encoded call.
+pub const SUBSTRATE_CALL_TYPE: &[u8] = b"SubstrateCall(bytes call,uint256 nonce,uint256 deadline)";
+/// Name of the EIP-712 domain of the signed calls.
+pub const DOMAIN_NAME: &[u8] = b"Moonbeam Substrate Call";
+/// Version of the EIP-712 domain of the signed calls.
+pub const DOMAIN_VERSION: &[u8] = b"1";
+
+/// EIP-712 domain separator of the signed calls on the chain with the given id.
+pub fn domain_separator(chain_id: u64) -> [u8; 32] {
+	let mut encoded = Vec::with_capacity(4 * 32);
+	encoded.extend_from_slice(&keccak_256(EIP712_DOMAIN_TYPE));
+	encoded.extend_from_slice(&keccak_256(DOMAIN_NAME));
+	encoded.extend_from_slice(&keccak_256(DOMAIN_VERSION));
+	encoded.extend_from_slice(&u256_word(chain_id.into()));
+	keccak_256(&encoded)
+}
+
+/// Hash an account signs, as EIP-712 typed data, to dispatch the SCALE encoded `call` with the
+/// given account nonce, until the `deadline` block.
+pub fn signing_hash(chain_id: u64, call: &[u8], nonce: U256, deadline: U256) -> [u8; 32] {
+	let mut encoded = Vec::with_capacity(4 * 32);
+	encoded.extend_from_slice(&keccak_256(SUBSTRATE_CALL_TYPE));
+	encoded.extend_from_slice(&keccak_256(call));
+	encoded.extend_from_slice(&u256_word(nonce));
+	encoded.extend_from_slice(&u256_word(deadline));
+
+	let mut message = Vec::with_capacity(2 + 32 + 32);
+	message.extend_from_slice(b"\x19\x01");
+	message.extend_from_slice(&domain_separator(chain_id));
+	message.extend_from_slice(&keccak_256(&encoded));
+	keccak_256(&message)
+}
+
+fn u256_word(value: U256) -> [u8; 32] {
+	let mut word = [0u8; 32];
+	value.to_big_endian(&mut word);
+	word
+}
+
+fn recover_signer(signature: &[u8; 65], message: &[u8; 32]) -> Option<H160> {
+	sp_io::crypto::secp256k1_ecdsa_recover(signature, message)
+		.ok()
+		.map(|public_key| H160::from(H256::from(keccak_256(&public_key))))
+}
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
+		pallet_prelude::*,
+		traits::{Contains, Currency, ExistenceRequirement, OnUnbalanced, WithdrawReasons},
+		weights::WeightToFee,
+	};
+	use frame_system::pallet_prelude::*;
+	use pallet_evm::AddressMapping;
+	use sp_runtime::traits::{Dispatchable, One, Saturating, UniqueSaturatedInto};
+	use sp_std::boxed::Box;
+
+	pub(crate) type BalanceOf<T> = <<T as pallet_evm::Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	pub(crate) type NegativeImbalanceOf<T> = <<T as pallet_evm::Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_evm::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The overarching call type.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Calls which can be dispatched with an EIP-712 signature.
+		type CallFilter: Contains<<Self as Config>::RuntimeCall>;
+
+		/// Conversion of the weight of the calls to the fee paid by the signers.
+		type WeightToFee: WeightToFee<Balance = BalanceOf<Self>>;
+
+		/// Conversion of the encoded length of the calls to the fee paid by the signers.
+		type LengthToFee: WeightToFee<Balance = BalanceOf<Self>>;
+
+		/// Handler for the fees paid by the signers.
+		type OnChargeFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Priority of the signed calls in the transaction pool.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The call cannot be dispatched with an EIP-712 signature.
+		CallFiltered,
+		/// The deadline of the signature has passed.
+		SignatureExpired,
+		/// The signature does not match the signer, call, nonce and deadline.
+		InvalidSignature,
+		/// The nonce is not the nonce of the signer.
+		InvalidNonce,
+		/// The signer cannot afford the fee.
+		CannotPayFee,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A call signed with an EIP-712 signature was dispatched.
+		SignedCallDispatched {
+			signer: H160,
+			nonce: T::Index,
+			fee: BalanceOf<T>,
+			result: DispatchResult,
+		},
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Dispatch a call with the account of `signer` as origin. `signature` is the EIP-712
+		/// signature of the [`signing_hash`] of the call by `signer`.
+		///
+		/// The extrinsic succeeds even if the call fails, as the fee is charged and the nonce is
+		/// consumed, the result of the call being reported by the `SignedCallDispatched` event.
+		#[pallet::call_index(0)]
+		#[pallet::weight({
+			let info = call.get_dispatch_info();
+			(
+				<T as Config>::WeightInfo::dispatch_signed().saturating_add(info.weight),
+				info.class,
+			)
+		})]
+		pub fn dispatch_signed(
+			origin: OriginFor<T>,
+			signer: H160,
+			call: Box<<T as Config>::RuntimeCall>,
+			nonce: T::Index,
+			deadline: T::BlockNumber,
+			signature: [u8; 65],
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+
+			let who = Self::check_call(signer, &call, nonce, deadline, &signature)?;
+			ensure!(
+				nonce == frame_system::Pallet::<T>::account_nonce(&who),
+				Error::<T>::InvalidNonce
+			);
+			let fee = Self::charge_fee(&who, &call)?;
+			frame_system::Pallet::<T>::inc_account_nonce(&who);
+
+			let info = call.get_dispatch_info();
+			let result = call.dispatch(frame_system::RawOrigin::Signed(who).into());
+			let actual_weight = <T as Config>::WeightInfo::dispatch_signed()
+				.saturating_add(extract_actual_weight(&result, &info));
+
+			Self::deposit_event(Event::SignedCallDispatched {
+				signer,
+				nonce,
+				fee,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+
+			Ok(Some(actual_weight).into())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::dispatch_signed {
+				nonce, deadline, ..
+			} = call
+			else {
+				return InvalidTransaction::Call.into();
+			};
+			let (who, account_nonce) = Self::check_unsigned(call)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut builder = ValidTransaction::with_tag_prefix("Eip712Dispatch")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((who.clone(), *nonce))
+				.longevity((*deadline - now + One::one()).unique_saturated_into())
+				.propagate(true);
+			if *nonce > account_nonce {
+				builder = builder.and_requires((who, *nonce - One::one()));
+			}
+			builder.build()
+		}
+
+		fn pre_dispatch(call: &Self::Call) -> Result<(), TransactionValidityError> {
+			let Call::dispatch_signed { nonce, .. } = call else {
+				return Err(InvalidTransaction::Call.into());
+			};
+			let (_, account_nonce) = Self::check_unsigned(call)?;
+
+			// Only the call with the current nonce can be dispatched: including a call with a
+			// future nonce would fail before charging the fee.
+			if *nonce > account_nonce {
+				return Err(InvalidTransaction::Future.into());
+			}
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Check a `dispatch_signed` extrinsic before it enters the transaction pool or a block,
+		/// returning the account of the signer and its current nonce.
+		fn check_unsigned(
+			call: &Call<T>,
+		) -> Result<(T::AccountId, T::Index), TransactionValidityError> {
+			let Call::dispatch_signed {
+				signer,
+				call,
+				nonce,
+				deadline,
+				signature,
+			} = call
+			else {
+				return Err(InvalidTransaction::Call.into());
+			};
+
+			let who =
+				Self::check_call(*signer, call, *nonce, *deadline, signature).map_err(|error| {
+					match error {
+						Error::<T>::InvalidSignature => InvalidTransaction::BadProof,
+						Error::<T>::SignatureExpired => InvalidTransaction::Stale,
+						_ => InvalidTransaction::Call,
+					}
+				})?;
+
+			let account_nonce = frame_system::Pallet::<T>::account_nonce(&who);
+			if *nonce < account_nonce {
+				return Err(InvalidTransaction::Stale.into());
+			}
+			if T::Currency::free_balance(&who) < Self::fee(call) {
+				return Err(InvalidTransaction::Payment.into());
+			}
+
+			Ok((who, account_nonce))
+		}
+
+		/// Check that the call can be dispatched with an EIP-712 signature and that `signer`
+		/// signed it, returning the account of the signer.
+		pub(crate) fn check_call(
+			signer: H160,
+			call: &<T as Config>::RuntimeCall,
+			nonce: T::Index,
+			deadline: T::BlockNumber,
+			signature: &[u8; 65],
+		) -> Result<T::AccountId, Error<T>> {
+			ensure!(T::CallFilter::contains(call), Error::<T>::CallFiltered);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= deadline,
+				Error::<T>::SignatureExpired
+			);
+
+			let message = signing_hash(
+				T::ChainId::get(),
+				&call.encode(),
+				U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(nonce)),
+				U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(deadline)),
+			);
+			ensure!(
+				recover_signer(signature, &message) == Some(signer),
+				Error::<T>::InvalidSignature
+			);
+
+			Ok(T::AddressMapping::into_account_id(signer))
+		}
+
+		/// Fee of a signed extrinsic with the given call, without tip.
+		pub(crate) fn fee(call: &<T as Config>::RuntimeCall) -> BalanceOf<T> {
+			let info = call.get_dispatch_info();
+			let base_weight = T::BlockWeights::get().get(info.class).base_extrinsic;
+			let length = Weight::from_parts(call.encoded_size() as u64, 0);
+
+			T::WeightToFee::weight_to_fee(&base_weight.saturating_add(info.weight))
+				.saturating_add(T::LengthToFee::weight_to_fee(&length))
+		}
+
+		/// Withdraw the fee of the call from the account of the signer.
+		pub(crate) fn charge_fee(
+			who: &T::AccountId,
+			call: &<T as Config>::RuntimeCall,
+		) -> Result<BalanceOf<T>, Error<T>> {
+			let fee = Self::fee(call);
+			let imbalance = T::Currency::withdraw(
+				who,
+				fee,
+				WithdrawReasons::FEE,
+				ExistenceRequirement::KeepAlive,
+			)
+			.map_err(|_| Error::<T>::CannotPayFee)?;
+			T::OnChargeFee::on_unbalanced(imbalance);
+
+			Ok(fee)
+		}
+	}
+}
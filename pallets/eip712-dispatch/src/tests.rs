@@ -0,0 +1,350 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{Call, Error, Event};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo};
+use hex_literal::hex;
+use sp_core::H160;
+use sp_runtime::{
+	traits::ValidateUnsigned,
+	transaction_validity::{
+		InvalidTransaction, TransactionSource, TransactionValidity, TransactionValidityError,
+	},
+};
+
+const RECIPIENT: H160 = H160([0xcc; 20]);
+
+fn dispatch(
+	call: RuntimeCall,
+	nonce: u64,
+	deadline: BlockNumber,
+	signature: [u8; 65],
+) -> DispatchResultWithPostInfo {
+	Eip712Dispatch::dispatch_signed(
+		RuntimeOrigin::none(),
+		signer(),
+		Box::new(call),
+		nonce,
+		deadline,
+		signature,
+	)
+}
+
+fn validate(
+	call: RuntimeCall,
+	nonce: u64,
+	deadline: BlockNumber,
+	signature: [u8; 65],
+) -> TransactionValidity {
+	Eip712Dispatch::validate_unsigned(
+		TransactionSource::External,
+		&Call::dispatch_signed {
+			signer: signer(),
+			call: Box::new(call),
+			nonce,
+			deadline,
+			signature,
+		},
+	)
+}
+
+fn pre_dispatch(
+	call: RuntimeCall,
+	nonce: u64,
+	deadline: BlockNumber,
+	signature: [u8; 65],
+) -> Result<(), TransactionValidityError> {
+	Eip712Dispatch::pre_dispatch(&Call::dispatch_signed {
+		signer: signer(),
+		call: Box::new(call),
+		nonce,
+		deadline,
+		signature,
+	})
+}
+
+fn signer() -> H160 {
+	address_of(&SIGNER_KEY)
+}
+
+fn transfer(value: Balance) -> RuntimeCall {
+	RuntimeCall::Balances(pallet_balances::Call::transfer {
+		dest: RECIPIENT,
+		value,
+	})
+}
+
+#[test]
+fn domain_separator_matches_eip712_encoding() {
+	assert_eq!(
+		crate::domain_separator(CHAIN_ID),
+		hex!("8f8dd43cf477ee939ca41ef807cd571e1e673e2f25c75bbb16d45ddc9496e5bf")
+	);
+}
+
+#[test]
+fn signed_call_is_dispatched_with_the_signer_as_origin() {
+	ExtBuilder::default()
+		.with_balances(vec![(signer(), 1_000)])
+		.build()
+		.execute_with(|| {
+			let call = transfer(40);
+			let signature = sign_call(&SIGNER_KEY, &call, 0, 10);
+
+			assert_ok!(validate(call.clone(), 0, 10, signature));
+			assert_ok!(dispatch(call.clone(), 0, 10, signature));
+
+			assert_eq!(Balances::free_balance(RECIPIENT), 40);
+			assert_eq!(Balances::free_balance(signer()), 1_000 - 40 - fee_of(&call));
+			assert_eq!(System::account_nonce(signer()), 1);
+			assert_eq!(
+				events(),
+				vec![Event::SignedCallDispatched {
+					signer: signer(),
+					nonce: 0,
+					fee: fee_of(&call),
+					result: Ok(()),
+				}]
+			);
+		});
+}
+
+#[test]
+fn failed_call_still_pays_the_fee_and_consumes_the_nonce() {
+	ExtBuilder::default()
+		.with_balances(vec![(signer(), 1_000)])
+		.build()
+		.execute_with(|| {
+			let call = transfer(10_000);
+			let signature = sign_call(&SIGNER_KEY, &call, 0, 10);
+
+			assert_ok!(dispatch(call.clone(), 0, 10, signature));
+
+			assert_eq!(Balances::free_balance(RECIPIENT), 0);
+			assert_eq!(Balances::free_balance(signer()), 1_000 - fee_of(&call));
+			assert_eq!(System::account_nonce(signer()), 1);
+			assert!(matches!(
+				events().as_slice(),
+				[Event::SignedCallDispatched { result: Err(_), .. }]
+			));
+		});
+}
+
+#[test]
+fn filtered_call_is_rejected() {
+	ExtBuilder::default()
+		.with_balances(vec![(signer(), 1_000)])
+		.build()
+		.execute_with(|| {
+			let call = RuntimeCall::System(frame_system::Call::remark {
+				remark: b"hello".to_vec(),
+			});
+			let signature = sign_call(&SIGNER_KEY, &call, 0, 10);
+
+			assert_eq!(
+				validate(call.clone(), 0, 10, signature),
+				InvalidTransaction::Call.into()
+			);
+			assert_noop!(
+				dispatch(call, 0, 10, signature),
+				Error::<Test>::CallFiltered
+			);
+		});
+}
+
+#[test]
+fn signature_of_another_key_is_rejected() {
+	ExtBuilder::default()
+		.with_balances(vec![(signer(), 1_000)])
+		.build()
+		.execute_with(|| {
+			let call = transfer(40);
+			let signature = sign_call(&OTHER_KEY, &call, 0, 10);
+
+			assert_eq!(
+				validate(call.clone(), 0, 10, signature),
+				InvalidTransaction::BadProof.into()
+			);
+			assert_noop!(
+				dispatch(call, 0, 10, signature),
+				Error::<Test>::InvalidSignature
+			);
+		});
+}
+
+#[test]
+fn signature_of_another_call_is_rejected() {
+	ExtBuilder::default()
+		.with_balances(vec![(signer(), 1_000)])
+		.build()
+		.execute_with(|| {
+			let signature = sign_call(&SIGNER_KEY, &transfer(40), 0, 10);
+
+			assert_eq!(
+				validate(transfer(400), 0, 10, signature),
+				InvalidTransaction::BadProof.into()
+			);
+			assert_noop!(
+				dispatch(transfer(400), 0, 10, signature),
+				Error::<Test>::InvalidSignature
+			);
+			// The deadline is part of the signed message too.
+			assert_noop!(
+				dispatch(transfer(40), 0, 20, signature),
+				Error::<Test>::InvalidSignature
+			);
+		});
+}
+
+#[test]
+fn signed_call_cannot_be_replayed() {
+	ExtBuilder::default()
+		.with_balances(vec![(signer(), 1_000)])
+		.build()
+		.execute_with(|| {
+			let call = transfer(40);
+			let signature = sign_call(&SIGNER_KEY, &call, 0, 10);
+			assert_ok!(dispatch(call.clone(), 0, 10, signature));
+
+			assert_eq!(
+				validate(call.clone(), 0, 10, signature),
+				InvalidTransaction::Stale.into()
+			);
+			assert_noop!(
+				dispatch(call, 0, 10, signature),
+				Error::<Test>::InvalidNonce
+			);
+		});
+}
+
+#[test]
+fn expired_signature_is_rejected() {
+	ExtBuilder::default()
+		.with_balances(vec![(signer(), 1_000)])
+		.build()
+		.execute_with(|| {
+			let call = transfer(40);
+			let signature = sign_call(&SIGNER_KEY, &call, 0, 1);
+			System::set_block_number(2);
+
+			assert_eq!(
+				validate(call.clone(), 0, 1, signature),
+				InvalidTransaction::Stale.into()
+			);
+			assert_noop!(
+				dispatch(call, 0, 1, signature),
+				Error::<Test>::SignatureExpired
+			);
+		});
+}
+
+#[test]
+fn future_nonce_requires_the_previous_one() {
+	ExtBuilder::default()
+		.with_balances(vec![(signer(), 1_000)])
+		.build()
+		.execute_with(|| {
+			let call = transfer(40);
+
+			let current = validate(call.clone(), 0, 10, sign_call(&SIGNER_KEY, &call, 0, 10))
+				.expect("current nonce is valid");
+			assert!(current.requires.is_empty());
+
+			let future = validate(call.clone(), 1, 10, sign_call(&SIGNER_KEY, &call, 1, 10))
+				.expect("future nonce is valid");
+			assert_eq!(future.requires, current.provides);
+			assert_eq!(future.longevity, 10);
+
+			assert_noop!(
+				dispatch(call.clone(), 1, 10, sign_call(&SIGNER_KEY, &call, 1, 10)),
+				Error::<Test>::InvalidNonce
+			);
+		});
+}
+
+#[test]
+fn signer_must_afford_the_fee() {
+	ExtBuilder::default()
+		.with_balances(vec![(signer(), WEIGHT_FEE)])
+		.build()
+		.execute_with(|| {
+			let call = transfer(0);
+			let signature = sign_call(&SIGNER_KEY, &call, 0, 10);
+
+			assert_eq!(
+				validate(call.clone(), 0, 10, signature),
+				InvalidTransaction::Payment.into()
+			);
+			assert_noop!(
+				dispatch(call, 0, 10, signature),
+				Error::<Test>::CannotPayFee
+			);
+		});
+}
+
+#[test]
+fn only_the_current_nonce_can_be_included_in_a_block() {
+	ExtBuilder::default()
+		.with_balances(vec![(signer(), 1_000)])
+		.build()
+		.execute_with(|| {
+			let call = transfer(40);
+
+			assert_ok!(pre_dispatch(
+				call.clone(),
+				0,
+				10,
+				sign_call(&SIGNER_KEY, &call, 0, 10)
+			));
+			assert_eq!(
+				pre_dispatch(call.clone(), 1, 10, sign_call(&SIGNER_KEY, &call, 1, 10)),
+				Err(InvalidTransaction::Future.into())
+			);
+
+			assert_ok!(dispatch(
+				call.clone(),
+				0,
+				10,
+				sign_call(&SIGNER_KEY, &call, 0, 10)
+			));
+			assert_eq!(
+				pre_dispatch(call.clone(), 0, 10, sign_call(&SIGNER_KEY, &call, 0, 10)),
+				Err(InvalidTransaction::Stale.into())
+			);
+			assert_ok!(pre_dispatch(
+				call.clone(),
+				1,
+				10,
+				sign_call(&SIGNER_KEY, &call, 1, 10)
+			));
+		});
+}
+
+#[test]
+fn call_the_signer_cannot_pay_for_is_not_included_in_a_block() {
+	ExtBuilder::default()
+		.with_balances(vec![(signer(), WEIGHT_FEE)])
+		.build()
+		.execute_with(|| {
+			let call = transfer(0);
+
+			assert_eq!(
+				pre_dispatch(call.clone(), 0, 10, sign_call(&SIGNER_KEY, &call, 0, 10)),
+				Err(InvalidTransaction::Payment.into())
+			);
+		});
+}
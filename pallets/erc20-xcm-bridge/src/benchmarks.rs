@@ -0,0 +1,68 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+
+use crate::{Call, Config, Erc20TransferGasLimits, Pallet, QuarantinedErc20s};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin;
+use sp_core::H160;
+
+benchmarks! {
+	set_erc20_transfer_gas_limit {
+		let contract_address = H160::repeat_byte(0xee);
+	}: _(RawOrigin::Root, contract_address, Some(500_000))
+	verify {
+		assert_eq!(Erc20TransferGasLimits::<T>::get(contract_address), Some(500_000));
+	}
+
+	release_erc20 {
+		let contract_address = H160::repeat_byte(0xee);
+		QuarantinedErc20s::<T>::insert(contract_address, ());
+	}: _(RawOrigin::Root, contract_address)
+	verify {
+		assert!(!Pallet::<T>::is_quarantined(contract_address));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::ExtBuilder;
+	use crate::Pallet;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn bench_set_erc20_transfer_gas_limit() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_set_erc20_transfer_gas_limit());
+		});
+	}
+
+	#[test]
+	fn bench_release_erc20() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_release_erc20());
+		});
+	}
+}
+
+impl_benchmark_test_suite!(
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
@@ -23,10 +23,21 @@ mod erc20_trap;
 mod errors;
 mod xcm_holding_ext;
 
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
 use frame_support::pallet;
 
 pub use erc20_trap::AssetTrapWrapper;
 pub use pallet::*;
+pub use weights::WeightInfo;
+#[cfg(feature = "runtime-benchmarks")]
+pub use xcm_holding_ext::with_erc20s_in_xcm_holding;
 pub use xcm_holding_ext::XcmExecutorWrapper;
 
 #[pallet]
@@ -35,9 +46,11 @@ pub mod pallet {
 	use crate::erc20_matcher::*;
 	use crate::errors::*;
 	use crate::xcm_holding_ext::*;
+	use crate::WeightInfo;
 	use ethereum_types::BigEndianHash;
 	use fp_evm::{ExitReason, ExitSucceed};
 	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
 	use pallet_evm::{GasWeightMapping, Runner};
 	use sp_core::{H160, H256, U256};
 	use sp_std::vec::Vec;
@@ -56,16 +69,106 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_evm::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type AccountIdConverter: Convert<MultiLocation, H160>;
 		type Erc20MultilocationPrefix: Get<MultiLocation>;
 		type Erc20TransferGasLimit: Get<u64>;
 		type EvmRunner: Runner<Self>;
+		/// Origin allowed to override the transfer gas limit of an erc20 token and to release
+		/// a quarantined erc20 token.
+		type Erc20ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Number of consecutive failed transfers after which an erc20 token is quarantined.
+		type MaxConsecutiveTransferFailures: Get<u32>;
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Gas limit of the `transfer` calls of an erc20 token, overriding `Erc20TransferGasLimit`.
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_transfer_gas_limit)]
+	pub type Erc20TransferGasLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, H160, u64, OptionQuery>;
+
+	/// Number of consecutive failed transfers of an erc20 token.
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_transfer_failures)]
+	pub type Erc20TransferFailures<T: Config> =
+		StorageMap<_, Blake2_128Concat, H160, u32, ValueQuery>;
+
+	/// Erc20 tokens that can no longer be transacted through xcm, because their transfers kept
+	/// failing.
+	#[pallet::storage]
+	pub type QuarantinedErc20s<T: Config> = StorageMap<_, Blake2_128Concat, H160, (), OptionQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The erc20 token is not quarantined
+		NotQuarantined,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The transfer gas limit of an erc20 token has been set, `None` meaning that the default
+		/// gas limit applies again.
+		Erc20TransferGasLimitSet {
+			contract_address: H160,
+			gas_limit: Option<u64>,
+		},
+		/// An erc20 token has been quarantined after too many consecutive failed transfers.
+		Erc20Quarantined {
+			contract_address: H160,
+			failures: u32,
+		},
+		/// A quarantined erc20 token can be transacted through xcm again.
+		Erc20Released { contract_address: H160 },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Override the gas limit of the `transfer` calls of an erc20 token, or restore the
+		/// default gas limit if `gas_limit` is `None`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_erc20_transfer_gas_limit())]
+		pub fn set_erc20_transfer_gas_limit(
+			origin: OriginFor<T>,
+			contract_address: H160,
+			gas_limit: Option<u64>,
+		) -> DispatchResult {
+			T::Erc20ManagerOrigin::ensure_origin(origin)?;
+
+			Erc20TransferGasLimits::<T>::set(contract_address, gas_limit);
+
+			Self::deposit_event(Event::Erc20TransferGasLimitSet {
+				contract_address,
+				gas_limit,
+			});
+			Ok(())
+		}
+
+		/// Allow a quarantined erc20 token to be transacted through xcm again.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::release_erc20())]
+		pub fn release_erc20(origin: OriginFor<T>, contract_address: H160) -> DispatchResult {
+			T::Erc20ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				QuarantinedErc20s::<T>::take(contract_address).is_some(),
+				Error::<T>::NotQuarantined
+			);
+			Erc20TransferFailures::<T>::remove(contract_address);
+
+			Self::deposit_event(Event::Erc20Released { contract_address });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		pub fn is_erc20_asset(asset: &MultiAsset) -> bool {
 			Erc20Matcher::<T::Erc20MultilocationPrefix>::is_erc20_asset(asset)
 		}
+		pub fn is_quarantined(contract_address: H160) -> bool {
+			QuarantinedErc20s::<T>::contains_key(contract_address)
+		}
 		pub fn gas_limit_of_erc20_transfer(asset_id: &AssetId) -> u64 {
 			if let AssetId::Concrete(multilocation) = asset_id {
 				if let Some(Junction::GeneralKey {
@@ -81,6 +184,14 @@ pub mod pallet {
 						}
 					}
 				}
+				if let Ok(contract_address) =
+					Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_erc20_multilocation(
+						multilocation,
+					) {
+					if let Some(gas_limit) = Erc20TransferGasLimits::<T>::get(contract_address) {
+						return gas_limit;
+					}
+				}
 			};
 			T::Erc20TransferGasLimit::get()
 		}
@@ -142,6 +253,48 @@ pub mod pallet {
 
 			Ok(())
 		}
+		fn ensure_not_quarantined(contract_address: H160) -> XcmResult {
+			ensure!(
+				!Self::is_quarantined(contract_address),
+				XcmError::FailedToTransactAsset("erc20 token is quarantined")
+			);
+			Ok(())
+		}
+		// Keep track of the consecutive failed transfers of an erc20 token, and quarantine it
+		// once they reach `MaxConsecutiveTransferFailures`, so that broken tokens don't keep
+		// wasting the weight of inbound xcm messages.
+		// Only the failures caused by the contract itself (a revert or an invalid return value)
+		// are counted: failures of the EVM runner never quarantine a token.
+		fn note_erc20_transfer_result(
+			contract_address: H160,
+			result: &Result<(), Erc20TransferError>,
+		) {
+			match result {
+				Ok(()) => {
+					if Erc20TransferFailures::<T>::contains_key(contract_address) {
+						Erc20TransferFailures::<T>::remove(contract_address);
+					}
+				}
+				Err(
+					Erc20TransferError::ContractTransferFail
+					| Erc20TransferError::ContractReturnInvalidValue,
+				) => {
+					let failures =
+						Erc20TransferFailures::<T>::get(contract_address).saturating_add(1);
+					if failures >= T::MaxConsecutiveTransferFailures::get() {
+						Erc20TransferFailures::<T>::remove(contract_address);
+						QuarantinedErc20s::<T>::insert(contract_address, ());
+						Self::deposit_event(Event::Erc20Quarantined {
+							contract_address,
+							failures,
+						});
+					} else {
+						Erc20TransferFailures::<T>::insert(contract_address, failures);
+					}
+				}
+				Err(_) => (),
+			}
+		}
 	}
 
 	impl<T: Config> xcm_executor::traits::TransactAsset for Pallet<T> {
@@ -155,6 +308,7 @@ pub mod pallet {
 		) -> XcmResult {
 			let (contract_address, amount) =
 				Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_fungibles(what)?;
+			Self::ensure_not_quarantined(contract_address)?;
 
 			let beneficiary = T::AccountIdConverter::convert_ref(who)
 				.map_err(|()| MatchError::AccountIdConversionFailed)?;
@@ -166,20 +320,23 @@ pub mod pallet {
 				match erc20s_origins.drain(contract_address, amount) {
 					// We perform the evm transfers in a storage transaction to ensure that if one
 					// of them fails all the changes of the previous evm calls are rolled back.
-					Ok(tokens_to_transfer) => frame_support::storage::with_storage_layer(|| {
-						tokens_to_transfer
-							.into_iter()
-							.try_for_each(|(from, subamount)| {
-								Self::erc20_transfer(
-									contract_address,
-									from,
-									beneficiary,
-									subamount,
-									gas_limit,
-								)
-							})
-					})
-					.map_err(Into::into),
+					Ok(tokens_to_transfer) => {
+						let result = frame_support::storage::with_storage_layer(|| {
+							tokens_to_transfer
+								.into_iter()
+								.try_for_each(|(from, subamount)| {
+									Self::erc20_transfer(
+										contract_address,
+										from,
+										beneficiary,
+										subamount,
+										gas_limit,
+									)
+								})
+						});
+						Self::note_erc20_transfer_result(contract_address, &result);
+						result.map_err(Into::into)
+					}
 					Err(DrainError::AssetNotFound) => Err(XcmError::AssetNotFound),
 					Err(DrainError::NotEnoughFounds) => Err(XcmError::FailedToTransactAsset(
 						"not enough founds in xcm holding",
@@ -202,6 +359,7 @@ pub mod pallet {
 		) -> Result<Assets, XcmError> {
 			let (contract_address, amount) =
 				Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_fungibles(asset)?;
+			Self::ensure_not_quarantined(contract_address)?;
 
 			let from = T::AccountIdConverter::convert_ref(from)
 				.map_err(|()| MatchError::AccountIdConversionFailed)?;
@@ -213,9 +371,11 @@ pub mod pallet {
 
 			// We perform the evm transfers in a storage transaction to ensure that if it fail
 			// any contract storage changes are rolled back.
-			frame_support::storage::with_storage_layer(|| {
+			let result = frame_support::storage::with_storage_layer(|| {
 				Self::erc20_transfer(contract_address, from, to, amount, gas_limit)
-			})?;
+			});
+			Self::note_erc20_transfer_result(contract_address, &result);
+			result?;
 
 			Ok(asset.clone().into())
 		}
@@ -234,6 +394,7 @@ pub mod pallet {
 		) -> Result<Assets, XcmError> {
 			let (contract_address, amount) =
 				Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_fungibles(what)?;
+			Self::ensure_not_quarantined(contract_address)?;
 			let who = T::AccountIdConverter::convert_ref(who)
 				.map_err(|()| MatchError::AccountIdConversionFailed)?;
 
@@ -0,0 +1,268 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test utilities
+
+use crate as pallet_erc20_xcm_bridge;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, Everything},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use pallet_evm::{AddressMapping, EnsureAddressNever};
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_std::borrow::Borrow;
+use xcm::latest::prelude::*;
+
+pub type AccountId = H160;
+pub type Balance = u128;
+pub type BlockNumber = u32;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage},
+		EVM: pallet_evm::{Pallet, Call, Storage, Event<T>},
+		Erc20XcmBridge: pallet_erc20_xcm_bridge::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Header = sp_runtime::generic::Header<BlockNumber, BlakeTwo256>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 0;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 4];
+	type MaxLocks = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
+/// Block storage limit in bytes. Set to 40 KB.
+const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
+
+parameter_types! {
+	pub BlockGasLimit: U256 = U256::from(15_000_000);
+	pub const WeightPerGas: Weight = Weight::from_parts(25_000, 0);
+	pub GasLimitPovSizeRatio: u64 = {
+		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+		block_gas_limit.saturating_div(MAX_POV_SIZE)
+	};
+	pub GasLimitStorageGrowthRatio: u64 = {
+		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+		block_gas_limit.saturating_div(BLOCK_STORAGE_LIMIT)
+	};
+}
+
+pub struct IdentityAddressMapping;
+impl AddressMapping<AccountId> for IdentityAddressMapping {
+	fn into_account_id(address: H160) -> AccountId {
+		address
+	}
+}
+
+impl pallet_evm::Config for Test {
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type CallOrigin = EnsureAddressNever<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type AddressMapping = IdentityAddressMapping;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type PrecompilesType = ();
+	type PrecompilesValue = ();
+	type ChainId = ();
+	type OnChargeTransaction = ();
+	type BlockGasLimit = BlockGasLimit;
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type FindAuthor = ();
+	type OnCreate = ();
+	type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
+	type GasLimitStorageGrowthRatio = GasLimitStorageGrowthRatio;
+	type Timestamp = Timestamp;
+	type WeightInfo = pallet_evm::weights::SubstrateWeight<Test>;
+}
+
+/// Converts a local `AccountKey20` into the corresponding ethereum address.
+pub struct AccountKey20Converter;
+impl xcm_executor::traits::Convert<MultiLocation, H160> for AccountKey20Converter {
+	fn convert_ref(location: impl Borrow<MultiLocation>) -> Result<H160, ()> {
+		match location.borrow() {
+			MultiLocation {
+				parents: 0,
+				interior: X1(AccountKey20 { key, .. }),
+			} => Ok(H160(*key)),
+			_ => Err(()),
+		}
+	}
+}
+
+parameter_types! {
+	pub Erc20MultilocationPrefix: MultiLocation = MultiLocation::new(0, X1(PalletInstance(42)));
+	pub const Erc20TransferGasLimit: u64 = 200_000;
+}
+
+impl pallet_erc20_xcm_bridge::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AccountIdConverter = AccountKey20Converter;
+	type Erc20MultilocationPrefix = Erc20MultilocationPrefix;
+	type Erc20TransferGasLimit = Erc20TransferGasLimit;
+	type EvmRunner = pallet_evm::runner::stack::Runner<Self>;
+	type Erc20ManagerOrigin = EnsureRoot<AccountId>;
+	type MaxConsecutiveTransferFailures = ConstU32<3>;
+	type WeightInfo = ();
+}
+
+// Runtime code of an erc20 contract whose calls all return `true`.
+// PUSH1 0x01 PUSH1 0x00 MSTORE PUSH1 0x20 PUSH1 0x00 RETURN
+pub(crate) const WORKING_ERC20_CODE: [u8; 10] =
+	[0x60, 0x01, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3];
+// Runtime code of an erc20 contract whose calls all revert.
+// PUSH1 0x00 PUSH1 0x00 REVERT
+pub(crate) const REVERTING_ERC20_CODE: [u8; 5] = [0x60, 0x00, 0x60, 0x00, 0xfd];
+
+pub(crate) const ERC20: H160 = H160([0xee; 20]);
+pub(crate) const ALICE: H160 = H160([0xaa; 20]);
+pub(crate) const BOB: H160 = H160([0xbb; 20]);
+
+pub(crate) fn deploy_erc20(code: &[u8]) {
+	pallet_evm::Pallet::<Test>::create_account(ERC20, code.to_vec());
+}
+
+pub(crate) fn erc20_asset(amount: u128) -> MultiAsset {
+	(
+		MultiLocation::new(
+			0,
+			X2(
+				PalletInstance(42),
+				AccountKey20 {
+					network: None,
+					key: ERC20.0,
+				},
+			),
+		),
+		amount,
+	)
+		.into()
+}
+
+pub(crate) fn account_location(address: H160) -> MultiLocation {
+	MultiLocation::new(
+		0,
+		X1(AccountKey20 {
+			network: None,
+			key: address.0,
+		}),
+	)
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder;
+
+impl ExtBuilder {
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::default()
+			.build_storage::<Test>()
+			.expect("Frame system builds valid default genesis config");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+pub(crate) fn events() -> Vec<pallet_erc20_xcm_bridge::Event<Test>> {
+	System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| {
+			if let RuntimeEvent::Erc20XcmBridge(inner) = e {
+				Some(inner)
+			} else {
+				None
+			}
+		})
+		.collect::<Vec<_>>()
+}
+
+pub fn expect_events(e: Vec<pallet_erc20_xcm_bridge::Event<Test>>) {
+	assert_eq!(events(), e);
+}
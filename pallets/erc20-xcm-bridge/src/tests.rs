@@ -0,0 +1,237 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{Erc20TransferFailures, Error, Event, Pallet};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::TransactAsset;
+
+fn context() -> XcmContext {
+	XcmContext {
+		origin: None,
+		message_id: [0; 32],
+		topic: None,
+	}
+}
+
+fn transfer_erc20() -> Result<xcm_executor::Assets, XcmError> {
+	Erc20XcmBridge::internal_transfer_asset(
+		&erc20_asset(100),
+		&account_location(ALICE),
+		&account_location(BOB),
+		&context(),
+	)
+}
+
+#[test]
+fn set_erc20_transfer_gas_limit_overrides_default_gas_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_id = erc20_asset(100).id;
+		assert_eq!(
+			Pallet::<Test>::gas_limit_of_erc20_transfer(&asset_id),
+			200_000
+		);
+
+		assert_ok!(Erc20XcmBridge::set_erc20_transfer_gas_limit(
+			RuntimeOrigin::root(),
+			ERC20,
+			Some(500_000)
+		));
+		assert_eq!(
+			Pallet::<Test>::gas_limit_of_erc20_transfer(&asset_id),
+			500_000
+		);
+
+		assert_ok!(Erc20XcmBridge::set_erc20_transfer_gas_limit(
+			RuntimeOrigin::root(),
+			ERC20,
+			None
+		));
+		assert_eq!(
+			Pallet::<Test>::gas_limit_of_erc20_transfer(&asset_id),
+			200_000
+		);
+
+		expect_events(vec![
+			Event::Erc20TransferGasLimitSet {
+				contract_address: ERC20,
+				gas_limit: Some(500_000),
+			},
+			Event::Erc20TransferGasLimitSet {
+				contract_address: ERC20,
+				gas_limit: None,
+			},
+		]);
+	});
+}
+
+#[test]
+fn set_erc20_transfer_gas_limit_requires_manager_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Erc20XcmBridge::set_erc20_transfer_gas_limit(
+				RuntimeOrigin::signed(ALICE),
+				ERC20,
+				Some(500_000)
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn gas_limit_in_asset_location_takes_precedence() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Erc20XcmBridge::set_erc20_transfer_gas_limit(
+			RuntimeOrigin::root(),
+			ERC20,
+			Some(500_000)
+		));
+
+		let mut data = [0u8; 32];
+		data[..10].copy_from_slice(b"gas_limit:");
+		data[10..18].copy_from_slice(&300_000u64.to_le_bytes());
+		let mut location = erc20_asset(100).id;
+		if let Concrete(ref mut location) = location {
+			location
+				.push_interior(GeneralKey { length: 18, data })
+				.expect("there is room for the gas limit");
+		}
+
+		assert_eq!(
+			Pallet::<Test>::gas_limit_of_erc20_transfer(&location),
+			300_000
+		);
+	});
+}
+
+#[test]
+fn successful_transfer_does_not_count_as_failure() {
+	ExtBuilder::default().build().execute_with(|| {
+		deploy_erc20(&WORKING_ERC20_CODE);
+
+		assert_ok!(transfer_erc20());
+		assert_eq!(Erc20TransferFailures::<Test>::get(ERC20), 0);
+		assert!(!Pallet::<Test>::is_quarantined(ERC20));
+	});
+}
+
+#[test]
+fn repeatedly_failing_erc20_is_quarantined() {
+	ExtBuilder::default().build().execute_with(|| {
+		deploy_erc20(&REVERTING_ERC20_CODE);
+
+		for failures in 1..3 {
+			assert!(transfer_erc20().is_err());
+			assert_eq!(Erc20TransferFailures::<Test>::get(ERC20), failures);
+			assert!(!Pallet::<Test>::is_quarantined(ERC20));
+		}
+
+		assert!(transfer_erc20().is_err());
+		assert!(Pallet::<Test>::is_quarantined(ERC20));
+		assert_eq!(Erc20TransferFailures::<Test>::get(ERC20), 0);
+
+		expect_events(vec![Event::Erc20Quarantined {
+			contract_address: ERC20,
+			failures: 3,
+		}]);
+	});
+}
+
+#[test]
+fn failures_must_be_consecutive_to_quarantine_erc20() {
+	ExtBuilder::default().build().execute_with(|| {
+		deploy_erc20(&REVERTING_ERC20_CODE);
+		assert!(transfer_erc20().is_err());
+		assert!(transfer_erc20().is_err());
+
+		deploy_erc20(&WORKING_ERC20_CODE);
+		assert_ok!(transfer_erc20());
+		assert_eq!(Erc20TransferFailures::<Test>::get(ERC20), 0);
+
+		deploy_erc20(&REVERTING_ERC20_CODE);
+		assert!(transfer_erc20().is_err());
+		assert!(!Pallet::<Test>::is_quarantined(ERC20));
+	});
+}
+
+#[test]
+fn quarantined_erc20_cannot_be_transacted() {
+	ExtBuilder::default().build().execute_with(|| {
+		deploy_erc20(&REVERTING_ERC20_CODE);
+		for _ in 0..3 {
+			assert!(transfer_erc20().is_err());
+		}
+
+		// Even once fixed, the token stays quarantined until governance releases it
+		deploy_erc20(&WORKING_ERC20_CODE);
+		let quarantined = XcmError::FailedToTransactAsset("erc20 token is quarantined");
+		assert_eq!(transfer_erc20(), Err(quarantined));
+		assert_eq!(
+			Erc20XcmBridge::withdraw_asset(
+				&erc20_asset(100),
+				&account_location(ALICE),
+				Some(&context())
+			),
+			Err(quarantined)
+		);
+		assert_eq!(
+			Erc20XcmBridge::deposit_asset(&erc20_asset(100), &account_location(BOB), &context()),
+			Err(quarantined)
+		);
+	});
+}
+
+#[test]
+fn release_erc20_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		deploy_erc20(&REVERTING_ERC20_CODE);
+		for _ in 0..3 {
+			assert!(transfer_erc20().is_err());
+		}
+		deploy_erc20(&WORKING_ERC20_CODE);
+
+		assert_ok!(Erc20XcmBridge::release_erc20(RuntimeOrigin::root(), ERC20));
+		assert!(!Pallet::<Test>::is_quarantined(ERC20));
+		assert_ok!(transfer_erc20());
+
+		expect_events(vec![
+			Event::Erc20Quarantined {
+				contract_address: ERC20,
+				failures: 3,
+			},
+			Event::Erc20Released {
+				contract_address: ERC20,
+			},
+		]);
+	});
+}
+
+#[test]
+fn release_erc20_fails_if_not_quarantined() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Erc20XcmBridge::release_erc20(RuntimeOrigin::root(), ERC20),
+			Error::<Test>::NotQuarantined
+		);
+		assert_noop!(
+			Erc20XcmBridge::release_erc20(RuntimeOrigin::signed(ALICE), ERC20),
+			DispatchError::BadOrigin
+		);
+	});
+}
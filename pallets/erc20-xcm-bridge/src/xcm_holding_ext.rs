@@ -131,6 +131,18 @@ where
 	}
 }
 
+/// Executes `f` with an xcm holding extension already tracking the given erc20 tokens
+/// (contract address, holder, amount), as if they had been withdrawn earlier in the XCM execution.
+/// This allows to benchmark the erc20 xcm instructions outside of [`XcmExecutorWrapper`].
+#[cfg(feature = "runtime-benchmarks")]
+pub fn with_erc20s_in_xcm_holding<R>(erc20s: Vec<(H160, H160, U256)>, f: impl FnOnce() -> R) -> R {
+	let mut erc20s_origins = XcmHoldingErc20sOrigins::default();
+	for (contract_address, who, amount) in erc20s {
+		erc20s_origins.insert(contract_address, who, amount);
+	}
+	XCM_HOLDING_ERC20_ORIGINS::using(&mut erc20s_origins, f)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
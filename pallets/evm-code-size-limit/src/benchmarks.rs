@@ -0,0 +1,51 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarking
+
+use crate::{Call, Config, Pallet};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+
+benchmarks! {
+	set_code_size_limit {
+		let limit = T::MaxCodeSizeLimit::get();
+	}: _(RawOrigin::Root, Some(limit))
+	verify {
+		assert_eq!(Pallet::<T>::code_size_limit(), Some(limit));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::ExtBuilder;
+	use crate::Pallet;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn bench_set_code_size_limit() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_set_code_size_limit());
+		});
+	}
+}
+
+impl_benchmark_test_suite!(
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
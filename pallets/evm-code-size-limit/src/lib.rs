@@ -0,0 +1,117 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # EVM code size limit pallet
+//!
+//! This pallet allows governance to raise the maximum size of the deployed contract code above
+//! the EIP-170 limit of 24 KiB, up to `MaxCodeSizeLimit`, without a runtime upgrade. The maximum
+//! init code size follows EIP-3860 and is always twice the contract code size limit.
+//!
+//! The limit is applied by wrapping the EVM runner in [`CodeSizeLimitRunner`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod runner;
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use runner::CodeSizeLimitRunner;
+pub use weights::WeightInfo;
+
+use frame_support::pallet;
+
+/// Contract code size limit defined by EIP-170, used while no override is set.
+pub const DEFAULT_CODE_SIZE_LIMIT: u32 = 0x6000;
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that is allowed to change the code size limit.
+		type CodeSizeLimitOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Upper bound of the contract code size limit that can be set by governance.
+		#[pallet::constant]
+		type MaxCodeSizeLimit: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Contract code size limit overriding the EIP-170 one, if any.
+	#[pallet::storage]
+	#[pallet::getter(fn code_size_limit)]
+	pub type CodeSizeLimit<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The code size limit is below the EIP-170 one or above `MaxCodeSizeLimit`.
+		CodeSizeLimitOutOfBounds,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The contract code size limit changed, `None` restoring the EIP-170 one.
+		CodeSizeLimitSet { limit: Option<u32> },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Override the contract code size limit, or restore the EIP-170 one with `None`.
+		/// The init code size limit is twice the code size limit.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_code_size_limit())]
+		pub fn set_code_size_limit(origin: OriginFor<T>, limit: Option<u32>) -> DispatchResult {
+			T::CodeSizeLimitOrigin::ensure_origin(origin)?;
+
+			if let Some(limit) = limit {
+				ensure!(
+					limit >= DEFAULT_CODE_SIZE_LIMIT && limit <= T::MaxCodeSizeLimit::get(),
+					Error::<T>::CodeSizeLimitOutOfBounds
+				);
+			}
+			CodeSizeLimit::<T>::set(limit);
+
+			Self::deposit_event(Event::CodeSizeLimitSet { limit });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The contract code size limit currently enforced.
+		pub fn effective_code_size_limit() -> u32 {
+			Self::code_size_limit().unwrap_or(DEFAULT_CODE_SIZE_LIMIT)
+		}
+	}
+}
@@ -0,0 +1,186 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! EVM runner wrapper applying the code size limit set by governance.
+
+use crate::{CodeSizeLimit, Config};
+use fp_evm::{CallInfo, CreateInfo};
+use frame_support::weights::Weight;
+use pallet_evm::{Runner, RunnerError};
+use sp_core::{H160, H256, U256};
+use sp_std::{borrow::Cow, marker::PhantomData, vec::Vec};
+
+/// Wraps the EVM runner `Inner`, giving it an EVM config which uses the code size limits set by
+/// governance, if any.
+pub struct CodeSizeLimitRunner<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T, Inner> CodeSizeLimitRunner<T, Inner>
+where
+	T: Config + pallet_evm::Config,
+{
+	fn with_code_size_limit(config: &fp_evm::Config) -> Cow<fp_evm::Config> {
+		match CodeSizeLimit::<T>::get() {
+			Some(limit) => {
+				let limit = limit as usize;
+				let mut config = config.clone();
+				config.create_contract_limit = Some(limit);
+				config.max_initcode_size = Some(limit.saturating_mul(2));
+				Cow::Owned(config)
+			}
+			None => Cow::Borrowed(config),
+		}
+	}
+}
+
+impl<T, Inner> Runner<T> for CodeSizeLimitRunner<T, Inner>
+where
+	T: Config + pallet_evm::Config,
+	Inner: Runner<T>,
+{
+	type Error = Inner::Error;
+
+	fn validate(
+		source: H160,
+		target: Option<H160>,
+		input: Vec<u8>,
+		value: U256,
+		gas_limit: u64,
+		max_fee_per_gas: Option<U256>,
+		max_priority_fee_per_gas: Option<U256>,
+		nonce: Option<U256>,
+		access_list: Vec<(H160, Vec<H256>)>,
+		is_transactional: bool,
+		weight_limit: Option<Weight>,
+		transaction_len: Option<u64>,
+		evm_config: &fp_evm::Config,
+	) -> Result<(), RunnerError<Self::Error>> {
+		Inner::validate(
+			source,
+			target,
+			input,
+			value,
+			gas_limit,
+			max_fee_per_gas,
+			max_priority_fee_per_gas,
+			nonce,
+			access_list,
+			is_transactional,
+			weight_limit,
+			transaction_len,
+			&Self::with_code_size_limit(evm_config),
+		)
+	}
+
+	fn call(
+		source: H160,
+		target: H160,
+		input: Vec<u8>,
+		value: U256,
+		gas_limit: u64,
+		max_fee_per_gas: Option<U256>,
+		max_priority_fee_per_gas: Option<U256>,
+		nonce: Option<U256>,
+		access_list: Vec<(H160, Vec<H256>)>,
+		is_transactional: bool,
+		validate: bool,
+		weight_limit: Option<Weight>,
+		transaction_len: Option<u64>,
+		config: &fp_evm::Config,
+	) -> Result<CallInfo, RunnerError<Self::Error>> {
+		Inner::call(
+			source,
+			target,
+			input,
+			value,
+			gas_limit,
+			max_fee_per_gas,
+			max_priority_fee_per_gas,
+			nonce,
+			access_list,
+			is_transactional,
+			validate,
+			weight_limit,
+			transaction_len,
+			&Self::with_code_size_limit(config),
+		)
+	}
+
+	fn create(
+		source: H160,
+		init: Vec<u8>,
+		value: U256,
+		gas_limit: u64,
+		max_fee_per_gas: Option<U256>,
+		max_priority_fee_per_gas: Option<U256>,
+		nonce: Option<U256>,
+		access_list: Vec<(H160, Vec<H256>)>,
+		is_transactional: bool,
+		validate: bool,
+		weight_limit: Option<Weight>,
+		transaction_len: Option<u64>,
+		config: &fp_evm::Config,
+	) -> Result<CreateInfo, RunnerError<Self::Error>> {
+		Inner::create(
+			source,
+			init,
+			value,
+			gas_limit,
+			max_fee_per_gas,
+			max_priority_fee_per_gas,
+			nonce,
+			access_list,
+			is_transactional,
+			validate,
+			weight_limit,
+			transaction_len,
+			&Self::with_code_size_limit(config),
+		)
+	}
+
+	fn create2(
+		source: H160,
+		init: Vec<u8>,
+		salt: H256,
+		value: U256,
+		gas_limit: u64,
+		max_fee_per_gas: Option<U256>,
+		max_priority_fee_per_gas: Option<U256>,
+		nonce: Option<U256>,
+		access_list: Vec<(H160, Vec<H256>)>,
+		is_transactional: bool,
+		validate: bool,
+		weight_limit: Option<Weight>,
+		transaction_len: Option<u64>,
+		config: &fp_evm::Config,
+	) -> Result<CreateInfo, RunnerError<Self::Error>> {
+		Inner::create2(
+			source,
+			init,
+			salt,
+			value,
+			gas_limit,
+			max_fee_per_gas,
+			max_priority_fee_per_gas,
+			nonce,
+			access_list,
+			is_transactional,
+			validate,
+			weight_limit,
+			transaction_len,
+			&Self::with_code_size_limit(config),
+		)
+	}
+}
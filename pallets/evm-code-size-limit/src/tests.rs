@@ -0,0 +1,111 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{Error, Event, Pallet, DEFAULT_CODE_SIZE_LIMIT};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::U256;
+use sp_runtime::DispatchError;
+
+/// Deploys a contract whose code is `code_size` zero bytes, returning whether it succeeded.
+fn deploy_contract_of_size(code_size: u32) -> bool {
+	let size = code_size.to_be_bytes();
+	// PUSH3 code_size, PUSH1 0, RETURN
+	let init = vec![0x62, size[1], size[2], size[3], 0x60, 0x00, 0xf3];
+	assert_ok!(EVM::create(
+		RuntimeOrigin::root(),
+		ALICE,
+		init,
+		U256::zero(),
+		14_000_000,
+		U256::zero(),
+		None,
+		None,
+		Vec::new(),
+	));
+	System::events().into_iter().any(|r| {
+		matches!(
+			r.event,
+			RuntimeEvent::EVM(pallet_evm::Event::Created { .. })
+		)
+	})
+}
+
+#[test]
+fn set_code_size_limit_within_bounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			Pallet::<Test>::effective_code_size_limit(),
+			DEFAULT_CODE_SIZE_LIMIT
+		);
+
+		assert_ok!(EvmCodeSizeLimit::set_code_size_limit(
+			RuntimeOrigin::root(),
+			Some(48 * 1024)
+		));
+		assert_eq!(Pallet::<Test>::effective_code_size_limit(), 48 * 1024);
+
+		assert_ok!(EvmCodeSizeLimit::set_code_size_limit(
+			RuntimeOrigin::root(),
+			None
+		));
+		assert_eq!(
+			Pallet::<Test>::effective_code_size_limit(),
+			DEFAULT_CODE_SIZE_LIMIT
+		);
+
+		expect_events(vec![
+			Event::CodeSizeLimitSet {
+				limit: Some(48 * 1024),
+			},
+			Event::CodeSizeLimitSet { limit: None },
+		]);
+	});
+}
+
+#[test]
+fn set_code_size_limit_fails_out_of_bounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EvmCodeSizeLimit::set_code_size_limit(
+				RuntimeOrigin::root(),
+				Some(DEFAULT_CODE_SIZE_LIMIT - 1)
+			),
+			Error::<Test>::CodeSizeLimitOutOfBounds
+		);
+		assert_noop!(
+			EvmCodeSizeLimit::set_code_size_limit(RuntimeOrigin::root(), Some(64 * 1024 + 1)),
+			Error::<Test>::CodeSizeLimitOutOfBounds
+		);
+		assert_noop!(
+			EvmCodeSizeLimit::set_code_size_limit(RuntimeOrigin::signed(ALICE), None),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn raised_code_size_limit_allows_larger_contracts() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(!deploy_contract_of_size(DEFAULT_CODE_SIZE_LIMIT + 1));
+
+		assert_ok!(EvmCodeSizeLimit::set_code_size_limit(
+			RuntimeOrigin::root(),
+			Some(32 * 1024)
+		));
+		assert!(deploy_contract_of_size(DEFAULT_CODE_SIZE_LIMIT + 1));
+	});
+}
@@ -0,0 +1,85 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+
+use crate::{Call, Config, ContractMetadata, DeploymentProof, MetadataOf, Pallet};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_core::{H160, H256};
+use sp_runtime::traits::Convert;
+use sp_std::vec;
+
+fn max_cid<T: Config>() -> BoundedVec<u8, T::MaxCidLength> {
+	vec![0x01; T::MaxCidLength::get() as usize]
+		.try_into()
+		.expect("cid fits in the bound")
+}
+
+benchmarks! {
+	register_metadata {
+		let caller: T::AccountId = whitelisted_caller();
+		let salt = H256::repeat_byte(0x01);
+		let init_code_hash = H256::repeat_byte(0x02);
+		let proof = DeploymentProof::Create2 { salt, init_code_hash };
+		let contract = proof.contract_address(T::AddressMapping::convert(caller.clone()));
+	}: _(RawOrigin::Signed(caller), contract, Some(proof), max_cid::<T>(), H256::repeat_byte(0x11))
+	verify {
+		assert!(MetadataOf::<T>::contains_key(contract));
+	}
+
+	clear_metadata {
+		let contract = H160::repeat_byte(0xaa);
+		MetadataOf::<T>::insert(contract, ContractMetadata {
+			registrar: contract,
+			cid: max_cid::<T>(),
+			source_hash: H256::repeat_byte(0x11),
+		});
+	}: _(RawOrigin::Root, contract)
+	verify {
+		assert!(!MetadataOf::<T>::contains_key(contract));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::ExtBuilder;
+	use crate::Pallet;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn bench_register_metadata() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_register_metadata());
+		});
+	}
+
+	#[test]
+	fn bench_clear_metadata() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_clear_metadata());
+		});
+	}
+}
+
+impl_benchmark_test_suite!(
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
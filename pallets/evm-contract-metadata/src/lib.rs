@@ -0,0 +1,239 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # EVM contract metadata pallet
+//!
+//! This pallet provides an on-chain registry of smart contract metadata: an IPFS CID (usually
+//! pointing to the Solidity metadata JSON and sources) and a hash of the sources. Explorers can
+//! use it to verify contracts without relying on a centralized database.
+//!
+//! The metadata of a contract can be registered by the contract itself (e.g. from its
+//! constructor) or by its deployer. The deployer proves it deployed the contract by providing
+//! the parameters from which the contract address is derived ([`DeploymentProof`]), so that no
+//! deployment has to be tracked on-chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::pallet;
+use frame_support::pallet_prelude::*;
+use sp_core::{H160, H256};
+
+/// Parameters from which the address of a contract is derived, proving that an address
+/// deployed it.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum DeploymentProof {
+	/// The contract was deployed with `CREATE`, using the deployer nonce.
+	Create { nonce: u64 },
+	/// The contract was deployed with `CREATE2`.
+	Create2 { salt: H256, init_code_hash: H256 },
+}
+
+impl DeploymentProof {
+	/// Address of the contract deployed by `deployer` with these parameters.
+	pub fn contract_address(&self, deployer: H160) -> H160 {
+		match self {
+			DeploymentProof::Create { nonce } => create_address(deployer, *nonce),
+			DeploymentProof::Create2 {
+				salt,
+				init_code_hash,
+			} => create2_address(deployer, *salt, *init_code_hash),
+		}
+	}
+}
+
+/// Address of a contract deployed with `CREATE`: `keccak256(rlp([deployer, nonce]))[12..]`.
+pub fn create_address(deployer: H160, nonce: u64) -> H160 {
+	let nonce_bytes = nonce.to_be_bytes();
+	let leading_zeros = nonce_bytes.iter().take_while(|b| **b == 0).count();
+	let nonce_bytes = &nonce_bytes[leading_zeros..];
+
+	// The payload is always shorter than 56 bytes, so the RLP headers fit in a single byte.
+	let mut stream = sp_std::vec::Vec::with_capacity(31);
+	stream.push(0);
+	stream.push(0x80 + 20);
+	stream.extend_from_slice(deployer.as_bytes());
+	match nonce_bytes {
+		[byte] if *byte < 0x80 => stream.push(*byte),
+		bytes => {
+			stream.push(0x80 + bytes.len() as u8);
+			stream.extend_from_slice(bytes);
+		}
+	}
+	stream[0] = 0xc0 + (stream.len() - 1) as u8;
+
+	H160::from_slice(&sp_io::hashing::keccak_256(&stream)[12..])
+}
+
+/// Address of a contract deployed with `CREATE2`:
+/// `keccak256(0xff ++ deployer ++ salt ++ keccak256(init_code))[12..]`.
+pub fn create2_address(deployer: H160, salt: H256, init_code_hash: H256) -> H160 {
+	let mut stream = [0u8; 85];
+	stream[0] = 0xff;
+	stream[1..21].copy_from_slice(deployer.as_bytes());
+	stream[21..53].copy_from_slice(salt.as_bytes());
+	stream[53..85].copy_from_slice(init_code_hash.as_bytes());
+
+	H160::from_slice(&sp_io::hashing::keccak_256(&stream)[12..])
+}
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Convert;
+
+	/// Metadata registered for a contract.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(MaxCidLength))]
+	pub struct ContractMetadata<MaxCidLength: Get<u32>> {
+		/// Address which registered the metadata: the contract itself or its deployer.
+		pub registrar: H160,
+		/// IPFS CID of the contract metadata.
+		pub cid: BoundedVec<u8, MaxCidLength>,
+		/// Hash of the contract sources.
+		pub source_hash: H256,
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Convert an account id into the corresponding EVM address.
+		type AddressMapping: Convert<Self::AccountId, H160>;
+
+		/// Origin that is allowed to remove the metadata of any contract (e.g. abusive content).
+		type MetadataManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum length of an IPFS CID.
+		#[pallet::constant]
+		type MaxCidLength: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Metadata registered for each contract.
+	#[pallet::storage]
+	#[pallet::getter(fn metadata_of)]
+	pub type MetadataOf<T: Config> =
+		StorageMap<_, Blake2_128Concat, H160, ContractMetadata<T::MaxCidLength>, OptionQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The caller is neither the contract nor its deployer.
+		NotContractOrDeployer,
+		/// The contract has no registered metadata.
+		NoMetadata,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The metadata of a contract was registered or updated.
+		MetadataRegistered {
+			contract: H160,
+			registrar: H160,
+			cid: BoundedVec<u8, T::MaxCidLength>,
+			source_hash: H256,
+		},
+		/// The metadata of a contract was removed.
+		MetadataCleared { contract: H160 },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register (or update) the metadata of a contract.
+		///
+		/// The caller must either be the contract itself, in which case no `deployment_proof` is
+		/// provided, or the deployer of the contract, proving it with the parameters from which
+		/// the contract address is derived.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register_metadata())]
+		pub fn register_metadata(
+			origin: OriginFor<T>,
+			contract: H160,
+			deployment_proof: Option<DeploymentProof>,
+			cid: BoundedVec<u8, T::MaxCidLength>,
+			source_hash: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let registrar = T::AddressMapping::convert(who);
+
+			let allowed = match deployment_proof {
+				None => registrar == contract,
+				Some(proof) => proof.contract_address(registrar) == contract,
+			};
+			ensure!(allowed, Error::<T>::NotContractOrDeployer);
+
+			MetadataOf::<T>::insert(
+				contract,
+				ContractMetadata {
+					registrar,
+					cid: cid.clone(),
+					source_hash,
+				},
+			);
+
+			Self::deposit_event(Event::MetadataRegistered {
+				contract,
+				registrar,
+				cid,
+				source_hash,
+			});
+			Ok(())
+		}
+
+		/// Remove the metadata of a contract.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::clear_metadata())]
+		pub fn clear_metadata(origin: OriginFor<T>, contract: H160) -> DispatchResult {
+			T::MetadataManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				MetadataOf::<T>::take(contract).is_some(),
+				Error::<T>::NoMetadata
+			);
+
+			Self::deposit_event(Event::MetadataCleared { contract });
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,224 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{
+	create2_address, create_address, ContractMetadata, DeploymentProof, Error, Event, MetadataOf,
+};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_core::{H160, H256};
+use sp_runtime::DispatchError;
+
+fn cid(value: &[u8]) -> BoundedVec<u8, <Test as crate::Config>::MaxCidLength> {
+	value.to_vec().try_into().expect("cid fits in the bound")
+}
+
+fn source_hash() -> H256 {
+	H256::repeat_byte(0x11)
+}
+
+#[test]
+fn create_address_matches_known_vectors() {
+	let deployer =
+		H160::from_slice(&hex_literal::hex!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0")[..]);
+
+	assert_eq!(
+		create_address(deployer, 0),
+		H160::from_slice(&hex_literal::hex!("cd234a471b72ba2f1ccf0a70fcaba648a5eecd8d")[..])
+	);
+	assert_eq!(
+		create_address(deployer, 1),
+		H160::from_slice(&hex_literal::hex!("343c43a37d37dff08ae8c4a11544c718abb4fcf8")[..])
+	);
+	assert_eq!(
+		create_address(deployer, 300),
+		H160::from_slice(&hex_literal::hex!("47bbbb5fe97aa84c3fa30dafbb067284d250c24c")[..])
+	);
+}
+
+#[test]
+fn create2_address_matches_eip1014_vector() {
+	assert_eq!(
+		create2_address(
+			H160::zero(),
+			H256::zero(),
+			H256::from_slice(&sp_io::hashing::keccak_256(&[0x00])[..])
+		),
+		H160::from_slice(&hex_literal::hex!("4d1a2e2bb4f88f0250f26ffff098b0b30b26bf38")[..])
+	);
+}
+
+#[test]
+fn contract_can_register_its_own_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmContractMetadata::register_metadata(
+			RuntimeOrigin::signed(ALICE),
+			ALICE,
+			None,
+			cid(b"cid"),
+			source_hash(),
+		));
+
+		assert_eq!(
+			EvmContractMetadata::metadata_of(ALICE),
+			Some(ContractMetadata {
+				registrar: ALICE,
+				cid: cid(b"cid"),
+				source_hash: source_hash(),
+			})
+		);
+		expect_events(vec![Event::MetadataRegistered {
+			contract: ALICE,
+			registrar: ALICE,
+			cid: cid(b"cid"),
+			source_hash: source_hash(),
+		}]);
+	});
+}
+
+#[test]
+fn deployer_can_register_metadata_with_create_proof() {
+	ExtBuilder::default().build().execute_with(|| {
+		let contract = create_address(ALICE, 3);
+
+		assert_ok!(EvmContractMetadata::register_metadata(
+			RuntimeOrigin::signed(ALICE),
+			contract,
+			Some(DeploymentProof::Create { nonce: 3 }),
+			cid(b"cid"),
+			source_hash(),
+		));
+
+		assert_eq!(
+			MetadataOf::<Test>::get(contract).map(|metadata| metadata.registrar),
+			Some(ALICE)
+		);
+	});
+}
+
+#[test]
+fn deployer_can_register_metadata_with_create2_proof() {
+	ExtBuilder::default().build().execute_with(|| {
+		let salt = H256::repeat_byte(0x01);
+		let init_code_hash = H256::repeat_byte(0x02);
+		let contract = create2_address(ALICE, salt, init_code_hash);
+
+		assert_ok!(EvmContractMetadata::register_metadata(
+			RuntimeOrigin::signed(ALICE),
+			contract,
+			Some(DeploymentProof::Create2 {
+				salt,
+				init_code_hash
+			}),
+			cid(b"cid"),
+			source_hash(),
+		));
+
+		assert!(MetadataOf::<Test>::contains_key(contract));
+	});
+}
+
+#[test]
+fn other_accounts_cannot_register_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EvmContractMetadata::register_metadata(
+				RuntimeOrigin::signed(BOB),
+				ALICE,
+				None,
+				cid(b"cid"),
+				source_hash(),
+			),
+			Error::<Test>::NotContractOrDeployer
+		);
+
+		// BOB did not deploy this contract.
+		let contract = create_address(ALICE, 0);
+		assert_noop!(
+			EvmContractMetadata::register_metadata(
+				RuntimeOrigin::signed(BOB),
+				contract,
+				Some(DeploymentProof::Create { nonce: 0 }),
+				cid(b"cid"),
+				source_hash(),
+			),
+			Error::<Test>::NotContractOrDeployer
+		);
+	});
+}
+
+#[test]
+fn registering_again_updates_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		let contract = create_address(ALICE, 0);
+
+		assert_ok!(EvmContractMetadata::register_metadata(
+			RuntimeOrigin::signed(ALICE),
+			contract,
+			Some(DeploymentProof::Create { nonce: 0 }),
+			cid(b"first"),
+			source_hash(),
+		));
+		assert_ok!(EvmContractMetadata::register_metadata(
+			RuntimeOrigin::signed(contract),
+			contract,
+			None,
+			cid(b"second"),
+			H256::repeat_byte(0x22),
+		));
+
+		assert_eq!(
+			EvmContractMetadata::metadata_of(contract),
+			Some(ContractMetadata {
+				registrar: contract,
+				cid: cid(b"second"),
+				source_hash: H256::repeat_byte(0x22),
+			})
+		);
+	});
+}
+
+#[test]
+fn clear_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmContractMetadata::register_metadata(
+			RuntimeOrigin::signed(ALICE),
+			ALICE,
+			None,
+			cid(b"cid"),
+			source_hash(),
+		));
+
+		assert_noop!(
+			EvmContractMetadata::clear_metadata(RuntimeOrigin::signed(ALICE), ALICE),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(EvmContractMetadata::clear_metadata(
+			RuntimeOrigin::root(),
+			ALICE
+		));
+		assert_eq!(EvmContractMetadata::metadata_of(ALICE), None);
+		assert_noop!(
+			EvmContractMetadata::clear_metadata(RuntimeOrigin::root(), ALICE),
+			Error::<Test>::NoMetadata
+		);
+
+		assert_eq!(
+			events().last(),
+			Some(&Event::MetadataCleared { contract: ALICE })
+		);
+	});
+}
@@ -0,0 +1,81 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+
+use crate::{AllowedDeployers, Call, Config, Pallet};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin;
+use sp_core::H160;
+
+benchmarks! {
+	set_filter_enabled {
+	}: _(RawOrigin::Root, true)
+	verify {
+		assert!(Pallet::<T>::is_filter_enabled());
+	}
+
+	allow_deployer {
+		let deployer = H160::repeat_byte(0xaa);
+	}: _(RawOrigin::Root, deployer)
+	verify {
+		assert!(Pallet::<T>::is_allowed_deployer(deployer));
+	}
+
+	disallow_deployer {
+		let deployer = H160::repeat_byte(0xaa);
+		AllowedDeployers::<T>::insert(deployer, ());
+	}: _(RawOrigin::Root, deployer)
+	verify {
+		assert!(!Pallet::<T>::is_allowed_deployer(deployer));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::ExtBuilder;
+	use crate::Pallet;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn bench_set_filter_enabled() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_set_filter_enabled());
+		});
+	}
+
+	#[test]
+	fn bench_allow_deployer() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_allow_deployer());
+		});
+	}
+
+	#[test]
+	fn bench_disallow_deployer() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_disallow_deployer());
+		});
+	}
+}
+
+impl_benchmark_test_suite!(
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
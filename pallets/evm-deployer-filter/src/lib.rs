@@ -0,0 +1,167 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # EVM deployer filter pallet
+//!
+//! This pallet allows governance to restrict the deployment of smart contracts to an allow-list
+//! of deployer addresses, for chains used in permissioned contexts. Calls to existing contracts
+//! are never restricted.
+//!
+//! The filter is disabled by default, anyone can then deploy contracts. Once enabled, only the
+//! allowed deployers can submit contract creations (`CREATE` and `CREATE2` transactions), which
+//! is enforced by wrapping the EVM runner in [`DeployerFilterRunner`].
+//!
+//! Only the deployers of top-level contract creations are checked: the EVM offers no hook on the
+//! `CREATE` and `CREATE2` opcodes executed by contracts, so the contract creations performed by a
+//! contract (e.g. a factory) are out of scope and never restricted. Anyone calling such a
+//! contract can deploy contracts through it, whoever deployed it and even if the filter was
+//! enabled in the meantime. Chains relying on the filter must therefore make sure that no
+//! contract able to create arbitrary contracts (e.g. a `CREATE2` deployer) is deployed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod runner;
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use runner::DeployerFilterRunner;
+pub use weights::WeightInfo;
+
+use frame_support::pallet;
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_core::H160;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that is allowed to enable the filter and to manage the allowed deployers.
+		type DeployerManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Whether contract deployments are restricted to the allowed deployers.
+	#[pallet::storage]
+	#[pallet::getter(fn is_filter_enabled)]
+	pub type FilterEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Addresses allowed to deploy contracts while the filter is enabled.
+	#[pallet::storage]
+	pub type AllowedDeployers<T: Config> = StorageMap<_, Blake2_128Concat, H160, (), OptionQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The address is already an allowed deployer.
+		AlreadyAllowed,
+		/// The address is not an allowed deployer.
+		NotAllowed,
+		/// The deployer is not allowed to deploy contracts.
+		DeployerNotAllowed,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Contract deployments are now restricted to the allowed deployers.
+		FilterEnabled,
+		/// Anyone can deploy contracts again.
+		FilterDisabled,
+		/// The address can now deploy contracts.
+		DeployerAllowed { deployer: H160 },
+		/// The address can no longer deploy contracts while the filter is enabled.
+		DeployerDisallowed { deployer: H160 },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Enable or disable the restriction of contract deployments to the allowed deployers.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_filter_enabled())]
+		pub fn set_filter_enabled(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			T::DeployerManagerOrigin::ensure_origin(origin)?;
+
+			FilterEnabled::<T>::put(enabled);
+
+			if enabled {
+				Self::deposit_event(Event::FilterEnabled);
+			} else {
+				Self::deposit_event(Event::FilterDisabled);
+			}
+			Ok(())
+		}
+
+		/// Allow an address to deploy contracts.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::allow_deployer())]
+		pub fn allow_deployer(origin: OriginFor<T>, deployer: H160) -> DispatchResult {
+			T::DeployerManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!AllowedDeployers::<T>::contains_key(deployer),
+				Error::<T>::AlreadyAllowed
+			);
+			AllowedDeployers::<T>::insert(deployer, ());
+
+			Self::deposit_event(Event::DeployerAllowed { deployer });
+			Ok(())
+		}
+
+		/// Remove an address from the allowed deployers.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::disallow_deployer())]
+		pub fn disallow_deployer(origin: OriginFor<T>, deployer: H160) -> DispatchResult {
+			T::DeployerManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				AllowedDeployers::<T>::take(deployer).is_some(),
+				Error::<T>::NotAllowed
+			);
+
+			Self::deposit_event(Event::DeployerDisallowed { deployer });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether the address is in the allow-list, regardless of the filter being enabled.
+		pub fn is_allowed_deployer(deployer: H160) -> bool {
+			AllowedDeployers::<T>::contains_key(deployer)
+		}
+
+		/// Whether the address can currently deploy contracts.
+		pub fn can_deploy(deployer: H160) -> bool {
+			!Self::is_filter_enabled() || Self::is_allowed_deployer(deployer)
+		}
+	}
+}
@@ -0,0 +1,202 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! EVM runner wrapper refusing the contract creations of deployers that are not allowed.
+
+use crate::{Config, Error, Pallet};
+use fp_evm::{CallInfo, CreateInfo};
+use frame_support::{traits::Get, weights::Weight};
+use pallet_evm::{Runner, RunnerError};
+use sp_core::{H160, H256, U256};
+use sp_runtime::DispatchError;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Wraps the EVM runner `Inner`, refusing contract creations from deployers that are not allowed
+/// while the filter is enabled. Calls are forwarded untouched.
+pub struct DeployerFilterRunner<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T, Inner> DeployerFilterRunner<T, Inner>
+where
+	T: Config + pallet_evm::Config,
+{
+	fn ensure_can_deploy(source: H160) -> Result<(), RunnerError<DispatchError>> {
+		if Pallet::<T>::can_deploy(source) {
+			Ok(())
+		} else {
+			Err(RunnerError {
+				error: Error::<T>::DeployerNotAllowed.into(),
+				// FilterEnabled and AllowedDeployers
+				weight: T::DbWeight::get().reads(2),
+			})
+		}
+	}
+}
+
+fn map_inner_error<E: Into<DispatchError>>(error: RunnerError<E>) -> RunnerError<DispatchError> {
+	RunnerError {
+		error: error.error.into(),
+		weight: error.weight,
+	}
+}
+
+impl<T, Inner> Runner<T> for DeployerFilterRunner<T, Inner>
+where
+	T: Config + pallet_evm::Config,
+	Inner: Runner<T>,
+{
+	type Error = DispatchError;
+
+	fn validate(
+		source: H160,
+		target: Option<H160>,
+		input: Vec<u8>,
+		value: U256,
+		gas_limit: u64,
+		max_fee_per_gas: Option<U256>,
+		max_priority_fee_per_gas: Option<U256>,
+		nonce: Option<U256>,
+		access_list: Vec<(H160, Vec<H256>)>,
+		is_transactional: bool,
+		weight_limit: Option<Weight>,
+		transaction_len: Option<u64>,
+		evm_config: &fp_evm::Config,
+	) -> Result<(), RunnerError<Self::Error>> {
+		if target.is_none() {
+			Self::ensure_can_deploy(source)?;
+		}
+		Inner::validate(
+			source,
+			target,
+			input,
+			value,
+			gas_limit,
+			max_fee_per_gas,
+			max_priority_fee_per_gas,
+			nonce,
+			access_list,
+			is_transactional,
+			weight_limit,
+			transaction_len,
+			evm_config,
+		)
+		.map_err(map_inner_error)
+	}
+
+	fn call(
+		source: H160,
+		target: H160,
+		input: Vec<u8>,
+		value: U256,
+		gas_limit: u64,
+		max_fee_per_gas: Option<U256>,
+		max_priority_fee_per_gas: Option<U256>,
+		nonce: Option<U256>,
+		access_list: Vec<(H160, Vec<H256>)>,
+		is_transactional: bool,
+		validate: bool,
+		weight_limit: Option<Weight>,
+		transaction_len: Option<u64>,
+		config: &fp_evm::Config,
+	) -> Result<CallInfo, RunnerError<Self::Error>> {
+		Inner::call(
+			source,
+			target,
+			input,
+			value,
+			gas_limit,
+			max_fee_per_gas,
+			max_priority_fee_per_gas,
+			nonce,
+			access_list,
+			is_transactional,
+			validate,
+			weight_limit,
+			transaction_len,
+			config,
+		)
+		.map_err(map_inner_error)
+	}
+
+	fn create(
+		source: H160,
+		init: Vec<u8>,
+		value: U256,
+		gas_limit: u64,
+		max_fee_per_gas: Option<U256>,
+		max_priority_fee_per_gas: Option<U256>,
+		nonce: Option<U256>,
+		access_list: Vec<(H160, Vec<H256>)>,
+		is_transactional: bool,
+		validate: bool,
+		weight_limit: Option<Weight>,
+		transaction_len: Option<u64>,
+		config: &fp_evm::Config,
+	) -> Result<CreateInfo, RunnerError<Self::Error>> {
+		Self::ensure_can_deploy(source)?;
+		Inner::create(
+			source,
+			init,
+			value,
+			gas_limit,
+			max_fee_per_gas,
+			max_priority_fee_per_gas,
+			nonce,
+			access_list,
+			is_transactional,
+			validate,
+			weight_limit,
+			transaction_len,
+			config,
+		)
+		.map_err(map_inner_error)
+	}
+
+	fn create2(
+		source: H160,
+		init: Vec<u8>,
+		salt: H256,
+		value: U256,
+		gas_limit: u64,
+		max_fee_per_gas: Option<U256>,
+		max_priority_fee_per_gas: Option<U256>,
+		nonce: Option<U256>,
+		access_list: Vec<(H160, Vec<H256>)>,
+		is_transactional: bool,
+		validate: bool,
+		weight_limit: Option<Weight>,
+		transaction_len: Option<u64>,
+		config: &fp_evm::Config,
+	) -> Result<CreateInfo, RunnerError<Self::Error>> {
+		Self::ensure_can_deploy(source)?;
+		Inner::create2(
+			source,
+			init,
+			salt,
+			value,
+			gas_limit,
+			max_fee_per_gas,
+			max_priority_fee_per_gas,
+			nonce,
+			access_list,
+			is_transactional,
+			validate,
+			weight_limit,
+			transaction_len,
+			config,
+		)
+		.map_err(map_inner_error)
+	}
+}
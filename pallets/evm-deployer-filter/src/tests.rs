@@ -0,0 +1,244 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{Error, Event, Pallet};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo};
+use sp_core::{H160, H256, U256};
+use sp_runtime::DispatchError;
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+fn create(source: H160) -> DispatchResultWithPostInfo {
+	EVM::create(
+		RuntimeOrigin::root(),
+		source,
+		Vec::new(),
+		U256::zero(),
+		GAS_LIMIT,
+		U256::zero(),
+		None,
+		None,
+		Vec::new(),
+	)
+}
+
+fn create2(source: H160) -> DispatchResultWithPostInfo {
+	EVM::create2(
+		RuntimeOrigin::root(),
+		source,
+		Vec::new(),
+		H256::repeat_byte(0x01),
+		U256::zero(),
+		GAS_LIMIT,
+		U256::zero(),
+		None,
+		None,
+		Vec::new(),
+	)
+}
+
+fn deployer_not_allowed() -> Result<(), DispatchError> {
+	Err(Error::<Test>::DeployerNotAllowed.into())
+}
+
+#[test]
+fn allow_and_disallow_deployer() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmDeployerFilter::allow_deployer(
+			RuntimeOrigin::root(),
+			ALICE
+		));
+		assert!(Pallet::<Test>::is_allowed_deployer(ALICE));
+		assert!(!Pallet::<Test>::is_allowed_deployer(BOB));
+
+		assert_ok!(EvmDeployerFilter::disallow_deployer(
+			RuntimeOrigin::root(),
+			ALICE
+		));
+		assert!(!Pallet::<Test>::is_allowed_deployer(ALICE));
+
+		expect_events(vec![
+			Event::DeployerAllowed { deployer: ALICE },
+			Event::DeployerDisallowed { deployer: ALICE },
+		]);
+	});
+}
+
+#[test]
+fn allow_deployer_fails_if_already_allowed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmDeployerFilter::allow_deployer(
+			RuntimeOrigin::root(),
+			ALICE
+		));
+		assert_noop!(
+			EvmDeployerFilter::allow_deployer(RuntimeOrigin::root(), ALICE),
+			Error::<Test>::AlreadyAllowed
+		);
+	});
+}
+
+#[test]
+fn disallow_deployer_fails_if_not_allowed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EvmDeployerFilter::disallow_deployer(RuntimeOrigin::root(), ALICE),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn calls_require_manager_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EvmDeployerFilter::set_filter_enabled(RuntimeOrigin::signed(ALICE), true),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			EvmDeployerFilter::allow_deployer(RuntimeOrigin::signed(ALICE), ALICE),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			EvmDeployerFilter::disallow_deployer(RuntimeOrigin::signed(ALICE), ALICE),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn anyone_can_deploy_while_filter_is_disabled() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(Pallet::<Test>::can_deploy(BOB));
+		assert_ok!(create(BOB));
+		assert_ok!(create2(BOB));
+	});
+}
+
+#[test]
+fn enabled_filter_restricts_deployments_to_allowed_deployers() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmDeployerFilter::allow_deployer(
+			RuntimeOrigin::root(),
+			ALICE
+		));
+		assert_ok!(EvmDeployerFilter::set_filter_enabled(
+			RuntimeOrigin::root(),
+			true
+		));
+
+		assert!(Pallet::<Test>::can_deploy(ALICE));
+		assert!(!Pallet::<Test>::can_deploy(BOB));
+
+		assert_ok!(create(ALICE));
+		assert_ok!(create2(ALICE));
+		assert_eq!(
+			create(BOB).map(|_| ()).map_err(|e| e.error),
+			deployer_not_allowed()
+		);
+		assert_eq!(
+			create2(BOB).map(|_| ()).map_err(|e| e.error),
+			deployer_not_allowed()
+		);
+
+		expect_events(vec![
+			Event::DeployerAllowed { deployer: ALICE },
+			Event::FilterEnabled,
+		]);
+	});
+}
+
+#[test]
+fn disabling_the_filter_allows_anyone_to_deploy_again() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmDeployerFilter::set_filter_enabled(
+			RuntimeOrigin::root(),
+			true
+		));
+		assert_eq!(
+			create(BOB).map(|_| ()).map_err(|e| e.error),
+			deployer_not_allowed()
+		);
+
+		assert_ok!(EvmDeployerFilter::set_filter_enabled(
+			RuntimeOrigin::root(),
+			false
+		));
+		assert_ok!(create(BOB));
+
+		expect_events(vec![Event::FilterEnabled, Event::FilterDisabled]);
+	});
+}
+
+#[test]
+fn enabled_filter_does_not_restrict_calls() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmDeployerFilter::set_filter_enabled(
+			RuntimeOrigin::root(),
+			true
+		));
+
+		assert_ok!(EVM::call(
+			RuntimeOrigin::root(),
+			BOB,
+			ALICE,
+			Vec::new(),
+			U256::zero(),
+			GAS_LIMIT,
+			U256::zero(),
+			None,
+			None,
+			Vec::new(),
+		));
+	});
+}
+
+#[test]
+fn contract_creations_by_contracts_are_out_of_scope() {
+	ExtBuilder::default().build().execute_with(|| {
+		// PUSH5 init code, PUSH1 0, MSTORE, PUSH1 5, PUSH1 27, PUSH1 0, CREATE, STOP, where the
+		// init code (PUSH1 1, PUSH1 0, RETURN) deploys a contract whose code is a single byte.
+		let factory_code = vec![
+			0x64, 0x60, 0x01, 0x60, 0x00, 0xf3, 0x60, 0x00, 0x52, 0x60, 0x05, 0x60, 0x1b, 0x60,
+			0x00, 0xf0, 0x00,
+		];
+		let factory = H160::repeat_byte(0xfa);
+		pallet_evm::Pallet::<Test>::create_account(factory, factory_code);
+
+		assert_ok!(EvmDeployerFilter::set_filter_enabled(
+			RuntimeOrigin::root(),
+			true
+		));
+		assert!(!Pallet::<Test>::can_deploy(BOB));
+
+		assert_ok!(EVM::call(
+			RuntimeOrigin::root(),
+			BOB,
+			factory,
+			Vec::new(),
+			U256::zero(),
+			GAS_LIMIT,
+			U256::zero(),
+			None,
+			None,
+			Vec::new(),
+		));
+
+		// The contract created by the factory on behalf of BOB was deployed.
+		assert_eq!(pallet_evm::AccountCodes::<Test>::iter().count(), 2);
+	});
+}
@@ -0,0 +1,110 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+
+use crate::{Call, Config, ContractState, DormancyPeriod, Pallet};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_core::{H160, H256};
+use sp_std::vec;
+
+/// Create a contract with `entries` storage entries, dormant since the dormancy period is
+/// set to 1 block.
+fn dormant_contract<T: Config>(entries: u32) -> H160 {
+	let contract = H160::repeat_byte(0xcc);
+	pallet_evm::Pallet::<T>::create_account(contract, vec![0x00; 1024]);
+	for i in 0..entries {
+		pallet_evm::AccountStorages::<T>::insert(
+			contract,
+			H256::from_low_u64_be(i as u64 + 1),
+			H256::repeat_byte(0x11),
+		);
+	}
+
+	DormancyPeriod::<T>::put(T::BlockNumber::from(1u32));
+	frame_system::Pallet::<T>::set_block_number(10u32.into());
+	contract
+}
+
+benchmarks! {
+	set_dormancy_period {
+	}: _(RawOrigin::Root, Some(100u32.into()))
+	verify {
+		assert_eq!(Pallet::<T>::dormancy_period(), Some(100u32.into()));
+	}
+
+	hibernate_contract {
+		let s in 0 .. T::MaxStorageEntries::get();
+		let contract = dormant_contract::<T>(s);
+	}: _(RawOrigin::Root, contract)
+	verify {
+		assert!(Pallet::<T>::is_hibernated(contract));
+	}
+
+	wake_contract {
+		let s in 0 .. T::MaxStorageEntries::get();
+		let contract = dormant_contract::<T>(s);
+		let caller: T::AccountId = whitelisted_caller();
+		let ContractState { code, storage } = Pallet::<T>::contract_state(contract);
+		Pallet::<T>::hibernate_contract(RawOrigin::Root.into(), contract)?;
+	}: _(
+		RawOrigin::Signed(caller),
+		contract,
+		code.try_into().expect("code fits in the bound"),
+		storage.try_into().expect("storage fits in the bound")
+	)
+	verify {
+		assert!(!Pallet::<T>::is_hibernated(contract));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::ExtBuilder;
+	use crate::Pallet;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn bench_set_dormancy_period() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_set_dormancy_period());
+		});
+	}
+
+	#[test]
+	fn bench_hibernate_contract() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_hibernate_contract());
+		});
+	}
+
+	#[test]
+	fn bench_wake_contract() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_wake_contract());
+		});
+	}
+}
+
+impl_benchmark_test_suite!(
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
@@ -0,0 +1,323 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # EVM hibernation pallet
+//!
+//! This pallet reduces the state bloat caused by abandoned smart contracts. The last block in
+//! which each contract was accessed is tracked, and once governance has set a dormancy period, it
+//! can hibernate a contract which was not accessed for that long:
+//! - its storage is removed from the state and replaced by a commitment to it,
+//! - its code is replaced by [`HIBERNATED_CODE`], which reverts, so that it cannot be used
+//!   with an empty storage.
+//!
+//! A hibernated contract is woken by providing its original code and storage, which are checked
+//! against the commitment. They can be read from the state of any block prior to the
+//! hibernation, see the `evm-hibernation-runtime-api` crate and the `moon_hibernatedContractState`
+//! RPC method. Contracts whose code exceeds [`MAX_CODE_SIZE`], which can be deployed once
+//! governance raised the code size limit, cannot be hibernated.
+//!
+//! Accesses are tracked by wrapping the EVM runner in [`HibernationRunner`]. Only the target
+//! of transactions and the contracts they deploy are tracked, so a contract only ever reached
+//! through `CALL` or `DELEGATECALL` from other contracts looks dormant. Hibernation is therefore
+//! restricted to `HibernationManagerOrigin`, which must check that a contract is really unused
+//! (e.g. from traces) before hibernating it; the dormancy period is only a lower bound.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod runner;
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use evm_hibernation_runtime_api::{storage_commitment, ContractState, HibernatedContract};
+pub use pallet::*;
+pub use runner::HibernationRunner;
+pub use weights::WeightInfo;
+
+use frame_support::pallet;
+
+/// Code of hibernated contracts: `PUSH1 0x00 DUP1 REVERT`.
+pub const HIBERNATED_CODE: [u8; 4] = [0x60, 0x00, 0x80, 0xfd];
+
+/// Dummy code stored at the address of precompiles, which must not be hibernated.
+const PRECOMPILE_CODE: [u8; 5] = [0x60, 0x00, 0x60, 0x00, 0xfd];
+
+/// Maximum size of the code of a contract which can be hibernated (EIP-170).
+pub const MAX_CODE_SIZE: u32 = 0x6000;
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_core::{H160, H256};
+	use sp_runtime::traits::Saturating;
+	use sp_std::vec::Vec;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_evm::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that is allowed to set the dormancy period and to hibernate contracts.
+		type HibernationManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of storage entries of a contract that can be hibernated, bounding the
+		/// weight of hibernating and waking a contract.
+		#[pallet::constant]
+		type MaxStorageEntries: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Number of blocks without access after which a contract can be hibernated. Hibernation
+	/// is disabled when not set.
+	#[pallet::storage]
+	#[pallet::getter(fn dormancy_period)]
+	pub type DormancyPeriod<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	/// Block from which accesses are tracked, contracts never accessed since then are
+	/// considered accessed at this block.
+	#[pallet::storage]
+	pub type TrackingStart<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// Last block in which each contract was accessed.
+	#[pallet::storage]
+	#[pallet::getter(fn last_access)]
+	pub type LastAccess<T: Config> =
+		StorageMap<_, Blake2_128Concat, H160, T::BlockNumber, OptionQuery>;
+
+	/// Commitment to the state of each hibernated contract.
+	#[pallet::storage]
+	#[pallet::getter(fn hibernated_contract)]
+	pub type HibernatedContracts<T: Config> =
+		StorageMap<_, Blake2_128Concat, H160, HibernatedContract<T::BlockNumber>, OptionQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Hibernation is disabled.
+		HibernationDisabled,
+		/// The address has no code, or is a precompile.
+		NotAContract,
+		/// The contract is already hibernated.
+		AlreadyHibernated,
+		/// The contract is not hibernated.
+		NotHibernated,
+		/// The contract was accessed within the dormancy period.
+		NotDormant,
+		/// The contract has more storage entries than can be hibernated.
+		TooManyStorageEntries,
+		/// The code of the contract is larger than `MAX_CODE_SIZE`.
+		CodeTooLarge,
+		/// The provided code does not match the code of the hibernated contract.
+		InvalidCode,
+		/// The provided storage does not match the storage of the hibernated contract.
+		InvalidStorage,
+		/// The contract is hibernated and must be woken before being called.
+		ContractHibernated,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The dormancy period was set, `None` disables hibernation.
+		DormancyPeriodSet { period: Option<T::BlockNumber> },
+		/// A contract was hibernated.
+		ContractHibernated {
+			contract: H160,
+			code_hash: H256,
+			storage_root: H256,
+			storage_entries: u32,
+		},
+		/// A hibernated contract was woken.
+		ContractWoken { contract: H160 },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the number of blocks without access after which a contract can be hibernated.
+		/// `None` disables hibernation.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_dormancy_period())]
+		pub fn set_dormancy_period(
+			origin: OriginFor<T>,
+			period: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			T::HibernationManagerOrigin::ensure_origin(origin)?;
+
+			// Accesses are not tracked while hibernation is disabled.
+			if period.is_some() && DormancyPeriod::<T>::get().is_none() {
+				TrackingStart::<T>::put(frame_system::Pallet::<T>::block_number());
+			}
+			DormancyPeriod::<T>::set(period);
+
+			Self::deposit_event(Event::DormancyPeriodSet { period });
+			Ok(())
+		}
+
+		/// Hibernate a contract which was not accessed during the dormancy period.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::hibernate_contract(T::MaxStorageEntries::get()))]
+		pub fn hibernate_contract(
+			origin: OriginFor<T>,
+			contract: H160,
+		) -> DispatchResultWithPostInfo {
+			T::HibernationManagerOrigin::ensure_origin(origin)?;
+
+			let period = DormancyPeriod::<T>::get().ok_or(Error::<T>::HibernationDisabled)?;
+			ensure!(
+				!HibernatedContracts::<T>::contains_key(contract),
+				Error::<T>::AlreadyHibernated
+			);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let tracking_start = TrackingStart::<T>::get();
+			let last_access = LastAccess::<T>::get(contract)
+				.map_or(tracking_start, |last_access| {
+					last_access.max(tracking_start)
+				});
+			ensure!(
+				now >= last_access.saturating_add(period),
+				Error::<T>::NotDormant
+			);
+
+			let code = pallet_evm::AccountCodes::<T>::get(contract);
+			ensure!(
+				!code.is_empty() && code != PRECOMPILE_CODE,
+				Error::<T>::NotAContract
+			);
+			// The code must be provided back to wake the contract.
+			ensure!(
+				code.len() <= MAX_CODE_SIZE as usize,
+				Error::<T>::CodeTooLarge
+			);
+
+			let max_entries = T::MaxStorageEntries::get();
+			let mut storage: Vec<(H256, H256)> =
+				pallet_evm::AccountStorages::<T>::iter_prefix(contract)
+					.take(max_entries as usize + 1)
+					.collect();
+			ensure!(
+				storage.len() <= max_entries as usize,
+				Error::<T>::TooManyStorageEntries
+			);
+			storage.sort_by_key(|(key, _)| *key);
+
+			let storage_entries = storage.len() as u32;
+			let hibernated = HibernatedContract {
+				code_hash: H256(sp_core::hashing::keccak_256(&code)),
+				storage_root: storage_commitment(&storage),
+				storage_entries,
+				hibernated_at: now,
+			};
+
+			let _ = pallet_evm::AccountStorages::<T>::clear_prefix(contract, storage_entries, None);
+			pallet_evm::Pallet::<T>::create_account(contract, HIBERNATED_CODE.to_vec());
+			LastAccess::<T>::remove(contract);
+			HibernatedContracts::<T>::insert(contract, hibernated);
+
+			Self::deposit_event(Event::ContractHibernated {
+				contract,
+				code_hash: hibernated.code_hash,
+				storage_root: hibernated.storage_root,
+				storage_entries,
+			});
+			Ok(Some(<T as Config>::WeightInfo::hibernate_contract(
+				storage_entries,
+			))
+			.into())
+		}
+
+		/// Wake a hibernated contract by providing its code and storage, sorted by key. Can be
+		/// called by anyone.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::wake_contract(storage.len() as u32))]
+		pub fn wake_contract(
+			origin: OriginFor<T>,
+			contract: H160,
+			code: BoundedVec<u8, ConstU32<MAX_CODE_SIZE>>,
+			storage: BoundedVec<(H256, H256), T::MaxStorageEntries>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let hibernated =
+				HibernatedContracts::<T>::get(contract).ok_or(Error::<T>::NotHibernated)?;
+			ensure!(
+				H256(sp_core::hashing::keccak_256(&code)) == hibernated.code_hash,
+				Error::<T>::InvalidCode
+			);
+			ensure!(
+				storage.len() as u32 == hibernated.storage_entries
+					&& storage.windows(2).all(|pair| pair[0].0 < pair[1].0)
+					&& storage_commitment(&storage) == hibernated.storage_root,
+				Error::<T>::InvalidStorage
+			);
+
+			pallet_evm::Pallet::<T>::create_account(contract, code.into_inner());
+			for (key, value) in storage {
+				pallet_evm::AccountStorages::<T>::insert(contract, key, value);
+			}
+			HibernatedContracts::<T>::remove(contract);
+			Self::note_access(contract);
+
+			Self::deposit_event(Event::ContractWoken { contract });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether the contract is hibernated.
+		pub fn is_hibernated(contract: H160) -> bool {
+			HibernatedContracts::<T>::contains_key(contract)
+		}
+
+		/// Record that the contract was accessed in the current block. Does nothing while
+		/// hibernation is disabled, or if the address has no code.
+		pub fn note_access(contract: H160) {
+			if DormancyPeriod::<T>::get().is_none()
+				|| !pallet_evm::AccountCodes::<T>::contains_key(contract)
+			{
+				return;
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			if LastAccess::<T>::get(contract) != Some(now) {
+				LastAccess::<T>::insert(contract, now);
+			}
+		}
+
+		/// Code and storage of a contract, for the runtime API.
+		pub fn contract_state(contract: H160) -> ContractState {
+			let mut storage: Vec<(H256, H256)> =
+				pallet_evm::AccountStorages::<T>::iter_prefix(contract).collect();
+			storage.sort_by_key(|(key, _)| *key);
+
+			ContractState {
+				code: pallet_evm::AccountCodes::<T>::get(contract),
+				storage,
+			}
+		}
+	}
+}
@@ -0,0 +1,404 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{
+	storage_commitment, ContractState, Error, Event, HibernatedContract, LastAccess, Pallet,
+	HIBERNATED_CODE, MAX_CODE_SIZE,
+};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo};
+use sp_core::{H160, H256, U256};
+use sp_runtime::DispatchError;
+
+const CONTRACT: H160 = H160([0xcc; 20]);
+const PROXY: H160 = H160([0xdd; 20]);
+const GAS_LIMIT: u64 = 1_000_000;
+
+/// Init code deploying a contract whose code is a single `STOP`.
+const INIT_CODE: [u8; 5] = [0x60, 0x01, 0x60, 0x00, 0xf3];
+
+fn contract_code() -> Vec<u8> {
+	vec![0x00, 0x01, 0x02]
+}
+
+fn contract_storage() -> Vec<(H256, H256)> {
+	vec![
+		(H256::repeat_byte(0x01), H256::repeat_byte(0x11)),
+		(H256::repeat_byte(0x02), H256::repeat_byte(0x22)),
+	]
+}
+
+/// Deploy `CONTRACT` with its storage, and enable hibernation at block 1 with a dormancy
+/// period of 10 blocks.
+fn setup_contract() {
+	pallet_evm::Pallet::<Test>::create_account(CONTRACT, contract_code());
+	for (key, value) in contract_storage() {
+		pallet_evm::AccountStorages::<Test>::insert(CONTRACT, key, value);
+	}
+	assert_ok!(EvmHibernation::set_dormancy_period(
+		RuntimeOrigin::root(),
+		Some(10)
+	));
+}
+
+fn call(target: H160) -> DispatchResultWithPostInfo {
+	EVM::call(
+		RuntimeOrigin::root(),
+		ALICE,
+		target,
+		Vec::new(),
+		U256::zero(),
+		GAS_LIMIT,
+		U256::zero(),
+		None,
+		None,
+		Vec::new(),
+	)
+}
+
+fn wake(code: Vec<u8>, storage: Vec<(H256, H256)>) -> Result<(), DispatchError> {
+	EvmHibernation::wake_contract(
+		RuntimeOrigin::signed(BOB),
+		CONTRACT,
+		code.try_into().expect("code fits in the bound"),
+		storage.try_into().expect("storage fits in the bound"),
+	)
+}
+
+#[test]
+fn set_dormancy_period_starts_tracking() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(5);
+		assert_ok!(EvmHibernation::set_dormancy_period(
+			RuntimeOrigin::root(),
+			Some(10)
+		));
+		assert_eq!(Pallet::<Test>::dormancy_period(), Some(10));
+		assert_eq!(crate::TrackingStart::<Test>::get(), 5);
+
+		// Changing the period does not restart the tracking.
+		System::set_block_number(8);
+		assert_ok!(EvmHibernation::set_dormancy_period(
+			RuntimeOrigin::root(),
+			Some(20)
+		));
+		assert_eq!(crate::TrackingStart::<Test>::get(), 5);
+
+		assert_ok!(EvmHibernation::set_dormancy_period(
+			RuntimeOrigin::root(),
+			None
+		));
+		assert_eq!(Pallet::<Test>::dormancy_period(), None);
+
+		assert_noop!(
+			EvmHibernation::set_dormancy_period(RuntimeOrigin::signed(ALICE), Some(1)),
+			DispatchError::BadOrigin
+		);
+
+		expect_events(vec![
+			Event::DormancyPeriodSet { period: Some(10) },
+			Event::DormancyPeriodSet { period: Some(20) },
+			Event::DormancyPeriodSet { period: None },
+		]);
+	});
+}
+
+#[test]
+fn cannot_hibernate_while_disabled() {
+	ExtBuilder::default().build().execute_with(|| {
+		pallet_evm::Pallet::<Test>::create_account(CONTRACT, contract_code());
+		System::set_block_number(100);
+
+		assert_noop!(
+			EvmHibernation::hibernate_contract(RuntimeOrigin::root(), CONTRACT),
+			Error::<Test>::HibernationDisabled
+		);
+	});
+}
+
+#[test]
+fn cannot_hibernate_before_dormancy_period() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_contract();
+
+		System::set_block_number(10);
+		assert_noop!(
+			EvmHibernation::hibernate_contract(RuntimeOrigin::root(), CONTRACT),
+			Error::<Test>::NotDormant
+		);
+
+		// An access restarts the dormancy period.
+		System::set_block_number(11);
+		Pallet::<Test>::note_access(CONTRACT);
+		System::set_block_number(20);
+		assert_noop!(
+			EvmHibernation::hibernate_contract(RuntimeOrigin::root(), CONTRACT),
+			Error::<Test>::NotDormant
+		);
+
+		System::set_block_number(21);
+		assert_ok!(EvmHibernation::hibernate_contract(
+			RuntimeOrigin::root(),
+			CONTRACT
+		));
+	});
+}
+
+#[test]
+fn contracts_only_reached_through_delegatecall_cannot_be_hibernated_by_anyone() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_contract();
+
+		// `PROXY` only ever reaches `CONTRACT` through `DELEGATECALL`, so the runner never
+		// records an access to it.
+		let mut proxy_code = vec![0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x73];
+		proxy_code.extend_from_slice(CONTRACT.as_bytes());
+		proxy_code.extend_from_slice(&[0x5a, 0xf4, 0x00]);
+		pallet_evm::Pallet::<Test>::create_account(PROXY, proxy_code);
+
+		System::set_block_number(20);
+		assert_ok!(call(PROXY));
+		assert_eq!(LastAccess::<Test>::get(PROXY), Some(20));
+		assert_eq!(LastAccess::<Test>::get(CONTRACT), None);
+
+		assert_noop!(
+			EvmHibernation::hibernate_contract(RuntimeOrigin::signed(BOB), CONTRACT),
+			DispatchError::BadOrigin
+		);
+		assert!(!Pallet::<Test>::is_hibernated(CONTRACT));
+		assert_ok!(call(PROXY));
+	});
+}
+
+#[test]
+fn cannot_hibernate_accounts_without_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_contract();
+		System::set_block_number(11);
+
+		assert_noop!(
+			EvmHibernation::hibernate_contract(RuntimeOrigin::root(), ALICE),
+			Error::<Test>::NotAContract
+		);
+	});
+}
+
+#[test]
+fn cannot_hibernate_contracts_larger_than_the_eip_170_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_contract();
+		pallet_evm::Pallet::<Test>::create_account(
+			CONTRACT,
+			vec![0x00; MAX_CODE_SIZE as usize + 1],
+		);
+		System::set_block_number(11);
+
+		assert_noop!(
+			EvmHibernation::hibernate_contract(RuntimeOrigin::root(), CONTRACT),
+			Error::<Test>::CodeTooLarge
+		);
+	});
+}
+
+#[test]
+fn cannot_hibernate_contracts_with_too_many_storage_entries() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_contract();
+		for i in 0..9u64 {
+			pallet_evm::AccountStorages::<Test>::insert(
+				CONTRACT,
+				H256::from_low_u64_be(i + 0x100),
+				H256::repeat_byte(0x11),
+			);
+		}
+		System::set_block_number(11);
+
+		assert_noop!(
+			EvmHibernation::hibernate_contract(RuntimeOrigin::root(), CONTRACT),
+			Error::<Test>::TooManyStorageEntries
+		);
+	});
+}
+
+#[test]
+fn hibernate_and_wake_contract() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_contract();
+		let state = Pallet::<Test>::contract_state(CONTRACT);
+		assert_eq!(
+			state,
+			ContractState {
+				code: contract_code(),
+				storage: contract_storage(),
+			}
+		);
+
+		System::set_block_number(11);
+		assert_ok!(EvmHibernation::hibernate_contract(
+			RuntimeOrigin::root(),
+			CONTRACT
+		));
+
+		let hibernated = HibernatedContract {
+			code_hash: H256(sp_io::hashing::keccak_256(&contract_code())),
+			storage_root: storage_commitment(&contract_storage()),
+			storage_entries: 2,
+			hibernated_at: 11,
+		};
+		assert_eq!(
+			Pallet::<Test>::hibernated_contract(CONTRACT),
+			Some(hibernated)
+		);
+		assert_eq!(
+			Pallet::<Test>::contract_state(CONTRACT),
+			ContractState {
+				code: HIBERNATED_CODE.to_vec(),
+				storage: Vec::new(),
+			}
+		);
+		assert_noop!(
+			EvmHibernation::hibernate_contract(RuntimeOrigin::root(), CONTRACT),
+			Error::<Test>::AlreadyHibernated
+		);
+
+		System::set_block_number(50);
+		assert_ok!(wake(contract_code(), contract_storage()));
+
+		assert!(!Pallet::<Test>::is_hibernated(CONTRACT));
+		assert_eq!(Pallet::<Test>::contract_state(CONTRACT), state);
+		assert_eq!(LastAccess::<Test>::get(CONTRACT), Some(50));
+
+		expect_events(vec![
+			Event::DormancyPeriodSet { period: Some(10) },
+			Event::ContractHibernated {
+				contract: CONTRACT,
+				code_hash: hibernated.code_hash,
+				storage_root: hibernated.storage_root,
+				storage_entries: 2,
+			},
+			Event::ContractWoken { contract: CONTRACT },
+		]);
+	});
+}
+
+#[test]
+fn wake_requires_the_hibernated_state() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_contract();
+
+		assert_noop!(
+			wake(contract_code(), contract_storage()),
+			Error::<Test>::NotHibernated
+		);
+
+		System::set_block_number(11);
+		assert_ok!(EvmHibernation::hibernate_contract(
+			RuntimeOrigin::root(),
+			CONTRACT
+		));
+
+		assert_noop!(
+			wake(vec![0x00], contract_storage()),
+			Error::<Test>::InvalidCode
+		);
+
+		let mut storage = contract_storage();
+		storage[1].1 = H256::repeat_byte(0x33);
+		assert_noop!(
+			wake(contract_code(), storage),
+			Error::<Test>::InvalidStorage
+		);
+
+		let mut storage = contract_storage();
+		storage.reverse();
+		assert_noop!(
+			wake(contract_code(), storage),
+			Error::<Test>::InvalidStorage
+		);
+
+		assert_noop!(
+			wake(contract_code(), contract_storage()[..1].to_vec()),
+			Error::<Test>::InvalidStorage
+		);
+	});
+}
+
+#[test]
+fn calls_to_hibernated_contracts_are_refused() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_contract();
+		System::set_block_number(11);
+		assert_ok!(EvmHibernation::hibernate_contract(
+			RuntimeOrigin::root(),
+			CONTRACT
+		));
+
+		assert_eq!(
+			call(CONTRACT).map_err(|e| e.error),
+			Err(Error::<Test>::ContractHibernated.into())
+		);
+
+		assert_ok!(wake(contract_code(), contract_storage()));
+		assert_ok!(call(CONTRACT));
+	});
+}
+
+#[test]
+fn runner_tracks_accesses_to_contracts() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_contract();
+
+		System::set_block_number(3);
+		assert_ok!(call(CONTRACT));
+		assert_eq!(LastAccess::<Test>::get(CONTRACT), Some(3));
+
+		// Calls to accounts without code are not tracked.
+		assert_ok!(call(BOB));
+		assert_eq!(LastAccess::<Test>::get(BOB), None);
+
+		System::set_block_number(4);
+		assert_ok!(EVM::create(
+			RuntimeOrigin::root(),
+			ALICE,
+			INIT_CODE.to_vec(),
+			U256::zero(),
+			GAS_LIMIT,
+			U256::zero(),
+			None,
+			None,
+			Vec::new(),
+		));
+		let tracked: Vec<(H160, u32)> = LastAccess::<Test>::iter()
+			.filter(|(address, _)| *address != CONTRACT)
+			.collect();
+		assert_eq!(tracked.len(), 1);
+		assert_eq!(tracked[0].1, 4);
+		assert_eq!(
+			pallet_evm::AccountCodes::<Test>::get(tracked[0].0),
+			vec![0x00]
+		);
+	});
+}
+
+#[test]
+fn accesses_are_not_tracked_while_disabled() {
+	ExtBuilder::default().build().execute_with(|| {
+		pallet_evm::Pallet::<Test>::create_account(CONTRACT, contract_code());
+
+		assert_ok!(call(CONTRACT));
+		assert_eq!(LastAccess::<Test>::get(CONTRACT), None);
+	});
+}
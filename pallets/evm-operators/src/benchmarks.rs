@@ -0,0 +1,112 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+
+use crate::{Call, Config, OperatorScope, Operators, Pallet};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_core::{H160, U256};
+use sp_runtime::traits::Convert;
+use sp_std::vec::Vec;
+
+fn contracts<T: Config>(count: u32) -> BoundedVec<H160, T::MaxContractsPerOperator> {
+	(0..count)
+		.map(|i| H160::from_low_u64_be(0x1000 + i as u64))
+		.collect::<Vec<_>>()
+		.try_into()
+		.expect("count is bounded by MaxContractsPerOperator")
+}
+
+benchmarks! {
+	add_operator {
+		let c in 1..T::MaxContractsPerOperator::get();
+		let caller: T::AccountId = account("delegator", 0, 0);
+		let operator = H160::repeat_byte(0xbb);
+	}: _(RawOrigin::Signed(caller.clone()), operator, contracts::<T>(c), U256::one(), U256::one())
+	verify {
+		let delegator = T::AccountIdToH160::convert(caller);
+		assert!(Operators::<T>::contains_key(delegator, operator));
+	}
+
+	remove_operator {
+		let caller: T::AccountId = account("delegator", 0, 0);
+		let delegator = T::AccountIdToH160::convert(caller.clone());
+		let operator = H160::repeat_byte(0xbb);
+		Operators::<T>::insert(delegator, operator, OperatorScope {
+			contracts: contracts::<T>(T::MaxContractsPerOperator::get()),
+			max_value: U256::zero(),
+			spend_limit: U256::zero(),
+			spent: U256::zero(),
+		});
+	}: _(RawOrigin::Signed(caller), operator)
+	verify {
+		assert!(!Operators::<T>::contains_key(delegator, operator));
+	}
+
+	operator_call {
+		let c in 1..T::MaxContractsPerOperator::get();
+		let caller: T::AccountId = account("operator", 0, 0);
+		let operator = T::AccountIdToH160::convert(caller.clone());
+		let delegator = H160::repeat_byte(0xaa);
+		let scope = contracts::<T>(c);
+		// Worst case: the target is the last contract of the scope.
+		let target = *scope.last().expect("c is at least 1");
+		Operators::<T>::insert(delegator, operator, OperatorScope {
+			contracts: scope,
+			max_value: U256::zero(),
+			spend_limit: U256::zero(),
+			spent: U256::zero(),
+		});
+	}: _(RawOrigin::Signed(caller), delegator, target, Vec::new(), U256::zero(), 100_000)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::ExtBuilder;
+	use crate::Pallet;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn bench_add_operator() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_add_operator());
+		});
+	}
+
+	#[test]
+	fn bench_remove_operator() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_remove_operator());
+		});
+	}
+
+	#[test]
+	fn bench_operator_call() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_operator_call());
+		});
+	}
+}
+
+impl_benchmark_test_suite!(
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
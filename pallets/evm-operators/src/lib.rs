@@ -0,0 +1,303 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # EVM operators pallet
+//!
+//! This pallet provides native account delegation for EVM calls, similar to what pallet-proxy
+//! offers for substrate calls. An account (the delegator) can register another address as one of
+//! its operators, with a scope made of the contracts the operator is allowed to call, of the
+//! maximum value it can transfer in a single call and of the total value it can transfer over all
+//! its calls.
+//!
+//! The operator submits its calls through [`Pallet::operator_call`]. Calls within the scope are
+//! executed by the EVM runner with the delegator as source, and go through the usual validation
+//! of `pallet_evm` (balance, gas limit, ...). The execution is paid by the operator through the
+//! weight of the extrinsic: the delegator is never charged any gas fee, so the only funds an
+//! operator can move are the values transferred within the limits of its scope.
+//!
+//! As the calls are executed with the delegator as source, each of them increments the EVM nonce
+//! of the delegator, exactly like a transaction it would have sent itself. Transactions the
+//! delegator signed in advance with a given nonce become invalid once an operator call used it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::pallet;
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use pallet_evm::{GasWeightMapping, Runner};
+	use sp_core::{H160, U256};
+	use sp_runtime::traits::{Convert, UniqueSaturatedInto};
+	use sp_std::vec::Vec;
+
+	/// Scope of the calls an operator can perform on behalf of a delegator.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(MaxContracts))]
+	pub struct OperatorScope<MaxContracts: Get<u32>> {
+		/// Contracts the operator is allowed to call.
+		pub contracts: BoundedVec<H160, MaxContracts>,
+		/// Maximum value the operator can transfer in a single call.
+		pub max_value: U256,
+		/// Maximum total value the operator can transfer over all its calls.
+		pub spend_limit: U256,
+		/// Total value already transferred by the operator.
+		pub spent: U256,
+	}
+
+	impl<MaxContracts: Get<u32>> OperatorScope<MaxContracts> {
+		/// Whether a call to `target` transferring `value` is within the scope.
+		pub fn allows(&self, target: H160, value: U256) -> bool {
+			value <= self.max_value
+				&& self.spent.saturating_add(value) <= self.spend_limit
+				&& self.contracts.contains(&target)
+		}
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_evm::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Convert an account id into the corresponding EVM address.
+		type AccountIdToH160: Convert<Self::AccountId, H160>;
+
+		/// EVM runner used to execute the operator calls.
+		type EvmRunner: Runner<Self>;
+
+		/// Maximum number of contracts in the scope of an operator.
+		#[pallet::constant]
+		type MaxContractsPerOperator: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Scope of the operators of each delegator.
+	#[pallet::storage]
+	#[pallet::getter(fn operator_scope)]
+	pub type Operators<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		H160,
+		Blake2_128Concat,
+		H160,
+		OperatorScope<T::MaxContractsPerOperator>,
+		OptionQuery,
+	>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// An account cannot be its own operator.
+		CannotDelegateToSelf,
+		/// The scope of an operator must contain at least one contract.
+		EmptyScope,
+		/// The address is not an operator of the delegator.
+		NotOperator,
+		/// The call is outside of the scope of the operator.
+		OutOfScope,
+		/// The EVM runner refused to execute the call.
+		EvmCallFailed,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An operator was registered or had its scope updated.
+		OperatorAdded {
+			delegator: H160,
+			operator: H160,
+			contracts: BoundedVec<H160, T::MaxContractsPerOperator>,
+			max_value: U256,
+			spend_limit: U256,
+		},
+		/// An operator was removed.
+		OperatorRemoved { delegator: H160, operator: H160 },
+		/// An operator executed a call on behalf of a delegator.
+		OperatorCallExecuted {
+			delegator: H160,
+			operator: H160,
+			target: H160,
+			succeeded: bool,
+		},
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register `operator` as an operator of the caller, or replace its scope if it already
+		/// is one. Replacing a scope resets the value spent by the operator.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_operator(contracts.len() as u32))]
+		pub fn add_operator(
+			origin: OriginFor<T>,
+			operator: H160,
+			contracts: BoundedVec<H160, T::MaxContractsPerOperator>,
+			max_value: U256,
+			spend_limit: U256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegator = T::AccountIdToH160::convert(who);
+
+			ensure!(delegator != operator, Error::<T>::CannotDelegateToSelf);
+			ensure!(!contracts.is_empty(), Error::<T>::EmptyScope);
+
+			Operators::<T>::insert(
+				delegator,
+				operator,
+				OperatorScope {
+					contracts: contracts.clone(),
+					max_value,
+					spend_limit,
+					spent: U256::zero(),
+				},
+			);
+
+			Self::deposit_event(Event::OperatorAdded {
+				delegator,
+				operator,
+				contracts,
+				max_value,
+				spend_limit,
+			});
+			Ok(())
+		}
+
+		/// Remove an operator of the caller.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_operator())]
+		pub fn remove_operator(origin: OriginFor<T>, operator: H160) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegator = T::AccountIdToH160::convert(who);
+
+			ensure!(
+				Operators::<T>::take(delegator, operator).is_some(),
+				Error::<T>::NotOperator
+			);
+
+			Self::deposit_event(Event::OperatorRemoved {
+				delegator,
+				operator,
+			});
+			Ok(())
+		}
+
+		/// Execute an EVM call on behalf of `delegator`, which must have registered the caller
+		/// as an operator whose scope covers the call.
+		///
+		/// The call is executed with the delegator as source and therefore increments its EVM
+		/// nonce. The value of a successful call counts towards the spend limit of the operator.
+		#[pallet::call_index(2)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::operator_call(T::MaxContractsPerOperator::get())
+				.saturating_add(T::GasWeightMapping::gas_to_weight(*gas_limit, true))
+		)]
+		pub fn operator_call(
+			origin: OriginFor<T>,
+			delegator: H160,
+			target: H160,
+			input: Vec<u8>,
+			value: U256,
+			gas_limit: u64,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let operator = T::AccountIdToH160::convert(who);
+
+			Self::ensure_operator_call(delegator, operator, target, value)?;
+
+			let weight_limit = T::GasWeightMapping::gas_to_weight(gas_limit, true);
+			let info = T::EvmRunner::call(
+				delegator,
+				target,
+				input,
+				value,
+				gas_limit,
+				None,
+				None,
+				None,
+				Vec::new(),
+				false,
+				true,
+				Some(weight_limit),
+				Some(0),
+				<T as pallet_evm::Config>::config(),
+			)
+			.map_err(|_| Error::<T>::EvmCallFailed)?;
+
+			let succeeded = info.exit_reason.is_succeed();
+			if succeeded && !value.is_zero() {
+				Operators::<T>::mutate(delegator, operator, |scope| {
+					if let Some(scope) = scope {
+						scope.spent = scope.spent.saturating_add(value);
+					}
+				});
+			}
+
+			Self::deposit_event(Event::OperatorCallExecuted {
+				delegator,
+				operator,
+				target,
+				succeeded,
+			});
+
+			let used_gas = info.used_gas.standard.unique_saturated_into();
+			Ok(Some(
+				<T as Config>::WeightInfo::operator_call(T::MaxContractsPerOperator::get())
+					.saturating_add(T::GasWeightMapping::gas_to_weight(used_gas, true)),
+			)
+			.into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Ensure that `operator` can call `target` with `value` on behalf of `delegator`.
+		pub fn ensure_operator_call(
+			delegator: H160,
+			operator: H160,
+			target: H160,
+			value: U256,
+		) -> Result<(), Error<T>> {
+			let scope = Operators::<T>::get(delegator, operator).ok_or(Error::<T>::NotOperator)?;
+			ensure!(scope.allows(target, value), Error::<T>::OutOfScope);
+			Ok(())
+		}
+	}
+}
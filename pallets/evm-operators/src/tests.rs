@@ -0,0 +1,308 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.use crate::mock::*;
+
+use crate::{Error, Event, Operators, Pallet};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo, BoundedVec};
+use sp_core::{H160, U256};
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+fn scope(
+	contracts: Vec<H160>,
+) -> BoundedVec<H160, <Test as crate::Config>::MaxContractsPerOperator> {
+	contracts
+		.try_into()
+		.expect("scope fits in MaxContractsPerOperator")
+}
+
+fn operator_call(operator: H160, target: H160, value: u128) -> DispatchResultWithPostInfo {
+	EvmOperators::operator_call(
+		RuntimeOrigin::signed(operator),
+		ALICE,
+		target,
+		Vec::new(),
+		value.into(),
+		GAS_LIMIT,
+	)
+}
+
+#[test]
+fn add_and_remove_operator() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmOperators::add_operator(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			scope(vec![CHARLIE]),
+			10.into(),
+			1_000.into(),
+		));
+		assert_eq!(
+			Pallet::<Test>::operator_scope(ALICE, BOB).map(|s| s.max_value),
+			Some(10.into())
+		);
+
+		assert_ok!(EvmOperators::remove_operator(
+			RuntimeOrigin::signed(ALICE),
+			BOB
+		));
+		assert!(!Operators::<Test>::contains_key(ALICE, BOB));
+
+		expect_events(vec![
+			Event::OperatorAdded {
+				delegator: ALICE,
+				operator: BOB,
+				contracts: scope(vec![CHARLIE]),
+				max_value: 10.into(),
+				spend_limit: 1_000.into(),
+			},
+			Event::OperatorRemoved {
+				delegator: ALICE,
+				operator: BOB,
+			},
+		]);
+	});
+}
+
+#[test]
+fn add_operator_replaces_existing_scope() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmOperators::add_operator(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			scope(vec![CHARLIE]),
+			10.into(),
+			1_000.into(),
+		));
+		assert_ok!(EvmOperators::add_operator(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			scope(vec![ALICE]),
+			U256::zero(),
+			1_000.into(),
+		));
+
+		let current = Pallet::<Test>::operator_scope(ALICE, BOB).expect("BOB is an operator");
+		assert!(current.allows(ALICE, U256::zero()));
+		assert!(!current.allows(CHARLIE, U256::zero()));
+	});
+}
+
+#[test]
+fn add_operator_rejects_invalid_scopes() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EvmOperators::add_operator(
+				RuntimeOrigin::signed(ALICE),
+				ALICE,
+				scope(vec![CHARLIE]),
+				U256::zero(),
+				1_000.into(),
+			),
+			Error::<Test>::CannotDelegateToSelf
+		);
+		assert_noop!(
+			EvmOperators::add_operator(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				scope(vec![]),
+				U256::zero(),
+				1_000.into(),
+			),
+			Error::<Test>::EmptyScope
+		);
+	});
+}
+
+#[test]
+fn remove_operator_fails_if_not_operator() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EvmOperators::remove_operator(RuntimeOrigin::signed(ALICE), BOB),
+			Error::<Test>::NotOperator
+		);
+	});
+}
+
+#[test]
+fn operator_can_call_within_its_scope() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(EvmOperators::add_operator(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				scope(vec![CHARLIE]),
+				100.into(),
+				1_000.into(),
+			));
+
+			assert_ok!(operator_call(BOB, CHARLIE, 100));
+
+			// Only the transferred value leaves the delegator account, the operator pays for the
+			// execution.
+			assert_eq!(Balances::free_balance(ALICE), 900);
+			assert_eq!(Balances::free_balance(CHARLIE), 100);
+			assert_eq!(
+				events().last(),
+				Some(&Event::OperatorCallExecuted {
+					delegator: ALICE,
+					operator: BOB,
+					target: CHARLIE,
+					succeeded: true,
+				})
+			);
+		});
+}
+
+#[test]
+fn operator_cannot_call_outside_of_its_scope() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(EvmOperators::add_operator(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				scope(vec![CHARLIE]),
+				100.into(),
+				1_000.into(),
+			));
+
+			assert_noop!(
+				operator_call(BOB, H160::repeat_byte(0xdd), 0),
+				Error::<Test>::OutOfScope
+			);
+			assert_noop!(operator_call(BOB, CHARLIE, 101), Error::<Test>::OutOfScope);
+			assert_noop!(
+				operator_call(CHARLIE, CHARLIE, 0),
+				Error::<Test>::NotOperator
+			);
+		});
+}
+
+#[test]
+fn removed_operator_can_no_longer_call() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(EvmOperators::add_operator(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				scope(vec![CHARLIE]),
+				100.into(),
+				1_000.into(),
+			));
+			assert_ok!(EvmOperators::remove_operator(
+				RuntimeOrigin::signed(ALICE),
+				BOB
+			));
+
+			assert_noop!(operator_call(BOB, CHARLIE, 0), Error::<Test>::NotOperator);
+		});
+}
+
+#[test]
+fn operator_call_reports_failed_executions() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 50)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(EvmOperators::add_operator(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				scope(vec![CHARLIE]),
+				100.into(),
+				1_000.into(),
+			));
+
+			// The delegator cannot afford the transferred value.
+			assert_ok!(operator_call(BOB, CHARLIE, 100));
+
+			assert_eq!(Balances::free_balance(ALICE), 50);
+			assert_eq!(
+				events().last(),
+				Some(&Event::OperatorCallExecuted {
+					delegator: ALICE,
+					operator: BOB,
+					target: CHARLIE,
+					succeeded: false,
+				})
+			);
+		});
+}
+
+#[test]
+fn operator_cannot_exceed_its_spend_limit() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(EvmOperators::add_operator(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				scope(vec![CHARLIE]),
+				100.into(),
+				150.into(),
+			));
+
+			assert_ok!(operator_call(BOB, CHARLIE, 100));
+			assert_eq!(
+				Pallet::<Test>::operator_scope(ALICE, BOB).map(|s| s.spent),
+				Some(100.into())
+			);
+
+			// Within the per call limit, but above the remaining spend limit.
+			assert_noop!(operator_call(BOB, CHARLIE, 51), Error::<Test>::OutOfScope);
+			assert_ok!(operator_call(BOB, CHARLIE, 50));
+			assert_eq!(Balances::free_balance(CHARLIE), 150);
+
+			// Replacing the scope resets the spent value.
+			assert_ok!(EvmOperators::add_operator(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				scope(vec![CHARLIE]),
+				100.into(),
+				150.into(),
+			));
+			assert_ok!(operator_call(BOB, CHARLIE, 100));
+		});
+}
+
+#[test]
+fn failed_operator_calls_do_not_count_towards_the_spend_limit() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 50)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(EvmOperators::add_operator(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				scope(vec![CHARLIE]),
+				100.into(),
+				100.into(),
+			));
+
+			// The delegator cannot afford the transferred value.
+			assert_ok!(operator_call(BOB, CHARLIE, 100));
+
+			assert_eq!(
+				Pallet::<Test>::operator_scope(ALICE, BOB).map(|s| s.spent),
+				Some(U256::zero())
+			);
+		});
+}
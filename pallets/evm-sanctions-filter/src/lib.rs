@@ -0,0 +1,164 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # EVM sanctions filter pallet
+//!
+//! This pallet allows governance to maintain a list of sanctioned addresses, for chains that must
+//! comply with sanctions regulations. While the filter is enabled, EVM transactions sent from or
+//! to a sanctioned address are rejected, both when they are validated by the transaction pool and
+//! when they are executed, which is enforced by wrapping the EVM runner in
+//! [`SanctionsFilterRunner`].
+//!
+//! The filter is disabled by default, such that the list can be prepared before it is enforced.
+//! Only the source and the target of the transactions are checked: the sub-calls performed by the
+//! contracts are not restricted.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod runner;
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use runner::SanctionsFilterRunner;
+pub use weights::WeightInfo;
+
+use frame_support::pallet;
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_core::H160;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that is allowed to enable the filter and to manage the sanctioned addresses.
+		type SanctionsManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Whether EVM transactions from or to the sanctioned addresses are rejected.
+	#[pallet::storage]
+	#[pallet::getter(fn is_filter_enabled)]
+	pub type FilterEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Addresses EVM transactions cannot be sent from or to while the filter is enabled.
+	#[pallet::storage]
+	pub type SanctionedAddresses<T: Config> =
+		StorageMap<_, Blake2_128Concat, H160, (), OptionQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The address is already sanctioned.
+		AlreadySanctioned,
+		/// The address is not sanctioned.
+		NotSanctioned,
+		/// The transaction is sent from or to a sanctioned address.
+		SanctionedAddress,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// EVM transactions from or to the sanctioned addresses are now rejected.
+		FilterEnabled,
+		/// EVM transactions are no longer filtered.
+		FilterDisabled,
+		/// The address was added to the sanctioned addresses.
+		AddressSanctioned { address: H160 },
+		/// The address was removed from the sanctioned addresses.
+		AddressUnsanctioned { address: H160 },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Enable or disable the rejection of the EVM transactions from or to the sanctioned
+		/// addresses.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_filter_enabled())]
+		pub fn set_filter_enabled(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			T::SanctionsManagerOrigin::ensure_origin(origin)?;
+
+			FilterEnabled::<T>::put(enabled);
+
+			if enabled {
+				Self::deposit_event(Event::FilterEnabled);
+			} else {
+				Self::deposit_event(Event::FilterDisabled);
+			}
+			Ok(())
+		}
+
+		/// Add an address to the sanctioned addresses.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::sanction_address())]
+		pub fn sanction_address(origin: OriginFor<T>, address: H160) -> DispatchResult {
+			T::SanctionsManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!SanctionedAddresses::<T>::contains_key(address),
+				Error::<T>::AlreadySanctioned
+			);
+			SanctionedAddresses::<T>::insert(address, ());
+
+			Self::deposit_event(Event::AddressSanctioned { address });
+			Ok(())
+		}
+
+		/// Remove an address from the sanctioned addresses.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::unsanction_address())]
+		pub fn unsanction_address(origin: OriginFor<T>, address: H160) -> DispatchResult {
+			T::SanctionsManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				SanctionedAddresses::<T>::take(address).is_some(),
+				Error::<T>::NotSanctioned
+			);
+
+			Self::deposit_event(Event::AddressUnsanctioned { address });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether the address is sanctioned, regardless of the filter being enabled.
+		pub fn is_sanctioned(address: H160) -> bool {
+			SanctionedAddresses::<T>::contains_key(address)
+		}
+
+		/// Whether EVM transactions from or to the address are currently rejected.
+		pub fn is_blocked(address: H160) -> bool {
+			Self::is_filter_enabled() && Self::is_sanctioned(address)
+		}
+	}
+}
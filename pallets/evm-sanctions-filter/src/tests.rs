@@ -0,0 +1,257 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{Error, Event, Pallet};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo};
+use pallet_evm::Runner;
+use sp_core::{H160, H256, U256};
+use sp_runtime::DispatchError;
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+fn call(source: H160, target: H160) -> DispatchResultWithPostInfo {
+	EVM::call(
+		RuntimeOrigin::root(),
+		source,
+		target,
+		Vec::new(),
+		U256::zero(),
+		GAS_LIMIT,
+		U256::zero(),
+		None,
+		None,
+		Vec::new(),
+	)
+}
+
+fn create(source: H160) -> DispatchResultWithPostInfo {
+	EVM::create(
+		RuntimeOrigin::root(),
+		source,
+		Vec::new(),
+		U256::zero(),
+		GAS_LIMIT,
+		U256::zero(),
+		None,
+		None,
+		Vec::new(),
+	)
+}
+
+fn create2(source: H160) -> DispatchResultWithPostInfo {
+	EVM::create2(
+		RuntimeOrigin::root(),
+		source,
+		Vec::new(),
+		H256::repeat_byte(0x01),
+		U256::zero(),
+		GAS_LIMIT,
+		U256::zero(),
+		None,
+		None,
+		Vec::new(),
+	)
+}
+
+fn sanctioned_address() -> Result<(), DispatchError> {
+	Err(Error::<Test>::SanctionedAddress.into())
+}
+
+fn sanction_and_enable(address: H160) {
+	assert_ok!(EvmSanctionsFilter::sanction_address(
+		RuntimeOrigin::root(),
+		address
+	));
+	assert_ok!(EvmSanctionsFilter::set_filter_enabled(
+		RuntimeOrigin::root(),
+		true
+	));
+}
+
+#[test]
+fn sanction_and_unsanction_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmSanctionsFilter::sanction_address(
+			RuntimeOrigin::root(),
+			ALICE
+		));
+		assert!(Pallet::<Test>::is_sanctioned(ALICE));
+		assert!(!Pallet::<Test>::is_sanctioned(BOB));
+
+		assert_ok!(EvmSanctionsFilter::unsanction_address(
+			RuntimeOrigin::root(),
+			ALICE
+		));
+		assert!(!Pallet::<Test>::is_sanctioned(ALICE));
+
+		expect_events(vec![
+			Event::AddressSanctioned { address: ALICE },
+			Event::AddressUnsanctioned { address: ALICE },
+		]);
+	});
+}
+
+#[test]
+fn sanction_address_fails_if_already_sanctioned() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmSanctionsFilter::sanction_address(
+			RuntimeOrigin::root(),
+			ALICE
+		));
+		assert_noop!(
+			EvmSanctionsFilter::sanction_address(RuntimeOrigin::root(), ALICE),
+			Error::<Test>::AlreadySanctioned
+		);
+	});
+}
+
+#[test]
+fn unsanction_address_fails_if_not_sanctioned() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EvmSanctionsFilter::unsanction_address(RuntimeOrigin::root(), ALICE),
+			Error::<Test>::NotSanctioned
+		);
+	});
+}
+
+#[test]
+fn calls_require_manager_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EvmSanctionsFilter::set_filter_enabled(RuntimeOrigin::signed(ALICE), true),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			EvmSanctionsFilter::sanction_address(RuntimeOrigin::signed(ALICE), BOB),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			EvmSanctionsFilter::unsanction_address(RuntimeOrigin::signed(ALICE), BOB),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn sanctioned_addresses_are_not_blocked_while_filter_is_disabled() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmSanctionsFilter::sanction_address(
+			RuntimeOrigin::root(),
+			BOB
+		));
+
+		assert!(!Pallet::<Test>::is_blocked(BOB));
+		assert_ok!(call(BOB, ALICE));
+		assert_ok!(call(ALICE, BOB));
+		assert_ok!(create(BOB));
+	});
+}
+
+#[test]
+fn enabled_filter_rejects_transactions_from_sanctioned_addresses() {
+	ExtBuilder::default().build().execute_with(|| {
+		sanction_and_enable(BOB);
+
+		assert!(Pallet::<Test>::is_blocked(BOB));
+		assert!(!Pallet::<Test>::is_blocked(ALICE));
+
+		assert_eq!(
+			call(BOB, ALICE).map(|_| ()).map_err(|e| e.error),
+			sanctioned_address()
+		);
+		assert_eq!(
+			create(BOB).map(|_| ()).map_err(|e| e.error),
+			sanctioned_address()
+		);
+		assert_eq!(
+			create2(BOB).map(|_| ()).map_err(|e| e.error),
+			sanctioned_address()
+		);
+		assert_ok!(create(ALICE));
+
+		expect_events(vec![
+			Event::AddressSanctioned { address: BOB },
+			Event::FilterEnabled,
+		]);
+	});
+}
+
+#[test]
+fn enabled_filter_rejects_transactions_to_sanctioned_addresses() {
+	ExtBuilder::default().build().execute_with(|| {
+		sanction_and_enable(BOB);
+
+		assert_eq!(
+			call(ALICE, BOB).map(|_| ()).map_err(|e| e.error),
+			sanctioned_address()
+		);
+		assert_ok!(call(ALICE, ALICE));
+	});
+}
+
+#[test]
+fn enabled_filter_rejects_sanctioned_transactions_during_validation() {
+	ExtBuilder::default().build().execute_with(|| {
+		sanction_and_enable(BOB);
+
+		let validate = |source, target| {
+			<Test as pallet_evm::Config>::Runner::validate(
+				source,
+				target,
+				Vec::new(),
+				U256::zero(),
+				GAS_LIMIT,
+				None,
+				None,
+				None,
+				Vec::new(),
+				true,
+				None,
+				None,
+				<Test as pallet_evm::Config>::config(),
+			)
+			.map_err(|e| e.error)
+		};
+
+		assert_eq!(validate(BOB, None), sanctioned_address());
+		assert_eq!(validate(ALICE, Some(BOB)), sanctioned_address());
+	});
+}
+
+#[test]
+fn disabling_the_filter_unblocks_sanctioned_addresses() {
+	ExtBuilder::default().build().execute_with(|| {
+		sanction_and_enable(BOB);
+		assert_eq!(
+			create(BOB).map(|_| ()).map_err(|e| e.error),
+			sanctioned_address()
+		);
+
+		assert_ok!(EvmSanctionsFilter::set_filter_enabled(
+			RuntimeOrigin::root(),
+			false
+		));
+		assert_ok!(create(BOB));
+
+		expect_events(vec![
+			Event::AddressSanctioned { address: BOB },
+			Event::FilterEnabled,
+			Event::FilterDisabled,
+		]);
+	});
+}
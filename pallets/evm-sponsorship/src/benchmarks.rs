@@ -0,0 +1,116 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+
+use crate::{sponsor_message, transaction_hash, Call, Config, Pallet, SponsorEnvelope};
+use ethereum::{EIP1559Transaction, EIP1559TransactionMessage, TransactionAction};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::{Currency, Get};
+use frame_system::RawOrigin;
+use pallet_evm::{AddressMapping, FeeCalculator};
+use sp_core::{H160, H256, U256};
+use sp_std::vec::Vec;
+
+const USER_KEY: [u8; 32] = [1u8; 32];
+const SPONSOR_KEY: [u8; 32] = [2u8; 32];
+
+fn address_of(secret: &[u8; 32]) -> H160 {
+	let secret = libsecp256k1::SecretKey::parse(secret).expect("valid secret key");
+	let public_key = &libsecp256k1::PublicKey::from_secret_key(&secret).serialize()[1..65];
+	H160::from(H256::from(sp_io::hashing::keccak_256(public_key)))
+}
+
+fn sign(secret: &[u8; 32], message: &[u8; 32]) -> ([u8; 64], u8) {
+	let secret = libsecp256k1::SecretKey::parse(secret).expect("valid secret key");
+	let (signature, recovery_id) =
+		libsecp256k1::sign(&libsecp256k1::Message::parse(message), &secret);
+	(signature.serialize(), recovery_id.serialize())
+}
+
+benchmarks! {
+	transact_sponsored {
+		let gas_limit = 21_000u64;
+		let message = EIP1559TransactionMessage {
+			chain_id: T::ChainId::get(),
+			nonce: U256::zero(),
+			max_priority_fee_per_gas: U256::zero(),
+			max_fee_per_gas: U256::zero(),
+			gas_limit: gas_limit.into(),
+			action: TransactionAction::Call(H160::repeat_byte(0xcc)),
+			value: U256::zero(),
+			input: Vec::new(),
+			access_list: Vec::new(),
+		};
+		let (rs, v) = sign(&USER_KEY, message.hash().as_fixed_bytes());
+		let transaction = EIP1559Transaction {
+			chain_id: message.chain_id,
+			nonce: message.nonce,
+			max_priority_fee_per_gas: message.max_priority_fee_per_gas,
+			max_fee_per_gas: message.max_fee_per_gas,
+			gas_limit: message.gas_limit,
+			action: message.action,
+			value: message.value,
+			input: message.input,
+			access_list: message.access_list,
+			odd_y_parity: v != 0,
+			r: H256::from_slice(&rs[0..32]),
+			s: H256::from_slice(&rs[32..64]),
+		};
+
+		let (gas_price, _) = T::FeeCalculator::min_gas_price();
+		let max_fee = gas_price.saturating_mul(gas_limit.into());
+		let (rs, v) = sign(
+			&SPONSOR_KEY,
+			&sponsor_message(T::ChainId::get(), transaction_hash(&transaction), max_fee),
+		);
+		let mut signature = [0u8; 65];
+		signature[0..64].copy_from_slice(&rs);
+		signature[64] = v;
+
+		let sponsor = T::AddressMapping::into_account_id(address_of(&SPONSOR_KEY));
+		T::Currency::make_free_balance_be(
+			&sponsor,
+			Pallet::<T>::u256_to_balance(max_fee.saturating_mul(2.into())),
+		);
+	}: _(RawOrigin::None, transaction, SponsorEnvelope { max_fee, signature })
+	verify {
+		let (account, _) = pallet_evm::Pallet::<T>::account_basic(&address_of(&USER_KEY));
+		assert_eq!(account.nonce, U256::one());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::ExtBuilder;
+	use crate::Pallet;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn bench_transact_sponsored() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_transact_sponsored());
+		});
+	}
+}
+
+impl_benchmark_test_suite!(
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
@@ -0,0 +1,360 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # EVM sponsorship pallet
+//!
+//! This pallet lets a sponsor pay for the gas of the EVM transactions of other accounts, so that
+//! dApps can onboard users that do not hold any native token. Unlike meta-transactions relayed by
+//! a forwarder contract, the transaction is executed natively, with the user as `msg.sender`.
+//!
+//! The user signs a regular EIP-1559 transaction. The sponsor signs an envelope committing to the
+//! hash of that transaction and to the maximum fee it accepts to pay (see [`sponsor_message`]).
+//! Anyone can submit both through the unsigned [`Pallet::transact_sponsored`] extrinsic, which is
+//! validated like an Ethereum transaction (chain id, signature and nonce of the user) and also
+//! checks the signature of the sponsor and that it can afford the maximum fee.
+//!
+//! The user is not charged any gas fee: the sponsor pays for the gas used at the minimum gas
+//! price, capped by the maximum fee of the envelope.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use ethereum::EIP1559Transaction;
+use frame_support::pallet;
+use sp_core::{H160, H256, U256};
+use sp_std::vec::Vec;
+
+/// Prefix of the message signed by the sponsor, to prevent its signature from being replayed as
+/// the signature of another kind of payload.
+pub const SPONSOR_MESSAGE_PREFIX: &[u8] = b"\x19Moonbeam sponsored transaction:";
+
+/// Message the sponsor signs to cover the gas of the transaction with the given hash, for at
+/// most `max_fee`.
+pub fn sponsor_message(chain_id: u64, transaction_hash: H256, max_fee: U256) -> [u8; 32] {
+	let mut max_fee_bytes = [0u8; 32];
+	max_fee.to_big_endian(&mut max_fee_bytes);
+
+	let mut message = Vec::with_capacity(SPONSOR_MESSAGE_PREFIX.len() + 8 + 32 + 32);
+	message.extend_from_slice(SPONSOR_MESSAGE_PREFIX);
+	message.extend_from_slice(&chain_id.to_be_bytes());
+	message.extend_from_slice(transaction_hash.as_bytes());
+	message.extend_from_slice(&max_fee_bytes);
+	sp_io::hashing::keccak_256(&message)
+}
+
+/// Hash of a transaction, as known by Ethereum clients.
+pub fn transaction_hash(transaction: &EIP1559Transaction) -> H256 {
+	ethereum::TransactionV2::EIP1559(transaction.clone()).hash()
+}
+
+fn recover_signer(signature: &[u8; 65], message: &[u8; 32]) -> Option<H160> {
+	sp_io::crypto::secp256k1_ecdsa_recover(signature, message)
+		.ok()
+		.map(|public_key| H160::from(H256::from(sp_io::hashing::keccak_256(&public_key))))
+}
+
+fn transaction_signer(transaction: &EIP1559Transaction) -> Option<H160> {
+	let mut signature = [0u8; 65];
+	signature[0..32].copy_from_slice(transaction.r.as_bytes());
+	signature[32..64].copy_from_slice(transaction.s.as_bytes());
+	signature[64] = transaction.odd_y_parity as u8;
+	let message = ethereum::EIP1559TransactionMessage::from(transaction.clone()).hash();
+
+	recover_signer(&signature, message.as_fixed_bytes())
+}
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use ethereum::TransactionAction;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Currency, ExistenceRequirement, Imbalance, OnUnbalanced, WithdrawReasons},
+	};
+	use frame_system::pallet_prelude::*;
+	use pallet_evm::{AddressMapping, FeeCalculator, GasWeightMapping, Runner};
+	use sp_runtime::traits::UniqueSaturatedInto;
+
+	pub(crate) type BalanceOf<T> = <<T as pallet_evm::Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	pub(crate) type NegativeImbalanceOf<T> = <<T as pallet_evm::Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
+	/// Commitment of a sponsor to pay for the gas of a transaction.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct SponsorEnvelope {
+		/// Maximum fee the sponsor accepts to pay.
+		pub max_fee: U256,
+		/// Signature of the sponsor over the [`sponsor_message`] of the transaction.
+		pub signature: [u8; 65],
+	}
+
+	/// A sponsored transaction whose signatures have been checked.
+	pub(crate) struct CheckedTransaction {
+		pub source: H160,
+		pub sponsor: H160,
+		pub target: H160,
+		pub nonce: U256,
+		pub gas_limit: u64,
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_evm::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// EVM runner used to execute the sponsored transactions.
+		type EvmRunner: Runner<Self>;
+
+		/// Handler for the fees paid by the sponsors.
+		type OnSponsoredFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Priority of the sponsored transactions in the transaction pool.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The transaction is signed for another chain.
+		InvalidChainId,
+		/// Only calls can be sponsored, not contract creations.
+		ContractCreationNotSupported,
+		/// The signature of the transaction is invalid.
+		InvalidTransactionSignature,
+		/// The signature of the sponsor is invalid.
+		InvalidSponsorSignature,
+		/// The gas limit of the transaction is above the block gas limit.
+		GasLimitTooHigh,
+		/// The maximum fee of the sponsor does not cover the gas limit of the transaction.
+		MaxFeeTooLow,
+		/// The sponsor cannot afford the maximum fee.
+		SponsorBalanceTooLow,
+		/// The EVM runner refused to execute the transaction.
+		EvmCallFailed,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A sponsored transaction was executed.
+		SponsoredTransactionExecuted {
+			sponsor: H160,
+			source: H160,
+			target: H160,
+			transaction_hash: H256,
+			fee: U256,
+			succeeded: bool,
+		},
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Execute an EVM transaction whose gas is paid by the sponsor of the envelope.
+		#[pallet::call_index(0)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::transact_sponsored().saturating_add(
+				T::GasWeightMapping::gas_to_weight(
+					transaction.gas_limit.unique_saturated_into(),
+					true,
+				)
+			)
+		)]
+		pub fn transact_sponsored(
+			origin: OriginFor<T>,
+			transaction: EIP1559Transaction,
+			envelope: SponsorEnvelope,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+
+			let checked = Self::check_transaction(&transaction, &envelope)?;
+			let sponsor_account = T::AddressMapping::into_account_id(checked.sponsor);
+
+			// The maximum fee is withdrawn upfront and the unused part is refunded afterwards.
+			let withdrawn = T::Currency::withdraw(
+				&sponsor_account,
+				Self::u256_to_balance(envelope.max_fee),
+				WithdrawReasons::FEE,
+				ExistenceRequirement::AllowDeath,
+			)
+			.map_err(|_| Error::<T>::SponsorBalanceTooLow)?;
+
+			let info = T::EvmRunner::call(
+				checked.source,
+				checked.target,
+				transaction.input.clone(),
+				transaction.value,
+				checked.gas_limit,
+				None,
+				None,
+				Some(checked.nonce),
+				transaction
+					.access_list
+					.iter()
+					.map(|item| (item.address, item.storage_keys.clone()))
+					.collect(),
+				false,
+				true,
+				Some(T::GasWeightMapping::gas_to_weight(checked.gas_limit, true)),
+				None,
+				<T as pallet_evm::Config>::config(),
+			)
+			.map_err(|_| Error::<T>::EvmCallFailed)?;
+
+			let (gas_price, _) = T::FeeCalculator::min_gas_price();
+			let fee = gas_price
+				.saturating_mul(info.used_gas.effective)
+				.min(envelope.max_fee);
+			let (fee_imbalance, refund) = withdrawn.split(Self::u256_to_balance(fee));
+			T::Currency::resolve_creating(&sponsor_account, refund);
+			T::OnSponsoredFee::on_unbalanced(fee_imbalance);
+
+			Self::deposit_event(Event::SponsoredTransactionExecuted {
+				sponsor: checked.sponsor,
+				source: checked.source,
+				target: checked.target,
+				transaction_hash: transaction_hash(&transaction),
+				fee,
+				succeeded: info.exit_reason.is_succeed(),
+			});
+
+			Ok(Some(
+				<T as Config>::WeightInfo::transact_sponsored().saturating_add(
+					T::GasWeightMapping::gas_to_weight(
+						info.used_gas.standard.unique_saturated_into(),
+						true,
+					),
+				),
+			)
+			.into())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::transact_sponsored {
+				transaction,
+				envelope,
+			} = call
+			else {
+				return InvalidTransaction::Call.into();
+			};
+
+			let checked =
+				Self::check_transaction(transaction, envelope).map_err(|error| match error {
+					Error::<T>::InvalidTransactionSignature
+					| Error::<T>::InvalidSponsorSignature => InvalidTransaction::BadProof,
+					Error::<T>::GasLimitTooHigh => InvalidTransaction::ExhaustsResources,
+					Error::<T>::MaxFeeTooLow | Error::<T>::SponsorBalanceTooLow => {
+						InvalidTransaction::Payment
+					}
+					_ => InvalidTransaction::Call,
+				})?;
+
+			let (account, _) = pallet_evm::Pallet::<T>::account_basic(&checked.source);
+			if checked.nonce < account.nonce {
+				return InvalidTransaction::Stale.into();
+			}
+
+			let mut builder = ValidTransaction::with_tag_prefix("EvmSponsorship")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((checked.source, checked.nonce))
+				.propagate(true);
+			if checked.nonce > account.nonce {
+				builder = builder.and_requires((checked.source, checked.nonce - 1));
+			}
+			builder.build()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Check the signatures of a sponsored transaction and that the sponsor can pay for it.
+		pub(crate) fn check_transaction(
+			transaction: &EIP1559Transaction,
+			envelope: &SponsorEnvelope,
+		) -> Result<CheckedTransaction, Error<T>> {
+			let chain_id = T::ChainId::get();
+			ensure!(transaction.chain_id == chain_id, Error::<T>::InvalidChainId);
+
+			let TransactionAction::Call(target) = transaction.action else {
+				return Err(Error::<T>::ContractCreationNotSupported);
+			};
+
+			let source =
+				transaction_signer(transaction).ok_or(Error::<T>::InvalidTransactionSignature)?;
+			let message =
+				sponsor_message(chain_id, transaction_hash(transaction), envelope.max_fee);
+			let sponsor = recover_signer(&envelope.signature, &message)
+				.ok_or(Error::<T>::InvalidSponsorSignature)?;
+
+			ensure!(
+				transaction.gas_limit <= T::BlockGasLimit::get(),
+				Error::<T>::GasLimitTooHigh
+			);
+			let (gas_price, _) = T::FeeCalculator::min_gas_price();
+			ensure!(
+				gas_price.saturating_mul(transaction.gas_limit) <= envelope.max_fee,
+				Error::<T>::MaxFeeTooLow
+			);
+
+			let sponsor_balance: u128 =
+				T::Currency::free_balance(&T::AddressMapping::into_account_id(sponsor))
+					.unique_saturated_into();
+			ensure!(
+				U256::from(sponsor_balance) >= envelope.max_fee,
+				Error::<T>::SponsorBalanceTooLow
+			);
+
+			Ok(CheckedTransaction {
+				source,
+				sponsor,
+				target,
+				nonce: transaction.nonce,
+				gas_limit: transaction.gas_limit.unique_saturated_into(),
+			})
+		}
+
+		pub(crate) fn u256_to_balance(value: U256) -> BalanceOf<T> {
+			value
+				.min(U256::from(u128::MAX))
+				.low_u128()
+				.unique_saturated_into()
+		}
+	}
+}
@@ -0,0 +1,295 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test utilities
+
+use crate as pallet_evm_sponsorship;
+use crate::SponsorEnvelope;
+use ethereum::{EIP1559Transaction, EIP1559TransactionMessage, TransactionAction};
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64, Everything},
+	weights::Weight,
+};
+use pallet_evm::{AddressMapping, EnsureAddressNever, EnsureAddressRoot, FeeCalculator};
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+
+pub type AccountId = H160;
+pub type Balance = u128;
+pub type BlockNumber = u32;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage},
+		EVM: pallet_evm::{Pallet, Call, Storage, Event<T>},
+		EvmSponsorship: pallet_evm_sponsorship::{Pallet, Call, Event<T>, ValidateUnsigned},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Header = sp_runtime::generic::Header<BlockNumber, BlakeTwo256>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 0;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 4];
+	type MaxLocks = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
+/// Block storage limit in bytes. Set to 40 KB.
+const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
+
+parameter_types! {
+	pub BlockGasLimit: U256 = U256::from(15_000_000);
+	pub const WeightPerGas: Weight = Weight::from_parts(25_000, 0);
+	pub GasLimitPovSizeRatio: u64 = {
+		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+		block_gas_limit.saturating_div(MAX_POV_SIZE)
+	};
+	pub GasLimitStorageGrowthRatio: u64 = {
+		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+		block_gas_limit.saturating_div(BLOCK_STORAGE_LIMIT)
+	};
+}
+
+pub struct IdentityAddressMapping;
+impl AddressMapping<AccountId> for IdentityAddressMapping {
+	fn into_account_id(address: H160) -> AccountId {
+		address
+	}
+}
+
+pub struct FixedGasPrice;
+impl FeeCalculator for FixedGasPrice {
+	fn min_gas_price() -> (U256, Weight) {
+		(GAS_PRICE.into(), Weight::zero())
+	}
+}
+
+impl pallet_evm::Config for Test {
+	type FeeCalculator = FixedGasPrice;
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type CallOrigin = EnsureAddressRoot<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type AddressMapping = IdentityAddressMapping;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type PrecompilesType = ();
+	type PrecompilesValue = ();
+	type ChainId = ConstU64<CHAIN_ID>;
+	type OnChargeTransaction = ();
+	type BlockGasLimit = BlockGasLimit;
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type FindAuthor = ();
+	type OnCreate = ();
+	type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
+	type GasLimitStorageGrowthRatio = GasLimitStorageGrowthRatio;
+	type Timestamp = Timestamp;
+	type WeightInfo = pallet_evm::weights::SubstrateWeight<Test>;
+}
+
+impl pallet_evm_sponsorship::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type EvmRunner = pallet_evm::runner::stack::Runner<Self>;
+	type OnSponsoredFee = ();
+	type UnsignedPriority = ConstU64<100>;
+	type WeightInfo = ();
+}
+
+pub(crate) const CHAIN_ID: u64 = 1281;
+pub(crate) const GAS_PRICE: u64 = 10;
+
+pub(crate) const USER_KEY: [u8; 32] = [1u8; 32];
+pub(crate) const SPONSOR_KEY: [u8; 32] = [2u8; 32];
+pub(crate) const RECIPIENT: H160 = H160([0xcc; 20]);
+
+pub(crate) fn address_of(secret: &[u8; 32]) -> H160 {
+	let secret = libsecp256k1::SecretKey::parse(secret).expect("valid secret key");
+	let public_key = &libsecp256k1::PublicKey::from_secret_key(&secret).serialize()[1..65];
+	H160::from(H256::from(sp_io::hashing::keccak_256(public_key)))
+}
+
+fn sign(secret: &[u8; 32], message: &[u8; 32]) -> ([u8; 64], u8) {
+	let secret = libsecp256k1::SecretKey::parse(secret).expect("valid secret key");
+	let (signature, recovery_id) =
+		libsecp256k1::sign(&libsecp256k1::Message::parse(message), &secret);
+	(signature.serialize(), recovery_id.serialize())
+}
+
+/// EIP-1559 transaction of the user, transferring `value` to `RECIPIENT`.
+pub(crate) fn user_transaction(nonce: u64, value: u128, gas_limit: u64) -> EIP1559Transaction {
+	let message = EIP1559TransactionMessage {
+		chain_id: CHAIN_ID,
+		nonce: nonce.into(),
+		max_priority_fee_per_gas: U256::zero(),
+		max_fee_per_gas: U256::zero(),
+		gas_limit: gas_limit.into(),
+		action: TransactionAction::Call(RECIPIENT),
+		value: value.into(),
+		input: Vec::new(),
+		access_list: Vec::new(),
+	};
+	let (rs, v) = sign(&USER_KEY, message.hash().as_fixed_bytes());
+
+	EIP1559Transaction {
+		chain_id: message.chain_id,
+		nonce: message.nonce,
+		max_priority_fee_per_gas: message.max_priority_fee_per_gas,
+		max_fee_per_gas: message.max_fee_per_gas,
+		gas_limit: message.gas_limit,
+		action: message.action,
+		value: message.value,
+		input: message.input,
+		access_list: message.access_list,
+		odd_y_parity: v != 0,
+		r: H256::from_slice(&rs[0..32]),
+		s: H256::from_slice(&rs[32..64]),
+	}
+}
+
+/// Envelope of the sponsor covering `transaction` for at most `max_fee`.
+pub(crate) fn sponsor_envelope(
+	secret: &[u8; 32],
+	transaction: &EIP1559Transaction,
+	max_fee: u128,
+) -> SponsorEnvelope {
+	let message = crate::sponsor_message(
+		CHAIN_ID,
+		crate::transaction_hash(transaction),
+		max_fee.into(),
+	);
+	let (rs, v) = sign(secret, &message);
+
+	let mut signature = [0u8; 65];
+	signature[0..64].copy_from_slice(&rs);
+	signature[64] = v;
+	SponsorEnvelope {
+		max_fee: max_fee.into(),
+		signature,
+	}
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder {
+	balances: Vec<(AccountId, Balance)>,
+}
+
+impl ExtBuilder {
+	pub(crate) fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Test>()
+			.expect("Frame system builds valid default genesis config");
+
+		pallet_balances::GenesisConfig::<Test> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.expect("Pallet balances storage can be assimilated");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+pub(crate) fn events() -> Vec<pallet_evm_sponsorship::Event<Test>> {
+	System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| {
+			if let RuntimeEvent::EvmSponsorship(inner) = e {
+				Some(inner)
+			} else {
+				None
+			}
+		})
+		.collect::<Vec<_>>()
+}
+
+pub fn expect_events(e: Vec<pallet_evm_sponsorship::Event<Test>>) {
+	assert_eq!(events(), e);
+}
@@ -0,0 +1,229 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{Call, Error, Event, SponsorEnvelope};
+use ethereum::{EIP1559Transaction, TransactionAction};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo};
+use sp_core::{H160, U256};
+use sp_runtime::{
+	traits::ValidateUnsigned,
+	transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidity},
+};
+
+const SIMPLE_TRANSFER_GAS: u64 = 21_000;
+const SIMPLE_TRANSFER_FEE: u128 = SIMPLE_TRANSFER_GAS as u128 * GAS_PRICE as u128;
+
+fn transact(
+	transaction: EIP1559Transaction,
+	envelope: SponsorEnvelope,
+) -> DispatchResultWithPostInfo {
+	EvmSponsorship::transact_sponsored(RuntimeOrigin::none(), transaction, envelope)
+}
+
+fn validate(transaction: EIP1559Transaction, envelope: SponsorEnvelope) -> TransactionValidity {
+	EvmSponsorship::validate_unsigned(
+		TransactionSource::External,
+		&Call::transact_sponsored {
+			transaction,
+			envelope,
+		},
+	)
+}
+
+fn user() -> H160 {
+	address_of(&USER_KEY)
+}
+
+fn sponsor() -> H160 {
+	address_of(&SPONSOR_KEY)
+}
+
+#[test]
+fn sponsor_pays_for_the_gas_of_the_user() {
+	ExtBuilder::default()
+		.with_balances(vec![(user(), 100), (sponsor(), 1_000_000)])
+		.build()
+		.execute_with(|| {
+			let transaction = user_transaction(0, 40, SIMPLE_TRANSFER_GAS);
+			let envelope = sponsor_envelope(&SPONSOR_KEY, &transaction, SIMPLE_TRANSFER_FEE);
+
+			assert_ok!(transact(transaction.clone(), envelope));
+
+			assert_eq!(Balances::free_balance(user()), 60);
+			assert_eq!(Balances::free_balance(RECIPIENT), 40);
+			assert_eq!(
+				Balances::free_balance(sponsor()),
+				1_000_000 - SIMPLE_TRANSFER_FEE
+			);
+			assert_eq!(EVM::account_basic(&user()).0.nonce, U256::one());
+			assert_eq!(
+				events(),
+				vec![Event::SponsoredTransactionExecuted {
+					sponsor: sponsor(),
+					source: user(),
+					target: RECIPIENT,
+					transaction_hash: crate::transaction_hash(&transaction),
+					fee: SIMPLE_TRANSFER_FEE.into(),
+					succeeded: true,
+				}]
+			);
+		});
+}
+
+#[test]
+fn unused_fee_is_refunded_to_the_sponsor() {
+	ExtBuilder::default()
+		.with_balances(vec![(sponsor(), 1_000_000)])
+		.build()
+		.execute_with(|| {
+			let transaction = user_transaction(0, 0, 50_000);
+			let envelope = sponsor_envelope(&SPONSOR_KEY, &transaction, 600_000);
+
+			assert_ok!(transact(transaction, envelope));
+
+			assert_eq!(
+				Balances::free_balance(sponsor()),
+				1_000_000 - SIMPLE_TRANSFER_FEE
+			);
+		});
+}
+
+#[test]
+fn max_fee_must_cover_the_gas_limit() {
+	ExtBuilder::default()
+		.with_balances(vec![(sponsor(), 1_000_000)])
+		.build()
+		.execute_with(|| {
+			let transaction = user_transaction(0, 0, SIMPLE_TRANSFER_GAS);
+			let envelope = sponsor_envelope(&SPONSOR_KEY, &transaction, SIMPLE_TRANSFER_FEE - 1);
+
+			assert_eq!(
+				validate(transaction.clone(), envelope.clone()),
+				InvalidTransaction::Payment.into()
+			);
+			assert_noop!(transact(transaction, envelope), Error::<Test>::MaxFeeTooLow);
+		});
+}
+
+#[test]
+fn sponsor_must_afford_the_max_fee() {
+	ExtBuilder::default()
+		.with_balances(vec![(sponsor(), SIMPLE_TRANSFER_FEE - 1)])
+		.build()
+		.execute_with(|| {
+			let transaction = user_transaction(0, 0, SIMPLE_TRANSFER_GAS);
+			let envelope = sponsor_envelope(&SPONSOR_KEY, &transaction, SIMPLE_TRANSFER_FEE);
+
+			assert_eq!(
+				validate(transaction.clone(), envelope.clone()),
+				InvalidTransaction::Payment.into()
+			);
+			assert_noop!(
+				transact(transaction, envelope),
+				Error::<Test>::SponsorBalanceTooLow
+			);
+		});
+}
+
+#[test]
+fn envelope_only_covers_the_signed_transaction() {
+	ExtBuilder::default()
+		.with_balances(vec![(sponsor(), 1_000_000)])
+		.build()
+		.execute_with(|| {
+			let sponsored = user_transaction(0, 0, SIMPLE_TRANSFER_GAS);
+			let envelope = sponsor_envelope(&SPONSOR_KEY, &sponsored, SIMPLE_TRANSFER_FEE);
+
+			// The envelope recovers another (unfunded) sponsor for any other transaction.
+			let other = user_transaction(0, 1, SIMPLE_TRANSFER_GAS);
+			assert_noop!(
+				transact(other, envelope),
+				Error::<Test>::SponsorBalanceTooLow
+			);
+		});
+}
+
+#[test]
+fn transactions_of_other_chains_are_rejected() {
+	ExtBuilder::default()
+		.with_balances(vec![(sponsor(), 1_000_000)])
+		.build()
+		.execute_with(|| {
+			let mut transaction = user_transaction(0, 0, SIMPLE_TRANSFER_GAS);
+			transaction.chain_id = CHAIN_ID + 1;
+			let envelope = sponsor_envelope(&SPONSOR_KEY, &transaction, SIMPLE_TRANSFER_FEE);
+
+			assert_eq!(
+				validate(transaction.clone(), envelope.clone()),
+				InvalidTransaction::Call.into()
+			);
+			assert_noop!(
+				transact(transaction, envelope),
+				Error::<Test>::InvalidChainId
+			);
+		});
+}
+
+#[test]
+fn contract_creations_cannot_be_sponsored() {
+	ExtBuilder::default()
+		.with_balances(vec![(sponsor(), 1_000_000)])
+		.build()
+		.execute_with(|| {
+			let mut transaction = user_transaction(0, 0, SIMPLE_TRANSFER_GAS);
+			transaction.action = TransactionAction::Create;
+			let envelope = sponsor_envelope(&SPONSOR_KEY, &transaction, SIMPLE_TRANSFER_FEE);
+
+			assert_noop!(
+				transact(transaction, envelope),
+				Error::<Test>::ContractCreationNotSupported
+			);
+		});
+}
+
+#[test]
+fn validation_orders_transactions_by_user_nonce() {
+	ExtBuilder::default()
+		.with_balances(vec![(sponsor(), 1_000_000)])
+		.build()
+		.execute_with(|| {
+			let first = user_transaction(0, 0, SIMPLE_TRANSFER_GAS);
+			let first_envelope = sponsor_envelope(&SPONSOR_KEY, &first, SIMPLE_TRANSFER_FEE);
+			let second = user_transaction(1, 0, SIMPLE_TRANSFER_GAS);
+			let second_envelope = sponsor_envelope(&SPONSOR_KEY, &second, SIMPLE_TRANSFER_FEE);
+
+			let valid = validate(first.clone(), first_envelope.clone()).expect("valid");
+			assert!(valid.requires.is_empty());
+			let valid = validate(second.clone(), second_envelope.clone()).expect("valid");
+			assert_eq!(valid.requires.len(), 1);
+
+			assert_ok!(transact(first.clone(), first_envelope.clone()));
+
+			// The first transaction cannot be replayed.
+			assert_eq!(
+				validate(first.clone(), first_envelope.clone()),
+				InvalidTransaction::Stale.into()
+			);
+			assert_noop!(
+				transact(first, first_envelope),
+				Error::<Test>::EvmCallFailed
+			);
+
+			let valid = validate(second, second_envelope).expect("valid");
+			assert!(valid.requires.is_empty());
+		});
+}
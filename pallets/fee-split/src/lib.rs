@@ -0,0 +1,144 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Fee split pallet
+//!
+//! Governance-settable split of the transaction fees between burning and the treasury. The fee
+//! handler of the runtime reads [`Pallet::fee_split`] for every fee it processes: the burned share
+//! is dropped, decreasing the total issuance, and the remaining share is sent to the treasury.
+//!
+//! The burned share can only be set within [`Config::MinBurnPercent`] and
+//! [`Config::MaxBurnPercent`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use fee_split_runtime_api::FeeSplit;
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::pallet;
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{PerThing, Percent};
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that is allowed to change the fee split.
+		type SetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Lowest share of the fees that can be burned.
+		#[pallet::constant]
+		type MinBurnPercent: Get<Percent>;
+
+		/// Highest share of the fees that can be burned.
+		#[pallet::constant]
+		type MaxBurnPercent: Get<Percent>;
+
+		/// Share of the fees that is burned until governance sets another one.
+		#[pallet::constant]
+		type DefaultBurnPercent: Get<Percent>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::type_value]
+	pub fn BurnPercentOnEmpty<T: Config>() -> Percent {
+		T::DefaultBurnPercent::get()
+	}
+
+	/// Share of the fees that is burned, the remaining share being sent to the treasury.
+	#[pallet::storage]
+	#[pallet::getter(fn burn_percent)]
+	pub type BurnPercent<T: Config> = StorageValue<_, Percent, ValueQuery, BurnPercentOnEmpty<T>>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The burned share is outside of the configured bounds.
+		BurnPercentOutOfBounds,
+		/// The burned share is already set to this value.
+		NoWritingSameValue,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The share of the fees that is burned was changed.
+		BurnPercentSet { old: Percent, new: Percent },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the share of the fees that is burned, the remaining share being sent to the
+		/// treasury.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_burn_percent())]
+		pub fn set_burn_percent(origin: OriginFor<T>, new: Percent) -> DispatchResult {
+			T::SetOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				new >= T::MinBurnPercent::get() && new <= T::MaxBurnPercent::get(),
+				Error::<T>::BurnPercentOutOfBounds
+			);
+			let old = BurnPercent::<T>::get();
+			ensure!(old != new, Error::<T>::NoWritingSameValue);
+
+			BurnPercent::<T>::put(new);
+
+			Self::deposit_event(Event::BurnPercentSet { old, new });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Current split of the fees between burning and the treasury.
+		pub fn fee_split() -> FeeSplit {
+			let burn = BurnPercent::<T>::get();
+
+			FeeSplit {
+				burn,
+				treasury: burn.left_from_one(),
+			}
+		}
+
+		/// Burned and treasury parts of the fees, as expected by `Imbalance::ration`.
+		pub fn ration() -> (u32, u32) {
+			let FeeSplit { burn, treasury } = Self::fee_split();
+
+			(burn.deconstruct().into(), treasury.deconstruct().into())
+		}
+	}
+}
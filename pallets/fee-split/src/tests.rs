@@ -0,0 +1,107 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{Error, Event, FeeSplit as Split};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError, Percent};
+
+#[test]
+fn default_split_burns_default_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			FeeSplit::fee_split(),
+			Split {
+				burn: Percent::from_percent(80),
+				treasury: Percent::from_percent(20),
+			}
+		);
+		assert_eq!(FeeSplit::ration(), (80, 20));
+	});
+}
+
+#[test]
+fn set_burn_percent_requires_set_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			FeeSplit::set_burn_percent(RuntimeOrigin::signed(1), Percent::from_percent(50)),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_burn_percent_updates_split() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(FeeSplit::set_burn_percent(
+			RuntimeOrigin::root(),
+			Percent::from_percent(50)
+		));
+
+		assert_eq!(FeeSplit::burn_percent(), Percent::from_percent(50));
+		assert_eq!(
+			FeeSplit::fee_split(),
+			Split {
+				burn: Percent::from_percent(50),
+				treasury: Percent::from_percent(50),
+			}
+		);
+		assert_eq!(FeeSplit::ration(), (50, 50));
+		expect_events(vec![Event::BurnPercentSet {
+			old: Percent::from_percent(80),
+			new: Percent::from_percent(50),
+		}]);
+	});
+}
+
+#[test]
+fn set_burn_percent_accepts_bounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(FeeSplit::set_burn_percent(
+			RuntimeOrigin::root(),
+			Percent::from_percent(10)
+		));
+		assert_ok!(FeeSplit::set_burn_percent(
+			RuntimeOrigin::root(),
+			Percent::from_percent(90)
+		));
+		assert_eq!(FeeSplit::burn_percent(), Percent::from_percent(90));
+	});
+}
+
+#[test]
+fn set_burn_percent_rejects_out_of_bounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			FeeSplit::set_burn_percent(RuntimeOrigin::root(), Percent::from_percent(9)),
+			Error::<Test>::BurnPercentOutOfBounds
+		);
+		assert_noop!(
+			FeeSplit::set_burn_percent(RuntimeOrigin::root(), Percent::from_percent(91)),
+			Error::<Test>::BurnPercentOutOfBounds
+		);
+	});
+}
+
+#[test]
+fn set_burn_percent_rejects_same_value() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			FeeSplit::set_burn_percent(RuntimeOrigin::root(), Percent::from_percent(80)),
+			Error::<Test>::NoWritingSameValue
+		);
+	});
+}
@@ -0,0 +1,157 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Governance origin registry pallet
+//!
+//! This pallet records the EVM addresses the well-known governance origins (root, general admin
+//! and treasurer) dispatch EVM calls as, such as the callers allowed by the restricted selectors
+//! of some precompiles. Smart contracts can then check whether a caller acts for governance
+//! through a precompile, instead of hardcoding the derived addresses.
+//!
+//! Each address is registered for at most one origin, and each origin has at most one address.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::{pallet, pallet_prelude::*};
+use sp_core::H160;
+
+/// A well-known governance origin.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub enum GovernanceOrigin {
+	/// Root, dispatched by the root track of the referenda.
+	Root = 0,
+	/// The general admin track of the referenda.
+	GeneralAdmin = 1,
+	/// The treasurer track of the referenda.
+	Treasurer = 2,
+}
+
+impl TryFrom<u8> for GovernanceOrigin {
+	type Error = ();
+
+	fn try_from(value: u8) -> Result<Self, Self::Error> {
+		match value {
+			0 => Ok(GovernanceOrigin::Root),
+			1 => Ok(GovernanceOrigin::GeneralAdmin),
+			2 => Ok(GovernanceOrigin::Treasurer),
+			_ => Err(()),
+		}
+	}
+}
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that is allowed to register the addresses of the governance origins.
+		type RegistrarOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// EVM address each governance origin dispatches as.
+	#[pallet::storage]
+	#[pallet::getter(fn address_of)]
+	pub type OriginAddresses<T: Config> =
+		StorageMap<_, Twox64Concat, GovernanceOrigin, H160, OptionQuery>;
+
+	/// Governance origin of each registered EVM address.
+	#[pallet::storage]
+	#[pallet::getter(fn origin_of)]
+	pub type AddressOrigins<T: Config> =
+		StorageMap<_, Blake2_128Concat, H160, GovernanceOrigin, OptionQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The address is already registered for another governance origin.
+		AddressAlreadyRegistered,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The address of a governance origin changed, `None` removing it.
+		OriginAddressSet {
+			origin: GovernanceOrigin,
+			address: Option<H160>,
+		},
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the EVM address a governance origin dispatches as, or remove it with `None`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_origin_address())]
+		pub fn set_origin_address(
+			origin: OriginFor<T>,
+			governance_origin: GovernanceOrigin,
+			address: Option<H160>,
+		) -> DispatchResult {
+			T::RegistrarOrigin::ensure_origin(origin)?;
+
+			if let Some(address) = address {
+				ensure!(
+					AddressOrigins::<T>::get(address)
+						.map_or(true, |registered| registered == governance_origin),
+					Error::<T>::AddressAlreadyRegistered
+				);
+			}
+
+			if let Some(previous) = OriginAddresses::<T>::take(governance_origin) {
+				AddressOrigins::<T>::remove(previous);
+			}
+			if let Some(address) = address {
+				OriginAddresses::<T>::insert(governance_origin, address);
+				AddressOrigins::<T>::insert(address, governance_origin);
+			}
+
+			Self::deposit_event(Event::OriginAddressSet {
+				origin: governance_origin,
+				address,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether the address is the address of a governance origin.
+		pub fn is_governance_origin(address: H160) -> bool {
+			AddressOrigins::<T>::contains_key(address)
+		}
+	}
+}
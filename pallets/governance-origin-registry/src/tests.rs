@@ -0,0 +1,145 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{Error, Event, GovernanceOrigin, Pallet};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn set_and_remove_origin_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(GovernanceOriginRegistry::set_origin_address(
+			RuntimeOrigin::root(),
+			GovernanceOrigin::Treasurer,
+			Some(ALICE)
+		));
+		assert_eq!(
+			Pallet::<Test>::address_of(GovernanceOrigin::Treasurer),
+			Some(ALICE)
+		);
+		assert_eq!(
+			Pallet::<Test>::origin_of(ALICE),
+			Some(GovernanceOrigin::Treasurer)
+		);
+		assert!(Pallet::<Test>::is_governance_origin(ALICE));
+		assert!(!Pallet::<Test>::is_governance_origin(BOB));
+
+		assert_ok!(GovernanceOriginRegistry::set_origin_address(
+			RuntimeOrigin::root(),
+			GovernanceOrigin::Treasurer,
+			None
+		));
+		assert_eq!(
+			Pallet::<Test>::address_of(GovernanceOrigin::Treasurer),
+			None
+		);
+		assert!(!Pallet::<Test>::is_governance_origin(ALICE));
+
+		expect_events(vec![
+			Event::OriginAddressSet {
+				origin: GovernanceOrigin::Treasurer,
+				address: Some(ALICE),
+			},
+			Event::OriginAddressSet {
+				origin: GovernanceOrigin::Treasurer,
+				address: None,
+			},
+		]);
+	});
+}
+
+#[test]
+fn changing_origin_address_unregisters_previous_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(GovernanceOriginRegistry::set_origin_address(
+			RuntimeOrigin::root(),
+			GovernanceOrigin::Root,
+			Some(ALICE)
+		));
+		assert_ok!(GovernanceOriginRegistry::set_origin_address(
+			RuntimeOrigin::root(),
+			GovernanceOrigin::Root,
+			Some(BOB)
+		));
+
+		assert_eq!(
+			Pallet::<Test>::address_of(GovernanceOrigin::Root),
+			Some(BOB)
+		);
+		assert_eq!(Pallet::<Test>::origin_of(ALICE), None);
+		assert_eq!(Pallet::<Test>::origin_of(BOB), Some(GovernanceOrigin::Root));
+	});
+}
+
+#[test]
+fn address_cannot_be_registered_for_two_origins() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(GovernanceOriginRegistry::set_origin_address(
+			RuntimeOrigin::root(),
+			GovernanceOrigin::Root,
+			Some(ALICE)
+		));
+
+		assert_noop!(
+			GovernanceOriginRegistry::set_origin_address(
+				RuntimeOrigin::root(),
+				GovernanceOrigin::GeneralAdmin,
+				Some(ALICE)
+			),
+			Error::<Test>::AddressAlreadyRegistered
+		);
+
+		// Registering the same address again is a no-op
+		assert_ok!(GovernanceOriginRegistry::set_origin_address(
+			RuntimeOrigin::root(),
+			GovernanceOrigin::Root,
+			Some(ALICE)
+		));
+		assert_eq!(
+			Pallet::<Test>::origin_of(ALICE),
+			Some(GovernanceOrigin::Root)
+		);
+	});
+}
+
+#[test]
+fn set_origin_address_requires_registrar_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			GovernanceOriginRegistry::set_origin_address(
+				RuntimeOrigin::signed(1),
+				GovernanceOrigin::Root,
+				Some(ALICE)
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn governance_origin_from_u8() {
+	assert_eq!(GovernanceOrigin::try_from(0u8), Ok(GovernanceOrigin::Root));
+	assert_eq!(
+		GovernanceOrigin::try_from(1u8),
+		Ok(GovernanceOrigin::GeneralAdmin)
+	);
+	assert_eq!(
+		GovernanceOrigin::try_from(2u8),
+		Ok(GovernanceOrigin::Treasurer)
+	);
+	assert_eq!(GovernanceOrigin::try_from(3u8), Err(()));
+}
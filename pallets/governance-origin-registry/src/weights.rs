@@ -0,0 +1,60 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_governance_origin_registry`
+//!
+//! These weights have not been benchmarked yet. They are hand-written, deliberately
+//! conservative estimates whose proof sizes follow the storage bounds; regenerate them
+//! with `moonbeam benchmark pallet` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_governance_origin_registry.
+pub trait WeightInfo {
+	fn set_origin_address() -> Weight;
+}
+
+/// Weights for pallet_governance_origin_registry using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: GovernanceOriginRegistry AddressOrigins (r:1 w:2)
+	/// Proof: GovernanceOriginRegistry AddressOrigins (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: GovernanceOriginRegistry OriginAddresses (r:1 w:1)
+	/// Proof: GovernanceOriginRegistry OriginAddresses (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	fn set_origin_address() -> Weight {
+		Weight::from_parts(39_000_000, 3502)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: GovernanceOriginRegistry AddressOrigins (r:1 w:2)
+	/// Proof: GovernanceOriginRegistry AddressOrigins (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: GovernanceOriginRegistry OriginAddresses (r:1 w:1)
+	/// Proof: GovernanceOriginRegistry OriginAddresses (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	fn set_origin_address() -> Weight {
+		Weight::from_parts(39_000_000, 3502)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
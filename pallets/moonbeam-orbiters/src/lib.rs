@@ -25,6 +25,9 @@
 //! In order not to impact the other pallets (notably nimbus and parachain-staking) this pallet
 //! simply redefines the lookup NimbusId-> AccountId, in order to replace the collator by its
 //! currently selected orbiter.
+//!
+//! For each orbiter, the pallet keeps the number of rounds it served and the rewards it earned,
+//! along with its most recent rewarded rounds, so the fairness of the rotation can be audited.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -44,6 +47,8 @@ pub use weights::WeightInfo;
 
 use frame_support::pallet;
 use nimbus_primitives::{AccountLookup, NimbusId};
+use orbiters_runtime_api::{OrbiterAuditSummary, OrbiterRoundRewards};
+use sp_std::vec::Vec;
 
 #[pallet]
 pub mod pallet {
@@ -82,6 +87,10 @@ pub mod pallet {
 		/// Origin that is allowed to remove a collator from orbiters program.
 		type DelCollatorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		#[pallet::constant]
+		/// Maximum number of rewarded rounds to keep in the audit history of each orbiter.
+		type MaxAuditRecords: Get<u32>;
+
 		#[pallet::constant]
 		/// Maximum number of orbiters per collator.
 		type MaxPoolSize: Get<u32>;
@@ -157,6 +166,16 @@ pub mod pallet {
 	/// Check if account is an orbiter
 	pub type RegisteredOrbiter<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool>;
 
+	#[pallet::storage]
+	/// Rounds served and rewards earned by each orbiter that was ever selected
+	pub(crate) type OrbiterAudit<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		OrbiterAuditInfo<T::AccountId, T::RoundIndex, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub min_orbiter_deposit: BalanceOf<T>,
@@ -489,6 +508,13 @@ pub mod pallet {
 						);
 						writes += 1;
 
+						OrbiterAudit::<T>::mutate(&next_orbiter, |maybe_audit| {
+							maybe_audit
+								.get_or_insert_with(Default::default)
+								.add_rounds_served(T::RotatePeriod::get())
+						});
+						writes += 1;
+
 						let mut i = Zero::zero();
 						while i < T::RotatePeriod::get() {
 							OrbiterPerRound::<T>::insert(
@@ -544,6 +570,18 @@ pub mod pallet {
 		) -> Weight {
 			if let Some(orbiter) = OrbiterPerRound::<T>::take(pay_for_round, &collator) {
 				if T::Currency::deposit_into_existing(&orbiter, amount).is_ok() {
+					OrbiterAudit::<T>::mutate(&orbiter, |maybe_audit| {
+						maybe_audit
+							.get_or_insert_with(Default::default)
+							.add_rewards(
+								OrbiterRewardRecord {
+									round: pay_for_round,
+									collator,
+									rewards: amount,
+								},
+								T::MaxAuditRecords::get(),
+							)
+					});
 					Self::deposit_event(Event::OrbiterRewarded {
 						account: orbiter,
 						rewards: amount,
@@ -560,6 +598,42 @@ pub mod pallet {
 		pub fn is_orbiter(for_round: T::RoundIndex, collator: T::AccountId) -> bool {
 			OrbiterPerRound::<T>::contains_key(for_round, &collator)
 		}
+
+		/// Summarize the rounds served and rewards earned by an orbiter, if it was ever selected
+		pub fn orbiter_audit(
+			orbiter: T::AccountId,
+		) -> Option<OrbiterAuditSummary<T::AccountId, T::RoundIndex, BalanceOf<T>>> {
+			OrbiterAudit::<T>::get(orbiter).map(Self::build_audit_summary)
+		}
+
+		/// Summarize the rounds served and rewards earned by all orbiters that were ever selected
+		pub fn orbiters_audit() -> Vec<(
+			T::AccountId,
+			OrbiterAuditSummary<T::AccountId, T::RoundIndex, BalanceOf<T>>,
+		)> {
+			OrbiterAudit::<T>::iter()
+				.map(|(orbiter, audit)| (orbiter, Self::build_audit_summary(audit)))
+				.collect()
+		}
+
+		fn build_audit_summary(
+			audit: OrbiterAuditInfo<T::AccountId, T::RoundIndex, BalanceOf<T>>,
+		) -> OrbiterAuditSummary<T::AccountId, T::RoundIndex, BalanceOf<T>> {
+			OrbiterAuditSummary {
+				rounds_served: audit.rounds_served,
+				rounds_rewarded: audit.rounds_rewarded,
+				total_rewards: audit.total_rewards,
+				recent_rewards: audit
+					.recent_records()
+					.into_iter()
+					.map(|record| OrbiterRoundRewards {
+						round: record.round,
+						collator: record.collator,
+						rewards: record.rewards,
+					})
+					.collect(),
+			}
+		}
 	}
 }
 
@@ -132,6 +132,8 @@ impl pallet_moonbeam_orbiters::Config for Test {
 	type AddCollatorOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type DelCollatorOrigin = EnsureRoot<AccountId>;
+	/// Maximum number of rewarded rounds kept in the audit history of each orbiter
+	type MaxAuditRecords = ConstU32<2>;
 	/// Maximum number of orbiters per collator
 	type MaxPoolSize = ConstU32<2>;
 	/// Maximum number of round to keep on storage
@@ -19,6 +19,7 @@
 use crate::mock::{roll_to, ExtBuilder, MoonbeamOrbiters, RuntimeOrigin, System, Test};
 use crate::{Error, Event};
 use frame_support::{assert_noop, assert_ok};
+use orbiters_runtime_api::{OrbiterAuditSummary, OrbiterRoundRewards};
 
 #[test]
 fn test_orbiter_rotation() {
@@ -335,3 +336,74 @@ fn test_orbiter_unregister() {
 			System::assert_last_event(Event::<Test>::OrbiterUnregistered { account: 2 }.into());
 		});
 }
+
+#[test]
+fn test_orbiter_audit() {
+	ExtBuilder::default()
+		.with_balances(vec![(2, 20_000), (3, 20_000)])
+		.with_min_orbiter_deposit(10_000)
+		.build()
+		.execute_with(|| {
+			// Add a collator to the orbiter program
+			assert_ok!(MoonbeamOrbiters::add_collator(RuntimeOrigin::root(), 1),);
+			// Register two orbiters
+			assert_ok!(MoonbeamOrbiters::orbiter_register(RuntimeOrigin::signed(2)),);
+			assert_ok!(MoonbeamOrbiters::collator_add_orbiter(
+				RuntimeOrigin::signed(1),
+				2
+			),);
+			assert_ok!(MoonbeamOrbiters::orbiter_register(RuntimeOrigin::signed(3)),);
+			assert_ok!(MoonbeamOrbiters::collator_add_orbiter(
+				RuntimeOrigin::signed(1),
+				3
+			),);
+
+			// No orbiter was selected yet
+			assert_eq!(MoonbeamOrbiters::orbiter_audit(2), None);
+			assert!(MoonbeamOrbiters::orbiters_audit().is_empty());
+
+			// Orbiter 2 serves rounds 2 and 3
+			roll_to(4);
+			MoonbeamOrbiters::distribute_rewards(2, 1, 100);
+			MoonbeamOrbiters::distribute_rewards(3, 1, 200);
+
+			// Orbiter 3 serves rounds 4 and 5, but produces no block
+			roll_to(8);
+
+			// Orbiter 2 serves rounds 6 and 7
+			roll_to(12);
+			MoonbeamOrbiters::distribute_rewards(6, 1, 300);
+
+			// Only the two most recent rewarded rounds are kept
+			assert_eq!(
+				MoonbeamOrbiters::orbiter_audit(2),
+				Some(OrbiterAuditSummary {
+					rounds_served: 4,
+					rounds_rewarded: 3,
+					total_rewards: 600,
+					recent_rewards: vec![
+						OrbiterRoundRewards {
+							round: 3,
+							collator: 1,
+							rewards: 200,
+						},
+						OrbiterRoundRewards {
+							round: 6,
+							collator: 1,
+							rewards: 300,
+						},
+					],
+				})
+			);
+			assert_eq!(
+				MoonbeamOrbiters::orbiter_audit(3),
+				Some(OrbiterAuditSummary {
+					rounds_served: 2,
+					rounds_rewarded: 0,
+					total_rewards: 0,
+					recent_rewards: vec![],
+				})
+			);
+			assert_eq!(MoonbeamOrbiters::orbiters_audit().len(), 2);
+		});
+}
@@ -17,6 +17,7 @@
 use scale_info::TypeInfo;
 use sp_runtime::{
 	codec::{Decode, Encode},
+	traits::{One, Saturating, Zero},
 	RuntimeDebug,
 };
 use sp_std::vec::Vec;
@@ -169,3 +170,70 @@ impl<AccountId: Ord> RoundAuthors<AccountId> {
 		(data, blocks_count)
 	}
 }
+
+#[derive(Clone, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OrbiterRewardRecord<AccountId, RoundIndex, Balance> {
+	pub round: RoundIndex,
+	pub collator: AccountId,
+	pub rewards: Balance,
+}
+
+/// Rotation and rewards history of an orbiter. The most recent rewarded rounds are kept in a
+/// ring buffer: once full, each new record overwrites the oldest one.
+#[derive(Clone, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OrbiterAuditInfo<AccountId, RoundIndex, Balance> {
+	pub rounds_served: RoundIndex,
+	pub rounds_rewarded: RoundIndex,
+	pub total_rewards: Balance,
+	records: Vec<OrbiterRewardRecord<AccountId, RoundIndex, Balance>>,
+	next_record: u32,
+}
+
+impl<AccountId, RoundIndex: Zero, Balance: Zero> Default
+	for OrbiterAuditInfo<AccountId, RoundIndex, Balance>
+{
+	fn default() -> Self {
+		Self {
+			rounds_served: Zero::zero(),
+			rounds_rewarded: Zero::zero(),
+			total_rewards: Zero::zero(),
+			records: Vec::new(),
+			next_record: 0,
+		}
+	}
+}
+
+impl<AccountId: Clone, RoundIndex: Copy + One + Saturating, Balance: Copy + Saturating>
+	OrbiterAuditInfo<AccountId, RoundIndex, Balance>
+{
+	pub(super) fn add_rounds_served(&mut self, rounds: RoundIndex) {
+		self.rounds_served = self.rounds_served.saturating_add(rounds);
+	}
+	pub(super) fn add_rewards(
+		&mut self,
+		record: OrbiterRewardRecord<AccountId, RoundIndex, Balance>,
+		max_records: u32,
+	) {
+		self.rounds_rewarded = self.rounds_rewarded.saturating_add(One::one());
+		self.total_rewards = self.total_rewards.saturating_add(record.rewards);
+
+		let len = self.records.len() as u32;
+		if len < max_records {
+			self.records.push(record);
+		} else if len > 0 {
+			let index = self.next_record % len;
+			self.records[index as usize] = record;
+			self.next_record = (index + 1) % len;
+		}
+	}
+	/// Most recent rewarded rounds, oldest first
+	pub fn recent_records(&self) -> Vec<OrbiterRewardRecord<AccountId, RoundIndex, Balance>> {
+		let split = if (self.next_record as usize) < self.records.len() {
+			self.next_record as usize
+		} else {
+			0
+		};
+		let (newest, oldest) = self.records.split_at(split);
+		oldest.iter().chain(newest.iter()).cloned().collect()
+	}
+}
@@ -193,14 +193,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: MoonbeamOrbiters OrbiterPerRound (max_values: None, max_size: None, mode: Measured)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: MoonbeamOrbiters OrbiterAudit (r:1 w:1)
+	/// Proof Skipped: MoonbeamOrbiters OrbiterAudit (max_values: None, max_size: None, mode: Measured)
 	fn distribute_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `260`
 		//  Estimated: `7306`
 		// Minimum execution time: 30_886_000 picoseconds.
 		Weight::from_parts(31_289_000, 7306)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: MoonbeamOrbiters ForceRotation (r:1 w:1)
 	/// Proof Skipped: MoonbeamOrbiters ForceRotation (max_values: Some(1), max_size: None, mode: Measured)
@@ -212,14 +214,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: MoonbeamOrbiters AccountLookupOverride (max_values: None, max_size: None, mode: Measured)
 	/// Storage: MoonbeamOrbiters CurrentRound (r:0 w:1)
 	/// Proof Skipped: MoonbeamOrbiters CurrentRound (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: MoonbeamOrbiters OrbiterAudit (r:1 w:1)
+	/// Proof Skipped: MoonbeamOrbiters OrbiterAudit (max_values: None, max_size: None, mode: Measured)
 	fn on_new_round() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `218`
 		//  Estimated: `8515`
 		// Minimum execution time: 40_065_000 picoseconds.
 		Weight::from_parts(40_509_000, 8515)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 }
 
@@ -351,14 +355,16 @@ impl WeightInfo for () {
 	/// Proof Skipped: MoonbeamOrbiters OrbiterPerRound (max_values: None, max_size: None, mode: Measured)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: MoonbeamOrbiters OrbiterAudit (r:1 w:1)
+	/// Proof Skipped: MoonbeamOrbiters OrbiterAudit (max_values: None, max_size: None, mode: Measured)
 	fn distribute_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `260`
 		//  Estimated: `7306`
 		// Minimum execution time: 30_886_000 picoseconds.
 		Weight::from_parts(31_289_000, 7306)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: MoonbeamOrbiters ForceRotation (r:1 w:1)
 	/// Proof Skipped: MoonbeamOrbiters ForceRotation (max_values: Some(1), max_size: None, mode: Measured)
@@ -370,13 +376,15 @@ impl WeightInfo for () {
 	/// Proof Skipped: MoonbeamOrbiters AccountLookupOverride (max_values: None, max_size: None, mode: Measured)
 	/// Storage: MoonbeamOrbiters CurrentRound (r:0 w:1)
 	/// Proof Skipped: MoonbeamOrbiters CurrentRound (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: MoonbeamOrbiters OrbiterAudit (r:1 w:1)
+	/// Proof Skipped: MoonbeamOrbiters OrbiterAudit (max_values: None, max_size: None, mode: Measured)
 	fn on_new_round() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `218`
 		//  Estimated: `8515`
 		// Minimum execution time: 40_065_000 picoseconds.
 		Weight::from_parts(40_509_000, 8515)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 }
\ No newline at end of file
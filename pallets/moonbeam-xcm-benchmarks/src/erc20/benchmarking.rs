@@ -0,0 +1,116 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::Get;
+use pallet_xcm_benchmarks::new_executor;
+use sp_core::{H160, U256};
+use sp_std::vec;
+use xcm::latest::prelude::*;
+
+// Runtime code of the erc20 contract used by the benchmarks: any call returns `true`, as
+// expected from the `transfer` method of an erc20 contract.
+// PUSH1 0x01 PUSH1 0x00 MSTORE PUSH1 0x20 PUSH1 0x00 RETURN
+const ERC20_CONTRACT_CODE: [u8; 10] = [0x60, 0x01, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3];
+
+const ERC20_AMOUNT: u128 = 1_000_000_000_000_000_000;
+
+fn deploy_erc20<T: Config>() -> H160 {
+	let contract_address = H160::repeat_byte(0xee);
+	pallet_evm::Pallet::<T>::create_account(contract_address, ERC20_CONTRACT_CODE.to_vec());
+	contract_address
+}
+
+fn erc20_asset<T: Config>(contract_address: H160) -> MultiAsset {
+	let mut location = <T as pallet_erc20_xcm_bridge::Config>::Erc20MultilocationPrefix::get();
+	location
+		.push_interior(AccountKey20 {
+			network: None,
+			key: contract_address.0,
+		})
+		.expect("the erc20 multilocation prefix leaves room for the contract address");
+	(location, ERC20_AMOUNT).into()
+}
+
+fn account_location(name: &'static str) -> (H160, MultiLocation) {
+	let address: H160 = account(name, 0, 0);
+	let location = MultiLocation::new(
+		0,
+		X1(AccountKey20 {
+			network: None,
+			key: address.0,
+		}),
+	);
+	(address, location)
+}
+
+benchmarks! {
+	withdraw_erc20_asset {
+		let contract_address = deploy_erc20::<T>();
+		let asset = erc20_asset::<T>(contract_address);
+		let (_, holder) = account_location("holder");
+
+		let mut executor = new_executor::<T>(holder);
+		let xcm = Xcm(vec![WithdrawAsset(asset.clone().into())]);
+	}: {
+		pallet_erc20_xcm_bridge::with_erc20s_in_xcm_holding(vec![], || {
+			executor.bench_process(xcm)
+		})?;
+	}
+	verify {
+		assert!(executor.holding().ensure_contains(&asset.into()).is_ok());
+	}
+
+	deposit_erc20_asset {
+		let contract_address = deploy_erc20::<T>();
+		let asset = erc20_asset::<T>(contract_address);
+		let (holder, _) = account_location("holder");
+		let (_, beneficiary) = account_location("beneficiary");
+
+		let mut executor = new_executor::<T>(Default::default());
+		executor.set_holding(asset.clone().into());
+		let xcm = Xcm(vec![DepositAsset {
+			assets: Definite(asset.into()),
+			beneficiary,
+		}]);
+	}: {
+		pallet_erc20_xcm_bridge::with_erc20s_in_xcm_holding(
+			vec![(contract_address, holder, U256::from(ERC20_AMOUNT))],
+			|| executor.bench_process(xcm),
+		)?;
+	}
+	verify {
+		assert!(executor.holding().is_empty());
+	}
+
+	transfer_erc20_asset {
+		let contract_address = deploy_erc20::<T>();
+		let asset = erc20_asset::<T>(contract_address);
+		let (_, holder) = account_location("holder");
+		let (_, beneficiary) = account_location("beneficiary");
+
+		let mut executor = new_executor::<T>(holder);
+		let xcm = Xcm(vec![TransferAsset {
+			assets: asset.into(),
+			beneficiary,
+		}]);
+	}: {
+		pallet_erc20_xcm_bridge::with_erc20s_in_xcm_holding(vec![], || {
+			executor.bench_process(xcm)
+		})?;
+	}
+}
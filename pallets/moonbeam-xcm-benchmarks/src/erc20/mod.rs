@@ -0,0 +1,38 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks of the xcm instructions moving erc20 tokens through pallet-erc20-xcm-bridge.
+//!
+//! The benchmarks run the instructions against a deployed contract, so that they account for the
+//! actual cost of an evm call (runner setup, account and code loading). The execution of the
+//! erc20 `transfer` itself is not part of the benchmarked weight: it is bounded by the gas limit
+//! of the token, and charged on top of it.
+
+pub use pallet::*;
+
+pub mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>:
+		frame_system::Config + crate::Config + pallet_erc20_xcm_bridge::Config
+	{
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T, I = ()>(_);
+}
@@ -18,6 +18,8 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "runtime-benchmarks")]
+pub mod erc20;
 #[cfg(feature = "runtime-benchmarks")]
 pub mod generic;
 
@@ -0,0 +1,132 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `moonbeam_xcm_benchmarks_erc20`
+//!
+//! These weights have not been benchmarked yet. They are hand-written, deliberately
+//! conservative estimates whose proof sizes follow the storage bounds; regenerate them
+//! with `moonbeam benchmark pallet` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for moonbeam_xcm_benchmarks_erc20.
+pub trait WeightInfo {
+	fn withdraw_erc20_asset() -> Weight;
+	fn deposit_erc20_asset() -> Weight;
+	fn transfer_erc20_asset() -> Weight;
+}
+
+/// Weights for moonbeam_xcm_benchmarks_erc20 using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Erc20XcmBridge QuarantinedErc20s (r:1 w:0)
+	/// Proof: Erc20XcmBridge QuarantinedErc20s (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn withdraw_erc20_asset() -> Weight {
+		Weight::from_parts(28_000_000, 3501)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: Erc20XcmBridge QuarantinedErc20s (r:1 w:0)
+	/// Proof: Erc20XcmBridge QuarantinedErc20s (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Erc20XcmBridge Erc20TransferGasLimits (r:1 w:0)
+	/// Proof: Erc20XcmBridge Erc20TransferGasLimits (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Erc20XcmBridge Erc20TransferFailures (r:1 w:0)
+	/// Proof: Erc20XcmBridge Erc20TransferFailures (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodes (r:1 w:0)
+	/// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodesMetadata (r:1 w:1)
+	/// Proof Skipped: EVM AccountCodesMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn deposit_erc20_asset() -> Weight {
+		Weight::from_parts(130_000_000, 3732)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Erc20XcmBridge QuarantinedErc20s (r:1 w:0)
+	/// Proof: Erc20XcmBridge QuarantinedErc20s (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Erc20XcmBridge Erc20TransferGasLimits (r:1 w:0)
+	/// Proof: Erc20XcmBridge Erc20TransferGasLimits (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Erc20XcmBridge Erc20TransferFailures (r:1 w:0)
+	/// Proof: Erc20XcmBridge Erc20TransferFailures (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodes (r:1 w:0)
+	/// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodesMetadata (r:1 w:1)
+	/// Proof Skipped: EVM AccountCodesMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn transfer_erc20_asset() -> Weight {
+		Weight::from_parts(130_000_000, 3732)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Erc20XcmBridge QuarantinedErc20s (r:1 w:0)
+	/// Proof: Erc20XcmBridge QuarantinedErc20s (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn withdraw_erc20_asset() -> Weight {
+		Weight::from_parts(28_000_000, 3501)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: Erc20XcmBridge QuarantinedErc20s (r:1 w:0)
+	/// Proof: Erc20XcmBridge QuarantinedErc20s (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Erc20XcmBridge Erc20TransferGasLimits (r:1 w:0)
+	/// Proof: Erc20XcmBridge Erc20TransferGasLimits (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Erc20XcmBridge Erc20TransferFailures (r:1 w:0)
+	/// Proof: Erc20XcmBridge Erc20TransferFailures (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodes (r:1 w:0)
+	/// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodesMetadata (r:1 w:1)
+	/// Proof Skipped: EVM AccountCodesMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn deposit_erc20_asset() -> Weight {
+		Weight::from_parts(130_000_000, 3732)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Erc20XcmBridge QuarantinedErc20s (r:1 w:0)
+	/// Proof: Erc20XcmBridge QuarantinedErc20s (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Erc20XcmBridge Erc20TransferGasLimits (r:1 w:0)
+	/// Proof: Erc20XcmBridge Erc20TransferGasLimits (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Erc20XcmBridge Erc20TransferFailures (r:1 w:0)
+	/// Proof: Erc20XcmBridge Erc20TransferFailures (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodes (r:1 w:0)
+	/// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodesMetadata (r:1 w:1)
+	/// Proof Skipped: EVM AccountCodesMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn transfer_erc20_asset() -> Weight {
+		Weight::from_parts(130_000_000, 3732)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -21,6 +21,7 @@
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
 use xcm::latest::MultiAsset;
+use super::erc20::{SubstrateWeight as Erc20Weight, WeightInfo as _};
 
 // Values copied from statemint benchmarks
 const ASSET_BURN_MAX_PROOF_SIZE: u64 = 7242;
@@ -32,22 +33,29 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config + pallet_erc20_xcm_bridge::Config> WeightInfo<T> {
 	pub(crate) fn withdraw_asset(asset: &MultiAsset) -> Weight {
 		if pallet_erc20_xcm_bridge::Pallet::<T>::is_erc20_asset(asset) {
-			pallet_erc20_xcm_bridge::Pallet::<T>::weight_of_erc20_transfer(&asset.id)
-			
+			// The EVM transfer happens on deposit, but its gas budget is charged here where
+			// the asset is known
+			Erc20Weight::<T>::withdraw_erc20_asset().saturating_add(
+				pallet_erc20_xcm_bridge::Pallet::<T>::weight_of_erc20_transfer(&asset.id),
+			)
 		} else {
 			Weight::from_parts(200_000_000 as u64, ASSET_BURN_MAX_PROOF_SIZE)
 		}
 	}
 	pub(crate) fn transfer_asset(asset: &MultiAsset) -> Weight {
 		if pallet_erc20_xcm_bridge::Pallet::<T>::is_erc20_asset(asset) {
-			pallet_erc20_xcm_bridge::Pallet::<T>::weight_of_erc20_transfer(&asset.id)
+			Erc20Weight::<T>::transfer_erc20_asset().saturating_add(
+				pallet_erc20_xcm_bridge::Pallet::<T>::weight_of_erc20_transfer(&asset.id),
+			)
 		} else {
 			Weight::from_parts(200_000_000 as u64, ASSET_TRANSFER_MAX_PROOF_SIZE)
 		}
 	}
 	pub(crate) fn transfer_reserve_asset(asset: &MultiAsset) -> Weight {
 		if pallet_erc20_xcm_bridge::Pallet::<T>::is_erc20_asset(asset) {
-			pallet_erc20_xcm_bridge::Pallet::<T>::weight_of_erc20_transfer(&asset.id)
+			Erc20Weight::<T>::transfer_erc20_asset().saturating_add(
+				pallet_erc20_xcm_bridge::Pallet::<T>::weight_of_erc20_transfer(&asset.id),
+			)
 		} else {
 			Weight::from_parts(200_000_000 as u64, ASSET_TRANSFER_MAX_PROOF_SIZE)
 		}
@@ -62,6 +70,10 @@ impl<T: frame_system::Config + pallet_erc20_xcm_bridge::Config> WeightInfo<T> {
 	pub(crate) fn deposit_reserve_asset() -> Weight {
 		Weight::from_parts(200_000_000 as u64, ASSET_MINT_MAX_PROOF_SIZE)
 	}
+	pub(crate) fn deposit_erc20_asset() -> Weight {
+		// The EVM gas budget was already charged on withdraw
+		Erc20Weight::<T>::deposit_erc20_asset()
+	}
 	pub(crate) fn initiate_teleport() -> Weight {
 		// Instruction disabled
 		Weight::MAX
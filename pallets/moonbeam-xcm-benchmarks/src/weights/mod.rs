@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
+pub mod erc20;
 pub mod fungible;
 pub mod generic;
 
@@ -51,6 +52,29 @@ impl WeighMultiAssetsFilter for MultiAssetFilter {
 	}
 }
 
+/// Weighs a deposit of `assets`, using the ERC-20 weight for definite ERC-20 assets and the
+/// worst of both for wildcard filters.
+fn weigh_deposit<Runtime>(assets: &MultiAssetFilter, fungible_weight: Weight) -> XCMWeight
+where
+	Runtime: frame_system::Config + pallet_erc20_xcm_bridge::Config,
+{
+	let erc20_weight = XcmFungibleWeight::<Runtime>::deposit_erc20_asset();
+	match assets {
+		MultiAssetFilter::Definite(assets) => {
+			assets.inner().iter().fold(Weight::zero(), |acc, asset| {
+				if pallet_erc20_xcm_bridge::Pallet::<Runtime>::is_erc20_asset(asset) {
+					acc.saturating_add(erc20_weight)
+				} else {
+					acc.saturating_add(fungible_weight)
+				}
+			})
+		}
+		MultiAssetFilter::Wild(_) => {
+			assets.weigh_multi_assets_filter(fungible_weight.max(erc20_weight))
+		}
+	}
+}
+
 impl WeighMultiAssets for MultiAssets {
 	fn weigh_multi_assets(&self, weight: Weight) -> XCMWeight {
 		weight.saturating_mul(self.inner().into_iter().count() as u64)
@@ -129,14 +153,17 @@ where
 		XcmGeneric::<Runtime>::report_error()
 	}
 	fn deposit_asset(assets: &MultiAssetFilter, _dest: &MultiLocation) -> XCMWeight {
-		assets.weigh_multi_assets_filter(XcmFungibleWeight::<Runtime>::deposit_asset())
+		weigh_deposit::<Runtime>(assets, XcmFungibleWeight::<Runtime>::deposit_asset())
 	}
 	fn deposit_reserve_asset(
 		assets: &MultiAssetFilter,
 		_dest: &MultiLocation,
 		_xcm: &Xcm<()>,
 	) -> XCMWeight {
-		assets.weigh_multi_assets_filter(XcmFungibleWeight::<Runtime>::deposit_reserve_asset())
+		weigh_deposit::<Runtime>(
+			assets,
+			XcmFungibleWeight::<Runtime>::deposit_reserve_asset(),
+		)
 	}
 	fn exchange_asset(
 		_give: &MultiAssetFilter,
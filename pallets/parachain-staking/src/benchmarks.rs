@@ -19,8 +19,9 @@
 //! Benchmarking
 use crate::{
 	AwardedPts, BalanceOf, BottomDelegations, Call, CandidateBondLessRequest, Config,
-	DelegationAction, Pallet, ParachainBondConfig, ParachainBondInfo, Points, Range, RewardPayment,
-	Round, ScheduledRequest, Staked, TopDelegations,
+	DelegationAction, InflationDistributionAccount, InflationDistributionConfig, OffenceConfig,
+	OffencePenalty, Pallet, ParachainBondConfig, Points, Range, RewardPayment, Round,
+	ScheduledRequest, Staked, TopDelegations, MAX_INFLATION_DISTRIBUTION_ACCOUNTS,
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::traits::{Currency, Get, OnFinalize, OnInitialize};
@@ -288,6 +289,20 @@ benchmarks! {
 		assert_eq!(Pallet::<T>::parachain_bond_info().percent, Percent::from_percent(33));
 	}
 
+	set_inflation_distribution_config {
+		let accounts = (0..MAX_INFLATION_DISTRIBUTION_ACCOUNTS)
+			.map(|i| InflationDistributionAccount {
+				account: account("TEST", i, USER_SEED),
+				percent: Percent::from_percent(10),
+			})
+			.collect::<Vec<_>>();
+		let config: InflationDistributionConfig<T::AccountId> =
+			accounts.try_into().expect("within bounds");
+	}: _(RawOrigin::Root, config.clone())
+	verify {
+		assert_eq!(Pallet::<T>::inflation_distribution_info(), config);
+	}
+
 	// ROOT DISPATCHABLES
 
 	set_total_selected {
@@ -307,6 +322,16 @@ benchmarks! {
 		assert_eq!(Pallet::<T>::round().length, 1200u32);
 	}
 
+	set_offence_config {
+		let config = OffenceConfig {
+			min_production: Percent::from_percent(50),
+			penalty: OffencePenalty::Suspension(2),
+		};
+	}: _(RawOrigin::Root, config)
+	verify {
+		assert_eq!(Pallet::<T>::offence_config(), config);
+	}
+
 	// USER DISPATCHABLES
 
 	join_candidates {
@@ -1510,7 +1535,7 @@ benchmarks! {
 		// may need:
 		//  <Points<T>>
 		//  <Staked<T>>
-		//  <ParachainBondInfo<T>>
+		//  <InflationDistributionInfo<T>>
 		//  ensure parachain bond account exists so that deposit_into_existing succeeds
 		<Points<T>>::insert(payout_round, 100);
 		<Staked<T>>::insert(payout_round, min_candidate_stk::<T>());
@@ -1521,7 +1546,7 @@ benchmarks! {
 			0,
 			min_candidate_stk::<T>(),
 		).0;
-		<ParachainBondInfo<T>>::put(ParachainBondConfig {
+		Pallet::<T>::put_parachain_bond(ParachainBondConfig {
 			account,
 			percent: Percent::from_percent(50),
 		});
@@ -2176,6 +2201,51 @@ benchmarks! {
 		);
 	}
 
+	switch_delegation {
+		use crate::auto_compound::AutoCompoundDelegations;
+
+		let mut seed = Seed::new();
+		let old_candidate = create_account::<T>(
+			"collator",
+			seed.take(),
+			AccountBalance::MinCandidateStake,
+			AccountAction::JoinCandidates{ amount: Amount::All, candidate_count: 1 },
+		)?;
+		let new_candidate = create_account::<T>(
+			"collator",
+			seed.take(),
+			AccountBalance::MinCandidateStake,
+			AccountAction::JoinCandidates{ amount: Amount::All, candidate_count: 2 },
+		)?;
+		let delegator = create_account::<T>(
+			"delegator",
+			seed.take(),
+			AccountBalance::MinDelegatorStake,
+			AccountAction::Delegate{
+				collator: old_candidate.clone(),
+				amount: Amount::All,
+				auto_compound: Percent::from_percent(50),
+				collator_delegation_count: 0,
+				collator_auto_compound_delegation_count: 0,
+			},
+		)?;
+	}: _(
+		RawOrigin::Signed(delegator.clone()),
+		old_candidate.clone(),
+		new_candidate.clone(),
+		T::MinDelegation::get()
+	)
+	verify {
+		let state = Pallet::<T>::delegator_state(&delegator).expect("delegator must exist");
+		assert_eq!(state.get_bond_amount(&old_candidate), None);
+		assert_eq!(state.get_bond_amount(&new_candidate), Some(T::MinDelegation::get()));
+		assert_eq!(
+			<AutoCompoundDelegations<T>>::get_storage(&new_candidate)
+				.get_for_delegator(&delegator),
+			Some(Percent::from_percent(50)),
+		);
+	}
+
 	mint_collator_reward {
 		let mut seed = Seed::new();
 		let collator = create_funded_collator::<T>(
@@ -2236,6 +2306,13 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn bench_set_inflation_distribution_config() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_set_inflation_distribution_config());
+		});
+	}
+
 	#[test]
 	fn bench_set_total_selected() {
 		new_test_ext().execute_with(|| {
@@ -2257,6 +2334,13 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn bench_set_offence_config() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_set_offence_config());
+		});
+	}
+
 	#[test]
 	fn bench_join_candidates() {
 		new_test_ext().execute_with(|| {
@@ -2369,6 +2453,13 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn bench_switch_delegation() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_switch_delegation());
+		});
+	}
+
 	#[test]
 	fn bench_base_on_initialize() {
 		new_test_ext().execute_with(|| {
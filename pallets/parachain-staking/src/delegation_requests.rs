@@ -115,6 +115,7 @@ impl<T: Config> Pallet<T> {
 		state.less_total = state.less_total.saturating_add(bonded_amount);
 		<DelegationScheduledRequests<T>>::insert(collator.clone(), scheduled_requests);
 		<DelegatorState<T>>::insert(delegator.clone(), state);
+		Self::exit_queue_push(collator.clone(), delegator.clone(), when);
 
 		Self::deposit_event(Event::DelegationRevocationScheduled {
 			round: now,
@@ -222,6 +223,9 @@ impl<T: Config> Pallet<T> {
 					post_info: Some(actual_weight).into(),
 					error: <Error<T>>::PendingDelegationRequestDNE.into(),
 				})?;
+		if let DelegationAction::Revoke(_) = request.action {
+			Self::exit_queue_remove(&collator, &delegator);
+		}
 
 		<DelegationScheduledRequests<T>>::insert(collator.clone(), scheduled_requests);
 		<DelegatorState<T>>::insert(delegator.clone(), state);
@@ -292,6 +296,7 @@ impl<T: Config> Pallet<T> {
 				// remove from pending requests
 				let amount = scheduled_requests.remove(request_idx).action.amount();
 				state.less_total = state.less_total.saturating_sub(amount);
+				Self::exit_queue_remove(&collator, &delegator);
 
 				// remove delegation from delegator state
 				state.rm_delegation::<T>(&collator);
@@ -420,6 +425,9 @@ impl<T: Config> Pallet<T> {
 			let request = scheduled_requests.remove(request_idx);
 			let amount = request.action.amount();
 			state.less_total = state.less_total.saturating_sub(amount);
+			if let DelegationAction::Revoke(_) = request.action {
+				Self::exit_queue_remove(collator, delegator);
+			}
 			<DelegationScheduledRequests<T>>::insert(collator, scheduled_requests);
 		}
 	}
@@ -0,0 +1,169 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Switching bonded stake between collator candidates
+//!
+//! A delegator can move part or all of a delegation to another candidate with
+//! `switch_delegation`, without going through the revoke or bond less delays. The stake stays
+//! locked during the switch, and each delegator may only switch up to
+//! `MaxDelegationSwitchesPerRound` times per round.
+
+use crate::auto_compound::AutoCompoundDelegations;
+use crate::pallet::{
+	BalanceOf, CandidateInfo, Config, DelegationSwitches, DelegatorState, Error, Event, Pallet,
+	Round, Total,
+};
+use crate::types::{Bond, DelegatorAdded};
+use crate::weights::WeightInfo;
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, traits::Get};
+use sp_runtime::traits::{Saturating, Zero};
+
+impl<T: Config> Pallet<T> {
+	/// Moves `amount` of the delegation towards `old_candidate` to `new_candidate`.
+	pub(crate) fn delegation_switch(
+		delegator: T::AccountId,
+		old_candidate: T::AccountId,
+		new_candidate: T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResultWithPostInfo {
+		ensure!(
+			old_candidate != new_candidate,
+			Error::<T>::CannotSwitchToSameCandidate
+		);
+		ensure!(!amount.is_zero(), Error::<T>::DelegationBelowMin);
+		let mut state = <DelegatorState<T>>::get(&delegator).ok_or(Error::<T>::DelegatorDNE)?;
+		let old_bond = state
+			.get_bond_amount(&old_candidate)
+			.ok_or(Error::<T>::DelegationDNE)?;
+		ensure!(
+			amount <= old_bond,
+			Error::<T>::SwitchAmountExceedsDelegation
+		);
+		ensure!(
+			!Self::delegation_request_exists(&old_candidate, &delegator),
+			Error::<T>::PendingDelegationRequestAlreadyExists
+		);
+		ensure!(
+			!Self::delegation_request_revoke_exists(&new_candidate, &delegator),
+			Error::<T>::PendingDelegationRevoke
+		);
+		let mut new_candidate_state =
+			<CandidateInfo<T>>::get(&new_candidate).ok_or(Error::<T>::CandidateDNE)?;
+		let mut old_candidate_state =
+			<CandidateInfo<T>>::get(&old_candidate).ok_or(Error::<T>::CandidateDNE)?;
+
+		let now = <Round<T>>::get().current;
+		let (round, switches) = <DelegationSwitches<T>>::get(&delegator).unwrap_or_default();
+		let switches = if round == now { switches } else { 0 };
+		ensure!(
+			switches < T::MaxDelegationSwitchesPerRound::get(),
+			Error::<T>::TooManyDelegationSwitches
+		);
+
+		let full_switch = amount == old_bond;
+		let auto_compound = <AutoCompoundDelegations<T>>::auto_compound(&old_candidate, &delegator);
+
+		// take the stake out of the old delegation, keeping it locked
+		if full_switch {
+			state
+				.delegations
+				.remove(&Bond::from_owner(old_candidate.clone()));
+			old_candidate_state.rm_delegation_if_exists::<T>(
+				&old_candidate,
+				delegator.clone(),
+				old_bond,
+			)?;
+			<AutoCompoundDelegations<T>>::remove_auto_compound(&old_candidate, &delegator);
+		} else {
+			ensure!(
+				old_bond.saturating_sub(amount) >= T::MinDelegation::get(),
+				Error::<T>::DelegationBelowMin
+			);
+			for bond in &mut state.delegations.0 {
+				if bond.owner == old_candidate {
+					bond.amount = bond.amount.saturating_sub(amount);
+				}
+			}
+			old_candidate_state.decrease_delegation::<T>(
+				&old_candidate,
+				delegator.clone(),
+				old_bond,
+				amount,
+			)?;
+		}
+		<CandidateInfo<T>>::insert(&old_candidate, old_candidate_state);
+
+		// put the stake into the new delegation
+		let in_top = if let Some(new_bond) = state.get_bond_amount(&new_candidate) {
+			for bond in &mut state.delegations.0 {
+				if bond.owner == new_candidate {
+					bond.amount = bond.amount.saturating_add(amount);
+				}
+			}
+			new_candidate_state.increase_delegation::<T>(
+				&new_candidate,
+				delegator.clone(),
+				new_bond,
+				amount,
+			)?
+		} else {
+			ensure!(
+				amount >= T::MinDelegation::get(),
+				Error::<T>::DelegationBelowMin
+			);
+			ensure!(
+				(state.delegations.0.len() as u32) < T::MaxDelegationsPerDelegator::get(),
+				Error::<T>::ExceedMaxDelegationsPerDelegator
+			);
+			state.delegations.insert(Bond {
+				owner: new_candidate.clone(),
+				amount,
+			});
+			let (delegator_position, less_total_staked) = new_candidate_state.add_delegation::<T>(
+				&new_candidate,
+				Bond {
+					owner: delegator.clone(),
+					amount,
+				},
+			)?;
+			// a bottom delegation was kicked out of the new candidate and unbonded
+			if let Some(less) = less_total_staked {
+				<Total<T>>::put(<Total<T>>::get().saturating_sub(less));
+			}
+			// carry the auto-compounding config over when moving the whole delegation
+			if full_switch && !auto_compound.is_zero() {
+				let mut auto_compounding_state =
+					<AutoCompoundDelegations<T>>::get_storage(&new_candidate);
+				auto_compounding_state.set_for_delegator(delegator.clone(), auto_compound)?;
+				auto_compounding_state.set_storage(&new_candidate);
+			}
+			matches!(delegator_position, DelegatorAdded::AddedToTop { .. })
+		};
+		<CandidateInfo<T>>::insert(&new_candidate, new_candidate_state);
+
+		<DelegatorState<T>>::insert(&delegator, state);
+		<DelegationSwitches<T>>::insert(&delegator, (now, switches.saturating_add(1)));
+		Self::deposit_event(Event::DelegationSwitched {
+			delegator,
+			old_candidate,
+			new_candidate,
+			amount,
+			in_top,
+		});
+
+		Ok(Some(T::WeightInfo::switch_delegation()).into())
+	}
+}
@@ -0,0 +1,121 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Exit queue for scheduled delegation revocations
+//!
+//! Revocation requests are appended to a FIFO queue when scheduled, and drained by
+//! `on_initialize` once due, up to [Config::MaxExitQueueWeight] per block. Requests can still be
+//! executed through `execute_delegation_request`, in which case their queue entry is skipped.
+
+use crate::pallet::{
+	Config, DelegatorExitQueue, DelegatorExitQueueBounds, DelegatorExitQueueIndex, Pallet, Round,
+	RoundIndex,
+};
+use crate::weights::WeightInfo;
+use frame_support::{pallet_prelude::Weight, traits::Get, RuntimeDebug};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, DispatchError};
+
+/// A revocation request waiting in the exit queue.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ExitQueueEntry<AccountId> {
+	pub collator: AccountId,
+	pub delegator: AccountId,
+	pub when_executable: RoundIndex,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Appends a scheduled revocation to the exit queue.
+	pub(crate) fn exit_queue_push(
+		collator: T::AccountId,
+		delegator: T::AccountId,
+		when_executable: RoundIndex,
+	) {
+		let (head, tail) = <DelegatorExitQueueBounds<T>>::get();
+		<DelegatorExitQueueIndex<T>>::insert(&delegator, &collator, tail);
+		<DelegatorExitQueue<T>>::insert(
+			tail,
+			ExitQueueEntry {
+				collator,
+				delegator,
+				when_executable,
+			},
+		);
+		<DelegatorExitQueueBounds<T>>::put((head, tail.saturating_add(1)));
+	}
+
+	/// Detaches a revocation from the exit queue, its entry being skipped once drained.
+	pub(crate) fn exit_queue_remove(collator: &T::AccountId, delegator: &T::AccountId) {
+		<DelegatorExitQueueIndex<T>>::remove(delegator, collator);
+	}
+
+	/// Number of queued entries ahead of the delegator's revocation towards `collator`, if any.
+	pub fn exit_queue_position(delegator: &T::AccountId, collator: &T::AccountId) -> Option<u32> {
+		let index = <DelegatorExitQueueIndex<T>>::get(delegator, collator)?;
+		let (head, _) = <DelegatorExitQueueBounds<T>>::get();
+		Some(index.saturating_sub(head))
+	}
+
+	/// Executes the due revocations at the head of the exit queue, within the weight cap.
+	pub(crate) fn drain_exit_queue() -> Weight {
+		let (mut head, tail) = <DelegatorExitQueueBounds<T>>::get();
+		let now = <Round<T>>::get().current;
+		let execute_weight = T::WeightInfo::execute_delegator_revoke_delegation_worst();
+		let entry_weight = T::DbWeight::get().reads_writes(2, 2);
+		let max_weight = T::MaxExitQueueWeight::get();
+
+		// read: Round, DelegatorExitQueueBounds
+		let mut weight = T::DbWeight::get().reads(2);
+		while head < tail {
+			if weight
+				.saturating_add(entry_weight)
+				.saturating_add(execute_weight)
+				.any_gt(max_weight)
+			{
+				break;
+			}
+
+			weight = weight.saturating_add(entry_weight);
+			let Some(entry) = <DelegatorExitQueue<T>>::get(head) else {
+				head = head.saturating_add(1);
+				continue;
+			};
+			// entries are queued in scheduling order, the following ones are not due either
+			if entry.when_executable > now {
+				break;
+			}
+
+			<DelegatorExitQueue<T>>::remove(head);
+			if <DelegatorExitQueueIndex<T>>::get(&entry.delegator, &entry.collator) == Some(head) {
+				weight = weight.saturating_add(execute_weight);
+				// the request can have become invalid in the meantime, in which case it is dropped
+				let _ = frame_support::storage::with_storage_layer(|| {
+					Self::delegation_execute_scheduled_request(
+						entry.collator.clone(),
+						entry.delegator.clone(),
+					)
+					.map_err(|err| -> DispatchError { err.error })
+				});
+				<DelegatorExitQueueIndex<T>>::remove(&entry.delegator, &entry.collator);
+			}
+			head = head.saturating_add(1);
+		}
+
+		<DelegatorExitQueueBounds<T>>::put((head, tail));
+		weight.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
@@ -33,6 +33,8 @@
 //! with each of its top `T::MaxTopDelegationsPerCandidate` delegators.
 //!
 //! To join the set of candidates, call `join_candidates` with `bond >= MinCandidateStk`.
+//! When `MinCandidateStk` is raised, candidates below the new minimum have
+//! `MinCandidateStkGracePeriod` rounds to top up their bond before leaving the candidate pool.
 //! To leave the set of candidates, call `schedule_leave_candidates`. If the call succeeds,
 //! the collator is removed from the pool of candidates so they cannot be selected for future
 //! collator sets, but they are not unbonded until their exit request is executed. Any signed
@@ -44,14 +46,23 @@
 //! `T::MaxDelegationsPerDelegator` collator candidates by calling `delegate`.
 //!
 //! To revoke a delegation, call `revoke_delegation` with the collator candidate's account.
+//! Once due, scheduled revocations are executed in scheduling order by `on_initialize`, up to
+//! `T::MaxExitQueueWeight` per block.
 //! To leave the set of delegators and revoke all delegations, call `leave_delegators`.
+//!
+//! To move bonded stake to another candidate without waiting for a revocation, call
+//! `switch_delegation`, at most `T::MaxDelegationSwitchesPerRound` times per round.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod auto_compound;
 mod delegation_requests;
+mod delegation_switch;
+mod exit_queue;
 pub mod inflation;
 pub mod migrations;
+mod min_bond;
+mod offences;
 pub mod traits;
 pub mod types;
 pub mod weights;
@@ -70,18 +81,26 @@ pub use weights::WeightInfo;
 
 pub use auto_compound::{AutoCompoundConfig, AutoCompoundDelegations};
 pub use delegation_requests::{CancelledScheduledRequest, DelegationAction, ScheduledRequest};
+pub use exit_queue::ExitQueueEntry;
+pub use offences::{OffenceConfig, OffencePenalty};
 pub use pallet::*;
 pub use traits::*;
 pub use types::*;
 pub use RoundIndex;
 
+/// Points awarded to a collator for each produced block.
+pub(crate) const POINTS_PER_BLOCK: u32 = 20;
+
 #[pallet]
 pub mod pallet {
 	use crate::delegation_requests::{
 		CancelledScheduledRequest, DelegationAction, ScheduledRequest,
 	};
 	use crate::{set::BoundedOrderedSet, traits::*, types::*, InflationInfo, Range, WeightInfo};
-	use crate::{AutoCompoundConfig, AutoCompoundDelegations};
+	use crate::{
+		AutoCompoundConfig, AutoCompoundDelegations, ExitQueueEntry, OffenceConfig, OffencePenalty,
+		POINTS_PER_BLOCK,
+	};
 	use frame_support::fail;
 	use frame_support::pallet_prelude::*;
 	use frame_support::traits::{
@@ -159,6 +178,9 @@ pub mod pallet {
 		/// Minimum stake required for any account to be a collator candidate
 		#[pallet::constant]
 		type MinCandidateStk: Get<BalanceOf<Self>>;
+		/// Number of rounds candidates below a raised `MinCandidateStk` have to top up their bond
+		#[pallet::constant]
+		type MinCandidateStkGracePeriod: Get<RoundIndex>;
 		/// Minimum stake for any registered on-chain account to delegate
 		#[pallet::constant]
 		type MinDelegation: Get<BalanceOf<Self>>;
@@ -178,6 +200,12 @@ pub mod pallet {
 		/// Maximum candidates
 		#[pallet::constant]
 		type MaxCandidates: Get<u32>;
+		/// Maximum weight spent per block executing the due revocations of the exit queue
+		#[pallet::constant]
+		type MaxExitQueueWeight: Get<Weight>;
+		/// Maximum number of delegation switches per delegator per round
+		#[pallet::constant]
+		type MaxDelegationSwitchesPerRound: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -233,6 +261,10 @@ pub mod pallet {
 		CandidateLimitReached,
 		CannotSetAboveMaxCandidates,
 		RemovedCall,
+		CannotSwitchToSameCandidate,
+		SwitchAmountExceedsDelegation,
+		TooManyDelegationSwitches,
+		TotalInflationDistributionPercentExceeds100,
 	}
 
 	#[pallet::event]
@@ -379,6 +411,7 @@ pub mod pallet {
 			rewards: BalanceOf<T>,
 		},
 		/// Transferred to account which holds funds reserved for parachain bond.
+		/// Superseded by `InflationDistributed`, which is emitted for every distribution account.
 		ReservedForParachainBond {
 			account: T::AccountId,
 			value: BalanceOf<T>,
@@ -431,6 +464,51 @@ pub mod pallet {
 			delegator: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// Collator produced fewer blocks than required during a round.
+		CollatorOffence {
+			collator: T::AccountId,
+			round: RoundIndex,
+			blocks_produced: u32,
+			blocks_expected: u32,
+			penalty: OffencePenalty,
+		},
+		/// Missed block production offence configuration (re)set.
+		OffenceConfigSet {
+			old: OffenceConfig,
+			new: OffenceConfig,
+		},
+		/// Candidate self bond is below a raised minimum and must be topped up before the deadline.
+		CandidateBondBelowMin {
+			candidate: T::AccountId,
+			bond: BalanceOf<T>,
+			min_bond: BalanceOf<T>,
+			top_up_deadline: RoundIndex,
+		},
+		/// Candidate left the candidate pool for not topping up its self bond before the deadline.
+		CandidateKickedBelowMinBond {
+			candidate: T::AccountId,
+			bond: BalanceOf<T>,
+			min_bond: BalanceOf<T>,
+		},
+		/// Delegated stake was moved from a candidate to another.
+		DelegationSwitched {
+			delegator: T::AccountId,
+			old_candidate: T::AccountId,
+			new_candidate: T::AccountId,
+			amount: BalanceOf<T>,
+			in_top: bool,
+		},
+		/// Transferred to an account receiving a share of the round inflation.
+		InflationDistributed {
+			index: u32,
+			account: T::AccountId,
+			value: BalanceOf<T>,
+		},
+		/// Accounts receiving a share of the round inflation (re)set.
+		InflationDistributionConfigUpdated {
+			old: InflationDistributionConfig<T::AccountId>,
+			new: InflationDistributionConfig<T::AccountId>,
+		},
 	}
 
 	#[pallet::hooks]
@@ -440,8 +518,14 @@ pub mod pallet {
 
 			let mut round = <Round<T>>::get();
 			if round.should_update(n) {
+				let (ended_round, ended_round_length) = (round.current, round.length);
 				// mutate round
 				round.update(n);
+				// penalize the collators which missed their block production slots
+				weight = weight
+					.saturating_add(Self::handle_missed_blocks(ended_round, ended_round_length));
+				// remove the candidates which did not top up their bond in time
+				weight = weight.saturating_add(Self::kick_candidates_below_min_bond(round.current));
 				// notify that new round begin
 				weight = weight.saturating_add(T::OnNewRound::on_new_round(round.current));
 				// pay all stakers for T::RewardPaymentDelay rounds ago
@@ -466,6 +550,9 @@ pub mod pallet {
 				weight = weight.saturating_add(Self::handle_delayed_payouts(round.current));
 			}
 
+			// execute the due revocations of the exit queue
+			weight = weight.saturating_add(Self::drain_exit_queue());
+
 			// add on_finalize weight
 			//   read:  Author, Points, AwardedPts
 			//   write: Points, AwardedPts
@@ -475,6 +562,11 @@ pub mod pallet {
 		fn on_finalize(_n: T::BlockNumber) {
 			Self::award_points_to_block_author();
 		}
+		fn on_runtime_upgrade() -> Weight {
+			// give the candidates below a raised minimum bond time to top it up
+			Self::schedule_bond_top_ups(<Round<T>>::get().current)
+				.saturating_add(T::DbWeight::get().reads(1))
+		}
 	}
 
 	#[pallet::storage]
@@ -488,10 +580,10 @@ pub mod pallet {
 	pub(crate) type TotalSelected<T: Config> = StorageValue<_, u32, ValueQuery>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn parachain_bond_info)]
-	/// Parachain bond config info { account, percent_of_inflation }
-	pub(crate) type ParachainBondInfo<T: Config> =
-		StorageValue<_, ParachainBondConfig<T::AccountId>, ValueQuery>;
+	#[pallet::getter(fn inflation_distribution_info)]
+	/// Accounts receiving a share of the round inflation, the first one being the parachain bond
+	pub(crate) type InflationDistributionInfo<T: Config> =
+		StorageValue<_, InflationDistributionConfig<T::AccountId>, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn round)]
@@ -542,6 +634,52 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Scheduled revocations waiting to be executed by `on_initialize`, by queue index.
+	#[pallet::storage]
+	pub(crate) type DelegatorExitQueue<T: Config> =
+		StorageMap<_, Twox64Concat, u32, ExitQueueEntry<T::AccountId>, OptionQuery>;
+
+	/// Head and tail indexes of the exit queue.
+	#[pallet::storage]
+	pub(crate) type DelegatorExitQueueBounds<T: Config> = StorageValue<_, (u32, u32), ValueQuery>;
+
+	/// Exit queue index of the pending revocation of a (delegator, collator) delegation.
+	#[pallet::storage]
+	pub(crate) type DelegatorExitQueueIndex<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Value of `MinCandidateStk` when it was last checked
+	pub(crate) type MinCandidateStkSnapshot<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn candidate_bond_top_up_deadline)]
+	/// Round by which a candidate must top up its bond to a raised `MinCandidateStk`
+	pub(crate) type CandidateBondTopUpDeadline<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, RoundIndex, OptionQuery>;
+
+	#[pallet::storage]
+	/// Round of the last delegation switch of a delegator, and number of switches in that round
+	pub(crate) type DelegationSwitches<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (RoundIndex, u32), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn offence_config)]
+	/// Missed block production offence configuration
+	pub(crate) type OffenceConfiguration<T: Config> = StorageValue<_, OffenceConfig, ValueQuery>;
+
+	#[pallet::storage]
+	/// Candidates excluded from the collator selection, until the given round (excluded)
+	pub(crate) type SuspendedCandidates<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, RoundIndex, OptionQuery>;
+
 	/// Stores auto-compounding configuration per collator.
 	#[pallet::storage]
 	#[pallet::getter(fn auto_compounding_delegations)]
@@ -758,7 +896,7 @@ pub mod pallet {
 			// Set collator commission to default config
 			<CollatorCommission<T>>::put(self.collator_commission);
 			// Set parachain bond config to default config
-			<ParachainBondInfo<T>>::put(ParachainBondConfig {
+			<Pallet<T>>::put_parachain_bond(ParachainBondConfig {
 				// must be set soon; if not => due inflation will be sent to collators/delegators
 				account: T::AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes())
 					.expect("infinite length input; no invalid inputs for type; qed"),
@@ -776,6 +914,7 @@ pub mod pallet {
 				Error::<T>::CannotSetAboveMaxCandidates
 			);
 			<TotalSelected<T>>::put(self.num_selected_candidates);
+			<MinCandidateStkSnapshot<T>>::put(T::MinCandidateStk::get());
 			// Choose top TotalSelected collator candidates
 			let (_, v_count, _, total_staked) = <Pallet<T>>::select_top_candidates(1u32);
 			// Start Round 1 at Block 0
@@ -856,9 +995,9 @@ pub mod pallet {
 			let ParachainBondConfig {
 				account: old,
 				percent,
-			} = <ParachainBondInfo<T>>::get();
+			} = Self::parachain_bond_info();
 			ensure!(old != new, Error::<T>::NoWritingSameValue);
-			<ParachainBondInfo<T>>::put(ParachainBondConfig {
+			Self::put_parachain_bond(ParachainBondConfig {
 				account: new.clone(),
 				percent,
 			});
@@ -877,9 +1016,17 @@ pub mod pallet {
 			let ParachainBondConfig {
 				account,
 				percent: old,
-			} = <ParachainBondInfo<T>>::get();
+			} = Self::parachain_bond_info();
 			ensure!(old != new, Error::<T>::NoWritingSameValue);
-			<ParachainBondInfo<T>>::put(ParachainBondConfig {
+			let mut config = <InflationDistributionInfo<T>>::get().into_inner();
+			if let Some(bond) = config.first_mut() {
+				bond.percent = new;
+			}
+			ensure!(
+				inflation_distribution_total(&config).is_some(),
+				Error::<T>::TotalInflationDistributionPercentExceeds100
+			);
+			Self::put_parachain_bond(ParachainBondConfig {
 				account,
 				percent: new,
 			});
@@ -964,6 +1111,21 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Set the missed block production offence configuration
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_offence_config())]
+		pub fn set_offence_config(
+			origin: OriginFor<T>,
+			new: OffenceConfig,
+		) -> DispatchResultWithPostInfo {
+			frame_system::ensure_root(origin)?;
+			let old = <OffenceConfiguration<T>>::get();
+			ensure!(old != new, Error::<T>::NoWritingSameValue);
+			<OffenceConfiguration<T>>::put(new);
+			Self::deposit_event(Event::OffenceConfigSet { old, new });
+			Ok(().into())
+		}
+
 		/// Join the set of collator candidates
 		#[pallet::call_index(7)]
 		#[pallet::weight(<T as Config>::WeightInfo::join_candidates(*candidate_count))]
@@ -1347,6 +1509,85 @@ pub mod pallet {
 			)
 		}
 
+		/// Move `amount` of the delegation towards `old_candidate` to `new_candidate`, without
+		/// unbonding it. Switching the whole delegation also moves its auto-compound config.
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as Config>::WeightInfo::switch_delegation())]
+		pub fn switch_delegation(
+			origin: OriginFor<T>,
+			old_candidate: T::AccountId,
+			new_candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let delegator = ensure_signed(origin)?;
+			Self::delegation_switch(delegator, old_candidate, new_candidate, amount)
+		}
+
+		/// Remove a candidate immediately, without waiting for its exit delay, returning its self
+		/// bond and all its delegations. Meant for governance to evict a misbehaving candidate.
+		#[pallet::call_index(31)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::schedule_leave_candidates(*candidate_count).saturating_add(
+				<T as Config>::WeightInfo::execute_leave_candidates_worst_case(
+					*candidate_delegation_count
+				)
+			)
+		)]
+		pub fn force_leave_candidates(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			candidate_count: u32,
+			candidate_delegation_count: u32,
+		) -> DispatchResultWithPostInfo {
+			frame_system::ensure_root(origin)?;
+			let state = <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
+			ensure!(
+				state.delegation_count <= candidate_delegation_count,
+				Error::<T>::TooLowCandidateDelegationCountToLeaveCandidates
+			);
+			let mut candidates = <CandidatePool<T>>::get();
+			ensure!(
+				candidate_count >= candidates.0.len() as u32,
+				Error::<T>::TooLowCandidateCountToLeaveCandidates
+			);
+			if candidates.remove(&Bond::from_owner(candidate.clone())) {
+				<CandidatePool<T>>::put(candidates);
+			}
+			Self::remove_candidate(candidate, state)?;
+			Ok(().into())
+		}
+
+		/// Cancel a pending request to change a delegation on behalf of the delegator.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_delegation_request(350))]
+		pub fn force_cancel_delegation_request(
+			origin: OriginFor<T>,
+			delegator: T::AccountId,
+			candidate: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			frame_system::ensure_root(origin)?;
+			Self::delegation_cancel_request(candidate, delegator)
+		}
+		/// Set the accounts receiving a share of the round inflation, the first one being the
+		/// parachain bond. The sum of their percents cannot exceed 100%.
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_inflation_distribution_config())]
+		pub fn set_inflation_distribution_config(
+			origin: OriginFor<T>,
+			new: InflationDistributionConfig<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+			let old = <InflationDistributionInfo<T>>::get();
+			ensure!(old != new, Error::<T>::NoWritingSameValue);
+			ensure!(
+				inflation_distribution_total(&new).is_some(),
+				Error::<T>::TotalInflationDistributionPercentExceeds100
+			);
+			<InflationDistributionInfo<T>>::put(new.clone());
+			Self::deposit_event(Event::InflationDistributionConfigUpdated { old, new });
+			Ok(().into())
+		}
+
 		/// Hotfix to remove existing empty entries for candidates that have left.
 		#[pallet::call_index(28)]
 		#[pallet::weight(
@@ -1389,6 +1630,28 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Parachain bond config info { account, percent_of_inflation }, the first inflation
+		/// distribution account
+		pub fn parachain_bond_info() -> ParachainBondConfig<T::AccountId> {
+			<InflationDistributionInfo<T>>::get()
+				.first()
+				.cloned()
+				.map(Into::into)
+				.unwrap_or_default()
+		}
+
+		/// Set the first inflation distribution account, keeping the other ones.
+		pub(crate) fn put_parachain_bond(bond: ParachainBondConfig<T::AccountId>) {
+			<InflationDistributionInfo<T>>::mutate(|config| {
+				if let Some(first) = config.first_mut() {
+					*first = bond.into();
+				} else {
+					// cannot fail: the config is empty
+					let _ = config.try_push(bond.into());
+				}
+			});
+		}
+
 		pub fn is_delegator(acc: &T::AccountId) -> bool {
 			<DelegatorState<T>>::get(acc).is_some()
 		}
@@ -1401,6 +1664,21 @@ pub mod pallet {
 			<SelectedCandidates<T>>::get().binary_search(acc).is_ok()
 		}
 
+		/// Storage key of the delegator state of `acc`.
+		pub fn delegator_state_key(acc: &T::AccountId) -> Vec<u8> {
+			<DelegatorState<T>>::hashed_key_for(acc)
+		}
+
+		/// Storage key of the candidate info of `acc`.
+		pub fn candidate_info_key(acc: &T::AccountId) -> Vec<u8> {
+			<CandidateInfo<T>>::hashed_key_for(acc)
+		}
+
+		/// Storage key of the current round.
+		pub fn round_key() -> Vec<u8> {
+			<Round<T>>::hashed_key().to_vec()
+		}
+
 		pub fn go_offline_inner(collator: T::AccountId) -> DispatchResultWithPostInfo {
 			let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
 			let mut candidates = <CandidatePool<T>>::get();
@@ -1444,6 +1722,13 @@ pub mod pallet {
 					error: <Error<T>>::CannotGoOnlineIfLeaving.into(),
 				}
 			);
+			ensure!(
+				state.bond >= T::MinCandidateStk::get(),
+				DispatchErrorWithPostInfo {
+					post_info: Some(actual_weight).into(),
+					error: <Error<T>>::CandidateBondBelowMin.into(),
+				}
+			);
 			state.go_online();
 
 			let maybe_inserted_candidate = candidates
@@ -1483,6 +1768,7 @@ pub mod pallet {
 					error: err,
 				})?;
 			let (is_active, total_counted) = (state.is_active(), state.total_counted);
+			Self::note_candidate_bond(&collator, state.bond);
 			<CandidateInfo<T>>::insert(&collator, state);
 			if is_active {
 				Self::update_active(collator, total_counted);
@@ -1528,6 +1814,16 @@ pub mod pallet {
 					post_info: Some(actual_weight).into(),
 					error: err,
 				})?;
+			Self::remove_candidate(candidate, state)?;
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Removes the candidate, returning its self bond and all its delegations, without
+		/// checking that it scheduled its exit.
+		fn remove_candidate(
+			candidate: T::AccountId,
+			state: CandidateMetadata<BalanceOf<T>>,
+		) -> DispatchResult {
 			let return_stake = |bond: Bond<T::AccountId, BalanceOf<T>>| {
 				// remove delegation from delegator state
 				let mut delegator = DelegatorState::<T>::get(&bond.owner).expect(
@@ -1589,7 +1885,7 @@ pub mod pallet {
 				unlocked_amount: total_backing,
 				new_total_amt_locked: new_total_staked,
 			});
-			Ok(Some(actual_weight).into())
+			Ok(())
 		}
 
 		/// Returns an account's free balance which is not locked in delegation staking
@@ -1683,18 +1979,22 @@ pub mod pallet {
 			let total_staked = <Staked<T>>::take(round_to_payout);
 			let total_issuance = Self::compute_issuance(total_staked);
 			let mut left_issuance = total_issuance;
-			// reserve portion of issuance for parachain bond account
-			let bond_config = <ParachainBondInfo<T>>::get();
-			let parachain_bond_reserve = bond_config.percent * total_issuance;
-			if let Ok(imb) =
-				T::Currency::deposit_into_existing(&bond_config.account, parachain_bond_reserve)
+			// reserve portion of issuance for each inflation distribution account
+			for (index, distribution) in <InflationDistributionInfo<T>>::get()
+				.into_iter()
+				.enumerate()
 			{
-				// update round issuance iff transfer succeeds
-				left_issuance = left_issuance.saturating_sub(imb.peek());
-				Self::deposit_event(Event::ReservedForParachainBond {
-					account: bond_config.account,
-					value: imb.peek(),
-				});
+				let reserve = distribution.percent * total_issuance;
+				if let Ok(imb) = T::Currency::deposit_into_existing(&distribution.account, reserve)
+				{
+					// update round issuance iff transfer succeeds
+					left_issuance = left_issuance.saturating_sub(imb.peek());
+					Self::deposit_event(Event::InflationDistributed {
+						index: index as u32,
+						account: distribution.account,
+						value: imb.peek(),
+					});
+				}
 			}
 
 			let payout = DelayedPayout {
@@ -1871,7 +2171,9 @@ pub mod pallet {
 				return vec![];
 			}
 
-			let candidates = <CandidatePool<T>>::get().0;
+			let mut candidates = <CandidatePool<T>>::get().0;
+			// suspended candidates are not eligible
+			candidates.retain(|candidate| !Self::is_suspended(&candidate.owner));
 
 			// If the number of candidates is greater than top_n, select the candidates with higher
 			// amount. Otherwise, return all the candidates.
@@ -2141,14 +2443,14 @@ pub mod pallet {
 	}
 
 	/// Add reward points to block authors:
-	/// * 20 points to the block producer for producing a block in the chain
+	/// * `POINTS_PER_BLOCK` points to the block producer for producing a block in the chain
 	impl<T: Config> Pallet<T> {
 		fn award_points_to_block_author() {
 			let author = T::BlockAuthor::get();
 			let now = <Round<T>>::get().current;
-			let score_plus_20 = <AwardedPts<T>>::get(now, &author).saturating_add(20);
-			<AwardedPts<T>>::insert(now, author, score_plus_20);
-			<Points<T>>::mutate(now, |x| *x = x.saturating_add(20));
+			let score = <AwardedPts<T>>::get(now, &author).saturating_add(POINTS_PER_BLOCK);
+			<AwardedPts<T>>::insert(now, author, score);
+			<Points<T>>::mutate(now, |x| *x = x.saturating_add(POINTS_PER_BLOCK));
 		}
 	}
 
@@ -15,3 +15,59 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 //! # Migrations
+
+use crate::{
+	Config, InflationDistributionConfig, InflationDistributionInfo, Pallet, ParachainBondConfig,
+};
+use frame_support::{
+	pallet_prelude::*,
+	storage_alias,
+	traits::{Get, OnRuntimeUpgrade},
+};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Parachain bond config, before it became the first inflation distribution account
+#[storage_alias]
+type ParachainBondInfo<T: Config> = StorageValue<
+	Pallet<T>,
+	ParachainBondConfig<<T as frame_system::Config>::AccountId>,
+	OptionQuery,
+>;
+
+/// Move the parachain bond config into the first inflation distribution account.
+pub struct MigrateParachainBondConfig<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateParachainBondConfig<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let Some(bond) = ParachainBondInfo::<T>::take() else {
+			return T::DbWeight::get().reads(1);
+		};
+		let config: InflationDistributionConfig<T::AccountId> =
+			BoundedVec::truncate_from(sp_std::vec![bond.into()]);
+		<InflationDistributionInfo<T>>::put(config);
+
+		T::DbWeight::get().reads_writes(1, 2)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+		Ok(ParachainBondInfo::<T>::get().encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		let bond = Option::<ParachainBondConfig<T::AccountId>>::decode(&mut &state[..])
+			.map_err(|_| "cannot decode the parachain bond config")?;
+		if let Some(bond) = bond {
+			ensure!(
+				Pallet::<T>::parachain_bond_info() == bond,
+				"parachain bond config was not migrated"
+			);
+		}
+		ensure!(
+			!ParachainBondInfo::<T>::exists(),
+			"parachain bond config was not removed"
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,118 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Grace period for collator candidates falling below a raised minimum self-bond
+//!
+//! When a runtime upgrade raises `MinCandidateStk`, the candidates whose self-bond is below the
+//! new minimum are given `MinCandidateStkGracePeriod` rounds to top it up with
+//! `candidate_bond_more`. Candidates still below the minimum once their deadline is reached are
+//! removed from the candidate pool, as if they had called `go_offline`.
+
+use crate::pallet::{
+	BalanceOf, CandidateBondTopUpDeadline, CandidateInfo, CandidatePool, Config, Event,
+	MinCandidateStkSnapshot, Pallet, RoundIndex,
+};
+use crate::types::Bond;
+use frame_support::{pallet_prelude::Weight, traits::Get};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Gives the candidates below `MinCandidateStk` a deadline to top up their self-bond, if
+	/// it was raised since the last check.
+	pub(crate) fn schedule_bond_top_ups(now: RoundIndex) -> Weight {
+		let min_bond = T::MinCandidateStk::get();
+		let previous_min_bond = <MinCandidateStkSnapshot<T>>::get();
+		// read: MinCandidateStkSnapshot
+		let mut weight = T::DbWeight::get().reads(1);
+		if previous_min_bond == min_bond {
+			return weight;
+		}
+		<MinCandidateStkSnapshot<T>>::put(min_bond);
+		weight = weight.saturating_add(T::DbWeight::get().writes(1));
+		// the minimum was unknown until now, no candidate had to comply with a lower one
+		if previous_min_bond.is_zero() || min_bond < previous_min_bond {
+			return weight;
+		}
+
+		let deadline = now.saturating_add(T::MinCandidateStkGracePeriod::get());
+		for (candidate, state) in <CandidateInfo<T>>::iter() {
+			weight = weight.saturating_add(T::DbWeight::get().reads(1));
+			if state.bond >= min_bond || state.is_leaving() {
+				continue;
+			}
+
+			<CandidateBondTopUpDeadline<T>>::insert(&candidate, deadline);
+			weight = weight.saturating_add(T::DbWeight::get().writes(1));
+			Self::deposit_event(Event::CandidateBondBelowMin {
+				candidate,
+				bond: state.bond,
+				min_bond,
+				top_up_deadline: deadline,
+			});
+		}
+
+		weight
+	}
+
+	/// Clears the top-up deadline of a candidate whose self-bond reached `MinCandidateStk`.
+	pub(crate) fn note_candidate_bond(candidate: &T::AccountId, bond: BalanceOf<T>) {
+		if bond >= T::MinCandidateStk::get() {
+			<CandidateBondTopUpDeadline<T>>::remove(candidate);
+		}
+	}
+
+	/// Removes from the candidate pool the candidates which did not top up their self-bond
+	/// before their deadline.
+	pub(crate) fn kick_candidates_below_min_bond(now: RoundIndex) -> Weight {
+		let due: Vec<_> = <CandidateBondTopUpDeadline<T>>::iter()
+			.filter(|(_, deadline)| *deadline <= now)
+			.map(|(candidate, _)| candidate)
+			.collect();
+		let mut weight = T::DbWeight::get().reads(due.len() as u64 + 1);
+		if due.is_empty() {
+			return weight;
+		}
+
+		let min_bond = T::MinCandidateStk::get();
+		let mut candidates = <CandidatePool<T>>::get();
+		for candidate in due {
+			<CandidateBondTopUpDeadline<T>>::remove(&candidate);
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+			let Some(mut state) = <CandidateInfo<T>>::get(&candidate) else {
+				continue;
+			};
+			if state.bond >= min_bond || !state.is_active() {
+				continue;
+			}
+
+			let bond = state.bond;
+			state.go_offline();
+			candidates.remove(&Bond::from_owner(candidate.clone()));
+			<CandidateInfo<T>>::insert(&candidate, state);
+			weight = weight.saturating_add(T::DbWeight::get().writes(1));
+			Self::deposit_event(Event::CandidateKickedBelowMinBond {
+				candidate,
+				bond,
+				min_bond,
+			});
+		}
+		<CandidatePool<T>>::put(candidates);
+
+		weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+}
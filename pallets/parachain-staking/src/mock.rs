@@ -121,9 +121,13 @@ parameter_types! {
 	pub const MaxTopDelegationsPerCandidate: u32 = 4;
 	pub const MaxBottomDelegationsPerCandidate: u32 = 4;
 	pub const MaxDelegationsPerDelegator: u32 = 4;
-	pub const MinCandidateStk: u128 = 10;
+	pub static MinCandidateStk: u128 = 10;
+	pub const MinCandidateStkGracePeriod: u32 = 2;
 	pub const MinDelegation: u128 = 3;
 	pub const MaxCandidates: u32 = 200;
+	pub const MaxDelegationSwitchesPerRound: u32 = 2;
+	// Disabled by default so that scheduled requests are executed explicitly
+	pub static MaxExitQueueWeight: Weight = Weight::zero();
 }
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -141,6 +145,7 @@ impl Config for Test {
 	type MaxBottomDelegationsPerCandidate = MaxBottomDelegationsPerCandidate;
 	type MaxDelegationsPerDelegator = MaxDelegationsPerDelegator;
 	type MinCandidateStk = MinCandidateStk;
+	type MinCandidateStkGracePeriod = MinCandidateStkGracePeriod;
 	type MinDelegation = MinDelegation;
 	type BlockAuthor = BlockAuthor;
 	type OnCollatorPayout = ();
@@ -148,6 +153,8 @@ impl Config for Test {
 	type OnNewRound = ();
 	type WeightInfo = ();
 	type MaxCandidates = MaxCandidates;
+	type MaxExitQueueWeight = MaxExitQueueWeight;
+	type MaxDelegationSwitchesPerRound = MaxDelegationSwitchesPerRound;
 }
 
 pub(crate) struct ExtBuilder {
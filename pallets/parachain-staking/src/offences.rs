@@ -0,0 +1,132 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Offences for collators missing their block production slots
+//!
+//! At every round change, each collator selected for the ending round is expected to have
+//! produced its share of the round blocks. Collators falling below the governance configured
+//! share are penalized, either by a deduction of their awarded points for the round or by a
+//! temporary exclusion from the collator selection.
+
+use crate::pallet::{
+	AwardedPts, Config, Event, OffenceConfiguration, Pallet, Points, RoundIndex,
+	SuspendedCandidates,
+};
+use crate::POINTS_PER_BLOCK;
+use frame_support::{pallet_prelude::Weight, traits::Get, RuntimeDebug};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	PerThing, Percent,
+};
+use sp_std::prelude::*;
+
+/// Penalty applied to a collator missing its block production slots.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+pub enum OffencePenalty {
+	/// The offence is only reported.
+	#[default]
+	None,
+	/// The given percent of the points awarded in the round is deducted.
+	PointsDeduction(Percent),
+	/// The collator is excluded from the selection for the given number of rounds.
+	Suspension(RoundIndex),
+}
+
+/// Missed block production offence configuration.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+pub struct OffenceConfig {
+	/// Minimum share of its expected blocks a collator must produce during a round. Offences are
+	/// disabled when zero.
+	pub min_production: Percent,
+	/// Penalty applied to the offenders.
+	pub penalty: OffencePenalty,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether the candidate is currently excluded from the collator selection.
+	pub fn is_suspended(candidate: &T::AccountId) -> bool {
+		<SuspendedCandidates<T>>::contains_key(candidate)
+	}
+
+	/// Penalizes the collators of the ended `round`, lasting `round_length` blocks, which did not
+	/// produce enough blocks, and lifts the suspensions ending at `round + 1`.
+	pub(crate) fn handle_missed_blocks(round: RoundIndex, round_length: u32) -> Weight {
+		// read: OffenceConfiguration
+		let mut weight = T::DbWeight::get().reads(1);
+
+		let next_round = round.saturating_add(1);
+		let lifted: Vec<_> = <SuspendedCandidates<T>>::iter()
+			.filter(|(_, until)| *until <= next_round)
+			.map(|(candidate, _)| candidate)
+			.collect();
+		weight = weight.saturating_add(
+			T::DbWeight::get().reads_writes(lifted.len() as u64 + 1, lifted.len() as u64),
+		);
+		for candidate in lifted {
+			<SuspendedCandidates<T>>::remove(candidate);
+		}
+
+		let config = <OffenceConfiguration<T>>::get();
+		if config.min_production.is_zero() {
+			return weight;
+		}
+
+		let collators = Self::selected_candidates();
+		if collators.is_empty() {
+			return weight;
+		}
+		let blocks_expected = round_length / collators.len() as u32;
+		let blocks_required = config.min_production.mul_floor(blocks_expected);
+
+		// read: SelectedCandidates
+		weight = weight.saturating_add(T::DbWeight::get().reads(1));
+		for collator in collators {
+			let points = <AwardedPts<T>>::get(round, &collator);
+			weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+			let blocks_produced = points / POINTS_PER_BLOCK;
+			if blocks_produced >= blocks_required {
+				continue;
+			}
+
+			match config.penalty {
+				OffencePenalty::None => (),
+				OffencePenalty::PointsDeduction(percent) => {
+					let deducted = percent.mul_floor(points);
+					<AwardedPts<T>>::insert(round, &collator, points.saturating_sub(deducted));
+					<Points<T>>::mutate(round, |total| *total = total.saturating_sub(deducted));
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+				}
+				OffencePenalty::Suspension(rounds) => {
+					<SuspendedCandidates<T>>::insert(&collator, next_round.saturating_add(rounds));
+					weight = weight.saturating_add(T::DbWeight::get().writes(1));
+				}
+			}
+
+			Self::deposit_event(Event::CollatorOffence {
+				collator,
+				round,
+				blocks_produced,
+				blocks_expected,
+				penalty: config.penalty,
+			});
+		}
+
+		weight
+	}
+}
@@ -25,15 +25,22 @@
 use crate::auto_compound::{AutoCompoundConfig, AutoCompoundDelegations};
 use crate::delegation_requests::{CancelledScheduledRequest, DelegationAction, ScheduledRequest};
 use crate::mock::{
-	roll_blocks, roll_to, roll_to_round_begin, roll_to_round_end, set_author, Balances,
-	BlockNumber, ExtBuilder, ParachainStaking, RuntimeOrigin, Test,
+	events, roll_blocks, roll_to, roll_to_round_begin, roll_to_round_end, set_author, Balances,
+	BlockNumber, ExtBuilder, MaxExitQueueWeight, MinCandidateStk, ParachainStaking, RuntimeOrigin,
+	Test,
 };
+use crate::weights::WeightInfo;
 use crate::{
 	assert_events_emitted, assert_events_emitted_match, assert_events_eq, assert_no_events,
-	AtStake, Bond, CollatorStatus, DelegationScheduledRequests, DelegatorAdded, Error, Event,
-	Range, DELEGATOR_LOCK_ID,
+	AtStake, AwardedPts, Bond, CollatorStatus, DelegationScheduledRequests, DelegatorAdded, Error,
+	Event, OffenceConfig, OffencePenalty, Points, Range, DELEGATOR_LOCK_ID,
+};
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::{constants::RocksDbWeight, Weight},
+	BoundedVec,
 };
-use frame_support::{assert_err, assert_noop, assert_ok, BoundedVec};
 use sp_runtime::{traits::Zero, DispatchError, ModuleError, Perbill, Percent};
 
 // ~~ ROOT ~~
@@ -353,6 +360,109 @@ fn round_immediately_jumps_if_current_duration_exceeds_new_blocks_per_round() {
 		});
 }
 
+// SET OFFENCE CONFIG
+
+#[test]
+fn set_offence_config_event_emits_correctly() {
+	ExtBuilder::default().build().execute_with(|| {
+		let config = OffenceConfig {
+			min_production: Percent::from_percent(50),
+			penalty: OffencePenalty::Suspension(1),
+		};
+		assert_ok!(ParachainStaking::set_offence_config(
+			RuntimeOrigin::root(),
+			config
+		));
+		assert_events_eq!(Event::OffenceConfigSet {
+			old: OffenceConfig::default(),
+			new: config,
+		});
+		assert_eq!(ParachainStaking::offence_config(), config);
+	});
+}
+
+#[test]
+fn cannot_set_same_offence_config() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ParachainStaking::set_offence_config(RuntimeOrigin::root(), OffenceConfig::default()),
+			Error::<Test>::NoWritingSameValue
+		);
+	});
+}
+
+#[test]
+fn missed_blocks_deduct_awarded_points() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 20)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_offence_config(
+				RuntimeOrigin::root(),
+				OffenceConfig {
+					min_production: Percent::from_percent(100),
+					penalty: OffencePenalty::PointsDeduction(Percent::from_percent(50)),
+				}
+			));
+			// 2 blocks expected per collator
+			set_author(1, 1, 40);
+			set_author(1, 2, 20);
+
+			roll_to_round_begin(2);
+			assert_events_emitted!(Event::CollatorOffence {
+				collator: 2,
+				round: 1,
+				blocks_produced: 1,
+				blocks_expected: 2,
+				penalty: OffencePenalty::PointsDeduction(Percent::from_percent(50)),
+			});
+			assert_eq!(<AwardedPts<Test>>::get(1, 1), 40);
+			assert_eq!(<AwardedPts<Test>>::get(1, 2), 10);
+			assert_eq!(<Points<Test>>::get(1), 50);
+		});
+}
+
+#[test]
+fn missed_blocks_suspend_collator_from_selection() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 20)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_offence_config(
+				RuntimeOrigin::root(),
+				OffenceConfig {
+					min_production: Percent::from_percent(50),
+					penalty: OffencePenalty::Suspension(1),
+				}
+			));
+			set_author(1, 1, 40);
+
+			roll_to_round_begin(2);
+			assert_events_emitted!(Event::CollatorOffence {
+				collator: 2,
+				round: 1,
+				blocks_produced: 0,
+				blocks_expected: 2,
+				penalty: OffencePenalty::Suspension(1),
+			});
+			assert!(ParachainStaking::is_suspended(&2));
+			assert_eq!(
+				ParachainStaking::selected_candidates().into_inner(),
+				vec![1]
+			);
+
+			set_author(2, 1, 100);
+			roll_to_round_begin(3);
+			assert!(!ParachainStaking::is_suspended(&2));
+			assert_eq!(
+				ParachainStaking::selected_candidates().into_inner(),
+				vec![1, 2]
+			);
+		});
+}
+
 // ~~ MONETARY GOVERNANCE ~~
 
 #[test]
@@ -676,6 +786,126 @@ fn cannot_set_same_parachain_bond_reserve_percent() {
 	});
 }
 
+// SET INFLATION DISTRIBUTION CONFIG
+
+fn inflation_distribution_config(
+	accounts: Vec<(u64, u8)>,
+) -> crate::InflationDistributionConfig<u64> {
+	accounts
+		.into_iter()
+		.map(|(account, percent)| crate::InflationDistributionAccount {
+			account,
+			percent: Percent::from_percent(percent),
+		})
+		.collect::<Vec<_>>()
+		.try_into()
+		.expect("within bounds")
+}
+
+#[test]
+fn set_inflation_distribution_config_event_emits_correctly() {
+	ExtBuilder::default().build().execute_with(|| {
+		let new = inflation_distribution_config(vec![(11, 20), (12, 10)]);
+		assert_ok!(ParachainStaking::set_inflation_distribution_config(
+			RuntimeOrigin::root(),
+			new.clone()
+		));
+		assert_events_eq!(Event::InflationDistributionConfigUpdated {
+			old: inflation_distribution_config(vec![(0, 30)]),
+			new,
+		});
+	});
+}
+
+#[test]
+fn set_inflation_distribution_config_storage_updates_correctly() {
+	ExtBuilder::default().build().execute_with(|| {
+		let new = inflation_distribution_config(vec![(11, 20), (12, 10)]);
+		assert_ok!(ParachainStaking::set_inflation_distribution_config(
+			RuntimeOrigin::root(),
+			new.clone()
+		));
+		assert_eq!(ParachainStaking::inflation_distribution_info(), new);
+		assert_eq!(ParachainStaking::parachain_bond_info().account, 11);
+		assert_eq!(
+			ParachainStaking::parachain_bond_info().percent,
+			Percent::from_percent(20)
+		);
+	});
+}
+
+#[test]
+fn cannot_set_inflation_distribution_config_above_100_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ParachainStaking::set_inflation_distribution_config(
+				RuntimeOrigin::root(),
+				inflation_distribution_config(vec![(11, 60), (12, 41)])
+			),
+			Error::<Test>::TotalInflationDistributionPercentExceeds100
+		);
+	});
+}
+
+#[test]
+fn cannot_set_parachain_bond_reserve_percent_above_remaining_distribution() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ParachainStaking::set_inflation_distribution_config(
+			RuntimeOrigin::root(),
+			inflation_distribution_config(vec![(11, 20), (12, 70)])
+		));
+		assert_noop!(
+			ParachainStaking::set_parachain_bond_reserve_percent(
+				RuntimeOrigin::root(),
+				Percent::from_percent(31)
+			),
+			Error::<Test>::TotalInflationDistributionPercentExceeds100
+		);
+	});
+}
+
+#[test]
+fn set_parachain_bond_account_keeps_other_distribution_accounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ParachainStaking::set_inflation_distribution_config(
+			RuntimeOrigin::root(),
+			inflation_distribution_config(vec![(11, 20), (12, 10)])
+		));
+		assert_ok!(ParachainStaking::set_parachain_bond_account(
+			RuntimeOrigin::root(),
+			13
+		));
+		assert_eq!(
+			ParachainStaking::inflation_distribution_info(),
+			inflation_distribution_config(vec![(13, 20), (12, 10)])
+		);
+	});
+}
+
+#[test]
+fn migrate_parachain_bond_config_moves_it_to_first_distribution_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		crate::InflationDistributionInfo::<Test>::kill();
+		frame_support::storage::unhashed::put(
+			&frame_support::storage::storage_prefix(b"ParachainStaking", b"ParachainBondInfo"),
+			&crate::ParachainBondConfig {
+				account: 11u64,
+				percent: Percent::from_percent(25),
+			},
+		);
+
+		crate::migrations::MigrateParachainBondConfig::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			ParachainStaking::inflation_distribution_info(),
+			inflation_distribution_config(vec![(11, 25)])
+		);
+		assert!(!frame_support::storage::unhashed::exists(
+			&frame_support::storage::storage_prefix(b"ParachainStaking", b"ParachainBondInfo")
+		));
+	});
+}
+
 // ~~ PUBLIC ~~
 
 // JOIN CANDIDATES
@@ -1190,6 +1420,108 @@ fn execute_leave_candidates_removes_pending_delegation_requests() {
 		});
 }
 
+#[test]
+fn force_leave_candidates_requires_root() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 10)])
+		.with_candidates(vec![(1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::force_leave_candidates(RuntimeOrigin::signed(1), 1, 1, 0),
+				DispatchError::BadOrigin
+			);
+		});
+}
+
+#[test]
+fn force_leave_candidates_removes_candidate_and_delegations_immediately() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 10), (2, 15)])
+		.with_candidates(vec![(1, 10)])
+		.with_delegations(vec![(2, 1, 15)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				5
+			));
+			assert_noop!(
+				ParachainStaking::force_leave_candidates(RuntimeOrigin::root(), 1, 1, 0),
+				Error::<Test>::TooLowCandidateDelegationCountToLeaveCandidates
+			);
+			assert_noop!(
+				ParachainStaking::force_leave_candidates(RuntimeOrigin::root(), 1, 0, 1),
+				Error::<Test>::TooLowCandidateCountToLeaveCandidates
+			);
+
+			assert_ok!(ParachainStaking::force_leave_candidates(
+				RuntimeOrigin::root(),
+				1,
+				1,
+				1
+			));
+			assert!(ParachainStaking::candidate_info(1).is_none());
+			assert!(ParachainStaking::candidate_pool().0.is_empty());
+			assert!(ParachainStaking::delegator_state(2).is_none());
+			assert!(!<DelegationScheduledRequests<Test>>::contains_key(&1));
+			assert_events_emitted!(Event::CandidateLeft {
+				ex_candidate: 1,
+				unlocked_amount: 25,
+				new_total_amt_locked: 0
+			});
+		});
+}
+
+#[test]
+fn force_cancel_delegation_request_requires_root() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 10)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(
+				RuntimeOrigin::signed(2),
+				1
+			));
+			assert_noop!(
+				ParachainStaking::force_cancel_delegation_request(RuntimeOrigin::signed(1), 2, 1),
+				DispatchError::BadOrigin
+			);
+		});
+}
+
+#[test]
+fn force_cancel_delegation_request_cancels_delegator_request() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 10)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(
+				RuntimeOrigin::signed(2),
+				1
+			));
+			assert_ok!(ParachainStaking::force_cancel_delegation_request(
+				RuntimeOrigin::root(),
+				2,
+				1
+			));
+			assert!(ParachainStaking::delegation_scheduled_requests(&1).is_empty());
+			assert_events_emitted!(Event::CancelledDelegationRequest {
+				delegator: 2,
+				collator: 1,
+				cancelled_request: CancelledScheduledRequest {
+					when_executable: 3,
+					action: DelegationAction::Revoke(10),
+				},
+			});
+		});
+}
+
 #[test]
 fn cannot_execute_leave_candidates_before_delay() {
 	ExtBuilder::default()
@@ -1351,6 +1683,86 @@ fn cannot_go_offline_if_already_offline() {
 		});
 }
 
+// MIN CANDIDATE STAKE GRACE PERIOD
+
+#[test]
+fn raising_min_candidate_stk_schedules_bond_top_up() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20), (2, 30)])
+		.build()
+		.execute_with(|| {
+			MinCandidateStk::set(25);
+			ParachainStaking::on_runtime_upgrade();
+			assert_events_eq!(Event::CandidateBondBelowMin {
+				candidate: 1,
+				bond: 20,
+				min_bond: 25,
+				top_up_deadline: 3,
+			});
+			assert_eq!(ParachainStaking::candidate_bond_top_up_deadline(1), Some(3));
+			assert_eq!(ParachainStaking::candidate_bond_top_up_deadline(2), None);
+		});
+}
+
+#[test]
+fn candidate_below_min_bond_is_kicked_after_deadline() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20), (2, 30)])
+		.build()
+		.execute_with(|| {
+			MinCandidateStk::set(25);
+			ParachainStaking::on_runtime_upgrade();
+
+			roll_to_round_end(2);
+			assert!(ParachainStaking::candidate_pool()
+				.0
+				.iter()
+				.any(|bond| bond.owner == 1));
+
+			roll_to_round_begin(3);
+			assert_events_emitted!(Event::CandidateKickedBelowMinBond {
+				candidate: 1,
+				bond: 20,
+				min_bond: 25,
+			});
+			assert!(!ParachainStaking::candidate_pool()
+				.0
+				.iter()
+				.any(|bond| bond.owner == 1));
+			assert_eq!(ParachainStaking::candidate_bond_top_up_deadline(1), None);
+			assert_noop!(
+				ParachainStaking::go_online(RuntimeOrigin::signed(1)).map_err(|err| err.error),
+				Error::<Test>::CandidateBondBelowMin
+			);
+		});
+}
+
+#[test]
+fn candidate_topping_up_bond_is_not_kicked() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20), (2, 30)])
+		.build()
+		.execute_with(|| {
+			MinCandidateStk::set(25);
+			ParachainStaking::on_runtime_upgrade();
+
+			assert_ok!(ParachainStaking::candidate_bond_more(
+				RuntimeOrigin::signed(1),
+				5
+			));
+			assert_eq!(ParachainStaking::candidate_bond_top_up_deadline(1), None);
+
+			roll_to_round_begin(3);
+			assert!(ParachainStaking::candidate_pool()
+				.0
+				.iter()
+				.any(|bond| bond.owner == 1));
+		});
+}
+
 // GO ONLINE
 
 #[test]
@@ -3584,6 +3996,301 @@ fn cancel_delegator_bond_less_updates_delegator_state() {
 		});
 }
 
+// SWITCH DELEGATION
+
+#[test]
+fn switch_delegation_moves_whole_delegation_without_unbonding() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 10), (3, 30)])
+		.with_candidates(vec![(1, 30), (3, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::switch_delegation(
+				RuntimeOrigin::signed(2),
+				1,
+				3,
+				10
+			));
+			assert_events_eq!(Event::DelegationSwitched {
+				delegator: 2,
+				old_candidate: 1,
+				new_candidate: 3,
+				amount: 10,
+				in_top: true,
+			});
+			let state = ParachainStaking::delegator_state(2).expect("exists");
+			assert_eq!(state.get_bond_amount(&1), None);
+			assert_eq!(state.get_bond_amount(&3), Some(10));
+			assert_eq!(state.total(), 10);
+			assert_eq!(
+				ParachainStaking::candidate_info(1)
+					.expect("exists")
+					.total_counted,
+				30
+			);
+			assert_eq!(
+				ParachainStaking::candidate_info(3)
+					.expect("exists")
+					.total_counted,
+				40
+			);
+			assert_eq!(ParachainStaking::total(), 70);
+			assert_eq!(ParachainStaking::get_delegator_stakable_free_balance(&2), 0);
+		});
+}
+
+#[test]
+fn switch_delegation_moves_part_of_delegation_to_existing_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20), (3, 30)])
+		.with_candidates(vec![(1, 30), (3, 30)])
+		.with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::switch_delegation(
+				RuntimeOrigin::signed(2),
+				1,
+				3,
+				5
+			));
+			let state = ParachainStaking::delegator_state(2).expect("exists");
+			assert_eq!(state.get_bond_amount(&1), Some(5));
+			assert_eq!(state.get_bond_amount(&3), Some(15));
+			assert_eq!(state.total(), 20);
+			assert_eq!(
+				ParachainStaking::candidate_info(1)
+					.expect("exists")
+					.total_counted,
+				35
+			);
+			assert_eq!(
+				ParachainStaking::candidate_info(3)
+					.expect("exists")
+					.total_counted,
+				45
+			);
+		});
+}
+
+#[test]
+fn switch_delegation_moves_auto_compound_config_with_whole_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 10), (3, 30)])
+		.with_candidates(vec![(1, 30), (3, 30)])
+		.with_auto_compounding_delegations(vec![(2, 1, 10, Percent::from_percent(50))])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::switch_delegation(
+				RuntimeOrigin::signed(2),
+				1,
+				3,
+				10
+			));
+			assert_eq!(
+				ParachainStaking::delegation_auto_compound(&1, &2),
+				Percent::zero()
+			);
+			assert_eq!(
+				ParachainStaking::delegation_auto_compound(&3, &2),
+				Percent::from_percent(50)
+			);
+		});
+}
+
+#[test]
+fn cannot_switch_delegation_to_same_candidate() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 10)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::switch_delegation(RuntimeOrigin::signed(2), 1, 1, 10),
+				Error::<Test>::CannotSwitchToSameCandidate
+			);
+		});
+}
+
+#[test]
+fn cannot_switch_more_than_delegated() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20), (3, 30)])
+		.with_candidates(vec![(1, 30), (3, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::switch_delegation(RuntimeOrigin::signed(2), 1, 3, 11),
+				Error::<Test>::SwitchAmountExceedsDelegation
+			);
+		});
+}
+
+#[test]
+fn cannot_switch_delegation_leaving_less_than_min() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 10), (3, 30)])
+		.with_candidates(vec![(1, 30), (3, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::switch_delegation(RuntimeOrigin::signed(2), 1, 3, 8),
+				Error::<Test>::DelegationBelowMin
+			);
+		});
+}
+
+#[test]
+fn cannot_switch_delegation_with_pending_request() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 10), (3, 30)])
+		.with_candidates(vec![(1, 30), (3, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				3
+			));
+			assert_noop!(
+				ParachainStaking::switch_delegation(RuntimeOrigin::signed(2), 1, 3, 5),
+				Error::<Test>::PendingDelegationRequestAlreadyExists
+			);
+		});
+}
+
+#[test]
+fn switch_delegation_is_limited_per_round() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 10), (3, 30)])
+		.with_candidates(vec![(1, 30), (3, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::switch_delegation(
+				RuntimeOrigin::signed(2),
+				1,
+				3,
+				10
+			));
+			assert_ok!(ParachainStaking::switch_delegation(
+				RuntimeOrigin::signed(2),
+				3,
+				1,
+				10
+			));
+			assert_noop!(
+				ParachainStaking::switch_delegation(RuntimeOrigin::signed(2), 1, 3, 10),
+				Error::<Test>::TooManyDelegationSwitches
+			);
+
+			roll_to_round_begin(2);
+			assert_ok!(ParachainStaking::switch_delegation(
+				RuntimeOrigin::signed(2),
+				1,
+				3,
+				10
+			));
+		});
+}
+
+// EXIT QUEUE
+
+#[test]
+fn exit_queue_executes_due_revocations() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			MaxExitQueueWeight::set(Weight::MAX);
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(
+				RuntimeOrigin::signed(2),
+				1
+			));
+			assert_eq!(ParachainStaking::exit_queue_position(&2, &1), Some(0));
+
+			roll_to_round_end(2);
+			assert!(ParachainStaking::is_delegator(&2));
+
+			roll_to_round_begin(3);
+			assert!(!ParachainStaking::is_delegator(&2));
+			assert_eq!(ParachainStaking::exit_queue_position(&2, &1), None);
+			assert_events_emitted!(Event::DelegationRevoked {
+				delegator: 2,
+				candidate: 1,
+				unstaked_amount: 10,
+			});
+		});
+}
+
+#[test]
+fn exit_queue_is_drained_within_weight_cap() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20), (3, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10), (3, 1, 10)])
+		.build()
+		.execute_with(|| {
+			let db_weight = RocksDbWeight::get();
+			MaxExitQueueWeight::set(
+				db_weight
+					.reads(2)
+					.saturating_add(db_weight.reads_writes(2, 2))
+					.saturating_add(
+						<() as WeightInfo>::execute_delegator_revoke_delegation_worst(),
+					),
+			);
+			for delegator in [2, 3] {
+				assert_ok!(ParachainStaking::schedule_revoke_delegation(
+					RuntimeOrigin::signed(delegator),
+					1
+				));
+			}
+			assert_eq!(ParachainStaking::exit_queue_position(&3, &1), Some(1));
+
+			roll_to_round_begin(3);
+			assert!(!ParachainStaking::is_delegator(&2));
+			assert!(ParachainStaking::is_delegator(&3));
+			assert_eq!(ParachainStaking::exit_queue_position(&3, &1), Some(0));
+
+			roll_blocks(1);
+			assert!(!ParachainStaking::is_delegator(&3));
+		});
+}
+
+#[test]
+fn exit_queue_skips_cancelled_revocations() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20), (3, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10), (3, 1, 10)])
+		.build()
+		.execute_with(|| {
+			MaxExitQueueWeight::set(Weight::MAX);
+			for delegator in [2, 3] {
+				assert_ok!(ParachainStaking::schedule_revoke_delegation(
+					RuntimeOrigin::signed(delegator),
+					1
+				));
+			}
+			assert_ok!(ParachainStaking::cancel_delegation_request(
+				RuntimeOrigin::signed(2),
+				1
+			));
+			assert_eq!(ParachainStaking::exit_queue_position(&2, &1), None);
+
+			roll_to_round_begin(3);
+			assert!(ParachainStaking::is_delegator(&2));
+			assert!(!ParachainStaking::is_delegator(&3));
+		});
+}
+
 // ~~ PROPERTY-BASED TESTS ~~
 
 #[test]
@@ -3664,6 +4371,42 @@ fn delegator_schedule_revocation_total() {
 }
 
 #[ignore]
+#[test]
+fn round_inflation_is_distributed_to_every_distribution_account() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (11, 1), (12, 1)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_inflation_distribution_config(
+				RuntimeOrigin::root(),
+				inflation_distribution_config(vec![(11, 20), (12, 10)])
+			));
+			roll_to_round_begin(2);
+			set_author(2, 1, 100);
+			roll_to_round_begin(4);
+
+			let distributed: Vec<_> = events()
+				.into_iter()
+				.filter_map(|e| match e {
+					Event::InflationDistributed {
+						index,
+						account,
+						value,
+					} => Some((index, account, value)),
+					_ => None,
+				})
+				.collect();
+			assert_eq!(distributed.len(), 2);
+			assert_eq!((distributed[0].0, distributed[0].1), (0, 11));
+			assert_eq!((distributed[1].0, distributed[1].1), (1, 12));
+			assert_eq!(Balances::free_balance(&11), 1 + distributed[0].2);
+			assert_eq!(Balances::free_balance(&12), 1 + distributed[1].2);
+			assert!(distributed[0].2 > distributed[1].2);
+		});
+}
+
 #[test]
 fn parachain_bond_inflation_reserve_matches_config() {
 	ExtBuilder::default()
@@ -3753,7 +4496,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 				1,
 			));
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 15,
 				},
@@ -3816,7 +4560,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 			// fast forward to block in which delegator 6 exit executes
 			roll_to_round_begin(5);
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 16,
 				},
@@ -3878,7 +4623,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 				10
 			));
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 16,
 				},
@@ -3945,7 +4691,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 			);
 			roll_to_round_begin(7);
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 17,
 				},
@@ -4011,7 +4758,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 			roll_to_round_begin(8);
 			// keep paying 6
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 30,
 				},
@@ -4067,7 +4815,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 			roll_to_round_begin(9);
 			// no more paying 6
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 32,
 				},
@@ -4138,7 +4887,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 			roll_to_round_begin(10);
 			// new delegation is not rewarded yet
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 33,
 				},
@@ -4195,7 +4945,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 			roll_to_round_begin(11);
 			// new delegation is still not rewarded yet
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 35,
 				},
@@ -4250,7 +5001,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 			roll_to_round_begin(12);
 			// new delegation is rewarded, 2 rounds after joining (`RewardPaymentDelay` is 2)
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 37,
 				},
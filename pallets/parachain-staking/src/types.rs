@@ -1751,6 +1751,56 @@ impl<A: Decode> Default for ParachainBondConfig<A> {
 	}
 }
 
+/// Maximum number of accounts the round inflation can be distributed to.
+pub const MAX_INFLATION_DISTRIBUTION_ACCOUNTS: u32 = 4;
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// Account receiving a share of the round inflation { account, percent_of_inflation }
+pub struct InflationDistributionAccount<AccountId> {
+	/// Account which receives funds
+	pub account: AccountId,
+	/// Percent of inflation set aside for the account
+	pub percent: Percent,
+}
+
+impl<A> From<ParachainBondConfig<A>> for InflationDistributionAccount<A> {
+	fn from(bond: ParachainBondConfig<A>) -> Self {
+		InflationDistributionAccount {
+			account: bond.account,
+			percent: bond.percent,
+		}
+	}
+}
+
+impl<A> From<InflationDistributionAccount<A>> for ParachainBondConfig<A> {
+	fn from(account: InflationDistributionAccount<A>) -> Self {
+		ParachainBondConfig {
+			account: account.account,
+			percent: account.percent,
+		}
+	}
+}
+
+/// Accounts receiving a share of the round inflation before it is paid to the stakers. The first
+/// one is the parachain bond.
+pub type InflationDistributionConfig<AccountId> = BoundedVec<
+	InflationDistributionAccount<AccountId>,
+	ConstU32<MAX_INFLATION_DISTRIBUTION_ACCOUNTS>,
+>;
+
+/// Sum of the percents of the accounts, `None` if it exceeds 100%.
+pub fn inflation_distribution_total<A>(
+	accounts: &[InflationDistributionAccount<A>],
+) -> Option<Percent> {
+	accounts
+		.iter()
+		.try_fold(0u8, |total, account| {
+			total.checked_add(account.percent.deconstruct())
+		})
+		.filter(|total| *total <= 100)
+		.map(Percent::from_percent)
+}
+
 pub enum BondAdjust<Balance> {
 	Increase(Balance),
 	Decrease,
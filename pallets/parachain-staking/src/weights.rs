@@ -56,9 +56,11 @@ pub trait WeightInfo {
 	fn set_inflation() -> Weight;
 	fn set_parachain_bond_account() -> Weight;
 	fn set_parachain_bond_reserve_percent() -> Weight;
+	fn set_inflation_distribution_config() -> Weight;
 	fn set_total_selected() -> Weight;
 	fn set_collator_commission() -> Weight;
 	fn set_blocks_per_round() -> Weight;
+	fn set_offence_config() -> Weight;
 	fn join_candidates(x: u32, ) -> Weight;
 	fn schedule_leave_candidates(x: u32, ) -> Weight;
 	fn execute_leave_candidates_worst_case(x: u32, ) -> Weight;
@@ -87,6 +89,7 @@ pub trait WeightInfo {
 	fn set_auto_compound(x: u32, y: u32, ) -> Weight;
 	fn delegate_with_auto_compound(x: u32, y: u32, z: u32, ) -> Weight;
 	fn delegate_with_auto_compound_worst() -> Weight;
+	fn switch_delegation() -> Weight;
 	fn mint_collator_reward() -> Weight;
 }
 
@@ -137,6 +140,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_inflation_distribution_config() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(31_000_000, 1491)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: ParachainStaking TotalSelected (r:1 w:1)
 	/// Proof Skipped: ParachainStaking TotalSelected (max_values: Some(1), max_size: None, mode: Measured)
 	fn set_total_selected() -> Weight {
@@ -172,6 +183,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: ParachainStaking OffenceConfiguration (r:1 w:1)
+	/// Proof Skipped: ParachainStaking OffenceConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_offence_config() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(29_000_000, 1512)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainStaking DelegatorState (r:1 w:0)
@@ -860,6 +879,38 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(15_u64))
 			.saturating_add(T::DbWeight::get().writes(13_u64))
 	}
+	/// Storage: ParachainStaking DelegatorState (r:2 w:2)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:2 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:2 w:2)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Round (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationSwitches (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegationSwitches (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:2 w:2)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:2 w:2)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking BottomDelegations (r:2 w:2)
+	/// Proof Skipped: ParachainStaking BottomDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1287), added: 3762, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: Some(1), max_size: None, mode: Measured)
+	fn switch_delegation() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(840_000_000, 102274)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	fn mint_collator_reward() -> Weight {
@@ -919,6 +970,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_inflation_distribution_config() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(31_000_000, 1491)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: ParachainStaking TotalSelected (r:1 w:1)
 	/// Proof Skipped: ParachainStaking TotalSelected (max_values: Some(1), max_size: None, mode: Measured)
 	fn set_total_selected() -> Weight {
@@ -954,6 +1013,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: ParachainStaking OffenceConfiguration (r:1 w:1)
+	/// Proof Skipped: ParachainStaking OffenceConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_offence_config() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(29_000_000, 1512)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
 	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainStaking DelegatorState (r:1 w:0)
@@ -1642,6 +1709,38 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(15_u64))
 			.saturating_add(RocksDbWeight::get().writes(13_u64))
 	}
+	/// Storage: ParachainStaking DelegatorState (r:2 w:2)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:2 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:2 w:2)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Round (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationSwitches (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegationSwitches (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:2 w:2)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:2 w:2)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking BottomDelegations (r:2 w:2)
+	/// Proof Skipped: ParachainStaking BottomDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1287), added: 3762, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: Some(1), max_size: None, mode: Measured)
+	fn switch_delegation() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(840_000_000, 102274)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	fn mint_collator_reward() -> Weight {
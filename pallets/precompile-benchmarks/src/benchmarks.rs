@@ -0,0 +1,113 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+
+use crate::{handle::BenchmarkHandle, Config, Pallet};
+use fp_evm::PrecompileSet;
+use frame_benchmarking::{benchmarks, BenchmarkError};
+use frame_support::traits::Get;
+use precompile_utils::solidity::codec::{Address, Writer};
+use sp_core::H160;
+use sp_std::vec::Vec;
+
+/// `isPrecompile(address)`
+const IS_PRECOMPILE_SELECTOR: u32 = 0x446b450e;
+/// `isActivePrecompile(address)`
+const IS_ACTIVE_PRECOMPILE_SELECTOR: u32 = 0x6f5e23cf;
+/// `updateAccountCode(address)`
+const UPDATE_ACCOUNT_CODE_SELECTOR: u32 = 0x48ceb1b4;
+/// `isCandidate(address)`
+const IS_CANDIDATE_SELECTOR: u32 = 0xd51b9e93;
+/// `isDelegator(address)`
+const IS_DELEGATOR_SELECTOR: u32 = 0xfd8ab482;
+
+/// An address in the range of the foreign assets precompiles. Checking it requires the precompile
+/// set to read the assets storage, which is the most expensive lookup of the Moonbeam runtimes.
+fn asset_precompile_address() -> H160 {
+	let mut address = [0xff; 20];
+	address[4..].copy_from_slice(&[0u8; 16]);
+	address[19] = 1;
+	H160(address)
+}
+
+/// Execute the precompile at `address` through the precompile set of the runtime.
+fn execute<T: Config>(
+	address: H160,
+	input: Vec<u8>,
+	is_static: bool,
+) -> Result<Vec<u8>, BenchmarkError> {
+	let mut handle = BenchmarkHandle::new(address, input, is_static);
+	match <T as pallet_evm::Config>::PrecompilesValue::get().execute(&mut handle) {
+		Some(Ok(output)) => Ok(output.output),
+		_ => Err(BenchmarkError::Stop("precompile execution failed")),
+	}
+}
+
+benchmarks! {
+	precompile_registry_is_precompile {
+		let input = Writer::new_with_selector(IS_PRECOMPILE_SELECTOR)
+			.write(Address(asset_precompile_address()))
+			.build();
+	}: {
+		execute::<T>(T::PrecompileRegistryAddress::get(), input, true)?;
+	}
+
+	precompile_registry_is_active_precompile {
+		let input = Writer::new_with_selector(IS_ACTIVE_PRECOMPILE_SELECTOR)
+			.write(Address(asset_precompile_address()))
+			.build();
+	}: {
+		execute::<T>(T::PrecompileRegistryAddress::get(), input, true)?;
+	}
+
+	precompile_registry_update_account_code {
+		let registry = T::PrecompileRegistryAddress::get();
+		let input = Writer::new_with_selector(UPDATE_ACCOUNT_CODE_SELECTOR)
+			.write(Address(registry))
+			.build();
+		pallet_evm::AccountCodes::<T>::remove(registry);
+	}: {
+		execute::<T>(registry, input, false)?;
+	}
+	verify {
+		assert!(pallet_evm::AccountCodes::<T>::contains_key(registry));
+	}
+
+	// The staking views are called `n` times with the same account, as a contract would in a loop.
+	// Their proof size does not grow with `n`: the trie nodes of a key are only recorded once.
+	parachain_staking_is_candidate {
+		let n in 1 .. 100;
+		let input = Writer::new_with_selector(IS_CANDIDATE_SELECTOR)
+			.write(Address(H160::repeat_byte(0x02)))
+			.build();
+	}: {
+		for _ in 0..n {
+			execute::<T>(T::ParachainStakingAddress::get(), input.clone(), true)?;
+		}
+	}
+
+	parachain_staking_is_delegator {
+		let n in 1 .. 100;
+		let input = Writer::new_with_selector(IS_DELEGATOR_SELECTOR)
+			.write(Address(H160::repeat_byte(0x02)))
+			.build();
+	}: {
+		for _ in 0..n {
+			execute::<T>(T::ParachainStakingAddress::get(), input.clone(), true)?;
+		}
+	}
+}
@@ -0,0 +1,115 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use fp_evm::{Context, ExitError, ExitReason, PrecompileHandle, Transfer};
+use sp_core::{H160, H256};
+use sp_std::vec::Vec;
+
+/// Precompile handle used to execute precompiles outside of the EVM while benchmarking.
+///
+/// Gas is metered like in the EVM, but external costs are ignored since the benchmark measures
+/// them. Subcalls are not supported.
+pub(crate) struct BenchmarkHandle {
+	code_address: H160,
+	input: Vec<u8>,
+	context: Context,
+	is_static: bool,
+	gas_limit: u64,
+	gas_used: u64,
+}
+
+impl BenchmarkHandle {
+	pub(crate) fn new(code_address: H160, input: Vec<u8>, is_static: bool) -> Self {
+		Self {
+			code_address,
+			input,
+			context: Context {
+				address: code_address,
+				caller: H160::repeat_byte(0x01),
+				apparent_value: Default::default(),
+			},
+			is_static,
+			gas_limit: u64::MAX,
+			gas_used: 0,
+		}
+	}
+}
+
+impl PrecompileHandle for BenchmarkHandle {
+	fn call(
+		&mut self,
+		_address: H160,
+		_transfer: Option<Transfer>,
+		_input: Vec<u8>,
+		_target_gas: Option<u64>,
+		_is_static: bool,
+		_context: &Context,
+	) -> (ExitReason, Vec<u8>) {
+		(
+			ExitReason::Error(ExitError::Other("subcalls are not benchmarked".into())),
+			Vec::new(),
+		)
+	}
+
+	fn record_cost(&mut self, cost: u64) -> Result<(), ExitError> {
+		self.gas_used = self.gas_used.saturating_add(cost);
+
+		if self.gas_used > self.gas_limit {
+			Err(ExitError::OutOfGas)
+		} else {
+			Ok(())
+		}
+	}
+
+	fn remaining_gas(&self) -> u64 {
+		self.gas_limit.saturating_sub(self.gas_used)
+	}
+
+	fn log(&mut self, _address: H160, _topics: Vec<H256>, _data: Vec<u8>) -> Result<(), ExitError> {
+		Ok(())
+	}
+
+	fn code_address(&self) -> H160 {
+		self.code_address
+	}
+
+	fn input(&self) -> &[u8] {
+		&self.input
+	}
+
+	fn context(&self) -> &Context {
+		&self.context
+	}
+
+	fn is_static(&self) -> bool {
+		self.is_static
+	}
+
+	fn gas_limit(&self) -> Option<u64> {
+		Some(self.gas_limit)
+	}
+
+	fn record_external_cost(
+		&mut self,
+		_ref_time: Option<u64>,
+		_proof_size: Option<u64>,
+		_storage_growth: Option<u64>,
+	) -> Result<(), ExitError> {
+		Ok(())
+	}
+
+	fn refund_external_cost(&mut self, _ref_time: Option<u64>, _proof_size: Option<u64>) {}
+}
@@ -0,0 +1,61 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Precompile benchmarks pallet
+//!
+//! Benchmarking harness of the custom precompiles. This pallet has no storage nor calls: its
+//! benchmarks execute the precompiles through the precompile set of the runtime, so that the
+//! generated [`WeightInfo`] measures their actual ref time and proof size under each runtime.
+//!
+//! Precompiles read their cost from the `WeightInfo` of this pallet and convert it into gas with
+//! the gas to weight mapping of the runtime, instead of estimating it from a number of database
+//! accesses. Re-running the benchmarks after a dependency bump keeps their gas pricing accurate.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarks;
+#[cfg(feature = "runtime-benchmarks")]
+mod handle;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::pallet;
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use sp_core::H160;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_evm::Config {
+		/// Address of the precompile registry in the precompile set of the runtime.
+		type PrecompileRegistryAddress: Get<H160>;
+
+		/// Address of the parachain staking precompile in the precompile set of the runtime.
+		type ParachainStakingAddress: Get<H160>;
+
+		/// Weight of the benchmarked precompile functions.
+		type WeightInfo: WeightInfo;
+	}
+}
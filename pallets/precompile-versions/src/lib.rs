@@ -0,0 +1,89 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Precompile versions pallet
+//!
+//! This pallet stores the interface version of versioned precompiles (see
+//! `precompile_utils::precompile_set::VersionedPrecompile`) pinned by each caller.
+//!
+//! When the behavior of a precompile selector changes (e.g. the encoding of its return value),
+//! the new behavior is added as a new version of the precompile interface. Contracts keep being
+//! dispatched to version 1 until they pin another version, so that the contracts deployed before
+//! the change are not broken by it.
+//!
+//! Versions are pinned from the EVM, whose gas accounts for the storage write; this pallet thus
+//! exposes no extrinsic.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+
+use frame_support::pallet;
+use frame_support::pallet_prelude::*;
+use sp_core::H160;
+
+/// Max encoded length of a `PinnedVersions` entry:
+/// Blake2_128(16) + H160(20) + Blake2_128(16) + H160(20) + u32(4)
+pub const PINNED_VERSION_MAX_ENCODED_LEN: usize = 76;
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	}
+
+	/// Interface version pinned by a caller, for each versioned precompile.
+	#[pallet::storage]
+	#[pallet::getter(fn pinned_version)]
+	pub type PinnedVersions<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, H160, Blake2_128Concat, H160, u32, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A caller pinned the interface version of a precompile.
+		InterfaceVersionPinned {
+			precompile: H160,
+			caller: H160,
+			version: u32,
+		},
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Pin the interface version of `precompile` used by `caller`.
+		pub fn pin_version(precompile: H160, caller: H160, version: u32) {
+			PinnedVersions::<T>::insert(precompile, caller, version);
+
+			Self::deposit_event(Event::InterfaceVersionPinned {
+				precompile,
+				caller,
+				version,
+			});
+		}
+	}
+}
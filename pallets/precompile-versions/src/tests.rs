@@ -0,0 +1,66 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{Event, PinnedVersions, PINNED_VERSION_MAX_ENCODED_LEN};
+use parity_scale_codec::MaxEncodedLen;
+use sp_core::H160;
+
+#[test]
+fn pinned_version_max_encoded_len_is_accurate() {
+	assert_eq!(
+		PINNED_VERSION_MAX_ENCODED_LEN,
+		2 * (16 + H160::max_encoded_len()) + u32::max_encoded_len()
+	);
+}
+
+#[test]
+fn no_version_pinned_by_default() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(PrecompileVersions::pinned_version(PRECOMPILE, ALICE), None);
+	});
+}
+
+#[test]
+fn pin_version_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		PrecompileVersions::pin_version(PRECOMPILE, ALICE, 2);
+
+		assert_eq!(
+			PrecompileVersions::pinned_version(PRECOMPILE, ALICE),
+			Some(2)
+		);
+		assert_eq!(PrecompileVersions::pinned_version(PRECOMPILE, BOB), None);
+		assert_eq!(
+			events(),
+			vec![Event::InterfaceVersionPinned {
+				precompile: PRECOMPILE,
+				caller: ALICE,
+				version: 2,
+			}]
+		);
+	});
+}
+
+#[test]
+fn pin_version_overrides_previous_pin() {
+	ExtBuilder::default().build().execute_with(|| {
+		PrecompileVersions::pin_version(PRECOMPILE, ALICE, 2);
+		PrecompileVersions::pin_version(PRECOMPILE, ALICE, 1);
+
+		assert_eq!(PinnedVersions::<Test>::get(PRECOMPILE, ALICE), Some(1));
+	});
+}
@@ -0,0 +1,106 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Precompile warm keys pallet
+//!
+//! This pallet tracks the storage keys read by preloaded precompiles (see
+//! `precompile_utils::precompile_set::AccessListPreloaded`) in the current block.
+//!
+//! The trie nodes of a storage key are only recorded once per block in the proof of validity, so
+//! a precompile reading a key already read in the block doesn't need to pay for its proof again.
+//!
+//! The set of warm keys is written at the start of each block and removed at its end, so that it
+//! is never part of the state: reading it during the block is served by the overlay and adds
+//! nothing to the proof.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+
+use frame_support::pallet;
+use frame_support::pallet_prelude::*;
+use sp_core::H256;
+use sp_std::vec::Vec;
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Maximum number of keys tracked in a block. Once reached, further keys are considered
+		/// cold for the rest of the block.
+		#[pallet::constant]
+		type MaxWarmKeys: Get<u32>;
+	}
+
+	/// Hashes of the storage keys read by preloaded precompiles in the current block.
+	#[pallet::storage]
+	pub type WarmKeys<T: Config> =
+		StorageValue<_, BoundedBTreeSet<H256, T::MaxWarmKeys>, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			WarmKeys::<T>::put(BoundedBTreeSet::new());
+
+			// Written here and removed in `on_finalize`.
+			T::DbWeight::get().writes(2)
+		}
+
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			WarmKeys::<T>::kill();
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Has `key` been read by a preloaded precompile in the current block.
+		pub fn is_warm(key: &[u8]) -> bool {
+			WarmKeys::<T>::get().contains(&Self::key_hash(key))
+		}
+
+		/// Encoded size of the set of warm keys, without decoding it.
+		pub fn encoded_size() -> usize {
+			sp_io::storage::read(&WarmKeys::<T>::hashed_key(), &mut [], 0).unwrap_or(0) as usize
+		}
+
+		/// Mark `keys` as read in the current block, as long as there is room for them.
+		pub fn warm(keys: Vec<Vec<u8>>) {
+			WarmKeys::<T>::mutate(|warm_keys| {
+				for key in keys {
+					if warm_keys.try_insert(Self::key_hash(&key)).is_err() {
+						break;
+					}
+				}
+			});
+		}
+
+		// A collision-resistant hash is required: a key crafted to collide with another one would
+		// get the proof of the latter refunded.
+		fn key_hash(key: &[u8]) -> H256 {
+			H256(sp_io::hashing::blake2_256(key))
+		}
+	}
+}
@@ -0,0 +1,74 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::WarmKeys;
+use frame_support::traits::Hooks;
+
+#[test]
+fn keys_are_cold_by_default() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(!PrecompileWarmKeys::is_warm(b"key"));
+	});
+}
+
+#[test]
+fn warm_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		PrecompileWarmKeys::warm(vec![b"key".to_vec()]);
+
+		assert!(PrecompileWarmKeys::is_warm(b"key"));
+		assert!(!PrecompileWarmKeys::is_warm(b"other key"));
+	});
+}
+
+#[test]
+fn encoded_size_follows_the_warm_keys() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(PrecompileWarmKeys::encoded_size(), 0);
+
+		PrecompileWarmKeys::warm(vec![b"key1".to_vec(), b"key2".to_vec()]);
+
+		assert_eq!(PrecompileWarmKeys::encoded_size(), 1 + 2 * 32);
+	});
+}
+
+#[test]
+fn keys_beyond_the_bound_stay_cold() {
+	ExtBuilder::default().build().execute_with(|| {
+		PrecompileWarmKeys::warm(vec![b"key1".to_vec(), b"key2".to_vec(), b"key3".to_vec()]);
+
+		assert!(PrecompileWarmKeys::is_warm(b"key1"));
+		assert!(PrecompileWarmKeys::is_warm(b"key2"));
+		assert!(!PrecompileWarmKeys::is_warm(b"key3"));
+	});
+}
+
+#[test]
+fn warm_keys_are_removed_at_the_end_of_the_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		PrecompileWarmKeys::warm(vec![b"key".to_vec()]);
+
+		PrecompileWarmKeys::on_finalize(1);
+
+		assert!(!WarmKeys::<Test>::exists());
+
+		System::set_block_number(2);
+		PrecompileWarmKeys::on_initialize(2);
+
+		assert!(!PrecompileWarmKeys::is_warm(b"key"));
+	});
+}
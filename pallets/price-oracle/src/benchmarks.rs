@@ -0,0 +1,141 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+
+use crate::{Call, Config, FeedConfig, Feeds, LatestRound, Pallet, PendingRounds};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+const FEED_ID: u32 = 1;
+
+fn feeders<T: Config>(count: u32) -> BoundedVec<T::AccountId, T::MaxFeeders> {
+	(0..count)
+		.map(|index| account("feeder", index, 0))
+		.collect::<Vec<_>>()
+		.try_into()
+		.expect("count is bounded by MaxFeeders")
+}
+
+fn longest_description<T: Config>() -> BoundedVec<u8, T::MaxDescriptionLength> {
+	vec![b'a'; T::MaxDescriptionLength::get() as usize]
+		.try_into()
+		.expect("description is bounded by MaxDescriptionLength")
+}
+
+fn create_feed<T: Config>(feeders: BoundedVec<T::AccountId, T::MaxFeeders>) {
+	let min_submissions = feeders.len() as u32;
+	Feeds::<T>::insert(
+		FEED_ID,
+		FeedConfig {
+			decimals: 8,
+			description: longest_description::<T>(),
+			feeders,
+			min_submissions,
+		},
+	);
+}
+
+benchmarks! {
+	create_feed {
+		let x in 1..T::MaxFeeders::get();
+		let feeders = feeders::<T>(x);
+	}: _(RawOrigin::Root, FEED_ID, 8, longest_description::<T>(), feeders, x)
+	verify {
+		assert!(Pallet::<T>::feed(FEED_ID).is_some());
+	}
+
+	set_feeders {
+		let x in 1..T::MaxFeeders::get();
+		create_feed::<T>(feeders::<T>(1));
+		let feeders = feeders::<T>(x);
+	}: _(RawOrigin::Root, FEED_ID, feeders.clone(), x)
+	verify {
+		assert_eq!(Pallet::<T>::feed(FEED_ID).map(|feed| feed.feeders), Some(feeders));
+	}
+
+	remove_feed {
+		create_feed::<T>(feeders::<T>(T::MaxFeeders::get()));
+	}: _(RawOrigin::Root, FEED_ID)
+	verify {
+		assert!(Pallet::<T>::feed(FEED_ID).is_none());
+	}
+
+	// Worst case: the answer completes a round requiring the answers of all the feeders.
+	submit {
+		let x in 1..T::MaxFeeders::get();
+		let feeders = feeders::<T>(x);
+		create_feed::<T>(feeders.clone());
+		let (last, others) = feeders.split_last().expect("there is at least one feeder");
+		for (index, feeder) in others.iter().enumerate() {
+			Pallet::<T>::submit(
+				RawOrigin::Signed(feeder.clone()).into(),
+				FEED_ID,
+				index as i128,
+			)?;
+		}
+	}: _(RawOrigin::Signed(last.clone()), FEED_ID, x as i128)
+	verify {
+		assert!(LatestRound::<T>::contains_key(FEED_ID));
+		assert!(!PendingRounds::<T>::contains_key(FEED_ID));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::ExtBuilder;
+	use crate::Pallet;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn bench_create_feed() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_create_feed());
+		});
+	}
+
+	#[test]
+	fn bench_set_feeders() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_set_feeders());
+		});
+	}
+
+	#[test]
+	fn bench_remove_feed() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_remove_feed());
+		});
+	}
+
+	#[test]
+	fn bench_submit() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_submit());
+		});
+	}
+}
+
+impl_benchmark_test_suite!(
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
@@ -0,0 +1,425 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Price oracle pallet
+//!
+//! This pallet maintains price feeds, identified by a governance-chosen feed id, to which a
+//! whitelist of feeders submit answers. Once enough feeders submitted an answer to the current
+//! round, the median of their answers becomes the latest answer of the feed and a new round
+//! starts. The latest answer of each feed is exposed to smart contracts through a
+//! Chainlink-compatible precompile, such that protocols have a native price source on networks
+//! where external oracles are not deployed.
+//!
+//! Feeders are plain accounts, such that a feed can also be delivered over XCM by whitelisting
+//! the account from which the remote oracle dispatches its `Transact` calls.
+//!
+//! The feeds can also price XCM execution through [`RelativePriceFeed`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::{
+	pallet, pallet_prelude::*, traits::UnixTime, CloneNoBound, EqNoBound, PartialEqNoBound,
+	RuntimeDebugNoBound,
+};
+use sp_runtime::{FixedPointNumber, FixedU128};
+use sp_std::vec::Vec;
+
+/// Identifier of a price feed.
+pub type FeedId = u32;
+
+/// Configuration of a price feed.
+#[derive(
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	RuntimeDebugNoBound,
+)]
+#[scale_info(skip_type_params(MaxFeeders, MaxDescriptionLength))]
+pub struct FeedConfig<
+	AccountId: Clone + PartialEq + Eq + core::fmt::Debug,
+	MaxFeeders: Get<u32>,
+	MaxDescriptionLength: Get<u32>,
+> {
+	/// Number of decimals of the answers.
+	pub decimals: u8,
+	/// Description of the feed, such as "DOT / USD".
+	pub description: BoundedVec<u8, MaxDescriptionLength>,
+	/// Accounts allowed to submit answers.
+	pub feeders: BoundedVec<AccountId, MaxFeeders>,
+	/// Number of answers required to complete a round.
+	pub min_submissions: u32,
+}
+
+/// Answer of a completed round, with timestamps in seconds.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub struct RoundData {
+	/// Identifier of the round, starting at 1 and incremented with each completed round.
+	pub round_id: u64,
+	/// Median of the answers submitted during the round.
+	pub answer: i128,
+	/// Time at which the first answer of the round was submitted.
+	pub started_at: u64,
+	/// Time at which the round completed.
+	pub updated_at: u64,
+}
+
+/// Prices of assets relative to the native token read from the feeds, for
+/// `pallet-xcm-weight-trader`.
+///
+/// The feed of an asset must answer the price of the smallest unit of the native token in
+/// smallest units of the asset, with the decimals of the feed. Feeds without a completed round,
+/// or whose latest answer is negative, have no price.
+pub struct RelativePriceFeed<T>(PhantomData<T>);
+
+impl<T: Config> pallet_xcm_weight_trader::PriceFeed<FeedId> for RelativePriceFeed<T> {
+	fn relative_price(feed_id: &FeedId) -> Option<(FixedU128, u64)> {
+		let decimals = Feeds::<T>::get(feed_id)?.decimals;
+		let round = LatestRound::<T>::get(feed_id)?;
+		let answer = u128::try_from(round.answer).ok()?;
+		let price = FixedU128::checked_from_rational(answer, 10u128.checked_pow(decimals.into())?)?;
+		// Rounds are timestamped in seconds, the weight trader expects milliseconds.
+		Some((price, round.updated_at.saturating_mul(1000)))
+	}
+}
+
+/// Answers submitted to the current round of a feed, with its start time in seconds.
+#[derive(
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	RuntimeDebugNoBound,
+)]
+#[scale_info(skip_type_params(MaxFeeders))]
+pub struct PendingRound<AccountId: Clone + PartialEq + Eq + core::fmt::Debug, MaxFeeders: Get<u32>>
+{
+	/// Time at which the first answer of the round was submitted.
+	pub started_at: u64,
+	/// Latest answer submitted by each feeder during the round.
+	pub submissions: BoundedVec<(AccountId, i128), MaxFeeders>,
+}
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	/// Configuration of a price feed for the provided Runtime.
+	pub type FeedConfigOf<T> = FeedConfig<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::MaxFeeders,
+		<T as Config>::MaxDescriptionLength,
+	>;
+
+	/// Current round of a price feed for the provided Runtime.
+	pub type PendingRoundOf<T> =
+		PendingRound<<T as frame_system::Config>::AccountId, <T as Config>::MaxFeeders>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that is allowed to create, configure and remove the feeds.
+		type FeedManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of feeders of a feed.
+		#[pallet::constant]
+		type MaxFeeders: Get<u32>;
+
+		/// Maximum length of the description of a feed.
+		#[pallet::constant]
+		type MaxDescriptionLength: Get<u32>;
+
+		/// Time provider, used to timestamp the rounds.
+		type Time: UnixTime;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Configuration of each feed.
+	#[pallet::storage]
+	#[pallet::getter(fn feed)]
+	pub type Feeds<T: Config> = StorageMap<_, Twox64Concat, FeedId, FeedConfigOf<T>, OptionQuery>;
+
+	/// Latest completed round of each feed.
+	#[pallet::storage]
+	#[pallet::getter(fn latest_round)]
+	pub type LatestRound<T: Config> = StorageMap<_, Twox64Concat, FeedId, RoundData, OptionQuery>;
+
+	/// Answers submitted to the current round of each feed.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_round)]
+	pub type PendingRounds<T: Config> =
+		StorageMap<_, Twox64Concat, FeedId, PendingRoundOf<T>, OptionQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// A feed with this id already exists.
+		FeedAlreadyExists,
+		/// There is no feed with this id.
+		FeedNotFound,
+		/// The number of answers required to complete a round is zero or exceeds the number of
+		/// feeders.
+		InvalidMinSubmissions,
+		/// The caller is not a feeder of the feed.
+		NotFeeder,
+		/// The current round has more answers than the maximum number of feeders.
+		TooManySubmissions,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A feed was created.
+		FeedCreated {
+			feed_id: FeedId,
+			decimals: u8,
+			description: Vec<u8>,
+		},
+		/// The feeders of a feed were set, and its current round was discarded.
+		FeedersSet {
+			feed_id: FeedId,
+			feeders: Vec<T::AccountId>,
+			min_submissions: u32,
+		},
+		/// A feed was removed along with its rounds.
+		FeedRemoved { feed_id: FeedId },
+		/// A feeder submitted an answer to the current round of a feed.
+		AnswerSubmitted {
+			feed_id: FeedId,
+			feeder: T::AccountId,
+			answer: i128,
+		},
+		/// A round of a feed completed with the median of the submitted answers.
+		RoundCompleted {
+			feed_id: FeedId,
+			round_id: u64,
+			answer: i128,
+		},
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a feed, whose rounds complete once `min_submissions` of its feeders submitted
+		/// an answer.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_feed(feeders.len() as u32))]
+		pub fn create_feed(
+			origin: OriginFor<T>,
+			feed_id: FeedId,
+			decimals: u8,
+			description: BoundedVec<u8, T::MaxDescriptionLength>,
+			feeders: BoundedVec<T::AccountId, T::MaxFeeders>,
+			min_submissions: u32,
+		) -> DispatchResult {
+			T::FeedManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!Feeds::<T>::contains_key(feed_id),
+				Error::<T>::FeedAlreadyExists
+			);
+			Self::ensure_valid_min_submissions(&feeders, min_submissions)?;
+
+			Feeds::<T>::insert(
+				feed_id,
+				FeedConfig {
+					decimals,
+					description: description.clone(),
+					feeders: feeders.clone(),
+					min_submissions,
+				},
+			);
+
+			Self::deposit_event(Event::FeedCreated {
+				feed_id,
+				decimals,
+				description: description.into_inner(),
+			});
+			Self::deposit_event(Event::FeedersSet {
+				feed_id,
+				feeders: feeders.into_inner(),
+				min_submissions,
+			});
+			Ok(())
+		}
+
+		/// Replace the feeders of a feed, discarding the answers submitted to its current round.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_feeders(feeders.len() as u32))]
+		pub fn set_feeders(
+			origin: OriginFor<T>,
+			feed_id: FeedId,
+			feeders: BoundedVec<T::AccountId, T::MaxFeeders>,
+			min_submissions: u32,
+		) -> DispatchResult {
+			T::FeedManagerOrigin::ensure_origin(origin)?;
+
+			Self::ensure_valid_min_submissions(&feeders, min_submissions)?;
+			Feeds::<T>::try_mutate(feed_id, |feed| -> DispatchResult {
+				let feed = feed.as_mut().ok_or(Error::<T>::FeedNotFound)?;
+				feed.feeders = feeders.clone();
+				feed.min_submissions = min_submissions;
+				Ok(())
+			})?;
+			PendingRounds::<T>::remove(feed_id);
+
+			Self::deposit_event(Event::FeedersSet {
+				feed_id,
+				feeders: feeders.into_inner(),
+				min_submissions,
+			});
+			Ok(())
+		}
+
+		/// Remove a feed along with its rounds.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::remove_feed())]
+		pub fn remove_feed(origin: OriginFor<T>, feed_id: FeedId) -> DispatchResult {
+			T::FeedManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				Feeds::<T>::take(feed_id).is_some(),
+				Error::<T>::FeedNotFound
+			);
+			LatestRound::<T>::remove(feed_id);
+			PendingRounds::<T>::remove(feed_id);
+
+			Self::deposit_event(Event::FeedRemoved { feed_id });
+			Ok(())
+		}
+
+		/// Submit an answer to the current round of a feed, replacing the answer previously
+		/// submitted by the caller to that round if any.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::submit(T::MaxFeeders::get()))]
+		pub fn submit(origin: OriginFor<T>, feed_id: FeedId, answer: i128) -> DispatchResult {
+			let feeder = ensure_signed(origin)?;
+
+			let feed = Feeds::<T>::get(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			ensure!(feed.feeders.contains(&feeder), Error::<T>::NotFeeder);
+
+			let now = T::Time::now().as_secs();
+			let mut round = PendingRounds::<T>::get(feed_id).unwrap_or_else(|| PendingRound {
+				started_at: now,
+				submissions: BoundedVec::default(),
+			});
+			match round
+				.submissions
+				.iter_mut()
+				.find(|(account, _)| account == &feeder)
+			{
+				Some((_, previous)) => *previous = answer,
+				None => round
+					.submissions
+					.try_push((feeder.clone(), answer))
+					.map_err(|_| Error::<T>::TooManySubmissions)?,
+			}
+
+			Self::deposit_event(Event::AnswerSubmitted {
+				feed_id,
+				feeder,
+				answer,
+			});
+
+			if (round.submissions.len() as u32) < feed.min_submissions {
+				PendingRounds::<T>::insert(feed_id, round);
+				return Ok(());
+			}
+
+			let answer = Self::median(
+				round
+					.submissions
+					.iter()
+					.map(|(_, answer)| *answer)
+					.collect(),
+			);
+			let round_id = LatestRound::<T>::get(feed_id)
+				.map_or(1, |latest| latest.round_id.saturating_add(1));
+			LatestRound::<T>::insert(
+				feed_id,
+				RoundData {
+					round_id,
+					answer,
+					started_at: round.started_at,
+					updated_at: now,
+				},
+			);
+			PendingRounds::<T>::remove(feed_id);
+
+			Self::deposit_event(Event::RoundCompleted {
+				feed_id,
+				round_id,
+				answer,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn ensure_valid_min_submissions(
+			feeders: &BoundedVec<T::AccountId, T::MaxFeeders>,
+			min_submissions: u32,
+		) -> DispatchResult {
+			ensure!(
+				min_submissions > 0 && min_submissions as usize <= feeders.len(),
+				Error::<T>::InvalidMinSubmissions
+			);
+			Ok(())
+		}
+
+		/// Median of non-empty answers. The median of an even number of answers is the mean of
+		/// the two middle ones, rounded towards zero.
+		pub(crate) fn median(mut answers: Vec<i128>) -> i128 {
+			answers.sort_unstable();
+			let middle = answers.len() / 2;
+			if answers.len() % 2 == 1 {
+				answers[middle]
+			} else {
+				let (low, high) = (answers[middle - 1], answers[middle]);
+				match low.checked_add(high) {
+					Some(sum) => sum / 2,
+					// Both answers have the same sign, such that halving them first rounds the
+					// same way
+					None => low / 2 + high / 2 + (low % 2 + high % 2) / 2,
+				}
+			}
+		}
+	}
+}
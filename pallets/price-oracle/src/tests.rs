@@ -0,0 +1,298 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{Error, Event, Pallet, RelativePriceFeed, RoundData};
+use frame_support::{assert_noop, assert_ok};
+use pallet_xcm_weight_trader::PriceFeed;
+use sp_runtime::{DispatchError, FixedU128};
+
+/// Unix time in milliseconds at which the rounds complete in the tests.
+const NOW: u64 = 1_700_000_000_000;
+
+fn create_feed(feeders: Vec<AccountId>, min_submissions: u32) {
+	assert_ok!(PriceOracle::create_feed(
+		RuntimeOrigin::root(),
+		FEED_ID,
+		8,
+		b"DOT / USD".to_vec().try_into().unwrap(),
+		feeders.try_into().unwrap(),
+		min_submissions
+	));
+}
+
+#[test]
+fn create_feed_requires_feed_manager_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PriceOracle::create_feed(
+				RuntimeOrigin::signed(ALICE),
+				FEED_ID,
+				8,
+				Default::default(),
+				vec![ALICE].try_into().unwrap(),
+				1
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_feed_requires_valid_min_submissions() {
+	ExtBuilder::default().build().execute_with(|| {
+		for min_submissions in [0, 3] {
+			assert_noop!(
+				PriceOracle::create_feed(
+					RuntimeOrigin::root(),
+					FEED_ID,
+					8,
+					Default::default(),
+					vec![ALICE, BOB].try_into().unwrap(),
+					min_submissions
+				),
+				Error::<Test>::InvalidMinSubmissions
+			);
+		}
+
+		create_feed(vec![ALICE, BOB], 2);
+		assert_noop!(
+			PriceOracle::create_feed(
+				RuntimeOrigin::root(),
+				FEED_ID,
+				8,
+				Default::default(),
+				vec![ALICE].try_into().unwrap(),
+				1
+			),
+			Error::<Test>::FeedAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn round_completes_with_median_of_answers() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_feed(vec![ALICE, BOB, CHARLIE], 3);
+
+		Timestamp::set_timestamp(NOW - 12_000);
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(ALICE),
+			FEED_ID,
+			100
+		));
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(BOB),
+			FEED_ID,
+			300
+		));
+		assert_eq!(Pallet::<Test>::latest_round(FEED_ID), None);
+
+		Timestamp::set_timestamp(NOW);
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(CHARLIE),
+			FEED_ID,
+			200
+		));
+
+		assert_eq!(
+			Pallet::<Test>::latest_round(FEED_ID),
+			Some(RoundData {
+				round_id: 1,
+				answer: 200,
+				started_at: (NOW - 12_000) / 1000,
+				updated_at: NOW / 1000,
+			})
+		);
+		assert_eq!(Pallet::<Test>::pending_round(FEED_ID), None);
+		assert_eq!(
+			events().last(),
+			Some(&Event::RoundCompleted {
+				feed_id: FEED_ID,
+				round_id: 1,
+				answer: 200,
+			})
+		);
+
+		// The next answer starts the second round
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(ALICE),
+			FEED_ID,
+			400
+		));
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(BOB),
+			FEED_ID,
+			400
+		));
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(CHARLIE),
+			FEED_ID,
+			400
+		));
+		assert_eq!(
+			Pallet::<Test>::latest_round(FEED_ID).map(|round| (round.round_id, round.answer)),
+			Some((2, 400))
+		);
+	});
+}
+
+#[test]
+fn resubmitting_replaces_the_previous_answer() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_feed(vec![ALICE, BOB], 2);
+
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(ALICE),
+			FEED_ID,
+			100
+		));
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(ALICE),
+			FEED_ID,
+			120
+		));
+		assert_eq!(Pallet::<Test>::latest_round(FEED_ID), None);
+
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(BOB),
+			FEED_ID,
+			181
+		));
+		// Mean of the two middle answers, rounded towards zero
+		assert_eq!(
+			Pallet::<Test>::latest_round(FEED_ID).map(|round| round.answer),
+			Some(150)
+		);
+	});
+}
+
+#[test]
+fn submit_requires_feeder() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PriceOracle::submit(RuntimeOrigin::signed(ALICE), FEED_ID, 100),
+			Error::<Test>::FeedNotFound
+		);
+
+		create_feed(vec![ALICE, BOB], 1);
+		assert_noop!(
+			PriceOracle::submit(RuntimeOrigin::signed(DAVE), FEED_ID, 100),
+			Error::<Test>::NotFeeder
+		);
+	});
+}
+
+#[test]
+fn set_feeders_discards_current_round() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_feed(vec![ALICE, BOB], 2);
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(ALICE),
+			FEED_ID,
+			100
+		));
+
+		assert_ok!(PriceOracle::set_feeders(
+			RuntimeOrigin::root(),
+			FEED_ID,
+			vec![BOB, CHARLIE].try_into().unwrap(),
+			2
+		));
+		assert_eq!(Pallet::<Test>::pending_round(FEED_ID), None);
+		assert_noop!(
+			PriceOracle::submit(RuntimeOrigin::signed(ALICE), FEED_ID, 100),
+			Error::<Test>::NotFeeder
+		);
+
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(BOB),
+			FEED_ID,
+			200
+		));
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(CHARLIE),
+			FEED_ID,
+			200
+		));
+		assert_eq!(
+			Pallet::<Test>::latest_round(FEED_ID).map(|round| round.answer),
+			Some(200)
+		);
+	});
+}
+
+#[test]
+fn remove_feed_clears_rounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_feed(vec![ALICE, BOB], 1);
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(ALICE),
+			FEED_ID,
+			100
+		));
+
+		assert_ok!(PriceOracle::remove_feed(RuntimeOrigin::root(), FEED_ID));
+		assert_eq!(Pallet::<Test>::feed(FEED_ID), None);
+		assert_eq!(Pallet::<Test>::latest_round(FEED_ID), None);
+		assert_eq!(
+			events().last(),
+			Some(&Event::FeedRemoved { feed_id: FEED_ID })
+		);
+
+		assert_noop!(
+			PriceOracle::remove_feed(RuntimeOrigin::root(), FEED_ID),
+			Error::<Test>::FeedNotFound
+		);
+	});
+}
+
+#[test]
+fn median_handles_negative_and_extreme_answers() {
+	assert_eq!(Pallet::<Test>::median(vec![-5, 3, -1]), -1);
+	assert_eq!(Pallet::<Test>::median(vec![-3, -6]), -4);
+	assert_eq!(
+		Pallet::<Test>::median(vec![i128::MAX, i128::MAX]),
+		i128::MAX
+	);
+	assert_eq!(Pallet::<Test>::median(vec![i128::MIN, i128::MAX]), 0);
+}
+
+#[test]
+fn relative_price_is_the_latest_answer_scaled_by_the_decimals() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_feed(vec![ALICE], 1);
+		assert_eq!(RelativePriceFeed::<Test>::relative_price(&FEED_ID), None);
+
+		Timestamp::set_timestamp(NOW);
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(ALICE),
+			FEED_ID,
+			150_000_000
+		));
+		assert_eq!(
+			RelativePriceFeed::<Test>::relative_price(&FEED_ID),
+			Some((FixedU128::from_rational(3, 2), NOW))
+		);
+
+		assert_ok!(PriceOracle::submit(
+			RuntimeOrigin::signed(ALICE),
+			FEED_ID,
+			-1
+		));
+		assert_eq!(RelativePriceFeed::<Test>::relative_price(&FEED_ID), None);
+	});
+}
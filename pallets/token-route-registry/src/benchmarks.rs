@@ -0,0 +1,97 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+
+use crate::{AccountFormat, BridgeProtocol, Call, Config, Pallet, Route, Routes};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_core::{H160, H256};
+use sp_std::vec;
+use xcm::latest::{Junction, Junctions, MultiLocation};
+
+/// Route with the maximum number of hops.
+fn longest_route<T: Config>() -> Route<T::MaxHops> {
+	let location = MultiLocation::new(
+		1,
+		Junctions::X2(
+			Junction::Parachain(1000),
+			Junction::AccountId32 {
+				network: None,
+				id: [0xaa; 32],
+			},
+		),
+	);
+
+	Route {
+		bridge_in: BridgeProtocol::Wormhole,
+		hops: vec![location; T::MaxHops::get() as usize]
+			.try_into()
+			.expect("hops are bounded by MaxHops"),
+		destination: location,
+		account_format: AccountFormat::AccountId32,
+	}
+}
+
+benchmarks! {
+	set_route {
+		let token = H160::repeat_byte(0xaa);
+		let dest_chain = H256::repeat_byte(0x01);
+		let route = longest_route::<T>();
+	}: _(RawOrigin::Root, token, dest_chain, route.clone())
+	verify {
+		assert_eq!(Pallet::<T>::route_for(token, dest_chain), Some(route));
+	}
+
+	remove_route {
+		let token = H160::repeat_byte(0xaa);
+		let dest_chain = H256::repeat_byte(0x01);
+		Routes::<T>::insert(token, dest_chain, longest_route::<T>());
+	}: _(RawOrigin::Root, token, dest_chain)
+	verify {
+		assert_eq!(Pallet::<T>::route_for(token, dest_chain), None);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::ExtBuilder;
+	use crate::Pallet;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn bench_set_route() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_set_route());
+		});
+	}
+
+	#[test]
+	fn bench_remove_route() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_remove_route());
+		});
+	}
+}
+
+impl_benchmark_test_suite!(
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
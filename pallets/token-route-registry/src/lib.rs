@@ -0,0 +1,216 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Token route registry pallet
+//!
+//! This pallet records, for each token and destination chain, the canonical route the token
+//! takes to reach that chain: the protocol bridging it into this chain, the XCM hops it goes
+//! through and the final chain along with the format of the accounts on it. The routes are
+//! managed by governance, and are exposed to smart contracts through a precompile, such that the
+//! GMP precompile and the frontends share a single source of truth for multi-hop routing.
+//!
+//! Destination chains are identified by an opaque 32 bytes identifier chosen by governance.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::{
+	pallet, pallet_prelude::*, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use sp_core::{H160, H256};
+use xcm::latest::MultiLocation;
+
+/// Protocol bridging a token into this chain.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub enum BridgeProtocol {
+	/// The token is reserve transferred through XCM.
+	Xcm = 0,
+	/// The token is bridged through the Wormhole token bridge.
+	Wormhole = 1,
+}
+
+/// Format of the accounts on the final chain of a route.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub enum AccountFormat {
+	/// 32 bytes accounts, such as the ones of the relay chain.
+	AccountId32 = 0,
+	/// 20 bytes accounts, such as the ones of the Ethereum compatible chains.
+	AccountKey20 = 1,
+}
+
+/// Canonical route of a token to a destination chain.
+#[derive(
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	RuntimeDebugNoBound,
+)]
+#[scale_info(skip_type_params(MaxHops))]
+pub struct Route<MaxHops: Get<u32>> {
+	/// Protocol bridging the token into this chain.
+	pub bridge_in: BridgeProtocol,
+	/// Chains the token goes through before reaching the final chain, in order.
+	pub hops: BoundedVec<MultiLocation, MaxHops>,
+	/// Final chain of the route.
+	pub destination: MultiLocation,
+	/// Format of the accounts on the final chain.
+	pub account_format: AccountFormat,
+}
+
+/// Provides the canonical route of a token to a destination chain.
+pub trait RouteProvider {
+	/// Maximum number of chains a token goes through before reaching the final chain.
+	type MaxHops: Get<u32>;
+
+	/// Route of `token`, identified by its ERC-20 address, to `dest_chain`, if any.
+	fn route_for(token: H160, dest_chain: H256) -> Option<Route<Self::MaxHops>>;
+}
+
+/// No routes.
+impl RouteProvider for () {
+	type MaxHops = ConstU32<0>;
+
+	fn route_for(_token: H160, _dest_chain: H256) -> Option<Route<Self::MaxHops>> {
+		None
+	}
+}
+
+impl<T: Config> RouteProvider for Pallet<T> {
+	type MaxHops = T::MaxHops;
+
+	fn route_for(token: H160, dest_chain: H256) -> Option<Route<Self::MaxHops>> {
+		Routes::<T>::get(token, dest_chain)
+	}
+}
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that is allowed to set and remove the routes.
+		type RouteManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of chains a token goes through before reaching the final chain.
+		#[pallet::constant]
+		type MaxHops: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Canonical route of each token, identified by its ERC-20 address, to each destination
+	/// chain.
+	#[pallet::storage]
+	#[pallet::getter(fn route_for)]
+	pub type Routes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		H160,
+		Blake2_128Concat,
+		H256,
+		Route<T::MaxHops>,
+		OptionQuery,
+	>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The final chain of the route is this chain.
+		InvalidDestination,
+		/// There is no route for the token to the destination chain.
+		RouteNotFound,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The route of a token to a destination chain was set.
+		RouteSet {
+			token: H160,
+			dest_chain: H256,
+			route: Route<T::MaxHops>,
+		},
+		/// The route of a token to a destination chain was removed.
+		RouteRemoved { token: H160, dest_chain: H256 },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the route of a token to a destination chain, replacing the previous one if any.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_route())]
+		pub fn set_route(
+			origin: OriginFor<T>,
+			token: H160,
+			dest_chain: H256,
+			route: Route<T::MaxHops>,
+		) -> DispatchResult {
+			T::RouteManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				route.destination != MultiLocation::here(),
+				Error::<T>::InvalidDestination
+			);
+
+			Routes::<T>::insert(token, dest_chain, route.clone());
+
+			Self::deposit_event(Event::RouteSet {
+				token,
+				dest_chain,
+				route,
+			});
+			Ok(())
+		}
+
+		/// Remove the route of a token to a destination chain.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_route())]
+		pub fn remove_route(origin: OriginFor<T>, token: H160, dest_chain: H256) -> DispatchResult {
+			T::RouteManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				Routes::<T>::take(token, dest_chain).is_some(),
+				Error::<T>::RouteNotFound
+			);
+
+			Self::deposit_event(Event::RouteRemoved { token, dest_chain });
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,145 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{AccountFormat, BridgeProtocol, Error, Event, Pallet, Route};
+use frame_support::{assert_noop, assert_ok, traits::ConstU32};
+use sp_core::H256;
+use sp_runtime::DispatchError;
+use xcm::latest::{Junction, Junctions, MultiLocation};
+
+fn route() -> Route<ConstU32<2>> {
+	Route {
+		bridge_in: BridgeProtocol::Wormhole,
+		hops: vec![MultiLocation::new(
+			1,
+			Junctions::X1(Junction::Parachain(1000)),
+		)]
+		.try_into()
+		.unwrap(),
+		destination: MultiLocation::parent(),
+		account_format: AccountFormat::AccountId32,
+	}
+}
+
+#[test]
+fn set_and_remove_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TokenRouteRegistry::set_route(
+			RuntimeOrigin::root(),
+			TOKEN,
+			DEST_CHAIN,
+			route()
+		));
+		assert_eq!(Pallet::<Test>::route_for(TOKEN, DEST_CHAIN), Some(route()));
+		assert_eq!(
+			Pallet::<Test>::route_for(TOKEN, H256::repeat_byte(0x02)),
+			None
+		);
+
+		assert_ok!(TokenRouteRegistry::remove_route(
+			RuntimeOrigin::root(),
+			TOKEN,
+			DEST_CHAIN
+		));
+		assert_eq!(Pallet::<Test>::route_for(TOKEN, DEST_CHAIN), None);
+
+		expect_events(vec![
+			Event::RouteSet {
+				token: TOKEN,
+				dest_chain: DEST_CHAIN,
+				route: route(),
+			},
+			Event::RouteRemoved {
+				token: TOKEN,
+				dest_chain: DEST_CHAIN,
+			},
+		]);
+	});
+}
+
+#[test]
+fn set_route_replaces_previous_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TokenRouteRegistry::set_route(
+			RuntimeOrigin::root(),
+			TOKEN,
+			DEST_CHAIN,
+			route()
+		));
+
+		let direct = Route {
+			bridge_in: BridgeProtocol::Xcm,
+			hops: Default::default(),
+			destination: MultiLocation::new(1, Junctions::X1(Junction::Parachain(2000))),
+			account_format: AccountFormat::AccountKey20,
+		};
+		assert_ok!(TokenRouteRegistry::set_route(
+			RuntimeOrigin::root(),
+			TOKEN,
+			DEST_CHAIN,
+			direct.clone()
+		));
+
+		assert_eq!(Pallet::<Test>::route_for(TOKEN, DEST_CHAIN), Some(direct));
+	});
+}
+
+#[test]
+fn route_to_this_chain_is_rejected() {
+	ExtBuilder::default().build().execute_with(|| {
+		let route = Route {
+			destination: MultiLocation::here(),
+			..route()
+		};
+
+		assert_noop!(
+			TokenRouteRegistry::set_route(RuntimeOrigin::root(), TOKEN, DEST_CHAIN, route),
+			Error::<Test>::InvalidDestination
+		);
+	});
+}
+
+#[test]
+fn removing_unknown_route_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TokenRouteRegistry::remove_route(RuntimeOrigin::root(), TOKEN, DEST_CHAIN),
+			Error::<Test>::RouteNotFound
+		);
+	});
+}
+
+#[test]
+fn only_route_manager_can_manage_routes() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TokenRouteRegistry::set_route(RuntimeOrigin::signed(1), TOKEN, DEST_CHAIN, route()),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(TokenRouteRegistry::set_route(
+			RuntimeOrigin::root(),
+			TOKEN,
+			DEST_CHAIN,
+			route()
+		));
+		assert_noop!(
+			TokenRouteRegistry::remove_route(RuntimeOrigin::signed(1), TOKEN, DEST_CHAIN),
+			DispatchError::BadOrigin
+		);
+	});
+}
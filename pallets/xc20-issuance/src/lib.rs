@@ -0,0 +1,200 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # XC-20 issuance pallet
+//!
+//! This pallet keeps track, per foreign asset, of the cumulative amounts minted and burned by the
+//! XCM executor. Comparing the issuance expected from those figures with the total issuance
+//! reported by `pallet_assets` allows detecting accounting drift, for instance after a bug in an
+//! asset transactor or a reorg on the reserve chain.
+//!
+//! Tracking is performed by [`IssuanceTrackingTransactor`], which must wrap the asset transactor
+//! in charge of foreign assets. The first time an asset moves through XCM, the issuance existing
+//! before that movement is recorded as the baseline of the asset.
+//!
+//! The figures are exposed through the `Xc20IssuanceApi` runtime API, and governance can reset the
+//! record of an asset once a drift has been investigated.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod transactor;
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use transactor::IssuanceTrackingTransactor;
+pub use weights::WeightInfo;
+pub use xc20_issuance_runtime_api::Xc20IssuanceReport;
+
+use frame_support::pallet;
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_support::traits::fungibles::{self, Inspect};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AtLeast32BitUnsigned, Saturating};
+	use sp_std::vec::Vec;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	/// Cumulative XCM flows of a foreign asset since the tracking started.
+	#[derive(
+		Clone, Copy, Default, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo,
+	)]
+	pub struct IssuanceRecord<Balance> {
+		/// Issuance of the asset when the tracking started.
+		pub baseline: Balance,
+		/// Cumulative amount minted by XCM deposits.
+		pub xcm_minted: Balance,
+		/// Cumulative amount burned by XCM withdrawals.
+		pub xcm_burned: Balance,
+	}
+
+	impl<Balance: Saturating + Copy> IssuanceRecord<Balance> {
+		/// The issuance expected from the XCM flows.
+		pub fn expected_issuance(&self) -> Balance {
+			self.baseline
+				.saturating_add(self.xcm_minted)
+				.saturating_sub(self.xcm_burned)
+		}
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The foreign asset identifier.
+		type AssetId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// The balance type of foreign assets.
+		type Balance: Member + Parameter + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
+
+		/// The foreign assets, used to read their total issuance.
+		type Assets: fungibles::Inspect<
+			Self::AccountId,
+			AssetId = Self::AssetId,
+			Balance = Self::Balance,
+		>;
+
+		/// Origin that is allowed to reset the issuance record of an asset.
+		type ResetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Cumulative XCM flows of each tracked foreign asset.
+	#[pallet::storage]
+	#[pallet::getter(fn issuance_record)]
+	pub type IssuanceRecords<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, IssuanceRecord<T::Balance>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The issuance record of an asset has been reset, its current issuance is the new
+		/// baseline.
+		IssuanceRecordReset {
+			asset_id: T::AssetId,
+			baseline: T::Balance,
+		},
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Reset the issuance record of an asset, taking its current total issuance as the new
+		/// baseline. Meant to be used once a drift has been investigated and accounted for, it
+		/// can also be used to start tracking an asset before it moves through XCM.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::reset_issuance_record())]
+		pub fn reset_issuance_record(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::ResetOrigin::ensure_origin(origin)?;
+
+			let baseline = T::Assets::total_issuance(asset_id);
+			IssuanceRecords::<T>::insert(
+				asset_id,
+				IssuanceRecord {
+					baseline,
+					xcm_minted: Default::default(),
+					xcm_burned: Default::default(),
+				},
+			);
+
+			Self::deposit_event(Event::IssuanceRecordReset { asset_id, baseline });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Record an amount minted through XCM. Must be called after the mint happened.
+		pub fn note_xcm_mint(asset_id: T::AssetId, amount: T::Balance) {
+			IssuanceRecords::<T>::mutate(asset_id, |maybe_record| {
+				let record = maybe_record.get_or_insert_with(|| IssuanceRecord {
+					baseline: T::Assets::total_issuance(asset_id).saturating_sub(amount),
+					..Default::default()
+				});
+				record.xcm_minted = record.xcm_minted.saturating_add(amount);
+			});
+		}
+
+		/// Record an amount burned through XCM. Must be called after the burn happened.
+		pub fn note_xcm_burn(asset_id: T::AssetId, amount: T::Balance) {
+			IssuanceRecords::<T>::mutate(asset_id, |maybe_record| {
+				let record = maybe_record.get_or_insert_with(|| IssuanceRecord {
+					baseline: T::Assets::total_issuance(asset_id).saturating_add(amount),
+					..Default::default()
+				});
+				record.xcm_burned = record.xcm_burned.saturating_add(amount);
+			});
+		}
+
+		/// Build the issuance report of an asset, if it is tracked.
+		pub fn issuance_report(asset_id: T::AssetId) -> Option<Xc20IssuanceReport<T::Balance>> {
+			IssuanceRecords::<T>::get(asset_id).map(|record| Self::build_report(asset_id, record))
+		}
+
+		/// Build the issuance reports of all tracked assets that are drifting.
+		pub fn drifting_assets() -> Vec<(T::AssetId, Xc20IssuanceReport<T::Balance>)> {
+			IssuanceRecords::<T>::iter()
+				.map(|(asset_id, record)| (asset_id, Self::build_report(asset_id, record)))
+				.filter(|(_, report)| report.is_drifting())
+				.collect()
+		}
+
+		fn build_report(
+			asset_id: T::AssetId,
+			record: IssuanceRecord<T::Balance>,
+		) -> Xc20IssuanceReport<T::Balance> {
+			Xc20IssuanceReport {
+				baseline: record.baseline,
+				xcm_minted: record.xcm_minted,
+				xcm_burned: record.xcm_burned,
+				expected_issuance: record.expected_issuance(),
+				total_issuance: T::Assets::total_issuance(asset_id),
+			}
+		}
+	}
+}
@@ -0,0 +1,325 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A minimal runtime including the xc20-issuance pallet
+
+use crate as pallet_xc20_issuance;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{
+		fungibles::Mutate,
+		tokens::{Fortitude, Precision},
+		AsEnsureOriginWithArg, ConstU32, Everything,
+	},
+	weights::Weight,
+};
+use frame_system::{EnsureNever, EnsureRoot};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
+};
+use xcm::latest::prelude::*;
+use xcm_executor::{
+	traits::{Error as MatchError, MatchesFungibles, TransactAsset},
+	Assets as XcmAssets,
+};
+
+pub type AccountId = u64;
+pub type AssetId = u128;
+pub type Balance = u128;
+pub type BlockNumber = u32;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+		Xc20Issuance: pallet_xc20_issuance::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+// Pallet system configuration
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+	pub const MaximumBlockWeight: Weight = Weight::from_parts(1024, 1);
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = sp_runtime::generic::Header<BlockNumber, BlakeTwo256>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+// Pallet balances configuration
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 0;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 4];
+	type MaxLocks = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+// Pallet assets configuration
+
+parameter_types! {
+	pub const AssetDeposit: Balance = 0;
+	pub const ApprovalDeposit: Balance = 0;
+	pub const AssetsStringLimit: u32 = 50;
+	pub const MetadataDepositBase: Balance = 0;
+	pub const MetadataDepositPerByte: Balance = 0;
+	pub const AssetAccountDeposit: Balance = 0;
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = AssetId;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = AssetsStringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<656>;
+	type AssetIdParameter = AssetId;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureNever<AccountId>>;
+	type CallbackHandle = ();
+	pallet_assets::runtime_benchmarks_enabled! {
+		type BenchmarkHelper = ();
+	}
+}
+
+// Pallet xc20-issuance configuration
+
+impl pallet_xc20_issuance::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type Assets = Assets;
+	type ResetOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+/// Matches `(Parent, GeneralIndex(asset_id))` fungible assets.
+pub struct MockMatcher;
+impl MatchesFungibles<AssetId, Balance> for MockMatcher {
+	fn matches_fungibles(asset: &MultiAsset) -> Result<(AssetId, Balance), MatchError> {
+		match asset {
+			MultiAsset {
+				id:
+					Concrete(MultiLocation {
+						parents: 1,
+						interior: X1(GeneralIndex(asset_id)),
+					}),
+				fun: Fungible(amount),
+			} => Ok((*asset_id, *amount)),
+			_ => Err(MatchError::AssetNotHandled),
+		}
+	}
+}
+
+/// A simple transactor minting and burning the assets matched by `MockMatcher`, for accounts
+/// represented as `AccountIndex64` junctions.
+pub struct MockTransactor;
+
+impl MockTransactor {
+	fn account(who: &MultiLocation) -> Result<AccountId, XcmError> {
+		match who {
+			MultiLocation {
+				parents: 0,
+				interior: X1(AccountIndex64 { index, .. }),
+			} => Ok(*index),
+			_ => Err(XcmError::FailedToTransactAsset("unknown account")),
+		}
+	}
+}
+
+impl TransactAsset for MockTransactor {
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation, _context: &XcmContext) -> XcmResult {
+		let (asset_id, amount) = MockMatcher::matches_fungibles(what)?;
+		Assets::mint_into(asset_id, &Self::account(who)?, amount)
+			.map_err(|_| XcmError::FailedToTransactAsset("mint failed"))?;
+		Ok(())
+	}
+
+	fn withdraw_asset(
+		what: &MultiAsset,
+		who: &MultiLocation,
+		_maybe_context: Option<&XcmContext>,
+	) -> Result<XcmAssets, XcmError> {
+		let (asset_id, amount) = MockMatcher::matches_fungibles(what)?;
+		Assets::burn_from(
+			asset_id,
+			&Self::account(who)?,
+			amount,
+			Precision::Exact,
+			Fortitude::Polite,
+		)
+		.map_err(|_| XcmError::FailedToTransactAsset("burn failed"))?;
+		Ok(what.clone().into())
+	}
+
+	fn internal_transfer_asset(
+		asset: &MultiAsset,
+		from: &MultiLocation,
+		to: &MultiLocation,
+		_context: &XcmContext,
+	) -> Result<XcmAssets, XcmError> {
+		let (asset_id, amount) = MockMatcher::matches_fungibles(asset)?;
+		<Assets as frame_support::traits::fungibles::Mutate<AccountId>>::transfer(
+			asset_id,
+			&Self::account(from)?,
+			&Self::account(to)?,
+			amount,
+			frame_support::traits::tokens::Preservation::Expendable,
+		)
+		.map_err(|_| XcmError::FailedToTransactAsset("transfer failed"))?;
+		Ok(asset.clone().into())
+	}
+}
+
+pub type TrackingTransactor =
+	pallet_xc20_issuance::IssuanceTrackingTransactor<Test, MockMatcher, MockTransactor>;
+
+pub const ASSET_ID: AssetId = 1;
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+/// The XCM representation of `ASSET_ID`.
+pub fn asset(amount: Balance) -> MultiAsset {
+	(MultiLocation::new(1, X1(GeneralIndex(ASSET_ID))), amount).into()
+}
+
+/// The XCM representation of a local account.
+pub fn account(who: AccountId) -> MultiLocation {
+	MultiLocation::new(
+		0,
+		X1(AccountIndex64 {
+			network: None,
+			index: who,
+		}),
+	)
+}
+
+pub fn xcm_context() -> XcmContext {
+	XcmContext::with_message_id([0; 32])
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder {
+	// assets to create, with their initial holders
+	assets: Vec<(AssetId, Vec<(AccountId, Balance)>)>,
+}
+
+impl ExtBuilder {
+	pub(crate) fn with_asset(
+		mut self,
+		asset_id: AssetId,
+		holders: Vec<(AccountId, Balance)>,
+	) -> Self {
+		self.assets.push((asset_id, holders));
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::default()
+			.build_storage::<Test>()
+			.expect("Frame system builds valid default genesis config");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			for (asset_id, holders) in self.assets {
+				Assets::force_create(RuntimeOrigin::root(), asset_id, ALICE, true, 1)
+					.expect("asset can be created");
+				for (who, amount) in holders {
+					Assets::mint_into(asset_id, &who, amount).expect("asset can be minted");
+				}
+			}
+		});
+		ext
+	}
+}
+
+pub(crate) fn events() -> Vec<super::Event<Test>> {
+	System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| {
+			if let RuntimeEvent::Xc20Issuance(inner) = e {
+				Some(inner)
+			} else {
+				None
+			}
+		})
+		.collect::<Vec<_>>()
+}
+
+pub fn expect_events(e: Vec<super::Event<Test>>) {
+	assert_eq!(events(), e);
+}
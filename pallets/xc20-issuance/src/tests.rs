@@ -0,0 +1,175 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for Xc20Issuance Pallet
+use crate::*;
+use mock::*;
+
+use frame_support::{assert_noop, assert_ok, traits::fungibles::Mutate};
+use sp_runtime::DispatchError;
+use xcm_executor::traits::TransactAsset;
+
+#[test]
+fn xcm_deposit_records_mint_on_top_of_existing_issuance() {
+	ExtBuilder::default()
+		.with_asset(ASSET_ID, vec![(ALICE, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(TrackingTransactor::deposit_asset(
+				&asset(50),
+				&account(BOB),
+				&xcm_context()
+			));
+
+			assert_eq!(
+				Xc20Issuance::issuance_record(ASSET_ID),
+				Some(IssuanceRecord {
+					baseline: 100,
+					xcm_minted: 50,
+					xcm_burned: 0,
+				})
+			);
+			let report = Xc20Issuance::issuance_report(ASSET_ID).expect("asset is tracked");
+			assert_eq!(report.expected_issuance, 150);
+			assert_eq!(report.total_issuance, 150);
+			assert!(!report.is_drifting());
+		});
+}
+
+#[test]
+fn xcm_withdraw_records_burn() {
+	ExtBuilder::default()
+		.with_asset(ASSET_ID, vec![(ALICE, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(TrackingTransactor::withdraw_asset(
+				&asset(30),
+				&account(ALICE),
+				Some(&xcm_context())
+			));
+			assert_ok!(TrackingTransactor::deposit_asset(
+				&asset(10),
+				&account(BOB),
+				&xcm_context()
+			));
+
+			assert_eq!(
+				Xc20Issuance::issuance_record(ASSET_ID),
+				Some(IssuanceRecord {
+					baseline: 100,
+					xcm_minted: 10,
+					xcm_burned: 30,
+				})
+			);
+			assert!(Xc20Issuance::drifting_assets().is_empty());
+		});
+}
+
+#[test]
+fn failed_xcm_deposit_is_not_recorded() {
+	ExtBuilder::default().build().execute_with(|| {
+		// The asset does not exist, so the inner transactor fails to mint it
+		assert!(
+			TrackingTransactor::deposit_asset(&asset(50), &account(BOB), &xcm_context()).is_err()
+		);
+
+		assert_eq!(Xc20Issuance::issuance_record(ASSET_ID), None);
+	});
+}
+
+#[test]
+fn xcm_transfer_is_not_recorded() {
+	ExtBuilder::default()
+		.with_asset(ASSET_ID, vec![(ALICE, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(TrackingTransactor::transfer_asset(
+				&asset(40),
+				&account(ALICE),
+				&account(BOB),
+				&xcm_context()
+			));
+
+			assert_eq!(Xc20Issuance::issuance_record(ASSET_ID), None);
+		});
+}
+
+#[test]
+fn mint_outside_xcm_is_reported_as_drift() {
+	ExtBuilder::default()
+		.with_asset(ASSET_ID, vec![(ALICE, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(TrackingTransactor::deposit_asset(
+				&asset(50),
+				&account(BOB),
+				&xcm_context()
+			));
+			assert_ok!(Assets::mint_into(ASSET_ID, &ALICE, 5));
+
+			let report = Xc20Issuance::issuance_report(ASSET_ID).expect("asset is tracked");
+			assert_eq!(report.expected_issuance, 150);
+			assert_eq!(report.total_issuance, 155);
+			assert_eq!(Xc20Issuance::drifting_assets(), vec![(ASSET_ID, report)]);
+		});
+}
+
+#[test]
+fn reset_issuance_record_requires_reset_origin() {
+	ExtBuilder::default()
+		.with_asset(ASSET_ID, vec![(ALICE, 100)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Xc20Issuance::reset_issuance_record(RuntimeOrigin::signed(ALICE), ASSET_ID),
+				DispatchError::BadOrigin
+			);
+		});
+}
+
+#[test]
+fn reset_issuance_record_clears_drift() {
+	ExtBuilder::default()
+		.with_asset(ASSET_ID, vec![(ALICE, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(TrackingTransactor::deposit_asset(
+				&asset(50),
+				&account(BOB),
+				&xcm_context()
+			));
+			assert_ok!(Assets::mint_into(ASSET_ID, &ALICE, 5));
+
+			assert_ok!(Xc20Issuance::reset_issuance_record(
+				RuntimeOrigin::root(),
+				ASSET_ID
+			));
+
+			assert_eq!(
+				Xc20Issuance::issuance_record(ASSET_ID),
+				Some(IssuanceRecord {
+					baseline: 155,
+					xcm_minted: 0,
+					xcm_burned: 0,
+				})
+			);
+			assert!(Xc20Issuance::drifting_assets().is_empty());
+			expect_events(vec![crate::Event::IssuanceRecordReset {
+				asset_id: ASSET_ID,
+				baseline: 155,
+			}]);
+		});
+}
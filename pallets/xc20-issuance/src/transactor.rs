@@ -0,0 +1,97 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Asset transactor wrapper recording the XCM mints and burns of foreign assets.
+
+use crate::{Config, Pallet};
+use sp_std::marker::PhantomData;
+use xcm::latest::prelude::*;
+use xcm_executor::{
+	traits::{MatchesFungibles, TransactAsset},
+	Assets,
+};
+
+/// Wraps the asset transactor in charge of foreign assets (`Inner`), and records in this pallet
+/// the amounts it mints and burns for the assets recognized by `Matcher`.
+///
+/// Asset transfers between two accounts don't change the issuance, so they are delegated to the
+/// inner transactor without being recorded.
+pub struct IssuanceTrackingTransactor<T, Matcher, Inner>(PhantomData<(T, Matcher, Inner)>);
+
+impl<T, Matcher, Inner> TransactAsset for IssuanceTrackingTransactor<T, Matcher, Inner>
+where
+	T: Config,
+	Matcher: MatchesFungibles<T::AssetId, T::Balance>,
+	Inner: TransactAsset,
+{
+	fn can_check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) -> XcmResult {
+		Inner::can_check_in(origin, what, context)
+	}
+
+	fn check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+		Inner::check_in(origin, what, context)
+	}
+
+	fn can_check_out(dest: &MultiLocation, what: &MultiAsset, context: &XcmContext) -> XcmResult {
+		Inner::can_check_out(dest, what, context)
+	}
+
+	fn check_out(dest: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+		Inner::check_out(dest, what, context)
+	}
+
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation, context: &XcmContext) -> XcmResult {
+		Inner::deposit_asset(what, who, context)?;
+
+		if let Ok((asset_id, amount)) = Matcher::matches_fungibles(what) {
+			Pallet::<T>::note_xcm_mint(asset_id, amount);
+		}
+
+		Ok(())
+	}
+
+	fn withdraw_asset(
+		what: &MultiAsset,
+		who: &MultiLocation,
+		maybe_context: Option<&XcmContext>,
+	) -> Result<Assets, XcmError> {
+		let assets = Inner::withdraw_asset(what, who, maybe_context)?;
+
+		if let Ok((asset_id, amount)) = Matcher::matches_fungibles(what) {
+			Pallet::<T>::note_xcm_burn(asset_id, amount);
+		}
+
+		Ok(assets)
+	}
+
+	fn internal_transfer_asset(
+		asset: &MultiAsset,
+		from: &MultiLocation,
+		to: &MultiLocation,
+		context: &XcmContext,
+	) -> Result<Assets, XcmError> {
+		Inner::internal_transfer_asset(asset, from, to, context)
+	}
+
+	fn transfer_asset(
+		asset: &MultiAsset,
+		from: &MultiLocation,
+		to: &MultiLocation,
+		context: &XcmContext,
+	) -> Result<Assets, XcmError> {
+		Inner::transfer_asset(asset, from, to, context)
+	}
+}
@@ -0,0 +1,85 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! XCM barrier applying the filters configured in this pallet.
+
+use crate::{Config, InstructionAllowlist, InstructionAllowlists, Pallet};
+use frame_support::{traits::ProcessMessageError, weights::Weight};
+use sp_std::marker::PhantomData;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::{Properties, ShouldExecute};
+
+/// Rejects the messages coming from a suspended origin, or containing instructions that are not
+/// in the allowlist of their origin. The other messages are passed to the `Inner` barrier.
+///
+/// While XCM is paused, the messages executed locally are rejected too. The messages from the
+/// relay chain are still accepted, as they would otherwise be lost.
+///
+/// The filters apply to the origin of the message as received, before any `DescendOrigin` or
+/// `UniversalOrigin` instruction, which is the channel the message came from.
+pub struct XcmFilterBarrier<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T: Config, Inner: ShouldExecute> ShouldExecute for XcmFilterBarrier<T, Inner> {
+	fn should_execute<RuntimeCall>(
+		origin: &MultiLocation,
+		instructions: &mut [Instruction<RuntimeCall>],
+		max_weight: Weight,
+		properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		if Pallet::<T>::is_xcm_paused() && *origin != MultiLocation::parent() {
+			log::trace!(
+				target: "xcm::barriers",
+				"XcmFilterBarrier XCM is paused, origin: {:?}",
+				origin,
+			);
+			return Err(ProcessMessageError::Unsupported);
+		}
+
+		if Pallet::<T>::is_suspended(origin) {
+			log::trace!(
+				target: "xcm::barriers",
+				"XcmFilterBarrier origin: {:?} is suspended",
+				origin,
+			);
+			return Err(ProcessMessageError::Unsupported);
+		}
+
+		if let Some(allowlist) = InstructionAllowlists::<T>::get(origin) {
+			if !all_accepted(&allowlist, instructions) {
+				log::trace!(
+					target: "xcm::barriers",
+					"XcmFilterBarrier origin: {:?} sent an instruction that is not allowed",
+					origin,
+				);
+				return Err(ProcessMessageError::Unsupported);
+			}
+		}
+
+		Inner::should_execute(origin, instructions, max_weight, properties)
+	}
+}
+
+/// Whether all the instructions, including the ones nested in appendix and error handlers, are
+/// accepted by the allowlist.
+fn all_accepted<Call>(
+	allowlist: &InstructionAllowlist,
+	instructions: &[Instruction<Call>],
+) -> bool {
+	instructions.iter().all(|instruction| match instruction {
+		SetAppendix(xcm) | SetErrorHandler(xcm) => all_accepted(allowlist, &xcm.0),
+		instruction => allowlist.accepts(instruction),
+	})
+}
@@ -0,0 +1,115 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+
+use crate::{Call, Config, InstructionAllowlist, InstructionAllowlists, Pallet, SuspendedOrigins};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin;
+use sp_std::boxed::Box;
+use xcm::latest::prelude::*;
+
+fn sibling(para_id: u32) -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(para_id)))
+}
+
+benchmarks! {
+	suspend_origin {
+		let location = sibling(1000);
+	}: _(RawOrigin::Root, Box::new(location.into()))
+	verify {
+		assert!(SuspendedOrigins::<T>::contains_key(location));
+	}
+
+	resume_origin {
+		let location = sibling(1000);
+		Pallet::<T>::suspend_origin(RawOrigin::Root.into(), Box::new(location.into()))?;
+	}: _(RawOrigin::Root, Box::new(location.into()))
+	verify {
+		assert!(!SuspendedOrigins::<T>::contains_key(location));
+	}
+
+	set_instruction_allowlist {
+		let location = sibling(1000);
+		let allowlist = InstructionAllowlist::default();
+	}: _(RawOrigin::Root, Box::new(location.into()), Some(allowlist))
+	verify {
+		assert_eq!(InstructionAllowlists::<T>::get(location), Some(allowlist));
+	}
+
+	pause_xcm {
+	}: _(RawOrigin::Root)
+	verify {
+		assert!(Pallet::<T>::is_xcm_paused());
+	}
+
+	resume_xcm {
+		Pallet::<T>::pause_xcm(RawOrigin::Root.into())?;
+	}: _(RawOrigin::Root)
+	verify {
+		assert!(!Pallet::<T>::is_xcm_paused());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::ExtBuilder;
+	use crate::Pallet;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn bench_suspend_origin() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_suspend_origin());
+		});
+	}
+
+	#[test]
+	fn bench_resume_origin() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_resume_origin());
+		});
+	}
+
+	#[test]
+	fn bench_set_instruction_allowlist() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_set_instruction_allowlist());
+		});
+	}
+
+	#[test]
+	fn bench_pause_xcm() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_pause_xcm());
+		});
+	}
+
+	#[test]
+	fn bench_resume_xcm() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<crate::mock::Test>::test_benchmark_resume_xcm());
+		});
+	}
+}
+
+impl_benchmark_test_suite!(
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
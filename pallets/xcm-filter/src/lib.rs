@@ -0,0 +1,258 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # XCM filter pallet
+//!
+//! This pallet provides an XCM barrier whose configuration is controlled by governance, allowing
+//! a fast response to a misbehaving channel without a runtime upgrade:
+//! - Inbound XCM from a given origin (typically a sibling parachain) can be suspended entirely.
+//! - The instructions that need special care (`Transact`, `ReserveAssetDeposited`) can be
+//! restricted per origin, by giving the origin an allowlist.
+//!
+//! - In an emergency, XCM can be paused altogether: the XCMP queue is suspended and the messages
+//! executed locally (`pallet_xcm` and other XCM transfers) are rejected.
+//!
+//! Origins without any configuration are not filtered, the decision is then left to the barrier
+//! wrapped by [`XcmFilterBarrier`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod barrier;
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use barrier::XcmFilterBarrier;
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::pallet;
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use moonkit_xcm_primitives::PauseXcmExecution;
+	use sp_std::boxed::Box;
+	use xcm::{latest::prelude::*, VersionedMultiLocation};
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	/// The restrictable instructions an origin is allowed to send.
+	#[derive(
+		Clone, Copy, Default, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo,
+	)]
+	pub struct InstructionAllowlist {
+		/// Whether `Transact` is accepted.
+		pub transact: bool,
+		/// Whether `ReserveAssetDeposited` is accepted.
+		pub reserve_asset_deposited: bool,
+	}
+
+	impl InstructionAllowlist {
+		/// Whether the given instruction is accepted. Instructions that can't be restricted are
+		/// always accepted.
+		pub fn accepts<Call>(&self, instruction: &Instruction<Call>) -> bool {
+			match instruction {
+				Transact { .. } => self.transact,
+				ReserveAssetDeposited(..) => self.reserve_asset_deposited,
+				_ => true,
+			}
+		}
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that is allowed to suspend, resume and restrict XCM origins.
+		type FilterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin that is allowed to pause and resume XCM in an emergency.
+		type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Suspends and resumes the execution of the XCMP queue.
+		type XcmExecutionManager: PauseXcmExecution;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Origins whose inbound XCM is rejected.
+	#[pallet::storage]
+	pub type SuspendedOrigins<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, (), OptionQuery>;
+
+	/// Origins restricted to an allowlist of instructions.
+	#[pallet::storage]
+	#[pallet::getter(fn instruction_allowlist)]
+	pub type InstructionAllowlists<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, InstructionAllowlist, OptionQuery>;
+
+	/// Whether XCM is paused.
+	#[pallet::storage]
+	#[pallet::getter(fn is_xcm_paused)]
+	pub type XcmPaused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The location could not be converted to the latest XCM version.
+		BadVersion,
+		/// The origin is already suspended.
+		AlreadySuspended,
+		/// The origin is not suspended.
+		NotSuspended,
+		/// XCM is already paused.
+		AlreadyPaused,
+		/// XCM is not paused.
+		NotPaused,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Inbound XCM from this origin is now rejected.
+		OriginSuspended { origin: MultiLocation },
+		/// Inbound XCM from this origin is accepted again.
+		OriginResumed { origin: MultiLocation },
+		/// The instructions accepted from this origin have been restricted.
+		InstructionAllowlistSet {
+			origin: MultiLocation,
+			allowlist: InstructionAllowlist,
+		},
+		/// The instructions accepted from this origin are no longer restricted.
+		InstructionAllowlistRemoved { origin: MultiLocation },
+		/// XCM has been paused.
+		XcmPaused,
+		/// XCM has been resumed.
+		XcmResumed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Reject all inbound XCM from the given origin.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::suspend_origin())]
+		pub fn suspend_origin(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+		) -> DispatchResult {
+			T::FilterOrigin::ensure_origin(origin)?;
+			let location =
+				MultiLocation::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
+
+			ensure!(
+				!SuspendedOrigins::<T>::contains_key(location),
+				Error::<T>::AlreadySuspended
+			);
+			SuspendedOrigins::<T>::insert(location, ());
+
+			Self::deposit_event(Event::OriginSuspended { origin: location });
+			Ok(())
+		}
+
+		/// Accept inbound XCM from a previously suspended origin again.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::resume_origin())]
+		pub fn resume_origin(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+		) -> DispatchResult {
+			T::FilterOrigin::ensure_origin(origin)?;
+			let location =
+				MultiLocation::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
+
+			ensure!(
+				SuspendedOrigins::<T>::contains_key(location),
+				Error::<T>::NotSuspended
+			);
+			SuspendedOrigins::<T>::remove(location);
+
+			Self::deposit_event(Event::OriginResumed { origin: location });
+			Ok(())
+		}
+
+		/// Restrict the instructions accepted from the given origin to `allowlist`, or lift the
+		/// restriction if `None`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_instruction_allowlist())]
+		pub fn set_instruction_allowlist(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+			allowlist: Option<InstructionAllowlist>,
+		) -> DispatchResult {
+			T::FilterOrigin::ensure_origin(origin)?;
+			let location =
+				MultiLocation::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
+
+			if let Some(allowlist) = allowlist {
+				InstructionAllowlists::<T>::insert(location, allowlist);
+				Self::deposit_event(Event::InstructionAllowlistSet {
+					origin: location,
+					allowlist,
+				});
+			} else {
+				InstructionAllowlists::<T>::remove(location);
+				Self::deposit_event(Event::InstructionAllowlistRemoved { origin: location });
+			}
+
+			Ok(())
+		}
+
+		/// Pause XCM: the XCMP queue is suspended and the messages executed locally are rejected.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::pause_xcm())]
+		pub fn pause_xcm(origin: OriginFor<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			ensure!(!XcmPaused::<T>::get(), Error::<T>::AlreadyPaused);
+			XcmPaused::<T>::put(true);
+			T::XcmExecutionManager::suspend_xcm_execution()?;
+
+			Self::deposit_event(Event::XcmPaused);
+			Ok(())
+		}
+
+		/// Resume XCM after it has been paused.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::resume_xcm())]
+		pub fn resume_xcm(origin: OriginFor<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			ensure!(XcmPaused::<T>::get(), Error::<T>::NotPaused);
+			XcmPaused::<T>::put(false);
+			T::XcmExecutionManager::resume_xcm_execution()?;
+
+			Self::deposit_event(Event::XcmResumed);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether inbound XCM from the given origin is rejected.
+		pub fn is_suspended(location: &MultiLocation) -> bool {
+			SuspendedOrigins::<T>::contains_key(location)
+		}
+	}
+}
@@ -0,0 +1,186 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A minimal runtime including the xcm-filter pallet
+
+use crate as pallet_xcm_filter;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, Everything, ProcessMessageError},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::DispatchResult;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
+};
+use std::cell::RefCell;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::{Properties, ShouldExecute};
+
+pub type AccountId = u64;
+pub type BlockNumber = u32;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		XcmFilter: pallet_xcm_filter::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+// Pallet system configuration
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+	pub const MaximumBlockWeight: Weight = Weight::from_parts(1024, 1);
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = sp_runtime::generic::Header<BlockNumber, BlakeTwo256>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+// Pallet xcm-filter configuration
+
+impl pallet_xcm_filter::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type FilterOrigin = EnsureRoot<AccountId>;
+	type PauseOrigin = EnsureRoot<AccountId>;
+	type XcmExecutionManager = MockXcmExecutionManager;
+	type WeightInfo = ();
+}
+
+// Simulates the suspension of the XCMP queue
+thread_local! {
+	pub static XCM_EXECUTION_SUSPENDED: RefCell<bool> = RefCell::new(false);
+}
+pub fn xcm_execution_suspended() -> bool {
+	XCM_EXECUTION_SUSPENDED.with(|s| *s.borrow())
+}
+pub struct MockXcmExecutionManager;
+impl moonkit_xcm_primitives::PauseXcmExecution for MockXcmExecutionManager {
+	fn suspend_xcm_execution() -> DispatchResult {
+		XCM_EXECUTION_SUSPENDED.with(|s| *s.borrow_mut() = true);
+		Ok(())
+	}
+	fn resume_xcm_execution() -> DispatchResult {
+		XCM_EXECUTION_SUSPENDED.with(|s| *s.borrow_mut() = false);
+		Ok(())
+	}
+}
+
+/// A barrier accepting every message, to check what the filter rejects on its own.
+pub struct AllowAll;
+impl ShouldExecute for AllowAll {
+	fn should_execute<RuntimeCall>(
+		_origin: &MultiLocation,
+		_instructions: &mut [Instruction<RuntimeCall>],
+		_max_weight: Weight,
+		_properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		Ok(())
+	}
+}
+
+pub type Barrier = pallet_xcm_filter::XcmFilterBarrier<Test, AllowAll>;
+
+/// Runs the barrier against the given message.
+pub fn check_barrier(
+	origin: MultiLocation,
+	mut message: Xcm<()>,
+) -> Result<(), ProcessMessageError> {
+	Barrier::should_execute(
+		&origin,
+		message.0.as_mut_slice(),
+		Weight::from_parts(1_000_000_000, 0),
+		&mut Properties {
+			weight_credit: Weight::zero(),
+			message_id: None,
+		},
+	)
+}
+
+pub fn sibling(para_id: u32) -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(para_id)))
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder;
+
+impl ExtBuilder {
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::default()
+			.build_storage::<Test>()
+			.expect("Frame system builds valid default genesis config");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+pub(crate) fn events() -> Vec<super::Event<Test>> {
+	System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| {
+			if let RuntimeEvent::XcmFilter(inner) = e {
+				Some(inner)
+			} else {
+				None
+			}
+		})
+		.collect::<Vec<_>>()
+}
+
+pub fn expect_events(e: Vec<super::Event<Test>>) {
+	assert_eq!(events(), e);
+}
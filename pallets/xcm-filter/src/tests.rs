@@ -0,0 +1,325 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{Error, Event, InstructionAllowlist, InstructionAllowlists, Pallet};
+use frame_support::{assert_noop, assert_ok, traits::ProcessMessageError, weights::Weight};
+use sp_runtime::DispatchError;
+use xcm::latest::prelude::*;
+
+fn transact() -> Instruction<()> {
+	Transact {
+		origin_kind: OriginKind::SovereignAccount,
+		require_weight_at_most: Weight::from_parts(1_000, 0),
+		call: vec![].into(),
+	}
+}
+
+fn reserve_asset_deposited() -> Instruction<()> {
+	ReserveAssetDeposited(MultiAsset::from((Parent, 100u128)).into())
+}
+
+#[test]
+fn suspend_and_resume_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmFilter::suspend_origin(
+			RuntimeOrigin::root(),
+			Box::new(sibling(1000).into())
+		));
+		assert!(Pallet::<Test>::is_suspended(&sibling(1000)));
+		assert!(!Pallet::<Test>::is_suspended(&sibling(1001)));
+
+		assert_ok!(XcmFilter::resume_origin(
+			RuntimeOrigin::root(),
+			Box::new(sibling(1000).into())
+		));
+		assert!(!Pallet::<Test>::is_suspended(&sibling(1000)));
+
+		expect_events(vec![
+			Event::OriginSuspended {
+				origin: sibling(1000),
+			},
+			Event::OriginResumed {
+				origin: sibling(1000),
+			},
+		]);
+	});
+}
+
+#[test]
+fn suspend_origin_twice_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmFilter::suspend_origin(
+			RuntimeOrigin::root(),
+			Box::new(sibling(1000).into())
+		));
+		assert_noop!(
+			XcmFilter::suspend_origin(RuntimeOrigin::root(), Box::new(sibling(1000).into())),
+			Error::<Test>::AlreadySuspended
+		);
+	});
+}
+
+#[test]
+fn resume_origin_not_suspended_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmFilter::resume_origin(RuntimeOrigin::root(), Box::new(sibling(1000).into())),
+			Error::<Test>::NotSuspended
+		);
+	});
+}
+
+#[test]
+fn only_filter_origin_can_configure() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmFilter::suspend_origin(RuntimeOrigin::signed(1), Box::new(sibling(1000).into())),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			XcmFilter::resume_origin(RuntimeOrigin::signed(1), Box::new(sibling(1000).into())),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			XcmFilter::set_instruction_allowlist(
+				RuntimeOrigin::signed(1),
+				Box::new(sibling(1000).into()),
+				None
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_and_remove_instruction_allowlist() {
+	ExtBuilder::default().build().execute_with(|| {
+		let allowlist = InstructionAllowlist {
+			transact: false,
+			reserve_asset_deposited: true,
+		};
+		assert_ok!(XcmFilter::set_instruction_allowlist(
+			RuntimeOrigin::root(),
+			Box::new(sibling(1000).into()),
+			Some(allowlist)
+		));
+		assert_eq!(
+			InstructionAllowlists::<Test>::get(sibling(1000)),
+			Some(allowlist)
+		);
+
+		assert_ok!(XcmFilter::set_instruction_allowlist(
+			RuntimeOrigin::root(),
+			Box::new(sibling(1000).into()),
+			None
+		));
+		assert_eq!(InstructionAllowlists::<Test>::get(sibling(1000)), None);
+
+		expect_events(vec![
+			Event::InstructionAllowlistSet {
+				origin: sibling(1000),
+				allowlist,
+			},
+			Event::InstructionAllowlistRemoved {
+				origin: sibling(1000),
+			},
+		]);
+	});
+}
+
+#[test]
+fn barrier_passes_unconfigured_origins_to_inner_barrier() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(check_barrier(
+			sibling(1000),
+			Xcm(vec![reserve_asset_deposited(), transact()])
+		));
+	});
+}
+
+#[test]
+fn barrier_rejects_suspended_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmFilter::suspend_origin(
+			RuntimeOrigin::root(),
+			Box::new(sibling(1000).into())
+		));
+
+		assert_eq!(
+			check_barrier(sibling(1000), Xcm(vec![ClearOrigin])),
+			Err(ProcessMessageError::Unsupported)
+		);
+		// Other origins are not affected
+		assert_ok!(check_barrier(sibling(1001), Xcm(vec![ClearOrigin])));
+
+		assert_ok!(XcmFilter::resume_origin(
+			RuntimeOrigin::root(),
+			Box::new(sibling(1000).into())
+		));
+		assert_ok!(check_barrier(sibling(1000), Xcm(vec![ClearOrigin])));
+	});
+}
+
+#[test]
+fn barrier_applies_instruction_allowlist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmFilter::set_instruction_allowlist(
+			RuntimeOrigin::root(),
+			Box::new(sibling(1000).into()),
+			Some(InstructionAllowlist {
+				transact: false,
+				reserve_asset_deposited: true,
+			})
+		));
+
+		assert_ok!(check_barrier(
+			sibling(1000),
+			Xcm(vec![reserve_asset_deposited(), ClearOrigin])
+		));
+		assert_eq!(
+			check_barrier(
+				sibling(1000),
+				Xcm(vec![reserve_asset_deposited(), transact()])
+			),
+			Err(ProcessMessageError::Unsupported)
+		);
+
+		assert_ok!(XcmFilter::set_instruction_allowlist(
+			RuntimeOrigin::root(),
+			Box::new(sibling(1000).into()),
+			Some(InstructionAllowlist {
+				transact: true,
+				reserve_asset_deposited: false,
+			})
+		));
+
+		assert_ok!(check_barrier(sibling(1000), Xcm(vec![transact()])));
+		assert_eq!(
+			check_barrier(sibling(1000), Xcm(vec![reserve_asset_deposited()])),
+			Err(ProcessMessageError::Unsupported)
+		);
+	});
+}
+
+#[test]
+fn barrier_applies_instruction_allowlist_to_nested_instructions() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmFilter::set_instruction_allowlist(
+			RuntimeOrigin::root(),
+			Box::new(sibling(1000).into()),
+			Some(InstructionAllowlist::default())
+		));
+
+		assert_eq!(
+			check_barrier(sibling(1000), Xcm(vec![SetAppendix(Xcm(vec![transact()]))])),
+			Err(ProcessMessageError::Unsupported)
+		);
+		assert_eq!(
+			check_barrier(
+				sibling(1000),
+				Xcm(vec![SetErrorHandler(Xcm(vec![reserve_asset_deposited()]))])
+			),
+			Err(ProcessMessageError::Unsupported)
+		);
+		assert_ok!(check_barrier(
+			sibling(1000),
+			Xcm(vec![SetAppendix(Xcm(vec![ClearOrigin]))])
+		));
+	});
+}
+
+#[test]
+fn pause_and_resume_xcm() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmFilter::pause_xcm(RuntimeOrigin::root()));
+		assert!(Pallet::<Test>::is_xcm_paused());
+		assert!(xcm_execution_suspended());
+
+		assert_ok!(XcmFilter::resume_xcm(RuntimeOrigin::root()));
+		assert!(!Pallet::<Test>::is_xcm_paused());
+		assert!(!xcm_execution_suspended());
+
+		expect_events(vec![Event::XcmPaused, Event::XcmResumed]);
+	});
+}
+
+#[test]
+fn pause_xcm_twice_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmFilter::pause_xcm(RuntimeOrigin::root()));
+		assert_noop!(
+			XcmFilter::pause_xcm(RuntimeOrigin::root()),
+			Error::<Test>::AlreadyPaused
+		);
+	});
+}
+
+#[test]
+fn resume_xcm_not_paused_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmFilter::resume_xcm(RuntimeOrigin::root()),
+			Error::<Test>::NotPaused
+		);
+	});
+}
+
+#[test]
+fn only_pause_origin_can_pause_and_resume() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmFilter::pause_xcm(RuntimeOrigin::signed(1)),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			XcmFilter::resume_xcm(RuntimeOrigin::signed(1)),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn barrier_rejects_all_but_relay_while_paused() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmFilter::pause_xcm(RuntimeOrigin::root()));
+
+		assert_eq!(
+			check_barrier(sibling(1000), Xcm(vec![ClearOrigin])),
+			Err(ProcessMessageError::Unsupported)
+		);
+		assert_eq!(
+			check_barrier(
+				MultiLocation::new(
+					0,
+					X1(AccountKey20 {
+						network: None,
+						key: [1u8; 20]
+					})
+				),
+				Xcm(vec![ClearOrigin])
+			),
+			Err(ProcessMessageError::Unsupported)
+		);
+		assert_ok!(check_barrier(
+			MultiLocation::parent(),
+			Xcm(vec![ClearOrigin])
+		));
+
+		assert_ok!(XcmFilter::resume_xcm(RuntimeOrigin::root()));
+		assert_ok!(check_barrier(sibling(1000), Xcm(vec![ClearOrigin])));
+	});
+}
@@ -0,0 +1,126 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_xcm_filter`
+//!
+//! These weights have not been benchmarked yet. They are hand-written, deliberately
+//! conservative estimates whose proof sizes follow the storage bounds; regenerate them
+//! with `moonbeam benchmark pallet` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_xcm_filter.
+pub trait WeightInfo {
+	fn suspend_origin() -> Weight;
+	fn resume_origin() -> Weight;
+	fn set_instruction_allowlist() -> Weight;
+	fn pause_xcm() -> Weight;
+	fn resume_xcm() -> Weight;
+}
+
+/// Weights for pallet_xcm_filter using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: XcmFilter SuspendedOrigins (r:1 w:1)
+	/// Proof: XcmFilter SuspendedOrigins (max_values: None, max_size: Some(614), added: 3089, mode: MaxEncodedLen)
+	fn suspend_origin() -> Weight {
+		Weight::from_parts(29_000_000, 4079)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmFilter SuspendedOrigins (r:1 w:1)
+	/// Proof: XcmFilter SuspendedOrigins (max_values: None, max_size: Some(614), added: 3089, mode: MaxEncodedLen)
+	fn resume_origin() -> Weight {
+		Weight::from_parts(32_000_000, 4079)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmFilter InstructionAllowlists (r:0 w:1)
+	/// Proof: XcmFilter InstructionAllowlists (max_values: None, max_size: Some(616), added: 3091, mode: MaxEncodedLen)
+	fn set_instruction_allowlist() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmFilter XcmPaused (r:1 w:1)
+	/// Proof: XcmFilter XcmPaused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: XcmpQueue QueueSuspended (r:0 w:1)
+	/// Proof Skipped: XcmpQueue QueueSuspended (max_values: Some(1), max_size: None, mode: Measured)
+	fn pause_xcm() -> Weight {
+		Weight::from_parts(29_000_000, 1486)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: XcmFilter XcmPaused (r:1 w:1)
+	/// Proof: XcmFilter XcmPaused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: MaintenanceMode MaintenanceMode (r:1 w:0)
+	/// Proof Skipped: MaintenanceMode MaintenanceMode (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XcmpQueue QueueSuspended (r:0 w:1)
+	/// Proof Skipped: XcmpQueue QueueSuspended (max_values: Some(1), max_size: None, mode: Measured)
+	fn resume_xcm() -> Weight {
+		Weight::from_parts(34_000_000, 1515)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: XcmFilter SuspendedOrigins (r:1 w:1)
+	/// Proof: XcmFilter SuspendedOrigins (max_values: None, max_size: Some(614), added: 3089, mode: MaxEncodedLen)
+	fn suspend_origin() -> Weight {
+		Weight::from_parts(29_000_000, 4079)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmFilter SuspendedOrigins (r:1 w:1)
+	/// Proof: XcmFilter SuspendedOrigins (max_values: None, max_size: Some(614), added: 3089, mode: MaxEncodedLen)
+	fn resume_origin() -> Weight {
+		Weight::from_parts(32_000_000, 4079)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmFilter InstructionAllowlists (r:0 w:1)
+	/// Proof: XcmFilter InstructionAllowlists (max_values: None, max_size: Some(616), added: 3091, mode: MaxEncodedLen)
+	fn set_instruction_allowlist() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmFilter XcmPaused (r:1 w:1)
+	/// Proof: XcmFilter XcmPaused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: XcmpQueue QueueSuspended (r:0 w:1)
+	/// Proof Skipped: XcmpQueue QueueSuspended (max_values: Some(1), max_size: None, mode: Measured)
+	fn pause_xcm() -> Weight {
+		Weight::from_parts(29_000_000, 1486)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: XcmFilter XcmPaused (r:1 w:1)
+	/// Proof: XcmFilter XcmPaused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: MaintenanceMode MaintenanceMode (r:1 w:0)
+	/// Proof Skipped: MaintenanceMode MaintenanceMode (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XcmpQueue QueueSuspended (r:0 w:1)
+	/// Proof Skipped: XcmpQueue QueueSuspended (max_values: Some(1), max_size: None, mode: Measured)
+	fn resume_xcm() -> Weight {
+		Weight::from_parts(34_000_000, 1515)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
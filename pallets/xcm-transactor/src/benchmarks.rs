@@ -16,18 +16,31 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::{Call, Config, Currency, CurrencyPayment, HrmpOperation, Pallet, TransactWeights};
+use crate::{
+	Call, Config, Currency, CurrencyPayment, DepositBalanceOf, HrmpOperation, Pallet,
+	TransactOutcome, TransactStatus, TransactStatusInfo, TransactWeights,
+};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
-use frame_support::dispatch::Weight;
+use frame_support::{
+	dispatch::Weight,
+	traits::{Currency as _, EnsureOrigin},
+};
 use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
 use sp_std::boxed::Box;
 use sp_std::vec;
 use xcm::latest::prelude::*;
 
+/// Fund an account so that it can afford the deposit of a derivative index
+fn fund_deposit<T: Config>(who: &T::AccountId) {
+	T::DepositCurrency::make_free_balance_be(who, DepositBalanceOf::<T>::max_value() / 2u32.into());
+}
+
 benchmarks! {
 	where_clause { where T::Transactor: Default, T::CurrencyId: From<MultiLocation>}
 	register {
 		let user: T::AccountId  = account("account id", 0u32, 0u32);
+		fund_deposit::<T>(&user);
 
 		let index = 1u16;
 	}: _(RawOrigin::Root, user.clone(), index)
@@ -37,6 +50,7 @@ benchmarks! {
 
 	deregister {
 		let user: T::AccountId  = account("account id", 0u32, 0u32);
+		fund_deposit::<T>(&user);
 		let index = 1u16;
 		Pallet::<T>::register(RawOrigin::Root.into(), user, index).expect("must succeed");
 	}: _(RawOrigin::Root, index)
@@ -44,6 +58,28 @@ benchmarks! {
 		assert!(Pallet::<T>::index_to_account(index).is_none());
 	}
 
+	transfer_index {
+		let user: T::AccountId  = account("account id", 0u32, 0u32);
+		let new_owner: T::AccountId  = account("account id", 1u32, 0u32);
+		fund_deposit::<T>(&user);
+		fund_deposit::<T>(&new_owner);
+		let index = 1u16;
+		Pallet::<T>::register(RawOrigin::Root.into(), user.clone(), index).expect("must succeed");
+	}: _(RawOrigin::Signed(user), index, new_owner.clone())
+	verify {
+		assert_eq!(Pallet::<T>::index_to_account(index), Some(new_owner));
+	}
+
+	release_index {
+		let user: T::AccountId  = account("account id", 0u32, 0u32);
+		fund_deposit::<T>(&user);
+		let index = 1u16;
+		Pallet::<T>::register(RawOrigin::Root.into(), user.clone(), index).expect("must succeed");
+	}: _(RawOrigin::Signed(user), index)
+	verify {
+		assert!(Pallet::<T>::index_to_account(index).is_none());
+	}
+
 	set_transact_info {
 		let extra_weight: Weight = Weight::from_parts(300000000u64, 0);
 		let fee_per_second = 1;
@@ -243,6 +279,66 @@ benchmarks! {
 		false
 	)
 
+	transact_through_signed_with_report {
+		let fee_per_second = 1;
+		let extra_weight: Weight = Weight::from_parts(300000000u64, 0);
+		let max_weight: Weight = Weight::from_parts(20000000000u64, u64::MAX);
+		let location = MultiLocation::parent();
+		let currency: T::CurrencyId = location.clone().into();
+		let call = vec![1u8];
+		let dest_weight: Weight = Weight::from_parts(100u64, 0);
+		let user: T::AccountId  = account("account id", 0u32, 0u32);
+		Pallet::<T>::set_transact_info(
+			RawOrigin::Root.into(),
+			Box::new(xcm::VersionedMultiLocation::V3(location.clone())),
+			extra_weight,
+			max_weight,
+			Some(extra_weight)
+		).expect("must succeed");
+		Pallet::<T>::set_fee_per_second(
+			RawOrigin::Root.into(),
+			Box::new(xcm::VersionedMultiLocation::V3(location.clone())),
+			fee_per_second
+		).expect("must succeed");
+	}: _(
+		RawOrigin::Signed(user.clone()),
+		Box::new(xcm::VersionedMultiLocation::V3(location.clone())),
+		CurrencyPayment {
+			// This might involve a db Read when translating, therefore worst case
+			currency: Currency::AsCurrencyId(currency),
+			// This involves a db Read, hence the None is worst case
+			fee_amount: None
+		},
+		call,
+		TransactWeights {
+			transact_required_weight_at_most: dest_weight,
+			// This involves a db Read, hence the None is worst case
+			overall_weight: None
+		},
+		// Refund adds to the appendix, hence true is worst case
+		true
+	)
+
+	transact_status_notify {
+		let user: T::AccountId  = account("account id", 0u32, 0u32);
+		let query_id = 1u64;
+		let origin = T::TransactStatusResponseOrigin::try_successful_origin()
+			.expect("must have a successful origin");
+		let responder = T::TransactStatusResponseOrigin::ensure_origin(origin.clone())
+			.expect("must succeed");
+		TransactStatus::<T>::insert(query_id, TransactStatusInfo {
+			sender: user,
+			dest: responder,
+			outcome: TransactOutcome::Pending,
+		});
+	}: _<T::RuntimeOrigin>(origin, query_id, Response::DispatchResult(MaybeErrorCode::Success))
+	verify {
+		assert_eq!(
+			Pallet::<T>::transact_status(query_id).map(|info| info.outcome),
+			Some(TransactOutcome::Success)
+		);
+	}
+
 	hrmp_manage {
 		let fee_per_second = 1;
 		let extra_weight: Weight = Weight::from_parts(300000000u64, 0);
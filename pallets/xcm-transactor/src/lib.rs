@@ -27,7 +27,7 @@
 //! derived from the multilocation of a use in this chain (tipically, hashing the ML).
 //! Such distinction is important since we want to keep the integrity of the sovereign account
 //!
-//! This pallet provides three ways of sending Transact operations to anothe chain
+//! This pallet provides four ways of sending Transact operations to anothe chain
 //!
 //! - transact_through_derivative: Transact through an address derived from this chains sovereign
 //! 	account in the destination chain. For the transaction to successfully be dispatched in the
@@ -48,7 +48,9 @@
 //! 	the inner call is executed from the derivative account and not the sovereign
 //! 	account itself.
 //!
-//! 	Index registration happens through DerivativeAddressRegistrationOrigin.
+//! 	Index registration happens through DerivativeAddressRegistrationOrigin, and reserves
+//! 	DerivativeIndexDeposit from the owner of the index. The owner can transfer the index,
+//! 	along with its deposit, to another account, or release it to get the deposit back.
 //! 	This derivative account can be funded by external users to
 //! 	ensure it has enough funds to make the calls
 //!
@@ -64,6 +66,11 @@
 //! 	(and DescendOrigin + WithdrawAsset + BuyExecution + Transact messages allowed) in the
 //! 	destination chain. Additionally, a ML-based derivation mechanism needs to be implemented
 //! 	in the destination chain.
+//!
+//! - transact_through_signed_with_report: Same as transact_through_signed, but additionally
+//! 	appends ReportTransactStatus so that the destination chain reports back whether the call
+//! 	was dispatched successfully. The outcome is tracked in the TransactStatus storage item
+//! 	under the id of the query registered through TransactStatusQuerier.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -84,14 +91,33 @@ pub mod weights;
 pub use crate::weights::WeightInfo;
 
 type CurrencyIdOf<T> = <T as Config>::CurrencyId;
+type DepositBalanceOf<T> = <<T as Config>::DepositCurrency as frame_support::traits::Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// Registers the queries under which destination chains report back the outcome of a
+/// transact sent with `transact_through_signed_with_report`
+pub trait TransactStatusQuerier {
+	/// Register a query expecting a `QueryResponse` from `responder` addressed to `querier`,
+	/// and return its id. The response should be notified back through
+	/// `Call::transact_status_notify`
+	fn new_transact_status_query(
+		responder: xcm::latest::MultiLocation,
+		querier: xcm::latest::MultiLocation,
+	) -> xcm::latest::QueryId;
+}
 
 #[pallet]
 pub mod pallet {
 	use super::*;
 	use crate::weights::WeightInfo;
-	use crate::CurrencyIdOf;
+	use crate::{CurrencyIdOf, DepositBalanceOf};
 	use cumulus_primitives_core::{relay_chain::HrmpChannelId, ParaId};
-	use frame_support::{pallet_prelude::*, weights::constants::WEIGHT_REF_TIME_PER_SECOND};
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{BalanceStatus, ReservableCurrency},
+		weights::constants::WEIGHT_REF_TIME_PER_SECOND,
+	};
 	use frame_system::{ensure_signed, pallet_prelude::*};
 	use orml_traits::location::{Parse, Reserve};
 	use sp_runtime::traits::{AtLeast32BitUnsigned, Bounded, Convert};
@@ -177,6 +203,24 @@ pub mod pallet {
 		/// Means of encoding HRMP transact calls
 		type HrmpEncoder: HrmpEncodeCall;
 
+		/// Means of registering the queries used to track the outcome of remote transacts
+		type TransactStatusQuerier: TransactStatusQuerier;
+
+		/// The origin that is allowed to report back the outcome of a remote transact. It
+		/// resolves to the location of the responder
+		type TransactStatusResponseOrigin: EnsureOrigin<
+			Self::RuntimeOrigin,
+			Success = MultiLocation,
+		>;
+
+		/// The currency in which derivative index registration deposits are reserved
+		type DepositCurrency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved from the owner of a derivative index, refunded once the index is
+		/// deregistered or released
+		#[pallet::constant]
+		type DerivativeIndexDeposit: Get<DepositBalanceOf<Self>>;
+
 		type WeightInfo: WeightInfo;
 	}
 
@@ -288,6 +332,28 @@ pub mod pallet {
 		pub overall_weight: Option<Weight>,
 	}
 
+	/// Outcome of a remote transact, as reported back by the destination chain
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+	pub enum TransactOutcome {
+		/// The destination chain has not reported back yet
+		Pending,
+		/// The call was dispatched successfully in the destination chain
+		Success,
+		/// The call failed to dispatch in the destination chain
+		Failed,
+	}
+
+	/// Tracks a transact whose outcome was requested to be reported back
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct TransactStatusInfo<AccountId> {
+		/// The account that issued the transact
+		pub sender: AccountId,
+		/// The chain in which the call is transacted
+		pub dest: MultiLocation,
+		/// The outcome of the transact
+		pub outcome: TransactOutcome,
+	}
+
 	/// Since we are using pallet-utility for account derivation (through AsDerivative),
 	/// we need to provide an index for the account derivation. This storage item stores the index
 	/// assigned for a given local account. These indices are usable as derivative in the relay chain
@@ -295,6 +361,12 @@ pub mod pallet {
 	#[pallet::getter(fn index_to_account)]
 	pub type IndexToAccount<T: Config> = StorageMap<_, Blake2_128Concat, u16, T::AccountId>;
 
+	/// Stores the deposit reserved from the owner of a derivative index. Indices registered
+	/// before deposits were introduced have no entry
+	#[pallet::storage]
+	#[pallet::getter(fn index_deposit)]
+	pub type IndexDeposit<T: Config> = StorageMap<_, Blake2_128Concat, u16, DepositBalanceOf<T>>;
+
 	/// Stores the transact info of a MultiLocation. This defines how much extra weight we need to
 	/// add when we want to transact in the destination chain and maximum amount of weight allowed
 	/// by the destination chain
@@ -310,6 +382,13 @@ pub mod pallet {
 	pub type DestinationAssetFeePerSecond<T: Config> =
 		StorageMap<_, Twox64Concat, MultiLocation, u128>;
 
+	/// Stores the status of the transacts sent with `transact_through_signed_with_report`,
+	/// indexed by the id of the query under which the destination chain reports back
+	#[pallet::storage]
+	#[pallet::getter(fn transact_status)]
+	pub type TransactStatus<T: Config> =
+		StorageMap<_, Twox64Concat, QueryId, TransactStatusInfo<T::AccountId>>;
+
 	/// An error that can occur while executing the mapping pallet's logic.
 	#[pallet::error]
 	pub enum Error<T> {
@@ -340,6 +419,9 @@ pub mod pallet {
 		TooMuchFeeUsed,
 		ErrorValidating,
 		RefundNotSupportedWithTransactInfo,
+		UnknownTransactStatusQuery,
+		UnexpectedTransactStatusResponder,
+		UnexpectedTransactStatusResponse,
 	}
 
 	#[pallet::event]
@@ -372,6 +454,12 @@ pub mod pallet {
 		DeRegisteredDerivative {
 			index: u16,
 		},
+		/// Transferred a derivative index, along with its deposit, to another account id.
+		TransferredDerivative {
+			index: u16,
+			from: T::AccountId,
+			to: T::AccountId,
+		},
 		/// Transact failed
 		TransactFailed {
 			error: XcmError,
@@ -398,6 +486,17 @@ pub mod pallet {
 		HrmpManagementSent {
 			action: HrmpOperation,
 		},
+		/// Requested the destination chain to report back the outcome of a transact
+		TransactStatusQueried {
+			query_id: QueryId,
+			sender: T::AccountId,
+			dest: MultiLocation,
+		},
+		/// The destination chain reported back the outcome of a transact
+		TransactStatusReceived {
+			query_id: QueryId,
+			outcome: TransactOutcome,
+		},
 	}
 
 	#[pallet::call]
@@ -405,6 +504,9 @@ pub mod pallet {
 		/// Register a derivative index for an account id. Dispatchable by
 		/// DerivativeAddressRegistrationOrigin
 		///
+		/// DerivativeIndexDeposit is reserved from the account id, and refunded once the index
+		/// is deregistered or released
+		///
 		/// We do not store the derivative address, but only the index. We do not need to store
 		/// the derivative address to issue calls, only the index is enough
 		///
@@ -420,7 +522,11 @@ pub mod pallet {
 				Error::<T>::IndexAlreadyClaimed
 			);
 
+			let deposit = T::DerivativeIndexDeposit::get();
+			T::DepositCurrency::reserve(&who, deposit)?;
+
 			IndexToAccount::<T>::insert(&index, who.clone());
+			IndexDeposit::<T>::insert(&index, deposit);
 
 			// Deposit event
 			Self::deposit_event(Event::<T>::RegisteredDerivative {
@@ -432,14 +538,15 @@ pub mod pallet {
 		}
 
 		/// De-Register a derivative index. This prevents an account to use a derivative address
-		/// (represented by an index) from our of our sovereign accounts anymore
+		/// (represented by an index) from our of our sovereign accounts anymore. The deposit of
+		/// the index is refunded to its owner
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::deregister())]
 		pub fn deregister(origin: OriginFor<T>, index: u16) -> DispatchResult {
 			T::DerivativeAddressRegistrationOrigin::ensure_origin(origin)?;
 
 			// Remove index
-			IndexToAccount::<T>::remove(&index);
+			Self::remove_index(index);
 
 			// Deposit event
 			Self::deposit_event(Event::<T>::DeRegisteredDerivative { index });
@@ -701,61 +808,7 @@ pub mod pallet {
 
 			let dest = MultiLocation::try_from(*dest).map_err(|()| Error::<T>::BadVersion)?;
 
-			let fee_location = Self::currency_to_multilocation(fee.currency)
-				.ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
-
-			// Calculate the total weight that the xcm message is going to spend in the
-			// destination chain
-			let total_weight = weight_info.overall_weight.map_or_else(
-				|| {
-					Self::take_weight_from_transact_info_signed(
-						dest.clone(),
-						weight_info.transact_required_weight_at_most,
-						refund,
-					)
-				},
-				|v| Ok(v),
-			)?;
-
-			// Fee to be paid
-			let fee = Self::calculate_fee(
-				fee_location,
-				fee.fee_amount,
-				dest.clone(),
-				total_weight.clone(),
-			)?;
-
-			// If refund is true, the appendix instruction will be a deposit back to the sender
-			let appendix = refund
-				.then(|| -> Result<_, DispatchError> {
-					let sender = T::AccountIdToMultiLocation::convert(who.clone());
-					Ok(vec![
-						RefundSurplus,
-						Self::deposit_instruction(sender, &dest, 1u32)?,
-					])
-				})
-				.transpose()?;
-
-			// Grab the destination
-			Self::transact_in_dest_chain_asset_signed(
-				dest.clone(),
-				who.clone(),
-				fee,
-				call.clone(),
-				OriginKind::SovereignAccount,
-				total_weight,
-				weight_info.transact_required_weight_at_most,
-				appendix,
-			)?;
-
-			// Deposit event
-			Self::deposit_event(Event::<T>::TransactedSigned {
-				fee_payer: who,
-				dest,
-				call,
-			});
-
-			Ok(())
+			Self::transact_through_signed_inner(who, dest, fee, call, weight_info, refund, false)
 		}
 
 		/// Set the fee per second of an asset on its reserve chain
@@ -889,9 +942,232 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Transact the call through a signed origin, as `transact_through_signed` does, and
+		/// request the destination chain to report back whether the call was dispatched
+		/// successfully.
+		///
+		/// The report is appended to the message as a `ReportTransactStatus` instruction. The
+		/// overall weight bought in the destination chain should account for it. The outcome
+		/// can be tracked in `TransactStatus` under the query id emitted in
+		/// `TransactStatusQueried`
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::transact_through_signed_with_report())]
+		pub fn transact_through_signed_with_report(
+			origin: OriginFor<T>,
+			// destination to which the message should be sent
+			dest: Box<VersionedMultiLocation>,
+			// fee to be used
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			// call to be executed in destination
+			call: Vec<u8>,
+			// weight information to be used
+			weight_info: TransactWeights,
+			// add RefundSurplus and DepositAsset appendix
+			refund: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let dest = MultiLocation::try_from(*dest).map_err(|()| Error::<T>::BadVersion)?;
+
+			Self::transact_through_signed_inner(who, dest, fee, call, weight_info, refund, true)
+		}
+
+		/// Record the outcome of a transact reported back by the destination chain. Dispatched
+		/// by the query handler when the response to a `TransactStatus` query arrives
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::transact_status_notify())]
+		pub fn transact_status_notify(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response,
+		) -> DispatchResult {
+			let responder = T::TransactStatusResponseOrigin::ensure_origin(origin)?;
+
+			let outcome = match response {
+				Response::DispatchResult(MaybeErrorCode::Success) => TransactOutcome::Success,
+				Response::DispatchResult(_) => TransactOutcome::Failed,
+				_ => return Err(Error::<T>::UnexpectedTransactStatusResponse.into()),
+			};
+
+			TransactStatus::<T>::try_mutate(query_id, |maybe_info| -> DispatchResult {
+				let info = maybe_info
+					.as_mut()
+					.ok_or(Error::<T>::UnknownTransactStatusQuery)?;
+				ensure!(
+					info.dest == responder,
+					Error::<T>::UnexpectedTransactStatusResponder
+				);
+				info.outcome = outcome.clone();
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::TransactStatusReceived { query_id, outcome });
+
+			Ok(())
+		}
+
+		/// Transfer a derivative index to another account id. Dispatchable by the owner of the
+		/// index
+		///
+		/// The deposit of the index is transferred along with it, and stays reserved in the
+		/// account of the new owner
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::transfer_index())]
+		pub fn transfer_index(
+			origin: OriginFor<T>,
+			index: u16,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let owner = IndexToAccount::<T>::get(&index).ok_or(Error::<T>::UnclaimedIndex)?;
+			ensure!(owner == who, Error::<T>::NotOwner);
+
+			if let Some(deposit) = IndexDeposit::<T>::get(&index) {
+				T::DepositCurrency::repatriate_reserved(
+					&owner,
+					&new_owner,
+					deposit,
+					BalanceStatus::Reserved,
+				)?;
+			}
+
+			IndexToAccount::<T>::insert(&index, new_owner.clone());
+
+			// Deposit event
+			Self::deposit_event(Event::<T>::TransferredDerivative {
+				index,
+				from: owner,
+				to: new_owner,
+			});
+
+			Ok(())
+		}
+
+		/// Release a derivative index, refunding its deposit. Dispatchable by the owner of the
+		/// index
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::release_index())]
+		pub fn release_index(origin: OriginFor<T>, index: u16) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let owner = IndexToAccount::<T>::get(&index).ok_or(Error::<T>::UnclaimedIndex)?;
+			ensure!(owner == who, Error::<T>::NotOwner);
+
+			Self::remove_index(index);
+
+			// Deposit event
+			Self::deposit_event(Event::<T>::DeRegisteredDerivative { index });
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Remove a derivative index, unreserving its deposit from its owner
+		fn remove_index(index: u16) {
+			if let Some(owner) = IndexToAccount::<T>::take(&index) {
+				if let Some(deposit) = IndexDeposit::<T>::take(&index) {
+					T::DepositCurrency::unreserve(&owner, deposit);
+				}
+			}
+		}
+
+		fn transact_through_signed_inner(
+			who: T::AccountId,
+			dest: MultiLocation,
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			call: Vec<u8>,
+			weight_info: TransactWeights,
+			refund: bool,
+			report: bool,
+		) -> DispatchResult {
+			let fee_location = Self::currency_to_multilocation(fee.currency)
+				.ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
+
+			// Calculate the total weight that the xcm message is going to spend in the
+			// destination chain
+			let total_weight = weight_info.overall_weight.map_or_else(
+				|| {
+					Self::take_weight_from_transact_info_signed(
+						dest.clone(),
+						weight_info.transact_required_weight_at_most,
+						refund,
+					)
+				},
+				|v| Ok(v),
+			)?;
+
+			// Fee to be paid
+			let fee = Self::calculate_fee(
+				fee_location,
+				fee.fee_amount,
+				dest.clone(),
+				total_weight.clone(),
+			)?;
+
+			let sender = T::AccountIdToMultiLocation::convert(who.clone());
+			let mut appendix = vec![];
+
+			// If report is true, the destination chain will report back the outcome of the
+			// transact to us under a newly registered query
+			let query_id = if report {
+				let query_id = T::TransactStatusQuerier::new_transact_status_query(
+					dest.clone(),
+					sender.clone(),
+				);
+				appendix.push(Self::report_transact_status_instruction(query_id, &dest)?);
+				Some(query_id)
+			} else {
+				None
+			};
+
+			// If refund is true, the appendix instruction will be a deposit back to the sender
+			if refund {
+				appendix.push(RefundSurplus);
+				appendix.push(Self::deposit_instruction(sender, &dest, 1u32)?);
+			}
+			let appendix = (!appendix.is_empty()).then(|| appendix);
+
+			// Grab the destination
+			Self::transact_in_dest_chain_asset_signed(
+				dest.clone(),
+				who.clone(),
+				fee,
+				call.clone(),
+				OriginKind::SovereignAccount,
+				total_weight,
+				weight_info.transact_required_weight_at_most,
+				appendix,
+			)?;
+
+			// Deposit event
+			Self::deposit_event(Event::<T>::TransactedSigned {
+				fee_payer: who.clone(),
+				dest: dest.clone(),
+				call,
+			});
+
+			if let Some(query_id) = query_id {
+				TransactStatus::<T>::insert(
+					query_id,
+					TransactStatusInfo {
+						sender: who.clone(),
+						dest: dest.clone(),
+						outcome: TransactOutcome::Pending,
+					},
+				);
+				Self::deposit_event(Event::<T>::TransactStatusQueried {
+					query_id,
+					sender: who,
+					dest,
+				});
+			}
+
+			Ok(())
+		}
+
 		fn transact_in_dest_chain_asset_non_signed(
 			dest: MultiLocation,
 			fee_payer: Option<T::AccountId>,
@@ -1084,6 +1360,24 @@ pub mod pallet {
 			})
 		}
 
+		/// Construct an instruction requesting `at` to report back the outcome of the transact
+		/// under `query_id`
+		fn report_transact_status_instruction(
+			query_id: QueryId,
+			at: &MultiLocation,
+		) -> Result<Instruction<()>, DispatchError> {
+			let universal_location = T::UniversalLocation::get();
+			let mut destination = T::SelfLocation::get();
+			destination
+				.reanchor(at, universal_location)
+				.map_err(|_| Error::<T>::CannotReanchor)?;
+			Ok(ReportTransactStatus(QueryResponseInfo {
+				destination,
+				query_id,
+				max_weight: T::WeightInfo::transact_status_notify(),
+			}))
+		}
+
 		/// Construct a withdraw instruction from a sovereign account
 		fn appendix_instruction(
 			instructions: Vec<Instruction<()>>,
@@ -1236,6 +1530,18 @@ pub mod pallet {
 			Ok(Self::calculate_fee_per_second(total_weight, fee_per_second))
 		}
 
+		/// Returns the fee charged in `fee_location` for transacting `transact_weight` in `dest`
+		/// through the derivative or sovereign account, based on the transact info and fee per
+		/// second stored for them
+		pub fn estimate_transact_fee(
+			dest: MultiLocation,
+			transact_weight: Weight,
+			fee_location: MultiLocation,
+		) -> Result<u128, DispatchError> {
+			let total_weight = Self::take_weight_from_transact_info(dest, transact_weight, false)?;
+			Self::take_fee_per_second_from_storage(fee_location, dest, total_weight)
+		}
+
 		/// Converts Currency to multilocation
 		pub fn currency_to_multilocation(
 			currency: Currency<CurrencyIdOf<T>>,
@@ -20,7 +20,7 @@ use super::*;
 use crate as pallet_xcm_transactor;
 use cumulus_primitives_core::MultiAssets;
 use frame_support::traits::PalletInfo as PalletInfoTrait;
-use frame_support::{construct_runtime, parameter_types, weights::Weight};
+use frame_support::{construct_runtime, parameter_types, traits::EnsureOrigin, weights::Weight};
 use frame_system::EnsureRoot;
 use parity_scale_codec::{Decode, Encode};
 
@@ -393,8 +393,42 @@ impl SendXcm for TestSendXcm {
 	}
 }
 
+thread_local! {
+	pub static TRANSACT_STATUS_QUERY_ID: RefCell<u64> = RefCell::new(0);
+}
+
+pub struct MockTransactStatusQuerier;
+impl TransactStatusQuerier for MockTransactStatusQuerier {
+	fn new_transact_status_query(
+		_responder: MultiLocation,
+		_querier: MultiLocation,
+	) -> xcm::latest::QueryId {
+		TRANSACT_STATUS_QUERY_ID.with(|q| {
+			let query_id = *q.borrow();
+			*q.borrow_mut() = query_id + 1;
+			query_id
+		})
+	}
+}
+
+/// Root acts as the relay chain reporting back the outcome of a transact
+pub struct EnsureRelayResponse;
+impl EnsureOrigin<RuntimeOrigin> for EnsureRelayResponse {
+	type Success = MultiLocation;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		EnsureRoot::<u64>::try_origin(o).map(|()| MultiLocation::parent())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::root())
+	}
+}
+
 parameter_types! {
 	pub MaxFee: MultiAsset = (MultiLocation::parent(), 1_000_000_000_000u128).into();
+	pub static DerivativeIndexDeposit: u128 = 0;
 }
 pub type MaxHrmpRelayFee = xcm_builder::Case<MaxFee>;
 
@@ -418,6 +452,10 @@ impl Config for Test {
 	type HrmpManipulatorOrigin = EnsureRoot<u64>;
 	type MaxHrmpFee = MaxHrmpRelayFee;
 	type HrmpEncoder = MockHrmpEncoder;
+	type TransactStatusQuerier = MockTransactStatusQuerier;
+	type TransactStatusResponseOrigin = EnsureRelayResponse;
+	type DepositCurrency = Balances;
+	type DerivativeIndexDeposit = DerivativeIndexDeposit;
 }
 
 pub(crate) struct ExtBuilder {
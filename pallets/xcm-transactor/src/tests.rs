@@ -624,6 +624,123 @@ fn de_registering_works() {
 		})
 }
 
+#[test]
+fn registering_reserves_deposit() {
+	ExtBuilder::default()
+		.with_balances(vec![(1u64, 1000)])
+		.build()
+		.execute_with(|| {
+			DerivativeIndexDeposit::set(100);
+
+			// The deposit cannot be afforded
+			assert_noop!(
+				XcmTransactor::register(RuntimeOrigin::root(), 2u64, 1),
+				pallet_balances::Error::<Test>::InsufficientBalance
+			);
+
+			assert_ok!(XcmTransactor::register(RuntimeOrigin::root(), 1u64, 1));
+
+			assert_eq!(XcmTransactor::index_deposit(&1), Some(100));
+			assert_eq!(Balances::reserved_balance(&1u64), 100);
+
+			// Deregistering refunds the deposit
+			assert_ok!(XcmTransactor::deregister(RuntimeOrigin::root(), 1));
+
+			assert!(XcmTransactor::index_deposit(&1).is_none());
+			assert_eq!(Balances::reserved_balance(&1u64), 0);
+			assert_eq!(Balances::free_balance(&1u64), 1000);
+		})
+}
+
+#[test]
+fn transferring_index_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(1u64, 1000), (2u64, 1000)])
+		.build()
+		.execute_with(|| {
+			DerivativeIndexDeposit::set(100);
+
+			assert_ok!(XcmTransactor::register(RuntimeOrigin::root(), 1u64, 1));
+
+			// Only the owner can transfer the index
+			assert_noop!(
+				XcmTransactor::transfer_index(RuntimeOrigin::signed(2u64), 1, 2u64),
+				Error::<Test>::NotOwner
+			);
+			assert_noop!(
+				XcmTransactor::transfer_index(RuntimeOrigin::signed(1u64), 2, 2u64),
+				Error::<Test>::UnclaimedIndex
+			);
+
+			assert_ok!(XcmTransactor::transfer_index(
+				RuntimeOrigin::signed(1u64),
+				1,
+				2u64
+			));
+
+			assert_eq!(XcmTransactor::index_to_account(&1), Some(2u64));
+
+			// The deposit is transferred along with the index
+			assert_eq!(Balances::free_balance(&1u64), 900);
+			assert_eq!(Balances::reserved_balance(&1u64), 0);
+			assert_eq!(Balances::free_balance(&2u64), 1000);
+			assert_eq!(Balances::reserved_balance(&2u64), 100);
+
+			let expected = vec![
+				crate::Event::RegisteredDerivative {
+					account_id: 1u64,
+					index: 1,
+				},
+				crate::Event::TransferredDerivative {
+					index: 1,
+					from: 1u64,
+					to: 2u64,
+				},
+			];
+			assert_eq!(events(), expected);
+		})
+}
+
+#[test]
+fn releasing_index_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(1u64, 1000)])
+		.build()
+		.execute_with(|| {
+			DerivativeIndexDeposit::set(100);
+
+			assert_ok!(XcmTransactor::register(RuntimeOrigin::root(), 1u64, 1));
+
+			// Only the owner can release the index
+			assert_noop!(
+				XcmTransactor::release_index(RuntimeOrigin::signed(2u64), 1),
+				Error::<Test>::NotOwner
+			);
+
+			assert_ok!(XcmTransactor::release_index(RuntimeOrigin::signed(1u64), 1));
+
+			assert!(XcmTransactor::index_to_account(&1).is_none());
+			assert_eq!(Balances::reserved_balance(&1u64), 0);
+			assert_eq!(Balances::free_balance(&1u64), 1000);
+
+			// The index can be claimed again
+			assert_ok!(XcmTransactor::register(RuntimeOrigin::root(), 1u64, 1));
+
+			let expected = vec![
+				crate::Event::RegisteredDerivative {
+					account_id: 1u64,
+					index: 1,
+				},
+				crate::Event::DeRegisteredDerivative { index: 1 },
+				crate::Event::RegisteredDerivative {
+					account_id: 1u64,
+					index: 1,
+				},
+			];
+			assert_eq!(events(), expected);
+		})
+}
+
 #[test]
 fn removing_transact_info_works() {
 	ExtBuilder::default()
@@ -1684,3 +1801,227 @@ fn test_transact_through_signed_with_refund_works() {
 			]))));
 		})
 }
+
+#[test]
+fn test_estimate_transact_fee() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			// TransactInfo not set
+			assert_noop!(
+				XcmTransactor::estimate_transact_fee(
+					MultiLocation::parent(),
+					1000u64.into(),
+					MultiLocation::parent()
+				),
+				Error::<Test>::TransactorInfoNotSet
+			);
+
+			assert_ok!(XcmTransactor::set_transact_info(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::parent())),
+				100.into(),
+				10000.into(),
+				None
+			));
+
+			// FeePerSecond not set
+			assert_noop!(
+				XcmTransactor::estimate_transact_fee(
+					MultiLocation::parent(),
+					1000u64.into(),
+					MultiLocation::parent()
+				),
+				Error::<Test>::FeePerSecondNotSet
+			);
+
+			assert_ok!(XcmTransactor::set_fee_per_second(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::parent())),
+				WEIGHT_REF_TIME_PER_SECOND as u128
+			));
+
+			// Fee for the transact weight plus the transact extra weight
+			assert_eq!(
+				XcmTransactor::estimate_transact_fee(
+					MultiLocation::parent(),
+					1000u64.into(),
+					MultiLocation::parent()
+				),
+				Ok(1100)
+			);
+
+			// Cannot exceed the max weight
+			assert_noop!(
+				XcmTransactor::estimate_transact_fee(
+					MultiLocation::parent(),
+					10000u64.into(),
+					MultiLocation::parent()
+				),
+				Error::<Test>::MaxWeightTransactReached
+			);
+		})
+}
+
+#[test]
+fn test_transact_through_signed_with_report_works() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			// Set fee per second
+			assert_ok!(XcmTransactor::set_fee_per_second(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::parent())),
+				1
+			));
+
+			// Overall weight to use
+			let total_weight: Weight = 10_100u64.into();
+			assert_ok!(XcmTransactor::transact_through_signed_with_report(
+				RuntimeOrigin::signed(1u64),
+				Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::parent())),
+				CurrencyPayment {
+					currency: Currency::AsCurrencyId(CurrencyId::OtherReserve(0)),
+					fee_amount: None
+				},
+				vec![1u8],
+				TransactWeights {
+					transact_required_weight_at_most: 100u64.into(),
+					overall_weight: Some(total_weight)
+				},
+				false
+			));
+
+			let expected = vec![
+				crate::Event::DestFeePerSecondChanged {
+					location: MultiLocation::parent(),
+					fee_per_second: 1,
+				},
+				crate::Event::TransactedSigned {
+					fee_payer: 1u64,
+					dest: MultiLocation::parent(),
+					call: vec![1u8],
+				},
+				crate::Event::TransactStatusQueried {
+					query_id: 0,
+					sender: 1u64,
+					dest: MultiLocation::parent(),
+				},
+			];
+			assert_eq!(events(), expected);
+			assert_eq!(
+				XcmTransactor::transact_status(0),
+				Some(TransactStatusInfo {
+					sender: 1u64,
+					dest: MultiLocation::parent(),
+					outcome: TransactOutcome::Pending,
+				})
+			);
+
+			let sent_messages = mock::sent_xcm();
+			let (_, sent_message) = sent_messages.first().unwrap();
+
+			// Check message asks the relay to report back to us
+			assert!(sent_message
+				.0
+				.contains(&SetAppendix(Xcm(vec![ReportTransactStatus(
+					QueryResponseInfo {
+						destination: MultiLocation::new(0, X1(Junction::Parachain(100))),
+						query_id: 0,
+						max_weight: <() as WeightInfo>::transact_status_notify(),
+					}
+				)]))));
+
+			// The relay reports back the outcome
+			assert_ok!(XcmTransactor::transact_status_notify(
+				RuntimeOrigin::root(),
+				0,
+				Response::DispatchResult(MaybeErrorCode::Success)
+			));
+			assert_eq!(
+				XcmTransactor::transact_status(0).map(|info| info.outcome),
+				Some(TransactOutcome::Success)
+			);
+			assert_eq!(
+				events().last(),
+				Some(&crate::Event::TransactStatusReceived {
+					query_id: 0,
+					outcome: TransactOutcome::Success,
+				})
+			);
+		})
+}
+
+#[test]
+fn test_transact_status_notify_errors() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			TransactStatus::<Test>::insert(
+				0,
+				TransactStatusInfo {
+					sender: 1u64,
+					dest: MultiLocation::new(1, X1(Junction::Parachain(1000))),
+					outcome: TransactOutcome::Pending,
+				},
+			);
+			TransactStatus::<Test>::insert(
+				1,
+				TransactStatusInfo {
+					sender: 1u64,
+					dest: MultiLocation::parent(),
+					outcome: TransactOutcome::Pending,
+				},
+			);
+
+			// Only the response origin can report back
+			assert_noop!(
+				XcmTransactor::transact_status_notify(
+					RuntimeOrigin::signed(1u64),
+					1,
+					Response::DispatchResult(MaybeErrorCode::Success)
+				),
+				DispatchError::BadOrigin
+			);
+
+			// Query not registered
+			assert_noop!(
+				XcmTransactor::transact_status_notify(
+					RuntimeOrigin::root(),
+					2,
+					Response::DispatchResult(MaybeErrorCode::Success)
+				),
+				Error::<Test>::UnknownTransactStatusQuery
+			);
+
+			// Response comes from a chain other than the destination
+			assert_noop!(
+				XcmTransactor::transact_status_notify(
+					RuntimeOrigin::root(),
+					0,
+					Response::DispatchResult(MaybeErrorCode::Success)
+				),
+				Error::<Test>::UnexpectedTransactStatusResponder
+			);
+
+			// Response is not a dispatch result
+			assert_noop!(
+				XcmTransactor::transact_status_notify(RuntimeOrigin::root(), 1, Response::Null),
+				Error::<Test>::UnexpectedTransactStatusResponse
+			);
+
+			// Any error code is recorded as a failure
+			assert_ok!(XcmTransactor::transact_status_notify(
+				RuntimeOrigin::root(),
+				1,
+				Response::DispatchResult(MaybeErrorCode::Error(vec![1u8].try_into().unwrap()))
+			));
+			assert_eq!(
+				XcmTransactor::transact_status(1).map(|info| info.outcome),
+				Some(TransactOutcome::Failed)
+			);
+		})
+}
@@ -61,6 +61,10 @@ pub trait WeightInfo {
 	fn transact_through_sovereign() -> Weight;
 	fn transact_through_signed() -> Weight;
 	fn hrmp_manage() -> Weight;
+	fn transact_through_signed_with_report() -> Weight;
+	fn transact_status_notify() -> Weight;
+	fn transfer_index() -> Weight;
+	fn release_index() -> Weight;
 }
 
 /// Weights for xcm_transactor using the Substrate node and recommended hardware.
@@ -68,24 +72,27 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: XcmTransactor IndexToAccount (r:1 w:1)
 	/// Proof Skipped: XcmTransactor IndexToAccount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: XcmTransactor IndexDeposit (r:0 w:1)
+	/// Proof Skipped: XcmTransactor IndexDeposit (max_values: None, max_size: None, mode: Measured)
 	fn register() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `3541`
-		// Minimum execution time: 17_609_000 picoseconds.
-		Weight::from_parts(18_079_000, 3541)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(64_000_000, 3581)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-	/// Storage: XcmTransactor IndexToAccount (r:0 w:1)
+	/// Storage: XcmTransactor IndexToAccount (r:1 w:1)
 	/// Proof Skipped: XcmTransactor IndexToAccount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor IndexDeposit (r:1 w:1)
+	/// Proof Skipped: XcmTransactor IndexDeposit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	fn deregister() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 12_958_000 picoseconds.
-		Weight::from_parts(13_219_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(61_000_000, 3768)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: XcmTransactor TransactInfoWithWeightLimit (r:0 w:1)
 	/// Proof Skipped: XcmTransactor TransactInfoWithWeightLimit (max_values: None, max_size: None, mode: Measured)
@@ -205,30 +212,93 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactInfoWithWeightLimit (r:1 w:0)
+	/// Proof Skipped: XcmTransactor TransactInfoWithWeightLimit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor DestinationAssetFeePerSecond (r:1 w:0)
+	/// Proof Skipped: XcmTransactor DestinationAssetFeePerSecond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	/// Proof Skipped: PolkadotXcm QueryCounter (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	/// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	/// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	/// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm Queries (r:0 w:1)
+	/// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactStatus (r:0 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatus (max_values: None, max_size: None, mode: Measured)
+	fn transact_through_signed_with_report() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(160_000_000, 26700)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: XcmTransactor TransactStatus (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatus (max_values: None, max_size: None, mode: Measured)
+	fn transact_status_notify() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(31_000_000, 3708)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmTransactor IndexToAccount (r:1 w:1)
+	/// Proof Skipped: XcmTransactor IndexToAccount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor IndexDeposit (r:1 w:0)
+	/// Proof Skipped: XcmTransactor IndexDeposit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn transfer_index() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(79_000_000, 6172)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: XcmTransactor IndexToAccount (r:1 w:1)
+	/// Proof Skipped: XcmTransactor IndexToAccount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor IndexDeposit (r:1 w:1)
+	/// Proof Skipped: XcmTransactor IndexDeposit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn release_index() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(63_000_000, 3768)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	/// Storage: XcmTransactor IndexToAccount (r:1 w:1)
 	/// Proof Skipped: XcmTransactor IndexToAccount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: XcmTransactor IndexDeposit (r:0 w:1)
+	/// Proof Skipped: XcmTransactor IndexDeposit (max_values: None, max_size: None, mode: Measured)
 	fn register() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `3541`
-		// Minimum execution time: 17_609_000 picoseconds.
-		Weight::from_parts(18_079_000, 3541)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(64_000_000, 3581)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
-	/// Storage: XcmTransactor IndexToAccount (r:0 w:1)
+	/// Storage: XcmTransactor IndexToAccount (r:1 w:1)
 	/// Proof Skipped: XcmTransactor IndexToAccount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor IndexDeposit (r:1 w:1)
+	/// Proof Skipped: XcmTransactor IndexDeposit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	fn deregister() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 12_958_000 picoseconds.
-		Weight::from_parts(13_219_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(61_000_000, 3768)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: XcmTransactor TransactInfoWithWeightLimit (r:0 w:1)
 	/// Proof Skipped: XcmTransactor TransactInfoWithWeightLimit (max_values: None, max_size: None, mode: Measured)
@@ -348,4 +418,64 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-}
\ No newline at end of file
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactInfoWithWeightLimit (r:1 w:0)
+	/// Proof Skipped: XcmTransactor TransactInfoWithWeightLimit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor DestinationAssetFeePerSecond (r:1 w:0)
+	/// Proof Skipped: XcmTransactor DestinationAssetFeePerSecond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	/// Proof Skipped: PolkadotXcm QueryCounter (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	/// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	/// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	/// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm Queries (r:0 w:1)
+	/// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactStatus (r:0 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatus (max_values: None, max_size: None, mode: Measured)
+	fn transact_through_signed_with_report() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(160_000_000, 26700)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: XcmTransactor TransactStatus (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatus (max_values: None, max_size: None, mode: Measured)
+	fn transact_status_notify() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(31_000_000, 3708)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmTransactor IndexToAccount (r:1 w:1)
+	/// Proof Skipped: XcmTransactor IndexToAccount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor IndexDeposit (r:1 w:0)
+	/// Proof Skipped: XcmTransactor IndexDeposit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn transfer_index() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(79_000_000, 6172)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: XcmTransactor IndexToAccount (r:1 w:1)
+	/// Proof Skipped: XcmTransactor IndexToAccount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor IndexDeposit (r:1 w:1)
+	/// Proof Skipped: XcmTransactor IndexDeposit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn release_index() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(63_000_000, 3768)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
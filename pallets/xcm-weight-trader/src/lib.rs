@@ -0,0 +1,207 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # XCM weight trader pallet
+//!
+//! This pallet prices the execution of XCM messages paid in foreign assets. The units of an asset
+//! charged per second of execution are derived from the price of the asset relative to the native
+//! token, as reported by the on-chain price feed governance assigned to the asset, such that XCM
+//! fees track market prices. Prices older than the maximum age set by governance are considered
+//! stale and ignored.
+//!
+//! When an asset has no feed or no fresh price, or oracle pricing is disabled by governance,
+//! the static units per second set by governance (the fallback, usually pallet-asset-manager) are
+//! charged instead. Whether an asset can pay for XCM execution at all, and which assets are
+//! preferred, is always decided by the fallback.
+//!
+//! The pallet implements [`UnitsToWeightRatio`], and is meant to be used as the units per second
+//! getter of the `FirstAssetTrader`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::{pallet, pallet_prelude::*, traits::UnixTime};
+use sp_runtime::{FixedPointNumber, FixedU128, SaturatedConversion};
+use sp_std::vec::Vec;
+use xcm_primitives::UnitsToWeightRatio;
+
+/// Feeds of the prices of assets relative to the native token, identified by `FeedId`.
+pub trait PriceFeed<FeedId> {
+	/// Price of the smallest unit of the native token, in smallest units of the asset priced by
+	/// the feed, along with the time of its last update in milliseconds since the unix epoch.
+	fn relative_price(feed_id: &FeedId) -> Option<(FixedU128, u64)>;
+}
+
+impl<FeedId> PriceFeed<FeedId> for () {
+	fn relative_price(_feed_id: &FeedId) -> Option<(FixedU128, u64)> {
+		None
+	}
+}
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The asset types XCM execution can be paid with.
+		type AssetType: Parameter + Default;
+
+		/// Identifier of the feeds of `PriceFeed`.
+		type FeedId: Parameter + Copy + Default + MaxEncodedLen;
+
+		/// Feeds of the prices of the assets relative to the native token.
+		type PriceFeed: PriceFeed<Self::FeedId>;
+
+		/// Units per second set by governance, charged when there is no fresh price for an asset.
+		/// Also decides which assets can pay for XCM execution.
+		type Fallback: UnitsToWeightRatio<Self::AssetType>;
+
+		/// Units of the native token charged per second of execution.
+		type NativeUnitsPerSecond: Get<u128>;
+
+		/// Time used to decide whether the prices are stale.
+		type Time: UnixTime;
+
+		/// Origin that is allowed to set the maximum age of the prices and the feeds of the assets.
+		type PriceManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Maximum age, in milliseconds, of the prices of the feed used to price XCM execution.
+	/// `None` disables oracle pricing, such that the fallback units per second are always charged.
+	#[pallet::storage]
+	#[pallet::getter(fn max_price_age)]
+	pub type MaxPriceAge<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+	/// Feed pricing each asset. Assets without a feed are charged the fallback units per second.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_price_feed)]
+	pub type AssetPriceFeeds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetType, T::FeedId, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The maximum age of the prices changed, `None` disabling oracle pricing.
+		MaxPriceAgeSet { max_price_age: Option<u64> },
+		/// The feed pricing an asset changed, `None` removing it.
+		AssetPriceFeedSet {
+			asset_type: T::AssetType,
+			feed_id: Option<T::FeedId>,
+		},
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the maximum age, in milliseconds, of the prices used to price XCM execution, or
+		/// disable oracle pricing with `None`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_max_price_age())]
+		pub fn set_max_price_age(
+			origin: OriginFor<T>,
+			max_price_age: Option<u64>,
+		) -> DispatchResult {
+			T::PriceManagerOrigin::ensure_origin(origin)?;
+
+			MaxPriceAge::<T>::set(max_price_age);
+
+			Self::deposit_event(Event::MaxPriceAgeSet { max_price_age });
+			Ok(())
+		}
+
+		/// Set the feed pricing `asset_type`, or charge the fallback units per second for it with
+		/// `None`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_asset_price_feed())]
+		pub fn set_asset_price_feed(
+			origin: OriginFor<T>,
+			asset_type: T::AssetType,
+			feed_id: Option<T::FeedId>,
+		) -> DispatchResult {
+			T::PriceManagerOrigin::ensure_origin(origin)?;
+
+			AssetPriceFeeds::<T>::set(&asset_type, feed_id);
+
+			Self::deposit_event(Event::AssetPriceFeedSet {
+				asset_type,
+				feed_id,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Units of the asset per second of execution derived from its price in its feed, if
+		/// oracle pricing is enabled and the price is not stale.
+		pub fn oracle_units_per_second(asset_type: &T::AssetType) -> Option<u128> {
+			let max_price_age = MaxPriceAge::<T>::get()?;
+			let feed_id = AssetPriceFeeds::<T>::get(asset_type)?;
+			let (price, updated_at) = T::PriceFeed::relative_price(&feed_id)?;
+
+			let now = T::Time::now().as_millis().saturated_into::<u64>();
+			if now.saturating_sub(updated_at) > max_price_age {
+				log::debug!(
+					target: "xcm-weight-trader",
+					"stale price updated at {}, now {}", updated_at, now,
+				);
+				return None;
+			}
+
+			// A null price would make XCM execution free: the fallback is charged instead.
+			let units_per_second = price.saturating_mul_int(T::NativeUnitsPerSecond::get());
+			(units_per_second > 0).then_some(units_per_second)
+		}
+	}
+
+	impl<T: Config> UnitsToWeightRatio<T::AssetType> for Pallet<T> {
+		fn payment_is_supported(asset_type: T::AssetType) -> bool {
+			T::Fallback::payment_is_supported(asset_type)
+		}
+		fn get_units_per_second(asset_type: T::AssetType) -> Option<u128> {
+			Self::oracle_units_per_second(&asset_type)
+				.or_else(|| T::Fallback::get_units_per_second(asset_type))
+		}
+		fn fee_asset_preference() -> Vec<T::AssetType> {
+			T::Fallback::fee_asset_preference()
+		}
+		#[cfg(feature = "runtime-benchmarks")]
+		fn set_units_per_second(asset_type: T::AssetType, fee_per_second: u128) {
+			T::Fallback::set_units_per_second(asset_type, fee_per_second)
+		}
+	}
+}
@@ -0,0 +1,174 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test utilities
+
+use crate as pallet_xcm_weight_trader;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU128, ConstU32, Everything},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	FixedU128,
+};
+use std::{cell::RefCell, collections::BTreeMap};
+use xcm_primitives::UnitsToWeightRatio;
+
+pub type AccountId = u64;
+pub type BlockNumber = u32;
+pub type AssetType = u32;
+pub type FeedId = u32;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		XcmWeightTrader: pallet_xcm_weight_trader::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Header = sp_runtime::generic::Header<BlockNumber, BlakeTwo256>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+/// Asset supported by the fallback, which charges `FALLBACK_UNITS_PER_SECOND` for it.
+pub(crate) const SUPPORTED_ASSET: AssetType = 1;
+/// Asset not supported by the fallback.
+pub(crate) const UNSUPPORTED_ASSET: AssetType = 2;
+/// Feed used to price `SUPPORTED_ASSET` in the tests.
+pub(crate) const FEED: FeedId = 10;
+pub(crate) const FALLBACK_UNITS_PER_SECOND: u128 = 500_000;
+pub(crate) const NATIVE_UNITS_PER_SECOND: u128 = 1_000_000;
+
+thread_local! {
+	static PRICES: RefCell<BTreeMap<FeedId, (FixedU128, u64)>> = RefCell::new(BTreeMap::new());
+}
+
+/// Price feeds reporting the prices set with `set_price`.
+pub struct MockPriceFeed;
+impl crate::PriceFeed<FeedId> for MockPriceFeed {
+	fn relative_price(feed_id: &FeedId) -> Option<(FixedU128, u64)> {
+		PRICES.with(|prices| prices.borrow().get(feed_id).copied())
+	}
+}
+
+/// Report `price` in the feed, updated at `updated_at`.
+pub(crate) fn set_price(feed_id: FeedId, price: FixedU128, updated_at: u64) {
+	PRICES.with(|prices| prices.borrow_mut().insert(feed_id, (price, updated_at)));
+}
+
+pub struct MockFallback;
+impl UnitsToWeightRatio<AssetType> for MockFallback {
+	fn payment_is_supported(asset_type: AssetType) -> bool {
+		asset_type == SUPPORTED_ASSET
+	}
+	fn get_units_per_second(asset_type: AssetType) -> Option<u128> {
+		(asset_type == SUPPORTED_ASSET).then_some(FALLBACK_UNITS_PER_SECOND)
+	}
+	fn fee_asset_preference() -> Vec<AssetType> {
+		vec![SUPPORTED_ASSET]
+	}
+}
+
+impl pallet_xcm_weight_trader::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetType = AssetType;
+	type FeedId = FeedId;
+	type PriceFeed = MockPriceFeed;
+	type Fallback = MockFallback;
+	type NativeUnitsPerSecond = ConstU128<NATIVE_UNITS_PER_SECOND>;
+	type Time = Timestamp;
+	type PriceManagerOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder;
+
+impl ExtBuilder {
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::default()
+			.build_storage::<Test>()
+			.expect("Frame system builds valid default genesis config");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+pub(crate) fn events() -> Vec<pallet_xcm_weight_trader::Event<Test>> {
+	System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| {
+			if let RuntimeEvent::XcmWeightTrader(inner) = e {
+				Some(inner)
+			} else {
+				None
+			}
+		})
+		.collect::<Vec<_>>()
+}
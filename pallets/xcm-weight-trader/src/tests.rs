@@ -0,0 +1,238 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{Event, Pallet};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError, FixedU128};
+use xcm_primitives::UnitsToWeightRatio;
+
+const MAX_PRICE_AGE: u64 = 60_000;
+const NOW: u64 = 1_000_000;
+
+fn price_supported_asset_with_feed() {
+	assert_ok!(XcmWeightTrader::set_asset_price_feed(
+		RuntimeOrigin::root(),
+		SUPPORTED_ASSET,
+		Some(FEED)
+	));
+}
+
+fn enable_oracle_pricing() {
+	assert_ok!(XcmWeightTrader::set_max_price_age(
+		RuntimeOrigin::root(),
+		Some(MAX_PRICE_AGE)
+	));
+	price_supported_asset_with_feed();
+	Timestamp::set_timestamp(NOW);
+}
+
+fn units_per_second(asset_type: AssetType) -> Option<u128> {
+	<Pallet<Test> as UnitsToWeightRatio<AssetType>>::get_units_per_second(asset_type)
+}
+
+#[test]
+fn fallback_is_charged_without_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		enable_oracle_pricing();
+
+		assert_eq!(
+			units_per_second(SUPPORTED_ASSET),
+			Some(FALLBACK_UNITS_PER_SECOND)
+		);
+	});
+}
+
+#[test]
+fn fallback_is_charged_for_assets_without_feed() {
+	ExtBuilder::default().build().execute_with(|| {
+		enable_oracle_pricing();
+		set_price(FEED, FixedU128::from_rational(3, 2), NOW);
+		assert_ok!(XcmWeightTrader::set_asset_price_feed(
+			RuntimeOrigin::root(),
+			SUPPORTED_ASSET,
+			None
+		));
+
+		assert_eq!(
+			units_per_second(SUPPORTED_ASSET),
+			Some(FALLBACK_UNITS_PER_SECOND)
+		);
+	});
+}
+
+#[test]
+fn fresh_price_is_charged() {
+	ExtBuilder::default().build().execute_with(|| {
+		enable_oracle_pricing();
+		set_price(FEED, FixedU128::from_rational(3, 2), NOW - MAX_PRICE_AGE);
+
+		assert_eq!(
+			units_per_second(SUPPORTED_ASSET),
+			Some(NATIVE_UNITS_PER_SECOND * 3 / 2)
+		);
+	});
+}
+
+#[test]
+fn stale_price_falls_back() {
+	ExtBuilder::default().build().execute_with(|| {
+		enable_oracle_pricing();
+		set_price(
+			FEED,
+			FixedU128::from_rational(3, 2),
+			NOW - MAX_PRICE_AGE - 1,
+		);
+
+		assert_eq!(
+			units_per_second(SUPPORTED_ASSET),
+			Some(FALLBACK_UNITS_PER_SECOND)
+		);
+	});
+}
+
+#[test]
+fn null_price_falls_back() {
+	ExtBuilder::default().build().execute_with(|| {
+		enable_oracle_pricing();
+		set_price(FEED, FixedU128::from_inner(0), NOW);
+
+		assert_eq!(
+			units_per_second(SUPPORTED_ASSET),
+			Some(FALLBACK_UNITS_PER_SECOND)
+		);
+	});
+}
+
+#[test]
+fn price_is_ignored_while_oracle_pricing_is_disabled() {
+	ExtBuilder::default().build().execute_with(|| {
+		price_supported_asset_with_feed();
+		Timestamp::set_timestamp(NOW);
+		set_price(FEED, FixedU128::from_rational(3, 2), NOW);
+
+		assert_eq!(Pallet::<Test>::max_price_age(), None);
+		assert_eq!(
+			units_per_second(SUPPORTED_ASSET),
+			Some(FALLBACK_UNITS_PER_SECOND)
+		);
+	});
+}
+
+#[test]
+fn fallback_decides_supported_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		enable_oracle_pricing();
+		assert_ok!(XcmWeightTrader::set_asset_price_feed(
+			RuntimeOrigin::root(),
+			UNSUPPORTED_ASSET,
+			Some(FEED)
+		));
+		set_price(FEED, FixedU128::from_rational(3, 2), NOW);
+
+		assert!(Pallet::<Test>::payment_is_supported(SUPPORTED_ASSET));
+		assert!(!Pallet::<Test>::payment_is_supported(UNSUPPORTED_ASSET));
+		assert_eq!(
+			Pallet::<Test>::fee_asset_preference(),
+			vec![SUPPORTED_ASSET]
+		);
+	});
+}
+
+#[test]
+fn set_max_price_age_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmWeightTrader::set_max_price_age(
+			RuntimeOrigin::root(),
+			Some(MAX_PRICE_AGE)
+		));
+		assert_eq!(Pallet::<Test>::max_price_age(), Some(MAX_PRICE_AGE));
+
+		assert_ok!(XcmWeightTrader::set_max_price_age(
+			RuntimeOrigin::root(),
+			None
+		));
+		assert_eq!(Pallet::<Test>::max_price_age(), None);
+
+		assert_eq!(
+			events(),
+			vec![
+				Event::MaxPriceAgeSet {
+					max_price_age: Some(MAX_PRICE_AGE),
+				},
+				Event::MaxPriceAgeSet {
+					max_price_age: None,
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn only_price_manager_can_set_max_price_age() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmWeightTrader::set_max_price_age(RuntimeOrigin::signed(1), Some(MAX_PRICE_AGE)),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_asset_price_feed_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		price_supported_asset_with_feed();
+		assert_eq!(
+			Pallet::<Test>::asset_price_feed(SUPPORTED_ASSET),
+			Some(FEED)
+		);
+
+		assert_ok!(XcmWeightTrader::set_asset_price_feed(
+			RuntimeOrigin::root(),
+			SUPPORTED_ASSET,
+			None
+		));
+		assert_eq!(Pallet::<Test>::asset_price_feed(SUPPORTED_ASSET), None);
+
+		assert_eq!(
+			events(),
+			vec![
+				Event::AssetPriceFeedSet {
+					asset_type: SUPPORTED_ASSET,
+					feed_id: Some(FEED),
+				},
+				Event::AssetPriceFeedSet {
+					asset_type: SUPPORTED_ASSET,
+					feed_id: None,
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn only_price_manager_can_set_asset_price_feed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmWeightTrader::set_asset_price_feed(
+				RuntimeOrigin::signed(1),
+				SUPPORTED_ASSET,
+				Some(FEED)
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
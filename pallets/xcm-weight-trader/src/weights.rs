@@ -0,0 +1,67 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_xcm_weight_trader`
+//!
+//! These weights have not been benchmarked yet. They are hand-written, deliberately
+//! conservative estimates whose proof sizes follow the storage bounds; regenerate them
+//! with `moonbeam benchmark pallet` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_xcm_weight_trader.
+pub trait WeightInfo {
+	fn set_max_price_age() -> Weight;
+	fn set_asset_price_feed() -> Weight;
+}
+
+/// Weights for pallet_xcm_weight_trader using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: XcmWeightTrader MaxPriceAge (r:0 w:1)
+	/// Proof: XcmWeightTrader MaxPriceAge (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn set_max_price_age() -> Weight {
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmWeightTrader AssetPriceFeeds (r:0 w:1)
+	/// Proof Skipped: XcmWeightTrader AssetPriceFeeds (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_price_feed() -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: XcmWeightTrader MaxPriceAge (r:0 w:1)
+	/// Proof: XcmWeightTrader MaxPriceAge (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn set_max_price_age() -> Weight {
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmWeightTrader AssetPriceFeeds (r:0 w:1)
+	/// Proof Skipped: XcmWeightTrader AssetPriceFeeds (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_price_feed() -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
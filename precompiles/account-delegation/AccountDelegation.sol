@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The AccountDelegation contract's address.
+address constant ACCOUNT_DELEGATION_ADDRESS = 0x0000000000000000000000000000000000000823;
+
+/// @dev The AccountDelegation contract's instance.
+AccountDelegation constant ACCOUNT_DELEGATION_CONTRACT = AccountDelegation(
+    ACCOUNT_DELEGATION_ADDRESS
+);
+
+/// @author The Moonbeam Team
+/// @title Account Delegation Interface
+/// @dev The interface through which externally owned accounts delegate to the code of a smart
+/// contract (e.g. a smart wallet), as with EIP-7702 set-code transactions. The delegated code is
+/// run in the context of the account through `execute`.
+/// @custom:address 0x0000000000000000000000000000000000000823
+interface AccountDelegation {
+    /// @dev Delegate the account of the caller to the code of `code`, or remove its delegation
+    /// if `code` is the zero address. The caller must be an externally owned account.
+    /// @custom:selector e6b3e704
+    /// @param code The contract whose code the caller delegates to
+    function setDelegation(address code) external;
+
+    /// @dev Delegate the account of `authority` to the code of `code`, or remove its delegation
+    /// if `code` is the zero address, with an EIP-712 authorization signed by `authority`.
+    /// The authorization can be submitted by any account.
+    /// @custom:selector 131ddb7b
+    /// @param authority The account signing the authorization
+    /// @param code The contract whose code `authority` delegates to
+    /// @param deadline Deadline in UNIX seconds after which the authorization is no longer valid
+    /// @param v V part of the signature
+    /// @param r R part of the signature
+    /// @param s S part of the signature
+    function setDelegationWithSignature(
+        address authority,
+        address code,
+        uint256 deadline,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external;
+
+    /// @dev Run the code `authority` delegates to in the context of `authority`: the code uses
+    /// the storage and balance of `authority`, and its calls are made on behalf of `authority`.
+    /// The caller of the code is the caller of `execute`.
+    /// @custom:selector 1cff79cd
+    /// @param authority The account whose delegated code is run
+    /// @param data Call data of the delegated code
+    /// @return output Output of the delegated code
+    function execute(address authority, bytes memory data)
+        external
+        returns (bytes memory output);
+
+    /// @dev Code an account delegates to
+    /// @custom:selector eed50a32
+    /// @param authority The delegating account
+    /// @return code The contract whose code `authority` delegates to, the zero address if none
+    function delegation(address authority) external view returns (address code);
+
+    /// @dev Nonce of the next authorization of an account
+    /// @custom:selector 7ecebe00
+    /// @param authority The account signing the authorizations
+    /// @return nonce The nonce to include in the next authorization
+    function nonces(address authority) external view returns (uint256 nonce);
+
+    /// @dev EIP-712 domain separator of the authorizations
+    /// @custom:selector 3644e515
+    /// @return separator The domain separator
+    // solhint-disable-next-line func-name-mixedcase
+    function DOMAIN_SEPARATOR() external view returns (bytes32 separator);
+
+    /// @dev Emitted when an account delegates to a new code, or removes its delegation
+    /// @custom:selector 39e3ace752997a751bda5ecf126da84d0d23d5e61f3f56f26a6b22990d7beed8
+    /// @param authority The delegating account
+    /// @param code The contract whose code `authority` delegates to, the zero address if removed
+    event DelegationSet(address indexed authority, address indexed code);
+}
@@ -0,0 +1,336 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile equivalent of the EIP-7702 code delegation of externally owned accounts.
+//!
+//! An EOA delegates to the code of a smart contract (e.g. a smart wallet), either directly or
+//! by signing an authorization submitted by another account. Calls to `execute` then run the
+//! delegated code in the context of the EOA, like a call to the EOA would under EIP-7702: the
+//! code uses the storage and the balance of the EOA, and its own calls are made on behalf of
+//! the EOA. Unlike EIP-7702, the EOA keeps no code, so it can still sign transactions and is
+//! validated as any other sender by the transaction pool.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use evm::ExitReason;
+use fp_evm::{Context, ExitRevert, PrecompileFailure, PrecompileHandle};
+use frame_support::{
+	ensure,
+	storage::types::{OptionQuery, StorageMap, ValueQuery},
+	traits::{ConstU32, Get, StorageInstance},
+	Blake2_128Concat,
+};
+use precompile_utils::{
+	precompile_set::{self, AddressType},
+	prelude::*,
+};
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Storage prefix for delegations.
+pub struct Delegations;
+
+impl StorageInstance for Delegations {
+	const STORAGE_PREFIX: &'static str = "Delegations";
+
+	fn pallet_prefix() -> &'static str {
+		"PrecompileAccountDelegation"
+	}
+}
+
+/// Code each EOA delegates to.
+pub type DelegationsStorage = StorageMap<
+	Delegations,
+	// Authority
+	Blake2_128Concat,
+	H160,
+	// Code
+	H160,
+	OptionQuery,
+>;
+
+/// Storage prefix for nonces.
+pub struct Nonces;
+
+impl StorageInstance for Nonces {
+	const STORAGE_PREFIX: &'static str = "Nonces";
+
+	fn pallet_prefix() -> &'static str {
+		"PrecompileAccountDelegation"
+	}
+}
+
+/// Nonces of the signed delegation authorizations, per authority.
+pub type NoncesStorage = StorageMap<
+	Nonces,
+	// Authority
+	Blake2_128Concat,
+	H160,
+	// Nonce
+	U256,
+	ValueQuery,
+>;
+
+/// EIP712 typehash of the delegation authorizations.
+pub const SET_DELEGATION_TYPEHASH: [u8; 32] =
+	keccak256!("SetDelegation(address authority,address code,uint256 nonce,uint256 deadline)");
+
+/// EIP712 domain used to compute an individualized domain separator.
+const DELEGATION_DOMAIN: [u8; 32] = keccak256!(
+	"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+);
+
+/// Solidity selector of the DelegationSet log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_DELEGATION_SET: [u8; 32] = keccak256!("DelegationSet(address,address)");
+
+pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
+
+/// Precompile allowing externally owned accounts to delegate to the code of a smart contract.
+pub struct AccountDelegationPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> AccountDelegationPrecompile<Runtime>
+where
+	Runtime: pallet_evm::Config + pallet_timestamp::Config,
+	<Runtime as pallet_timestamp::Config>::Moment: Into<U256>,
+{
+	/// Delegate the account of the caller to the code of `code`, or remove its delegation if
+	/// `code` is the zero address.
+	#[precompile::public("setDelegation(address)")]
+	fn set_delegation(handle: &mut impl PrecompileHandle, code: Address) -> EvmResult {
+		let authority = handle.context().caller;
+
+		// Check that only externally owned accounts delegate
+		let AddressType::EOA = precompile_set::get_address_type::<Runtime>(handle, authority)?
+		else {
+			return Err(revert("Only externally owned accounts can delegate"));
+		};
+
+		Self::inner_set_delegation(handle, authority, code.into())
+	}
+
+	/// Delegate the account of `authority` to the code of `code`, or remove its delegation if
+	/// `code` is the zero address, authorized by an EIP-712 signature of `authority`. The call
+	/// can be submitted by any account, allowing `authority` to delegate without paying for gas.
+	#[precompile::public(
+		"setDelegationWithSignature(address,address,uint256,uint8,bytes32,bytes32)"
+	)]
+	fn set_delegation_with_signature(
+		handle: &mut impl PrecompileHandle,
+		authority: Address,
+		code: Address,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> EvmResult {
+		// Now: 8
+		handle.record_db_read::<Runtime>(8)?;
+		// NoncesStorage: Blake2_128(16) + authority(20) + nonce(32)
+		handle.record_db_read::<Runtime>(68)?;
+		handle.record_cost(Self::signature_inherent_cost())?;
+
+		// pallet_timestamp is in ms while Ethereum use second timestamps.
+		let timestamp: U256 = (pallet_timestamp::Pallet::<Runtime>::get()).into() / 1000;
+		ensure!(deadline >= timestamp, revert("Authorization expired"));
+
+		let authority: H160 = authority.into();
+		let code: H160 = code.into();
+		let nonce = NoncesStorage::get(authority);
+
+		let message = Self::generate_set_delegation_message(
+			handle.context().address,
+			authority,
+			code,
+			nonce,
+			deadline,
+		);
+
+		let mut signature = [0u8; 65];
+		signature[0..32].copy_from_slice(&r.as_bytes());
+		signature[32..64].copy_from_slice(&s.as_bytes());
+		signature[64] = v;
+
+		let signer = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &message)
+			.map_err(|_| revert("Invalid authorization"))?;
+		let signer = H160::from(H256::from_slice(keccak_256(&signer).as_slice()));
+
+		ensure!(
+			signer != H160::zero() && signer == authority,
+			revert("Invalid authorization")
+		);
+
+		NoncesStorage::insert(authority, nonce + U256::one());
+
+		Self::inner_set_delegation(handle, authority, code)
+	}
+
+	/// Run the code `authority` delegates to with the call data `data`, in the context of
+	/// `authority`. The code is called by the caller, and returns its output.
+	#[precompile::public("execute(address,bytes)")]
+	fn execute(
+		handle: &mut impl PrecompileHandle,
+		authority: Address,
+		data: BoundedBytes<ConstU32<CALL_DATA_LIMIT>>,
+	) -> EvmResult<UnboundedBytes> {
+		let authority: H160 = authority.into();
+
+		// The delegated code can only act on behalf of externally owned accounts
+		let AddressType::EOA = precompile_set::get_address_type::<Runtime>(handle, authority)?
+		else {
+			return Err(revert("authority address must be EOA"));
+		};
+
+		// DelegationsStorage: Blake2_128(16) + authority(20) + code(20)
+		handle.record_db_read::<Runtime>(56)?;
+		let code = DelegationsStorage::get(authority).ok_or_else(|| revert("No delegation"))?;
+
+		// The code of `code` is executed on the account of `authority`, like a DELEGATECALL.
+		let sub_context = Context {
+			caller: handle.context().caller,
+			address: authority,
+			apparent_value: U256::zero(),
+		};
+
+		let (reason, output) = handle.call(
+			code,
+			None,
+			data.into(),
+			Some(handle.remaining_gas()),
+			false,
+			&sub_context,
+		);
+		match reason {
+			ExitReason::Error(exit_status) => Err(PrecompileFailure::Error { exit_status }),
+			ExitReason::Fatal(exit_status) => Err(PrecompileFailure::Fatal { exit_status }),
+			ExitReason::Revert(_) => Err(PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output,
+			}),
+			ExitReason::Succeed(_) => Ok(output.into()),
+		}
+	}
+
+	/// Code `authority` delegates to, or the zero address if it has no delegation.
+	#[precompile::public("delegation(address)")]
+	#[precompile::view]
+	fn delegation(handle: &mut impl PrecompileHandle, authority: Address) -> EvmResult<Address> {
+		// DelegationsStorage: Blake2_128(16) + authority(20) + code(20)
+		handle.record_db_read::<Runtime>(56)?;
+
+		let code = DelegationsStorage::get(H160::from(authority)).unwrap_or_default();
+
+		Ok(Address(code))
+	}
+
+	/// Nonce of the next delegation authorization of `authority`.
+	#[precompile::public("nonces(address)")]
+	#[precompile::view]
+	fn nonces(handle: &mut impl PrecompileHandle, authority: Address) -> EvmResult<U256> {
+		// NoncesStorage: Blake2_128(16) + authority(20) + nonce(32)
+		handle.record_db_read::<Runtime>(68)?;
+
+		Ok(NoncesStorage::get(H160::from(authority)))
+	}
+
+	/// EIP-712 domain separator of the delegation authorizations.
+	#[precompile::public("DOMAIN_SEPARATOR()")]
+	#[precompile::view]
+	fn domain_separator(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+		// ChainId
+		handle.record_db_read::<Runtime>(8)?;
+
+		Ok(Self::compute_domain_separator(handle.context().address).into())
+	}
+
+	fn inner_set_delegation(
+		handle: &mut impl PrecompileHandle,
+		authority: H160,
+		code: H160,
+	) -> EvmResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		handle.record_log_costs_manual(3, 0)?;
+
+		if code.is_zero() {
+			DelegationsStorage::remove(authority);
+		} else {
+			DelegationsStorage::insert(authority, code);
+		}
+
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_DELEGATION_SET,
+			authority,
+			code,
+			solidity::encode_event_data(()),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	fn compute_domain_separator(address: H160) -> [u8; 32] {
+		let name: H256 = keccak_256(b"Account Delegation Precompile").into();
+		let version: H256 = keccak256!("1").into();
+		let chain_id: U256 = Runtime::ChainId::get().into();
+
+		let domain_separator_inner = solidity::encode_arguments((
+			H256::from(DELEGATION_DOMAIN),
+			name,
+			version,
+			chain_id,
+			Address(address),
+		));
+
+		keccak_256(&domain_separator_inner)
+	}
+
+	pub fn generate_set_delegation_message(
+		address: H160,
+		authority: H160,
+		code: H160,
+		nonce: U256,
+		deadline: U256,
+	) -> [u8; 32] {
+		let domain_separator = Self::compute_domain_separator(address);
+
+		let message_content = solidity::encode_arguments((
+			H256::from(SET_DELEGATION_TYPEHASH),
+			Address(authority),
+			Address(code),
+			nonce,
+			deadline,
+		));
+		let message_content = keccak_256(&message_content);
+		let mut pre_digest = Vec::with_capacity(2 + 32 + 32);
+		pre_digest.extend_from_slice(b"\x19\x01");
+		pre_digest.extend_from_slice(&domain_separator);
+		pre_digest.extend_from_slice(&message_content);
+		keccak_256(&pre_digest)
+	}
+
+	pub fn signature_inherent_cost() -> u64 {
+		3_000 // cost of ECRecover precompile for reference
+			+ RuntimeHelper::<Runtime>::db_write_gas_cost() // we write nonce
+	}
+}
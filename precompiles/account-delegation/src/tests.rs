@@ -0,0 +1,329 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	mock::{AccountDelegation, ExtBuilder, PCall, Precompiles, PrecompilesValue, Runtime, Wallet},
+	AccountDelegationPrecompile, DelegationsStorage, SELECTOR_LOG_DELEGATION_SET,
+};
+use libsecp256k1::{sign, Message, SecretKey};
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, H256, U256};
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn set_delegation_with_signature(authority: H160, code: H160, deadline: U256) -> PCall {
+	let nonce = crate::NoncesStorage::get(authority);
+	let message = AccountDelegationPrecompile::<Runtime>::generate_set_delegation_message(
+		AccountDelegation.into(),
+		authority,
+		code,
+		nonce,
+		deadline,
+	);
+
+	let secret_key = SecretKey::parse(&alith_secret_key()).unwrap();
+	let (rs, v) = sign(&Message::parse(&message), &secret_key);
+
+	PCall::set_delegation_with_signature {
+		authority: Address(authority),
+		code: Address(code),
+		deadline,
+		v: v.serialize(),
+		r: H256::from(rs.r.b32()),
+		s: H256::from(rs.s.b32()),
+	}
+}
+
+fn delegation_set_log(authority: impl Into<H160>, code: impl Into<H160>) -> Log {
+	log3(
+		AccountDelegation,
+		SELECTOR_LOG_DELEGATION_SET,
+		authority.into(),
+		code.into(),
+		solidity::encode_event_data(()),
+	)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::set_delegation_selectors().contains(&0xe6b3e704));
+	assert!(PCall::set_delegation_with_signature_selectors().contains(&0x131ddb7b));
+	assert!(PCall::execute_selectors().contains(&0x1cff79cd));
+	assert!(PCall::delegation_selectors().contains(&0xeed50a32));
+	assert!(PCall::nonces_selectors().contains(&0x7ecebe00));
+	assert!(PCall::domain_separator_selectors().contains(&0x3644e515));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, AccountDelegation);
+
+		tester.test_default_modifier(PCall::set_delegation_selectors());
+		tester.test_default_modifier(PCall::set_delegation_with_signature_selectors());
+		tester.test_default_modifier(PCall::execute_selectors());
+		tester.test_view_modifier(PCall::delegation_selectors());
+		tester.test_view_modifier(PCall::nonces_selectors());
+		tester.test_view_modifier(PCall::domain_separator_selectors());
+	});
+}
+
+#[test]
+fn set_delegation_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				AccountDelegation,
+				PCall::set_delegation {
+					code: Address(Wallet.into()),
+				},
+			)
+			.expect_log(delegation_set_log(Alice, Wallet))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				AccountDelegation,
+				PCall::delegation {
+					authority: Address(Alice.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(Address(Wallet.into()));
+	});
+}
+
+#[test]
+fn set_delegation_to_zero_address_removes_delegation() {
+	ExtBuilder::default().build().execute_with(|| {
+		DelegationsStorage::insert(H160::from(Alice), H160::from(Wallet));
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				AccountDelegation,
+				PCall::set_delegation {
+					code: Address(H160::zero()),
+				},
+			)
+			.expect_log(delegation_set_log(Alice, H160::zero()))
+			.execute_returns(());
+
+		assert_eq!(DelegationsStorage::get(H160::from(Alice)), None);
+		precompiles()
+			.prepare_test(
+				Bob,
+				AccountDelegation,
+				PCall::delegation {
+					authority: Address(Alice.into()),
+				},
+			)
+			.execute_returns(Address(H160::zero()));
+	});
+}
+
+#[test]
+fn set_delegation_fails_for_contract() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Wallet,
+				AccountDelegation,
+				PCall::set_delegation {
+					code: Address(Bob.into()),
+				},
+			)
+			.execute_reverts(|output| output == b"Only externally owned accounts can delegate");
+	});
+}
+
+#[test]
+fn set_delegation_with_signature_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call =
+			set_delegation_with_signature(CryptoAlith.into(), Wallet.into(), 1_000u32.into());
+
+		precompiles()
+			.prepare_test(Bob, AccountDelegation, call)
+			.expect_log(delegation_set_log(CryptoAlith, Wallet))
+			.execute_returns(());
+
+		assert_eq!(
+			DelegationsStorage::get(H160::from(CryptoAlith)),
+			Some(Wallet.into())
+		);
+		precompiles()
+			.prepare_test(
+				Bob,
+				AccountDelegation,
+				PCall::nonces {
+					authority: Address(CryptoAlith.into()),
+				},
+			)
+			.execute_returns(U256::one());
+	});
+}
+
+#[test]
+fn set_delegation_with_signature_cannot_be_replayed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let input: Vec<u8> =
+			set_delegation_with_signature(CryptoAlith.into(), Wallet.into(), 1_000u32.into())
+				.into();
+
+		precompiles()
+			.prepare_test(Bob, AccountDelegation, input.clone())
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Bob, AccountDelegation, input)
+			.execute_reverts(|output| output == b"Invalid authorization");
+	});
+}
+
+#[test]
+fn set_delegation_with_signature_fails_if_not_signed_by_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		let PCall::set_delegation_with_signature {
+			code,
+			deadline,
+			v,
+			r,
+			s,
+			..
+		} = set_delegation_with_signature(CryptoAlith.into(), Wallet.into(), 1_000u32.into())
+		else {
+			unreachable!()
+		};
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				AccountDelegation,
+				PCall::set_delegation_with_signature {
+					authority: Address(Bob.into()),
+					code,
+					deadline,
+					v,
+					r,
+					s,
+				},
+			)
+			.execute_reverts(|output| output == b"Invalid authorization");
+	});
+}
+
+#[test]
+fn set_delegation_with_signature_fails_if_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call =
+			set_delegation_with_signature(CryptoAlith.into(), Wallet.into(), 1_000u32.into());
+		pallet_timestamp::Pallet::<Runtime>::set_timestamp(2_000_000);
+
+		precompiles()
+			.prepare_test(Bob, AccountDelegation, call)
+			.execute_reverts(|output| output == b"Authorization expired");
+	});
+}
+
+#[test]
+fn execute_fails_without_delegation() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Bob,
+				AccountDelegation,
+				PCall::execute {
+					authority: Address(Alice.into()),
+					data: BoundedBytes::from([1, 2, 3, 4]),
+				},
+			)
+			.execute_reverts(|output| output == b"No delegation");
+	});
+}
+
+#[test]
+fn execute_fails_for_contract_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		DelegationsStorage::insert(H160::from(Wallet), H160::from(Alice));
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				AccountDelegation,
+				PCall::execute {
+					authority: Address(Wallet.into()),
+					data: BoundedBytes::from([1, 2, 3, 4]),
+				},
+			)
+			.execute_reverts(|output| output == b"authority address must be EOA");
+	});
+}
+
+#[test]
+fn execute_runs_delegated_code_in_authority_context() {
+	ExtBuilder::default().build().execute_with(|| {
+		DelegationsStorage::insert(H160::from(Alice), H160::from(Wallet));
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				AccountDelegation,
+				PCall::execute {
+					authority: Address(Alice.into()),
+					data: BoundedBytes::from([1, 2, 3, 4]),
+				},
+			)
+			.with_subcall_handle(move |subcall| {
+				let Subcall {
+					address,
+					transfer,
+					input,
+					target_gas: _,
+					is_static,
+					context,
+				} = subcall;
+
+				// The code of the wallet is run on the account of Alice, called by Bob.
+				assert_eq!(address, Wallet.into());
+				assert_eq!(context.address, Alice.into());
+				assert_eq!(context.caller, Bob.into());
+				assert_eq!(context.apparent_value, U256::zero());
+				assert_eq!(is_static, false);
+				assert!(transfer.is_none());
+				assert_eq!(&input, &[1, 2, 3, 4]);
+
+				SubcallOutput {
+					output: b"TEST".to_vec(),
+					cost: 13,
+					..SubcallOutput::succeed()
+				}
+			})
+			.execute_returns(UnboundedBytes::from(b"TEST"));
+	});
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["AccountDelegation.sol"],
+		PCall::supports_selector,
+	)
+}
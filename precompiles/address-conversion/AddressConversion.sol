@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The AddressConversion contract's address.
+address constant ADDRESS_CONVERSION_ADDRESS = 0x0000000000000000000000000000000000000821;
+
+/// @dev The AddressConversion contract's instance.
+AddressConversion constant ADDRESS_CONVERSION_CONTRACT = AddressConversion(
+    ADDRESS_CONVERSION_ADDRESS
+);
+
+/// @author The Moonbeam Team
+/// @title Address conversion
+/// @title The interface through which solidity contracts can convert between the address formats
+/// of Moonbeam accounts.
+/// @custom:address 0x0000000000000000000000000000000000000821
+interface AddressConversion {
+    /// @dev SS58 address of an account, using the address format of the chain
+    /// @custom:selector 395c2896
+    /// @param account The address of the account
+    /// @return The SS58 address, as an ASCII string
+    function toSs58Bytes(address account) external view returns (bytes memory);
+
+    /// @dev Address of the account controlled, through XCM, by a relay chain account
+    /// @custom:selector 0030c00f
+    /// @param publicKey The 32 bytes public key of the relay chain account
+    /// @return The address of the account
+    function fromPublicKey(bytes32 publicKey) external view returns (address);
+
+    /// @dev Address of a derivative account, controlled by an account through
+    /// `utility.asDerivative`. Derivative indices of xcm-transactor map to such accounts.
+    /// @custom:selector 3c017394
+    /// @param account The address of the account controlling the derivative account
+    /// @param index The derivative index
+    /// @return The address of the derivative account
+    function derivativeAccountOf(address account, uint16 index)
+        external
+        view
+        returns (address);
+}
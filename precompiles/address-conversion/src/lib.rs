@@ -0,0 +1,142 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile converting between the address formats of Moonbeam accounts: Ethereum addresses,
+//! SS58 addresses, relay chain public keys and derivative accounts.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::traits::Get;
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::{hashing::blake2_512, H160, H256};
+use sp_std::{marker::PhantomData, vec::Vec};
+use xcm::latest::{Junction, Junctions, MultiLocation};
+use xcm_executor::traits::Convert;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Alphabet of the base58 encoding used by SS58 addresses.
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// A precompile converting between the address formats of Moonbeam accounts.
+pub struct AddressConversionPrecompile<Runtime, LocationToAccountId>(
+	PhantomData<(Runtime, LocationToAccountId)>,
+);
+
+#[precompile_utils::precompile]
+impl<Runtime, LocationToAccountId> AddressConversionPrecompile<Runtime, LocationToAccountId>
+where
+	Runtime: pallet_evm::Config + pallet_utility::Config,
+	Runtime::AccountId: Into<H160>,
+	LocationToAccountId: Convert<MultiLocation, Runtime::AccountId>,
+{
+	#[precompile::public("toSs58Bytes(address)")]
+	#[precompile::view]
+	fn to_ss58_bytes(
+		_handle: &mut impl PrecompileHandle,
+		account: Address,
+	) -> EvmResult<UnboundedBytes> {
+		let prefix: u16 = <Runtime as frame_system::Config>::SS58Prefix::get();
+
+		Ok(ss58_encode(prefix, account.0.as_bytes()).into())
+	}
+
+	#[precompile::public("fromPublicKey(bytes32)")]
+	#[precompile::view]
+	fn from_public_key(
+		_handle: &mut impl PrecompileHandle,
+		public_key: H256,
+	) -> EvmResult<Address> {
+		let location = MultiLocation {
+			parents: 1,
+			interior: Junctions::X1(Junction::AccountId32 {
+				network: None,
+				id: public_key.0,
+			}),
+		};
+		let account = LocationToAccountId::convert(location)
+			.map_err(|_| revert("Relay chain account cannot be converted"))?;
+
+		Ok(Address(account.into()))
+	}
+
+	#[precompile::public("derivativeAccountOf(address,uint16)")]
+	#[precompile::view]
+	fn derivative_account_of(
+		_handle: &mut impl PrecompileHandle,
+		account: Address,
+		index: u16,
+	) -> EvmResult<Address> {
+		let account = Runtime::AddressMapping::into_account_id(account.0);
+		let derivative = pallet_utility::Pallet::<Runtime>::derivative_account_id(account, index);
+
+		Ok(Address(derivative.into()))
+	}
+}
+
+/// SS58 encoding of an account with the given address format.
+pub fn ss58_encode(prefix: u16, account: &[u8]) -> Vec<u8> {
+	let mut data = match prefix {
+		0..=63 => sp_std::vec![prefix as u8],
+		_ => {
+			// Two bytes prefix, see https://docs.substrate.io/reference/address-formats/
+			let first = ((prefix & 0b0000_0000_1111_1100) as u8) >> 2;
+			let second = ((prefix >> 8) as u8) | ((prefix & 0b0000_0000_0000_0011) as u8) << 6;
+			sp_std::vec![first | 0b0100_0000, second]
+		}
+	};
+	data.extend_from_slice(account);
+
+	let mut preimage = b"SS58PRE".to_vec();
+	preimage.extend_from_slice(&data);
+	data.extend_from_slice(&blake2_512(&preimage)[..2]);
+
+	base58_encode(&data)
+}
+
+fn base58_encode(input: &[u8]) -> Vec<u8> {
+	// Little endian base58 digits.
+	let mut digits: Vec<u8> = Vec::with_capacity(input.len() * 138 / 100 + 1);
+	for byte in input {
+		let mut carry = *byte as u32;
+		for digit in digits.iter_mut() {
+			carry += (*digit as u32) << 8;
+			*digit = (carry % 58) as u8;
+			carry /= 58;
+		}
+		while carry > 0 {
+			digits.push((carry % 58) as u8);
+			carry /= 58;
+		}
+	}
+
+	// Leading zero bytes are encoded as leading ones.
+	let leading_zeros = input.iter().take_while(|byte| **byte == 0).count();
+	sp_std::iter::repeat(BASE58_ALPHABET[0])
+		.take(leading_zeros)
+		.chain(
+			digits
+				.iter()
+				.rev()
+				.map(|digit| BASE58_ALPHABET[*digit as usize]),
+		)
+		.collect()
+}
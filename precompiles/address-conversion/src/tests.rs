@@ -0,0 +1,135 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use precompile_utils::testing::*;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["AddressConversion.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::to_ss58_bytes_selectors().contains(&0x395c2896));
+	assert!(PCall::from_public_key_selectors().contains(&0x0030c00f));
+	assert!(PCall::derivative_account_of_selectors().contains(&0x3c017394));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_view_modifier(PCall::to_ss58_bytes_selectors());
+		tester.test_view_modifier(PCall::from_public_key_selectors());
+		tester.test_view_modifier(PCall::derivative_account_of_selectors());
+	});
+}
+
+#[test]
+fn to_ss58_bytes_uses_chain_prefix() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::to_ss58_bytes {
+					account: Address(Alice.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(UnboundedBytes::from("t7XrZ52hx66GNgJxDfPD4Et9WLb2ns6"));
+
+		// Moonbase address format, encoded on two bytes.
+		SS58Prefix::set(1287);
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::to_ss58_bytes {
+					account: Address(Alice.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(UnboundedBytes::from("6zmibCvoo8arDsr9FMZmVcAbqddXXP5ge"));
+	});
+}
+
+#[test]
+fn ss58_encode_matches_substrate() {
+	// Leading zero bytes are encoded as leading ones.
+	assert_eq!(
+		ss58_encode(0, &[0u8; 32]),
+		b"111111111111111111111111111111111HC1".to_vec()
+	);
+	assert_eq!(
+		ss58_encode(
+			42,
+			&hex_literal::hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+		),
+		b"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".to_vec()
+	);
+}
+
+#[test]
+fn from_public_key_converts_relay_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::from_public_key {
+					public_key: H256::repeat_byte(0x11),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(Address(H160::repeat_byte(0x11)));
+	});
+}
+
+#[test]
+fn derivative_account_of_matches_utility() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::derivative_account_of {
+					account: Address(Alice.into()),
+					index: 3,
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(Address(H160::from(hex_literal::hex!(
+				"c88a4bedf644f75f8a3078c850c7f611aa5540a9"
+			))));
+
+		assert_eq!(
+			pallet_utility::Pallet::<Runtime>::derivative_account_id(Alice.into(), 3),
+			AccountId::from(H160::from(hex_literal::hex!(
+				"c88a4bedf644f75f8a3078c850c7f611aa5540a9"
+			)))
+		);
+	});
+}
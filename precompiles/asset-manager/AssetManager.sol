@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The AssetManager contract's address.
+address constant ASSET_MANAGER_ADDRESS = 0x0000000000000000000000000000000000000827;
+
+/// @dev The AssetManager contract's instance.
+AssetManager constant ASSET_MANAGER_CONTRACT = AssetManager(ASSET_MANAGER_ADDRESS);
+
+/// @author The Moonbeam Team
+/// @title Asset Manager Interface
+/// @dev The interface through which governance proposals executed through the EVM onboard
+/// foreign assets. Only callable by the addresses of the governance origins allowed to manage
+/// assets.
+/// @custom:address 0x0000000000000000000000000000000000000827
+interface AssetManager {
+    // A multilocation is defined by its number of parents and the encoded junctions (interior)
+    struct Multilocation {
+        uint8 parents;
+        bytes[] interior;
+    }
+
+    /// @dev Register a foreign asset, which is sufficient and has a minimum balance of 1.
+    /// @custom:selector 6770f71b
+    /// @param location The multilocation of the asset
+    /// @param name The name of the asset
+    /// @param symbol The symbol of the asset
+    /// @param decimals The number of decimals of the asset
+    function registerForeignAsset(
+        Multilocation memory location,
+        string memory name,
+        string memory symbol,
+        uint8 decimals
+    ) external;
+
+    /// @dev Set the units of a foreign asset charged per second of XCM execution, making it
+    /// usable to pay for XCM execution.
+    /// @custom:selector 10582b24
+    /// @param location The multilocation of the asset
+    /// @param unitsPerSecond The units of the asset charged per second of execution
+    function setUnitsPerSecond(
+        Multilocation memory location,
+        uint256 unitsPerSecond
+    ) external;
+}
@@ -0,0 +1,159 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile allowing governance to onboard foreign assets from the EVM.
+//!
+//! Only the addresses registered in pallet-governance-origin-registry can call it, and the calls
+//! are dispatched to pallet-asset-manager with the runtime origin of their governance origin, such
+//! that proposals executed through the EVM are subject to the same origin checks as extrinsics.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	traits::Get,
+};
+use pallet_governance_origin_registry::GovernanceOrigin;
+use precompile_utils::prelude::*;
+use sp_core::U256;
+use sp_runtime::traits::Convert;
+use sp_std::{marker::PhantomData, vec::Vec};
+use xcm::latest::MultiLocation;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Metadata of a foreign asset registered through the precompile, from which the runtime builds
+/// its `AssetRegistrarMetadata`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ForeignAssetMetadata {
+	pub name: Vec<u8>,
+	pub symbol: Vec<u8>,
+	pub decimals: u8,
+}
+
+/// Minimum balance of the foreign assets registered through the precompile.
+pub const FOREIGN_ASSET_MIN_BALANCE: u32 = 1;
+
+type OriginOf<Runtime> =
+	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin;
+
+/// A precompile to register foreign assets and the rate at which they pay for XCM execution.
+/// `GovernanceOrigins` converts the governance origin of the caller into the runtime origin the
+/// calls are dispatched with, `None` if the origin cannot manage assets. `StringLimit` bounds the
+/// name and symbol of the assets.
+pub struct AssetManagerPrecompile<Runtime, GovernanceOrigins, StringLimit>(
+	PhantomData<(Runtime, GovernanceOrigins, StringLimit)>,
+);
+
+#[precompile_utils::precompile]
+impl<Runtime, GovernanceOrigins, StringLimit>
+	AssetManagerPrecompile<Runtime, GovernanceOrigins, StringLimit>
+where
+	Runtime: pallet_asset_manager::Config
+		+ pallet_governance_origin_registry::Config
+		+ pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_asset_manager::Call<Runtime>>,
+	Runtime::ForeignAssetType: From<MultiLocation>,
+	Runtime::AssetRegistrarMetadata: From<ForeignAssetMetadata>,
+	GovernanceOrigins: Convert<GovernanceOrigin, Option<OriginOf<Runtime>>>,
+	StringLimit: Get<u32>,
+{
+	#[precompile::public("registerForeignAsset((uint8,bytes[]),string,string,uint8)")]
+	fn register_foreign_asset(
+		handle: &mut impl PrecompileHandle,
+		location: MultiLocation,
+		name: BoundedString<StringLimit>,
+		symbol: BoundedString<StringLimit>,
+		decimals: u8,
+	) -> EvmResult {
+		let origin = Self::governance_origin(handle)?;
+
+		let metadata = ForeignAssetMetadata {
+			name: name.into(),
+			symbol: symbol.into(),
+			decimals,
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			origin,
+			pallet_asset_manager::Call::<Runtime>::register_foreign_asset {
+				asset: location.into(),
+				metadata: metadata.into(),
+				min_amount: FOREIGN_ASSET_MIN_BALANCE.into(),
+				// Foreign assets are sufficient, such that accounts can receive them through XCM
+				// without holding native tokens
+				is_sufficient: true,
+			},
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("setUnitsPerSecond((uint8,bytes[]),uint256)")]
+	fn set_units_per_second(
+		handle: &mut impl PrecompileHandle,
+		location: MultiLocation,
+		units_per_second: U256,
+	) -> EvmResult {
+		let origin = Self::governance_origin(handle)?;
+
+		let units_per_second: u128 = units_per_second
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("u128").in_field("unitsPerSecond"))?;
+
+		// Storage item: SupportedFeePaymentAssets length prefix: Compact<u32>(5)
+		handle.record_db_read::<Runtime>(5)?;
+		// The asset is added to the supported assets if it is not already one of them
+		let num_assets_weight_hint =
+			pallet_asset_manager::SupportedFeePaymentAssets::<Runtime>::decode_len()
+				.unwrap_or_default()
+				.saturating_add(1) as u32;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			origin,
+			pallet_asset_manager::Call::<Runtime>::set_asset_units_per_second {
+				asset_type: location.into(),
+				units_per_second,
+				num_assets_weight_hint,
+			},
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	/// Runtime origin of the governance origin the caller is registered for.
+	fn governance_origin(handle: &mut impl PrecompileHandle) -> EvmResult<OriginOf<Runtime>> {
+		// Storage item: AddressOrigins: Blake2_128(16) + H160(20) + GovernanceOrigin(1)
+		handle.record_db_read::<Runtime>(37)?;
+
+		let governance_origin = pallet_governance_origin_registry::Pallet::<Runtime>::origin_of(
+			handle.context().caller,
+		)
+		.ok_or_else(|| revert("Caller is not a governance origin"))?;
+
+		GovernanceOrigins::convert(governance_origin)
+			.ok_or_else(|| revert("Governance origin cannot manage assets"))
+	}
+}
@@ -0,0 +1,305 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test utilities
+use super::*;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU128, ConstU32, Everything},
+	weights::Weight,
+};
+use frame_system::{EnsureRoot, RawOrigin};
+use pallet_evm::{EnsureAddressNever, EnsureAddressRoot};
+use parity_scale_codec::{Decode, Encode};
+use precompile_utils::{precompile_set::*, testing::MockAccount};
+use scale_info::TypeInfo;
+use sp_core::{H256, U256};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+	DispatchResult, Perbill,
+};
+
+pub type AccountId = MockAccount;
+pub type Balance = u128;
+pub type BlockNumber = u32;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Evm: pallet_evm::{Pallet, Call, Storage, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		GovernanceOriginRegistry:
+			pallet_governance_origin_registry::{Pallet, Call, Storage, Event<T>},
+		AssetManager: pallet_asset_manager::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+	pub const MaximumBlockWeight: Weight = Weight::from_parts(1024, 1);
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Header = sp_runtime::generic::Header<BlockNumber, BlakeTwo256>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 0;
+}
+impl pallet_balances::Config for Runtime {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 4];
+	type MaxLocks = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
+/// Block storage limit in bytes. Set to 40 KB.
+const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
+
+parameter_types! {
+	pub BlockGasLimit: U256 = U256::from(u64::MAX);
+	pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
+	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+	pub GasLimitPovSizeRatio: u64 = {
+		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+		block_gas_limit.saturating_div(MAX_POV_SIZE)
+	};
+	pub GasLimitStorageGrowthRatio: u64 = {
+		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+		block_gas_limit.saturating_div(BLOCK_STORAGE_LIMIT)
+	};
+}
+
+parameter_types! {
+	pub const StringLimit: u32 = 16;
+}
+
+pub type Precompiles<R> = PrecompileSetBuilder<
+	R,
+	(PrecompileAt<AddressU64<1>, AssetManagerPrecompile<R, MockGovernanceOrigins, StringLimit>>,),
+>;
+
+pub type PCall = AssetManagerPrecompileCall<Runtime, MockGovernanceOrigins, StringLimit>;
+
+/// Only the root governance origin can manage assets.
+pub struct MockGovernanceOrigins;
+impl Convert<GovernanceOrigin, Option<RuntimeOrigin>> for MockGovernanceOrigins {
+	fn convert(origin: GovernanceOrigin) -> Option<RuntimeOrigin> {
+		match origin {
+			GovernanceOrigin::Root => Some(RawOrigin::Root.into()),
+			GovernanceOrigin::GeneralAdmin => Some(RawOrigin::None.into()),
+			GovernanceOrigin::Treasurer => None,
+		}
+	}
+}
+
+impl pallet_evm::Config for Runtime {
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type CallOrigin = EnsureAddressRoot<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type AddressMapping = AccountId;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type PrecompilesType = Precompiles<Runtime>;
+	type PrecompilesValue = PrecompilesValue;
+	type ChainId = ();
+	type OnChargeTransaction = ();
+	type BlockGasLimit = BlockGasLimit;
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type FindAuthor = ();
+	type OnCreate = ();
+	type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
+	type GasLimitStorageGrowthRatio = GasLimitStorageGrowthRatio;
+	type Timestamp = Timestamp;
+	type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+impl pallet_timestamp::Config for Runtime {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+impl pallet_governance_origin_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RegistrarOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+#[derive(Clone, Default, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, Debug, TypeInfo)]
+pub enum MockAssetType {
+	Xcm(MultiLocation),
+	#[default]
+	None,
+}
+
+impl From<MultiLocation> for MockAssetType {
+	fn from(location: MultiLocation) -> Self {
+		Self::Xcm(location)
+	}
+}
+
+impl From<MockAssetType> for u128 {
+	fn from(asset: MockAssetType) -> u128 {
+		let mut result = [0u8; 16];
+		result.copy_from_slice(&BlakeTwo256::hash_of(&asset).as_bytes()[0..16]);
+		u128::from_le_bytes(result)
+	}
+}
+
+#[derive(Clone, Default, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct MockAssetMetadata {
+	pub name: Vec<u8>,
+	pub symbol: Vec<u8>,
+	pub decimals: u8,
+}
+
+impl From<ForeignAssetMetadata> for MockAssetMetadata {
+	fn from(metadata: ForeignAssetMetadata) -> Self {
+		Self {
+			name: metadata.name,
+			symbol: metadata.symbol,
+			decimals: metadata.decimals,
+		}
+	}
+}
+
+parameter_types! {
+	/// Foreign assets created by the registrar: (asset id, min balance, metadata, sufficient).
+	pub static CreatedAssets: Vec<(u128, Balance, MockAssetMetadata, bool)> = Vec::new();
+}
+
+pub struct MockAssetRegistrar;
+impl pallet_asset_manager::AssetRegistrar<Runtime> for MockAssetRegistrar {
+	fn create_foreign_asset(
+		asset: u128,
+		min_balance: Balance,
+		metadata: MockAssetMetadata,
+		is_sufficient: bool,
+	) -> DispatchResult {
+		CreatedAssets::mutate(|assets| assets.push((asset, min_balance, metadata, is_sufficient)));
+		Ok(())
+	}
+}
+
+pub struct MockLocalAssetIdCreator;
+impl pallet_asset_manager::LocalAssetIdCreator<Runtime> for MockLocalAssetIdCreator {
+	fn create_asset_id_from_metadata(local_asset_counter: u128) -> u128 {
+		local_asset_counter
+	}
+}
+
+impl pallet_asset_manager::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = u128;
+	type AssetRegistrarMetadata = MockAssetMetadata;
+	type ForeignAssetType = MockAssetType;
+	type AssetRegistrar = MockAssetRegistrar;
+	type ForeignAssetModifierOrigin = EnsureRoot<AccountId>;
+	type LocalAssetModifierOrigin = EnsureRoot<AccountId>;
+	type LocalAssetIdCreator = MockLocalAssetIdCreator;
+	type Currency = Balances;
+	type LocalAssetDeposit = ConstU128<0>;
+	type AssetTypeAliasPeriod = ConstU32<10>;
+	type WeightInfo = ();
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder;
+
+impl ExtBuilder {
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.expect("Frame system builds valid default genesis config");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			// Alice acts for root and Bob for the general admin, which cannot manage assets
+			GovernanceOriginRegistry::set_origin_address(
+				RuntimeOrigin::root(),
+				GovernanceOrigin::Root,
+				Some(MockAccount::Alice.into()),
+			)
+			.expect("Root can register governance origins");
+			GovernanceOriginRegistry::set_origin_address(
+				RuntimeOrigin::root(),
+				GovernanceOrigin::GeneralAdmin,
+				Some(MockAccount::Bob.into()),
+			)
+			.expect("Root can register governance origins");
+			GovernanceOriginRegistry::set_origin_address(
+				RuntimeOrigin::root(),
+				GovernanceOrigin::Treasurer,
+				Some(MockAccount::Charlie.into()),
+			)
+			.expect("Root can register governance origins");
+		});
+		ext
+	}
+}
@@ -0,0 +1,190 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::U256;
+use std::str::from_utf8;
+use xcm::latest::{Junction, Junctions, MultiLocation};
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn sibling_asset() -> MultiLocation {
+	MultiLocation {
+		parents: 1,
+		interior: Junctions::X2(Junction::Parachain(2000), Junction::GeneralIndex(1)),
+	}
+}
+
+fn register_sibling_asset() -> PCall {
+	PCall::register_foreign_asset {
+		location: sibling_asset(),
+		name: "Sibling".into(),
+		symbol: "SIB".into(),
+		decimals: 12,
+	}
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(&["AssetManager.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::register_foreign_asset_selectors().contains(&0x6770f71b));
+	assert!(PCall::set_units_per_second_selectors().contains(&0x10582b24));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_default_modifier(PCall::register_foreign_asset_selectors());
+		tester.test_default_modifier(PCall::set_units_per_second_selectors());
+	});
+}
+
+#[test]
+fn governance_registers_foreign_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, register_sibling_asset())
+			.execute_returns(());
+
+		let asset_id: u128 = MockAssetType::from(sibling_asset()).into();
+		assert_eq!(
+			AssetManager::asset_id_type(asset_id),
+			Some(MockAssetType::from(sibling_asset()))
+		);
+		assert_eq!(
+			CreatedAssets::get(),
+			vec![(
+				asset_id,
+				1,
+				MockAssetMetadata {
+					name: b"Sibling".to_vec(),
+					symbol: b"SIB".to_vec(),
+					decimals: 12,
+				},
+				true
+			)]
+		);
+	});
+}
+
+#[test]
+fn governance_sets_units_per_second() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, register_sibling_asset())
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::set_units_per_second {
+					location: sibling_asset(),
+					units_per_second: 1_000_000.into(),
+				},
+			)
+			.execute_returns(());
+
+		let asset_type = MockAssetType::from(sibling_asset());
+		assert_eq!(
+			AssetManager::asset_type_units_per_second(&asset_type),
+			Some(1_000_000)
+		);
+		assert_eq!(
+			AssetManager::supported_fee_payment_assets(),
+			vec![asset_type]
+		);
+	});
+}
+
+#[test]
+fn register_by_non_governance_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(David, Precompile1, register_sibling_asset())
+			.execute_reverts(|output| output == b"Caller is not a governance origin");
+
+		assert!(CreatedAssets::get().is_empty());
+	});
+}
+
+#[test]
+fn register_by_origin_not_managing_assets_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Charlie, Precompile1, register_sibling_asset())
+			.execute_reverts(|output| output == b"Governance origin cannot manage assets");
+	});
+}
+
+#[test]
+fn register_by_origin_failing_asset_manager_check_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Bob, Precompile1, register_sibling_asset())
+			.execute_reverts(|output| output == b"Dispatched call failed with error: BadOrigin");
+	});
+}
+
+#[test]
+fn units_per_second_of_unknown_asset_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::set_units_per_second {
+					location: sibling_asset(),
+					units_per_second: 1_000_000.into(),
+				},
+			)
+			.execute_reverts(|output| {
+				from_utf8(output)
+					.unwrap()
+					.contains("Dispatched call failed with error: ")
+					&& from_utf8(output).unwrap().contains("AssetDoesNotExist")
+			});
+	});
+}
+
+#[test]
+fn too_large_units_per_second_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, register_sibling_asset())
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::set_units_per_second {
+					location: sibling_asset(),
+					units_per_second: U256::MAX,
+				},
+			)
+			.execute_reverts(|output| output == b"unitsPerSecond: Value is too large for u128");
+	});
+}
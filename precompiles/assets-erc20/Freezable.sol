@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @author The Moonbeam Team
+/// @title Extension of the ERC20 interface exposing the freezes of the asset
+/// @dev Transfers from a frozen account, or of a frozen asset, revert with "Account is frozen"
+/// or "Asset is frozen".
+/// The events below are emitted whenever pallet-assets freezes or thaws the asset or an account,
+/// whatever dispatched the freeze (this precompile, a substrate extrinsic or governance).
+/// They are deposited as `EVM.Log` substrate events at the end of the block.
+/// @custom:address 0xFFFFFFFF + hex(assetId)
+interface Freezable {
+    /// @dev Whether an account is frozen (or blocked), preventing it from transferring the asset.
+    /// @custom:selector e5839836
+    /// @param account The account to check
+    /// @return Whether the account is frozen
+    function isFrozen(address account) external view returns (bool);
+
+    /// @dev Whether the asset is frozen, preventing all transfers.
+    /// @custom:selector d9c262ad
+    /// @return Whether the asset is frozen
+    function assetFrozen() external view returns (bool);
+
+    /// @dev Event emitted when an account is frozen.
+    /// @custom:selector 8a5c4736a33c7b7f29a2c34ea9ff9608afc5718d56f6fd6dcbd2d3711a1a4913
+    /// @param account The frozen account
+    event Frozen(address indexed account);
+
+    /// @dev Event emitted when an account is thawed.
+    /// @custom:selector 6fda897d48d5c966a4c6312d6d9776784d44f0aa4d9954a453d4a5a14bf65e8e
+    /// @param account The thawed account
+    event Thawed(address indexed account);
+
+    /// @dev Event emitted when the asset is frozen.
+    /// @custom:selector 9c1780b1b23cc92c136deee9acc6339c14f455641d2580b639876cf0bdffacf2
+    event AssetFrozen();
+
+    /// @dev Event emitted when the asset is thawed.
+    /// @custom:selector 319f89a0fefac0d38983dfe474a88e60cfd555e7369c2bc98787f32fe1d3911c
+    event AssetThawed();
+}
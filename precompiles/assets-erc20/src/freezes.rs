@@ -0,0 +1,280 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Freeze status of the assets and of their accounts, and EVM logs of their changes.
+
+use super::*;
+use fp_evm::Log;
+use frame_support::{
+	storage::types::{OptionQuery, StorageDoubleMap, StorageMap},
+	traits::{
+		Currency, OffchainWorker, OnFinalize, OnIdle, OnInitialize, OnRuntimeUpgrade,
+		PalletInfoAccess, StorageInstance,
+	},
+	weights::Weight,
+	Blake2_128Concat,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode};
+
+/// Status of an asset, as stored by pallet-assets.
+#[derive(Encode, Decode, PartialEq, Eq)]
+pub enum AssetStatus {
+	Live,
+	Frozen,
+	Destroying,
+}
+
+/// Status of an asset account, as stored by pallet-assets.
+#[derive(Encode, Decode, PartialEq, Eq)]
+pub enum AccountStatus {
+	Liquid,
+	Frozen,
+	Blocked,
+}
+
+/// Leading fields of the `AssetDetails` of pallet-assets, up to the status of the asset.
+#[derive(Encode, Decode)]
+pub struct AssetDetailsStatus<Balance, AccountId, DepositBalance> {
+	pub owner: AccountId,
+	pub issuer: AccountId,
+	pub admin: AccountId,
+	pub freezer: AccountId,
+	pub supply: Balance,
+	pub deposit: DepositBalance,
+	pub min_balance: Balance,
+	pub is_sufficient: bool,
+	pub accounts: u32,
+	pub sufficients: u32,
+	pub approvals: u32,
+	pub status: AssetStatus,
+}
+
+/// Leading fields of the `AssetAccount` of pallet-assets, up to the status of the account.
+#[derive(Encode, Decode)]
+pub struct AssetAccountStatus<Balance> {
+	pub balance: Balance,
+	pub status: AccountStatus,
+}
+
+/// Prefix of the `Asset` storage of the pallet-assets instance.
+pub struct AssetPrefix<Runtime, Instance>(PhantomData<(Runtime, Instance)>);
+
+impl<Runtime, Instance> StorageInstance for AssetPrefix<Runtime, Instance>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance>,
+{
+	const STORAGE_PREFIX: &'static str = "Asset";
+
+	fn pallet_prefix() -> &'static str {
+		<pallet_assets::Pallet<Runtime, Instance> as PalletInfoAccess>::name()
+	}
+}
+
+/// Prefix of the `Account` storage of the pallet-assets instance.
+pub struct AccountPrefix<Runtime, Instance>(PhantomData<(Runtime, Instance)>);
+
+impl<Runtime, Instance> StorageInstance for AccountPrefix<Runtime, Instance>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance>,
+{
+	const STORAGE_PREFIX: &'static str = "Account";
+
+	fn pallet_prefix() -> &'static str {
+		<pallet_assets::Pallet<Runtime, Instance> as PalletInfoAccess>::name()
+	}
+}
+
+type DepositBalanceOf<Runtime, Instance> =
+	<<Runtime as pallet_assets::Config<Instance>>::Currency as Currency<
+		<Runtime as frame_system::Config>::AccountId,
+	>>::Balance;
+
+/// Storage type used to read the status of the assets from pallet-assets.
+pub type AssetStorage<Runtime, Instance> = StorageMap<
+	AssetPrefix<Runtime, Instance>,
+	Blake2_128Concat,
+	AssetIdOf<Runtime, Instance>,
+	AssetDetailsStatus<
+		BalanceOf<Runtime, Instance>,
+		<Runtime as frame_system::Config>::AccountId,
+		DepositBalanceOf<Runtime, Instance>,
+	>,
+	OptionQuery,
+>;
+
+/// Storage type used to read the status of the asset accounts from pallet-assets.
+pub type AccountStorage<Runtime, Instance> = StorageDoubleMap<
+	AccountPrefix<Runtime, Instance>,
+	Blake2_128Concat,
+	AssetIdOf<Runtime, Instance>,
+	Blake2_128Concat,
+	<Runtime as frame_system::Config>::AccountId,
+	AssetAccountStatus<BalanceOf<Runtime, Instance>>,
+	OptionQuery,
+>;
+
+/// Whether the asset is frozen.
+pub fn asset_is_frozen<Runtime, Instance>(asset_id: AssetIdOf<Runtime, Instance>) -> bool
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance>,
+{
+	AssetStorage::<Runtime, Instance>::get(asset_id)
+		.map_or(false, |asset| asset.status == AssetStatus::Frozen)
+}
+
+/// Whether the account is frozen or blocked for the asset.
+pub fn account_is_frozen<Runtime, Instance>(
+	asset_id: AssetIdOf<Runtime, Instance>,
+	who: &Runtime::AccountId,
+) -> bool
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance>,
+{
+	AccountStorage::<Runtime, Instance>::get(asset_id, who)
+		.map_or(false, |account| account.status != AccountStatus::Liquid)
+}
+
+/// Emits the `Frozen`, `Thawed`, `AssetFrozen` and `AssetThawed` logs of the assets of a
+/// pallet-assets instance when the corresponding pallet-assets events fire, whatever dispatched
+/// the freeze (the precompile, a signed extrinsic or governance).
+///
+/// The logs are emitted as `pallet_evm` `Log` events at the end of the block, from the address of
+/// the precompile of the asset, which is built from `AddressPrefix`. A change of status forced by
+/// `force_asset_status` emits `AssetFrozen` or `AssetThawed` depending on the resulting status.
+pub struct FreezeLogs<Runtime, Instance, AddressPrefix>(
+	PhantomData<(Runtime, Instance, AddressPrefix)>,
+);
+
+impl<Runtime, Instance, AddressPrefix> FreezeLogs<Runtime, Instance, AddressPrefix>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+	Runtime: AccountIdAssetIdConversion<Runtime::AccountId, AssetIdOf<Runtime, Instance>>,
+	Runtime::AccountId: Into<H160>,
+	<Runtime as frame_system::Config>::RuntimeEvent:
+		TryInto<pallet_assets::Event<Runtime, Instance>>,
+	AddressPrefix: Get<&'static [u8]>,
+{
+	fn asset_address(asset_id: AssetIdOf<Runtime, Instance>) -> H160 {
+		Runtime::asset_id_to_account(AddressPrefix::get(), asset_id).into()
+	}
+
+	/// Log of a pallet-assets event, if it changes the freeze status of an asset or an account.
+	fn freeze_log(event: pallet_assets::Event<Runtime, Instance>) -> Option<Log> {
+		let log = match event {
+			pallet_assets::Event::Frozen { asset_id, who } => {
+				let who: H160 = who.into();
+				log2(
+					Self::asset_address(asset_id),
+					SELECTOR_LOG_FROZEN,
+					who,
+					Vec::new(),
+				)
+			}
+			pallet_assets::Event::Thawed { asset_id, who } => {
+				let who: H160 = who.into();
+				log2(
+					Self::asset_address(asset_id),
+					SELECTOR_LOG_THAWED,
+					who,
+					Vec::new(),
+				)
+			}
+			pallet_assets::Event::AssetFrozen { asset_id } => log1(
+				Self::asset_address(asset_id),
+				SELECTOR_LOG_ASSET_FROZEN,
+				Vec::new(),
+			),
+			pallet_assets::Event::AssetThawed { asset_id } => log1(
+				Self::asset_address(asset_id),
+				SELECTOR_LOG_ASSET_THAWED,
+				Vec::new(),
+			),
+			pallet_assets::Event::AssetStatusChanged { asset_id } => {
+				let selector = if asset_is_frozen::<Runtime, Instance>(asset_id.clone()) {
+					SELECTOR_LOG_ASSET_FROZEN
+				} else {
+					SELECTOR_LOG_ASSET_THAWED
+				};
+				log1(Self::asset_address(asset_id), selector, Vec::new())
+			}
+			_ => return None,
+		};
+		Some(log)
+	}
+}
+
+impl<Runtime, Instance, AddressPrefix> OnInitialize<BlockNumberFor<Runtime>>
+	for FreezeLogs<Runtime, Instance, AddressPrefix>
+where
+	Runtime: frame_system::Config,
+{
+	fn on_initialize(_n: BlockNumberFor<Runtime>) -> Weight {
+		// The events of the block are read once on finalize.
+		<Runtime as frame_system::Config>::DbWeight::get().reads(1)
+	}
+}
+
+impl<Runtime, Instance, AddressPrefix> OnFinalize<BlockNumberFor<Runtime>>
+	for FreezeLogs<Runtime, Instance, AddressPrefix>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+	Runtime: AccountIdAssetIdConversion<Runtime::AccountId, AssetIdOf<Runtime, Instance>>,
+	Runtime::AccountId: Into<H160>,
+	<Runtime as frame_system::Config>::RuntimeEvent:
+		TryInto<pallet_assets::Event<Runtime, Instance>>,
+	AddressPrefix: Get<&'static [u8]>,
+{
+	fn on_finalize(_n: BlockNumberFor<Runtime>) {
+		// The logs are collected before being deposited, as depositing an event while reading the
+		// events of the block would change them.
+		let logs: Vec<Log> = frame_system::Pallet::<Runtime>::read_events_no_consensus()
+			.filter_map(|record| record.event.try_into().ok())
+			.filter_map(Self::freeze_log)
+			.collect();
+
+		for log in logs {
+			frame_system::Pallet::<Runtime>::deposit_event(
+				<Runtime as pallet_evm::Config>::RuntimeEvent::from(pallet_evm::Event::Log { log }),
+			);
+		}
+	}
+}
+
+impl<Runtime, Instance, AddressPrefix> OnIdle<BlockNumberFor<Runtime>>
+	for FreezeLogs<Runtime, Instance, AddressPrefix>
+where
+	Runtime: frame_system::Config,
+{
+}
+
+impl<Runtime, Instance, AddressPrefix> OnRuntimeUpgrade
+	for FreezeLogs<Runtime, Instance, AddressPrefix>
+{
+}
+
+impl<Runtime, Instance, AddressPrefix> OffchainWorker<BlockNumberFor<Runtime>>
+	for FreezeLogs<Runtime, Instance, AddressPrefix>
+where
+	Runtime: frame_system::Config,
+{
+}
@@ -42,6 +42,10 @@ use sp_std::{
 mod eip2612;
 use eip2612::Eip2612;
 
+mod freezes;
+pub use freezes::FreezeLogs;
+use freezes::{account_is_frozen, asset_is_frozen};
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -53,6 +57,18 @@ pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address
 /// Solidity selector of the Approval log, which is the Keccak of the Log signature.
 pub const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
 
+/// Solidity selector of the Frozen log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_FROZEN: [u8; 32] = keccak256!("Frozen(address)");
+
+/// Solidity selector of the Thawed log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_THAWED: [u8; 32] = keccak256!("Thawed(address)");
+
+/// Solidity selector of the AssetFrozen log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_ASSET_FROZEN: [u8; 32] = keccak256!("AssetFrozen()");
+
+/// Solidity selector of the AssetThawed log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_ASSET_THAWED: [u8; 32] = keccak256!("AssetThawed()");
+
 /// Length limit of strings (symbol and name).
 type GetAssetsStringLimit<R, I> = <R as pallet_assets::Config<I>>::StringLimit;
 
@@ -165,7 +181,7 @@ where
 	) -> EvmResult<U256> {
 		// Storage item: Asset:
 		// Blake2_128(16) + AssetId(16) + AssetDetails((4 * AccountId(20)) + (3 * Balance(16)) + 15)
-		handle.record_db_read::<Runtime>(175)?;
+		handle.record_map_read::<Runtime>(175)?;
 
 		Ok(pallet_assets::Pallet::<Runtime, Instance>::total_issuance(asset_id).into())
 	}
@@ -179,7 +195,7 @@ where
 	) -> EvmResult<U256> {
 		// Storage item: Account:
 		// Blake2_128(16) + AssetId(16) + Blake2_128(16) + AccountId(20) + AssetAccount(19 + Extra)
-		handle.record_db_read::<Runtime>(
+		handle.record_map_read::<Runtime>(
 			87 + <Runtime as pallet_assets::Config<Instance>>::Extra::max_encoded_len(),
 		)?;
 
@@ -205,7 +221,7 @@ where
 	) -> EvmResult<U256> {
 		// Storage item: Approvals:
 		// Blake2_128(16) + AssetId(16) + (2 * Blake2_128(16) + AccountId(20)) + Approval(32)
-		handle.record_db_read::<Runtime>(136)?;
+		handle.record_map_read::<Runtime>(136)?;
 
 		let owner: H160 = owner.into();
 		let spender: H160 = spender.into();
@@ -264,7 +280,7 @@ where
 
 		// Storage item: Approvals:
 		// Blake2_128(16) + AssetId(16) + (2 * Blake2_128(16) + AccountId(20)) + Approval(32)
-		handle.record_db_read::<Runtime>(136)?;
+		handle.record_map_read::<Runtime>(136)?;
 
 		// If previous approval exists, we need to clean it
 		if pallet_assets::Pallet::<Runtime, Instance>::allowance(asset_id.clone(), &owner, &spender)
@@ -312,6 +328,8 @@ where
 			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 			let to = Runtime::AddressMapping::into_account_id(to);
 
+			Self::ensure_not_frozen(asset_id.clone(), handle, &origin)?;
+
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -357,6 +375,8 @@ where
 			let from: Runtime::AccountId = Runtime::AddressMapping::into_account_id(from.clone());
 			let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
 
+			Self::ensure_not_frozen(asset_id.clone(), handle, &from)?;
+
 			// If caller is "from", it can spend as much as it wants from its own balance.
 			if caller != from {
 				// Dispatch call (if enough gas).
@@ -408,7 +428,7 @@ where
 		// Storage item: Metadata:
 		// Blake2_128(16) + AssetId(16) + AssetMetadata[deposit(16) + name(StringLimit)
 		// + symbol(StringLimit) + decimals(1) + is_frozen(1)]
-		handle.record_db_read::<Runtime>(
+		handle.record_map_read::<Runtime>(
 			50 + (2 * <Runtime as pallet_assets::Config<Instance>>::StringLimit::get()) as usize,
 		)?;
 
@@ -428,7 +448,7 @@ where
 		// Storage item: Metadata:
 		// Blake2_128(16) + AssetId(16) + AssetMetadata[deposit(16) + name(StringLimit)
 		// + symbol(StringLimit) + decimals(1) + is_frozen(1)]
-		handle.record_db_read::<Runtime>(
+		handle.record_map_read::<Runtime>(
 			50 + (2 * <Runtime as pallet_assets::Config<Instance>>::StringLimit::get()) as usize,
 		)?;
 
@@ -448,7 +468,7 @@ where
 		// Storage item: Metadata:
 		// Blake2_128(16) + AssetId(16) + AssetMetadata[deposit(16) + name(StringLimit)
 		// + symbol(StringLimit) + decimals(1) + is_frozen(1)]
-		handle.record_db_read::<Runtime>(
+		handle.record_map_read::<Runtime>(
 			50 + (2 * <Runtime as pallet_assets::Config<Instance>>::StringLimit::get()) as usize,
 		)?;
 
@@ -465,7 +485,7 @@ where
 	) -> EvmResult<Address> {
 		// Storage item: Asset:
 		// Blake2_128(16) + AssetId(16) + AssetDetails((4 * AccountId(20)) + (3 * Balance(16)) + 15)
-		handle.record_db_read::<Runtime>(175)?;
+		handle.record_map_read::<Runtime>(175)?;
 
 		let owner: H160 = pallet_assets::Pallet::<Runtime, Instance>::owner(asset_id)
 			.ok_or(revert("No owner set"))?
@@ -482,7 +502,7 @@ where
 	) -> EvmResult<Address> {
 		// Storage item: Asset:
 		// Blake2_128(16) + AssetId(16) + AssetDetails((4 * AccountId(20)) + (3 * Balance(16)) + 15)
-		handle.record_db_read::<Runtime>(175)?;
+		handle.record_map_read::<Runtime>(175)?;
 
 		let issuer: H160 = pallet_assets::Pallet::<Runtime, Instance>::issuer(asset_id)
 			.ok_or(revert("No issuer set"))?
@@ -499,7 +519,7 @@ where
 	) -> EvmResult<Address> {
 		// Storage item: Asset:
 		// Blake2_128(16) + AssetId(16) + AssetDetails((4 * AccountId(20)) + (3 * Balance(16)) + 15)
-		handle.record_db_read::<Runtime>(175)?;
+		handle.record_map_read::<Runtime>(175)?;
 
 		let admin: H160 = pallet_assets::Pallet::<Runtime, Instance>::admin(asset_id)
 			.ok_or(revert("No admin set"))?
@@ -516,7 +536,7 @@ where
 	) -> EvmResult<Address> {
 		// Storage item: Asset:
 		// Blake2_128(16) + AssetId(16) + AssetDetails((4 * AccountId(20)) + (3 * Balance(16)) + 15)
-		handle.record_db_read::<Runtime>(175)?;
+		handle.record_map_read::<Runtime>(175)?;
 
 		let freezer: H160 = pallet_assets::Pallet::<Runtime, Instance>::freezer(asset_id)
 			.ok_or(revert("No freezer set"))?
@@ -739,6 +759,37 @@ where
 		Ok(true)
 	}
 
+	#[precompile::public("isFrozen(address)")]
+	#[precompile::view]
+	fn is_frozen(
+		asset_id: AssetIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+		account: Address,
+	) -> EvmResult<bool> {
+		// Storage item: Account:
+		// Blake2_128(16) + AssetId(16) + Blake2_128(16) + AccountId(20) + AssetAccount(19 + Extra)
+		handle.record_map_read::<Runtime>(
+			87 + <Runtime as pallet_assets::Config<Instance>>::Extra::max_encoded_len(),
+		)?;
+
+		let account = Runtime::AddressMapping::into_account_id(account.into());
+
+		Ok(account_is_frozen::<Runtime, Instance>(asset_id, &account))
+	}
+
+	#[precompile::public("assetFrozen()")]
+	#[precompile::view]
+	fn asset_frozen(
+		asset_id: AssetIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<bool> {
+		// Storage item: Asset:
+		// Blake2_128(16) + AssetId(16) + AssetDetails((4 * AccountId(20)) + (3 * Balance(16)) + 15)
+		handle.record_map_read::<Runtime>(175)?;
+
+		Ok(asset_is_frozen::<Runtime, Instance>(asset_id))
+	}
+
 	#[precompile::public("transferOwnership(address)")]
 	#[precompile::public("transfer_ownership(address)")]
 	fn transfer_ownership(
@@ -911,6 +962,32 @@ where
 		<Eip2612<Runtime, IsLocal, Instance>>::domain_separator(asset_id, handle)
 	}
 
+	/// Reverts if `from` cannot transfer the asset because it or the asset is frozen, as the
+	/// dispatch error of pallet-assets would not tell the freeze apart from a too low balance.
+	fn ensure_not_frozen(
+		asset_id: AssetIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+		from: &Runtime::AccountId,
+	) -> EvmResult {
+		// Storage item: Asset:
+		// Blake2_128(16) + AssetId(16) + AssetDetails((4 * AccountId(20)) + (3 * Balance(16)) + 15)
+		handle.record_map_read::<Runtime>(175)?;
+		if asset_is_frozen::<Runtime, Instance>(asset_id.clone()) {
+			return Err(revert("Asset is frozen"));
+		}
+
+		// Storage item: Account:
+		// Blake2_128(16) + AssetId(16) + Blake2_128(16) + AccountId(20) + AssetAccount(19 + Extra)
+		handle.record_map_read::<Runtime>(
+			87 + <Runtime as pallet_assets::Config<Instance>>::Extra::max_encoded_len(),
+		)?;
+		if account_is_frozen::<Runtime, Instance>(asset_id, from) {
+			return Err(revert("Account is frozen"));
+		}
+
+		Ok(())
+	}
+
 	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime, Instance>> {
 		value
 			.try_into()
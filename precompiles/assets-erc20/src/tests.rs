@@ -15,7 +15,7 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{eip2612::Eip2612, mock::*, *};
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::OnFinalize};
 use hex_literal::hex;
 use libsecp256k1::{sign, Message, SecretKey};
 use precompile_utils::testing::*;
@@ -90,6 +90,8 @@ fn selectors() {
 	assert!(ForeignPCall::set_team_selectors().contains(&0xc7d93c59));
 	assert!(ForeignPCall::set_metadata_selectors().contains(&0x37d2c2f4));
 	assert!(ForeignPCall::clear_metadata_selectors().contains(&0xefb6d432));
+	assert!(ForeignPCall::is_frozen_selectors().contains(&0xe5839836));
+	assert!(ForeignPCall::asset_frozen_selectors().contains(&0xd9c262ad));
 
 	assert_eq!(
 		crate::SELECTOR_LOG_TRANSFER,
@@ -100,6 +102,16 @@ fn selectors() {
 		crate::SELECTOR_LOG_APPROVAL,
 		&Keccak256::digest(b"Approval(address,address,uint256)")[..]
 	);
+
+	assert_eq!(
+		crate::SELECTOR_LOG_FROZEN,
+		&Keccak256::digest(b"Frozen(address)")[..]
+	);
+
+	assert_eq!(
+		crate::SELECTOR_LOG_THAWED,
+		&Keccak256::digest(b"Thawed(address)")[..]
+	);
 }
 
 #[test]
@@ -130,6 +142,8 @@ fn modifiers() {
 			tester.test_view_modifier(ForeignPCall::eip2612_nonces_selectors());
 			tester.test_default_modifier(ForeignPCall::eip2612_permit_selectors());
 			tester.test_view_modifier(ForeignPCall::eip2612_domain_separator_selectors());
+			tester.test_view_modifier(ForeignPCall::is_frozen_selectors());
+			tester.test_view_modifier(ForeignPCall::asset_frozen_selectors());
 
 			tester.test_default_modifier(ForeignPCall::mint_selectors());
 			tester.test_default_modifier(ForeignPCall::burn_selectors());
@@ -1066,12 +1080,7 @@ fn freeze_local_assets() {
 						value: 400.into(),
 					},
 				)
-				.execute_reverts(|output| {
-					from_utf8(&output)
-						.unwrap()
-						.contains("Dispatched call failed with error: ")
-						&& from_utf8(&output).unwrap().contains("Frozen")
-				});
+				.execute_reverts(|output| output == b"Account is frozen");
 		});
 }
 
@@ -1195,12 +1204,7 @@ fn freeze_asset_local_asset() {
 						value: 400.into(),
 					},
 				)
-				.execute_reverts(|output| {
-					from_utf8(&output)
-						.unwrap()
-						.contains("Dispatched call failed with error: ")
-						&& from_utf8(&output).unwrap().contains("AssetNotLive")
-				});
+				.execute_reverts(|output| output == b"Asset is frozen");
 		});
 }
 
@@ -1269,6 +1273,232 @@ fn thaw_asset_local_assets() {
 		});
 }
 
+#[test]
+fn frozen_views_reflect_freezes_of_foreign_assets() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000), (Bob.into(), 2500)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ForeignAssets::force_create(
+				RuntimeOrigin::root(),
+				0u128,
+				CryptoAlith.into(),
+				true,
+				1
+			));
+			assert_ok!(ForeignAssets::mint(
+				RuntimeOrigin::signed(CryptoAlith.into()),
+				0u128,
+				Bob.into(),
+				1000
+			));
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					ForeignAssetId(0u128),
+					ForeignPCall::is_frozen {
+						account: Address(Bob.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(false);
+
+			assert_ok!(ForeignAssets::freeze(
+				RuntimeOrigin::signed(CryptoAlith.into()),
+				0u128,
+				Bob.into()
+			));
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					ForeignAssetId(0u128),
+					ForeignPCall::is_frozen {
+						account: Address(Bob.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(true);
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					ForeignAssetId(0u128),
+					ForeignPCall::asset_frozen {},
+				)
+				.expect_no_logs()
+				.execute_returns(false);
+			precompiles()
+				.prepare_test(
+					Bob,
+					ForeignAssetId(0u128),
+					ForeignPCall::transfer {
+						to: Address(CryptoAlith.into()),
+						value: 400.into(),
+					},
+				)
+				.execute_reverts(|output| output == b"Account is frozen");
+
+			assert_ok!(ForeignAssets::thaw(
+				RuntimeOrigin::signed(CryptoAlith.into()),
+				0u128,
+				Bob.into()
+			));
+			assert_ok!(ForeignAssets::freeze_asset(
+				RuntimeOrigin::signed(CryptoAlith.into()),
+				0u128
+			));
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					ForeignAssetId(0u128),
+					ForeignPCall::asset_frozen {},
+				)
+				.expect_no_logs()
+				.execute_returns(true);
+			precompiles()
+				.prepare_test(
+					Bob,
+					ForeignAssetId(0u128),
+					ForeignPCall::transfer {
+						to: Address(CryptoAlith.into()),
+						value: 400.into(),
+					},
+				)
+				.execute_reverts(|output| output == b"Asset is frozen");
+		});
+}
+
+#[test]
+fn freeze_logs_are_emitted_for_freezes_dispatched_outside_of_the_evm() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000), (Bob.into(), 2500)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ForeignAssets::force_create(
+				RuntimeOrigin::root(),
+				0u128,
+				CryptoAlith.into(),
+				true,
+				1
+			));
+			assert_ok!(ForeignAssets::mint(
+				RuntimeOrigin::signed(CryptoAlith.into()),
+				0u128,
+				Bob.into(),
+				1000
+			));
+
+			assert_ok!(ForeignAssets::freeze(
+				RuntimeOrigin::signed(CryptoAlith.into()),
+				0u128,
+				Bob.into()
+			));
+			assert_ok!(ForeignAssets::thaw(
+				RuntimeOrigin::signed(CryptoAlith.into()),
+				0u128,
+				Bob.into()
+			));
+			assert_ok!(ForeignAssets::freeze_asset(
+				RuntimeOrigin::signed(CryptoAlith.into()),
+				0u128
+			));
+			// Governance thaws the asset by forcing its status.
+			assert_ok!(ForeignAssets::force_asset_status(
+				RuntimeOrigin::root(),
+				0u128,
+				CryptoAlith.into(),
+				CryptoAlith.into(),
+				CryptoAlith.into(),
+				CryptoAlith.into(),
+				1,
+				true,
+				false
+			));
+
+			FreezeLogs::<Runtime, pallet_assets::Instance1, ForeignAssetPrefix>::on_finalize(1);
+
+			let logs: Vec<_> = System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::Evm(pallet_evm::Event::Log { log }) => Some(log),
+					_ => None,
+				})
+				.collect();
+			assert_eq!(
+				logs,
+				vec![
+					log2(ForeignAssetId(0u128), SELECTOR_LOG_FROZEN, Bob, Vec::new()),
+					log2(ForeignAssetId(0u128), SELECTOR_LOG_THAWED, Bob, Vec::new()),
+					log1(ForeignAssetId(0u128), SELECTOR_LOG_ASSET_FROZEN, Vec::new()),
+					log1(ForeignAssetId(0u128), SELECTOR_LOG_ASSET_THAWED, Vec::new()),
+				]
+			);
+
+			// The logs of the local assets only cover their own instance.
+			FreezeLogs::<Runtime, pallet_assets::Instance2, LocalAssetPrefix>::on_finalize(1);
+			assert_eq!(
+				System::events()
+					.into_iter()
+					.filter(|record| matches!(record.event, RuntimeEvent::Evm(_)))
+					.count(),
+				4
+			);
+		});
+}
+
+#[test]
+fn transfer_from_frozen_owner_reverts() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000), (Bob.into(), 2500)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ForeignAssets::force_create(
+				RuntimeOrigin::root(),
+				0u128,
+				CryptoAlith.into(),
+				true,
+				1
+			));
+			assert_ok!(ForeignAssets::mint(
+				RuntimeOrigin::signed(CryptoAlith.into()),
+				0u128,
+				CryptoAlith.into(),
+				1000
+			));
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					ForeignAssetId(0u128),
+					ForeignPCall::approve {
+						spender: Address(Bob.into()),
+						value: 500.into(),
+					},
+				)
+				.execute_returns(true);
+
+			assert_ok!(ForeignAssets::freeze(
+				RuntimeOrigin::signed(CryptoAlith.into()),
+				0u128,
+				CryptoAlith.into()
+			));
+
+			precompiles()
+				.prepare_test(
+					Bob,
+					ForeignAssetId(0u128),
+					ForeignPCall::transfer_from {
+						from: Address(CryptoAlith.into()),
+						to: Address(Bob.into()),
+						value: 400.into(),
+					},
+				)
+				.execute_reverts(|output| output == b"Account is frozen");
+		});
+}
+
 #[test]
 fn transfer_ownership_local_assets() {
 	ExtBuilder::default()
@@ -2534,7 +2764,7 @@ fn get_freezer() {
 #[test]
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
 	check_precompile_implements_solidity_interfaces(
-		&["ERC20.sol", "LocalAsset.sol", "Permit.sol"],
+		&["ERC20.sol", "LocalAsset.sol", "Permit.sol", "Freezable.sol"],
 		LocalPCall::supports_selector,
 	)
 }
@@ -121,3 +121,29 @@ interface WrappedNativeCurrency {
     /// @param value uint256 The amount of tokens "unwrapped".
     event Withdrawal(address indexed owner, uint256 value);
 }
+
+/// @title Native balances interface.
+/// @dev Breakdown of the balance of an account, as `balanceOf` only returns the transferable
+/// part of it.
+/// Moonbase address : 0x0000000000000000000000000000000000000802
+interface NativeBalances {
+    /// @dev Returns the free balance of an account, including the locked balance.
+    /// @custom:selector 95b881de
+    /// @param owner The address to query the balance of.
+    /// @return The free balance.
+    function freeBalance(address owner) external view returns (uint256);
+
+    /// @dev Returns the reserved balance of an account (e.g. deposits, holds), which is not part
+    /// of its free balance.
+    /// @custom:selector e2c61aeb
+    /// @param owner The address to query the balance of.
+    /// @return The reserved balance.
+    function reservedBalance(address owner) external view returns (uint256);
+
+    /// @dev Returns the part of the free balance of an account that cannot be transferred, the
+    /// largest of its locks (e.g. staking) and freezes.
+    /// @custom:selector 9ae697bf
+    /// @param owner The address to query the balance of.
+    /// @return The locked balance.
+    function lockedBalance(address owner) external view returns (uint256);
+}
@@ -23,7 +23,7 @@ use frame_support::{
 	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
 	sp_runtime::traits::{Bounded, CheckedSub, StaticLookup},
 	storage::types::{StorageDoubleMap, StorageMap, ValueQuery},
-	traits::StorageInstance,
+	traits::{Get, StorageInstance},
 	Blake2_128Concat,
 };
 use pallet_balances::pallet::{
@@ -31,6 +31,7 @@ use pallet_balances::pallet::{
 	Instance2, Instance3, Instance4, Instance5, Instance6, Instance7, Instance8, Instance9,
 };
 use pallet_evm::AddressMapping;
+use parity_scale_codec::MaxEncodedLen;
 use precompile_utils::prelude::*;
 use sp_core::{H160, H256, U256};
 use sp_std::{
@@ -211,6 +212,60 @@ where
 		Ok(pallet_balances::Pallet::<Runtime, Instance>::usable_balance(&owner).into())
 	}
 
+	#[precompile::public("freeBalance(address)")]
+	#[precompile::view]
+	fn free_balance(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+		// frame_system::Account:
+		// Blake2128(16) + AccountId(20) + AccountInfo ((4 * 4) + AccountData(16 * 4))
+		handle.record_db_read::<Runtime>(116)?;
+
+		let owner: Runtime::AccountId = Runtime::AddressMapping::into_account_id(owner.into());
+
+		Ok(pallet_balances::Pallet::<Runtime, Instance>::free_balance(&owner).into())
+	}
+
+	#[precompile::public("reservedBalance(address)")]
+	#[precompile::view]
+	fn reserved_balance(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+		// frame_system::Account:
+		// Blake2128(16) + AccountId(20) + AccountInfo ((4 * 4) + AccountData(16 * 4))
+		handle.record_db_read::<Runtime>(116)?;
+
+		let owner: Runtime::AccountId = Runtime::AddressMapping::into_account_id(owner.into());
+
+		Ok(pallet_balances::Pallet::<Runtime, Instance>::reserved_balance(&owner).into())
+	}
+
+	#[precompile::public("lockedBalance(address)")]
+	#[precompile::view]
+	fn locked_balance(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+		// Locks: Blake2128(16) + AccountId(20) + Vec length(1)
+		// + MaxLocks * BalanceLock(LockIdentifier(8) + Balance(16) + Reasons(1))
+		handle.record_db_read::<Runtime>(37 + Runtime::MaxLocks::get() as usize * 25)?;
+		// Freezes: Blake2128(16) + AccountId(20) + Vec length(1)
+		// + MaxFreezes * IdAmount(FreezeIdentifier + Balance(16))
+		handle.record_db_read::<Runtime>(
+			37 + Runtime::MaxFreezes::get() as usize
+				* (Runtime::FreezeIdentifier::max_encoded_len() + 16),
+		)?;
+
+		let owner: Runtime::AccountId = Runtime::AddressMapping::into_account_id(owner.into());
+
+		// Locks and freezes overlap, the locked balance is the largest of them.
+		let locked = pallet_balances::Locks::<Runtime, Instance>::get(&owner)
+			.into_iter()
+			.map(|lock| lock.amount)
+			.chain(
+				pallet_balances::Freezes::<Runtime, Instance>::get(&owner)
+					.into_iter()
+					.map(|freeze| freeze.amount),
+			)
+			.max()
+			.unwrap_or_default();
+
+		Ok(locked.into())
+	}
+
 	#[precompile::public("allowance(address,address)")]
 	#[precompile::view]
 	fn allowance(
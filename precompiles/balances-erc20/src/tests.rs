@@ -18,6 +18,10 @@ use std::str::from_utf8;
 
 use crate::{eip2612::Eip2612, mock::*, *};
 
+use frame_support::{
+	assert_ok,
+	traits::{LockableCurrency, ReservableCurrency, WithdrawReasons},
+};
 use libsecp256k1::{sign, Message, SecretKey};
 use precompile_utils::testing::*;
 use sha3::{Digest, Keccak256};
@@ -43,6 +47,9 @@ fn selectors() {
 	assert!(PCall::eip2612_nonces_selectors().contains(&0x7ecebe00));
 	assert!(PCall::eip2612_permit_selectors().contains(&0xd505accf));
 	assert!(PCall::eip2612_domain_separator_selectors().contains(&0x3644e515));
+	assert!(PCall::free_balance_selectors().contains(&0x95b881de));
+	assert!(PCall::reserved_balance_selectors().contains(&0xe2c61aeb));
+	assert!(PCall::locked_balance_selectors().contains(&0x9ae697bf));
 
 	assert_eq!(
 		crate::SELECTOR_LOG_TRANSFER,
@@ -88,6 +95,9 @@ fn modifiers() {
 			tester.test_view_modifier(PCall::eip2612_nonces_selectors());
 			tester.test_default_modifier(PCall::eip2612_permit_selectors());
 			tester.test_view_modifier(PCall::eip2612_domain_separator_selectors());
+			tester.test_view_modifier(PCall::free_balance_selectors());
+			tester.test_view_modifier(PCall::reserved_balance_selectors());
+			tester.test_view_modifier(PCall::locked_balance_selectors());
 		});
 }
 
@@ -145,6 +155,63 @@ fn get_balances_unknown_user() {
 		});
 }
 
+#[test]
+fn get_balances_breakdown() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let alith: AccountId = CryptoAlith.into();
+			Balances::set_lock(*b"stkngdel", &alith, 300, WithdrawReasons::all());
+			Balances::set_lock(*b"democrac", &alith, 100, WithdrawReasons::all());
+			assert_ok!(Balances::reserve(&alith, 200));
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					Precompile1,
+					PCall::free_balance {
+						owner: Address(CryptoAlith.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(800u64));
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					Precompile1,
+					PCall::reserved_balance {
+						owner: Address(CryptoAlith.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(200u64));
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					Precompile1,
+					PCall::locked_balance {
+						owner: Address(CryptoAlith.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(300u64));
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					Precompile1,
+					PCall::locked_balance {
+						owner: Address(Bob.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(0u64));
+		});
+}
+
 #[test]
 fn approve() {
 	ExtBuilder::default()
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The ChainInfo contract's address.
+address constant CHAIN_INFO_ADDRESS = 0x000000000000000000000000000000000000081E;
+
+/// @dev The ChainInfo contract's instance.
+ChainInfo constant CHAIN_INFO_CONTRACT = ChainInfo(CHAIN_INFO_ADDRESS);
+
+/// @author The Moonbeam Team
+/// @title Substrate and relay chain information
+/// @title The interface through which solidity contracts can read facts about the relay chain
+/// block the current block is built on, and about the Substrate chain itself.
+/// @custom:address 0x000000000000000000000000000000000000081E
+interface ChainInfo {
+    /// @dev Number of the relay chain block the current block is built on
+    /// @custom:selector edfec347
+    /// @return The relay parent block number
+    function relayBlockNumber() external view returns (uint32);
+
+    /// @dev Storage root of the relay chain block the current block is built on
+    /// @custom:selector a79f226a
+    /// @return The relay parent storage root
+    function relayStateRoot() external view returns (bytes32);
+
+    /// @dev Hash of a Substrate block. Only the hashes of the 256 most recent blocks are
+    /// available, the zero hash is returned for other blocks.
+    /// @custom:selector 18ece448
+    /// @param blockNumber The number of the block
+    /// @return The block hash
+    function substrateBlockHash(uint32 blockNumber) external view returns (bytes32);
+
+    /// @dev Index, in the current Substrate block, of the extrinsic being executed
+    /// @custom:selector 3dc270cc
+    /// @return The extrinsic index
+    function extrinsicIndex() external view returns (uint32);
+}
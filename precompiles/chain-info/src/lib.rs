@@ -0,0 +1,101 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to query the relay chain block the current block is built on, and Substrate
+//! block and extrinsic data.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use precompile_utils::prelude::*;
+use sp_core::H256;
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Storage read when querying the relay parent.
+/// ValidationData: PersistedValidationData, with a parent head data of up to 256 bytes:
+/// head data(2 + 256) + relay parent number(4) + relay parent storage root(32) + max PoV size(4)
+pub const VALIDATION_DATA_SIZE: usize = 298;
+/// Storage read when querying a block hash.
+/// BlockHash: Twox64Concat(8) + BlockNumber(4) + Hash(32)
+pub const BLOCK_HASH_SIZE: usize = 44;
+/// Storage read when querying the extrinsic index.
+pub const EXTRINSIC_INDEX_SIZE: usize = 4;
+
+/// Provides the relay chain block the current block is built on.
+pub trait RelayParentProvider {
+	/// Number and storage root of the relay parent, if known.
+	fn relay_parent() -> Option<(u32, H256)>;
+}
+
+/// A precompile to query relay chain and Substrate block data.
+pub struct ChainInfoPrecompile<Runtime, RelayParent>(PhantomData<(Runtime, RelayParent)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, RelayParent> ChainInfoPrecompile<Runtime, RelayParent>
+where
+	Runtime: pallet_evm::Config + frame_system::Config,
+	Runtime::BlockNumber: From<u32>,
+	Runtime::Hash: Into<H256>,
+	RelayParent: RelayParentProvider,
+{
+	#[precompile::public("relayBlockNumber()")]
+	#[precompile::view]
+	fn relay_block_number(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+		let (number, _) = Self::relay_parent(handle)?;
+		Ok(number)
+	}
+
+	#[precompile::public("relayStateRoot()")]
+	#[precompile::view]
+	fn relay_state_root(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+		let (_, storage_root) = Self::relay_parent(handle)?;
+		Ok(storage_root)
+	}
+
+	#[precompile::public("substrateBlockHash(uint32)")]
+	#[precompile::view]
+	fn substrate_block_hash(
+		handle: &mut impl PrecompileHandle,
+		block_number: u32,
+	) -> EvmResult<H256> {
+		handle.record_db_read::<Runtime>(BLOCK_HASH_SIZE)?;
+
+		Ok(
+			frame_system::Pallet::<Runtime>::block_hash(Runtime::BlockNumber::from(block_number))
+				.into(),
+		)
+	}
+
+	#[precompile::public("extrinsicIndex()")]
+	#[precompile::view]
+	fn extrinsic_index(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+		handle.record_db_read::<Runtime>(EXTRINSIC_INDEX_SIZE)?;
+
+		frame_system::Pallet::<Runtime>::extrinsic_index()
+			.ok_or_else(|| revert("Not executed in an extrinsic"))
+	}
+
+	fn relay_parent(handle: &mut impl PrecompileHandle) -> EvmResult<(u32, H256)> {
+		handle.record_db_read::<Runtime>(VALIDATION_DATA_SIZE)?;
+
+		RelayParent::relay_parent().ok_or_else(|| revert("Relay parent is not available"))
+	}
+}
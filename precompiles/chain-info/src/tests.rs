@@ -0,0 +1,130 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use precompile_utils::testing::*;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(&["ChainInfo.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::relay_block_number_selectors().contains(&0xedfec347));
+	assert!(PCall::relay_state_root_selectors().contains(&0xa79f226a));
+	assert!(PCall::substrate_block_hash_selectors().contains(&0x18ece448));
+	assert!(PCall::extrinsic_index_selectors().contains(&0x3dc270cc));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_view_modifier(PCall::relay_block_number_selectors());
+		tester.test_view_modifier(PCall::relay_state_root_selectors());
+		tester.test_view_modifier(PCall::substrate_block_hash_selectors());
+		tester.test_view_modifier(PCall::extrinsic_index_selectors());
+	});
+}
+
+#[test]
+fn relay_parent_is_returned() {
+	ExtBuilder::default().build().execute_with(|| {
+		RelayParent::set(Some((42, H256::repeat_byte(0x42))));
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::relay_block_number {})
+			.expect_no_logs()
+			.execute_returns(42u32);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::relay_state_root {})
+			.expect_no_logs()
+			.execute_returns(H256::repeat_byte(0x42));
+	});
+}
+
+#[test]
+fn unknown_relay_parent_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		RelayParent::set(None);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::relay_block_number {})
+			.execute_reverts(|output| output == b"Relay parent is not available");
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::relay_state_root {})
+			.execute_reverts(|output| output == b"Relay parent is not available");
+	});
+}
+
+#[test]
+fn substrate_block_hash_is_returned() {
+	ExtBuilder::default().build().execute_with(|| {
+		frame_system::BlockHash::<Runtime>::insert(5, H256::repeat_byte(5));
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::substrate_block_hash { block_number: 5 },
+			)
+			.expect_no_logs()
+			.execute_returns(H256::repeat_byte(5));
+	});
+}
+
+#[test]
+fn unknown_substrate_block_hash_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::substrate_block_hash { block_number: 1000 },
+			)
+			.expect_no_logs()
+			.execute_returns(H256::zero());
+	});
+}
+
+#[test]
+fn extrinsic_index_is_returned() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_extrinsic_index(3);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::extrinsic_index {})
+			.expect_no_logs()
+			.execute_returns(3u32);
+	});
+}
+
+#[test]
+fn extrinsic_index_outside_extrinsic_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::extrinsic_index {})
+			.execute_reverts(|output| output == b"Not executed in an extrinsic");
+	});
+}
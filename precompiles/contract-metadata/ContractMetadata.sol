@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The ContractMetadata contract's address.
+address constant CONTRACT_METADATA_ADDRESS = 0x000000000000000000000000000000000000081C;
+
+/// @dev The ContractMetadata contract's instance.
+ContractMetadata constant CONTRACT_METADATA_CONTRACT = ContractMetadata(
+    CONTRACT_METADATA_ADDRESS
+);
+
+/// @author The Moonbeam Team
+/// @title Smart contract metadata registry interface
+/// @title The interface through which contracts and their deployers can register the metadata
+/// (IPFS CID and source hash) used to verify contracts, and explorers can query it
+/// @custom:address 0x000000000000000000000000000000000000081C
+interface ContractMetadata {
+    /// @dev Emitted when the metadata of a contract is registered or updated
+    /// @custom:selector 8ae2c92cdbef84c7ae7778e709b21c913d935b60024701aa38b898f330bccf39
+    /// @param contractAddress The contract whose metadata was registered
+    /// @param registrar The address which registered the metadata
+    event MetadataRegistered(
+        address indexed contractAddress,
+        address indexed registrar
+    );
+
+    /// @dev Register the metadata of the calling contract, e.g. from its constructor
+    /// @custom:selector c6197619
+    /// @param cid The IPFS CID of the contract metadata
+    /// @param sourceHash The hash of the contract sources
+    function registerMetadata(bytes memory cid, bytes32 sourceHash) external;
+
+    /// @dev Register the metadata of a contract deployed by the caller with CREATE
+    /// @custom:selector 218138cb
+    /// @param contractAddress The deployed contract
+    /// @param nonce The nonce of the caller used to deploy the contract
+    /// @param cid The IPFS CID of the contract metadata
+    /// @param sourceHash The hash of the contract sources
+    function registerMetadataAsDeployer(
+        address contractAddress,
+        uint64 nonce,
+        bytes memory cid,
+        bytes32 sourceHash
+    ) external;
+
+    /// @dev Register the metadata of a contract deployed by the caller with CREATE2
+    /// @custom:selector 62bc77b1
+    /// @param contractAddress The deployed contract
+    /// @param salt The salt used to deploy the contract
+    /// @param initCodeHash The keccak256 hash of the contract init code
+    /// @param cid The IPFS CID of the contract metadata
+    /// @param sourceHash The hash of the contract sources
+    function registerMetadataAsCreate2Deployer(
+        address contractAddress,
+        bytes32 salt,
+        bytes32 initCodeHash,
+        bytes memory cid,
+        bytes32 sourceHash
+    ) external;
+
+    /// @dev The metadata registered for a contract, empty if none
+    /// @custom:selector e194aa25
+    /// @param contractAddress The contract to query
+    /// @return registrar The address which registered the metadata
+    /// @return cid The IPFS CID of the contract metadata
+    /// @return sourceHash The hash of the contract sources
+    function metadataOf(address contractAddress)
+        external
+        view
+        returns (
+            address registrar,
+            bytes memory cid,
+            bytes32 sourceHash
+        );
+}
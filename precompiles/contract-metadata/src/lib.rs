@@ -0,0 +1,187 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to register and query the metadata of smart contracts stored in
+//! pallet-evm-contract-metadata, anchoring source verification on-chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{Log, PrecompileHandle};
+use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
+use pallet_evm::AddressMapping;
+use pallet_evm_contract_metadata::DeploymentProof;
+use precompile_utils::prelude::*;
+use sp_core::{Get, H160, H256};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity selector of the MetadataRegistered log.
+pub const SELECTOR_LOG_METADATA_REGISTERED: [u8; 32] =
+	keccak256!("MetadataRegistered(address,address)");
+
+/// Storage growth of a metadata registration, excluding the CID.
+/// MetadataOf: Blake2_128(16) + H160(20) + registrar(20) + CID length(2) + source hash(32)
+pub const METADATA_STORAGE_GROWTH: u64 = 90;
+
+pub fn log_metadata_registered(
+	address: impl Into<H160>,
+	contract: impl Into<H160>,
+	registrar: impl Into<H160>,
+) -> Log {
+	log3(
+		address.into(),
+		SELECTOR_LOG_METADATA_REGISTERED,
+		contract.into(),
+		registrar.into(),
+		Vec::new(),
+	)
+}
+
+/// Bound for the CID size.
+pub struct GetMaxCidLength<R>(PhantomData<R>);
+
+impl<R: pallet_evm_contract_metadata::Config> Get<u32> for GetMaxCidLength<R> {
+	fn get() -> u32 {
+		R::MaxCidLength::get()
+	}
+}
+
+/// A precompile to register and query the metadata of smart contracts.
+pub struct ContractMetadataPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> ContractMetadataPrecompile<Runtime>
+where
+	Runtime: pallet_evm_contract_metadata::Config + pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_evm_contract_metadata::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+{
+	#[precompile::public("registerMetadata(bytes,bytes32)")]
+	fn register_metadata(
+		handle: &mut impl PrecompileHandle,
+		cid: BoundedBytes<GetMaxCidLength<Runtime>>,
+		source_hash: H256,
+	) -> EvmResult {
+		let contract = handle.context().caller;
+		Self::register(handle, contract, None, cid, source_hash)
+	}
+
+	#[precompile::public("registerMetadataAsDeployer(address,uint64,bytes,bytes32)")]
+	fn register_metadata_as_deployer(
+		handle: &mut impl PrecompileHandle,
+		contract: Address,
+		nonce: u64,
+		cid: BoundedBytes<GetMaxCidLength<Runtime>>,
+		source_hash: H256,
+	) -> EvmResult {
+		Self::register(
+			handle,
+			contract.into(),
+			Some(DeploymentProof::Create { nonce }),
+			cid,
+			source_hash,
+		)
+	}
+
+	#[precompile::public(
+		"registerMetadataAsCreate2Deployer(address,bytes32,bytes32,bytes,bytes32)"
+	)]
+	fn register_metadata_as_create2_deployer(
+		handle: &mut impl PrecompileHandle,
+		contract: Address,
+		salt: H256,
+		init_code_hash: H256,
+		cid: BoundedBytes<GetMaxCidLength<Runtime>>,
+		source_hash: H256,
+	) -> EvmResult {
+		Self::register(
+			handle,
+			contract.into(),
+			Some(DeploymentProof::Create2 {
+				salt,
+				init_code_hash,
+			}),
+			cid,
+			source_hash,
+		)
+	}
+
+	#[precompile::public("metadataOf(address)")]
+	#[precompile::view]
+	fn metadata_of(
+		handle: &mut impl PrecompileHandle,
+		contract: Address,
+	) -> EvmResult<(Address, UnboundedBytes, H256)> {
+		// Storage item: MetadataOf:
+		// Blake2_128(16) + H160(20) + registrar(20) + CID(2 + MaxCidLength) + source hash(32)
+		handle.record_db_read::<Runtime>(
+			METADATA_STORAGE_GROWTH as usize + GetMaxCidLength::<Runtime>::get() as usize,
+		)?;
+
+		Ok(
+			match pallet_evm_contract_metadata::Pallet::<Runtime>::metadata_of(H160::from(contract))
+			{
+				Some(metadata) => (
+					Address(metadata.registrar),
+					metadata.cid.into_inner().into(),
+					metadata.source_hash,
+				),
+				None => (
+					Address(H160::zero()),
+					UnboundedBytes::from(&[][..]),
+					H256::zero(),
+				),
+			},
+		)
+	}
+
+	fn register(
+		handle: &mut impl PrecompileHandle,
+		contract: H160,
+		deployment_proof: Option<DeploymentProof>,
+		cid: BoundedBytes<GetMaxCidLength<Runtime>>,
+		source_hash: H256,
+	) -> EvmResult {
+		let caller = handle.context().caller;
+		let log = log_metadata_registered(handle.context().address, contract, caller);
+		handle.record_log_costs(&[&log])?;
+
+		let cid: Vec<u8> = cid.into();
+		let storage_growth = METADATA_STORAGE_GROWTH + cid.len() as u64;
+		let cid = cid
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("cid").in_field("cid"))?;
+
+		let origin = Runtime::AddressMapping::into_account_id(caller);
+		let call = pallet_evm_contract_metadata::Call::<Runtime>::register_metadata {
+			contract,
+			deployment_proof,
+			cid,
+			source_hash,
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, storage_growth)?;
+
+		log.record(handle)?;
+
+		Ok(())
+	}
+}
@@ -0,0 +1,182 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::*;
+use pallet_evm_contract_metadata::create_address;
+use precompile_utils::testing::*;
+use sha3::{Digest, Keccak256};
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["ContractMetadata.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::register_metadata_selectors().contains(&0xc6197619));
+	assert!(PCall::register_metadata_as_deployer_selectors().contains(&0x218138cb));
+	assert!(PCall::register_metadata_as_create2_deployer_selectors().contains(&0x62bc77b1));
+	assert!(PCall::metadata_of_selectors().contains(&0xe194aa25));
+
+	assert_eq!(
+		crate::SELECTOR_LOG_METADATA_REGISTERED,
+		&Keccak256::digest(b"MetadataRegistered(address,address)")[..]
+	);
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_default_modifier(PCall::register_metadata_selectors());
+		tester.test_default_modifier(PCall::register_metadata_as_deployer_selectors());
+		tester.test_default_modifier(PCall::register_metadata_as_create2_deployer_selectors());
+		tester.test_view_modifier(PCall::metadata_of_selectors());
+	});
+}
+
+#[test]
+fn metadata_of_unknown_contract_is_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::metadata_of {
+					contract: Address(Bob.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns((
+				Address(H160::zero()),
+				UnboundedBytes::from(&[][..]),
+				H256::zero(),
+			));
+	});
+}
+
+#[test]
+fn contract_registers_its_own_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::register_metadata {
+					cid: b"cid".into(),
+					source_hash: H256::repeat_byte(0x11),
+				},
+			)
+			.expect_log(log_metadata_registered(Precompile1, Bob, Bob))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::metadata_of {
+					contract: Address(Bob.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns((
+				Address(Bob.into()),
+				UnboundedBytes::from(b"cid"),
+				H256::repeat_byte(0x11),
+			));
+	});
+}
+
+#[test]
+fn deployer_registers_contract_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		let contract = create_address(Alice.into(), 7);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::register_metadata_as_deployer {
+					contract: Address(contract),
+					nonce: 7,
+					cid: b"cid".into(),
+					source_hash: H256::repeat_byte(0x11),
+				},
+			)
+			.expect_log(log_metadata_registered(Precompile1, contract, Alice))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::metadata_of {
+					contract: Address(contract),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns((
+				Address(Alice.into()),
+				UnboundedBytes::from(b"cid"),
+				H256::repeat_byte(0x11),
+			));
+	});
+}
+
+#[test]
+fn only_contract_or_deployer_can_register_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		let contract = create_address(Alice.into(), 0);
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::register_metadata_as_deployer {
+					contract: Address(contract),
+					nonce: 0,
+					cid: b"cid".into(),
+					source_hash: H256::repeat_byte(0x11),
+				},
+			)
+			.expect_no_logs()
+			.execute_reverts(|output| output.ends_with(b"NotContractOrDeployer\") })"));
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::register_metadata_as_create2_deployer {
+					contract: Address(contract),
+					salt: H256::zero(),
+					init_code_hash: H256::zero(),
+					cid: b"cid".into(),
+					source_hash: H256::repeat_byte(0x11),
+				},
+			)
+			.expect_no_logs()
+			.execute_reverts(|output| output.ends_with(b"NotContractOrDeployer\") })"));
+	});
+}
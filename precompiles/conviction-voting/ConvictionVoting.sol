@@ -207,6 +207,22 @@ interface ConvictionVoting {
         uint256 abstain
     ) external;
 
+    /// @dev Vote yes or no in many polls at once.
+    /// A vote failing to be cast (e.g. the poll is no longer ongoing) doesn't prevent the others
+    /// from being cast.
+    /// @custom:selector 1b8bacee
+    /// @param pollIndexes Indexes of the polls
+    /// @param voteKinds For each poll, 0 to vote no and 1 to vote yes
+    /// @param amounts For each poll, balance locked for vote
+    /// @param convictions For each poll, conviction multiplier for length of vote lock
+    /// @return cast For each poll, whether the vote was cast
+    function voteMany(
+        uint32[] memory pollIndexes,
+        uint8[] memory voteKinds,
+        uint256[] memory amounts,
+        Conviction[] memory convictions
+    ) external returns (bool[] memory cast);
+
     /// @dev Remove vote in poll
     /// @custom:selector 79cae220
     /// @param pollIndex Index of the poll
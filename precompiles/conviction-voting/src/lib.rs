@@ -16,7 +16,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use fp_evm::PrecompileHandle;
+use fp_evm::{PrecompileFailure, PrecompileHandle};
 use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
 use frame_support::traits::{Currency, Polling};
 use pallet_conviction_voting::Call as ConvictionVotingCall;
@@ -27,7 +27,7 @@ use pallet_conviction_voting::{
 use pallet_evm::{AddressMapping, Log};
 use precompile_utils::prelude::*;
 use sp_core::{Get, MaxEncodedLen, H160, H256, U256};
-use sp_runtime::traits::StaticLookup;
+use sp_runtime::traits::{ConstU32, StaticLookup};
 use sp_std::marker::PhantomData;
 use sp_std::vec::Vec;
 
@@ -96,6 +96,11 @@ pub(crate) const SELECTOR_LOG_UNDELEGATED: [u8; 32] = keccak256!("Undelegated(ui
 /// Solidity selector of the Unlock log, which is the Keccak of the Log signature.
 pub(crate) const SELECTOR_LOG_UNLOCKED: [u8; 32] = keccak256!("Unlocked(uint16,address)");
 
+/// Maximum number of polls that can be voted on with a single `voteMany` call.
+pub const ARRAY_LIMIT: u32 = 2u32.pow(7);
+
+type GetArrayLimit = ConstU32<ARRAY_LIMIT>;
+
 /// A precompile to wrap the functionality from pallet-conviction-voting.
 pub struct ConvictionVotingPrecompile<Runtime>(PhantomData<Runtime>);
 
@@ -229,6 +234,65 @@ where
 		)
 	}
 
+	/// Vote yes or no in many polls at once. A vote failing to be dispatched (e.g. because the
+	/// poll is no longer ongoing) does not prevent the other votes from being cast.
+	///
+	/// Parameters:
+	/// * poll_indexes: Indexes of the polls
+	/// * vote_kinds: For each poll, 0 to vote no and 1 to vote yes
+	/// * amounts: For each poll, balance locked for vote
+	/// * convictions: For each poll, conviction multiplier for length of vote lock
+	///
+	/// Returns, for each poll, whether the vote was cast.
+	#[precompile::public("voteMany(uint32[],uint8[],uint256[],uint8[])")]
+	fn vote_many(
+		handle: &mut impl PrecompileHandle,
+		poll_indexes: BoundedVec<u32, GetArrayLimit>,
+		vote_kinds: BoundedVec<u8, GetArrayLimit>,
+		amounts: BoundedVec<U256, GetArrayLimit>,
+		convictions: BoundedVec<u8, GetArrayLimit>,
+	) -> EvmResult<Vec<bool>> {
+		let poll_indexes: Vec<_> = poll_indexes.into();
+		let vote_kinds: Vec<_> = vote_kinds.into();
+		let amounts: Vec<_> = amounts.into();
+		let convictions: Vec<_> = convictions.into();
+
+		let len = poll_indexes.len();
+		if vote_kinds.len() != len || amounts.len() != len || convictions.len() != len {
+			return Err(revert("Arrays must have the same length"));
+		}
+
+		// Check all the votes before casting any, such that malformed input reverts the whole
+		// batch.
+		let mut votes = Vec::with_capacity(len);
+		for ((vote_kind, amount), conviction) in
+			vote_kinds.into_iter().zip(amounts).zip(convictions)
+		{
+			Self::u256_to_amount(amount).in_field("amounts")?;
+
+			votes.push(AccountVote::Standard {
+				vote: Vote {
+					aye: Self::u8_to_aye(vote_kind).in_field("voteKinds")?,
+					conviction: Self::u8_to_conviction(conviction).in_field("convictions")?,
+				},
+				balance: amount,
+			});
+		}
+
+		let mut results = Vec::with_capacity(len);
+		for (poll_index, vote) in poll_indexes.into_iter().zip(votes) {
+			let cast = match Self::vote(handle, poll_index, vote) {
+				Ok(()) => true,
+				Err(PrecompileFailure::Revert { .. }) => false,
+				// Running out of gas or fatal errors abort the whole batch.
+				Err(e) => return Err(e),
+			};
+			results.push(cast);
+		}
+
+		Ok(results)
+	}
+
 	#[precompile::public("removeVote(uint32)")]
 	fn remove_vote(handle: &mut impl PrecompileHandle, poll_index: u32) -> EvmResult {
 		Self::rm_vote(handle, poll_index, None)
@@ -500,6 +564,14 @@ where
 			.map_err(|_| RevertReason::custom("Must be an integer between 0 and 6 included").into())
 	}
 
+	fn u8_to_aye(vote_kind: u8) -> MayRevert<bool> {
+		match vote_kind {
+			0 => Ok(false),
+			1 => Ok(true),
+			_ => Err(RevertReason::custom("Must be 0 (no) or 1 (yes)").into()),
+		}
+	}
+
 	fn u32_to_index(index: u32) -> MayRevert<IndexOf<Runtime>> {
 		index
 			.try_into()
@@ -573,3 +573,107 @@ fn test_class_locks_for_returns_correct_value() {
 				}]);
 		})
 }
+
+#[test]
+fn vote_many_reports_per_poll_results() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 100_000)])
+		.build()
+		.execute_with(|| {
+			// Poll 1 is completed, so the vote cannot be cast.
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::vote_many {
+						poll_indexes: vec![ONGOING_POLL_INDEX, 1].into(),
+						vote_kinds: vec![1, 0].into(),
+						amounts: vec![U256::from(100_000), U256::from(50_000)].into(),
+						convictions: vec![1, 2].into(),
+					},
+				)
+				.expect_log(log2(
+					Precompile1,
+					SELECTOR_LOG_VOTED,
+					H256::from_low_u64_be(ONGOING_POLL_INDEX as u64),
+					solidity::encode_event_data((
+						Address(Alice.into()), // caller
+						true,                  // vote
+						U256::from(100_000),   // amount
+						1u8,                   // conviction
+					)),
+				))
+				.execute_returns(vec![true, false]);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::voting_for {
+						who: H160::from(Alice).into(),
+						track_id: 0u16,
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(crate::OutputVotingFor {
+					is_casting: true,
+					casting: crate::OutputCasting {
+						votes: vec![crate::PollAccountVote {
+							poll_index: ONGOING_POLL_INDEX,
+							account_vote: crate::OutputAccountVote {
+								is_standard: true,
+								standard: crate::StandardVote {
+									vote: crate::OutputVote {
+										aye: true,
+										conviction: 1,
+									},
+									balance: 100_000.into(),
+								},
+								..Default::default()
+							},
+						}],
+						delegations: crate::Delegations {
+							votes: 0.into(),
+							capital: 0.into(),
+						},
+						prior: crate::PriorLock { balance: 0.into() },
+					},
+					..Default::default()
+				});
+		})
+}
+
+#[test]
+fn vote_many_reverts_on_malformed_input() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 100_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::vote_many {
+						poll_indexes: vec![ONGOING_POLL_INDEX, 1].into(),
+						vote_kinds: vec![1].into(),
+						amounts: vec![U256::from(100_000), U256::from(50_000)].into(),
+						convictions: vec![1, 2].into(),
+					},
+				)
+				.execute_reverts(|output| output == b"Arrays must have the same length");
+
+			// A malformed vote reverts the whole batch, even if it comes after a valid one.
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::vote_many {
+						poll_indexes: vec![ONGOING_POLL_INDEX, 1].into(),
+						vote_kinds: vec![1, 2].into(),
+						amounts: vec![U256::from(100_000), U256::from(50_000)].into(),
+						convictions: vec![1, 2].into(),
+					},
+				)
+				.execute_reverts(|output| output == b"voteKinds: Must be 0 (no) or 1 (yes)");
+		})
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The DeployerFilter contract's address.
+address constant DEPLOYER_FILTER_ADDRESS = 0x000000000000000000000000000000000000081B;
+
+/// @dev The DeployerFilter contract's instance.
+DeployerFilter constant DEPLOYER_FILTER_CONTRACT = DeployerFilter(
+    DEPLOYER_FILTER_ADDRESS
+);
+
+/// @author The Moonbeam Team
+/// @title Smart contract deployer allow-list interface
+/// @title The interface through which solidity contracts can check which addresses are allowed
+/// to deploy smart contracts
+/// @dev Only the deployers of top-level contract creations are checked, the contracts created by
+/// other contracts (e.g. factories) are never restricted.
+/// @custom:address 0x000000000000000000000000000000000000081B
+interface DeployerFilter {
+    /// @dev Whether contract deployments are restricted to the allowed deployers
+    /// @custom:selector 887070f4
+    /// @return Whether the filter is enabled
+    function isFilterEnabled() external view returns (bool);
+
+    /// @dev Whether an address is in the allow-list of deployers, regardless of the filter
+    /// being enabled
+    /// @custom:selector 6b288d20
+    /// @param deployer The address to check
+    /// @return Whether the address is an allowed deployer
+    function isAllowedDeployer(address deployer) external view returns (bool);
+
+    /// @dev Whether an address can currently deploy contracts
+    /// @custom:selector 569e0d29
+    /// @param deployer The address to check
+    /// @return Whether the address can deploy contracts
+    function canDeploy(address deployer) external view returns (bool);
+}
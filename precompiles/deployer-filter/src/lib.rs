@@ -0,0 +1,72 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile exposing the allow-list of smart contract deployers of
+//! pallet-evm-deployer-filter.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use precompile_utils::prelude::*;
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// A precompile to query the allow-list of smart contract deployers.
+pub struct DeployerFilterPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> DeployerFilterPrecompile<Runtime>
+where
+	Runtime: pallet_evm_deployer_filter::Config + pallet_evm::Config,
+{
+	#[precompile::public("isFilterEnabled()")]
+	#[precompile::view]
+	fn is_filter_enabled(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+		// Storage item: FilterEnabled: bool(1)
+		handle.record_db_read::<Runtime>(1)?;
+
+		Ok(pallet_evm_deployer_filter::Pallet::<Runtime>::is_filter_enabled())
+	}
+
+	#[precompile::public("isAllowedDeployer(address)")]
+	#[precompile::view]
+	fn is_allowed_deployer(
+		handle: &mut impl PrecompileHandle,
+		deployer: Address,
+	) -> EvmResult<bool> {
+		// Storage item: AllowedDeployers: Blake2_128(16) + H160(20)
+		handle.record_db_read::<Runtime>(36)?;
+
+		Ok(pallet_evm_deployer_filter::Pallet::<Runtime>::is_allowed_deployer(deployer.into()))
+	}
+
+	#[precompile::public("canDeploy(address)")]
+	#[precompile::view]
+	fn can_deploy(handle: &mut impl PrecompileHandle, deployer: Address) -> EvmResult<bool> {
+		// Storage item: FilterEnabled: bool(1)
+		handle.record_db_read::<Runtime>(1)?;
+		// Storage item: AllowedDeployers: Blake2_128(16) + H160(20)
+		handle.record_db_read::<Runtime>(36)?;
+
+		Ok(pallet_evm_deployer_filter::Pallet::<Runtime>::can_deploy(
+			deployer.into(),
+		))
+	}
+}
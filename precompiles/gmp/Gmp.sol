@@ -23,4 +23,24 @@ interface Gmp {
     ///
     /// @custom:selector f53774ab
     function wormholeTransferERC20(bytes memory vaa) external;
+
+    /// Publish a message through the Wormhole core contract. The message is emitted by an account
+    /// derived from the caller, such that receivers can authenticate its sender.
+    /// The value sent must match the message fee of the core contract.
+    ///
+    /// @custom:selector bdb2ec5b
+    /// @param payload The payload of the message
+    /// @param nonce The nonce of the message
+    /// @return sequence The sequence number of the message
+    function wormholePublishWithPayload(bytes memory payload, uint32 nonce)
+        external
+        payable
+        returns (uint64 sequence);
+
+    /// Get the Wormhole emitter of the messages published by a sender
+    ///
+    /// @custom:selector a8d33691
+    /// @param sender The sender of the messages
+    /// @return emitter The emitter address of the messages
+    function wormholeEmitter(address sender) external view returns (address emitter);
 }
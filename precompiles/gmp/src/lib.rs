@@ -14,12 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
-//! Precompile to receive GMP callbacks and forward to XCM
+//! Precompile to receive GMP callbacks and forward to XCM, and to publish outbound GMP messages
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use evm::ExitReason;
-use fp_evm::{Context, ExitRevert, PrecompileFailure, PrecompileHandle};
+use fp_evm::{Context, ExitRevert, PrecompileFailure, PrecompileHandle, Transfer};
 use frame_support::{
 	codec::Decode,
 	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
@@ -27,13 +27,16 @@ use frame_support::{
 	traits::ConstU32,
 };
 use pallet_evm::AddressMapping;
-use parity_scale_codec::DecodeLimit;
+use pallet_token_route_registry::{AccountFormat, BridgeProtocol, Route, RouteProvider};
+use parity_scale_codec::{DecodeLimit, MaxEncodedLen};
 use precompile_utils::{prelude::*, solidity::revert::revert_as_bytes};
-use sp_core::{H160, U256};
+use sp_core::{H160, H256, U256};
 use sp_std::boxed::Box;
 use sp_std::{marker::PhantomData, vec::Vec};
 use types::*;
+use xcm::latest::Junction;
 use xcm::opaque::latest::WeightLimit;
+use xcm::VersionedMultiLocation;
 use xcm_primitives::AccountIdToCurrencyId;
 
 #[cfg(test)]
@@ -56,13 +59,26 @@ const COMPLETE_TRANSFER_WITH_PAYLOAD_SELECTOR: u32 = 0xc3f511c1_u32;
 const WRAPPED_ASSET_SELECTOR: u32 = 0x1ff1e286_u32;
 const BALANCE_OF_SELECTOR: u32 = 0x70a08231_u32;
 const TRANSFER_SELECTOR: u32 = 0xa9059cbb_u32;
+const WITHDRAW_SELECTOR: u32 = 0x2e1a7d4d_u32;
+const MESSAGE_FEE_SELECTOR: u32 = 0x1a90a219_u32;
+const PUBLISH_MESSAGE_SELECTOR: u32 = 0xb19a437e_u32;
+
+/// Consistency level of the published Wormhole messages, such that guardians only sign them once
+/// the block including them is finalized.
+const WORMHOLE_CONSISTENCY_LEVEL_FINALIZED: u8 = 1;
+
+/// The native currency is identified by the address of its ERC-20 precompile.
+const NATIVE_ERC20_ADDRESS: u64 = 2050;
 
 /// Gmp precompile.
+///
+/// Transfers can be routed along the canonical routes of the tokens provided by `Routes`, which
+/// provides no route by default.
 #[derive(Debug, Clone)]
-pub struct GmpPrecompile<Runtime>(PhantomData<Runtime>);
+pub struct GmpPrecompile<Runtime, Routes = ()>(PhantomData<(Runtime, Routes)>);
 
 #[precompile_utils::precompile]
-impl<Runtime> GmpPrecompile<Runtime>
+impl<Runtime, Routes> GmpPrecompile<Runtime, Routes>
 where
 	Runtime: pallet_evm::Config + frame_system::Config + pallet_xcm::Config + orml_xtokens::Config,
 	SystemCallOf<Runtime>: Dispatchable<PostInfo = PostDispatchInfo> + Decode + GetDispatchInfo,
@@ -71,6 +87,7 @@ where
 	<Runtime as frame_system::Config>::RuntimeCall: From<orml_xtokens::Call<Runtime>>,
 	Runtime: AccountIdToCurrencyId<Runtime::AccountId, CurrencyIdOf<Runtime>>,
 	XBalanceOf<Runtime>: TryFrom<U256> + Into<U256> + solidity::Codec,
+	Routes: RouteProvider,
 {
 	#[precompile::public("wormholeTransferERC20(bytes)")]
 	pub fn wormhole_transfer_erc20(
@@ -90,6 +107,10 @@ where
 		handle.record_db_read::<Runtime>(20)?;
 		// PrecompileEnabled: AccountId(1)
 		handle.record_db_read::<Runtime>(1)?;
+		// WrappedNativeAddress: AccountId(20)
+		handle.record_db_read::<Runtime>(20)?;
+		// MinimumTransferAmount: Blake2128(16) + AccountId(20) + U256(32)
+		handle.record_db_read::<Runtime>(68)?;
 
 		ensure_enabled()?;
 
@@ -102,6 +123,12 @@ where
 		log::trace!(target: "gmp-precompile", "core contract: {:?}", wormhole);
 		log::trace!(target: "gmp-precompile", "bridge contract: {:?}", wormhole_bridge);
 
+		// reject malformed VAAs before doing any call to the Wormhole contracts
+		let vaa = WormholeVaa::parse(wormhole_vaa.as_bytes()).in_field("wormholeVaa")?;
+		WormholeTransferWithPayloadData::parse(vaa.payload)
+			.in_field("payload")
+			.in_field("wormholeVaa")?;
+
 		// get the wormhole VM from the provided VAA. Unfortunately, this forces us to parse
 		// the VAA twice -- this seems to be a restriction imposed from the Wormhole contract design
 		let output = Self::call(
@@ -139,6 +166,18 @@ where
 		let wrapped_address: Address = solidity::decode_return_value(&output[..])?;
 		log::debug!(target: "gmp-precompile", "wrapped token address: {:?}", wrapped_address);
 
+		// tokens native to this chain (such as the wrapped native token) are not wrapped by the
+		// bridge, which reports the zero address for them: the token is then the one in the VAA.
+		let wrapped_address = if wrapped_address.0.is_zero() {
+			Address(transfer_with_payload.token_address.into())
+		} else {
+			wrapped_address
+		};
+
+		// the wrapped native token is unwrapped and sent through xtokens as the native currency
+		let unwrap_native = storage::WrappedNativeAddress::get() == Some(wrapped_address.into());
+		log::debug!(target: "gmp-precompile", "unwrap native: {}", unwrap_native);
+
 		// query our "before" balance (our being this precompile)
 		let output = Self::call(
 			handle,
@@ -156,7 +195,12 @@ where
 		.map_err(|_| RevertReason::Custom("Invalid GMP Payload".into()))?;
 		log::debug!(target: "gmp-precompile", "user action: {:?}", user_action);
 
-		let currency_account_id = Runtime::AddressMapping::into_account_id(wrapped_address.into());
+		let currency_address = if unwrap_native {
+			H160::from_low_u64_be(NATIVE_ERC20_ADDRESS)
+		} else {
+			wrapped_address.into()
+		};
+		let currency_account_id = Runtime::AddressMapping::into_account_id(currency_address);
 
 		let currency_id: <Runtime as orml_xtokens::Config>::CurrencyId =
 			Runtime::account_to_currency_id(currency_account_id)
@@ -189,66 +233,70 @@ where
 			.map_err(|_| revert("Amount overflows balance"))?;
 
 		log::debug!(target: "gmp-precompile", "sending XCM via xtokens::transfer...");
-		let call: Option<orml_xtokens::Call<Runtime>> = match user_action {
+		let transfer: Option<(VersionedMultiLocation, XBalanceOf<Runtime>)> = match user_action {
 			VersionedUserAction::V1(action) => {
 				log::debug!(target: "gmp-precompile", "Payload: V1");
-				Some(orml_xtokens::Call::<Runtime>::transfer {
-					currency_id,
-					amount,
-					dest: Box::new(action.destination),
-					dest_weight_limit: WeightLimit::Unlimited,
-				})
+				Some((action.destination, amount))
 			}
 			VersionedUserAction::V2(action) => {
 				log::debug!(target: "gmp-precompile", "Payload: V2");
-				// if the specified fee is more than the amount being transferred, we'll be nice to
-				// the sender and pay them the entire amount.
-				let fee = action.fee.min(amount_transferred);
-
-				if fee > U256::zero() {
-					let output = Self::call(
-						handle,
-						wrapped_address.into(),
-						solidity::encode_with_selector(
-							TRANSFER_SELECTOR,
-							(Address::from(handle.context().caller), fee),
-						),
-					)?;
-					let transferred: bool = solidity::decode_return_value(&output[..])?;
-
-					if !transferred {
-						return Err(RevertReason::custom("failed to transfer() fee").into());
-					}
-				}
-
-				let fee = fee
-					.try_into()
-					.map_err(|_| revert("Fee amount overflows balance"))?;
+				Self::pay_fee(
+					handle,
+					wrapped_address.into(),
+					action.fee,
+					amount_transferred,
+					amount,
+				)?
+				.map(|remaining| (action.destination, remaining))
+			}
+			VersionedUserAction::V3(action) => {
+				log::debug!(target: "gmp-precompile", "Payload: V3");
+				let destination = Self::route_destination(
+					handle,
+					currency_address,
+					action.dest_chain,
+					action.beneficiary,
+				)?;
+				Self::pay_fee(
+					handle,
+					wrapped_address.into(),
+					action.fee,
+					amount_transferred,
+					amount,
+				)?
+				.map(|remaining| (destination, remaining))
+			}
+		};
 
+		if let Some((destination, amount)) = transfer {
+			// sending dust would cost more in XCM fees than it is worth, and could fail the
+			// existential deposit on the destination. The transfer is rejected so that the VAA can
+			// still be redeemed if the minimum is lowered.
+			let minimum = storage::MinimumTransferAmount::get(currency_address);
+			let sent: U256 = amount.into();
+			if sent < minimum {
 				log::debug!(
 					target: "gmp-precompile",
-					"deducting fee from transferred amount {:?} - {:?} = {:?}",
-					amount, fee, (amount - fee)
+					"amount {} below minimum {} for {:?}", sent, minimum, currency_address,
 				);
-
-				let remaining = amount.saturating_sub(fee);
-
-				if !remaining.is_zero() {
-					Some(orml_xtokens::Call::<Runtime>::transfer {
-						currency_id,
-						amount: remaining,
-						dest: Box::new(action.destination),
-						dest_weight_limit: WeightLimit::Unlimited,
-					})
-				} else {
-					None
-				}
+				return Err(RevertReason::custom("amount below minimum transfer amount").into());
 			}
-		};
 
-		if let Some(call) = call {
+			let sender = if unwrap_native {
+				Self::unwrap_native(handle, wrapped_address.into(), amount.into())?
+			} else {
+				handle.code_address()
+			};
+
+			let call = orml_xtokens::Call::<Runtime>::transfer {
+				currency_id,
+				amount,
+				dest: Box::new(destination),
+				dest_weight_limit: WeightLimit::Unlimited,
+			};
+
 			log::debug!(target: "gmp-precompile", "sending xcm {:?}", call);
-			let origin = Runtime::AddressMapping::into_account_id(handle.code_address());
+			let origin = Runtime::AddressMapping::into_account_id(sender);
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
 				Some(origin).into(),
@@ -266,15 +314,231 @@ where
 		Ok(())
 	}
 
+	#[precompile::public("wormholePublishWithPayload(bytes,uint32)")]
+	#[precompile::payable]
+	pub fn wormhole_publish_with_payload(
+		handle: &mut impl PrecompileHandle,
+		payload: BoundedBytes<GetCallDataLimit>,
+		nonce: u32,
+	) -> EvmResult<u64> {
+		// CoreAddress: AccountId(20)
+		handle.record_db_read::<Runtime>(20)?;
+		// PrecompileEnabled: AccountId(1)
+		handle.record_db_read::<Runtime>(1)?;
+
+		ensure_enabled()?;
+
+		let wormhole = storage::CoreAddress::get()
+			.ok_or(RevertReason::custom("invalid wormhole core address"))?;
+
+		// the message fee of the core contract is paid by the caller, and forwarded from this
+		// precompile which received it.
+		let output = Self::call(
+			handle,
+			wormhole,
+			solidity::encode_with_selector(MESSAGE_FEE_SELECTOR, ()),
+		)?;
+		let message_fee: U256 = solidity::decode_return_value(&output[..])?;
+
+		if handle.context().apparent_value != message_fee {
+			return Err(RevertReason::custom("value must match the wormhole message fee").into());
+		}
+
+		// each caller publishes under its own emitter, such that receivers can authenticate the
+		// sender of the messages from the emitter address of the VAAs.
+		let emitter = wormhole_emitter(handle.context().caller);
+		log::debug!(target: "gmp-precompile", "publishing message from emitter {:?}", emitter);
+
+		let sub_context = Context {
+			caller: emitter,
+			address: wormhole,
+			apparent_value: message_fee,
+		};
+		let transfer = if message_fee.is_zero() {
+			None
+		} else {
+			Some(Transfer {
+				source: handle.code_address(),
+				target: wormhole,
+				value: message_fee,
+			})
+		};
+
+		let (reason, output) = handle.call(
+			wormhole,
+			transfer,
+			solidity::encode_with_selector(
+				PUBLISH_MESSAGE_SELECTOR,
+				(nonce, payload, WORMHOLE_CONSISTENCY_LEVEL_FINALIZED),
+			),
+			None,
+			false,
+			&sub_context,
+		);
+		ensure_exit_reason_success(reason, &output[..])?;
+
+		let sequence: u64 = solidity::decode_return_value(&output[..])?;
+		log::debug!(target: "gmp-precompile", "published message with sequence {}", sequence);
+
+		Ok(sequence)
+	}
+
+	#[precompile::public("wormholeEmitter(address)")]
+	#[precompile::view]
+	fn wormhole_emitter_of(
+		_handle: &mut impl PrecompileHandle,
+		sender: Address,
+	) -> EvmResult<Address> {
+		Ok(Address(wormhole_emitter(sender.0)))
+	}
+
+	/// Pay `fee` of the transferred token to the caller out of the `amount` transferred to this
+	/// precompile, returning the amount remaining to be sent through XCM, if any.
+	fn pay_fee(
+		handle: &mut impl PrecompileHandle,
+		token: H160,
+		fee: U256,
+		amount_transferred: U256,
+		amount: XBalanceOf<Runtime>,
+	) -> EvmResult<Option<XBalanceOf<Runtime>>> {
+		// if the specified fee is more than the amount being transferred, we'll be nice to
+		// the sender and pay them the entire amount.
+		let fee = fee.min(amount_transferred);
+
+		if fee > U256::zero() {
+			let output = Self::call(
+				handle,
+				token,
+				solidity::encode_with_selector(
+					TRANSFER_SELECTOR,
+					(Address::from(handle.context().caller), fee),
+				),
+			)?;
+			let transferred: bool = solidity::decode_return_value(&output[..])?;
+
+			if !transferred {
+				return Err(RevertReason::custom("failed to transfer() fee").into());
+			}
+		}
+
+		let fee = fee
+			.try_into()
+			.map_err(|_| revert("Fee amount overflows balance"))?;
+
+		log::debug!(
+			target: "gmp-precompile",
+			"deducting fee from transferred amount {:?} - {:?} = {:?}",
+			amount, fee, (amount - fee)
+		);
+
+		let remaining = amount.saturating_sub(fee);
+
+		if !remaining.is_zero() {
+			Ok(Some(remaining))
+		} else {
+			Ok(None)
+		}
+	}
+
+	/// Destination of `beneficiary` on `dest_chain`, following the canonical route of `token`.
+	///
+	/// xtokens sends the tokens through their reserve, which the hops of the route describe: only
+	/// the final chain of the route and the format of its accounts are needed to build the
+	/// destination.
+	fn route_destination(
+		handle: &mut impl PrecompileHandle,
+		token: H160,
+		dest_chain: H256,
+		beneficiary: H256,
+	) -> EvmResult<VersionedMultiLocation> {
+		// Routes: Blake2128(16) + H160(20) + Blake2128(16) + H256(32) + Route
+		handle.record_db_read::<Runtime>(84 + Route::<Routes::MaxHops>::max_encoded_len())?;
+
+		let route = Routes::route_for(token, dest_chain).ok_or(RevertReason::custom(
+			"no route for the token to the destination chain",
+		))?;
+		log::debug!(target: "gmp-precompile", "route: {:?}", route);
+
+		// the tokens received by this precompile are bridged in through Wormhole
+		if route.bridge_in != BridgeProtocol::Wormhole {
+			return Err(
+				RevertReason::custom("route does not bridge the token through wormhole").into(),
+			);
+		}
+
+		let account = match route.account_format {
+			AccountFormat::AccountId32 => Junction::AccountId32 {
+				network: None,
+				id: beneficiary.0,
+			},
+			AccountFormat::AccountKey20 => Junction::AccountKey20 {
+				network: None,
+				key: H160::from(beneficiary).0,
+			},
+		};
+		let destination = route
+			.destination
+			.pushed_with_interior(account)
+			.map_err(|_| RevertReason::custom("route destination is too long"))?;
+
+		Ok(destination.into())
+	}
+
+	/// Unwrap `amount` of the wrapped native token held by this precompile, returning the
+	/// account now holding it as native balance.
+	///
+	/// The wrapped native token sends the unwrapped balance back to its caller, which would
+	/// execute this precompile again if it was the caller. The tokens are thus first moved to a
+	/// plain escrow account, which then unwraps them.
+	fn unwrap_native(
+		handle: &mut impl PrecompileHandle,
+		wrapped_native: H160,
+		amount: U256,
+	) -> EvmResult<H160> {
+		let escrow = native_escrow();
+
+		let output = Self::call(
+			handle,
+			wrapped_native,
+			solidity::encode_with_selector(TRANSFER_SELECTOR, (Address(escrow), amount)),
+		)?;
+		let transferred: bool = solidity::decode_return_value(&output[..])?;
+
+		if !transferred {
+			return Err(RevertReason::custom("failed to transfer() wrapped native token").into());
+		}
+
+		log::debug!(target: "gmp-precompile", "unwrapping {} native from {}", amount, escrow);
+		Self::call_as(
+			handle,
+			escrow,
+			wrapped_native,
+			solidity::encode_with_selector(WITHDRAW_SELECTOR, amount),
+		)?;
+
+		Ok(escrow)
+	}
+
 	/// call the given contract / function selector and return its output. Returns Err if the EVM
 	/// exit reason is not Succeed.
 	fn call(
 		handle: &mut impl PrecompileHandle,
 		contract_address: H160,
 		call_data: Vec<u8>,
+	) -> EvmResult<Vec<u8>> {
+		let caller = handle.code_address();
+		Self::call_as(handle, caller, contract_address, call_data)
+	}
+
+	/// Same as `call`, but with the given caller instead of this precompile.
+	fn call_as(
+		handle: &mut impl PrecompileHandle,
+		caller: H160,
+		contract_address: H160,
+		call_data: Vec<u8>,
 	) -> EvmResult<Vec<u8>> {
 		let sub_context = Context {
-			caller: handle.code_address(),
+			caller,
 			address: contract_address,
 			apparent_value: U256::zero(),
 		};
@@ -308,6 +572,20 @@ fn ensure_exit_reason_success(reason: ExitReason, output: &[u8]) -> EvmResult<()
 	}
 }
 
+/// Account unwrapping the wrapped native token on behalf of the precompile, derived from
+/// keccak256("gmp-native-escrow").
+pub fn native_escrow() -> H160 {
+	H160::from_slice(&sp_io::hashing::keccak_256(b"gmp-native-escrow")[12..])
+}
+
+/// Account publishing the Wormhole messages of `sender`, derived from
+/// keccak256("gmp-wormhole-emitter" ++ sender).
+pub fn wormhole_emitter(sender: H160) -> H160 {
+	let mut preimage = b"gmp-wormhole-emitter".to_vec();
+	preimage.extend_from_slice(sender.as_bytes());
+	H160::from_slice(&sp_io::hashing::keccak_256(&preimage)[12..])
+}
+
 pub fn is_enabled() -> bool {
 	match storage::PrecompileEnabled::get() {
 		Some(enabled) => enabled,
@@ -332,11 +610,14 @@ fn ensure_enabled() -> EvmResult<()> {
 /// twox_128("CoreAddress") => 0x59ff23ff65cc809711800d9d04e4b14c
 /// twox_128("BridgeAddress") => 0xc1586bde54b249fb7f521faf831ade45
 /// twox_128("PrecompileEnabled") => 0x2551bba17abb82ef3498bab688e470b8
+/// twox_128("WrappedNativeAddress") => 0xf8539b2c8d2b40db555315dfda640993
+/// twox_128("MinimumTransferAmount") => 0x2b2cc1f67826c5ae96fdb02f21f8df90
 mod storage {
 	use super::*;
 	use frame_support::{
-		storage::types::{OptionQuery, StorageValue},
+		storage::types::{OptionQuery, StorageMap, StorageValue, ValueQuery},
 		traits::StorageInstance,
+		Blake2_128Concat,
 	};
 
 	// storage for the core contract
@@ -369,4 +650,27 @@ mod storage {
 		}
 	}
 	pub type PrecompileEnabled = StorageValue<PrecompileEnabledStorageInstance, bool, OptionQuery>;
+
+	// storage for the wrapped native token contract (WGLMR), unwrapped before being sent
+	pub struct WrappedNativeAddressStorageInstance;
+	impl StorageInstance for WrappedNativeAddressStorageInstance {
+		const STORAGE_PREFIX: &'static str = "WrappedNativeAddress";
+		fn pallet_prefix() -> &'static str {
+			"gmp"
+		}
+	}
+	pub type WrappedNativeAddress =
+		StorageValue<WrappedNativeAddressStorageInstance, H160, OptionQuery>;
+
+	// storage for the minimum amount sent through XCM, per currency address. Currencies without
+	// an entry have no minimum.
+	pub struct MinimumTransferAmountStorageInstance;
+	impl StorageInstance for MinimumTransferAmountStorageInstance {
+		const STORAGE_PREFIX: &'static str = "MinimumTransferAmount";
+		fn pallet_prefix() -> &'static str {
+			"gmp"
+		}
+	}
+	pub type MinimumTransferAmount =
+		StorageMap<MinimumTransferAmountStorageInstance, Blake2_128Concat, H160, U256, ValueQuery>;
 }
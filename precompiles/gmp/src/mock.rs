@@ -23,13 +23,22 @@ use frame_support::{construct_runtime, parameter_types, weights::Weight};
 use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key};
 use pallet_evm::{EnsureAddressNever, EnsureAddressRoot};
 use parity_scale_codec::{Decode, Encode};
-use precompile_utils::{mock_account, precompile_set::*, testing::MockAccount};
+use precompile_utils::{
+	mock_account,
+	precompile_set::*,
+	testing::{MockAccount, Subcall, SubcallOutput, SubcallTrait},
+};
 use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 	Perbill,
 };
+use std::{
+	cell::RefCell,
+	collections::{BTreeMap, BTreeSet},
+	rc::Rc,
+};
 use xcm::latest::{prelude::*, Error as XcmError};
 use xcm_builder::{AllowUnpaidExecutionFrom, FixedWeightBounds};
 use xcm_executor::{
@@ -58,6 +67,7 @@ construct_runtime!(
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 		Xtokens: orml_xtokens::{Pallet, Call, Storage, Event<T>},
 		PolkadotXcm: pallet_xcm::{Pallet, Call, Config, Event<T>, Origin},
+		TokenRouteRegistry: pallet_token_route_registry::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -204,7 +214,11 @@ impl xcm_executor::Config for XcmConfig {
 pub type Precompiles<R> = PrecompileSetBuilder<
 	R,
 	(
-		PrecompileAt<AddressU64<1>, GmpPrecompile<R>, (SubcallWithMaxNesting<1>,)>,
+		PrecompileAt<
+			AddressU64<1>,
+			GmpPrecompile<R, pallet_token_route_registry::Pallet<R>>,
+			(SubcallWithMaxNesting<1>,),
+		>,
 		RevertPrecompile<AddressU64<2>>,
 	),
 >;
@@ -237,7 +251,7 @@ impl WeightTrader for DummyWeightTrader {
 	}
 }
 
-pub type PCall = GmpPrecompileCall<Runtime>;
+pub type PCall = GmpPrecompileCall<Runtime, TokenRouteRegistry>;
 
 mock_account!(Batch, |_| MockAccount::from_u64(1));
 mock_account!(Revert, |_| MockAccount::from_u64(2));
@@ -284,6 +298,13 @@ impl pallet_evm::Config for Runtime {
 	type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
 }
 
+impl pallet_token_route_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RouteManagerOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxHops = ConstU32<2>;
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	pub const MinimumPeriod: u64 = 5;
 }
@@ -446,3 +467,251 @@ impl ExtBuilder {
 		ext
 	}
 }
+
+/// Address of the mock Wormhole core contract.
+pub(crate) const WORMHOLE_CORE: H160 = H160([0xc0; 20]);
+/// Address of the mock Wormhole token bridge contract.
+pub(crate) const WORMHOLE_BRIDGE: H160 = H160([0xb0; 20]);
+
+/// Address of the XC-20 of a foreign asset.
+pub(crate) fn foreign_asset_address(asset_id: u128) -> H160 {
+	let mut address = [0xff; 20];
+	address[4..].copy_from_slice(&asset_id.to_be_bytes());
+	H160(address)
+}
+
+/// Mock of the Wormhole core and token bridge contracts, along with the ERC-20 of the token
+/// they bridge, answering the subcalls of the precompile.
+///
+/// VAAs are parsed as the contracts do, without verifying their signatures. Completing a
+/// transfer mints its amount of the wrapped token to the caller. Published messages are recorded
+/// along with their emitter, and sequenced per emitter.
+#[derive(Clone)]
+pub(crate) struct MockWormhole(Rc<RefCell<MockWormholeState>>);
+
+struct MockWormholeState {
+	wrapped_asset: H160,
+	invalid_signatures: bool,
+	balances: BTreeMap<H160, U256>,
+	completed: BTreeSet<H256>,
+	message_fee: U256,
+	published: Vec<PublishedMessage>,
+}
+
+/// Message published through the mock Wormhole core contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PublishedMessage {
+	pub emitter: H160,
+	pub nonce: u32,
+	pub payload: Vec<u8>,
+	pub consistency_level: u8,
+	pub sequence: u64,
+}
+
+impl MockWormhole {
+	/// Contracts bridging a token wrapped as `wrapped_asset` on this chain.
+	pub(crate) fn new(wrapped_asset: H160) -> Self {
+		Self(Rc::new(RefCell::new(MockWormholeState {
+			wrapped_asset,
+			invalid_signatures: false,
+			balances: BTreeMap::new(),
+			completed: BTreeSet::new(),
+			message_fee: U256::zero(),
+			published: Vec::new(),
+		})))
+	}
+
+	/// The core contract rejects the signatures of every VAA.
+	pub(crate) fn with_invalid_signatures(self) -> Self {
+		self.0.borrow_mut().invalid_signatures = true;
+		self
+	}
+
+	/// The core contract charges `fee` to publish a message.
+	pub(crate) fn with_message_fee(self, fee: U256) -> Self {
+		self.0.borrow_mut().message_fee = fee;
+		self
+	}
+
+	/// Messages published through the core contract, in order.
+	pub(crate) fn published(&self) -> Vec<PublishedMessage> {
+		self.0.borrow().published.clone()
+	}
+
+	/// Enable the precompile and make it call the mock contracts.
+	pub(crate) fn register(&self) {
+		crate::storage::PrecompileEnabled::set(Some(true));
+		crate::storage::CoreAddress::set(Some(WORMHOLE_CORE));
+		crate::storage::BridgeAddress::set(Some(WORMHOLE_BRIDGE));
+	}
+
+	/// Balance of the wrapped token held by `holder`.
+	pub(crate) fn balance_of(&self, holder: impl Into<H160>) -> U256 {
+		self.0
+			.borrow()
+			.balances
+			.get(&holder.into())
+			.copied()
+			.unwrap_or_default()
+	}
+
+	pub(crate) fn subcall_handle(&self) -> impl SubcallTrait {
+		let state = self.0.clone();
+		move |subcall| state.borrow_mut().call(subcall)
+	}
+}
+
+impl MockWormholeState {
+	fn call(&mut self, subcall: Subcall) -> SubcallOutput {
+		let Subcall {
+			address,
+			input,
+			context,
+			..
+		} = subcall;
+
+		let result = match solidity::codec::selector(&input) {
+			Some(PARSE_VM_SELECTOR) if address == WORMHOLE_CORE => self.parse_vm(&input[4..]),
+			Some(PARSE_TRANSFER_WITH_PAYLOAD_SELECTOR) if address == WORMHOLE_BRIDGE => {
+				Self::parse_transfer_with_payload(&input[4..])
+			}
+			Some(WRAPPED_ASSET_SELECTOR) if address == WORMHOLE_BRIDGE => {
+				Ok(solidity::encode_return_value(Address(self.wrapped_asset)))
+			}
+			Some(COMPLETE_TRANSFER_WITH_PAYLOAD_SELECTOR) if address == WORMHOLE_BRIDGE => {
+				self.complete_transfer_with_payload(context.caller, &input[4..])
+			}
+			Some(MESSAGE_FEE_SELECTOR) if address == WORMHOLE_CORE => {
+				Ok(solidity::encode_return_value(self.message_fee))
+			}
+			Some(PUBLISH_MESSAGE_SELECTOR) if address == WORMHOLE_CORE => {
+				self.publish_message(context.caller, context.apparent_value, &input[4..])
+			}
+			Some(BALANCE_OF_SELECTOR) if address == self.wrapped_asset => {
+				self.balance_of(&input[4..])
+			}
+			Some(TRANSFER_SELECTOR) if address == self.wrapped_asset => {
+				self.transfer(context.caller, &input[4..])
+			}
+			_ => Err("unexpected call"),
+		};
+
+		match result {
+			Ok(output) => SubcallOutput {
+				output,
+				..SubcallOutput::succeed()
+			},
+			Err(message) => SubcallOutput {
+				output: revert_as_bytes(message),
+				..SubcallOutput::revert()
+			},
+		}
+	}
+
+	fn parse_vm(&self, input: &[u8]) -> Result<Vec<u8>, &'static str> {
+		let encoded: UnboundedBytes =
+			solidity::decode_arguments(input).map_err(|_| "invalid input")?;
+		let vaa = WormholeVaa::parse(encoded.as_bytes()).map_err(|_| "invalid VAA")?;
+
+		if self.invalid_signatures {
+			return Err("VM signature invalid");
+		}
+
+		Ok(solidity::encode_return_value(WormholeVM {
+			version: vaa.version,
+			timestamp: vaa.timestamp,
+			nonce: vaa.nonce,
+			emitter_chain_id: vaa.emitter_chain_id,
+			emitter_address: vaa.emitter_address,
+			sequence: vaa.sequence,
+			consistency_level: vaa.consistency_level,
+			payload: vaa.payload.into(),
+			guardian_set_index: vaa.guardian_set_index,
+			hash: vaa.hash(),
+			signatures: vaa.signatures,
+		}))
+	}
+
+	fn parse_transfer_with_payload(input: &[u8]) -> Result<Vec<u8>, &'static str> {
+		let encoded: UnboundedBytes =
+			solidity::decode_arguments(input).map_err(|_| "invalid input")?;
+		let transfer = WormholeTransferWithPayloadData::parse(encoded.as_bytes())
+			.map_err(|_| "invalid transfer")?;
+
+		Ok(solidity::encode_return_value(transfer))
+	}
+
+	fn complete_transfer_with_payload(
+		&mut self,
+		caller: H160,
+		input: &[u8],
+	) -> Result<Vec<u8>, &'static str> {
+		let encoded: UnboundedBytes =
+			solidity::decode_arguments(input).map_err(|_| "invalid input")?;
+		let vaa = WormholeVaa::parse(encoded.as_bytes()).map_err(|_| "invalid VAA")?;
+		let transfer =
+			WormholeTransferWithPayloadData::parse(vaa.payload).map_err(|_| "invalid transfer")?;
+
+		if !self.completed.insert(vaa.hash()) {
+			return Err("transfer already completed");
+		}
+
+		let balance = self.balances.entry(caller).or_default();
+		*balance = balance.saturating_add(transfer.amount);
+
+		Ok(Vec::new())
+	}
+
+	fn publish_message(
+		&mut self,
+		emitter: H160,
+		value: U256,
+		input: &[u8],
+	) -> Result<Vec<u8>, &'static str> {
+		let (nonce, payload, consistency_level): (u32, UnboundedBytes, u8) =
+			solidity::decode_arguments(input).map_err(|_| "invalid input")?;
+
+		if value != self.message_fee {
+			return Err("invalid fee");
+		}
+
+		let sequence = self
+			.published
+			.iter()
+			.filter(|message| message.emitter == emitter)
+			.count() as u64;
+
+		self.published.push(PublishedMessage {
+			emitter,
+			nonce,
+			payload: payload.into(),
+			consistency_level,
+			sequence,
+		});
+
+		Ok(solidity::encode_return_value(sequence))
+	}
+
+	fn balance_of(&self, input: &[u8]) -> Result<Vec<u8>, &'static str> {
+		let holder: Address = solidity::decode_arguments(input).map_err(|_| "invalid input")?;
+
+		Ok(solidity::encode_return_value(
+			self.balances.get(&holder.0).copied().unwrap_or_default(),
+		))
+	}
+
+	fn transfer(&mut self, from: H160, input: &[u8]) -> Result<Vec<u8>, &'static str> {
+		let (to, amount): (Address, U256) =
+			solidity::decode_arguments(input).map_err(|_| "invalid input")?;
+
+		let from_balance = self.balances.entry(from).or_default();
+		*from_balance = from_balance
+			.checked_sub(amount)
+			.ok_or("transfer amount exceeds balance")?;
+
+		let to_balance = self.balances.entry(to.0).or_default();
+		*to_balance = to_balance.saturating_add(amount);
+
+		Ok(solidity::encode_return_value(true))
+	}
+}
@@ -14,9 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::mock::*;
+use crate::{mock::*, types::*};
 use fp_evm::{ExitRevert, PrecompileFailure};
-use precompile_utils::{solidity::revert::revert_as_bytes, testing::*};
+use pallet_token_route_registry::{AccountFormat, BridgeProtocol, Route, Routes};
+use parity_scale_codec::Encode;
+use precompile_utils::{prelude::*, solidity::revert::revert_as_bytes, testing::*};
+use sp_core::{H160, H256, U256};
+use xcm::{
+	latest::{Junction, Junctions, MultiLocation},
+	VersionedMultiLocation,
+};
 
 fn precompiles() -> Precompiles<Runtime> {
 	PrecompilesValue::get()
@@ -104,6 +111,618 @@ fn contract_disabling_works() {
 		})
 }
 
+fn encode_transfer_with_payload(payload_id: u8, amount: u64, payload: &[u8]) -> Vec<u8> {
+	encode_transfer_with_payload_amount(payload_id, amount.into(), payload)
+}
+
+fn encode_transfer_with_payload_amount(payload_id: u8, amount: U256, payload: &[u8]) -> Vec<u8> {
+	let mut encoded = vec![payload_id];
+	let mut amount_bytes = [0u8; 32];
+	amount.to_big_endian(&mut amount_bytes);
+	encoded.extend_from_slice(&amount_bytes);
+	encoded.extend_from_slice(&[0x11; 32]); // token_address
+	encoded.extend_from_slice(&2u16.to_be_bytes()); // token_chain
+	encoded.extend_from_slice(&[0x22; 32]); // to
+	encoded.extend_from_slice(&16u16.to_be_bytes()); // to_chain
+	encoded.extend_from_slice(&[0x33; 32]); // from_address
+	encoded.extend_from_slice(payload);
+	encoded
+}
+
+fn encode_vaa(version: u8, signatures: u8, payload: &[u8]) -> Vec<u8> {
+	let mut vaa = vec![version];
+	vaa.extend_from_slice(&3u32.to_be_bytes()); // guardian_set_index
+	vaa.push(signatures);
+	for guardian_index in 0..signatures {
+		vaa.push(guardian_index);
+		vaa.extend_from_slice(&[0xaa; 32]); // r
+		vaa.extend_from_slice(&[0xbb; 32]); // s
+		vaa.push(1); // v
+	}
+	vaa.extend_from_slice(&1_700_000_000u32.to_be_bytes()); // timestamp
+	vaa.extend_from_slice(&42u32.to_be_bytes()); // nonce
+	vaa.extend_from_slice(&2u16.to_be_bytes()); // emitter_chain_id
+	vaa.extend_from_slice(&[0x44; 32]); // emitter_address
+	vaa.extend_from_slice(&7u64.to_be_bytes()); // sequence
+	vaa.push(15); // consistency_level
+	vaa.extend_from_slice(payload);
+	vaa
+}
+
+#[test]
+fn vaa_parsing_works() {
+	let transfer = encode_transfer_with_payload(TRANSFER_WITH_PAYLOAD_ID, 100, b"user action");
+	let encoded = encode_vaa(VAA_VERSION, 2, &transfer);
+
+	let vaa = WormholeVaa::parse(&encoded).expect("valid VAA");
+	assert_eq!(vaa.version, VAA_VERSION);
+	assert_eq!(vaa.guardian_set_index, 3);
+	assert_eq!(vaa.signatures.len(), 2);
+	assert_eq!(
+		vaa.signatures[1],
+		WormholeSignature {
+			r: U256::from_big_endian(&[0xaa; 32]),
+			s: U256::from_big_endian(&[0xbb; 32]),
+			v: 28,
+			guardian_index: 1,
+		}
+	);
+	assert_eq!(vaa.timestamp, 1_700_000_000);
+	assert_eq!(vaa.nonce, 42);
+	assert_eq!(vaa.emitter_chain_id, 2);
+	assert_eq!(vaa.emitter_address, H256::repeat_byte(0x44));
+	assert_eq!(vaa.sequence, 7);
+	assert_eq!(vaa.consistency_level, 15);
+	assert_eq!(vaa.payload, &transfer[..]);
+
+	// the body starts right after the signatures
+	let body = &encoded[6 + 2 * 66..];
+	assert_eq!(vaa.body, body);
+	assert_eq!(
+		vaa.hash(),
+		H256(sp_io::hashing::keccak_256(&sp_io::hashing::keccak_256(
+			body
+		)))
+	);
+
+	assert_eq!(
+		WormholeTransferWithPayloadData::parse(vaa.payload),
+		Ok(WormholeTransferWithPayloadData {
+			payload_id: TRANSFER_WITH_PAYLOAD_ID,
+			amount: U256::from(100u64),
+			token_address: H256::repeat_byte(0x11),
+			token_chain: 2,
+			to: H256::repeat_byte(0x22),
+			to_chain: 16,
+			from_address: H256::repeat_byte(0x33),
+			payload: b"user action".to_vec().into(),
+		})
+	);
+}
+
+#[test]
+fn vaa_parsing_rejects_unsupported_version() {
+	let encoded = encode_vaa(2, 1, &[]);
+
+	assert_eq!(
+		WormholeVaa::parse(&encoded),
+		Err(RevertReason::custom("unsupported VAA version").in_field("version"))
+	);
+}
+
+#[test]
+fn vaa_parsing_rejects_missing_signatures() {
+	let mut encoded = encode_vaa(VAA_VERSION, 0, &[]);
+	// announce more signatures than the VAA contains
+	encoded[5] = 200;
+
+	assert_eq!(
+		WormholeVaa::parse(&encoded),
+		Err(RevertReason::read_out_of_bounds("signatures").into())
+	);
+}
+
+#[test]
+fn vaa_parsing_rejects_truncated_body() {
+	let encoded = encode_vaa(VAA_VERSION, 1, &[]);
+
+	assert_eq!(
+		WormholeVaa::parse(&encoded[..encoded.len() - 1]),
+		Err(RevertReason::read_out_of_bounds("consistency_level").into())
+	);
+	assert_eq!(
+		WormholeVaa::parse(&[]),
+		Err(RevertReason::read_out_of_bounds("version").into())
+	);
+}
+
+#[test]
+fn transfer_with_payload_parsing_rejects_malformed_payload() {
+	let transfer = encode_transfer_with_payload(1, 100, &[]);
+	assert_eq!(
+		WormholeTransferWithPayloadData::parse(&transfer),
+		Err(RevertReason::custom("not a transfer with payload").in_field("payload_id"))
+	);
+
+	let transfer = encode_transfer_with_payload(TRANSFER_WITH_PAYLOAD_ID, 100, &[]);
+	assert_eq!(
+		WormholeTransferWithPayloadData::parse(&transfer[..90]),
+		Err(RevertReason::read_out_of_bounds("to").into())
+	);
+}
+
+#[test]
+fn malformed_vaa_reverts_before_calling_wormhole() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 100_000)])
+		.build()
+		.execute_with(|| {
+			crate::storage::PrecompileEnabled::set(Some(true));
+			// no contract is deployed at these addresses
+			crate::storage::CoreAddress::set(Some(H160::repeat_byte(0x01)));
+			crate::storage::BridgeAddress::set(Some(H160::repeat_byte(0x02)));
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					Precompile1,
+					PCall::wormhole_transfer_erc20 {
+						wormhole_vaa: encode_vaa(2, 0, &[]).into(),
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"wormholeVaa.version: unsupported VAA version"
+				});
+
+			let transfer = encode_transfer_with_payload(1, 100, &[]);
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					Precompile1,
+					PCall::wormhole_transfer_erc20 {
+						wormhole_vaa: encode_vaa(VAA_VERSION, 1, &transfer).into(),
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"wormholeVaa.payload.payload_id: not a transfer with payload"
+				});
+		})
+}
+
+fn destination() -> VersionedMultiLocation {
+	VersionedMultiLocation::V3(MultiLocation::new(
+		1,
+		Junctions::X2(
+			Junction::Parachain(2),
+			Junction::AccountId32 {
+				network: None,
+				id: [0x55; 32],
+			},
+		),
+	))
+}
+
+fn v1_action() -> Vec<u8> {
+	VersionedUserAction::V1(XcmRoutingUserAction {
+		destination: destination(),
+	})
+	.encode()
+}
+
+fn wormhole_transfer(amount: U256, action: &[u8]) -> PCall {
+	let transfer = encode_transfer_with_payload_amount(TRANSFER_WITH_PAYLOAD_ID, amount, action);
+	PCall::wormhole_transfer_erc20 {
+		wormhole_vaa: encode_vaa(VAA_VERSION, 1, &transfer).into(),
+	}
+}
+
+fn xtokens_transferred() -> bool {
+	System::events().iter().any(|record| {
+		matches!(
+			record.event,
+			RuntimeEvent::Xtokens(orml_xtokens::Event::TransferredMultiAssets { .. })
+		)
+	})
+}
+
+#[test]
+fn wormhole_transfer_dispatches_xtokens_transfer() {
+	ExtBuilder::default().build().execute_with(|| {
+		let wormhole = MockWormhole::new(foreign_asset_address(1));
+		wormhole.register();
+
+		precompiles()
+			.prepare_test(
+				CryptoAlith,
+				Precompile1,
+				wormhole_transfer(100.into(), &v1_action()),
+			)
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_returns(());
+
+		assert_eq!(wormhole.balance_of(Precompile1), 100.into());
+		assert!(xtokens_transferred());
+	})
+}
+
+#[test]
+fn wormhole_transfer_pays_fee_to_caller() {
+	ExtBuilder::default().build().execute_with(|| {
+		let wormhole = MockWormhole::new(foreign_asset_address(1));
+		wormhole.register();
+
+		let action = VersionedUserAction::V2(XcmRoutingUserActionWithFee {
+			destination: destination(),
+			fee: 10.into(),
+		})
+		.encode();
+
+		precompiles()
+			.prepare_test(
+				CryptoAlith,
+				Precompile1,
+				wormhole_transfer(100.into(), &action),
+			)
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_returns(());
+
+		assert_eq!(wormhole.balance_of(CryptoAlith), 10.into());
+		assert_eq!(wormhole.balance_of(Precompile1), 90.into());
+		assert!(xtokens_transferred());
+	})
+}
+
+const DEST_CHAIN: H256 = H256([0x01; 32]);
+
+fn set_route(bridge_in: BridgeProtocol) {
+	Routes::<Runtime>::insert(
+		foreign_asset_address(1),
+		DEST_CHAIN,
+		Route {
+			bridge_in,
+			hops: Default::default(),
+			destination: MultiLocation::new(1, Junctions::X1(Junction::Parachain(2))),
+			account_format: AccountFormat::AccountKey20,
+		},
+	);
+}
+
+fn route_action(fee: U256) -> Vec<u8> {
+	VersionedUserAction::V3(XcmRoutingUserActionByRoute {
+		dest_chain: DEST_CHAIN,
+		beneficiary: H160::repeat_byte(0x55).into(),
+		fee,
+	})
+	.encode()
+}
+
+fn xtokens_destination() -> Option<MultiLocation> {
+	System::events()
+		.into_iter()
+		.find_map(|record| match record.event {
+			RuntimeEvent::Xtokens(orml_xtokens::Event::TransferredMultiAssets { dest, .. }) => {
+				Some(dest)
+			}
+			_ => None,
+		})
+}
+
+#[test]
+fn wormhole_transfer_follows_the_route_of_the_token() {
+	ExtBuilder::default().build().execute_with(|| {
+		let wormhole = MockWormhole::new(foreign_asset_address(1));
+		wormhole.register();
+		set_route(BridgeProtocol::Wormhole);
+
+		precompiles()
+			.prepare_test(
+				CryptoAlith,
+				Precompile1,
+				wormhole_transfer(100.into(), &route_action(10.into())),
+			)
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_returns(());
+
+		assert_eq!(wormhole.balance_of(CryptoAlith), 10.into());
+		assert_eq!(
+			xtokens_destination(),
+			Some(MultiLocation::new(
+				1,
+				Junctions::X2(
+					Junction::Parachain(2),
+					Junction::AccountKey20 {
+						network: None,
+						key: [0x55; 20],
+					},
+				),
+			))
+		);
+	})
+}
+
+#[test]
+fn wormhole_transfer_reverts_without_route_of_the_token() {
+	ExtBuilder::default().build().execute_with(|| {
+		let wormhole = MockWormhole::new(foreign_asset_address(1));
+		wormhole.register();
+
+		precompiles()
+			.prepare_test(
+				CryptoAlith,
+				Precompile1,
+				wormhole_transfer(100.into(), &route_action(U256::zero())),
+			)
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_reverts(|output| output == b"no route for the token to the destination chain");
+
+		assert!(!xtokens_transferred());
+	})
+}
+
+#[test]
+fn wormhole_transfer_reverts_if_route_does_not_bridge_through_wormhole() {
+	ExtBuilder::default().build().execute_with(|| {
+		let wormhole = MockWormhole::new(foreign_asset_address(1));
+		wormhole.register();
+		set_route(BridgeProtocol::Xcm);
+
+		precompiles()
+			.prepare_test(
+				CryptoAlith,
+				Precompile1,
+				wormhole_transfer(100.into(), &route_action(U256::zero())),
+			)
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_reverts(|output| {
+				output == b"route does not bridge the token through wormhole"
+			});
+
+		assert!(!xtokens_transferred());
+	})
+}
+
+#[test]
+fn wormhole_transfer_reverts_if_core_rejects_vaa() {
+	ExtBuilder::default().build().execute_with(|| {
+		let wormhole = MockWormhole::new(foreign_asset_address(1)).with_invalid_signatures();
+		wormhole.register();
+
+		precompiles()
+			.prepare_test(
+				CryptoAlith,
+				Precompile1,
+				wormhole_transfer(100.into(), &v1_action()),
+			)
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_reverts(|output| output == b"VM signature invalid");
+
+		assert_eq!(wormhole.balance_of(Precompile1), U256::zero());
+	})
+}
+
+#[test]
+fn wormhole_transfer_reverts_on_invalid_user_action() {
+	ExtBuilder::default().build().execute_with(|| {
+		let wormhole = MockWormhole::new(foreign_asset_address(1));
+		wormhole.register();
+
+		precompiles()
+			.prepare_test(
+				CryptoAlith,
+				Precompile1,
+				wormhole_transfer(100.into(), b"not a user action"),
+			)
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_reverts(|output| output == b"Invalid GMP Payload");
+	})
+}
+
+#[test]
+fn wormhole_transfer_reverts_on_unsupported_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		let wormhole = MockWormhole::new(H160::repeat_byte(0x99));
+		wormhole.register();
+
+		precompiles()
+			.prepare_test(
+				CryptoAlith,
+				Precompile1,
+				wormhole_transfer(100.into(), &v1_action()),
+			)
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_reverts(|output| output == b"Unsupported asset, not a valid currency id");
+
+		assert!(!xtokens_transferred());
+	})
+}
+
+#[test]
+fn wormhole_transfer_reverts_on_amount_overflow() {
+	ExtBuilder::default().build().execute_with(|| {
+		let wormhole = MockWormhole::new(foreign_asset_address(1));
+		wormhole.register();
+
+		precompiles()
+			.prepare_test(
+				CryptoAlith,
+				Precompile1,
+				wormhole_transfer(U256::from(u128::MAX) + 1, &v1_action()),
+			)
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_reverts(|output| output == b"Amount overflows balance");
+
+		assert!(!xtokens_transferred());
+	})
+}
+
+#[test]
+fn wormhole_transfer_reverts_if_already_completed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let wormhole = MockWormhole::new(foreign_asset_address(1));
+		wormhole.register();
+
+		precompiles()
+			.prepare_test(
+				CryptoAlith,
+				Precompile1,
+				wormhole_transfer(100.into(), &v1_action()),
+			)
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				CryptoAlith,
+				Precompile1,
+				wormhole_transfer(100.into(), &v1_action()),
+			)
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_reverts(|output| output == b"transfer already completed");
+	})
+}
+
+fn wormhole_publish(payload: &[u8], nonce: u32) -> PCall {
+	PCall::wormhole_publish_with_payload {
+		payload: payload.to_vec().into(),
+		nonce,
+	}
+}
+
+#[test]
+fn wormhole_publish_publishes_message_from_sender_emitter() {
+	ExtBuilder::default().build().execute_with(|| {
+		let wormhole = MockWormhole::new(foreign_asset_address(1));
+		wormhole.register();
+
+		precompiles()
+			.prepare_test(CryptoAlith, Precompile1, wormhole_publish(b"hello", 7))
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_returns(0u64);
+
+		precompiles()
+			.prepare_test(CryptoAlith, Precompile1, wormhole_publish(b"world", 8))
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_returns(1u64);
+
+		let emitter = crate::wormhole_emitter(CryptoAlith.into());
+		assert_eq!(
+			wormhole.published(),
+			vec![
+				PublishedMessage {
+					emitter,
+					nonce: 7,
+					payload: b"hello".to_vec(),
+					consistency_level: 1,
+					sequence: 0,
+				},
+				PublishedMessage {
+					emitter,
+					nonce: 8,
+					payload: b"world".to_vec(),
+					consistency_level: 1,
+					sequence: 1,
+				},
+			]
+		);
+	})
+}
+
+#[test]
+fn wormhole_publish_uses_an_emitter_per_sender() {
+	ExtBuilder::default().build().execute_with(|| {
+		let wormhole = MockWormhole::new(foreign_asset_address(1));
+		wormhole.register();
+
+		precompiles()
+			.prepare_test(CryptoAlith, Precompile1, wormhole_publish(b"hello", 0))
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_returns(0u64);
+
+		precompiles()
+			.prepare_test(CryptoBaltathar, Precompile1, wormhole_publish(b"hello", 0))
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_returns(0u64);
+
+		let emitters: Vec<H160> = wormhole
+			.published()
+			.into_iter()
+			.map(|message| message.emitter)
+			.collect();
+		assert_eq!(
+			emitters,
+			vec![
+				crate::wormhole_emitter(CryptoAlith.into()),
+				crate::wormhole_emitter(CryptoBaltathar.into()),
+			]
+		);
+		assert_ne!(emitters[0], emitters[1]);
+
+		precompiles()
+			.prepare_test(
+				CryptoAlith,
+				Precompile1,
+				PCall::wormhole_emitter_of {
+					sender: Address(CryptoBaltathar.into()),
+				},
+			)
+			.execute_returns(Address(emitters[1]));
+	})
+}
+
+#[test]
+fn wormhole_publish_forwards_message_fee() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let wormhole = MockWormhole::new(foreign_asset_address(1)).with_message_fee(10.into());
+			wormhole.register();
+
+			precompiles()
+				.prepare_test(CryptoAlith, Precompile1, wormhole_publish(b"hello", 0))
+				.with_value(10)
+				.with_subcall_handle(wormhole.subcall_handle())
+				.execute_returns(0u64);
+
+			assert_eq!(wormhole.published().len(), 1);
+		})
+}
+
+#[test]
+fn wormhole_publish_reverts_if_fee_does_not_match() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let wormhole = MockWormhole::new(foreign_asset_address(1)).with_message_fee(10.into());
+			wormhole.register();
+
+			precompiles()
+				.prepare_test(CryptoAlith, Precompile1, wormhole_publish(b"hello", 0))
+				.with_value(5)
+				.with_subcall_handle(wormhole.subcall_handle())
+				.execute_reverts(|output| output == b"value must match the wormhole message fee");
+
+			assert!(wormhole.published().is_empty());
+		})
+}
+
+#[test]
+fn wormhole_publish_reverts_if_disabled() {
+	ExtBuilder::default().build().execute_with(|| {
+		let wormhole = MockWormhole::new(foreign_asset_address(1));
+		wormhole.register();
+		crate::storage::PrecompileEnabled::set(Some(false));
+
+		precompiles()
+			.prepare_test(CryptoAlith, Precompile1, wormhole_publish(b"hello", 0))
+			.with_subcall_handle(wormhole.subcall_handle())
+			.execute_reverts(|output| output == b"GMP Precompile is not enabled");
+
+		assert!(wormhole.published().is_empty());
+	})
+}
+
 #[test]
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
 	check_precompile_implements_solidity_interfaces(&["Gmp.sol"], PCall::supports_selector)
@@ -22,6 +22,14 @@ use sp_core::{H256, U256};
 use sp_std::vec::Vec;
 use xcm::VersionedMultiLocation;
 
+/// Only VAA version supported by the Wormhole contracts.
+pub const VAA_VERSION: u8 = 1;
+/// Token bridge payload id of a "TransferWithPayload".
+pub const TRANSFER_WITH_PAYLOAD_ID: u8 = 3;
+
+/// Size of a guardian signature in a VAA: guardian index, r, s and v.
+const SIGNATURE_SIZE: usize = 1 + 32 + 32 + 1;
+
 // A user action which will attempt to route the transferred assets to the account/chain specified
 // by the given MultiLocation. Recall that a MultiLocation can contain both a chain and an account
 // on that chain, as this one should.
@@ -38,6 +46,17 @@ pub struct XcmRoutingUserActionWithFee {
 	pub fee: U256,
 }
 
+// A user action which routes the transferred assets along the canonical route of the token to the
+// given destination chain, as recorded in the token route registry. The beneficiary is encoded
+// like the Wormhole addresses: 32 bytes accounts are used as is, and 20 bytes accounts are taken
+// from the last 20 bytes. A fee can be paid as with XcmRoutingUserActionWithFee.
+#[derive(Encode, Decode, Debug)]
+pub struct XcmRoutingUserActionByRoute {
+	pub dest_chain: H256,
+	pub beneficiary: H256,
+	pub fee: U256,
+}
+
 // A simple versioning wrapper around the initial XcmRoutingUserAction use-case. This should make
 // future breaking changes easy to add in a backwards-compatible way.
 #[derive(Encode, Decode, Debug)]
@@ -45,6 +64,7 @@ pub struct XcmRoutingUserActionWithFee {
 pub enum VersionedUserAction {
 	V1(XcmRoutingUserAction),
 	V2(XcmRoutingUserActionWithFee),
+	V3(XcmRoutingUserActionByRoute),
 }
 
 // Struct representing a Wormhole VM
@@ -69,7 +89,7 @@ pub struct WormholeVM {
 }
 
 // Struct representing a Wormhole Signature struct
-#[derive(Debug, solidity::Codec)]
+#[derive(Debug, PartialEq, Eq, solidity::Codec)]
 pub struct WormholeSignature {
 	pub r: U256,
 	pub s: U256,
@@ -80,7 +100,7 @@ pub struct WormholeSignature {
 // Struct representing a wormhole "BridgeStructs.TransferWithPayload" struct
 // As with WormholeVM, the main purpose of this struct is to decode the ABI encoded struct when it
 // returned from calls to Wormhole Ethereum contracts.
-#[derive(Debug, solidity::Codec)]
+#[derive(Debug, PartialEq, Eq, solidity::Codec)]
 pub struct WormholeTransferWithPayloadData {
 	pub payload_id: u8,
 	pub amount: U256,
@@ -91,3 +111,164 @@ pub struct WormholeTransferWithPayloadData {
 	pub from_address: H256,
 	pub payload: BoundedBytes<crate::GetCallDataLimit>,
 }
+
+// A VAA as laid out on the wire, parsed without calling into the Wormhole contracts. This only
+// checks the structure of the VAA (version, signatures framing and body size); the signatures
+// themselves are only verified by the core contract.
+//
+// https://docs.wormhole.com/wormhole/explore-wormhole/vaa#vaa-format
+#[derive(Debug, PartialEq, Eq)]
+pub struct WormholeVaa<'a> {
+	pub version: u8,
+	pub guardian_set_index: u32,
+	pub signatures: Vec<WormholeSignature>,
+	pub timestamp: u32,
+	pub nonce: u32,
+	pub emitter_chain_id: u16,
+	pub emitter_address: H256,
+	pub sequence: u64,
+	pub consistency_level: u8,
+	// The signed part of the VAA, from the timestamp to the end of the payload.
+	pub body: &'a [u8],
+	pub payload: &'a [u8],
+}
+
+impl<'a> WormholeVaa<'a> {
+	pub fn parse(input: &'a [u8]) -> MayRevert<Self> {
+		let mut reader = VaaReader::new(input);
+
+		let version = reader.read_u8("version")?;
+		if version != VAA_VERSION {
+			return Err(RevertReason::custom("unsupported VAA version").in_field("version"));
+		}
+
+		let guardian_set_index = reader.read_u32("guardian_set_index")?;
+		let signatures_len = usize::from(reader.read_u8("signatures_len")?);
+
+		// make sure all the signatures are there before allocating anything
+		reader.ensure_remaining(signatures_len * SIGNATURE_SIZE, "signatures")?;
+		let mut signatures = Vec::with_capacity(signatures_len);
+		for _ in 0..signatures_len {
+			let guardian_index = reader.read_u8("guardian_index")?;
+			let r = reader.read_u256("r")?;
+			let s = reader.read_u256("s")?;
+			// the contracts expect an Ethereum recovery id
+			let v = reader.read_u8("v")?.saturating_add(27);
+			signatures.push(WormholeSignature {
+				r,
+				s,
+				v,
+				guardian_index,
+			});
+		}
+
+		let body = reader.remaining();
+		let timestamp = reader.read_u32("timestamp")?;
+		let nonce = reader.read_u32("nonce")?;
+		let emitter_chain_id = reader.read_u16("emitter_chain_id")?;
+		let emitter_address = reader.read_h256("emitter_address")?;
+		let sequence = reader.read_u64("sequence")?;
+		let consistency_level = reader.read_u8("consistency_level")?;
+		let payload = reader.remaining();
+
+		Ok(Self {
+			version,
+			guardian_set_index,
+			signatures,
+			timestamp,
+			nonce,
+			emitter_chain_id,
+			emitter_address,
+			sequence,
+			consistency_level,
+			body,
+			payload,
+		})
+	}
+
+	/// Digest signed by the guardians, as computed by the core contract.
+	pub fn hash(&self) -> H256 {
+		let body_hash = sp_io::hashing::keccak_256(self.body);
+		sp_io::hashing::keccak_256(&body_hash).into()
+	}
+}
+
+impl WormholeTransferWithPayloadData {
+	/// Parse a token bridge "TransferWithPayload" from the payload of a VAA, as
+	/// `parseTransferWithPayload` does.
+	pub fn parse(input: &[u8]) -> MayRevert<Self> {
+		let mut reader = VaaReader::new(input);
+
+		let payload_id = reader.read_u8("payload_id")?;
+		if payload_id != TRANSFER_WITH_PAYLOAD_ID {
+			return Err(RevertReason::custom("not a transfer with payload").in_field("payload_id"));
+		}
+
+		Ok(Self {
+			payload_id,
+			amount: reader.read_u256("amount")?,
+			token_address: reader.read_h256("token_address")?,
+			token_chain: reader.read_u16("token_chain")?,
+			to: reader.read_h256("to")?,
+			to_chain: reader.read_u16("to_chain")?,
+			from_address: reader.read_h256("from_address")?,
+			payload: reader.remaining().into(),
+		})
+	}
+}
+
+// Big-endian reader over the packed encoding used by Wormhole.
+struct VaaReader<'a> {
+	input: &'a [u8],
+}
+
+impl<'a> VaaReader<'a> {
+	fn new(input: &'a [u8]) -> Self {
+		Self { input }
+	}
+
+	fn ensure_remaining(&self, len: usize, what: &str) -> MayRevert {
+		if self.input.len() < len {
+			return Err(RevertReason::read_out_of_bounds(what).into());
+		}
+		Ok(())
+	}
+
+	fn read<const N: usize>(&mut self, what: &str) -> MayRevert<[u8; N]> {
+		self.ensure_remaining(N, what)?;
+		let (bytes, rest) = self.input.split_at(N);
+		self.input = rest;
+
+		let mut array = [0u8; N];
+		array.copy_from_slice(bytes);
+		Ok(array)
+	}
+
+	fn read_u8(&mut self, what: &str) -> MayRevert<u8> {
+		Ok(self.read::<1>(what)?[0])
+	}
+
+	fn read_u16(&mut self, what: &str) -> MayRevert<u16> {
+		Ok(u16::from_be_bytes(self.read(what)?))
+	}
+
+	fn read_u32(&mut self, what: &str) -> MayRevert<u32> {
+		Ok(u32::from_be_bytes(self.read(what)?))
+	}
+
+	fn read_u64(&mut self, what: &str) -> MayRevert<u64> {
+		Ok(u64::from_be_bytes(self.read(what)?))
+	}
+
+	fn read_u256(&mut self, what: &str) -> MayRevert<U256> {
+		Ok(U256::from_big_endian(&self.read::<32>(what)?))
+	}
+
+	fn read_h256(&mut self, what: &str) -> MayRevert<H256> {
+		Ok(H256(self.read(what)?))
+	}
+
+	fn remaining(&self) -> &'a [u8] {
+		self.input
+	}
+}
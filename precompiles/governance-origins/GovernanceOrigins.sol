@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The GovernanceOrigins contract's address.
+address constant GOVERNANCE_ORIGINS_ADDRESS = 0x0000000000000000000000000000000000000826;
+
+/// @dev The GovernanceOrigins contract's instance.
+GovernanceOrigins constant GOVERNANCE_ORIGINS_CONTRACT = GovernanceOrigins(
+    GOVERNANCE_ORIGINS_ADDRESS
+);
+
+/// @author The Moonbeam Team
+/// @title Governance origins interface
+/// @dev The interface through which solidity contracts check whether a caller is the EVM address
+/// a governance origin dispatches as. The origins are 0 for Root, 1 for GeneralAdmin and 2 for
+/// Treasurer.
+/// @custom:address 0x0000000000000000000000000000000000000826
+interface GovernanceOrigins {
+    /// @dev Whether an address is the address of a governance origin
+    /// @custom:selector c2168f84
+    /// @param account The address to check
+    /// @return Whether the address is the address of a governance origin
+    function isGovernanceOrigin(address account) external view returns (bool);
+
+    /// @dev The governance origin of an address, reverting if it is not one
+    /// @custom:selector bb060f14
+    /// @param account The address of the governance origin
+    /// @return The governance origin
+    function governanceOriginOf(address account) external view returns (uint8);
+
+    /// @dev The address of a governance origin, or the zero address if it has none
+    /// @custom:selector 0ebee51a
+    /// @param origin The governance origin
+    /// @return The address of the governance origin
+    function governanceOriginAddress(uint8 origin) external view returns (address);
+}
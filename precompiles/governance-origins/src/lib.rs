@@ -0,0 +1,85 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile exposing the EVM addresses of the governance origins registered in
+//! pallet-governance-origin-registry.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use pallet_governance_origin_registry::GovernanceOrigin;
+use precompile_utils::prelude::*;
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// A precompile to query the EVM addresses of the governance origins.
+pub struct GovernanceOriginsPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> GovernanceOriginsPrecompile<Runtime>
+where
+	Runtime: pallet_governance_origin_registry::Config + pallet_evm::Config,
+{
+	#[precompile::public("isGovernanceOrigin(address)")]
+	#[precompile::view]
+	fn is_governance_origin(
+		handle: &mut impl PrecompileHandle,
+		account: Address,
+	) -> EvmResult<bool> {
+		// Storage item: AddressOrigins: Blake2_128(16) + H160(20) + GovernanceOrigin(1)
+		handle.record_db_read::<Runtime>(37)?;
+
+		Ok(
+			pallet_governance_origin_registry::Pallet::<Runtime>::is_governance_origin(
+				account.into(),
+			),
+		)
+	}
+
+	#[precompile::public("governanceOriginOf(address)")]
+	#[precompile::view]
+	fn governance_origin_of(handle: &mut impl PrecompileHandle, account: Address) -> EvmResult<u8> {
+		// Storage item: AddressOrigins: Blake2_128(16) + H160(20) + GovernanceOrigin(1)
+		handle.record_db_read::<Runtime>(37)?;
+
+		let origin = pallet_governance_origin_registry::Pallet::<Runtime>::origin_of(account.0)
+			.ok_or_else(|| revert("Not a governance origin"))?;
+
+		Ok(origin as u8)
+	}
+
+	#[precompile::public("governanceOriginAddress(uint8)")]
+	#[precompile::view]
+	fn governance_origin_address(
+		handle: &mut impl PrecompileHandle,
+		origin: u8,
+	) -> EvmResult<Address> {
+		let origin = GovernanceOrigin::try_from(origin)
+			.map_err(|_| RevertReason::custom("Unknown governance origin").in_field("origin"))?;
+
+		// Storage item: OriginAddresses: Twox64(8) + GovernanceOrigin(1) + H160(20)
+		handle.record_db_read::<Runtime>(29)?;
+
+		let address = pallet_governance_origin_registry::Pallet::<Runtime>::address_of(origin)
+			.unwrap_or_default();
+
+		Ok(Address(address))
+	}
+}
@@ -0,0 +1,149 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use frame_support::assert_ok;
+use pallet_governance_origin_registry::GovernanceOrigin;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::H160;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn register(origin: GovernanceOrigin, address: impl Into<H160>) {
+	assert_ok!(GovernanceOriginRegistry::set_origin_address(
+		RuntimeOrigin::root(),
+		origin,
+		Some(address.into())
+	));
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["GovernanceOrigins.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::is_governance_origin_selectors().contains(&0xc2168f84));
+	assert!(PCall::governance_origin_of_selectors().contains(&0xbb060f14));
+	assert!(PCall::governance_origin_address_selectors().contains(&0x0ebee51a));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_view_modifier(PCall::is_governance_origin_selectors());
+		tester.test_view_modifier(PCall::governance_origin_of_selectors());
+		tester.test_view_modifier(PCall::governance_origin_address_selectors());
+	});
+}
+
+#[test]
+fn registered_address_is_governance_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		register(GovernanceOrigin::Treasurer, Bob);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::is_governance_origin {
+					account: Address(Bob.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::governance_origin_of {
+					account: Address(Bob.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(GovernanceOrigin::Treasurer as u8);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::governance_origin_address {
+					origin: GovernanceOrigin::Treasurer as u8,
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(Address(Bob.into()));
+	});
+}
+
+#[test]
+fn unregistered_address_is_not_governance_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::is_governance_origin {
+					account: Address(Bob.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::governance_origin_of {
+					account: Address(Bob.into()),
+				},
+			)
+			.execute_reverts(|output| output == b"Not a governance origin");
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::governance_origin_address {
+					origin: GovernanceOrigin::Root as u8,
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(Address(H160::zero()));
+	});
+}
+
+#[test]
+fn unknown_governance_origin_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::governance_origin_address { origin: 3 },
+			)
+			.execute_reverts(|output| output == b"origin: Unknown governance origin");
+	});
+}
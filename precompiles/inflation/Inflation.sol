@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Inflation contract's address.
+address constant INFLATION_ADDRESS = 0x0000000000000000000000000000000000000820;
+
+/// @dev The Inflation contract's instance.
+Inflation constant INFLATION_CONTRACT = Inflation(INFLATION_ADDRESS);
+
+/// @author The Moonbeam Team
+/// @title Parachain Staking Inflation Interface
+/// @title The interface through which the inflation configuration of parachain staking can be
+/// read, and updated by governance
+/// @custom:address 0x0000000000000000000000000000000000000820
+interface Inflation {
+    /// @dev A range of balances
+    struct BalanceRange {
+        uint256 min;
+        uint256 ideal;
+        uint256 max;
+    }
+
+    /// @dev A range of rates, in parts per billion
+    struct PerbillRange {
+        uint32 min;
+        uint32 ideal;
+        uint32 max;
+    }
+
+    /// @dev The inflation configuration
+    struct InflationConfig {
+        /// Staking expectations
+        BalanceRange expect;
+        /// Annual inflation rate
+        PerbillRange annual;
+        /// Round inflation rate, derived from the annual one
+        PerbillRange round;
+    }
+
+    /// @dev An account receiving a share of the round inflation
+    struct InflationDistributionAccount {
+        /// The account receiving the share
+        address account;
+        /// The percent of the round inflation it receives
+        uint8 percent;
+    }
+
+    /// @dev Get the inflation configuration
+    /// @custom:selector a00b1f10
+    /// @return The inflation configuration
+    function inflationConfig() external view returns (InflationConfig memory);
+
+    /// @dev Get the parachain bond configuration, which is the first inflation distribution
+    /// account
+    /// @custom:selector 8e92274e
+    /// @return account The account receiving the parachain bond reserve
+    /// @return percent The percent of inflation set aside for the parachain bond
+    function parachainBondInfo()
+        external
+        view
+        returns (address account, uint8 percent);
+
+    /// @dev Get the accounts receiving a share of the round inflation before it is paid to the
+    /// stakers, the first one being the parachain bond
+    /// @custom:selector 73be324c
+    /// @return The inflation distribution accounts
+    function inflationDistributionConfig()
+        external
+        view
+        returns (InflationDistributionAccount[] memory);
+
+    /// @dev Set the annual inflation rate. Only callable from the governance address
+    /// 0x7374616b696e672f676f7665726e616e63650000, which only the root origin can use as source
+    /// of an EVM call
+    /// @custom:selector 780eadf3
+    /// @param min The minimum annual inflation rate, in parts per billion
+    /// @param ideal The ideal annual inflation rate, in parts per billion
+    /// @param max The maximum annual inflation rate, in parts per billion
+    function setInflation(
+        uint32 min,
+        uint32 ideal,
+        uint32 max
+    ) external;
+
+    /// @dev Set the account receiving the parachain bond reserve. Only callable from the
+    /// governance address 0x7374616b696e672f676f7665726e616e63650000
+    /// @custom:selector eb7fb052
+    /// @param account The new parachain bond account
+    function setParachainBondAccount(address account) external;
+
+    /// @dev Set the percent of inflation set aside for the parachain bond. Only callable from the
+    /// governance address 0x7374616b696e672f676f7665726e616e63650000
+    /// @custom:selector 3afccf5b
+    /// @param percent The new percent, at most 100
+    function setParachainBondReservePercent(uint8 percent) external;
+}
@@ -0,0 +1,224 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile exposing the inflation configuration of parachain-staking, and allowing governance
+//! to update it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::Currency;
+use pallet_evm::AddressMapping;
+use pallet_evm_precompile_parachain_staking::GOVERNANCE_ADDRESS;
+use pallet_parachain_staking::{Range, MAX_INFLATION_DISTRIBUTION_ACCOUNTS};
+use precompile_utils::prelude::*;
+use sp_core::{H160, U256};
+use sp_runtime::{PerThing, Perbill, Percent};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// InflationDistributionInfo: Twox128(16) + Twox128(16) + Compact length(1)
+/// + MAX_INFLATION_DISTRIBUTION_ACCOUNTS * (AccountId(20) + Percent(1))
+const INFLATION_DISTRIBUTION_INFO_SIZE: usize =
+	33 + MAX_INFLATION_DISTRIBUTION_ACCOUNTS as usize * 21;
+
+type BalanceOf<Runtime> = <<Runtime as pallet_parachain_staking::Config>::Currency as Currency<
+	<Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// A precompile exposing the inflation configuration of parachain-staking.
+///
+/// The setters map to the `MonetaryGovernanceOrigin` calls of the pallet. As for the governance
+/// selectors of the parachain staking precompile, they must be called from
+/// [`GOVERNANCE_ADDRESS`], and are dispatched with the root origin.
+pub struct InflationPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> InflationPrecompile<Runtime>
+where
+	Runtime: pallet_parachain_staking::Config + pallet_evm::Config,
+	Runtime::AccountId: Into<H160>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		From<frame_system::RawOrigin<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<pallet_parachain_staking::Call<Runtime>>,
+	BalanceOf<Runtime>: Into<U256>,
+{
+	#[precompile::public("inflationConfig()")]
+	#[precompile::view]
+	fn inflation_config(handle: &mut impl PrecompileHandle) -> EvmResult<InflationConfig> {
+		// InflationConfig: Twox128(16) + Twox128(16)
+		// + InflationInfo(3 * Balance(16) + 6 * Perbill(4))
+		handle.record_db_read::<Runtime>(104)?;
+
+		let config = pallet_parachain_staking::Pallet::<Runtime>::inflation_config();
+
+		Ok(InflationConfig {
+			expect: BalanceRange {
+				min: config.expect.min.into(),
+				ideal: config.expect.ideal.into(),
+				max: config.expect.max.into(),
+			},
+			annual: config.annual.into(),
+			round: config.round.into(),
+		})
+	}
+
+	#[precompile::public("parachainBondInfo()")]
+	#[precompile::view]
+	fn parachain_bond_info(handle: &mut impl PrecompileHandle) -> EvmResult<(Address, u8)> {
+		handle.record_db_read::<Runtime>(INFLATION_DISTRIBUTION_INFO_SIZE)?;
+
+		let info = pallet_parachain_staking::Pallet::<Runtime>::parachain_bond_info();
+
+		Ok((Address(info.account.into()), info.percent.deconstruct()))
+	}
+
+	#[precompile::public("inflationDistributionConfig()")]
+	#[precompile::view]
+	fn inflation_distribution_config(
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<Vec<InflationDistributionAccount>> {
+		handle.record_db_read::<Runtime>(INFLATION_DISTRIBUTION_INFO_SIZE)?;
+
+		let config = pallet_parachain_staking::Pallet::<Runtime>::inflation_distribution_info();
+
+		Ok(config
+			.into_iter()
+			.map(|distribution| InflationDistributionAccount {
+				account: Address(distribution.account.into()),
+				percent: distribution.percent.deconstruct(),
+			})
+			.collect())
+	}
+
+	#[precompile::public("setInflation(uint32,uint32,uint32)")]
+	fn set_inflation(
+		handle: &mut impl PrecompileHandle,
+		min: u32,
+		ideal: u32,
+		max: u32,
+	) -> EvmResult {
+		let origin = Self::ensure_governance(handle)?;
+		let schedule = Range {
+			min: Self::perbill(min)?,
+			ideal: Self::perbill(ideal)?,
+			max: Self::perbill(max)?,
+		};
+		let call = pallet_parachain_staking::Call::<Runtime>::set_inflation { schedule };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("setParachainBondAccount(address)")]
+	fn set_parachain_bond_account(
+		handle: &mut impl PrecompileHandle,
+		account: Address,
+	) -> EvmResult {
+		let origin = Self::ensure_governance(handle)?;
+		let new = Runtime::AddressMapping::into_account_id(account.0);
+		let call = pallet_parachain_staking::Call::<Runtime>::set_parachain_bond_account { new };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("setParachainBondReservePercent(uint8)")]
+	fn set_parachain_bond_reserve_percent(
+		handle: &mut impl PrecompileHandle,
+		percent: u8,
+	) -> EvmResult {
+		let origin = Self::ensure_governance(handle)?;
+		if percent > 100 {
+			return Err(RevertReason::custom("Percent cannot exceed 100")
+				.in_field("percent")
+				.into());
+		}
+		let call = pallet_parachain_staking::Call::<Runtime>::set_parachain_bond_reserve_percent {
+			new: Percent::from_percent(percent),
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+		Ok(())
+	}
+
+	/// Returns the root origin if the caller is the governance address, reverts otherwise.
+	fn ensure_governance(
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin> {
+		if handle.context().caller != GOVERNANCE_ADDRESS {
+			return Err(revert("Caller is not the governance address"));
+		}
+
+		Ok(frame_system::RawOrigin::Root.into())
+	}
+
+	fn perbill(parts: u32) -> EvmResult<Perbill> {
+		if parts > Perbill::ACCURACY {
+			return Err(revert("Perbill cannot exceed 1_000_000_000 parts"));
+		}
+
+		Ok(Perbill::from_parts(parts))
+	}
+}
+
+#[derive(Default, solidity::Codec)]
+pub struct BalanceRange {
+	min: U256,
+	ideal: U256,
+	max: U256,
+}
+
+/// Range of rates, in parts per billion.
+#[derive(Default, solidity::Codec)]
+pub struct PerbillRange {
+	min: u32,
+	ideal: u32,
+	max: u32,
+}
+
+impl From<Range<Perbill>> for PerbillRange {
+	fn from(range: Range<Perbill>) -> Self {
+		PerbillRange {
+			min: range.min.deconstruct(),
+			ideal: range.ideal.deconstruct(),
+			max: range.max.deconstruct(),
+		}
+	}
+}
+
+/// Account receiving a share of the round inflation, `percent` being between 0 and 100.
+#[derive(Default, solidity::Codec)]
+pub struct InflationDistributionAccount {
+	account: Address,
+	percent: u8,
+}
+
+#[derive(Default, solidity::Codec)]
+pub struct InflationConfig {
+	expect: BalanceRange,
+	annual: PerbillRange,
+	round: PerbillRange,
+}
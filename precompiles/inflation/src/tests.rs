@@ -0,0 +1,257 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	mock::*, BalanceRange, InflationConfig, InflationDistributionAccount, PerbillRange,
+	GOVERNANCE_ADDRESS,
+};
+use frame_support::assert_ok;
+use pallet_parachain_staking::Event as StakingEvent;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::H160;
+use sp_runtime::{Perbill, Percent};
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(&["Inflation.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::inflation_config_selectors().contains(&0xa00b1f10));
+	assert!(PCall::parachain_bond_info_selectors().contains(&0x8e92274e));
+	assert!(PCall::inflation_distribution_config_selectors().contains(&0x73be324c));
+	assert!(PCall::set_inflation_selectors().contains(&0x780eadf3));
+	assert!(PCall::set_parachain_bond_account_selectors().contains(&0xeb7fb052));
+	assert!(PCall::set_parachain_bond_reserve_percent_selectors().contains(&0x3afccf5b));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_view_modifier(PCall::inflation_config_selectors());
+		tester.test_view_modifier(PCall::parachain_bond_info_selectors());
+		tester.test_view_modifier(PCall::inflation_distribution_config_selectors());
+		tester.test_default_modifier(PCall::set_inflation_selectors());
+		tester.test_default_modifier(PCall::set_parachain_bond_account_selectors());
+		tester.test_default_modifier(PCall::set_parachain_bond_reserve_percent_selectors());
+	});
+}
+
+#[test]
+fn inflation_config_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::inflation_config {})
+			.expect_no_logs()
+			.execute_returns(InflationConfig {
+				expect: BalanceRange {
+					min: 700.into(),
+					ideal: 700.into(),
+					max: 700.into(),
+				},
+				annual: PerbillRange {
+					min: 500_000_000,
+					ideal: 500_000_000,
+					max: 500_000_000,
+				},
+				round: PerbillRange {
+					min: 50_000_000,
+					ideal: 50_000_000,
+					max: 50_000_000,
+				},
+			});
+	});
+}
+
+#[test]
+fn parachain_bond_info_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::parachain_bond_info {})
+			.expect_no_logs()
+			.execute_returns((Address(H160::zero()), 30u8));
+	});
+}
+
+#[test]
+fn inflation_distribution_config_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let config = vec![
+			pallet_parachain_staking::InflationDistributionAccount {
+				account: Bob.into(),
+				percent: Percent::from_percent(20),
+			},
+			pallet_parachain_staking::InflationDistributionAccount {
+				account: Charlie.into(),
+				percent: Percent::from_percent(10),
+			},
+		];
+		assert_ok!(ParachainStaking::set_inflation_distribution_config(
+			RuntimeOrigin::root(),
+			config.try_into().expect("within bounds")
+		));
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::inflation_distribution_config {})
+			.expect_no_logs()
+			.execute_returns(vec![
+				InflationDistributionAccount {
+					account: Address(Bob.into()),
+					percent: 20,
+				},
+				InflationDistributionAccount {
+					account: Address(Charlie.into()),
+					percent: 10,
+				},
+			]);
+	});
+}
+
+#[test]
+fn setters_revert_for_non_governance_caller() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::set_inflation {
+					min: 1,
+					ideal: 2,
+					max: 3,
+				},
+			)
+			.execute_reverts(|output| output == b"Caller is not the governance address");
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::set_parachain_bond_account {
+					account: Address(Alice.into()),
+				},
+			)
+			.execute_reverts(|output| output == b"Caller is not the governance address");
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::set_parachain_bond_reserve_percent { percent: 50 },
+			)
+			.execute_reverts(|output| output == b"Caller is not the governance address");
+	});
+}
+
+#[test]
+fn set_inflation_works_from_governance_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				GOVERNANCE_ADDRESS,
+				Precompile1,
+				PCall::set_inflation {
+					min: 30_000_000,
+					ideal: 40_000_000,
+					max: 50_000_000,
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(());
+
+		let config = ParachainStaking::inflation_config();
+		assert_eq!(config.annual.min, Perbill::from_percent(3));
+		assert_eq!(config.annual.ideal, Perbill::from_percent(4));
+		assert_eq!(config.annual.max, Perbill::from_percent(5));
+		assert!(events().iter().any(|e| matches!(
+			e,
+			RuntimeEvent::ParachainStaking(StakingEvent::InflationSet { .. })
+		)));
+	});
+}
+
+#[test]
+fn set_inflation_reverts_on_invalid_perbill() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				GOVERNANCE_ADDRESS,
+				Precompile1,
+				PCall::set_inflation {
+					min: 1,
+					ideal: 2,
+					max: 1_000_000_001,
+				},
+			)
+			.execute_reverts(|output| output == b"Perbill cannot exceed 1_000_000_000 parts");
+	});
+}
+
+#[test]
+fn set_parachain_bond_works_from_governance_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				GOVERNANCE_ADDRESS,
+				Precompile1,
+				PCall::set_parachain_bond_account {
+					account: Address(Bob.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				GOVERNANCE_ADDRESS,
+				Precompile1,
+				PCall::set_parachain_bond_reserve_percent { percent: 50 },
+			)
+			.expect_no_logs()
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::parachain_bond_info {})
+			.expect_no_logs()
+			.execute_returns((Address(Bob.into()), 50u8));
+
+		let expected: RuntimeEvent = StakingEvent::ParachainBondReservePercentSet {
+			old: Percent::from_percent(30),
+			new: Percent::from_percent(50),
+		}
+		.into();
+		assert!(events().contains(&expected));
+	});
+}
+
+#[test]
+fn set_parachain_bond_reserve_percent_reverts_above_100() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				GOVERNANCE_ADDRESS,
+				Precompile1,
+				PCall::set_parachain_bond_reserve_percent { percent: 101 },
+			)
+			.execute_reverts(|output| output == b"percent: Percent cannot exceed 100");
+	});
+}
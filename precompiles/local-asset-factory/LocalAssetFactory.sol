@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The LocalAssetFactory contract's address.
+address constant LOCAL_ASSET_FACTORY_ADDRESS = 0x0000000000000000000000000000000000000824;
+
+/// @dev The LocalAssetFactory contract's instance.
+LocalAssetFactory constant LOCAL_ASSET_FACTORY_CONTRACT = LocalAssetFactory(
+    LOCAL_ASSET_FACTORY_ADDRESS
+);
+
+/// @author The Moonbeam Team
+/// @title Local Asset Factory Interface
+/// @dev The interface through which contracts and accounts create local assets. As with CREATE2,
+/// the XC-20 address of an asset only depends on its creator and a salt, so it can be computed
+/// before the asset is created:
+/// `address(uint160(0xfffffffe) << 128 | uint128(bytes16(keccak256(abi.encodePacked(
+/// bytes1(0xff), creator, salt)))))`
+/// @custom:address 0x0000000000000000000000000000000000000824
+interface LocalAssetFactory {
+    /// @dev Create a local asset at the XC-20 address derived from the caller and `salt`. The
+    /// asset deposit is reserved from the caller, and `owner` administrates the asset.
+    /// @custom:selector e7c786f8
+    /// @param salt The salt from which the address of the asset is derived
+    /// @param minBalance The minimum balance of the accounts holding the asset
+    /// @param owner The owner of the asset
+    /// @return asset The XC-20 address of the created asset
+    function createLocalAsset(
+        bytes32 salt,
+        uint256 minBalance,
+        address owner
+    ) external returns (address asset);
+
+    /// @dev The XC-20 address of the local asset created by `creator` with `salt`.
+    /// @custom:selector 0d1c7807
+    /// @param creator The creator of the asset
+    /// @param salt The salt from which the address of the asset is derived
+    /// @return asset The XC-20 address of the asset
+    function localAssetAddress(address creator, bytes32 salt)
+        external
+        view
+        returns (address asset);
+
+    /// @dev Emitted when a local asset is created.
+    /// @custom:selector 149107a06536fb9e32a1e7e52d221f41ffc2fdd9cea2b97c8272392b60ff8ba0
+    /// @param creator The creator of the asset
+    /// @param asset The XC-20 address of the asset
+    /// @param salt The salt from which the address of the asset is derived
+    event LocalAssetCreated(address indexed creator, address indexed asset, bytes32 salt);
+}
@@ -0,0 +1,132 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile creating local assets at deterministic XC-20 addresses.
+//!
+//! The asset id of a local asset created through this precompile is derived from its creator
+//! and a salt, like the address of a contract deployed with CREATE2, such that factory contracts
+//! can compute the address of the assets they will create beforehand.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use frame_support::traits::Get;
+use pallet_asset_manager::WeightInfo;
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity selector of the LocalAssetCreated log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_LOCAL_ASSET_CREATED: [u8; 32] =
+	keccak256!("LocalAssetCreated(address,address,bytes32)");
+
+/// Precompile creating local assets whose XC-20 address is derived from the creator and a salt.
+/// `LocalAssetPrefix` is the prefix of the XC-20 addresses of local assets.
+pub struct LocalAssetFactoryPrecompile<Runtime, LocalAssetPrefix>(
+	PhantomData<(Runtime, LocalAssetPrefix)>,
+);
+
+#[precompile_utils::precompile]
+impl<Runtime, LocalAssetPrefix> LocalAssetFactoryPrecompile<Runtime, LocalAssetPrefix>
+where
+	Runtime: pallet_evm::Config + pallet_asset_manager::Config,
+	Runtime::AssetId: From<u128>,
+	<Runtime as pallet_asset_manager::Config>::Balance: TryFrom<U256>,
+	LocalAssetPrefix: Get<&'static [u8]>,
+{
+	#[precompile::public("createLocalAsset(bytes32,uint256,address)")]
+	fn create_local_asset(
+		handle: &mut impl PrecompileHandle,
+		salt: H256,
+		min_balance: U256,
+		owner: Address,
+	) -> EvmResult<Address> {
+		handle.record_weight::<Runtime>(
+			<Runtime as pallet_asset_manager::Config>::WeightInfo::register_local_asset(),
+		)?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		let min_balance = min_balance
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field("minBalance"))?;
+
+		let creator = handle.context().caller;
+		let asset_id = Self::asset_id(creator, salt);
+		let asset = Self::asset_address(asset_id);
+
+		pallet_asset_manager::Pallet::<Runtime>::do_register_local_asset(
+			asset_id.into(),
+			Runtime::AddressMapping::into_account_id(creator),
+			Runtime::AddressMapping::into_account_id(owner.into()),
+			// Assets created by anyone cannot be sufficient, as accounts holding them would not
+			// need an existential deposit
+			false,
+			min_balance,
+		)
+		.map_err(TryDispatchError::Substrate)?;
+
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_LOCAL_ASSET_CREATED,
+			creator,
+			asset,
+			solidity::encode_event_data(salt),
+		)
+		.record(handle)?;
+
+		Ok(Address(asset))
+	}
+
+	#[precompile::public("localAssetAddress(address,bytes32)")]
+	#[precompile::view]
+	fn local_asset_address(
+		_handle: &mut impl PrecompileHandle,
+		creator: Address,
+		salt: H256,
+	) -> EvmResult<Address> {
+		Ok(Address(Self::asset_address(Self::asset_id(
+			creator.into(),
+			salt,
+		))))
+	}
+
+	/// Asset id of the local asset created by `creator` with `salt`: the first 16 bytes of
+	/// `keccak256(0xff ++ creator ++ salt)`, as in the CREATE2 address derivation.
+	pub fn asset_id(creator: H160, salt: H256) -> u128 {
+		let mut preimage = [0u8; 1 + 20 + 32];
+		preimage[0] = 0xff;
+		preimage[1..21].copy_from_slice(creator.as_bytes());
+		preimage[21..53].copy_from_slice(salt.as_bytes());
+
+		let mut asset_id = [0u8; 16];
+		asset_id.copy_from_slice(&keccak_256(&preimage)[0..16]);
+		u128::from_be_bytes(asset_id)
+	}
+
+	/// XC-20 address of the local asset `asset_id`.
+	pub fn asset_address(asset_id: u128) -> H160 {
+		let mut address = [0u8; 20];
+		address[0..4].copy_from_slice(LocalAssetPrefix::get());
+		address[4..20].copy_from_slice(&asset_id.to_be_bytes());
+		H160(address)
+	}
+}
@@ -0,0 +1,207 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	mock::{
+		Balances, CreatedAssets, ExtBuilder, LocalAssetPrefix, PCall, Precompiles,
+		PrecompilesValue, Runtime,
+	},
+	LocalAssetFactoryPrecompile, SELECTOR_LOG_LOCAL_ASSET_CREATED,
+};
+use frame_support::traits::ReservableCurrency;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, H256, U256};
+
+type Factory = LocalAssetFactoryPrecompile<Runtime, LocalAssetPrefix>;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn create_local_asset(salt: H256, owner: impl Into<H160>) -> PCall {
+	PCall::create_local_asset {
+		salt,
+		min_balance: 1.into(),
+		owner: Address(owner.into()),
+	}
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["LocalAssetFactory.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::create_local_asset_selectors().contains(&0xe7c786f8));
+	assert!(PCall::local_asset_address_selectors().contains(&0x0d1c7807));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_default_modifier(PCall::create_local_asset_selectors());
+		tester.test_view_modifier(PCall::local_asset_address_selectors());
+	});
+}
+
+#[test]
+fn asset_address_is_derived_like_create2() {
+	let creator = H160::repeat_byte(0x11);
+	let salt = H256::repeat_byte(0x22);
+
+	let mut preimage = vec![0xff];
+	preimage.extend_from_slice(creator.as_bytes());
+	preimage.extend_from_slice(salt.as_bytes());
+	let hash = sp_io::hashing::keccak_256(&preimage);
+
+	let address = Factory::asset_address(Factory::asset_id(creator, salt));
+
+	assert_eq!(&address.as_bytes()[0..4], &[255u8, 255, 255, 254]);
+	assert_eq!(&address.as_bytes()[4..20], &hash[0..16]);
+}
+
+#[test]
+fn local_asset_address_depends_on_creator_and_salt() {
+	ExtBuilder::default().build().execute_with(|| {
+		let salt = H256::repeat_byte(1);
+		let alice_asset = Factory::asset_address(Factory::asset_id(Alice.into(), salt));
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::local_asset_address {
+					creator: Address(Alice.into()),
+					salt,
+				},
+			)
+			.execute_returns(Address(alice_asset));
+
+		assert_ne!(
+			alice_asset,
+			Factory::asset_address(Factory::asset_id(Bob.into(), salt))
+		);
+		assert_ne!(
+			alice_asset,
+			Factory::asset_address(Factory::asset_id(Alice.into(), H256::repeat_byte(2)))
+		);
+	});
+}
+
+#[test]
+fn create_local_asset_at_precomputed_address() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let salt = H256::repeat_byte(1);
+			let asset_id = Factory::asset_id(Alice.into(), salt);
+			let asset = Factory::asset_address(asset_id);
+
+			precompiles()
+				.prepare_test(Alice, Precompile1, create_local_asset(salt, Bob))
+				.expect_log(log3(
+					Precompile1,
+					SELECTOR_LOG_LOCAL_ASSET_CREATED,
+					H160::from(Alice),
+					asset,
+					solidity::encode_event_data(salt),
+				))
+				.execute_returns(Address(asset));
+
+			assert_eq!(CreatedAssets::get(), vec![(asset_id, 1, Bob.into())]);
+			assert_eq!(
+				pallet_asset_manager::LocalAssetDeposit::<Runtime>::get(asset_id)
+					.map(|info| (info.creator, info.deposit)),
+				Some((Alice.into(), 100))
+			);
+			assert_eq!(Balances::reserved_balance(&Alice.into()), 100);
+		});
+}
+
+#[test]
+fn create_local_asset_twice_with_same_salt_reverts() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let salt = H256::repeat_byte(1);
+
+			precompiles()
+				.prepare_test(Alice, Precompile1, create_local_asset(salt, Alice))
+				.execute_some();
+
+			precompiles()
+				.prepare_test(Alice, Precompile1, create_local_asset(salt, Alice))
+				.execute_reverts(|output| {
+					core::str::from_utf8(output)
+						.unwrap()
+						.contains("AssetAlreadyExists")
+				});
+
+			assert_eq!(CreatedAssets::get().len(), 1);
+		});
+}
+
+#[test]
+fn create_local_asset_without_deposit_reverts() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 50)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					create_local_asset(H256::repeat_byte(1), Alice),
+				)
+				.execute_reverts(|output| {
+					core::str::from_utf8(output)
+						.unwrap()
+						.contains("NotSufficientDeposit")
+				});
+
+			assert!(CreatedAssets::get().is_empty());
+		});
+}
+
+#[test]
+fn create_local_asset_with_too_large_min_balance_reverts() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::create_local_asset {
+						salt: H256::repeat_byte(1),
+						min_balance: U256::MAX,
+						owner: Address(Alice.into()),
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"minBalance: Value is too large for balance type"
+				});
+		});
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Migrations contract's address.
+address constant MIGRATIONS_ADDRESS = 0x0000000000000000000000000000000000000822;
+
+/// @dev The Migrations contract's instance.
+Migrations constant MIGRATIONS_CONTRACT = Migrations(MIGRATIONS_ADDRESS);
+
+/// @author The Moonbeam Team
+/// @title Migrations status interface
+/// @title The interface through which solidity contracts and tools can check that the
+/// migrations of the runtime upgrades completed
+/// @custom:address 0x0000000000000000000000000000000000000822
+interface Migrations {
+    /// @dev Status of a migration registered in the runtime.
+    struct Migration {
+        /// Friendly name of the migration
+        string name;
+        /// Whether the migration was started
+        bool started;
+        /// Whether the migration completed
+        bool completed;
+        /// Whether the weight consumed by the migration is known, which is not the case for
+        /// the migrations which completed before it was recorded
+        bool hasConsumedWeight;
+        /// Execution time consumed by the migration, in picoseconds
+        uint64 consumedRefTime;
+        /// Proof size consumed by the migration, in bytes
+        uint64 consumedProofSize;
+    }
+
+    /// @dev Status of each migration registered in the runtime.
+    /// @custom:selector 0f7d6941
+    /// @return The status of each migration
+    function migrations() external view returns (Migration[] memory);
+
+    /// @dev Whether all the migrations registered in the runtime completed.
+    /// @custom:selector e4d4df79
+    /// @return Whether all the migrations completed
+    function allCompleted() external view returns (bool);
+}
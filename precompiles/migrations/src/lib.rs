@@ -0,0 +1,104 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile exposing the status of the migrations registered in pallet-migrations, so that
+//! contracts and tools can check that the migrations of a runtime upgrade completed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::traits::Get;
+use migrations_runtime_api::MigrationStatus;
+use precompile_utils::prelude::*;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// A precompile exposing the status of the migrations registered in pallet-migrations.
+/// `Status` provides the status of each migration.
+pub struct MigrationsPrecompile<Runtime, Status>(PhantomData<(Runtime, Status)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, Status> MigrationsPrecompile<Runtime, Status>
+where
+	Runtime: pallet_evm::Config,
+	Status: Get<Vec<MigrationStatus>>,
+{
+	#[precompile::public("migrations()")]
+	#[precompile::view]
+	fn migrations(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<Migration>> {
+		Ok(Self::read_statuses(handle)?
+			.into_iter()
+			.map(Into::into)
+			.collect())
+	}
+
+	#[precompile::public("allCompleted()")]
+	#[precompile::view]
+	fn all_completed(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+		Ok(Self::read_statuses(handle)?
+			.iter()
+			.all(|status| status.completed))
+	}
+
+	fn read_statuses(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<MigrationStatus>> {
+		let statuses = Status::get();
+
+		// The statuses are charged once read, as their number is bounded by the migrations of the
+		// runtime.
+		for status in &statuses {
+			// Storage item: MigrationState:
+			// Twox64(8) + name + bool(1)
+			handle.record_db_read::<Runtime>(status.name.len() + 9)?;
+			// Storage item: MigrationRuns:
+			// Twox64(8) + name + MigrationRun(1 + 2 * 9)
+			handle.record_db_read::<Runtime>(status.name.len() + 27)?;
+		}
+
+		Ok(statuses)
+	}
+}
+
+#[derive(Eq, PartialEq, Debug, solidity::Codec)]
+pub struct Migration {
+	name: UnboundedString,
+	started: bool,
+	completed: bool,
+	has_consumed_weight: bool,
+	consumed_ref_time: u64,
+	consumed_proof_size: u64,
+}
+
+impl From<MigrationStatus> for Migration {
+	fn from(status: MigrationStatus) -> Self {
+		let (consumed_ref_time, consumed_proof_size) = status
+			.consumed_weight
+			.map(|weight| (weight.ref_time, weight.proof_size))
+			.unwrap_or_default();
+
+		Self {
+			name: status.name.into(),
+			started: status.started,
+			completed: status.completed,
+			has_consumed_weight: status.consumed_weight.is_some(),
+			consumed_ref_time,
+			consumed_proof_size,
+		}
+	}
+}
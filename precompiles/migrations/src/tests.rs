@@ -0,0 +1,109 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::Migration;
+use migrations_runtime_api::{MigrationStatus, MigrationWeight};
+use precompile_utils::testing::*;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn status(name: &str, completed: bool, consumed_weight: Option<(u64, u64)>) -> MigrationStatus {
+	MigrationStatus {
+		name: name.as_bytes().to_vec(),
+		started: completed || consumed_weight.is_some(),
+		completed,
+		consumed_weight: consumed_weight.map(|(ref_time, proof_size)| MigrationWeight {
+			ref_time,
+			proof_size,
+		}),
+	}
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(&["Migrations.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::migrations_selectors().contains(&0x0f7d6941));
+	assert!(PCall::all_completed_selectors().contains(&0xe4d4df79));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_view_modifier(PCall::migrations_selectors());
+		tester.test_view_modifier(PCall::all_completed_selectors());
+	});
+}
+
+#[test]
+fn migrations_returns_the_status_of_each_migration() {
+	ExtBuilder::default().build().execute_with(|| {
+		MigrationStatuses::set(vec![
+			status("MM_Old", true, None),
+			status("MM_New", true, Some((1_000, 64))),
+		]);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::migrations {})
+			.expect_no_logs()
+			.execute_returns(vec![
+				Migration {
+					name: "MM_Old".into(),
+					started: true,
+					completed: true,
+					has_consumed_weight: false,
+					consumed_ref_time: 0,
+					consumed_proof_size: 0,
+				},
+				Migration {
+					name: "MM_New".into(),
+					started: true,
+					completed: true,
+					has_consumed_weight: true,
+					consumed_ref_time: 1_000,
+					consumed_proof_size: 64,
+				},
+			]);
+	});
+}
+
+#[test]
+fn all_completed_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		MigrationStatuses::set(vec![status("MM_Done", true, Some((1_000, 64)))]);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::all_completed {})
+			.execute_returns(true);
+
+		MigrationStatuses::set(vec![
+			status("MM_Done", true, Some((1_000, 64))),
+			status("MM_Pending", false, None),
+		]);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::all_completed {})
+			.execute_returns(false);
+	});
+}
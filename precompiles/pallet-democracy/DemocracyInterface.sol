@@ -167,6 +167,14 @@ interface Democracy {
     /// @param target The account whose tokens should be unlocked. This may be any account.
     function unlock(address target) external;
 
+    /// Remove the democracy votes, delegation and lock of an account, now that governance has
+    /// moved to OpenGov. The delegation is converted to conviction voting delegations, or released,
+    /// according to the rule set by governance.
+    /// @custom:selector 033dd937
+    ///
+    /// @param target The account whose democracy lock should be migrated. This may be any account.
+    function migrateLock(address target) external;
+
     /// Register the preimage for an upcoming proposal. This doesn't require the proposal to be
     /// in the dispatch queue but does require a deposit, returned once enacted.
     /// @custom:selector cb00f603
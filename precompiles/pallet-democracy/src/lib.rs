@@ -24,6 +24,7 @@ use frame_support::traits::{Bounded, ConstU32, Currency, QueryPreimage};
 use pallet_democracy::{
 	AccountVote, Call as DemocracyCall, Conviction, ReferendumInfo, Vote, VoteThreshold,
 };
+use pallet_democracy_lock_migration::Call as LockMigrationCall;
 use pallet_evm::AddressMapping;
 use pallet_preimage::Call as PreimageCall;
 use precompile_utils::prelude::*;
@@ -80,13 +81,15 @@ where
 	Runtime: pallet_democracy::Config
 		+ pallet_evm::Config
 		+ frame_system::Config
-		+ pallet_preimage::Config,
+		+ pallet_preimage::Config
+		+ pallet_democracy_lock_migration::Config,
 	U256: From<BalanceOf<Runtime>>,
 	BalanceOf<Runtime>: TryFrom<U256> + TryInto<u128> + Into<U256> + Debug + solidity::Codec,
 	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
 	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
 	Runtime::RuntimeCall: From<DemocracyCall<Runtime>>,
 	Runtime::RuntimeCall: From<PreimageCall<Runtime>>,
+	Runtime::RuntimeCall: From<LockMigrationCall<Runtime>>,
 	Runtime::Hash: From<H256> + Into<H256>,
 	Runtime::BlockNumber: Into<U256>,
 {
@@ -441,6 +444,24 @@ where
 		Ok(())
 	}
 
+	#[precompile::public("migrateLock(address)")]
+	fn migrate_lock(handle: &mut impl PrecompileHandle, target: Address) -> EvmResult {
+		let target: H160 = target.into();
+		let who = Runtime::AddressMapping::into_account_id(target);
+
+		log::trace!(
+			target: "democracy-precompile",
+			"Migrating democracy lock of {:?}", who
+		);
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = LockMigrationCall::<Runtime>::migrate_democracy_lock { who };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
 	#[precompile::public("notePreimage(bytes)")]
 	#[precompile::public("note_preimage(bytes)")]
 	fn note_preimage(
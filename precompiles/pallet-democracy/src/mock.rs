@@ -18,15 +18,22 @@
 use super::*;
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{EqualPrivilegeOnly, Everything, OnFinalize, OnInitialize, StorePreimage},
+	traits::{
+		EqualPrivilegeOnly, Everything, OnFinalize, OnInitialize, PollStatus, Polling,
+		StorePreimage, TotalIssuanceOf,
+	},
 	weights::Weight,
 };
 use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_conviction_voting::TallyOf;
 use pallet_democracy::{BoundedCallOf, VoteThreshold};
 use pallet_evm::{EnsureAddressNever, EnsureAddressRoot, SubstrateBlockHashMapping};
 use precompile_utils::{precompile_set::*, testing::MockAccount};
 use sp_core::{H256, U256};
-use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	DispatchError,
+};
 
 pub type AccountId = MockAccount;
 pub type Balance = u128;
@@ -49,6 +56,8 @@ construct_runtime!(
 		Democracy: pallet_democracy::{Pallet, Storage, Config<T>, Event<T>, Call},
 		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
 		Preimage: pallet_preimage::{Pallet, Event<T>, Call},
+		ConvictionVoting: pallet_conviction_voting,
+		DemocracyLockMigration: pallet_democracy_lock_migration::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -230,6 +239,60 @@ impl pallet_preimage::Config for Runtime {
 	type ByteDeposit = ByteDeposit;
 }
 
+/// OpenGov polls, of which there are none as only delegations are migrated to conviction voting.
+pub struct TestPolls;
+impl Polling<TallyOf<Runtime>> for TestPolls {
+	type Index = u8;
+	type Votes = u128;
+	type Moment = u32;
+	type Class = u8;
+	fn classes() -> Vec<u8> {
+		vec![0, 1, 2]
+	}
+	fn as_ongoing(_index: u8) -> Option<(TallyOf<Runtime>, Self::Class)> {
+		None
+	}
+	fn access_poll<R>(
+		_index: Self::Index,
+		f: impl FnOnce(PollStatus<&mut TallyOf<Runtime>, u32, u8>) -> R,
+	) -> R {
+		f(PollStatus::None)
+	}
+	fn try_access_poll<R>(
+		_index: Self::Index,
+		f: impl FnOnce(PollStatus<&mut TallyOf<Runtime>, u32, u8>) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError> {
+		f(PollStatus::None)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_ongoing(_class: Self::Class) -> Result<Self::Index, ()> {
+		Err(())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn end_ongoing(_index: Self::Index, _approved: bool) -> Result<(), ()> {
+		Err(())
+	}
+}
+
+impl pallet_conviction_voting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type VoteLockingPeriod = VoteLockingPeriod;
+	type MaxVotes = ConstU32<3>;
+	type WeightInfo = ();
+	type MaxTurnout = TotalIssuanceOf<Balances, AccountId>;
+	type Polls = TestPolls;
+}
+
+impl pallet_democracy_lock_migration::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MigrationManagerOrigin = EnsureRoot<AccountId>;
+	type MaxTracks = ConstU32<3>;
+	type WeightInfo = ();
+}
+
 /// Build test externalities, prepopulated with data for testing democracy precompiles
 pub(crate) struct ExtBuilder {
 	/// Endowed accounts with balances
@@ -16,8 +16,9 @@
 
 use crate::{
 	mock::{
-		events, roll_to, set_balance_proposal, AccountId, Balances, Democracy, ExtBuilder, PCall,
-		Precompiles, PrecompilesValue, Preimage, Runtime, RuntimeCall, RuntimeOrigin,
+		events, roll_to, set_balance_proposal, AccountId, Balances, Democracy,
+		DemocracyLockMigration, ExtBuilder, PCall, Precompiles, PrecompilesValue, Preimage,
+		Runtime, RuntimeCall, RuntimeOrigin,
 	},
 	SELECTOR_LOG_DELEGATED, SELECTOR_LOG_PROPOSED, SELECTOR_LOG_SECONDED,
 	SELECTOR_LOG_STANDARD_VOTE, SELECTOR_LOG_UNDELEGATED,
@@ -28,6 +29,7 @@ use frame_support::{
 	traits::{Currency, PreimageProvider, QueryPreimage, StorePreimage},
 };
 use pallet_balances::Event as BalancesEvent;
+use pallet_democracy_lock_migration::DelegationMigration;
 use pallet_preimage::Event as PreimageEvent;
 
 use pallet_democracy::{
@@ -90,6 +92,7 @@ fn selectors() {
 	assert!(PCall::standard_vote_selectors().contains(&0x6cd18b0d));
 	assert!(PCall::un_delegate_selectors().contains(&0x1eef225c));
 	assert!(PCall::unlock_selectors().contains(&0x2f6c493c));
+	assert!(PCall::migrate_lock_selectors().contains(&0x033dd937));
 
 	// TODO also test logs once we have them
 }
@@ -111,6 +114,7 @@ fn modifiers() {
 		tester.test_default_modifier(PCall::standard_vote_selectors());
 		tester.test_default_modifier(PCall::un_delegate_selectors());
 		tester.test_default_modifier(PCall::unlock_selectors());
+		tester.test_default_modifier(PCall::migrate_lock_selectors());
 	});
 }
 
@@ -1173,6 +1177,76 @@ fn unlock_with_nothing_locked() {
 		})
 }
 
+#[test]
+fn migrate_lock_converts_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Democracy::delegate(
+				RuntimeOrigin::signed(Alice.into()),
+				Bob.into(),
+				1u8.try_into().unwrap(),
+				100
+			));
+			assert_ok!(DemocracyLockMigration::set_delegation_rule(
+				RuntimeOrigin::root(),
+				Some(DelegationMigration::Convert(vec![0].try_into().unwrap()))
+			));
+
+			// Anyone can migrate the lock of Alice
+			precompiles()
+				.prepare_test(
+					Charlie,
+					Precompile1,
+					PCall::migrate_lock {
+						target: H160::from(Alice).into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+
+			assert_eq!(
+				pallet_democracy::VotingOf::<Runtime>::get(AccountId::from(Alice)).locked_balance(),
+				0
+			);
+			match pallet_conviction_voting::VotingFor::<Runtime>::get(AccountId::from(Alice), 0) {
+				pallet_conviction_voting::Voting::Delegating(delegating) => {
+					assert_eq!(delegating.target, Bob.into());
+					assert_eq!(delegating.balance, 100);
+				}
+				_ => panic!("expected a conviction voting delegation"),
+			}
+		})
+}
+
+#[test]
+fn migrate_lock_requires_delegation_rule() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Democracy::delegate(
+				RuntimeOrigin::signed(Alice.into()),
+				Bob.into(),
+				1u8.try_into().unwrap(),
+				100
+			));
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::migrate_lock {
+						target: H160::from(Alice).into(),
+					},
+				)
+				.execute_reverts(|output| {
+					from_utf8(&output).unwrap().contains("MigrationDisabled")
+				});
+		})
+}
+
 #[test]
 fn note_preimage_works() {
 	ExtBuilder::default()
@@ -36,6 +36,15 @@ interface ParachainStaking {
         address candidate
     ) external view returns (bool);
 
+    /// @dev Check whether the specified address would be part of the active set if the next
+    /// round started now
+    /// @custom:selector 54c29876
+    /// @param candidate the address that we want to confirm is part of the next active set
+    /// @return A boolean confirming whether the address would be part of the next active set
+    function isSelectedForNextRound(
+        address candidate
+    ) external view returns (bool);
+
     /// @dev Total points awarded to all collators in a particular round
     /// @custom:selector 9799b4e7
     /// @param round the round for which we are querying the points total
@@ -88,6 +97,15 @@ interface ParachainStaking {
     /// @return The current round number
     function round() external view returns (uint256);
 
+    /// @dev Get the current round information
+    /// @custom:selector cace12e6
+    /// @return The current round number, its first block, its length in blocks and the number of
+    /// blocks remaining before the next round
+    function roundInfo()
+        external
+        view
+        returns (uint256, uint256, uint256, uint256);
+
     /// @dev Get the CandidateDelegationCount weight hint
     /// @custom:selector 2ec087eb
     /// @param candidate The address for which we are querying the nomination count
@@ -128,6 +146,24 @@ interface ParachainStaking {
         address candidate
     ) external view returns (bool);
 
+    /// @dev Position of a scheduled delegation revocation in the exit queue
+    /// @custom:selector 3e2811bc
+    /// @param delegator the delegator that made the delegation
+    /// @param candidate the candidate for which the delegation was made
+    /// @return Whether the revocation is queued, and the number of queued entries ahead of it
+    function delegationExitQueuePosition(
+        address delegator,
+        address candidate
+    ) external view returns (bool, uint256);
+
+    /// @dev Round by which a candidate must top up its bond to the minimum candidate stake
+    /// @custom:selector 8aafb492
+    /// @param candidate the candidate to query
+    /// @return Whether a top-up is required, and the round after which the candidate is kicked
+    function candidateBondTopUpDeadline(
+        address candidate
+    ) external view returns (bool, uint256);
+
     /// @dev Whether there exists a pending exit for candidate
     /// @custom:selector 43443682
     /// @param candidate the candidate for which the exit request was made
@@ -249,6 +285,17 @@ interface ParachainStaking {
     /// @param more The amount by which the delegation is increased
     function delegatorBondMore(address candidate, uint256 more) external;
 
+    /// @dev Move bonded stake from a collator candidate to another without unbonding it
+    /// @custom:selector 76c9c705
+    /// @param oldCandidate The address of the collator candidate the stake is moved from
+    /// @param newCandidate The address of the collator candidate the stake is moved to
+    /// @param amount The amount of stake to move
+    function switchDelegation(
+        address oldCandidate,
+        address newCandidate,
+        uint256 amount
+    ) external;
+
     /// @dev Request to bond less for delegators with respect to a specific collator candidate
     /// @custom:selector c172fd2b
     /// @param candidate The address of the collator candidate for which delegation shall decrease
@@ -286,6 +333,28 @@ interface ParachainStaking {
         uint256 delegatorDelegationCount
     ) external;
 
+    /// @dev Remove a candidate immediately, returning its bond and all its delegations. Only
+    /// callable from the governance address 0x7374616b696e672f676f7665726e616e63650000, which
+    /// only the root origin can use as source of an EVM call
+    /// @custom:selector e51d4743
+    /// @param candidate The address of the candidate to remove
+    /// @param candidateCount The number of candidates in the CandidatePool
+    /// @param candidateDelegationCount The number of delegations of the candidate
+    function forceLeaveCandidates(
+        address candidate,
+        uint256 candidateCount,
+        uint256 candidateDelegationCount
+    ) external;
+
+    /// @dev Cancel the pending delegation request of a delegator. Only callable from the
+    /// governance address 0x7374616b696e672f676f7665726e616e63650000, which only the root
+    /// origin can use as source of an EVM call
+    /// @custom:selector 0fb29d62
+    /// @param delegator The address of the delegator
+    /// @param candidate The address of the candidate
+    function forceCancelDelegationRequest(address delegator, address candidate)
+        external;
+
     /// @dev Fetch the total staked amount of a delegator, regardless of the
     /// candidate.
     /// @custom:selector e6861713
@@ -28,10 +28,16 @@ use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
 use frame_support::sp_runtime::Percent;
 use frame_support::traits::{Currency, Get};
 use pallet_evm::AddressMapping;
+use precompile_utils::precompile_set::{StorageAccess, StorageAccessList};
 use precompile_utils::prelude::*;
 use sp_core::{H160, U256};
 use sp_std::{convert::TryInto, marker::PhantomData, vec::Vec};
 
+/// Source address expected for the governance-only selectors. It is not controlled by any key nor
+/// contract: only the root origin can dispatch an EVM call from it (`pallet_evm::Call::call`
+/// accepts any source from root), which is how referenda executing as root reach them.
+pub const GOVERNANCE_ADDRESS: H160 = H160(*b"staking/governance\0\0");
+
 type BalanceOf<Runtime> = <<Runtime as pallet_parachain_staking::Config>::Currency as Currency<
 	<Runtime as frame_system::Config>::AccountId,
 >>::Balance;
@@ -52,8 +58,11 @@ where
 	Runtime::AccountId: Into<H160>,
 	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
 	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		From<frame_system::RawOrigin<Runtime::AccountId>>,
 	Runtime::RuntimeCall: From<pallet_parachain_staking::Call<Runtime>>,
 	BalanceOf<Runtime>: TryFrom<U256> + Into<U256> + solidity::Codec,
+	<Runtime as frame_system::Config>::BlockNumber: Into<U256>,
 {
 	// Constants
 	#[precompile::public("minDelegation()")]
@@ -126,6 +135,25 @@ where
 		Ok(round)
 	}
 
+	#[precompile::public("roundInfo()")]
+	#[precompile::view]
+	fn round_info(handle: &mut impl PrecompileHandle) -> EvmResult<(u32, U256, u32, U256)> {
+		// Round: RoundInfo(RoundIndex(4) + BlockNumber(4) + 4)
+		handle.record_db_read::<Runtime>(12)?;
+		let round = <pallet_parachain_staking::Pallet<Runtime>>::round();
+
+		// Number: BlockNumber(4)
+		handle.record_db_read::<Runtime>(4)?;
+		let now: U256 = <frame_system::Pallet<Runtime>>::block_number().into();
+
+		let first: U256 = round.first.into();
+		let remaining = first
+			.saturating_add(round.length.into())
+			.saturating_sub(now);
+
+		Ok((round.current, first, round.length, remaining))
+	}
+
 	#[precompile::public("candidateDelegationCount(address)")]
 	#[precompile::public("candidate_delegation_count(address)")]
 	#[precompile::view]
@@ -357,6 +385,28 @@ where
 		Ok(is_selected)
 	}
 
+	#[precompile::public("isSelectedForNextRound(address)")]
+	#[precompile::view]
+	fn is_selected_for_next_round(
+		handle: &mut impl PrecompileHandle,
+		candidate: Address,
+	) -> EvmResult<bool> {
+		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
+
+		// TotalSelected
+		handle.record_db_read::<Runtime>(4)?;
+		// CandidatePool: Vec(Bond(AccountId(20) + Balance(16))) * MaxCandidates
+		// SuspendedCandidates: (Blake2128(16) + AccountId(20) + RoundIndex(4)) * MaxCandidates
+		handle.record_db_read::<Runtime>(
+			76 * (<Runtime as pallet_parachain_staking::Config>::MaxCandidates::get() as usize),
+		)?;
+
+		let is_selected = pallet_parachain_staking::Pallet::<Runtime>::compute_top_candidates()
+			.contains(&candidate);
+
+		Ok(is_selected)
+	}
+
 	#[precompile::public("delegationRequestIsPending(address,address)")]
 	#[precompile::public("delegation_request_is_pending(address,address)")]
 	#[precompile::view]
@@ -390,6 +440,45 @@ where
 		Ok(pending)
 	}
 
+	#[precompile::public("delegationExitQueuePosition(address,address)")]
+	#[precompile::view]
+	fn delegation_exit_queue_position(
+		handle: &mut impl PrecompileHandle,
+		delegator: Address,
+		candidate: Address,
+	) -> EvmResult<(bool, U256)> {
+		let delegator = Runtime::AddressMapping::into_account_id(delegator.0);
+		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
+
+		// DelegatorExitQueueIndex:
+		// Blake2128(16) + AccountId(20) + Blake2128(16) + AccountId(20) + u32(4)
+		// DelegatorExitQueueBounds: (u32, u32)
+		handle.record_db_read::<Runtime>(84)?;
+
+		let position = <pallet_parachain_staking::Pallet<Runtime>>::exit_queue_position(
+			&delegator, &candidate,
+		);
+
+		Ok((position.is_some(), position.unwrap_or_default().into()))
+	}
+
+	#[precompile::public("candidateBondTopUpDeadline(address)")]
+	#[precompile::view]
+	fn candidate_bond_top_up_deadline(
+		handle: &mut impl PrecompileHandle,
+		candidate: Address,
+	) -> EvmResult<(bool, U256)> {
+		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
+
+		// CandidateBondTopUpDeadline: Twox64Concat(8) + AccountId(20) + RoundIndex(4)
+		handle.record_db_read::<Runtime>(32)?;
+
+		let deadline =
+			<pallet_parachain_staking::Pallet<Runtime>>::candidate_bond_top_up_deadline(&candidate);
+
+		Ok((deadline.is_some(), deadline.unwrap_or_default().into()))
+	}
+
 	#[precompile::public("candidateExitIsPending(address)")]
 	#[precompile::public("candidate_exit_is_pending(address)")]
 	#[precompile::view]
@@ -768,6 +857,31 @@ where
 		Ok(())
 	}
 
+	#[precompile::public("switchDelegation(address,address,uint256)")]
+	fn switch_delegation(
+		handle: &mut impl PrecompileHandle,
+		old_candidate: Address,
+		new_candidate: Address,
+		amount: U256,
+	) -> EvmResult {
+		let old_candidate = Runtime::AddressMapping::into_account_id(old_candidate.0);
+		let new_candidate = Runtime::AddressMapping::into_account_id(new_candidate.0);
+		let amount = Self::u256_to_amount(amount).in_field("amount")?;
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::switch_delegation {
+			old_candidate,
+			new_candidate,
+			amount,
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
 	#[precompile::public("scheduleDelegatorBondLess(address,uint256)")]
 	#[precompile::public("schedule_delegator_bond_less(address,uint256)")]
 	fn schedule_delegator_bond_less(
@@ -871,6 +985,47 @@ where
 		Ok(())
 	}
 
+	#[precompile::public("forceLeaveCandidates(address,uint256,uint256)")]
+	fn force_leave_candidates(
+		handle: &mut impl PrecompileHandle,
+		candidate: Address,
+		candidate_count: Convert<U256, u32>,
+		candidate_delegation_count: Convert<U256, u32>,
+	) -> EvmResult {
+		let origin = Self::ensure_governance(handle)?;
+		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
+		let call = pallet_parachain_staking::Call::<Runtime>::force_leave_candidates {
+			candidate,
+			candidate_count: candidate_count.converted(),
+			candidate_delegation_count: candidate_delegation_count.converted(),
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("forceCancelDelegationRequest(address,address)")]
+	fn force_cancel_delegation_request(
+		handle: &mut impl PrecompileHandle,
+		delegator: Address,
+		candidate: Address,
+	) -> EvmResult {
+		let origin = Self::ensure_governance(handle)?;
+		let delegator = Runtime::AddressMapping::into_account_id(delegator.0);
+		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
+		let call = pallet_parachain_staking::Call::<Runtime>::force_cancel_delegation_request {
+			delegator,
+			candidate,
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call, 0)?;
+
+		Ok(())
+	}
+
 	#[precompile::public("getDelegatorTotalStaked(address)")]
 	#[precompile::view]
 	fn get_delegator_total_staked(
@@ -911,9 +1066,80 @@ where
 		Ok(amount.into())
 	}
 
+	/// Returns the root origin if the caller is the governance address, reverts otherwise.
+	fn ensure_governance(
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin> {
+		if handle.context().caller != GOVERNANCE_ADDRESS {
+			return Err(revert("Caller is not the governance address"));
+		}
+
+		Ok(frame_system::RawOrigin::Root.into())
+	}
+
 	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
 		value
 			.try_into()
 			.map_err(|_| RevertReason::value_is_too_large("balance type").into())
 	}
 }
+
+/// Lists the storage keys read by the views most often called in a loop, so that they can be
+/// preloaded. The proof sizes match the ones recorded by the views.
+impl<Runtime> StorageAccessList for ParachainStakingPrecompile<Runtime>
+where
+	Runtime: pallet_parachain_staking::Config + pallet_evm::Config,
+	Runtime::AccountId: Into<H160>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		From<frame_system::RawOrigin<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<pallet_parachain_staking::Call<Runtime>>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256> + solidity::Codec,
+	<Runtime as frame_system::Config>::BlockNumber: Into<U256>,
+{
+	fn storage_access_list(handle: &mut impl PrecompileHandle) -> Vec<StorageAccess> {
+		let call = match ParachainStakingPrecompileCall::<Runtime>::parse_call_data(handle) {
+			Ok(call) => call,
+			Err(_) => return Vec::new(),
+		};
+
+		let max_delegations =
+			<Runtime as pallet_parachain_staking::Config>::MaxDelegationsPerDelegator::get();
+		let delegator_state = |delegator: Address| {
+			let delegator = Runtime::AddressMapping::into_account_id(delegator.0);
+			StorageAccess {
+				key: pallet_parachain_staking::Pallet::<Runtime>::delegator_state_key(&delegator),
+				// Twox64Concat(8) + AccountId(20) + Delegator(56 + MaxDelegationsPerDelegator)
+				proof_size: 84 + max_delegations as u64,
+			}
+		};
+		let candidate_info = |candidate: Address| {
+			let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
+			StorageAccess {
+				key: pallet_parachain_staking::Pallet::<Runtime>::candidate_info_key(&candidate),
+				// Twox64Concat(8) + AccountId(20) + CandidateMetadata(105)
+				proof_size: 133,
+			}
+		};
+
+		match call {
+			ParachainStakingPrecompileCall::is_delegator { delegator }
+			| ParachainStakingPrecompileCall::delegator_delegation_count { delegator } => {
+				sp_std::vec![delegator_state(delegator)]
+			}
+			ParachainStakingPrecompileCall::is_candidate { candidate }
+			| ParachainStakingPrecompileCall::candidate_delegation_count { candidate }
+			| ParachainStakingPrecompileCall::candidate_exit_is_pending { candidate }
+			| ParachainStakingPrecompileCall::candidate_request_is_pending { candidate } => {
+				sp_std::vec![candidate_info(candidate)]
+			}
+			ParachainStakingPrecompileCall::round {} => sp_std::vec![StorageAccess {
+				key: pallet_parachain_staking::Pallet::<Runtime>::round_key(),
+				// RoundInfo(RoundIndex(4) + BlockNumber(4) + 4)
+				proof_size: 12,
+			}],
+			_ => Vec::new(),
+		}
+	}
+}
@@ -181,8 +181,11 @@ parameter_types! {
 	pub const MaxBottomDelegationsPerCandidate: u32 = 4;
 	pub const MaxDelegationsPerDelegator: u32 = 4;
 	pub const MinCandidateStk: u128 = 10;
+	pub const MinCandidateStkGracePeriod: u32 = 2;
 	pub const MinDelegation: u128 = 3;
 	pub const MaxCandidates: u32 = 10;
+	pub const MaxExitQueueWeight: Weight = Weight::zero();
+	pub const MaxDelegationSwitchesPerRound: u32 = 2;
 	pub BlockAuthor: AccountId = Alice.into();
 }
 impl pallet_parachain_staking::Config for Runtime {
@@ -201,6 +204,7 @@ impl pallet_parachain_staking::Config for Runtime {
 	type MaxBottomDelegationsPerCandidate = MaxBottomDelegationsPerCandidate;
 	type MaxDelegationsPerDelegator = MaxDelegationsPerDelegator;
 	type MinCandidateStk = MinCandidateStk;
+	type MinCandidateStkGracePeriod = MinCandidateStkGracePeriod;
 	type MinDelegation = MinDelegation;
 	type BlockAuthor = BlockAuthor;
 	type PayoutCollatorReward = ();
@@ -208,6 +212,8 @@ impl pallet_parachain_staking::Config for Runtime {
 	type OnNewRound = ();
 	type WeightInfo = ();
 	type MaxCandidates = MaxCandidates;
+	type MaxExitQueueWeight = MaxExitQueueWeight;
+	type MaxDelegationSwitchesPerRound = MaxDelegationSwitchesPerRound;
 }
 
 pub(crate) struct ExtBuilder {
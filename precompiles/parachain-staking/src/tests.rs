@@ -18,12 +18,18 @@ use crate::mock::{
 	events, roll_to, roll_to_round_begin, set_points, ExtBuilder, PCall, ParachainStaking,
 	Precompiles, PrecompilesValue, Runtime, RuntimeCall, RuntimeOrigin,
 };
+use crate::ParachainStakingPrecompile;
 use core::str::from_utf8;
+use fp_evm::Context;
 use frame_support::sp_runtime::Percent;
 use frame_support::{assert_ok, dispatch::Dispatchable};
 use pallet_evm::Call as EvmCall;
 use pallet_parachain_staking::Event as StakingEvent;
-use precompile_utils::{prelude::*, testing::*};
+use precompile_utils::{
+	precompile_set::{StorageAccess, StorageAccessList},
+	prelude::*,
+	testing::*,
+};
 use sp_core::{H160, U256};
 
 fn precompiles() -> Precompiles<Runtime> {
@@ -49,17 +55,21 @@ fn selectors() {
 	assert!(PCall::is_delegator_selectors().contains(&0xfd8ab482));
 	assert!(PCall::is_candidate_selectors().contains(&0xd51b9e93));
 	assert!(PCall::is_selected_candidate_selectors().contains(&0x740d7d2a));
+	assert!(PCall::is_selected_for_next_round_selectors().contains(&0x54c29876));
 	assert!(PCall::delegation_amount_selectors().contains(&0xa73e51bc));
 	assert!(PCall::is_in_top_delegations_selectors().contains(&0x91cc8657));
 	assert!(PCall::points_selectors().contains(&0x9799b4e7));
 	assert!(PCall::min_delegation_selectors().contains(&0x02985992));
 	assert!(PCall::candidate_count_selectors().contains(&0xa9a981a3));
 	assert!(PCall::round_selectors().contains(&0x146ca531));
+	assert!(PCall::round_info_selectors().contains(&0xcace12e6));
 	assert!(PCall::candidate_delegation_count_selectors().contains(&0x2ec087eb));
 	assert!(PCall::candidate_auto_compounding_delegation_count_selectors().contains(&0x905f0806));
 	assert!(PCall::delegator_delegation_count_selectors().contains(&0x067ec822));
 	assert!(PCall::selected_candidates_selectors().contains(&0xbcf868a6));
 	assert!(PCall::delegation_request_is_pending_selectors().contains(&0x3b16def8));
+	assert!(PCall::delegation_exit_queue_position_selectors().contains(&0x3e2811bc));
+	assert!(PCall::candidate_bond_top_up_deadline_selectors().contains(&0x8aafb492));
 	assert!(PCall::candidate_exit_is_pending_selectors().contains(&0x43443682));
 	assert!(PCall::candidate_request_is_pending_selectors().contains(&0xd0deec11));
 	assert!(PCall::join_candidates_selectors().contains(&0x1f2f83ad));
@@ -75,9 +85,12 @@ fn selectors() {
 	assert!(PCall::delegate_selectors().contains(&0x829f5ee3));
 	assert!(PCall::schedule_revoke_delegation_selectors().contains(&0x1a1c740c));
 	assert!(PCall::delegator_bond_more_selectors().contains(&0x0465135b));
+	assert!(PCall::switch_delegation_selectors().contains(&0x76c9c705));
 	assert!(PCall::schedule_delegator_bond_less_selectors().contains(&0xc172fd2b));
 	assert!(PCall::execute_delegation_request_selectors().contains(&0xe98c8abe));
 	assert!(PCall::cancel_delegation_request_selectors().contains(&0xc90eee83));
+	assert!(PCall::force_leave_candidates_selectors().contains(&0xe51d4743));
+	assert!(PCall::force_cancel_delegation_request_selectors().contains(&0x0fb29d62));
 	assert!(PCall::get_delegator_total_staked_selectors().contains(&0xe6861713));
 	assert!(PCall::get_candidate_total_counted_selectors().contains(&0xbc5a1043));
 }
@@ -90,16 +103,20 @@ fn modifiers() {
 		tester.test_view_modifier(PCall::is_delegator_selectors());
 		tester.test_view_modifier(PCall::is_candidate_selectors());
 		tester.test_view_modifier(PCall::is_selected_candidate_selectors());
+		tester.test_view_modifier(PCall::is_selected_for_next_round_selectors());
 		tester.test_view_modifier(PCall::points_selectors());
 		tester.test_view_modifier(PCall::delegation_amount_selectors());
 		tester.test_view_modifier(PCall::is_in_top_delegations_selectors());
 		tester.test_view_modifier(PCall::min_delegation_selectors());
 		tester.test_view_modifier(PCall::candidate_count_selectors());
 		tester.test_view_modifier(PCall::round_selectors());
+		tester.test_view_modifier(PCall::round_info_selectors());
 		tester.test_view_modifier(PCall::candidate_delegation_count_selectors());
 		tester.test_view_modifier(PCall::delegator_delegation_count_selectors());
 		tester.test_view_modifier(PCall::selected_candidates_selectors());
 		tester.test_view_modifier(PCall::delegation_request_is_pending_selectors());
+		tester.test_view_modifier(PCall::delegation_exit_queue_position_selectors());
+		tester.test_view_modifier(PCall::candidate_bond_top_up_deadline_selectors());
 		tester.test_view_modifier(PCall::candidate_exit_is_pending_selectors());
 		tester.test_view_modifier(PCall::candidate_request_is_pending_selectors());
 		tester.test_default_modifier(PCall::join_candidates_selectors());
@@ -115,9 +132,12 @@ fn modifiers() {
 		tester.test_default_modifier(PCall::delegate_selectors());
 		tester.test_default_modifier(PCall::schedule_revoke_delegation_selectors());
 		tester.test_default_modifier(PCall::delegator_bond_more_selectors());
+		tester.test_default_modifier(PCall::switch_delegation_selectors());
 		tester.test_default_modifier(PCall::schedule_delegator_bond_less_selectors());
 		tester.test_default_modifier(PCall::execute_delegation_request_selectors());
 		tester.test_default_modifier(PCall::cancel_delegation_request_selectors());
+		tester.test_default_modifier(PCall::force_leave_candidates_selectors());
+		tester.test_default_modifier(PCall::force_cancel_delegation_request_selectors());
 		tester.test_view_modifier(PCall::get_delegator_total_staked_selectors());
 		tester.test_view_modifier(PCall::get_candidate_total_counted_selectors());
 	});
@@ -381,6 +401,31 @@ fn round_works() {
 	});
 }
 
+#[test]
+fn round_info_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::round_info {})
+			.expect_cost(0) // TODO: Test db read/write costs
+			.expect_no_logs()
+			.execute_returns((1u32, U256::zero(), 5u32, U256::from(4)));
+
+		roll_to_round_begin(2);
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::round_info {})
+			.expect_cost(0) // TODO: Test db read/write costs
+			.expect_no_logs()
+			.execute_returns((2u32, U256::from(5), 5u32, U256::from(5)));
+
+		roll_to(8);
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::round_info {})
+			.expect_cost(0) // TODO: Test db read/write costs
+			.expect_no_logs()
+			.execute_returns((2u32, U256::from(5), 5u32, U256::from(2)));
+	});
+}
+
 #[test]
 fn candidate_delegation_count_works() {
 	ExtBuilder::default()
@@ -554,6 +599,51 @@ fn is_delegator_true() {
 		});
 }
 
+#[test]
+fn storage_access_list_declares_the_keys_read_by_views() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut handle = MockHandle::new(
+			Precompile1.into(),
+			Context {
+				address: Precompile1.into(),
+				caller: Alice.into(),
+				apparent_value: U256::zero(),
+			},
+		);
+
+		handle.input = PCall::is_delegator {
+			delegator: Address(Charlie.into()),
+		}
+		.into();
+		assert_eq!(
+			ParachainStakingPrecompile::<Runtime>::storage_access_list(&mut handle),
+			vec![StorageAccess {
+				key: ParachainStaking::delegator_state_key(&Charlie.into()),
+				proof_size: 88,
+			}]
+		);
+
+		handle.input = PCall::is_candidate {
+			candidate: Address(Charlie.into()),
+		}
+		.into();
+		assert_eq!(
+			ParachainStakingPrecompile::<Runtime>::storage_access_list(&mut handle),
+			vec![StorageAccess {
+				key: ParachainStaking::candidate_info_key(&Charlie.into()),
+				proof_size: 133,
+			}]
+		);
+
+		// Calls which are not preloaded.
+		handle.input = PCall::candidate_count {}.into();
+		assert_eq!(
+			ParachainStakingPrecompile::<Runtime>::storage_access_list(&mut handle),
+			vec![]
+		);
+	});
+}
+
 #[test]
 fn is_candidate_false() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -634,6 +724,56 @@ fn is_selected_candidate_true() {
 		});
 }
 
+#[test]
+fn is_selected_for_next_round_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::join_candidates(
+				RuntimeOrigin::signed(Bob.into()),
+				1_000,
+				1
+			));
+
+			// Bob is not part of the current active set, but will be selected next round
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::is_selected_candidate {
+						candidate: Address(Bob.into()),
+					},
+				)
+				.expect_cost(0) // TODO: Test db read/write costs
+				.expect_no_logs()
+				.execute_returns(false);
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::is_selected_for_next_round {
+						candidate: Address(Bob.into()),
+					},
+				)
+				.expect_cost(0) // TODO: Test db read/write costs
+				.expect_no_logs()
+				.execute_returns(true);
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::is_selected_for_next_round {
+						candidate: Address(Charlie.into()),
+					},
+				)
+				.expect_cost(0) // TODO: Test db read/write costs
+				.expect_no_logs()
+				.execute_returns(false);
+		});
+}
+
 #[test]
 fn selected_candidates_works() {
 	ExtBuilder::default()
@@ -723,6 +863,78 @@ fn delegation_request_is_pending_returns_false_for_non_existing_delegator() {
 	})
 }
 
+#[test]
+fn delegation_exit_queue_position_works() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(Alice.into(), 1_000),
+			(Charlie.into(), 50),
+			(David.into(), 50),
+		])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.with_delegations(vec![
+			(Charlie.into(), Alice.into(), 50),
+			(David.into(), Alice.into(), 50),
+		])
+		.build()
+		.execute_with(|| {
+			// Not queued
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::delegation_exit_queue_position {
+						delegator: Address(David.into()),
+						candidate: Address(Alice.into()),
+					},
+				)
+				.expect_cost(0) // TODO: Test db read/write costs
+				.expect_no_logs()
+				.execute_returns((false, U256::zero()));
+
+			for delegator in [Charlie, David] {
+				assert_ok!(ParachainStaking::schedule_revoke_delegation(
+					RuntimeOrigin::signed(delegator.into()),
+					Alice.into()
+				));
+			}
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::delegation_exit_queue_position {
+						delegator: Address(David.into()),
+						candidate: Address(Alice.into()),
+					},
+				)
+				.expect_cost(0) // TODO: Test db read/write costs
+				.expect_no_logs()
+				.execute_returns((true, U256::one()));
+		})
+}
+
+#[test]
+fn candidate_bond_top_up_deadline_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::candidate_bond_top_up_deadline {
+						candidate: Address(Alice.into()),
+					},
+				)
+				.expect_cost(0) // TODO: Test db read/write costs
+				.expect_no_logs()
+				.execute_returns((false, U256::zero()));
+		})
+}
+
 #[test]
 fn candidate_exit_is_pending_works() {
 	ExtBuilder::default()
@@ -1281,6 +1493,40 @@ fn delegator_bond_more_works() {
 		});
 }
 
+#[test]
+fn switch_delegation_works() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(Alice.into(), 1_000),
+			(Bob.into(), 500),
+			(Charlie.into(), 1_000),
+		])
+		.with_candidates(vec![(Alice.into(), 1_000), (Charlie.into(), 1_000)])
+		.with_delegations(vec![(Bob.into(), Alice.into(), 500)])
+		.build()
+		.execute_with(|| {
+			let input_data = PCall::switch_delegation {
+				old_candidate: Address(Alice.into()),
+				new_candidate: Address(Charlie.into()),
+				amount: 200.into(),
+			}
+			.into();
+
+			assert_ok!(RuntimeCall::Evm(evm_call(Bob, input_data)).dispatch(RuntimeOrigin::root()));
+
+			let expected: crate::mock::RuntimeEvent = StakingEvent::DelegationSwitched {
+				delegator: Bob.into(),
+				old_candidate: Alice.into(),
+				new_candidate: Charlie.into(),
+				amount: 200,
+				in_top: true,
+			}
+			.into();
+			// Assert that the events vector contains the one expected
+			assert!(events().contains(&expected));
+		});
+}
+
 #[test]
 fn schedule_delegator_bond_less_works() {
 	ExtBuilder::default()
@@ -1456,6 +1702,126 @@ fn cancel_delegator_bonded_less_works() {
 		});
 }
 
+#[test]
+fn force_leave_candidates_reverts_for_non_governance_caller() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::force_leave_candidates {
+						candidate: Address(Alice.into()),
+						candidate_count: 1.into(),
+						candidate_delegation_count: 0.into(),
+					},
+				)
+				.execute_reverts(|output| output == b"Caller is not the governance address");
+
+			assert!(ParachainStaking::is_candidate(&Alice.into()));
+		});
+}
+
+#[test]
+fn force_leave_candidates_works_from_governance_address() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Bob.into(), 500)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.with_delegations(vec![(Bob.into(), Alice.into(), 500)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					crate::GOVERNANCE_ADDRESS,
+					Precompile1,
+					PCall::force_leave_candidates {
+						candidate: Address(Alice.into()),
+						candidate_count: 1.into(),
+						candidate_delegation_count: 1.into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+
+			let expected: crate::mock::RuntimeEvent = StakingEvent::CandidateLeft {
+				ex_candidate: Alice.into(),
+				unlocked_amount: 1_500,
+				new_total_amt_locked: 0,
+			}
+			.into();
+			assert!(events().contains(&expected));
+			assert!(!ParachainStaking::is_candidate(&Alice.into()));
+			assert!(!ParachainStaking::is_delegator(&Bob.into()));
+		});
+}
+
+#[test]
+fn force_cancel_delegation_request_reverts_for_non_governance_caller() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.with_delegations(vec![(Bob.into(), Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(
+				RuntimeOrigin::signed(Bob.into()),
+				Alice.into()
+			));
+
+			precompiles()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::force_cancel_delegation_request {
+						delegator: Address(Bob.into()),
+						candidate: Address(Alice.into()),
+					},
+				)
+				.execute_reverts(|output| output == b"Caller is not the governance address");
+		});
+}
+
+#[test]
+fn force_cancel_delegation_request_works_from_governance_address() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.with_delegations(vec![(Bob.into(), Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(
+				RuntimeOrigin::signed(Bob.into()),
+				Alice.into()
+			));
+
+			precompiles()
+				.prepare_test(
+					crate::GOVERNANCE_ADDRESS,
+					Precompile1,
+					PCall::force_cancel_delegation_request {
+						delegator: Address(Bob.into()),
+						candidate: Address(Alice.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+
+			let expected: crate::mock::RuntimeEvent = StakingEvent::CancelledDelegationRequest {
+				delegator: Bob.into(),
+				collator: Alice.into(),
+				cancelled_request: pallet_parachain_staking::CancelledScheduledRequest {
+					when_executable: 3,
+					action: pallet_parachain_staking::DelegationAction::Revoke(1_000),
+				},
+			}
+			.into();
+			assert!(events().contains(&expected));
+		});
+}
+
 #[test]
 fn delegate_with_auto_compound_works() {
 	for auto_compound_percent in [0, 50, 100] {
@@ -153,6 +153,12 @@ impl pallet_timestamp::Config for Runtime {
 	type WeightInfo = ();
 }
 
+impl pallet_precompile_benchmarks::Config for Runtime {
+	type PrecompileRegistryAddress = AddressU64<1>;
+	type ParachainStakingAddress = AddressU64<2048>;
+	type WeightInfo = ();
+}
+
 pub(crate) struct ExtBuilder {
 	// endowed accounts with balances
 	balances: Vec<(AccountId, Balance)>,
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @author The Moonbeam Team
+/// @title Chainlink AggregatorV3Interface
+/// @dev see https://docs.chain.link/data-feeds/api-reference
+/// @dev Each feed of the price oracle is exposed at the address made of the price feeds prefix
+/// followed by 12 zero bytes and the feed id. Only the latest round of a feed is available.
+interface AggregatorV3Interface {
+    /// @dev Returns the number of decimals of the answers.
+    /// @custom:selector 313ce567
+    function decimals() external view returns (uint8);
+
+    /// @dev Returns the description of the feed, such as "DOT / USD".
+    /// @custom:selector 7284e416
+    function description() external view returns (string memory);
+
+    /// @dev Returns the version of the aggregator implementation.
+    /// @custom:selector 54fd4d50
+    function version() external view returns (uint256);
+
+    /// @dev Returns the data of a round. Reverts unless it is the latest round.
+    /// @custom:selector 9a6fc8f5
+    /// @param _roundId The id of the round
+    /// @return roundId The id of the round
+    /// @return answer The median of the answers submitted during the round
+    /// @return startedAt The time at which the round started, in seconds
+    /// @return updatedAt The time at which the round completed, in seconds
+    /// @return answeredInRound The id of the round in which the answer was computed
+    function getRoundData(uint80 _roundId)
+        external
+        view
+        returns (
+            uint80 roundId,
+            int256 answer,
+            uint256 startedAt,
+            uint256 updatedAt,
+            uint80 answeredInRound
+        );
+
+    /// @dev Returns the data of the latest round. Reverts if no round completed yet.
+    /// @custom:selector feaf968c
+    /// @return roundId The id of the round
+    /// @return answer The median of the answers submitted during the round
+    /// @return startedAt The time at which the round started, in seconds
+    /// @return updatedAt The time at which the round completed, in seconds
+    /// @return answeredInRound The id of the round in which the answer was computed
+    function latestRoundData()
+        external
+        view
+        returns (
+            uint80 roundId,
+            int256 answer,
+            uint256 startedAt,
+            uint256 updatedAt,
+            uint80 answeredInRound
+        );
+}
@@ -0,0 +1,191 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile set exposing the feeds of pallet-price-oracle through the Chainlink
+//! `AggregatorV3Interface`.
+//!
+//! The feed with id X is exposed at the address made of a runtime-defined 4 bytes prefix, followed
+//! by 12 zero bytes and the 4 big-endian bytes of X. Only the latest round of a feed is kept, such
+//! that `getRoundData` only returns the data of the latest round.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use pallet_price_oracle::{FeedConfigOf, FeedId, RoundData};
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::{H160, U256};
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Version of the aggregator interface implementation, as returned by `version()`.
+pub const AGGREGATOR_VERSION: u8 = 1;
+
+/// Address of the feed `feed_id` for the 4 bytes address `prefix`.
+pub fn feed_address(prefix: &[u8], feed_id: FeedId) -> H160 {
+	let mut address = [0u8; 20];
+	address[0..4].copy_from_slice(prefix);
+	address[16..20].copy_from_slice(&feed_id.to_be_bytes());
+	H160(address)
+}
+
+/// Two's complement representation of an answer, as expected for a Solidity `int256`.
+pub fn int256(value: i128) -> U256 {
+	if value >= 0 {
+		U256::from(value as u128)
+	} else {
+		// !(-value - 1) == value in two's complement, without overflowing for i128::MIN
+		!U256::from((-(value + 1)) as u128)
+	}
+}
+
+/// Round data as returned by `latestRoundData` and `getRoundData`: the round id, the answer, the
+/// time at which the round started, the time at which it completed and the round in which the
+/// answer was computed.
+type RoundDataOutput = (U256, U256, U256, U256, U256);
+
+/// The precompile for FeedId X, where X is a u32 (i.e. 4 bytes), is Prefix + 12 zero bytes +
+/// Bytes(X). In order to route the address to PriceFeedsPrecompileSet<R>, we check whether the
+/// feed exists in pallet-price-oracle.
+pub struct PriceFeedsPrecompileSet<Runtime>(PhantomData<Runtime>);
+
+impl<T> Clone for PriceFeedsPrecompileSet<T> {
+	fn clone(&self) -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T> Default for PriceFeedsPrecompileSet<T> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<Runtime> PriceFeedsPrecompileSet<Runtime> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+#[precompile_utils::precompile]
+#[precompile::precompile_set]
+#[precompile::test_concrete_types(mock::Runtime)]
+impl<Runtime> PriceFeedsPrecompileSet<Runtime>
+where
+	Runtime: pallet_price_oracle::Config + pallet_evm::Config,
+{
+	/// PrecompileSet discriminant. Allows to knows if the address maps to a feed id,
+	/// and if this is the case which one.
+	#[precompile::discriminant]
+	fn discriminant(address: H160, gas: u64) -> DiscriminantResult<FeedId> {
+		let extra_cost = RuntimeHelper::<Runtime>::db_read_gas_cost();
+		if gas < extra_cost {
+			return DiscriminantResult::OutOfGas;
+		}
+
+		let (padding, id_part) = address.as_bytes()[4..].split_at(12);
+		if padding.iter().any(|byte| *byte != 0) {
+			return DiscriminantResult::None(extra_cost);
+		}
+
+		let mut data = [0u8; 4];
+		data.copy_from_slice(id_part);
+		let feed_id = FeedId::from_be_bytes(data);
+
+		if pallet_price_oracle::Feeds::<Runtime>::contains_key(feed_id) {
+			DiscriminantResult::Some(feed_id, extra_cost)
+		} else {
+			DiscriminantResult::None(extra_cost)
+		}
+	}
+
+	#[precompile::public("decimals()")]
+	#[precompile::view]
+	fn decimals(feed_id: FeedId, handle: &mut impl PrecompileHandle) -> EvmResult<u8> {
+		Ok(Self::feed(feed_id, handle)?.decimals)
+	}
+
+	#[precompile::public("description()")]
+	#[precompile::view]
+	fn description(
+		feed_id: FeedId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<UnboundedBytes> {
+		Ok(Self::feed(feed_id, handle)?.description.into_inner().into())
+	}
+
+	#[precompile::public("version()")]
+	#[precompile::view]
+	fn version(_feed_id: FeedId, _handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		Ok(AGGREGATOR_VERSION.into())
+	}
+
+	#[precompile::public("getRoundData(uint80)")]
+	#[precompile::view]
+	fn get_round_data(
+		feed_id: FeedId,
+		handle: &mut impl PrecompileHandle,
+		round_id: U256,
+	) -> EvmResult<RoundDataOutput> {
+		let round = Self::latest_round(feed_id, handle)?;
+		if U256::from(round.round_id) != round_id {
+			return Err(revert("Only the latest round is available"));
+		}
+
+		Ok(Self::round_data_output(round))
+	}
+
+	#[precompile::public("latestRoundData()")]
+	#[precompile::view]
+	fn latest_round_data(
+		feed_id: FeedId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<RoundDataOutput> {
+		Self::latest_round(feed_id, handle).map(Self::round_data_output)
+	}
+
+	fn feed(
+		feed_id: FeedId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<FeedConfigOf<Runtime>> {
+		// Storage item: Feeds: Twox64(8) + FeedId(4) + FeedConfig
+		handle.record_db_read::<Runtime>(12 + FeedConfigOf::<Runtime>::max_encoded_len())?;
+
+		pallet_price_oracle::Pallet::<Runtime>::feed(feed_id).ok_or_else(|| revert("Unknown feed"))
+	}
+
+	fn latest_round(feed_id: FeedId, handle: &mut impl PrecompileHandle) -> EvmResult<RoundData> {
+		// Storage item: LatestRound: Twox64(8) + FeedId(4) + RoundData
+		handle.record_db_read::<Runtime>(12 + RoundData::max_encoded_len())?;
+
+		pallet_price_oracle::Pallet::<Runtime>::latest_round(feed_id)
+			.ok_or_else(|| revert("No data present"))
+	}
+
+	fn round_data_output(round: RoundData) -> RoundDataOutput {
+		(
+			round.round_id.into(),
+			int256(round.answer),
+			round.started_at.into(),
+			round.updated_at.into(),
+			round.round_id.into(),
+		)
+	}
+}
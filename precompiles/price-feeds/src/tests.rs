@@ -0,0 +1,199 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use frame_support::assert_ok;
+use precompile_utils::{prelude::*, testing::*};
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// Unix time in milliseconds at which the rounds complete in the tests.
+const NOW: u64 = 1_700_000_000_000;
+
+/// Creates feed 1 with 8 decimals, whose rounds complete with the answers of Alice and Bob.
+fn create_feed() {
+	assert_ok!(PriceOracle::create_feed(
+		RuntimeOrigin::root(),
+		1,
+		8,
+		b"DOT / USD".to_vec().try_into().unwrap(),
+		vec![Alice.into(), Bob.into()].try_into().unwrap(),
+		2
+	));
+}
+
+fn complete_round(answers: [i128; 2]) {
+	Timestamp::set_timestamp(NOW);
+	assert_ok!(PriceOracle::submit(
+		RuntimeOrigin::signed(Alice.into()),
+		1,
+		answers[0]
+	));
+	assert_ok!(PriceOracle::submit(
+		RuntimeOrigin::signed(Bob.into()),
+		1,
+		answers[1]
+	));
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["AggregatorV3Interface.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::decimals_selectors().contains(&0x313ce567));
+	assert!(PCall::description_selectors().contains(&0x7284e416));
+	assert!(PCall::version_selectors().contains(&0x54fd4d50));
+	assert!(PCall::get_round_data_selectors().contains(&0x9a6fc8f5));
+	assert!(PCall::latest_round_data_selectors().contains(&0xfeaf968c));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_feed();
+
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, PriceFeed(1));
+
+		tester.test_view_modifier(PCall::decimals_selectors());
+		tester.test_view_modifier(PCall::description_selectors());
+		tester.test_view_modifier(PCall::version_selectors());
+		tester.test_view_modifier(PCall::get_round_data_selectors());
+		tester.test_view_modifier(PCall::latest_round_data_selectors());
+	});
+}
+
+#[test]
+fn feed_address_matches_prefixed_set() {
+	assert_eq!(
+		feed_address(&[0xff, 0xff, 0xff, 0xfc], 1),
+		H160::from(PriceFeed(1))
+	);
+}
+
+#[test]
+fn feed_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_feed();
+
+		precompiles()
+			.prepare_test(Alice, PriceFeed(1), PCall::decimals {})
+			.expect_no_logs()
+			.execute_returns(8u8);
+
+		precompiles()
+			.prepare_test(Alice, PriceFeed(1), PCall::description {})
+			.expect_no_logs()
+			.execute_returns(UnboundedBytes::from("DOT / USD"));
+
+		precompiles()
+			.prepare_test(Alice, PriceFeed(1), PCall::version {})
+			.expect_no_logs()
+			.execute_returns(U256::from(AGGREGATOR_VERSION));
+	});
+}
+
+#[test]
+fn latest_round_data_returns_median_of_answers() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_feed();
+		complete_round([100, 300]);
+
+		let timestamp = U256::from(NOW / 1000);
+		precompiles()
+			.prepare_test(Alice, PriceFeed(1), PCall::latest_round_data {})
+			.expect_no_logs()
+			.execute_returns((
+				U256::one(),
+				U256::from(200),
+				timestamp,
+				timestamp,
+				U256::one(),
+			));
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				PriceFeed(1),
+				PCall::get_round_data {
+					round_id: U256::one(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns((
+				U256::one(),
+				U256::from(200),
+				timestamp,
+				timestamp,
+				U256::one(),
+			));
+	});
+}
+
+#[test]
+fn negative_answers_are_encoded_as_int256() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_feed();
+		complete_round([-3, -1]);
+
+		let timestamp = U256::from(NOW / 1000);
+		precompiles()
+			.prepare_test(Alice, PriceFeed(1), PCall::latest_round_data {})
+			.expect_no_logs()
+			.execute_returns((
+				U256::one(),
+				U256::MAX - U256::one(),
+				timestamp,
+				timestamp,
+				U256::one(),
+			));
+	});
+
+	assert_eq!(int256(-1), U256::MAX);
+	assert_eq!(int256(i128::MIN), U256::MAX - U256::from(i128::MAX as u128));
+	assert_eq!(int256(i128::MAX), U256::from(i128::MAX as u128));
+}
+
+#[test]
+fn round_data_reverts_when_unavailable() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_feed();
+
+		precompiles()
+			.prepare_test(Alice, PriceFeed(1), PCall::latest_round_data {})
+			.expect_no_logs()
+			.execute_reverts(|output| output == b"No data present");
+
+		complete_round([100, 300]);
+		precompiles()
+			.prepare_test(
+				Alice,
+				PriceFeed(1),
+				PCall::get_round_data {
+					round_id: U256::from(2),
+				},
+			)
+			.expect_no_logs()
+			.execute_reverts(|output| output == b"Only the latest round is available");
+	});
+}
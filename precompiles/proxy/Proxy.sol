@@ -36,6 +36,38 @@ interface Proxy {
         uint32 delay
     ) external;
 
+    /// @dev Register a proxy account for `real`, authorised by an EIP-712 signature of `real`.
+    /// Can be submitted by any account, allowing `real` to add a proxy without paying for gas.
+    /// The signed message is:
+    /// AddProxy(address real,address delegate,uint8 proxyType,uint32 delay,uint256 nonce,uint256 deadline)
+    /// @custom:selector a0fe53a0
+    /// @param real The account that signed the message and for which the proxy is added
+    /// @param delegate The account that `real` would like to make a proxy
+    /// @param proxyType The permissions allowed for this proxy account
+    /// @param delay The announcement period required of the initial proxy, will generally be zero
+    /// @param deadline Timestamp (in seconds) after which the signature is no longer valid
+    /// @param signature The `r || s || v` signature of the message by `real`
+    function addProxyWithSignature(
+        address real,
+        address delegate,
+        ProxyType proxyType,
+        uint32 delay,
+        uint256 deadline,
+        bytes memory signature
+    ) external;
+
+    /// @dev Nonce of the next message of `real` accepted by `addProxyWithSignature`
+    /// @custom:selector 7ecebe00
+    /// @param real The account signing the messages
+    /// @return nonce The nonce to include in the next message
+    function nonces(address real) external view returns (uint256 nonce);
+
+    /// @dev EIP-712 domain separator of the messages accepted by `addProxyWithSignature`
+    /// @custom:selector 3644e515
+    /// @return separator The domain separator
+    // solhint-disable-next-line func-name-mixedcase
+    function DOMAIN_SEPARATOR() external view returns (bytes32 separator);
+
     /// @dev Removes a proxy account from the sender
     /// @custom:selector fef3f708
     /// @param delegate The account that the caller would like to remove as a proxy
@@ -77,6 +109,19 @@ interface Proxy {
         bytes memory callData
     ) external payable;
 
+    /// @dev Dispatch the given call (`data`) of a precompile from an account that the sender
+    /// is authorised for through `addProxy`. The call is allowed if the precompile and selector
+    /// pair is in the allow-list of the type of one of the proxies of the sender for `real`.
+    /// @custom:selector cad4ef36
+    /// @param real The account that the proxy will make a call on behalf of
+    /// @param precompile The precompile to call on behalf of the `real` account
+    /// @param data Data of the call to be made by the `real` account, starting with the selector
+    function proxyPrecompile(
+        address real,
+        address precompile,
+        bytes memory data
+    ) external;
+
     /// @dev Checks if the caller has an account proxied with a given proxy type
     /// @custom:selector e26d38ed
     /// @param real The real account that maybe has a proxy
@@ -18,19 +18,26 @@
 
 use evm::ExitReason;
 use fp_evm::{Context, PrecompileFailure, PrecompileHandle, Transfer};
-use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	ensure,
+	storage::types::{StorageMap, ValueQuery},
+	traits::StorageInstance,
+	Blake2_128Concat,
+};
 use pallet_balances::Call as BalancesCall;
 use pallet_evm::AddressMapping;
 use pallet_proxy::Call as ProxyCall;
 use pallet_proxy::Pallet as ProxyPallet;
 use precompile_utils::precompile_set::{self, AddressType, SelectorFilter};
 use precompile_utils::prelude::*;
-use sp_core::{Get, H160, U256};
+use sp_core::{Get, H160, H256, U256};
+use sp_io::hashing::keccak_256;
 use sp_runtime::{
 	codec::Decode,
 	traits::{ConstU32, StaticLookup, Zero},
 };
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 #[cfg(test)]
 mod mock;
@@ -42,8 +49,11 @@ pub struct OnlyIsProxy<Runtime>(PhantomData<Runtime>);
 
 impl<Runtime> SelectorFilter for OnlyIsProxy<Runtime>
 where
-	Runtime:
-		pallet_proxy::Config + pallet_evm::Config + frame_system::Config + pallet_balances::Config,
+	Runtime: pallet_proxy::Config
+		+ pallet_evm::Config
+		+ frame_system::Config
+		+ pallet_balances::Config
+		+ pallet_timestamp::Config,
 	<<Runtime as pallet_proxy::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
 		From<Option<Runtime::AccountId>>,
 	<Runtime as pallet_proxy::Config>::ProxyType: Decode + EvmProxyCallFilter,
@@ -54,6 +64,7 @@ where
 	<Runtime as frame_system::Config>::RuntimeCall:
 		From<ProxyCall<Runtime>> + From<BalancesCall<Runtime>>,
 	<Runtime as pallet_balances::Config<()>>::Balance: TryFrom<U256> + Into<U256>,
+	<Runtime as pallet_timestamp::Config>::Moment: Into<U256>,
 {
 	fn is_allowed(_caller: H160, selector: Option<u32>) -> bool {
 		match selector {
@@ -74,8 +85,11 @@ pub struct OnlyIsProxyAndProxy<Runtime>(PhantomData<Runtime>);
 
 impl<Runtime> SelectorFilter for OnlyIsProxyAndProxy<Runtime>
 where
-	Runtime:
-		pallet_proxy::Config + pallet_evm::Config + frame_system::Config + pallet_balances::Config,
+	Runtime: pallet_proxy::Config
+		+ pallet_evm::Config
+		+ frame_system::Config
+		+ pallet_balances::Config
+		+ pallet_timestamp::Config,
 	<<Runtime as pallet_proxy::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
 		From<Option<Runtime::AccountId>>,
 	<Runtime as pallet_proxy::Config>::ProxyType: Decode + EvmProxyCallFilter,
@@ -86,6 +100,7 @@ where
 	<Runtime as frame_system::Config>::RuntimeCall:
 		From<ProxyCall<Runtime>> + From<BalancesCall<Runtime>>,
 	<Runtime as pallet_balances::Config<()>>::Balance: TryFrom<U256> + Into<U256>,
+	<Runtime as pallet_timestamp::Config>::Moment: Into<U256>,
 {
 	fn is_allowed(_caller: H160, selector: Option<u32>) -> bool {
 		match selector {
@@ -95,17 +110,24 @@ where
 					|| ProxyPrecompileCall::<Runtime>::proxy_selectors().contains(&selector)
 					|| ProxyPrecompileCall::<Runtime>::proxy_force_type_selectors()
 						.contains(&selector)
+					|| ProxyPrecompileCall::<Runtime>::proxy_precompile_selectors()
+						.contains(&selector)
 			}
 		}
 	}
 
 	fn description() -> String {
-		"Allowed for all callers only for selectors 'is_proxy', 'proxy', 'proxy_force_type'".into()
+		"Allowed for all callers only for selectors 'is_proxy', 'proxy', 'proxy_force_type', \
+			'proxy_precompile'"
+			.into()
 	}
 }
 
 pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
 
+/// Length of a secp256k1 signature in its `r || s || v` form.
+pub const SIGNATURE_LENGTH: u32 = 65;
+
 type GetCallDataLimit = ConstU32<CALL_DATA_LIMIT>;
 
 pub struct EvmSubCall {
@@ -114,6 +136,36 @@ pub struct EvmSubCall {
 	pub call_data: BoundedBytes<ConstU32<CALL_DATA_LIMIT>>,
 }
 
+pub struct Nonces;
+
+impl StorageInstance for Nonces {
+	const STORAGE_PREFIX: &'static str = "Nonces";
+
+	fn pallet_prefix() -> &'static str {
+		"PrecompileProxy"
+	}
+}
+
+/// Nonces of the signed `addProxyWithSignature` messages, per real account.
+pub type NoncesStorage = StorageMap<
+	Nonces,
+	// Real
+	Blake2_128Concat,
+	H160,
+	// Nonce
+	U256,
+	ValueQuery,
+>;
+
+pub const ADD_PROXY_TYPEHASH: [u8; 32] = keccak256!(
+	"AddProxy(address real,address delegate,uint8 proxyType,uint32 delay,uint256 nonce\
+,uint256 deadline)"
+);
+
+const ADD_PROXY_DOMAIN: [u8; 32] = keccak256!(
+	"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+);
+
 /// A trait to filter if an evm subcall is allowed to be executed by a proxy account.
 /// This trait should be implemented by the `ProxyType` type configured in pallet proxy.
 pub trait EvmProxyCallFilter: Sized + Send + Sync {
@@ -127,6 +179,13 @@ pub trait EvmProxyCallFilter: Sized + Send + Sync {
 	) -> EvmResult<bool> {
 		Ok(false)
 	}
+
+	/// If returns `false`, then the call of the function `selector` of the precompile at
+	/// `precompile` through `proxyPrecompile` will not be executed and the evm transaction will
+	/// revert with error message "CallFiltered".
+	fn is_evm_proxy_precompile_call_allowed(&self, _precompile: H160, _selector: u32) -> bool {
+		false
+	}
 }
 
 /// A precompile to wrap the functionality from pallet-proxy.
@@ -135,8 +194,11 @@ pub struct ProxyPrecompile<Runtime>(PhantomData<Runtime>);
 #[precompile_utils::precompile]
 impl<Runtime> ProxyPrecompile<Runtime>
 where
-	Runtime:
-		pallet_proxy::Config + pallet_evm::Config + frame_system::Config + pallet_balances::Config,
+	Runtime: pallet_proxy::Config
+		+ pallet_evm::Config
+		+ frame_system::Config
+		+ pallet_balances::Config
+		+ pallet_timestamp::Config,
 	<<Runtime as pallet_proxy::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
 		From<Option<Runtime::AccountId>>,
 	<Runtime as pallet_proxy::Config>::ProxyType: Decode + EvmProxyCallFilter,
@@ -147,6 +209,7 @@ where
 	<Runtime as frame_system::Config>::RuntimeCall:
 		From<ProxyCall<Runtime>> + From<BalancesCall<Runtime>>,
 	<Runtime as pallet_balances::Config<()>>::Balance: TryFrom<U256> + Into<U256>,
+	<Runtime as pallet_timestamp::Config>::Moment: Into<U256>,
 {
 	/// Register a proxy account for the sender that is able to make calls on its behalf.
 	/// The dispatch origin for this call must be Signed.
@@ -162,44 +225,94 @@ where
 		proxy_type: u8,
 		delay: u32,
 	) -> EvmResult {
-		let delegate = Runtime::AddressMapping::into_account_id(delegate.into());
-		let proxy_type = Runtime::ProxyType::decode(&mut proxy_type.to_le_bytes().as_slice())
-			.map_err(|_| {
-				RevertReason::custom("Failed decoding value to ProxyType").in_field("proxyType")
-			})?;
-		let delay = delay.into();
-
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 
-		// Disallow re-adding proxy via precompile to prevent re-entrancy.
-		// See: https://github.com/PureStake/sr-moonbeam/issues/30
-		// Note: It is also assumed that EVM calls are only allowed through `Origin::Root` and
-		// filtered via CallFilter
-		// Proxies:
-		// Twox64Concat(8) + AccountId(20) + BoundedVec(ProxyDefinition * MaxProxies) + Balance(16)
-		handle.record_db_read::<Runtime>(
-			28 + (29 * (<Runtime as pallet_proxy::Config>::MaxProxies::get() as usize)) + 8,
-		)?;
-		if ProxyPallet::<Runtime>::proxies(&origin)
-			.0
-			.iter()
-			.any(|pd| pd.delegate == delegate)
-		{
-			return Err(revert("Cannot add more than one proxy"));
-		}
+		Self::inner_add_proxy(handle, origin, delegate, proxy_type, delay)
+	}
 
-		let delegate: <Runtime::Lookup as StaticLookup>::Source =
-			Runtime::Lookup::unlookup(delegate.clone());
-		let call: ProxyCall<Runtime> = ProxyCall::<Runtime>::add_proxy {
-			delegate,
+	/// Register a proxy account for `real`, authorised by an EIP-712 signature of `real`.
+	/// The call can be submitted by any account, allowing `real` to set up a proxy without paying
+	/// for gas.
+	///
+	/// Parameters:
+	/// * real: The account signing the message, for which the proxy is added.
+	/// * delegate: The account that `real` would like to make a proxy.
+	/// * proxy_type: The permissions allowed for this proxy account.
+	/// * delay: The announcement period required of the initial proxy. Will generally be zero.
+	/// * deadline: Timestamp (in seconds) after which the signature is no longer valid.
+	/// * signature: The `r || s || v` signature of the `AddProxy` message by `real`.
+	#[precompile::public("addProxyWithSignature(address,address,uint8,uint32,uint256,bytes)")]
+	fn add_proxy_with_signature(
+		handle: &mut impl PrecompileHandle,
+		real: Address,
+		delegate: Address,
+		proxy_type: u8,
+		delay: u32,
+		deadline: U256,
+		signature: BoundedBytes<ConstU32<SIGNATURE_LENGTH>>,
+	) -> EvmResult {
+		// Now: 8
+		handle.record_db_read::<Runtime>(8)?;
+		// NoncesStorage: Blake2_128(16) + real(20) + nonce(32)
+		handle.record_db_read::<Runtime>(68)?;
+		handle.record_cost(Self::signature_inherent_cost())?;
+
+		// pallet_timestamp is in ms while Ethereum use second timestamps.
+		let timestamp: U256 = (pallet_timestamp::Pallet::<Runtime>::get()).into() / 1000;
+		ensure!(deadline >= timestamp, revert("Signature expired"));
+
+		let real: H160 = real.into();
+		let nonce = NoncesStorage::get(real);
+
+		let message = Self::generate_add_proxy_message(
+			handle.context().address,
+			real,
+			delegate.into(),
 			proxy_type,
 			delay,
-		}
-		.into();
+			nonce,
+			deadline,
+		);
 
-		<RuntimeHelper<Runtime>>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		let signature: Vec<u8> = signature.into();
+		let signature: [u8; SIGNATURE_LENGTH as usize] = signature.try_into().map_err(|_| {
+			RevertReason::custom("Expected a 65 bytes signature").in_field("signature")
+		})?;
 
-		Ok(())
+		let signer = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &message)
+			.map_err(|_| revert("Invalid signature"))?;
+		let signer = H160::from(H256::from_slice(keccak_256(&signer).as_slice()));
+
+		ensure!(
+			signer != H160::zero() && signer == real,
+			revert("Invalid signature")
+		);
+
+		NoncesStorage::insert(real, nonce + U256::one());
+
+		let origin = Runtime::AddressMapping::into_account_id(real);
+
+		Self::inner_add_proxy(handle, origin, delegate, proxy_type, delay)
+	}
+
+	/// Nonce of the next `addProxyWithSignature` message of `real`.
+	#[precompile::public("nonces(address)")]
+	#[precompile::view]
+	fn nonces(handle: &mut impl PrecompileHandle, real: Address) -> EvmResult<U256> {
+		// NoncesStorage: Blake2_128(16) + real(20) + nonce(32)
+		handle.record_db_read::<Runtime>(68)?;
+
+		Ok(NoncesStorage::get(H160::from(real)))
+	}
+
+	/// EIP-712 domain separator of the `addProxyWithSignature` messages.
+	#[precompile::public("DOMAIN_SEPARATOR()")]
+	#[precompile::view]
+	fn domain_separator(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+		// ChainId
+		handle.record_db_read::<Runtime>(8)?;
+
+		Ok(Self::compute_domain_separator(handle.context().address).into())
 	}
 
 	/// Unregister a proxy account for the sender.
@@ -309,6 +422,85 @@ where
 		Self::inner_proxy(handle, real, Some(proxy_type), evm_subcall)
 	}
 
+	/// Dispatch the given call (`data`) of a precompile from an account that the sender is
+	/// authorised for through `add_proxy`. Unlike `proxy`, the call is only filtered by the
+	/// function it targets: it is allowed if the (`precompile`, selector) pair is in the allow-list
+	/// of the type of any proxy of the sender for `real`.
+	///
+	/// Parameters:
+	/// - `real`: The account that the proxy will make a call on behalf of.
+	/// - `precompile`: Address of the precompile to call.
+	/// - `data`: Data of the call to be made by the `real` account, starting with the selector.
+	#[precompile::public("proxyPrecompile(address,address,bytes)")]
+	fn proxy_precompile(
+		handle: &mut impl PrecompileHandle,
+		real: Address,
+		precompile: Address,
+		data: BoundedBytes<GetCallDataLimit>,
+	) -> EvmResult {
+		let selector: [u8; 4] = data
+			.as_bytes()
+			.get(0..4)
+			.and_then(|selector| selector.try_into().ok())
+			.ok_or_else(|| RevertReason::custom("Missing selector").in_field("data"))?;
+		let selector = u32::from_be_bytes(selector);
+
+		// Check that we only perform proxy calls on behalf of externally owned accounts
+		let AddressType::EOA = precompile_set::get_address_type::<Runtime>(handle, real.into())?
+		else {
+			return Err(revert("real address must be EOA"));
+		};
+
+		// Read proxies
+		let real_account_id = Runtime::AddressMapping::into_account_id(real.into());
+		let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		// Proxies:
+		// Twox64Concat(8) + AccountId(20) + BoundedVec(ProxyDefinition * MaxProxies) + Balance(16)
+		handle.record_db_read::<Runtime>(
+			28 + (29 * (<Runtime as pallet_proxy::Config>::MaxProxies::get() as usize)) + 8,
+		)?;
+		let proxies = ProxyPallet::<Runtime>::proxies(&real_account_id).0;
+		let mut defs = proxies.iter().filter(|def| def.delegate == who).peekable();
+		ensure!(defs.peek().is_some(), revert("Not proxy"));
+		let mut defs = defs.filter(|def| def.delay.is_zero()).peekable();
+		ensure!(defs.peek().is_some(), revert("Unannounced"));
+
+		// Apply proxy type allow-lists
+		ensure!(
+			defs.any(|def| def
+				.proxy_type
+				.is_evm_proxy_precompile_call_allowed(precompile.into(), selector)),
+			revert("CallFiltered")
+		);
+
+		let address = precompile.0;
+		let sub_context = Context {
+			caller: real.0,
+			address: address.clone(),
+			apparent_value: U256::zero(),
+		};
+
+		let (reason, output) = handle.call(
+			address,
+			None,
+			data.into(),
+			Some(handle.remaining_gas()),
+			false,
+			&sub_context,
+		);
+
+		// Return subcall result
+		match reason {
+			ExitReason::Fatal(exit_status) => Err(PrecompileFailure::Fatal { exit_status }),
+			ExitReason::Revert(exit_status) => Err(PrecompileFailure::Revert {
+				exit_status,
+				output,
+			}),
+			ExitReason::Error(exit_status) => Err(PrecompileFailure::Error { exit_status }),
+			ExitReason::Succeed(_) => Ok(()),
+		}
+	}
+
 	/// Checks if the caller has an account proxied with a given proxy type
 	///
 	/// Parameters:
@@ -346,6 +538,100 @@ where
 		Ok(is_proxy)
 	}
 
+	fn inner_add_proxy(
+		handle: &mut impl PrecompileHandle,
+		origin: Runtime::AccountId,
+		delegate: Address,
+		proxy_type: u8,
+		delay: u32,
+	) -> EvmResult {
+		let delegate = Runtime::AddressMapping::into_account_id(delegate.into());
+		let proxy_type = Runtime::ProxyType::decode(&mut proxy_type.to_le_bytes().as_slice())
+			.map_err(|_| {
+				RevertReason::custom("Failed decoding value to ProxyType").in_field("proxyType")
+			})?;
+		let delay = delay.into();
+
+		// Disallow re-adding proxy via precompile to prevent re-entrancy.
+		// See: https://github.com/PureStake/sr-moonbeam/issues/30
+		// Note: It is also assumed that EVM calls are only allowed through `Origin::Root` and
+		// filtered via CallFilter
+		// Proxies:
+		// Twox64Concat(8) + AccountId(20) + BoundedVec(ProxyDefinition * MaxProxies) + Balance(16)
+		handle.record_db_read::<Runtime>(
+			28 + (29 * (<Runtime as pallet_proxy::Config>::MaxProxies::get() as usize)) + 8,
+		)?;
+		if ProxyPallet::<Runtime>::proxies(&origin)
+			.0
+			.iter()
+			.any(|pd| pd.delegate == delegate)
+		{
+			return Err(revert("Cannot add more than one proxy"));
+		}
+
+		let delegate: <Runtime::Lookup as StaticLookup>::Source =
+			Runtime::Lookup::unlookup(delegate.clone());
+		let call: ProxyCall<Runtime> = ProxyCall::<Runtime>::add_proxy {
+			delegate,
+			proxy_type,
+			delay,
+		}
+		.into();
+
+		<RuntimeHelper<Runtime>>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	fn compute_domain_separator(address: H160) -> [u8; 32] {
+		let name: H256 = keccak_256(b"Proxy Precompile").into();
+		let version: H256 = keccak256!("1").into();
+		let chain_id: U256 = <Runtime as pallet_evm::Config>::ChainId::get().into();
+
+		let domain_separator_inner = solidity::encode_arguments((
+			H256::from(ADD_PROXY_DOMAIN),
+			name,
+			version,
+			chain_id,
+			Address(address),
+		));
+
+		keccak_256(&domain_separator_inner)
+	}
+
+	pub fn generate_add_proxy_message(
+		address: H160,
+		real: H160,
+		delegate: H160,
+		proxy_type: u8,
+		delay: u32,
+		nonce: U256,
+		deadline: U256,
+	) -> [u8; 32] {
+		let domain_separator = Self::compute_domain_separator(address);
+
+		let message_content = solidity::encode_arguments((
+			H256::from(ADD_PROXY_TYPEHASH),
+			Address(real),
+			Address(delegate),
+			proxy_type,
+			delay,
+			nonce,
+			deadline,
+		));
+		let message_content = keccak_256(&message_content);
+		let mut pre_digest = Vec::with_capacity(2 + 32 + 32);
+		pre_digest.extend_from_slice(b"\x19\x01");
+		pre_digest.extend_from_slice(&domain_separator);
+		pre_digest.extend_from_slice(&message_content);
+		keccak_256(&pre_digest)
+	}
+
+	pub fn signature_inherent_cost() -> u64 {
+		3_000 // cost of ECRecover precompile for reference
+			+ RuntimeHelper::<Runtime>::db_write_gas_cost() // we write nonce
+	}
+
 	fn inner_proxy(
 		handle: &mut impl PrecompileHandle,
 		real: Address,
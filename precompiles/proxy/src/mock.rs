@@ -30,7 +30,7 @@ use precompile_utils::{
 	testing::MockAccount,
 };
 use scale_info::TypeInfo;
-use sp_core::{H160, H256, U256};
+use sp_core::{Get, H160, H256, U256};
 use sp_io;
 use sp_runtime::codec::{Decode, Encode, MaxEncodedLen};
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
@@ -204,6 +204,10 @@ pub enum ProxyType {
 	Nothing = 2,
 }
 
+/// Only selector of the precompile at address 2 allowed for `ProxyType::Something` proxies
+/// through `proxyPrecompile`.
+pub const SOMETHING_SELECTOR: u32 = 0x01020304;
+
 impl std::default::Default for ProxyType {
 	fn default() -> Self {
 		ProxyType::Any
@@ -223,6 +227,16 @@ impl crate::EvmProxyCallFilter for ProxyType {
 			Self::Nothing => false,
 		})
 	}
+
+	fn is_evm_proxy_precompile_call_allowed(&self, precompile: H160, selector: u32) -> bool {
+		match self {
+			Self::Any => true,
+			Self::Something => {
+				precompile == AddressU64::<2>::get() && selector == SOMETHING_SELECTOR
+			}
+			Self::Nothing => false,
+		}
+	}
 }
 
 impl InstanceFilter<RuntimeCall> for ProxyType {
@@ -14,11 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::mock::{
-	AccountId, ExtBuilder, PCall, PrecompilesValue, ProxyType, Runtime, RuntimeCall, RuntimeEvent,
-	RuntimeOrigin,
+use crate::{
+	mock::{
+		AccountId, ExtBuilder, PCall, PrecompilesValue, ProxyType, Runtime, RuntimeCall,
+		RuntimeEvent, RuntimeOrigin, Timestamp, SOMETHING_SELECTOR,
+	},
+	ProxyPrecompile,
 };
 use frame_support::{assert_ok, dispatch::Dispatchable};
+use libsecp256k1::{sign, Message, SecretKey};
 use pallet_evm::Call as EvmCall;
 use pallet_proxy::{
 	Call as ProxyCall, Event as ProxyEvent, Pallet as ProxyPallet, ProxyDefinition,
@@ -56,6 +60,10 @@ fn selectors() {
 	assert!(PCall::proxy_selectors().contains(&0x0d3cff86));
 	assert!(PCall::proxy_force_type_selectors().contains(&0x4a36b2cd));
 	assert!(PCall::is_proxy_selectors().contains(&0xe26d38ed));
+	assert!(PCall::add_proxy_with_signature_selectors().contains(&0xa0fe53a0));
+	assert!(PCall::nonces_selectors().contains(&0x7ecebe00));
+	assert!(PCall::domain_separator_selectors().contains(&0x3644e515));
+	assert!(PCall::proxy_precompile_selectors().contains(&0xcad4ef36));
 }
 
 #[test]
@@ -70,6 +78,10 @@ fn modifiers() {
 		tester.test_payable_modifier(PCall::proxy_selectors());
 		tester.test_payable_modifier(PCall::proxy_force_type_selectors());
 		tester.test_view_modifier(PCall::is_proxy_selectors());
+		tester.test_default_modifier(PCall::add_proxy_with_signature_selectors());
+		tester.test_view_modifier(PCall::nonces_selectors());
+		tester.test_view_modifier(PCall::domain_separator_selectors());
+		tester.test_default_modifier(PCall::proxy_precompile_selectors());
 	});
 }
 
@@ -210,6 +222,191 @@ fn test_add_proxy_succeeds() {
 		})
 }
 
+fn sign_add_proxy(
+	secret_key: [u8; 32],
+	real: H160,
+	delegate: H160,
+	nonce: U256,
+	deadline: U256,
+) -> Vec<u8> {
+	let message = ProxyPrecompile::<Runtime>::generate_add_proxy_message(
+		Precompile1.into(),
+		real,
+		delegate,
+		ProxyType::Something as u8,
+		0,
+		nonce,
+		deadline,
+	);
+
+	let secret_key = SecretKey::parse(&secret_key).unwrap();
+	let (rs, v) = sign(&Message::parse(&message), &secret_key);
+
+	let mut signature = rs.serialize().to_vec();
+	signature.push(v.serialize() + 27);
+	signature
+}
+
+fn add_proxy_with_signature(deadline: U256, signature: Vec<u8>) -> PCall {
+	PCall::add_proxy_with_signature {
+		real: Address(CryptoAlith.into()),
+		delegate: Address(Bob.into()),
+		proxy_type: ProxyType::Something as u8,
+		delay: 0,
+		deadline,
+		signature: signature.into(),
+	}
+}
+
+#[test]
+fn test_add_proxy_with_signature_succeeds() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let signature = sign_add_proxy(
+				alith_secret_key(),
+				CryptoAlith.into(),
+				Bob.into(),
+				U256::zero(),
+				1_000u32.into(),
+			);
+
+			// submitted by an account that is neither the real account nor the delegate
+			PrecompilesValue::get()
+				.prepare_test(
+					Charlie,
+					Precompile1,
+					add_proxy_with_signature(1_000u32.into(), signature),
+				)
+				.execute_returns(());
+			assert_event_emitted!(RuntimeEvent::Proxy(ProxyEvent::ProxyAdded {
+				delegator: CryptoAlith.into(),
+				delegatee: Bob.into(),
+				proxy_type: ProxyType::Something,
+				delay: 0,
+			}));
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Charlie,
+					Precompile1,
+					PCall::nonces {
+						real: Address(CryptoAlith.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::one());
+		})
+}
+
+#[test]
+fn test_add_proxy_with_signature_cannot_be_replayed() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let signature = sign_add_proxy(
+				alith_secret_key(),
+				CryptoAlith.into(),
+				Bob.into(),
+				U256::zero(),
+				1_000u32.into(),
+			);
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Charlie,
+					Precompile1,
+					add_proxy_with_signature(1_000u32.into(), signature.clone()),
+				)
+				.execute_returns(());
+
+			assert_ok!(RuntimeCall::Proxy(ProxyCall::remove_proxies {})
+				.dispatch(RuntimeOrigin::signed(CryptoAlith.into())));
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Charlie,
+					Precompile1,
+					add_proxy_with_signature(1_000u32.into(), signature),
+				)
+				.execute_reverts(|o| o == b"Invalid signature");
+		})
+}
+
+#[test]
+fn test_add_proxy_with_signature_fails_if_not_signed_by_real() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let signature = sign_add_proxy(
+				baltathar_secret_key(),
+				CryptoAlith.into(),
+				Bob.into(),
+				U256::zero(),
+				1_000u32.into(),
+			);
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Charlie,
+					Precompile1,
+					add_proxy_with_signature(1_000u32.into(), signature),
+				)
+				.execute_reverts(|o| o == b"Invalid signature");
+			assert_event_not_emitted!(RuntimeEvent::Proxy(ProxyEvent::ProxyAdded {
+				delegator: CryptoAlith.into(),
+				delegatee: Bob.into(),
+				proxy_type: ProxyType::Something,
+				delay: 0,
+			}));
+		})
+}
+
+#[test]
+fn test_add_proxy_with_signature_fails_if_expired() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			Timestamp::set_timestamp(2_000_000);
+
+			let signature = sign_add_proxy(
+				alith_secret_key(),
+				CryptoAlith.into(),
+				Bob.into(),
+				U256::zero(),
+				1_000u32.into(),
+			);
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Charlie,
+					Precompile1,
+					add_proxy_with_signature(1_000u32.into(), signature),
+				)
+				.execute_reverts(|o| o == b"Signature expired");
+		})
+}
+
+#[test]
+fn test_add_proxy_with_signature_fails_if_malformed_signature() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			PrecompilesValue::get()
+				.prepare_test(
+					Charlie,
+					Precompile1,
+					add_proxy_with_signature(1_000u32.into(), vec![0u8; 64]),
+				)
+				.execute_reverts(|o| o == b"signature: Expected a 65 bytes signature");
+		})
+}
+
 #[test]
 fn test_remove_proxy_fails_if_invalid_value_for_proxy_type() {
 	ExtBuilder::default()
@@ -411,6 +608,174 @@ fn test_proxy_fails_if_call_filtered() {
 		})
 }
 
+#[test]
+fn test_proxy_precompile_fails_if_missing_selector() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			PrecompilesValue::get()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::proxy_precompile {
+						real: Address(Alice.into()),
+						precompile: Address(AddressU64::<2>::get()),
+						data: BoundedBytes::from([1, 2, 3]),
+					},
+				)
+				.execute_reverts(|o| o == b"data: Missing selector");
+		})
+}
+
+#[test]
+fn test_proxy_precompile_fails_if_not_proxy() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			PrecompilesValue::get()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::proxy_precompile {
+						real: Address(Alice.into()),
+						precompile: Address(AddressU64::<2>::get()),
+						data: BoundedBytes::from(SOMETHING_SELECTOR.to_be_bytes()),
+					},
+				)
+				.execute_reverts(|o| o == b"Not proxy");
+		})
+}
+
+#[test]
+fn test_proxy_precompile_fails_if_unannounced() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(RuntimeCall::Proxy(ProxyCall::add_proxy {
+				delegate: Bob.into(),
+				proxy_type: ProxyType::Something,
+				delay: 1,
+			})
+			.dispatch(RuntimeOrigin::signed(Alice.into())));
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::proxy_precompile {
+						real: Address(Alice.into()),
+						precompile: Address(AddressU64::<2>::get()),
+						data: BoundedBytes::from(SOMETHING_SELECTOR.to_be_bytes()),
+					},
+				)
+				.execute_reverts(|o| o == b"Unannounced");
+		})
+}
+
+#[test]
+fn test_proxy_precompile_fails_if_selector_not_allowed() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(RuntimeCall::Proxy(ProxyCall::add_proxy {
+				delegate: Bob.into(),
+				proxy_type: ProxyType::Something,
+				delay: 0,
+			})
+			.dispatch(RuntimeOrigin::signed(Alice.into())));
+
+			// Allowed selector of another precompile
+			PrecompilesValue::get()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::proxy_precompile {
+						real: Address(Alice.into()),
+						precompile: Address(Precompile1.into()),
+						data: BoundedBytes::from(SOMETHING_SELECTOR.to_be_bytes()),
+					},
+				)
+				.execute_reverts(|o| o == b"CallFiltered");
+
+			// Other selector of the allowed precompile
+			PrecompilesValue::get()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::proxy_precompile {
+						real: Address(Alice.into()),
+						precompile: Address(AddressU64::<2>::get()),
+						data: BoundedBytes::from([4, 3, 2, 1]),
+					},
+				)
+				.execute_reverts(|o| o == b"CallFiltered");
+		})
+}
+
+#[test]
+fn test_proxy_precompile_succeeds_if_selector_allowed() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(RuntimeCall::Proxy(ProxyCall::add_proxy {
+				delegate: Bob.into(),
+				proxy_type: ProxyType::Something,
+				delay: 0,
+			})
+			.dispatch(RuntimeOrigin::signed(Alice.into())));
+
+			let inside = Rc::new(Cell::new(false));
+			let inside2 = inside.clone();
+
+			let mut data = SOMETHING_SELECTOR.to_be_bytes().to_vec();
+			data.push(5);
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::proxy_precompile {
+						real: Address(Alice.into()),
+						precompile: Address(AddressU64::<2>::get()),
+						data: BoundedBytes::from(data.clone()),
+					},
+				)
+				.with_subcall_handle(move |subcall| {
+					let Subcall {
+						address,
+						transfer,
+						input,
+						target_gas: _,
+						is_static,
+						context,
+					} = subcall;
+
+					assert_eq!(context.caller, Alice.into());
+					assert_eq!(address, AddressU64::<2>::get());
+					assert_eq!(is_static, false);
+
+					assert!(transfer.is_none());
+
+					assert_eq!(context.address, AddressU64::<2>::get());
+					assert_eq!(context.apparent_value, 0u8.into());
+
+					assert_eq!(&input, &data);
+
+					inside2.set(true);
+
+					SubcallOutput::succeed()
+				})
+				.execute_returns(());
+
+			assert!(inside.get(), "subcall not called");
+		})
+}
+
 #[test]
 fn test_is_proxy_returns_false_if_not_proxy() {
 	ExtBuilder::default()
@@ -46,6 +46,20 @@ interface Randomness {
         RelayBabeEpoch
     }
 
+    /// @notice The depth of the relaychain Babe randomness used to fulfill a request
+    /// @param OneEpochAgo Randomness from the VRF outputs of the epoch following the current one.
+    /// Fulfillable at the beginning of the 2nd relay epoch following the current one.
+    /// None of the VRF outputs are known at the time the request is performed.
+    /// @param TwoEpochsAgo Randomness from the VRF outputs of the current epoch.
+    /// Fulfillable at the beginning of the relay epoch following the current one.
+    /// The VRF outputs already produced during the current epoch are known at the time the request
+    /// is performed: the random words are only unpredictable if enough relay blocks remain in the
+    /// current epoch. Use it only when a lower latency matters more than unpredictability.
+    enum RelayBabeEpochDepth {
+        OneEpochAgo,
+        TwoEpochsAgo
+    }
+
     /// @notice The request details
     /// @param id The id of the request (is always < 2**64)
     /// @param refundAddress The address receiving the left-over fees after the fulfillment
@@ -107,6 +121,24 @@ interface Randomness {
         view
         returns (Request memory request);
 
+    /// @notice Returns the ids of the pending requests made by a contract, scanning up to `limit`
+    /// requests at a time
+    /// @param contractAddress The address of the contract which made the requests
+    /// @param fromRequestId The id of the first request to scan (must be < 2**64)
+    /// @param limit The maximum number of requests to scan
+    /// @return requestIds The ids of the requests made by the contract
+    /// @return nextRequestId The id to scan from in the next call, 0 once all the requests have
+    /// been scanned
+    /// @custom:selector 92099b62
+    function requestsByContract(
+        address contractAddress,
+        uint256 fromRequestId,
+        uint32 limit
+    )
+        external
+        view
+        returns (uint256[] memory requestIds, uint256 nextRequestId);
+
     /// @notice Request random words generated from the parachain VRF
     /// @dev This is using pseudo-random VRF executed by the collator at the fulfillment
     /// @dev Warning:
@@ -166,6 +198,29 @@ interface Randomness {
         uint8 numWords
     ) external returns (uint256);
 
+    /// @notice Request random words generated from the relaychain Babe consensus, at the given depth
+    /// @dev Same as requestRelayBabeEpochRandomWords, which uses the OneEpochAgo depth.
+    /// @dev The TwoEpochsAgo depth can be fulfilled one epoch earlier but the VRF outputs of the
+    /// @dev current epoch produced before the request are public (see RelayBabeEpochDepth).
+    /// @dev Both depths are reported as RelayBabeEpoch by getRequest, with their own
+    /// @dev fulfillmentEpochIndex.
+    /// @param refundAddress The address receiving the left-over fees after the fulfillment
+    /// @param fee Amount to set aside to pay for the fulfillment. Those fees are taken from the contract
+    /// @param gasLimit Gas limit for the fulfillment
+    /// @param salt Salt to be mixed with raw randomness to get output
+    /// @param numWords Number of random words to be returned (limited to MAX_RANDOM_WORDS)
+    /// @param depth The depth of the relaychain Babe randomness
+    /// @return requestId The id of the request
+    /// @custom:selector b680abc8
+    function requestRelayBabeEpochRandomWordsAtDepth(
+        address refundAddress,
+        uint256 fee,
+        uint64 gasLimit,
+        bytes32 salt,
+        uint8 numWords,
+        RelayBabeEpochDepth depth
+    ) external returns (uint256);
+
     /// @dev fulFill the request which will call the contract method "fulfillRandomWords"
     /// @dev Fees of the caller are refunded if the request is fulfillable
     /// @param requestId Request to be fulfilled (must be < 2**64)
@@ -322,6 +322,52 @@ where
 		))
 	}
 
+	/// Scan up to `limit` request ids starting from `from_request_id`, returning the ids of the
+	/// pending requests made by `contract_address` and the request id to resume from, which is 0
+	/// once all the requests have been scanned.
+	#[precompile::public("requestsByContract(address,uint256,uint32)")]
+	#[precompile::view]
+	fn requests_by_contract(
+		handle: &mut impl PrecompileHandle,
+		contract_address: Address,
+		from_request_id: Convert<U256, u64>,
+		limit: u32,
+	) -> EvmResult<(Vec<U256>, U256)> {
+		let contract_address: H160 = contract_address.into();
+		let from_request_id = from_request_id.converted();
+
+		// Storage item read: pallet_randomness::RequestCount
+		// Max encoded len: u64(8)
+		handle.record_db_read::<Runtime>(8)?;
+		let request_count = Pallet::<Runtime>::request_count();
+		let to_request_id = from_request_id
+			.saturating_add(limit.into())
+			.min(request_count);
+
+		let mut request_ids = Vec::new();
+		for request_id in from_request_id..to_request_id {
+			// Storage item read: pallet_randomness::Requests
+			// Max encoded len: Twox64(8) + RequestId(8) + RequestState(
+			// 	request(refund_address(20)+contract_address(20)+fee(16)+gas_limit(8)+num_words(1)
+			//   +salt(32)+info(17))
+			// + deposit(16) )
+			handle.record_db_read::<Runtime>(146)?;
+			if let Some(RequestState { request, .. }) = Pallet::<Runtime>::requests(request_id) {
+				if request.contract_address == contract_address {
+					request_ids.push(request_id.into());
+				}
+			}
+		}
+
+		let next_request_id = if to_request_id < request_count {
+			to_request_id
+		} else {
+			0
+		};
+
+		Ok((request_ids, next_request_id.into()))
+	}
+
 	/// Make request for babe randomness one epoch ago
 	#[precompile::public("requestRelayBabeEpochRandomWords(address,uint256,uint64,bytes32,uint8)")]
 	fn request_babe_randomness(
@@ -331,6 +377,30 @@ where
 		gas_limit: u64,
 		salt: H256,
 		num_words: u8,
+	) -> EvmResult<U256> {
+		Self::request_babe_randomness_at_depth(
+			handle,
+			refund_address,
+			fee,
+			gas_limit,
+			salt,
+			num_words,
+			RelayBabeEpochDepth::OneEpochAgo,
+		)
+	}
+
+	/// Make request for babe randomness at the given depth
+	#[precompile::public(
+		"requestRelayBabeEpochRandomWordsAtDepth(address,uint256,uint64,bytes32,uint8,uint8)"
+	)]
+	fn request_babe_randomness_at_depth(
+		handle: &mut impl PrecompileHandle,
+		refund_address: Address,
+		fee: U256,
+		gas_limit: u64,
+		salt: H256,
+		num_words: u8,
+		depth: RelayBabeEpochDepth,
 	) -> EvmResult<U256> {
 		// Until proper benchmark, charge few hardcoded gas to prevent free spam
 		handle.record_cost(500)?;
@@ -342,10 +412,9 @@ where
 
 		let contract_address = handle.context().caller;
 
-		let two_epochs_later =
-			<Runtime as pallet_randomness::Config>::BabeDataGetter::get_epoch_index()
-				.checked_add(2u64)
-				.ok_or(revert("Epoch Index (u64) overflowed"))?;
+		let epoch_due = <Runtime as pallet_randomness::Config>::BabeDataGetter::get_epoch_index()
+			.checked_add(depth.epoch_delay())
+			.ok_or(revert("Epoch Index (u64) overflowed"))?;
 
 		let request = Request {
 			refund_address,
@@ -354,7 +423,7 @@ where
 			gas_limit,
 			num_words,
 			salt,
-			info: RequestType::BabeEpoch(two_epochs_later),
+			info: RequestType::BabeEpoch(epoch_due),
 		};
 
 		let request_randomness_weight =
@@ -32,6 +32,29 @@ pub enum RandomnessSource {
 	RelayBabeEpoch,
 }
 
+/// Depth of the relay BABE randomness used to fulfill a request.
+///
+/// The randomness stored by the pallet for a relay epoch is built from the VRF outputs of the
+/// previous relay epoch. `OneEpochAgo` requests are due two epochs after the current one and use
+/// the VRF outputs of the next epoch, none of which is known when the request is made.
+/// `TwoEpochsAgo` requests are due one epoch earlier and use the VRF outputs of the current
+/// epoch: the latency is lower but the outputs already produced in the current epoch are public,
+/// so the result is only unpredictable if enough relay blocks remain in the epoch.
+pub enum RelayBabeEpochDepth {
+	OneEpochAgo,
+	TwoEpochsAgo,
+}
+
+impl RelayBabeEpochDepth {
+	/// Number of relay epochs between the current epoch and the one the request is due.
+	pub fn epoch_delay(&self) -> u64 {
+		match self {
+			RelayBabeEpochDepth::OneEpochAgo => 2,
+			RelayBabeEpochDepth::TwoEpochsAgo => 1,
+		}
+	}
+}
+
 impl solidity::Codec for RequestStatus {
 	fn read(reader: &mut Reader) -> MayRevert<Self> {
 		match reader.read().in_field("variant")? {
@@ -87,3 +110,29 @@ impl solidity::Codec for RandomnessSource {
 		u8::signature()
 	}
 }
+
+impl solidity::Codec for RelayBabeEpochDepth {
+	fn read(reader: &mut Reader) -> MayRevert<Self> {
+		match reader.read().in_field("variant")? {
+			0u8 => Ok(RelayBabeEpochDepth::OneEpochAgo),
+			1u8 => Ok(RelayBabeEpochDepth::TwoEpochsAgo),
+			_ => Err(RevertReason::custom("Unknown RelayBabeEpochDepth variant").into()),
+		}
+	}
+
+	fn write(writer: &mut Writer, value: Self) {
+		let encoded: u8 = match value {
+			RelayBabeEpochDepth::OneEpochAgo => 0u8,
+			RelayBabeEpochDepth::TwoEpochsAgo => 1u8,
+		};
+		solidity::Codec::write(writer, encoded);
+	}
+
+	fn has_static_size() -> bool {
+		true
+	}
+
+	fn signature() -> String {
+		u8::signature()
+	}
+}
@@ -15,7 +15,10 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 //! Randomness precompile unit tests
-use crate::{mock::*, prepare_and_finish_fulfillment_gas_cost, subcall_overhead_gas_costs};
+use crate::{
+	mock::*, prepare_and_finish_fulfillment_gas_cost, subcall_overhead_gas_costs,
+	RelayBabeEpochDepth,
+};
 use fp_evm::FeeCalculator;
 use pallet_randomness::{Event as RandomnessEvent, RandomnessResults, RequestType};
 use precompile_utils::{assert_event_emitted, prelude::*, testing::*};
@@ -45,8 +48,10 @@ fn selectors() {
 	assert!(PCall::required_deposit_selectors().contains(&0xfb7cfdd7));
 	assert!(PCall::get_request_status_selectors().contains(&0xd8a4676f));
 	assert!(PCall::get_request_selectors().contains(&0xc58343ef));
+	assert!(PCall::requests_by_contract_selectors().contains(&0x92099b62));
 	assert!(PCall::request_local_randomness_selectors().contains(&0x9478430c));
 	assert!(PCall::request_babe_randomness_selectors().contains(&0x33c14a63));
+	assert!(PCall::request_babe_randomness_at_depth_selectors().contains(&0xb680abc8));
 	assert!(PCall::fulfill_request_selectors().contains(&0x9a91eb0d));
 	assert!(PCall::increase_request_fee_selectors().contains(&0xd0408a7f));
 	assert!(PCall::purge_expired_request_selectors().contains(&0x1d26cbab));
@@ -62,8 +67,10 @@ fn modifiers() {
 		tester.test_view_modifier(PCall::required_deposit_selectors());
 		tester.test_view_modifier(PCall::get_request_status_selectors());
 		tester.test_view_modifier(PCall::get_request_selectors());
+		tester.test_view_modifier(PCall::requests_by_contract_selectors());
 		tester.test_default_modifier(PCall::request_local_randomness_selectors());
 		tester.test_default_modifier(PCall::request_babe_randomness_selectors());
+		tester.test_default_modifier(PCall::request_babe_randomness_at_depth_selectors());
 		tester.test_default_modifier(PCall::fulfill_request_selectors());
 		tester.test_default_modifier(PCall::purge_expired_request_selectors());
 	});
@@ -269,6 +276,70 @@ fn get_request_works() {
 		})
 }
 
+#[test]
+fn requests_by_contract_paginates_requests() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Charlie.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			pallet_evm::AccountCodes::<Runtime>::insert(H160::from(Alice), vec![10u8]);
+			pallet_evm::AccountCodes::<Runtime>::insert(H160::from(Charlie), vec![10u8]);
+			for requester in [Alice, Charlie, Alice] {
+				PrecompilesValue::get()
+					.prepare_test(
+						requester,
+						Precompile1,
+						PCall::request_local_randomness {
+							refund_address: Address(Bob.into()),
+							fee: U256::one(),
+							gas_limit: 100u64,
+							salt: H256::default(),
+							num_words: 1u8,
+							delay: 2.into(),
+						},
+					)
+					.execute_some();
+			}
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::requests_by_contract {
+						contract_address: Address(Alice.into()),
+						from_request_id: 0.into(),
+						limit: 2,
+					},
+				)
+				.expect_no_logs()
+				.execute_returns((vec![U256::zero()], U256::from(2)));
+			PrecompilesValue::get()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::requests_by_contract {
+						contract_address: Address(Alice.into()),
+						from_request_id: 2.into(),
+						limit: 2,
+					},
+				)
+				.expect_no_logs()
+				.execute_returns((vec![U256::from(2)], U256::zero()));
+			PrecompilesValue::get()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::requests_by_contract {
+						contract_address: Address(Charlie.into()),
+						from_request_id: 0.into(),
+						limit: 10,
+					},
+				)
+				.expect_no_logs()
+				.execute_returns((vec![U256::one()], U256::zero()));
+		})
+}
+
 #[test]
 fn request_babe_randomness_works() {
 	ExtBuilder::default()
@@ -305,6 +376,69 @@ fn request_babe_randomness_works() {
 		})
 }
 
+#[test]
+fn request_babe_randomness_two_epochs_ago_is_due_one_epoch_earlier() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			pallet_evm::AccountCodes::<Runtime>::insert(H160::from(Alice), vec![10u8]);
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::request_babe_randomness_at_depth {
+						refund_address: Address(H160::from(Bob)),
+						fee: U256::one(),
+						gas_limit: 100u64,
+						salt: H256::default(),
+						num_words: 1u8,
+						depth: RelayBabeEpochDepth::TwoEpochsAgo,
+					},
+				)
+				.execute_returns(U256::zero());
+			assert_event_emitted!(RuntimeEvent::Randomness(
+				RandomnessEvent::RandomnessRequestedBabeEpoch {
+					id: 0,
+					refund_address: H160::from(Bob),
+					contract_address: H160::from(Alice),
+					fee: 1,
+					gas_limit: 100u64,
+					num_words: 1u8,
+					salt: H256::default(),
+					earliest_epoch: 2,
+				}
+			));
+		})
+}
+
+#[test]
+fn request_babe_randomness_at_unknown_depth_reverts() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			pallet_evm::AccountCodes::<Runtime>::insert(H160::from(Alice), vec![10u8]);
+
+			let input = solidity::encode_with_selector(
+				0xb680abc8u32,
+				(
+					Address(H160::from(Bob)),
+					U256::one(),
+					100u64,
+					H256::default(),
+					1u8,
+					2u8,
+				),
+			);
+
+			PrecompilesValue::get()
+				.prepare_test(Alice, Precompile1, input)
+				.execute_reverts(|output| output.ends_with(b"Unknown RelayBabeEpochDepth variant"));
+		})
+}
+
 #[test]
 fn request_local_randomness_works() {
 	ExtBuilder::default()
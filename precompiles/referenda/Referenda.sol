@@ -101,6 +101,28 @@ interface Referenda {
     /// @custom:selector 34038146
     function trackInfo(uint16 trackId) external view returns (TrackInfo memory);
 
+    /// Return the approval needed for a referendum of the input TrackId to pass, after the
+    /// input number of blocks elapsed in its decision period
+    /// @param trackId The track identifier
+    /// @param elapsed The number of blocks since the referendum started being decided
+    /// @return The approval needed, in parts per billion
+    /// @custom:selector fa6ed742
+    function approvalNeededAt(uint16 trackId, uint256 elapsed)
+        external
+        view
+        returns (uint32);
+
+    /// Return the support needed for a referendum of the input TrackId to pass, after the
+    /// input number of blocks elapsed in its decision period
+    /// @param trackId The track identifier
+    /// @param elapsed The number of blocks since the referendum started being decided
+    /// @return The support needed, in parts per billion
+    /// @custom:selector 8bf36276
+    function supportNeededAt(uint16 trackId, uint256 elapsed)
+        external
+        view
+        returns (uint32);
+
     /// Return the ReferendumStatus for the input referendumIndex
     /// @param referendumIndex The index of the referendum
     /// @custom:selector 8d407c0b
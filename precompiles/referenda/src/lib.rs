@@ -29,6 +29,7 @@ use pallet_referenda::{
 use parity_scale_codec::{Encode, MaxEncodedLen};
 use precompile_utils::prelude::*;
 use sp_core::{H160, H256, U256};
+use sp_runtime::Perbill;
 use sp_std::{boxed::Box, marker::PhantomData, str::FromStr, vec::Vec};
 
 #[cfg(test)]
@@ -211,15 +212,7 @@ where
 	#[precompile::public("trackInfo(uint16)")]
 	#[precompile::view]
 	fn track_info(_handle: &mut impl PrecompileHandle, track_id: u16) -> EvmResult<TrackInfo> {
-		let track_id: TrackIdOf<Runtime> = track_id
-			.try_into()
-			.map_err(|_| RevertReason::value_is_too_large("Track id type").into())
-			.in_field("trackId")?;
-		let track = Runtime::Tracks::tracks()
-			.iter()
-			.find(|(id, _)| *id == track_id)
-			.ok_or(RevertReason::custom("No such track").in_field("trackId"))?;
-		let track_info = &track.1;
+		let track_info = Self::track(track_id)?;
 
 		Ok(TrackInfo {
 			name: track_info.name.into(),
@@ -234,6 +227,61 @@ where
 		})
 	}
 
+	#[precompile::public("approvalNeededAt(uint16,uint256)")]
+	#[precompile::view]
+	fn approval_needed_at(
+		_handle: &mut impl PrecompileHandle,
+		track_id: u16,
+		elapsed: U256,
+	) -> EvmResult<u32> {
+		let track_info = Self::track(track_id)?;
+		let x = Self::decision_progress(track_info, elapsed);
+
+		Ok(track_info.min_approval.threshold(x).deconstruct())
+	}
+
+	#[precompile::public("supportNeededAt(uint16,uint256)")]
+	#[precompile::view]
+	fn support_needed_at(
+		_handle: &mut impl PrecompileHandle,
+		track_id: u16,
+		elapsed: U256,
+	) -> EvmResult<u32> {
+		let track_info = Self::track(track_id)?;
+		let x = Self::decision_progress(track_info, elapsed);
+
+		Ok(track_info.min_support.threshold(x).deconstruct())
+	}
+
+	/// Use Runtime::Tracks::tracks to get the track info for input trackId
+	fn track(
+		track_id: u16,
+	) -> EvmResult<&'static pallet_referenda::TrackInfo<BalanceOf<Runtime>, Runtime::BlockNumber>>
+	{
+		let track_id: TrackIdOf<Runtime> = track_id
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("Track id type").into())
+			.in_field("trackId")?;
+		let track = Runtime::Tracks::tracks()
+			.iter()
+			.find(|(id, _)| *id == track_id)
+			.ok_or(RevertReason::custom("No such track").in_field("trackId"))?;
+
+		Ok(&track.1)
+	}
+
+	/// Fraction of the decision period elapsed, as used by pallet-referenda to evaluate the
+	/// approval and support curves.
+	fn decision_progress(
+		track_info: &pallet_referenda::TrackInfo<BalanceOf<Runtime>, Runtime::BlockNumber>,
+		elapsed: U256,
+	) -> Perbill {
+		let decision_period: U256 = track_info.decision_period.into();
+		let elapsed = elapsed.min(decision_period);
+
+		Perbill::from_rational(elapsed.low_u128(), decision_period.low_u128())
+	}
+
 	/// Use Runtime::Tracks::tracks to get the origin for input trackId
 	fn track_id_to_origin(track_id: TrackIdOf<Runtime>) -> EvmResult<Box<OriginOf<Runtime>>> {
 		let track = Runtime::Tracks::tracks()
@@ -310,3 +310,51 @@ fn submit_track_id_oob_fails() {
 				.execute_reverts(|output| output == b"trackId: No such track");
 		});
 }
+
+#[test]
+fn approval_and_support_needed_follow_track_curves() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Track 0 has a decision period of 4 blocks, its approval decreases linearly from 100%
+		// to 50% and its support from 100% to 0%.
+		for (elapsed, approval, support) in [
+			(0u32, 1_000_000_000u32, 1_000_000_000u32),
+			(2, 750_000_000, 500_000_000),
+			(4, 500_000_000, 0),
+			(10, 500_000_000, 0),
+		] {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::approval_needed_at {
+						track_id: 0u16,
+						elapsed: elapsed.into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(approval);
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::support_needed_at {
+						track_id: 0u16,
+						elapsed: elapsed.into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(support);
+		}
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::approval_needed_at {
+					track_id: 2u16,
+					elapsed: 0.into(),
+				},
+			)
+			.execute_reverts(|output| output == b"trackId: No such track");
+	});
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The SanctionsFilter contract's address.
+address constant SANCTIONS_FILTER_ADDRESS = 0x0000000000000000000000000000000000000828;
+
+/// @dev The SanctionsFilter contract's instance.
+SanctionsFilter constant SANCTIONS_FILTER_CONTRACT = SanctionsFilter(
+    SANCTIONS_FILTER_ADDRESS
+);
+
+/// @author The Moonbeam Team
+/// @title Sanctioned addresses interface
+/// @title The interface through which solidity contracts can check which addresses EVM
+/// transactions cannot be sent from or to
+/// @custom:address 0x0000000000000000000000000000000000000828
+interface SanctionsFilter {
+    /// @dev Whether the transactions from or to the sanctioned addresses are rejected
+    /// @custom:selector 887070f4
+    /// @return Whether the filter is enabled
+    function isFilterEnabled() external view returns (bool);
+
+    /// @dev Whether an address is in the list of sanctioned addresses, regardless of the filter
+    /// being enabled
+    /// @custom:selector df592f7d
+    /// @param account The address to check
+    /// @return Whether the address is sanctioned
+    function isSanctioned(address account) external view returns (bool);
+
+    /// @dev Whether the transactions from or to an address are currently rejected
+    /// @custom:selector fbac3951
+    /// @param account The address to check
+    /// @return Whether the address is blocked
+    function isBlocked(address account) external view returns (bool);
+}
@@ -0,0 +1,68 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to query the addresses sanctioned in pallet-evm-sanctions-filter.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use precompile_utils::prelude::*;
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// A precompile to query the addresses EVM transactions cannot be sent from or to.
+pub struct SanctionsFilterPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> SanctionsFilterPrecompile<Runtime>
+where
+	Runtime: pallet_evm_sanctions_filter::Config + pallet_evm::Config,
+{
+	#[precompile::public("isFilterEnabled()")]
+	#[precompile::view]
+	fn is_filter_enabled(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+		// Storage item: FilterEnabled: bool(1)
+		handle.record_db_read::<Runtime>(1)?;
+
+		Ok(pallet_evm_sanctions_filter::Pallet::<Runtime>::is_filter_enabled())
+	}
+
+	#[precompile::public("isSanctioned(address)")]
+	#[precompile::view]
+	fn is_sanctioned(handle: &mut impl PrecompileHandle, account: Address) -> EvmResult<bool> {
+		// Storage item: SanctionedAddresses: Blake2_128(16) + H160(20)
+		handle.record_db_read::<Runtime>(36)?;
+
+		Ok(pallet_evm_sanctions_filter::Pallet::<Runtime>::is_sanctioned(account.into()))
+	}
+
+	#[precompile::public("isBlocked(address)")]
+	#[precompile::view]
+	fn is_blocked(handle: &mut impl PrecompileHandle, account: Address) -> EvmResult<bool> {
+		// Storage item: FilterEnabled: bool(1)
+		handle.record_db_read::<Runtime>(1)?;
+		// Storage item: SanctionedAddresses: Blake2_128(16) + H160(20)
+		handle.record_db_read::<Runtime>(36)?;
+
+		Ok(pallet_evm_sanctions_filter::Pallet::<Runtime>::is_blocked(
+			account.into(),
+		))
+	}
+}
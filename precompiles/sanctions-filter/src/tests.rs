@@ -0,0 +1,138 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use frame_support::assert_ok;
+use precompile_utils::{prelude::*, testing::*};
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["SanctionsFilter.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::is_filter_enabled_selectors().contains(&0x887070f4));
+	assert!(PCall::is_sanctioned_selectors().contains(&0xdf592f7d));
+	assert!(PCall::is_blocked_selectors().contains(&0xfbac3951));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_view_modifier(PCall::is_filter_enabled_selectors());
+		tester.test_view_modifier(PCall::is_sanctioned_selectors());
+		tester.test_view_modifier(PCall::is_blocked_selectors());
+	});
+}
+
+#[test]
+fn sanctioned_address_is_not_blocked_while_filter_is_disabled() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmSanctionsFilter::sanction_address(
+			RuntimeOrigin::root(),
+			Bob.into()
+		));
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::is_filter_enabled {})
+			.expect_no_logs()
+			.execute_returns(false);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::is_sanctioned {
+					account: Address(Bob.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::is_blocked {
+					account: Address(Bob.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+	});
+}
+
+#[test]
+fn only_sanctioned_addresses_are_blocked_while_filter_is_enabled() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EvmSanctionsFilter::sanction_address(
+			RuntimeOrigin::root(),
+			Bob.into()
+		));
+		assert_ok!(EvmSanctionsFilter::set_filter_enabled(
+			RuntimeOrigin::root(),
+			true
+		));
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::is_filter_enabled {})
+			.expect_no_logs()
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::is_blocked {
+					account: Address(Bob.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::is_sanctioned {
+					account: Address(Charlie.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::is_blocked {
+					account: Address(Charlie.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+	});
+}
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Scheduler contract's address.
+address constant SCHEDULER_ADDRESS = 0x000000000000000000000000000000000000082a;
+
+/// @dev The Scheduler contract's instance.
+Scheduler constant SCHEDULER_CONTRACT = Scheduler(SCHEDULER_ADDRESS);
+
+/// @author The Moonbeam Team
+/// @title Scheduler Interface
+/// @dev The interface through which governance proposals executed through the EVM schedule
+/// calls, which are dispatched with the governance origin of the caller once due. Only callable
+/// by the addresses of the governance origins allowed to schedule calls.
+/// @custom:address 0x000000000000000000000000000000000000082a
+interface Scheduler {
+    /// @dev Schedule a call. Its index in the agenda of the block, required to cancel it, is only
+    /// emitted in the Substrate Scheduled event, such that calls to cancel from the EVM should be
+    /// scheduled with a name.
+    /// @custom:selector bb8954ce
+    /// @param when The block at which the call is dispatched
+    /// @param priority The priority of the call, 0 being the highest
+    /// @param call The SCALE encoded runtime call
+    function schedule(
+        uint32 when,
+        uint8 priority,
+        bytes memory call
+    ) external;
+
+    /// @dev Schedule a call, identified by a name with which it can be cancelled.
+    /// @custom:selector bd4fb45e
+    /// @param id The name of the call
+    /// @param when The block at which the call is dispatched
+    /// @param priority The priority of the call, 0 being the highest
+    /// @param call The SCALE encoded runtime call
+    function scheduleNamed(
+        bytes32 id,
+        uint32 when,
+        uint8 priority,
+        bytes memory call
+    ) external;
+
+    /// @dev Cancel a call scheduled with the governance origin of the caller.
+    /// @custom:selector b13a05fd
+    /// @param when The block at which the call is dispatched
+    /// @param index The index of the call in the agenda of the block
+    function cancel(uint32 when, uint32 index) external;
+
+    /// @dev Cancel a named call scheduled with the governance origin of the caller.
+    /// @custom:selector 802f7948
+    /// @param id The name of the call
+    function cancelNamed(bytes32 id) external;
+}
@@ -0,0 +1,182 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile allowing governance to schedule calls through pallet-scheduler from the EVM.
+//!
+//! Only the addresses registered in pallet-governance-origin-registry can call it, and the calls
+//! are scheduled with the runtime origin of their governance origin, such that they are dispatched
+//! with that origin once due and can only be cancelled by it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
+use pallet_governance_origin_registry::GovernanceOrigin;
+use parity_scale_codec::DecodeLimit as _;
+use precompile_utils::prelude::*;
+use sp_core::{ConstU32, Get, H256};
+use sp_runtime::traits::Convert;
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Storage growth when scheduling a call.
+/// Agenda entry: Option<TaskName>(33) + Priority(1) + inline Bounded call(131)
+/// + Option<Period>(9) + PalletsOrigin(2)
+pub const SCHEDULED_CALL_STORAGE_GROWTH: u64 = 176;
+
+/// Storage growth when scheduling a named call, which is also indexed by its name.
+/// Lookup: Twox64(8) + TaskName(32) + TaskAddress(8)
+pub const SCHEDULED_NAMED_CALL_STORAGE_GROWTH: u64 = SCHEDULED_CALL_STORAGE_GROWTH + 48;
+
+type CallLimit = ConstU32<{ 2u32.pow(16) }>;
+type DecodeLimit = ConstU32<8>;
+
+type OriginOf<Runtime> =
+	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin;
+
+/// A precompile to schedule calls and cancel them. `GovernanceOrigins` converts the governance
+/// origin of the caller into the runtime origin the calls are scheduled with, `None` if the origin
+/// cannot schedule calls.
+pub struct SchedulerPrecompile<Runtime, GovernanceOrigins>(
+	PhantomData<(Runtime, GovernanceOrigins)>,
+);
+
+#[precompile_utils::precompile]
+impl<Runtime, GovernanceOrigins> SchedulerPrecompile<Runtime, GovernanceOrigins>
+where
+	Runtime:
+		pallet_scheduler::Config + pallet_governance_origin_registry::Config + pallet_evm::Config,
+	<Runtime as frame_system::Config>::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime as frame_system::Config>::RuntimeCall: From<pallet_scheduler::Call<Runtime>>,
+	Runtime::BlockNumber: From<u32>,
+	GovernanceOrigins: Convert<GovernanceOrigin, Option<OriginOf<Runtime>>>,
+{
+	#[precompile::public("schedule(uint32,uint8,bytes)")]
+	fn schedule(
+		handle: &mut impl PrecompileHandle,
+		when: u32,
+		priority: u8,
+		call: BoundedBytes<CallLimit>,
+	) -> EvmResult {
+		let call = Self::decode_call(call)?;
+
+		let origin = Self::governance_origin(handle)?;
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			origin,
+			pallet_scheduler::Call::<Runtime>::schedule {
+				when: when.into(),
+				maybe_periodic: None,
+				priority,
+				call,
+			},
+			SCHEDULED_CALL_STORAGE_GROWTH,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("scheduleNamed(bytes32,uint32,uint8,bytes)")]
+	fn schedule_named(
+		handle: &mut impl PrecompileHandle,
+		id: H256,
+		when: u32,
+		priority: u8,
+		call: BoundedBytes<CallLimit>,
+	) -> EvmResult {
+		let call = Self::decode_call(call)?;
+
+		let origin = Self::governance_origin(handle)?;
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			origin,
+			pallet_scheduler::Call::<Runtime>::schedule_named {
+				id: id.0,
+				when: when.into(),
+				maybe_periodic: None,
+				priority,
+				call,
+			},
+			SCHEDULED_NAMED_CALL_STORAGE_GROWTH,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("cancel(uint32,uint32)")]
+	fn cancel(handle: &mut impl PrecompileHandle, when: u32, index: u32) -> EvmResult {
+		let origin = Self::governance_origin(handle)?;
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			origin,
+			pallet_scheduler::Call::<Runtime>::cancel {
+				when: when.into(),
+				index,
+			},
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("cancelNamed(bytes32)")]
+	fn cancel_named(handle: &mut impl PrecompileHandle, id: H256) -> EvmResult {
+		let origin = Self::governance_origin(handle)?;
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			origin,
+			pallet_scheduler::Call::<Runtime>::cancel_named { id: id.0 },
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	fn decode_call(
+		call: BoundedBytes<CallLimit>,
+	) -> EvmResult<Box<<Runtime as pallet_scheduler::Config>::RuntimeCall>> {
+		let call: Vec<u8> = call.into();
+		<Runtime as pallet_scheduler::Config>::RuntimeCall::decode_with_depth_limit(
+			DecodeLimit::get(),
+			&mut &*call,
+		)
+		.map(Box::new)
+		.map_err(|_| {
+			RevertReason::custom("Failed to decode call")
+				.in_field("call")
+				.into()
+		})
+	}
+
+	/// Runtime origin of the governance origin the caller is registered for.
+	fn governance_origin(handle: &mut impl PrecompileHandle) -> EvmResult<OriginOf<Runtime>> {
+		// Storage item: AddressOrigins: Blake2_128(16) + H160(20) + GovernanceOrigin(1)
+		handle.record_db_read::<Runtime>(37)?;
+
+		let governance_origin = pallet_governance_origin_registry::Pallet::<Runtime>::origin_of(
+			handle.context().caller,
+		)
+		.ok_or_else(|| revert("Caller is not a governance origin"))?;
+
+		GovernanceOrigins::convert(governance_origin)
+			.ok_or_else(|| revert("Governance origin cannot schedule calls"))
+	}
+}
@@ -0,0 +1,213 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use frame_support::traits::Hooks;
+use parity_scale_codec::Encode;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::H256;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+const TASK_ID: H256 = H256([0x01; 32]);
+
+/// Encoded call writing `value` under the `key` storage key, which requires the root origin.
+fn set_storage_call() -> Vec<u8> {
+	RuntimeCall::System(frame_system::Call::set_storage {
+		items: vec![(b"key".to_vec(), b"value".to_vec())],
+	})
+	.encode()
+}
+
+fn scheduled_count(when: BlockNumber) -> usize {
+	pallet_scheduler::Agenda::<Runtime>::get(when)
+		.iter()
+		.flatten()
+		.count()
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(&["Scheduler.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::schedule_selectors().contains(&0xbb8954ce));
+	assert!(PCall::schedule_named_selectors().contains(&0xbd4fb45e));
+	assert!(PCall::cancel_selectors().contains(&0xb13a05fd));
+	assert!(PCall::cancel_named_selectors().contains(&0x802f7948));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_default_modifier(PCall::schedule_selectors());
+		tester.test_default_modifier(PCall::schedule_named_selectors());
+		tester.test_default_modifier(PCall::cancel_selectors());
+		tester.test_default_modifier(PCall::cancel_named_selectors());
+	});
+}
+
+#[test]
+fn governance_schedules_call_dispatched_with_its_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::schedule {
+					when: 5,
+					priority: 0,
+					call: set_storage_call().into(),
+				},
+			)
+			.execute_returns(());
+		assert_eq!(scheduled_count(5), 1);
+
+		Scheduler::on_initialize(5);
+		assert_eq!(
+			sp_io::storage::get(b"key").map(|value| value.to_vec()),
+			Some(b"value".to_vec())
+		);
+	});
+}
+
+#[test]
+fn governance_cancels_scheduled_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::schedule {
+					when: 5,
+					priority: 0,
+					call: set_storage_call().into(),
+				},
+			)
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::cancel { when: 5, index: 0 })
+			.execute_returns(());
+		assert_eq!(scheduled_count(5), 0);
+
+		Scheduler::on_initialize(5);
+		assert_eq!(sp_io::storage::get(b"key"), None);
+	});
+}
+
+#[test]
+fn governance_schedules_and_cancels_named_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::schedule_named {
+					id: TASK_ID,
+					when: 5,
+					priority: 0,
+					call: set_storage_call().into(),
+				},
+			)
+			.execute_returns(());
+		assert!(pallet_scheduler::Lookup::<Runtime>::contains_key(TASK_ID.0));
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::cancel_named { id: TASK_ID })
+			.execute_returns(());
+		assert!(!pallet_scheduler::Lookup::<Runtime>::contains_key(
+			TASK_ID.0
+		));
+		assert_eq!(scheduled_count(5), 0);
+	});
+}
+
+#[test]
+fn schedule_by_non_governance_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				David,
+				Precompile1,
+				PCall::schedule {
+					when: 5,
+					priority: 0,
+					call: set_storage_call().into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Caller is not a governance origin");
+
+		assert_eq!(scheduled_count(5), 0);
+	});
+}
+
+#[test]
+fn schedule_by_origin_not_scheduling_calls_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Charlie,
+				Precompile1,
+				PCall::schedule {
+					when: 5,
+					priority: 0,
+					call: set_storage_call().into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Governance origin cannot schedule calls");
+	});
+}
+
+#[test]
+fn schedule_by_origin_failing_scheduler_check_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::schedule {
+					when: 5,
+					priority: 0,
+					call: set_storage_call().into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Dispatched call failed with error: BadOrigin");
+	});
+}
+
+#[test]
+fn schedule_undecodable_call_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::schedule {
+					when: 5,
+					priority: 0,
+					call: vec![0xff; 4].into(),
+				},
+			)
+			.execute_reverts(|output| output == b"call: Failed to decode call");
+	});
+}
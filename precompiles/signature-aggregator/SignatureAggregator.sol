@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The SignatureAggregator contract's address.
+address constant SIGNATURE_AGGREGATOR_ADDRESS = 0x000000000000000000000000000000000000081D;
+
+/// @dev The SignatureAggregator contract's instance.
+SignatureAggregator constant SIGNATURE_AGGREGATOR_CONTRACT = SignatureAggregator(
+    SIGNATURE_AGGREGATOR_ADDRESS
+);
+
+/// @author The Moonbeam Team
+/// @title ERC-4337 ECDSA signature aggregator
+/// @title An IAggregator implementation for ERC-4337 EntryPoint contracts, verifying a bundle
+/// of ECDSA signatures in a single call.
+/// The signature of a user operation is the address of the expected signer (20 bytes) followed
+/// by its ECDSA signature (65 bytes) of the user operation hash. The account must ensure the
+/// signer prefix of the signature is its owner.
+/// @custom:address 0x000000000000000000000000000000000000081D
+interface SignatureAggregator {
+    /// @dev ERC-4337 (v0.6) user operation.
+    struct UserOperation {
+        address sender;
+        uint256 nonce;
+        bytes initCode;
+        bytes callData;
+        uint256 callGasLimit;
+        uint256 verificationGasLimit;
+        uint256 preVerificationGas;
+        uint256 maxFeePerGas;
+        uint256 maxPriorityFeePerGas;
+        bytes paymasterAndData;
+        bytes signature;
+    }
+
+    /// @dev Validate the aggregated signature of user operations, reverts if it is invalid.
+    /// The caller is expected to be the EntryPoint the user operations are submitted to.
+    /// @custom:selector e3563a4f
+    /// @param userOps The user operations, whose signature starts with the signer address
+    /// @param signature The concatenated 65 bytes ECDSA signatures of the user operations
+    function validateSignatures(
+        UserOperation[] calldata userOps,
+        bytes calldata signature
+    ) external view;
+
+    /// @dev Validate the signature of a single user operation, reverts if it is invalid.
+    /// The caller is expected to be the EntryPoint the user operation is submitted to.
+    /// @custom:selector 64c530cd
+    /// @param userOp The user operation, signed with its signer address prepended
+    /// @return sigForUserOp The signature to use in the user operation once aggregated
+    function validateUserOpSignature(
+        UserOperation calldata userOp
+    ) external view returns (bytes memory sigForUserOp);
+
+    /// @dev Aggregate the signatures of user operations into a single signature.
+    /// @custom:selector 275e2d79
+    /// @param userOps The user operations, signed with their signer address prepended
+    /// @return aggregatedSignature The concatenated ECDSA signatures
+    function aggregateSignatures(
+        UserOperation[] calldata userOps
+    ) external view returns (bytes memory aggregatedSignature);
+}
@@ -0,0 +1,237 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile implementing the ERC-4337 IAggregator interface with ECDSA signatures, allowing
+//! an EntryPoint contract to verify the signatures of a whole bundle of user operations in a
+//! single call.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use precompile_utils::prelude::*;
+use sp_core::{ConstU32, Get, H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Maximum number of user operations handled in a single call.
+pub const MAX_USER_OPERATIONS: u32 = 256;
+/// Length of the signer address prepended to the signature of a user operation.
+pub const SIGNER_LENGTH: usize = 20;
+/// Length of an ECDSA signature (r, s, v).
+pub const ECDSA_SIGNATURE_LENGTH: usize = 65;
+/// Cost of the ECRecover precompile, charged for each verified signature.
+pub const ECRECOVER_COST: u64 = 3_000;
+
+type GetMaxUserOperations = ConstU32<MAX_USER_OPERATIONS>;
+
+/// ERC-4337 (v0.6) user operation.
+#[derive(Clone, solidity::Codec)]
+pub struct UserOperation {
+	pub sender: Address,
+	pub nonce: U256,
+	pub init_code: UnboundedBytes,
+	pub call_data: UnboundedBytes,
+	pub call_gas_limit: U256,
+	pub verification_gas_limit: U256,
+	pub pre_verification_gas: U256,
+	pub max_fee_per_gas: U256,
+	pub max_priority_fee_per_gas: U256,
+	pub paymaster_and_data: UnboundedBytes,
+	pub signature: UnboundedBytes,
+}
+
+impl UserOperation {
+	/// Hash of the user operation, as computed by `EntryPoint.getUserOpHash`.
+	pub fn hash(&self, entry_point: H160, chain_id: u64) -> H256 {
+		let packed = solidity::encode_arguments((
+			self.sender,
+			self.nonce,
+			H256(keccak_256(self.init_code.as_bytes())),
+			H256(keccak_256(self.call_data.as_bytes())),
+			self.call_gas_limit,
+			self.verification_gas_limit,
+			self.pre_verification_gas,
+			self.max_fee_per_gas,
+			self.max_priority_fee_per_gas,
+			H256(keccak_256(self.paymaster_and_data.as_bytes())),
+		));
+
+		H256(keccak_256(&solidity::encode_arguments((
+			H256(keccak_256(&packed)),
+			Address(entry_point),
+			U256::from(chain_id),
+		))))
+	}
+
+	/// Digest signed by the signer of the user operation (EIP-191 message of its hash).
+	pub fn signed_digest(&self, entry_point: H160, chain_id: u64) -> H256 {
+		let mut message = Vec::with_capacity(60);
+		message.extend_from_slice(b"\x19Ethereum Signed Message:\n32");
+		message.extend_from_slice(self.hash(entry_point, chain_id).as_bytes());
+		H256(keccak_256(&message))
+	}
+
+	/// Gas cost of computing `signed_digest`.
+	fn digest_cost(&self) -> u64 {
+		keccak_cost(self.init_code.as_bytes().len())
+			+ keccak_cost(self.call_data.as_bytes().len())
+			+ keccak_cost(self.paymaster_and_data.as_bytes().len())
+			// packed user operation, hash with the entry point and chain id, signed message
+			+ keccak_cost(10 * 32)
+			+ keccak_cost(3 * 32)
+			+ keccak_cost(60)
+	}
+
+	/// Signer address prepended to the signature.
+	fn signer(&self) -> EvmResult<H160> {
+		let signature = self.signature.as_bytes();
+		if signature.len() < SIGNER_LENGTH {
+			return Err(RevertReason::custom("Missing signer")
+				.in_field("signature")
+				.into());
+		}
+
+		Ok(H160::from_slice(&signature[..SIGNER_LENGTH]))
+	}
+
+	/// ECDSA signature following the signer address.
+	fn ecdsa_signature(&self) -> EvmResult<&[u8]> {
+		let signature = self.signature.as_bytes();
+		if signature.len() != SIGNER_LENGTH + ECDSA_SIGNATURE_LENGTH {
+			return Err(
+				RevertReason::custom("Expected a signer followed by a signature")
+					.in_field("signature")
+					.into(),
+			);
+		}
+
+		Ok(&signature[SIGNER_LENGTH..])
+	}
+}
+
+/// Gas cost of the keccak256 of `len` bytes.
+fn keccak_cost(len: usize) -> u64 {
+	30 + 6 * ((len as u64 + 31) / 32)
+}
+
+/// A precompile verifying the ECDSA signatures of ERC-4337 user operations on behalf of an
+/// EntryPoint contract.
+pub struct SignatureAggregatorPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> SignatureAggregatorPrecompile<Runtime>
+where
+	Runtime: pallet_evm::Config,
+{
+	#[precompile::public(
+		"validateSignatures((address,uint256,bytes,bytes,uint256,uint256,uint256,uint256,\
+		uint256,bytes,bytes)[],bytes)"
+	)]
+	#[precompile::view]
+	fn validate_signatures(
+		handle: &mut impl PrecompileHandle,
+		user_ops: BoundedVec<UserOperation, GetMaxUserOperations>,
+		signature: UnboundedBytes,
+	) -> EvmResult {
+		let entry_point = handle.context().caller;
+		let user_ops: Vec<UserOperation> = user_ops.into();
+		let signature = signature.as_bytes();
+
+		if signature.len() != user_ops.len() * ECDSA_SIGNATURE_LENGTH {
+			return Err(
+				RevertReason::custom("Expected one signature per user operation")
+					.in_field("signature")
+					.into(),
+			);
+		}
+
+		for (user_op, signature) in user_ops
+			.iter()
+			.zip(signature.chunks_exact(ECDSA_SIGNATURE_LENGTH))
+		{
+			Self::verify(handle, user_op, entry_point, signature)?;
+		}
+
+		Ok(())
+	}
+
+	#[precompile::public(
+		"validateUserOpSignature((address,uint256,bytes,bytes,uint256,uint256,uint256,uint256,\
+		uint256,bytes,bytes))"
+	)]
+	#[precompile::view]
+	fn validate_user_op_signature(
+		handle: &mut impl PrecompileHandle,
+		user_op: UserOperation,
+	) -> EvmResult<UnboundedBytes> {
+		let entry_point = handle.context().caller;
+		Self::verify(handle, &user_op, entry_point, user_op.ecdsa_signature()?)?;
+
+		// The ECDSA signature is moved to the aggregated signature, only the signer is kept.
+		Ok(user_op.signer()?.as_bytes().into())
+	}
+
+	#[precompile::public(
+		"aggregateSignatures((address,uint256,bytes,bytes,uint256,uint256,uint256,uint256,\
+		uint256,bytes,bytes)[])"
+	)]
+	#[precompile::view]
+	fn aggregate_signatures(
+		_handle: &mut impl PrecompileHandle,
+		user_ops: BoundedVec<UserOperation, GetMaxUserOperations>,
+	) -> EvmResult<UnboundedBytes> {
+		let user_ops: Vec<UserOperation> = user_ops.into();
+		let mut aggregated = Vec::with_capacity(user_ops.len() * ECDSA_SIGNATURE_LENGTH);
+		for user_op in &user_ops {
+			aggregated.extend_from_slice(user_op.ecdsa_signature()?);
+		}
+
+		Ok(aggregated.into())
+	}
+
+	fn verify(
+		handle: &mut impl PrecompileHandle,
+		user_op: &UserOperation,
+		entry_point: H160,
+		signature: &[u8],
+	) -> EvmResult {
+		handle.record_cost(user_op.digest_cost() + ECRECOVER_COST)?;
+
+		let signer = user_op.signer()?;
+		let digest = user_op.signed_digest(entry_point, Runtime::ChainId::get());
+
+		let mut sig = [0u8; ECDSA_SIGNATURE_LENGTH];
+		sig.copy_from_slice(signature);
+		// Ethereum signatures use 27 and 28 as recovery ids.
+		if sig[64] >= 27 {
+			sig[64] -= 27;
+		}
+
+		let recovered = sp_io::crypto::secp256k1_ecdsa_recover(&sig, digest.as_fixed_bytes())
+			.map_err(|_| revert("Invalid signature"))?;
+		let recovered = H160::from(H256::from_slice(keccak_256(&recovered).as_slice()));
+		if recovered != signer {
+			return Err(revert("Signature does not match the signer"));
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,245 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use libsecp256k1::{Message, PublicKey, SecretKey};
+use precompile_utils::testing::*;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn secret_key(seed: u8) -> SecretKey {
+	SecretKey::parse(&[seed; 32]).expect("valid secret key")
+}
+
+fn address_of(secret: &SecretKey) -> H160 {
+	let public = PublicKey::from_secret_key(secret);
+	H160::from(H256::from_slice(&keccak_256(&public.serialize()[1..])))
+}
+
+fn user_operation(sender: impl Into<H160>, nonce: u64) -> UserOperation {
+	UserOperation {
+		sender: Address(sender.into()),
+		nonce: nonce.into(),
+		init_code: UnboundedBytes::from(&[][..]),
+		call_data: UnboundedBytes::from(&b"call"[..]),
+		call_gas_limit: 100_000.into(),
+		verification_gas_limit: 100_000.into(),
+		pre_verification_gas: 21_000.into(),
+		max_fee_per_gas: 1_000.into(),
+		max_priority_fee_per_gas: 1.into(),
+		paymaster_and_data: UnboundedBytes::from(&[][..]),
+		signature: UnboundedBytes::from(&[][..]),
+	}
+}
+
+/// ECDSA signature of the user operation for the given entry point.
+fn ecdsa_signature(
+	user_op: &UserOperation,
+	secret: &SecretKey,
+	entry_point: impl Into<H160>,
+) -> Vec<u8> {
+	let digest = user_op.signed_digest(entry_point.into(), CHAIN_ID);
+	let (signature, recovery_id) =
+		libsecp256k1::sign(&Message::parse(digest.as_fixed_bytes()), secret);
+
+	let mut output = signature.serialize().to_vec();
+	output.push(recovery_id.serialize() + 27);
+	output
+}
+
+/// Signs the user operation for the given entry point, prepending the signer address.
+fn signed(
+	mut user_op: UserOperation,
+	secret: &SecretKey,
+	entry_point: impl Into<H160>,
+) -> UserOperation {
+	let mut signature = address_of(secret).as_bytes().to_vec();
+	signature.extend(ecdsa_signature(&user_op, secret, entry_point));
+	user_op.signature = signature.into();
+	user_op
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["SignatureAggregator.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::validate_signatures_selectors().contains(&0xe3563a4f));
+	assert!(PCall::validate_user_op_signature_selectors().contains(&0x64c530cd));
+	assert!(PCall::aggregate_signatures_selectors().contains(&0x275e2d79));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_view_modifier(PCall::validate_signatures_selectors());
+		tester.test_view_modifier(PCall::validate_user_op_signature_selectors());
+		tester.test_view_modifier(PCall::aggregate_signatures_selectors());
+	});
+}
+
+#[test]
+fn user_op_signature_is_validated_and_reduced_to_signer() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret = secret_key(1);
+		let user_op = signed(user_operation(Bob, 0), &secret, Alice);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::validate_user_op_signature { user_op },
+			)
+			.expect_no_logs()
+			.execute_returns(UnboundedBytes::from(address_of(&secret).as_bytes()));
+	});
+}
+
+#[test]
+fn user_op_signature_must_match_signer() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut user_op = user_operation(Bob, 0);
+		let mut signature = address_of(&secret_key(2)).as_bytes().to_vec();
+		signature.extend(ecdsa_signature(&user_op, &secret_key(1), Alice));
+		user_op.signature = signature.into();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::validate_user_op_signature { user_op },
+			)
+			.execute_reverts(|output| output == b"Signature does not match the signer");
+	});
+}
+
+#[test]
+fn user_op_signature_must_include_signer() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut user_op = user_operation(Bob, 0);
+		user_op.signature = ecdsa_signature(&user_op, &secret_key(1), Alice).into();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::validate_user_op_signature { user_op },
+			)
+			.execute_reverts(|output| {
+				output == b"signature: Expected a signer followed by a signature"
+			});
+	});
+}
+
+#[test]
+fn signatures_are_aggregated() {
+	ExtBuilder::default().build().execute_with(|| {
+		let first = signed(user_operation(Bob, 0), &secret_key(1), Alice);
+		let second = signed(user_operation(Charlie, 0), &secret_key(2), Alice);
+
+		let mut expected = first.signature.as_bytes()[SIGNER_LENGTH..].to_vec();
+		expected.extend_from_slice(&second.signature.as_bytes()[SIGNER_LENGTH..]);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::aggregate_signatures {
+					user_ops: vec![first, second].into(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(UnboundedBytes::from(expected));
+	});
+}
+
+#[test]
+fn aggregated_signatures_are_validated() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut user_ops = vec![user_operation(Bob, 0), user_operation(Charlie, 3)];
+		let mut aggregated = Vec::new();
+		for (user_op, seed) in user_ops.iter_mut().zip([1, 2]) {
+			let secret = secret_key(seed);
+			aggregated.extend(ecdsa_signature(user_op, &secret, Alice));
+			// As returned by `validateUserOpSignature`.
+			user_op.signature = address_of(&secret).as_bytes().into();
+		}
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::validate_signatures {
+					user_ops: user_ops.into(),
+					signature: aggregated.into(),
+				},
+			)
+			.expect_no_logs()
+			.expect_cost(2 * (ECRECOVER_COST + 30 + 36 + 30 + 90 + 48 + 42))
+			.execute_returns(());
+	});
+}
+
+#[test]
+fn aggregated_signature_is_bound_to_entry_point() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret = secret_key(1);
+		let mut user_op = user_operation(Bob, 0);
+		let signature = ecdsa_signature(&user_op, &secret, Alice);
+		user_op.signature = address_of(&secret).as_bytes().into();
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::validate_signatures {
+					user_ops: vec![user_op].into(),
+					signature: signature.into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Signature does not match the signer");
+	});
+}
+
+#[test]
+fn aggregated_signature_length_must_match_user_ops() {
+	ExtBuilder::default().build().execute_with(|| {
+		let user_op = signed(user_operation(Bob, 0), &secret_key(1), Alice);
+		let signature = user_op.signature.as_bytes()[SIGNER_LENGTH..].to_vec();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::validate_signatures {
+					user_ops: vec![user_op.clone(), user_op].into(),
+					signature: signature.into(),
+				},
+			)
+			.execute_reverts(|output| {
+				output == b"signature: Expected one signature per user operation"
+			});
+	});
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The SovereignAccounts contract's address.
+address constant SOVEREIGN_ACCOUNTS_ADDRESS = 0x0000000000000000000000000000000000000825;
+
+/// @dev The SovereignAccounts contract's instance.
+SovereignAccounts constant SOVEREIGN_ACCOUNTS_CONTRACT = SovereignAccounts(
+    SOVEREIGN_ACCOUNTS_ADDRESS
+);
+
+/// @author The Moonbeam Team
+/// @title Sovereign Accounts Interface
+/// @dev The interface through which solidity contracts compute the accounts the relay chain and
+/// the sibling parachains hold on this chain, and governance moves funds out of them.
+/// @custom:address 0x0000000000000000000000000000000000000825
+interface SovereignAccounts {
+    /// @dev The sovereign account of a sibling parachain.
+    /// @custom:selector f06afac4
+    /// @param paraId The id of the sibling parachain
+    /// @return account The sovereign account of the parachain
+    function sovereignOf(uint32 paraId) external view returns (address account);
+
+    /// @dev The sovereign account of the relay chain.
+    /// @custom:selector 8734fa0e
+    /// @return account The sovereign account of the relay chain
+    function parentSovereign() external view returns (address account);
+
+    /// @dev Transfer native tokens from the sovereign account of a sibling parachain. Only
+    /// callable by governance.
+    /// @custom:selector 6f8b313d
+    /// @param paraId The id of the sibling parachain
+    /// @param to The recipient of the tokens
+    /// @param amount The amount of tokens to transfer
+    function transferFromSovereign(
+        uint32 paraId,
+        address to,
+        uint256 amount
+    ) external;
+}
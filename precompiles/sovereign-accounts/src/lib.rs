@@ -0,0 +1,119 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile exposing the sovereign accounts the relay chain and the sibling parachains hold on
+//! this chain, and allowing governance to transfer funds out of the sibling ones.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	ensure,
+	traits::Get,
+};
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::{H160, U256};
+use sp_runtime::traits::StaticLookup;
+use sp_std::marker::PhantomData;
+use xcm::latest::{Junction, Junctions, MultiLocation};
+use xcm_executor::traits::Convert;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type BalanceOf<Runtime> = <Runtime as pallet_balances::Config>::Balance;
+
+/// A precompile exposing the sovereign accounts of the parent and sibling chains.
+/// `LocationToAccountId` is the conversion used by the XCM executor, and `GovernanceCaller` the
+/// only address allowed to transfer funds out of the sovereign accounts. It must be an address
+/// no one controls, such that only the root origin can dispatch EVM calls from it.
+pub struct SovereignAccountsPrecompile<Runtime, LocationToAccountId, GovernanceCaller>(
+	PhantomData<(Runtime, LocationToAccountId, GovernanceCaller)>,
+);
+
+#[precompile_utils::precompile]
+impl<Runtime, LocationToAccountId, GovernanceCaller>
+	SovereignAccountsPrecompile<Runtime, LocationToAccountId, GovernanceCaller>
+where
+	Runtime: pallet_balances::Config + pallet_evm::Config,
+	Runtime::AccountId: Into<H160>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_balances::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	BalanceOf<Runtime>: TryFrom<U256>,
+	LocationToAccountId: Convert<MultiLocation, Runtime::AccountId>,
+	GovernanceCaller: Get<H160>,
+{
+	#[precompile::public("sovereignOf(uint32)")]
+	#[precompile::view]
+	fn sovereign_of(_handle: &mut impl PrecompileHandle, para_id: u32) -> EvmResult<Address> {
+		Ok(Address(Self::sibling_sovereign(para_id)?.into()))
+	}
+
+	#[precompile::public("parentSovereign()")]
+	#[precompile::view]
+	fn parent_sovereign(_handle: &mut impl PrecompileHandle) -> EvmResult<Address> {
+		let account = LocationToAccountId::convert(MultiLocation::parent())
+			.map_err(|_| revert("Relay chain location cannot be converted"))?;
+
+		Ok(Address(account.into()))
+	}
+
+	#[precompile::public("transferFromSovereign(uint32,address,uint256)")]
+	fn transfer_from_sovereign(
+		handle: &mut impl PrecompileHandle,
+		para_id: u32,
+		to: Address,
+		amount: U256,
+	) -> EvmResult {
+		ensure!(
+			handle.context().caller == GovernanceCaller::get(),
+			revert("Caller is not governance")
+		);
+
+		let sovereign = Self::sibling_sovereign(para_id)?;
+		let to = Runtime::AddressMapping::into_account_id(to.into());
+		let amount: BalanceOf<Runtime> = amount
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field("amount"))?;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(sovereign).into(),
+			pallet_balances::Call::<Runtime>::transfer {
+				dest: Runtime::Lookup::unlookup(to),
+				value: amount,
+			},
+			SYSTEM_ACCOUNT_SIZE,
+		)?;
+
+		Ok(())
+	}
+
+	fn sibling_sovereign(para_id: u32) -> EvmResult<Runtime::AccountId> {
+		let location = MultiLocation {
+			parents: 1,
+			interior: Junctions::X1(Junction::Parachain(para_id)),
+		};
+
+		LocationToAccountId::convert(location)
+			.map_err(|_| revert("Sibling parachain location cannot be converted"))
+	}
+}
@@ -0,0 +1,141 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, U256};
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn sibling(para_id: u32) -> H160 {
+	let mut account = [0u8; 20];
+	account[0..4].copy_from_slice(b"sibl");
+	account[4..8].copy_from_slice(&para_id.to_le_bytes());
+	H160(account)
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["SovereignAccounts.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::sovereign_of_selectors().contains(&0xf06afac4));
+	assert!(PCall::parent_sovereign_selectors().contains(&0x8734fa0e));
+	assert!(PCall::transfer_from_sovereign_selectors().contains(&0x6f8b313d));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_view_modifier(PCall::sovereign_of_selectors());
+		tester.test_view_modifier(PCall::parent_sovereign_selectors());
+		tester.test_default_modifier(PCall::transfer_from_sovereign_selectors());
+	});
+}
+
+#[test]
+fn sovereign_of_returns_sibling_sovereign_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::sovereign_of { para_id: 2000 })
+			.expect_no_logs()
+			.execute_returns(Address(sibling(2000)));
+	});
+}
+
+#[test]
+fn parent_sovereign_returns_relay_sovereign_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut parent = [0u8; 20];
+		parent[0..6].copy_from_slice(b"Parent");
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::parent_sovereign {})
+			.expect_no_logs()
+			.execute_returns(Address(H160(parent)));
+	});
+}
+
+#[test]
+fn governance_transfers_from_sovereign() {
+	ExtBuilder::default()
+		.with_balances(vec![(sibling(2000).into(), 1000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Charlie,
+					Precompile1,
+					PCall::transfer_from_sovereign {
+						para_id: 2000,
+						to: Address(Bob.into()),
+						amount: 400.into(),
+					},
+				)
+				.execute_returns(());
+
+			assert_eq!(Balances::free_balance(&sibling(2000).into()), 600);
+			assert_eq!(Balances::free_balance(&Bob.into()), 400);
+		});
+}
+
+#[test]
+fn transfer_from_sovereign_by_non_governance_reverts() {
+	ExtBuilder::default()
+		.with_balances(vec![(sibling(2000).into(), 1000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::transfer_from_sovereign {
+						para_id: 2000,
+						to: Address(Alice.into()),
+						amount: 400.into(),
+					},
+				)
+				.execute_reverts(|output| output == b"Caller is not governance");
+
+			assert_eq!(Balances::free_balance(&sibling(2000).into()), 1000);
+		});
+}
+
+#[test]
+fn transfer_from_sovereign_with_too_large_amount_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Charlie,
+				Precompile1,
+				PCall::transfer_from_sovereign {
+					para_id: 2000,
+					to: Address(Bob.into()),
+					amount: U256::MAX,
+				},
+			)
+			.execute_reverts(|output| output == b"amount: Value is too large for balance type");
+	});
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The TokenRouteRegistry contract's address.
+address constant TOKEN_ROUTE_REGISTRY_ADDRESS = 0x0000000000000000000000000000000000000829;
+
+/// @dev The TokenRouteRegistry contract's instance.
+TokenRouteRegistry constant TOKEN_ROUTE_REGISTRY_CONTRACT = TokenRouteRegistry(
+    TOKEN_ROUTE_REGISTRY_ADDRESS
+);
+
+/// @author The Moonbeam Team
+/// @title Token route registry interface
+/// @title The interface through which solidity contracts can query the canonical route a token
+/// takes to reach a destination chain, as recorded by governance
+/// @custom:address 0x0000000000000000000000000000000000000829
+interface TokenRouteRegistry {
+    // A multilocation is defined by its number of parents and the encoded junctions (interior)
+    struct Multilocation {
+        uint8 parents;
+        bytes[] interior;
+    }
+
+    // The route of a token to a destination chain
+    struct Route {
+        // Protocol bridging the token into this chain: 0 for XCM, 1 for Wormhole
+        uint8 bridgeIn;
+        // Chains the token goes through before reaching the final chain, in order
+        Multilocation[] hops;
+        // Final chain of the route
+        Multilocation destination;
+        // Format of the accounts on the final chain: 0 for 32 bytes, 1 for 20 bytes
+        uint8 accountFormat;
+    }
+
+    /// @dev The route of a token to a destination chain. Reverts if there is none.
+    /// @custom:selector 3b20dd77
+    /// @param token The ERC-20 address of the token
+    /// @param destChain The identifier of the destination chain
+    /// @return The route of the token to the destination chain
+    function routeFor(address token, bytes32 destChain)
+        external
+        view
+        returns (Route memory);
+}
@@ -0,0 +1,80 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to query the canonical cross-chain routes of the tokens recorded in
+//! pallet-token-route-registry.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use pallet_token_route_registry::Route as RegistryRoute;
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::H256;
+use sp_std::{marker::PhantomData, vec::Vec};
+use xcm::latest::MultiLocation;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Route of a token to a destination chain, as returned to the EVM.
+#[derive(Debug, PartialEq, Eq, solidity::Codec)]
+pub struct Route {
+	/// Protocol bridging the token into this chain: 0 for XCM, 1 for Wormhole.
+	pub bridge_in: u8,
+	pub hops: Vec<MultiLocation>,
+	pub destination: MultiLocation,
+	/// Format of the accounts on the final chain: 0 for 32 bytes, 1 for 20 bytes.
+	pub account_format: u8,
+}
+
+impl<MaxHops: frame_support::traits::Get<u32>> From<RegistryRoute<MaxHops>> for Route {
+	fn from(route: RegistryRoute<MaxHops>) -> Self {
+		Self {
+			bridge_in: route.bridge_in as u8,
+			hops: route.hops.into_inner(),
+			destination: route.destination,
+			account_format: route.account_format as u8,
+		}
+	}
+}
+
+/// A precompile to query the route a token takes to reach a destination chain.
+pub struct TokenRouteRegistryPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> TokenRouteRegistryPrecompile<Runtime>
+where
+	Runtime: pallet_token_route_registry::Config + pallet_evm::Config,
+{
+	#[precompile::public("routeFor(address,bytes32)")]
+	#[precompile::view]
+	fn route_for(
+		handle: &mut impl PrecompileHandle,
+		token: Address,
+		dest_chain: H256,
+	) -> EvmResult<Route> {
+		// Storage item: Routes: Blake2_128(16) + H160(20) + Blake2_128(16) + H256(32) + Route
+		handle
+			.record_db_read::<Runtime>(84 + RegistryRoute::<Runtime::MaxHops>::max_encoded_len())?;
+
+		pallet_token_route_registry::Pallet::<Runtime>::route_for(token.0, dest_chain)
+			.map(Into::into)
+			.ok_or_else(|| revert("No route for the token to the destination chain"))
+	}
+}
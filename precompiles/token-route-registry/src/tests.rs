@@ -0,0 +1,104 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, Route};
+use frame_support::assert_ok;
+use pallet_token_route_registry::{AccountFormat, BridgeProtocol};
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::H256;
+use xcm::latest::{Junction, Junctions, MultiLocation};
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+const DEST_CHAIN: H256 = H256([0x01; 32]);
+
+fn asset_hub() -> MultiLocation {
+	MultiLocation::new(1, Junctions::X1(Junction::Parachain(1000)))
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["TokenRouteRegistry.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::route_for_selectors().contains(&0x3b20dd77));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_view_modifier(PCall::route_for_selectors());
+	});
+}
+
+#[test]
+fn route_for_returns_registered_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TokenRouteRegistry::set_route(
+			RuntimeOrigin::root(),
+			Bob.into(),
+			DEST_CHAIN,
+			pallet_token_route_registry::Route {
+				bridge_in: BridgeProtocol::Wormhole,
+				hops: vec![asset_hub()].try_into().unwrap(),
+				destination: MultiLocation::parent(),
+				account_format: AccountFormat::AccountId32,
+			}
+		));
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::route_for {
+					token: Address(Bob.into()),
+					dest_chain: DEST_CHAIN,
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(Route {
+				bridge_in: 1,
+				hops: vec![asset_hub()],
+				destination: MultiLocation::parent(),
+				account_format: 0,
+			});
+	});
+}
+
+#[test]
+fn route_for_unknown_route_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::route_for {
+					token: Address(Bob.into()),
+					dest_chain: DEST_CHAIN,
+				},
+			)
+			.execute_reverts(|output| output == b"No route for the token to the destination chain");
+	});
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @author The Moonbeam Team
+/// @title ERC721 interface
+/// @dev see https://eips.ethereum.org/EIPS/eip-721
+/// @dev Each collection of NFTs is exposed at the address made of the NFT collections prefix
+/// followed by the collection id, and its items are identified by their item id as token id.
+/// Operator approvals are not supported.
+interface IERC721 {
+    /// @dev Returns the name of the collection, as set in its metadata.
+    /// @custom:selector 06fdde03
+    function name() external view returns (string memory);
+
+    /// @dev Returns the metadata URI of a token, as set in its metadata.
+    /// @custom:selector c87b56dd
+    /// @param tokenId The token to query the metadata URI of.
+    function tokenURI(uint256 tokenId) external view returns (string memory);
+
+    /// @dev Gets the number of tokens of the collection owned by an address.
+    /// @custom:selector 70a08231
+    /// @param owner The address to query the balance of.
+    /// @return The number of tokens owned by the address.
+    function balanceOf(address owner) external view returns (uint256);
+
+    /// @dev Gets the owner of a token.
+    /// @custom:selector 6352211e
+    /// @param tokenId The token to query the owner of.
+    /// @return The address owning the token.
+    function ownerOf(uint256 tokenId) external view returns (address);
+
+    /// @dev Gets the address approved to transfer a token, the zero address if there is none.
+    /// @custom:selector 081812fc
+    /// @param tokenId The token to query the approved address of.
+    /// @return The address approved to transfer the token.
+    function getApproved(uint256 tokenId) external view returns (address);
+
+    /// @dev Whether an operator can transfer all the tokens of an owner, which is never the case
+    /// as operator approvals are not supported.
+    /// @custom:selector e985e9c5
+    /// @param owner The owner of the tokens.
+    /// @param operator The operator to check.
+    /// @return Always false.
+    function isApprovedForAll(address owner, address operator)
+        external
+        view
+        returns (bool);
+
+    /// @dev Approve an address to transfer a token owned by msg.sender, or remove the approval
+    /// with the zero address.
+    /// @custom:selector 095ea7b3
+    /// @param approved The address allowed to transfer the token.
+    /// @param tokenId The token to approve.
+    function approve(address approved, uint256 tokenId) external;
+
+    /// @dev Always reverts, operator approvals are not supported.
+    /// @custom:selector a22cb465
+    /// @param operator The operator.
+    /// @param approved Whether the operator is approved.
+    function setApprovalForAll(address operator, bool approved) external;
+
+    /// @dev Transfer a token, msg.sender being either its owner or the approved address.
+    /// @custom:selector 23b872dd
+    /// @param from The owner of the token.
+    /// @param to The address receiving the token.
+    /// @param tokenId The token to transfer.
+    function transferFrom(
+        address from,
+        address to,
+        uint256 tokenId
+    ) external;
+
+    /// @dev Event emited when a token has been transferred.
+    /// @custom:selector ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef
+    /// @param from The previous owner of the token.
+    /// @param to The new owner of the token.
+    /// @param tokenId The transferred token.
+    event Transfer(
+        address indexed from,
+        address indexed to,
+        uint256 indexed tokenId
+    );
+
+    /// @dev Event emited when the approved address of a token has changed.
+    /// @custom:selector 8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925
+    /// @param owner The owner of the token.
+    /// @param approved The address approved to transfer the token.
+    /// @param tokenId The token.
+    event Approval(
+        address indexed owner,
+        address indexed approved,
+        uint256 indexed tokenId
+    );
+}
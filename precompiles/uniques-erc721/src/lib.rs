@@ -0,0 +1,419 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile set exposing the collections of pallet-uniques as ERC-721 contracts.
+//!
+//! Like XC-20s, the collection with id X is exposed at the address made of a runtime-defined
+//! 4 bytes prefix followed by the 16 bytes of X, and its items are identified by their item id as
+//! token id. pallet-uniques only allows one approved account per item, such that operator
+//! approvals (`setApprovalForAll`) are not supported.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	traits::{
+		tokens::nonfungibles::{Inspect, InspectEnumerable},
+		Currency, Get,
+	},
+	Blake2_128Concat,
+};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::{Decode, Encode};
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::StaticLookup;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity selector of the Transfer log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
+
+/// Solidity selector of the Approval log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
+
+/// Alias for the Collection Id type for the provided Runtime and Instance.
+pub type CollectionIdOf<Runtime, Instance = ()> =
+	<Runtime as pallet_uniques::Config<Instance>>::CollectionId;
+
+/// Alias for the Item Id type for the provided Runtime and Instance.
+pub type ItemIdOf<Runtime, Instance = ()> = <Runtime as pallet_uniques::Config<Instance>>::ItemId;
+
+type DepositBalanceOf<Runtime, Instance> =
+	<<Runtime as pallet_uniques::Config<Instance>>::Currency as Currency<
+		<Runtime as frame_system::Config>::AccountId,
+	>>::Balance;
+
+/// This trait ensure we can convert AccountIds to CollectionIds
+/// We will require Runtime to have this trait implemented
+pub trait AccountIdCollectionIdConversion<Account, CollectionId> {
+	// Get collectionId from account
+	fn account_to_collection_id(account: Account) -> Option<CollectionId>;
+
+	// Get AccountId from CollectionId
+	fn collection_id_to_account(collection_id: CollectionId) -> Account;
+}
+
+/// Mirror of `pallet_uniques::ItemDetails`, whose fields are private, to read the account approved
+/// to transfer an item.
+#[derive(Encode, Decode)]
+#[allow(dead_code)]
+struct ItemDetails<AccountId, DepositBalance> {
+	owner: AccountId,
+	approved: Option<AccountId>,
+	is_frozen: bool,
+	deposit: DepositBalance,
+}
+
+#[frame_support::storage_alias]
+type Asset<T: pallet_uniques::Config<I>, I: 'static> = StorageDoubleMap<
+	pallet_uniques::Pallet<T, I>,
+	Blake2_128Concat,
+	CollectionIdOf<T, I>,
+	Blake2_128Concat,
+	ItemIdOf<T, I>,
+	ItemDetails<<T as frame_system::Config>::AccountId, DepositBalanceOf<T, I>>,
+>;
+
+/// Asset precompiles can only fall between
+/// 	0xFFFFFFFF00000000000000000000000000000000 - 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+/// The precompile for CollectionId X, where X is a u128 (i.e.16 bytes), is Prefix + Bytes(X).
+/// In order to route the address to Erc721CollectionsPrecompileSet<R>, we check whether the
+/// collection exists in pallet-uniques.
+pub struct Erc721CollectionsPrecompileSet<Runtime, Instance: 'static = ()>(
+	PhantomData<(Runtime, Instance)>,
+);
+
+impl<T, U> Clone for Erc721CollectionsPrecompileSet<T, U> {
+	fn clone(&self) -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T, U> Default for Erc721CollectionsPrecompileSet<T, U> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<Runtime, Instance> Erc721CollectionsPrecompileSet<Runtime, Instance> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+#[precompile_utils::precompile]
+#[precompile::precompile_set]
+#[precompile::test_concrete_types(mock::Runtime, ())]
+impl<Runtime, Instance> Erc721CollectionsPrecompileSet<Runtime, Instance>
+where
+	Instance: 'static,
+	Runtime: pallet_uniques::Config<Instance> + pallet_evm::Config + frame_system::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_uniques::Call<Runtime, Instance>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime: AccountIdCollectionIdConversion<Runtime::AccountId, CollectionIdOf<Runtime, Instance>>,
+	ItemIdOf<Runtime, Instance>: TryFrom<U256>,
+	Runtime::AccountId: Into<H160>,
+{
+	/// PrecompileSet discriminant. Allows to knows if the address maps to a collection id,
+	/// and if this is the case which one.
+	#[precompile::discriminant]
+	fn discriminant(
+		address: H160,
+		gas: u64,
+	) -> DiscriminantResult<CollectionIdOf<Runtime, Instance>> {
+		let extra_cost = RuntimeHelper::<Runtime>::db_read_gas_cost();
+		if gas < extra_cost {
+			return DiscriminantResult::OutOfGas;
+		}
+
+		let account_id = Runtime::AddressMapping::into_account_id(address);
+		let collection_id = match Runtime::account_to_collection_id(account_id) {
+			Some(collection_id) => collection_id,
+			None => return DiscriminantResult::None(extra_cost),
+		};
+
+		if pallet_uniques::Pallet::<Runtime, Instance>::collection_owner(&collection_id).is_some() {
+			DiscriminantResult::Some(collection_id, extra_cost)
+		} else {
+			DiscriminantResult::None(extra_cost)
+		}
+	}
+
+	#[precompile::public("name()")]
+	#[precompile::view]
+	fn name(
+		collection_id: CollectionIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<UnboundedBytes> {
+		// Storage item: ClassMetadataOf:
+		// Blake2_128(16) + CollectionId(16) + CollectionMetadata[deposit(16) + data(StringLimit)
+		// + is_frozen(1)]
+		handle.record_map_read::<Runtime>(
+			49 + <Runtime as pallet_uniques::Config<Instance>>::StringLimit::get() as usize,
+		)?;
+
+		// The empty attribute key maps to the metadata of the collection
+		let name =
+			pallet_uniques::Pallet::<Runtime, Instance>::collection_attribute(&collection_id, &[])
+				.unwrap_or_default();
+
+		Ok(name.as_slice().into())
+	}
+
+	#[precompile::public("tokenURI(uint256)")]
+	#[precompile::view]
+	fn token_uri(
+		collection_id: CollectionIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+		token_id: U256,
+	) -> EvmResult<UnboundedBytes> {
+		let item_id = Self::u256_to_item_id(token_id).in_field("tokenId")?;
+		Self::item_details(handle, collection_id.clone(), item_id.clone())?;
+
+		// Storage item: InstanceMetadataOf:
+		// Blake2_128(16) + CollectionId(16) + Blake2_128(16) + ItemId(16)
+		// + ItemMetadata[deposit(16) + data(StringLimit) + is_frozen(1)]
+		handle.record_map_read::<Runtime>(
+			81 + <Runtime as pallet_uniques::Config<Instance>>::StringLimit::get() as usize,
+		)?;
+
+		// The empty attribute key maps to the metadata of the item
+		let uri =
+			pallet_uniques::Pallet::<Runtime, Instance>::attribute(&collection_id, &item_id, &[])
+				.unwrap_or_default();
+
+		Ok(uri.as_slice().into())
+	}
+
+	#[precompile::public("balanceOf(address)")]
+	#[precompile::view]
+	fn balance_of(
+		collection_id: CollectionIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+		owner: Address,
+	) -> EvmResult<U256> {
+		let owner = Runtime::AddressMapping::into_account_id(owner.into());
+
+		// Storage item: Account (read until the end of the owned items):
+		// Blake2_128(16) + AccountId(20) + Blake2_128(16) + CollectionId(16) + Blake2_128(16)
+		// + ItemId(16)
+		handle.record_map_read::<Runtime>(100)?;
+
+		let mut balance = U256::zero();
+		for _ in
+			pallet_uniques::Pallet::<Runtime, Instance>::owned_in_collection(&collection_id, &owner)
+		{
+			handle.record_map_read::<Runtime>(100)?;
+			balance = balance.saturating_add(U256::one());
+		}
+
+		Ok(balance)
+	}
+
+	#[precompile::public("ownerOf(uint256)")]
+	#[precompile::view]
+	fn owner_of(
+		collection_id: CollectionIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+		token_id: U256,
+	) -> EvmResult<Address> {
+		let item_id = Self::u256_to_item_id(token_id).in_field("tokenId")?;
+		let details = Self::item_details(handle, collection_id, item_id)?;
+
+		Ok(Address(details.owner.into()))
+	}
+
+	#[precompile::public("getApproved(uint256)")]
+	#[precompile::view]
+	fn get_approved(
+		collection_id: CollectionIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+		token_id: U256,
+	) -> EvmResult<Address> {
+		let item_id = Self::u256_to_item_id(token_id).in_field("tokenId")?;
+		let details = Self::item_details(handle, collection_id, item_id)?;
+
+		Ok(Address(
+			details.approved.map(Into::into).unwrap_or_default(),
+		))
+	}
+
+	#[precompile::public("isApprovedForAll(address,address)")]
+	#[precompile::view]
+	fn is_approved_for_all(
+		_collection_id: CollectionIdOf<Runtime, Instance>,
+		_handle: &mut impl PrecompileHandle,
+		_owner: Address,
+		_operator: Address,
+	) -> EvmResult<bool> {
+		Ok(false)
+	}
+
+	#[precompile::public("setApprovalForAll(address,bool)")]
+	fn set_approval_for_all(
+		_collection_id: CollectionIdOf<Runtime, Instance>,
+		_handle: &mut impl PrecompileHandle,
+		_operator: Address,
+		_approved: bool,
+	) -> EvmResult {
+		Err(revert("Operator approvals are not supported"))
+	}
+
+	#[precompile::public("approve(address,uint256)")]
+	fn approve(
+		collection_id: CollectionIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+		approved: Address,
+		token_id: U256,
+	) -> EvmResult {
+		handle.record_log_costs_manual(4, 0)?;
+
+		let item_id = Self::u256_to_item_id(token_id).in_field("tokenId")?;
+		let details = Self::item_details(handle, collection_id.clone(), item_id.clone())?;
+		let owner: H160 = details.owner.into();
+		let approved: H160 = approved.into();
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		if approved == H160::zero() {
+			// Approving the zero address clears the approval, if any
+			if details.approved.is_some() {
+				RuntimeHelper::<Runtime>::try_dispatch(
+					handle,
+					Some(origin).into(),
+					pallet_uniques::Call::<Runtime, Instance>::cancel_approval {
+						collection: collection_id,
+						item: item_id,
+						maybe_check_delegate: None,
+					},
+					0,
+				)?;
+			}
+		} else {
+			let delegate = Runtime::AddressMapping::into_account_id(approved);
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(origin).into(),
+				pallet_uniques::Call::<Runtime, Instance>::approve_transfer {
+					collection: collection_id,
+					item: item_id,
+					delegate: Runtime::Lookup::unlookup(delegate),
+				},
+				0,
+			)?;
+		}
+
+		log4(
+			handle.context().address,
+			SELECTOR_LOG_APPROVAL,
+			owner,
+			approved,
+			Self::token_id_topic(token_id),
+			Vec::new(),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("transferFrom(address,address,uint256)")]
+	fn transfer_from(
+		collection_id: CollectionIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+		from: Address,
+		to: Address,
+		token_id: U256,
+	) -> EvmResult {
+		handle.record_log_costs_manual(4, 0)?;
+
+		let from: H160 = from.into();
+		let to: H160 = to.into();
+		let item_id = Self::u256_to_item_id(token_id).in_field("tokenId")?;
+
+		if to == H160::zero() {
+			return Err(RevertReason::custom("Cannot transfer to the zero address")
+				.in_field("to")
+				.into());
+		}
+
+		let details = Self::item_details(handle, collection_id.clone(), item_id.clone())?;
+		let owner: H160 = details.owner.into();
+		if owner != from {
+			return Err(RevertReason::custom("Not the owner of the token")
+				.in_field("from")
+				.into());
+		}
+
+		// pallet-uniques only lets the owner, the approved account and the admin of the collection
+		// transfer the item.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let dest = Runtime::AddressMapping::into_account_id(to);
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			pallet_uniques::Call::<Runtime, Instance>::transfer {
+				collection: collection_id,
+				item: item_id,
+				dest: Runtime::Lookup::unlookup(dest),
+			},
+			0,
+		)?;
+
+		log4(
+			handle.context().address,
+			SELECTOR_LOG_TRANSFER,
+			from,
+			to,
+			Self::token_id_topic(token_id),
+			Vec::new(),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	fn item_details(
+		handle: &mut impl PrecompileHandle,
+		collection_id: CollectionIdOf<Runtime, Instance>,
+		item_id: ItemIdOf<Runtime, Instance>,
+	) -> EvmResult<ItemDetails<Runtime::AccountId, DepositBalanceOf<Runtime, Instance>>> {
+		// Storage item: Asset:
+		// Blake2_128(16) + CollectionId(16) + Blake2_128(16) + ItemId(16)
+		// + ItemDetails[owner(20) + approved(21) + is_frozen(1) + deposit(16)]
+		handle.record_map_read::<Runtime>(122)?;
+
+		Asset::<Runtime, Instance>::get(collection_id, item_id)
+			.ok_or_else(|| revert("Token does not exist"))
+	}
+
+	fn u256_to_item_id(value: U256) -> MayRevert<ItemIdOf<Runtime, Instance>> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("item id").into())
+	}
+
+	fn token_id_topic(token_id: U256) -> H256 {
+		let mut topic = [0u8; 32];
+		token_id.to_big_endian(&mut topic);
+		H256(topic)
+	}
+}
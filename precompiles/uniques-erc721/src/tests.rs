@@ -0,0 +1,478 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use frame_support::assert_ok;
+use precompile_utils::{prelude::*, testing::*};
+use sha3::{Digest, Keccak256};
+use sp_core::H256;
+use std::str::from_utf8;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// Creates collection 0 owned by Alice, with items 1 and 2 minted to Alice.
+fn create_collection() {
+	assert_ok!(Uniques::force_create(
+		RuntimeOrigin::root(),
+		0,
+		Alice.into(),
+		true
+	));
+	for item in [1, 2] {
+		assert_ok!(Uniques::mint(
+			RuntimeOrigin::signed(Alice.into()),
+			0,
+			item,
+			Alice.into()
+		));
+	}
+}
+
+fn approve(approved: impl Into<H160>, token_id: u128) {
+	precompiles()
+		.prepare_test(
+			Alice,
+			NftCollection(0),
+			PCall::approve {
+				approved: Address(approved.into()),
+				token_id: token_id.into(),
+			},
+		)
+		.execute_returns(());
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(&["ERC721.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::name_selectors().contains(&0x06fdde03));
+	assert!(PCall::token_uri_selectors().contains(&0xc87b56dd));
+	assert!(PCall::balance_of_selectors().contains(&0x70a08231));
+	assert!(PCall::owner_of_selectors().contains(&0x6352211e));
+	assert!(PCall::get_approved_selectors().contains(&0x081812fc));
+	assert!(PCall::is_approved_for_all_selectors().contains(&0xe985e9c5));
+	assert!(PCall::approve_selectors().contains(&0x095ea7b3));
+	assert!(PCall::set_approval_for_all_selectors().contains(&0xa22cb465));
+	assert!(PCall::transfer_from_selectors().contains(&0x23b872dd));
+
+	assert_eq!(
+		crate::SELECTOR_LOG_TRANSFER,
+		&Keccak256::digest(b"Transfer(address,address,uint256)")[..]
+	);
+	assert_eq!(
+		crate::SELECTOR_LOG_APPROVAL,
+		&Keccak256::digest(b"Approval(address,address,uint256)")[..]
+	);
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_collection();
+
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, NftCollection(0));
+
+		tester.test_view_modifier(PCall::name_selectors());
+		tester.test_view_modifier(PCall::token_uri_selectors());
+		tester.test_view_modifier(PCall::balance_of_selectors());
+		tester.test_view_modifier(PCall::owner_of_selectors());
+		tester.test_view_modifier(PCall::get_approved_selectors());
+		tester.test_view_modifier(PCall::is_approved_for_all_selectors());
+		tester.test_default_modifier(PCall::approve_selectors());
+		tester.test_default_modifier(PCall::set_approval_for_all_selectors());
+		tester.test_default_modifier(PCall::transfer_from_selectors());
+	});
+}
+
+#[test]
+fn owner_of_and_balance_of() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_collection();
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				NftCollection(0),
+				PCall::owner_of { token_id: 1.into() },
+			)
+			.expect_no_logs()
+			.execute_returns(Address(Alice.into()));
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				NftCollection(0),
+				PCall::balance_of {
+					owner: Address(Alice.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(U256::from(2));
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				NftCollection(0),
+				PCall::balance_of {
+					owner: Address(Bob.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(U256::zero());
+	});
+}
+
+#[test]
+fn owner_of_unknown_token_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_collection();
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				NftCollection(0),
+				PCall::owner_of { token_id: 3.into() },
+			)
+			.execute_reverts(|output| output == b"Token does not exist");
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				NftCollection(0),
+				PCall::owner_of {
+					token_id: U256::MAX,
+				},
+			)
+			.execute_reverts(|output| output == b"tokenId: Value is too large for item id");
+	});
+}
+
+#[test]
+fn name_and_token_uri_come_from_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_collection();
+		assert_ok!(Uniques::set_collection_metadata(
+			RuntimeOrigin::signed(Alice.into()),
+			0,
+			b"Moonbeam Collection".to_vec().try_into().unwrap(),
+			false
+		));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(Alice.into()),
+			0,
+			1,
+			b"ipfs://token-1".to_vec().try_into().unwrap(),
+			false
+		));
+
+		precompiles()
+			.prepare_test(Bob, NftCollection(0), PCall::name {})
+			.expect_no_logs()
+			.execute_returns(UnboundedBytes::from("Moonbeam Collection"));
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				NftCollection(0),
+				PCall::token_uri { token_id: 1.into() },
+			)
+			.expect_no_logs()
+			.execute_returns(UnboundedBytes::from("ipfs://token-1"));
+
+		// Item without metadata
+		precompiles()
+			.prepare_test(
+				Bob,
+				NftCollection(0),
+				PCall::token_uri { token_id: 2.into() },
+			)
+			.expect_no_logs()
+			.execute_returns(UnboundedBytes::from(""));
+	});
+}
+
+#[test]
+fn approve_sets_the_approved_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_collection();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NftCollection(0),
+				PCall::approve {
+					approved: Address(Bob.into()),
+					token_id: 1.into(),
+				},
+			)
+			.expect_log(log4(
+				NftCollection(0),
+				SELECTOR_LOG_APPROVAL,
+				Alice,
+				Bob,
+				H256::from_low_u64_be(1),
+				vec![],
+			))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NftCollection(0),
+				PCall::get_approved { token_id: 1.into() },
+			)
+			.expect_no_logs()
+			.execute_returns(Address(Bob.into()));
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NftCollection(0),
+				PCall::get_approved { token_id: 2.into() },
+			)
+			.expect_no_logs()
+			.execute_returns(Address(H160::zero()));
+	});
+}
+
+#[test]
+fn approving_the_zero_address_clears_the_approval() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_collection();
+		approve(Bob, 1);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NftCollection(0),
+				PCall::approve {
+					approved: Address(H160::zero()),
+					token_id: 1.into(),
+				},
+			)
+			.expect_log(log4(
+				NftCollection(0),
+				SELECTOR_LOG_APPROVAL,
+				Alice,
+				H160::zero(),
+				H256::from_low_u64_be(1),
+				vec![],
+			))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NftCollection(0),
+				PCall::get_approved { token_id: 1.into() },
+			)
+			.execute_returns(Address(H160::zero()));
+	});
+}
+
+#[test]
+fn approve_by_non_owner_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_collection();
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				NftCollection(0),
+				PCall::approve {
+					approved: Address(Charlie.into()),
+					token_id: 1.into(),
+				},
+			)
+			.execute_reverts(|output| from_utf8(output).unwrap().contains("NoPermission"));
+	});
+}
+
+#[test]
+fn owner_can_transfer() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_collection();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NftCollection(0),
+				PCall::transfer_from {
+					from: Address(Alice.into()),
+					to: Address(Bob.into()),
+					token_id: 1.into(),
+				},
+			)
+			.expect_log(log4(
+				NftCollection(0),
+				SELECTOR_LOG_TRANSFER,
+				Alice,
+				Bob,
+				H256::from_low_u64_be(1),
+				vec![],
+			))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NftCollection(0),
+				PCall::owner_of { token_id: 1.into() },
+			)
+			.execute_returns(Address(Bob.into()));
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NftCollection(0),
+				PCall::balance_of {
+					owner: Address(Bob.into()),
+				},
+			)
+			.execute_returns(U256::one());
+	});
+}
+
+#[test]
+fn approved_account_can_transfer() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_collection();
+		approve(Bob, 1);
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				NftCollection(0),
+				PCall::transfer_from {
+					from: Address(Alice.into()),
+					to: Address(Charlie.into()),
+					token_id: 1.into(),
+				},
+			)
+			.expect_log(log4(
+				NftCollection(0),
+				SELECTOR_LOG_TRANSFER,
+				Alice,
+				Charlie,
+				H256::from_low_u64_be(1),
+				vec![],
+			))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NftCollection(0),
+				PCall::owner_of { token_id: 1.into() },
+			)
+			.execute_returns(Address(Charlie.into()));
+
+		// The approval is cleared by the transfer
+		precompiles()
+			.prepare_test(
+				Alice,
+				NftCollection(0),
+				PCall::get_approved { token_id: 1.into() },
+			)
+			.execute_returns(Address(H160::zero()));
+	});
+}
+
+#[test]
+fn transfer_by_unapproved_account_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_collection();
+		approve(Bob, 1);
+
+		precompiles()
+			.prepare_test(
+				Charlie,
+				NftCollection(0),
+				PCall::transfer_from {
+					from: Address(Alice.into()),
+					to: Address(Charlie.into()),
+					token_id: 1.into(),
+				},
+			)
+			.execute_reverts(|output| from_utf8(output).unwrap().contains("NoPermission"));
+	});
+}
+
+#[test]
+fn transfer_from_checks_owner_and_recipient() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_collection();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NftCollection(0),
+				PCall::transfer_from {
+					from: Address(Bob.into()),
+					to: Address(Charlie.into()),
+					token_id: 1.into(),
+				},
+			)
+			.execute_reverts(|output| output == b"from: Not the owner of the token");
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NftCollection(0),
+				PCall::transfer_from {
+					from: Address(Alice.into()),
+					to: Address(H160::zero()),
+					token_id: 1.into(),
+				},
+			)
+			.execute_reverts(|output| output == b"to: Cannot transfer to the zero address");
+	});
+}
+
+#[test]
+fn operator_approvals_are_not_supported() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_collection();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NftCollection(0),
+				PCall::set_approval_for_all {
+					operator: Address(Bob.into()),
+					approved: true,
+				},
+			)
+			.execute_reverts(|output| output == b"Operator approvals are not supported");
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NftCollection(0),
+				PCall::is_approved_for_all {
+					owner: Address(Alice.into()),
+					operator: Address(Bob.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+	});
+}
@@ -116,6 +116,20 @@ fn extract_precompile_name_and_prefix_for_precompile_at(
 									{
 										return Some((instance_ident, precompile_id));
 									}
+								} else if &path_segment_2.ident.to_string() == "VersionedPrecompile"
+								{
+									if let Some(version_ident) =
+										first_version_ident(&path_segment_2)
+									{
+										return Some((version_ident, precompile_id));
+									}
+								} else if &path_segment_2.ident.to_string() == "AccessListPreloaded"
+								{
+									if let Some(preloaded_ident) =
+										preloaded_precompile_ident(&path_segment_2)
+									{
+										return Some((preloaded_ident, precompile_id));
+									}
 								} else {
 									return Some((path_segment_2.ident.clone(), precompile_id));
 								}
@@ -141,3 +155,36 @@ fn precompile_instance_ident(path_segment: &syn::PathSegment) -> Option<Ident> {
 
 	None
 }
+
+/// A versioned precompile is named after its first version.
+fn first_version_ident(path_segment: &syn::PathSegment) -> Option<Ident> {
+	if let syn::PathArguments::AngleBracketed(generics_) = &path_segment.arguments {
+		if let Some(GenericArgument::Type(Type::Tuple(versions))) = generics_.args.iter().nth(1) {
+			if let Some(Type::Path(version_type_path)) = versions.elems.first() {
+				if let Some(version_type) = version_type_path.path.segments.last() {
+					if &version_type.ident.to_string() == "AccessListPreloaded" {
+						return preloaded_precompile_ident(version_type);
+					}
+					return Some(version_type.ident.clone());
+				}
+			}
+		}
+	}
+
+	None
+}
+
+/// A preloaded precompile is named after the precompile it wraps.
+fn preloaded_precompile_ident(path_segment: &syn::PathSegment) -> Option<Ident> {
+	if let syn::PathArguments::AngleBracketed(generics_) = &path_segment.arguments {
+		if let Some(GenericArgument::Type(Type::Path(precompile_type_path))) =
+			generics_.args.iter().nth(1)
+		{
+			if let Some(precompile_type) = precompile_type_path.path.segments.last() {
+				return Some(precompile_type.ident.clone());
+			}
+		}
+	}
+
+	None
+}
@@ -24,8 +24,14 @@ use {
 		EvmResult,
 	},
 	fp_evm::{Log, PrecompileHandle},
+	frame_support::weights::Weight,
+	pallet_evm::GasWeightMapping,
 };
 
+/// Proof size of the trie nodes leading to an entry of a storage map, as estimated by the
+/// benchmarks for a map of up to a million entries (`max_values: None`, `MaxEncodedLen` mode).
+pub const STORAGE_MAP_PROOF_OVERHEAD: usize = 2475;
+
 pub trait PrecompileHandleExt: PrecompileHandle {
 	/// Record cost of one DB read manually.
 	/// The max encoded lenght of the data that will be read should be provided.
@@ -35,6 +41,23 @@ pub trait PrecompileHandleExt: PrecompileHandle {
 		data_max_encoded_len: usize,
 	) -> Result<(), evm::ExitError>;
 
+	/// Record cost of one read of a storage map entry manually.
+	/// As in the benchmarks, the proof size accounts for the trie nodes leading to the entry on
+	/// top of its max encoded length.
+	#[must_use]
+	fn record_map_read<Runtime: pallet_evm::Config>(
+		&mut self,
+		data_max_encoded_len: usize,
+	) -> Result<(), evm::ExitError>;
+
+	/// Record the cost of a benchmarked weight: its ref time is converted into gas with the runtime
+	/// gas to weight mapping, and its proof size is recorded as an external cost.
+	#[must_use]
+	fn record_weight<Runtime: pallet_evm::Config>(
+		&mut self,
+		weight: Weight,
+	) -> Result<(), evm::ExitError>;
+
 	/// Record cost of a log manually.
 	/// This can be useful to record log costs early when their content have static size.
 	#[must_use]
@@ -69,6 +92,23 @@ impl<T: PrecompileHandle> PrecompileHandleExt for T {
 		self.record_external_cost(None, Some(data_max_encoded_len as u64), None)
 	}
 
+	#[must_use]
+	fn record_map_read<Runtime: pallet_evm::Config>(
+		&mut self,
+		data_max_encoded_len: usize,
+	) -> Result<(), evm::ExitError> {
+		self.record_db_read::<Runtime>(data_max_encoded_len + STORAGE_MAP_PROOF_OVERHEAD)
+	}
+
+	#[must_use]
+	fn record_weight<Runtime: pallet_evm::Config>(
+		&mut self,
+		weight: Weight,
+	) -> Result<(), evm::ExitError> {
+		self.record_cost(<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight))?;
+		self.record_external_cost(None, Some(weight.proof_size()), None)
+	}
+
 	/// Record cost of a log manualy.
 	/// This can be useful to record log costs early when their content have static size.
 	#[must_use]
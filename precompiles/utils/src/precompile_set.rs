@@ -20,12 +20,16 @@
 
 use crate::{
 	evm::handle::PrecompileHandleExt,
-	solidity::{codec::String, revert::revert},
+	solidity::{
+		codec::{encode_return_value, String},
+		modifier::FunctionModifier,
+		revert::{revert, InjectBacktrace, RevertReason},
+	},
 	EvmResult,
 };
 use fp_evm::{
-	ExitError, IsPrecompileResult, Precompile, PrecompileFailure, PrecompileHandle,
-	PrecompileResult, PrecompileSet,
+	ExitError, ExitSucceed, IsPrecompileResult, Precompile, PrecompileFailure, PrecompileHandle,
+	PrecompileOutput, PrecompileResult, PrecompileSet,
 };
 use frame_support::pallet_prelude::Get;
 use impl_trait_for_tuples::impl_for_tuples;
@@ -497,6 +501,255 @@ pub trait IsActivePrecompile {
 	fn is_active_precompile(&self, address: H160, gas: u64) -> IsPrecompileResult;
 }
 
+// VERSIONED PRECOMPILE
+
+/// Storage of the interface version each caller pinned for a versioned precompile.
+pub trait InterfaceVersionStore {
+	/// Max encoded length of a pinned version entry, used to record the proof size of a read.
+	const ENTRY_MAX_ENCODED_LEN: usize;
+
+	/// Version of the interface of `precompile` pinned by `caller`, if any.
+	fn pinned_version(precompile: H160, caller: H160) -> Option<u32>;
+
+	/// Pin the version of the interface of `precompile` used by `caller`.
+	fn pin_version(precompile: H160, caller: H160, version: u32);
+}
+
+/// Successive versions of the interface of a precompile, as a tuple of `Precompile`.
+/// The first element of the tuple is version 1, the second one version 2, etc.
+pub trait PrecompileVersions {
+	/// Latest version of the interface.
+	fn latest_version() -> u32;
+
+	/// Execute the given version of the interface, returns None if it doesn't exist.
+	fn execute_version(
+		handle: &mut impl PrecompileHandle,
+		version: u32,
+	) -> Option<PrecompileResult>;
+}
+
+#[impl_for_tuples(1, 8)]
+#[tuple_types_custom_trait_bound(Precompile)]
+impl PrecompileVersions for Tuple {
+	#[inline(always)]
+	fn latest_version() -> u32 {
+		let mut latest = 0u32;
+
+		for_tuples!(#(
+			let _ = PhantomData::<Tuple>;
+			latest += 1;
+		)*);
+
+		latest
+	}
+
+	#[inline(always)]
+	fn execute_version(
+		handle: &mut impl PrecompileHandle,
+		version: u32,
+	) -> Option<PrecompileResult> {
+		let mut current = 0u32;
+
+		for_tuples!(#(
+			current += 1;
+			if current == version {
+				return Some(Tuple::execute(handle));
+			}
+		)*);
+
+		None
+	}
+}
+
+/// `interfaceVersion()`
+pub const INTERFACE_VERSION_SELECTOR: u32 = 0x1d8ffa4d;
+/// `latestInterfaceVersion()`
+pub const LATEST_INTERFACE_VERSION_SELECTOR: u32 = 0x89fafad3;
+/// `pinInterfaceVersion(uint32)`
+pub const PIN_INTERFACE_VERSION_SELECTOR: u32 = 0x2427db05;
+
+/// Wraps the successive versions `V` of the interface of a precompile, so that a behavior change
+/// (e.g. the encoding of a return value) doesn't break contracts relying on the former one.
+///
+/// Each caller is dispatched to the version it pinned in `S` with `pinInterfaceVersion(uint32)`.
+/// Callers which never pinned a version, among which all the contracts deployed before the
+/// precompile was versioned, are dispatched to version 1.
+///
+/// The selectors `interfaceVersion()`, `latestInterfaceVersion()` and
+/// `pinInterfaceVersion(uint32)` are handled by the wrapper and thus not forwarded to the
+/// wrapped versions.
+pub struct VersionedPrecompile<R, V, S>(PhantomData<(R, V, S)>);
+
+impl<R, V, S> VersionedPrecompile<R, V, S>
+where
+	R: pallet_evm::Config,
+	V: PrecompileVersions,
+	S: InterfaceVersionStore,
+{
+	fn current_version(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+		handle.record_db_read::<R>(S::ENTRY_MAX_ENCODED_LEN)?;
+
+		Ok(S::pinned_version(handle.code_address(), handle.context().caller).unwrap_or(1))
+	}
+
+	fn pin_version(handle: &mut impl PrecompileHandle) -> EvmResult {
+		handle.check_function_modifier(FunctionModifier::NonPayable)?;
+
+		let mut input = handle.read_after_selector()?;
+		input.expect_arguments(1)?;
+		let version: u32 = input.read().in_field("version")?;
+
+		if version == 0 || version > V::latest_version() {
+			return Err(RevertReason::custom("Unknown interface version")
+				.in_field("version")
+				.into());
+		}
+
+		handle.record_cost(crate::prelude::RuntimeHelper::<R>::db_write_gas_cost())?;
+		S::pin_version(handle.code_address(), handle.context().caller, version);
+
+		Ok(())
+	}
+}
+
+impl<R, V, S> Precompile for VersionedPrecompile<R, V, S>
+where
+	R: pallet_evm::Config,
+	V: PrecompileVersions,
+	S: InterfaceVersionStore,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let output = match handle.read_u32_selector() {
+			Ok(INTERFACE_VERSION_SELECTOR) => {
+				handle.check_function_modifier(FunctionModifier::View)?;
+				encode_return_value(Self::current_version(handle)?)
+			}
+			Ok(LATEST_INTERFACE_VERSION_SELECTOR) => {
+				handle.check_function_modifier(FunctionModifier::View)?;
+				encode_return_value(V::latest_version())
+			}
+			Ok(PIN_INTERFACE_VERSION_SELECTOR) => {
+				Self::pin_version(handle)?;
+				encode_return_value(())
+			}
+			_ => {
+				let version = Self::current_version(handle)?;
+				return V::execute_version(handle, version)
+					.unwrap_or_else(|| Err(revert("Unknown interface version")));
+			}
+		};
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			output,
+		})
+	}
+}
+
+// STORAGE ACCESS LIST
+
+/// A storage key read by a precompile call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageAccess {
+	/// Final key of the storage entry (e.g. as returned by `hashed_key_for`).
+	pub key: Vec<u8>,
+	/// Proof size recorded by the precompile for this read.
+	pub proof_size: u64,
+}
+
+/// Implemented by precompiles declaring ahead of execution the storage keys a call will read.
+pub trait StorageAccessList {
+	/// Storage keys read by the call of `handle`. Each read must be recorded by the precompile
+	/// with the declared proof size whenever the call succeeds. Calls for which an empty list is
+	/// returned are executed as if the precompile was not preloaded.
+	fn storage_access_list(handle: &mut impl PrecompileHandle) -> Vec<StorageAccess>;
+}
+
+/// Storage keys already read by preloaded precompiles in the current block.
+pub trait WarmStorageKeys {
+	/// Encoded size of the warm keys, which are decoded by `is_warm` and decoded then re-encoded
+	/// by `warm`.
+	fn encoded_size() -> usize;
+
+	/// Has `key` already been read in the current block.
+	fn is_warm(key: &[u8]) -> bool;
+
+	/// Mark `keys` as read in the current block.
+	fn warm(keys: Vec<Vec<u8>>);
+}
+
+/// Wraps a precompile `P` declaring the storage keys its calls read, so that the proof of each
+/// key is only paid for once per block.
+///
+/// The keys not read yet in the block (cold keys) are preloaded before executing the call, so
+/// that their trie nodes are fetched and recorded together. The trie nodes of a key are recorded
+/// once per block by the PoV recorder: when the call succeeds, the proof size recorded by `P`
+/// for the keys already read in the block (warm keys) is refunded, and the cold keys are marked
+/// as warm in `W`.
+///
+/// This mostly benefits views which are called many times per block, e.g. from a contract
+/// checking the staking state of each of its users.
+///
+/// Each access to the warm keys is charged by their encoded size, as the whole set is decoded
+/// (and re-encoded when marking the cold keys as warm).
+pub struct AccessListPreloaded<R, P, W>(PhantomData<(R, P, W)>);
+
+impl<R, P, W> Precompile for AccessListPreloaded<R, P, W>
+where
+	R: pallet_evm::Config,
+	P: Precompile + StorageAccessList,
+	W: WarmStorageKeys,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let access_list = P::storage_access_list(handle);
+		if access_list.is_empty() {
+			return P::execute(handle);
+		}
+
+		// The warm keys are written at the start of the block, reading them adds nothing to the
+		// proof, but the whole set is decoded for each key checked.
+		let warm_keys_cost = warm_keys_gas_cost(W::encoded_size());
+		handle.record_cost(
+			crate::prelude::RuntimeHelper::<R>::db_read_gas_cost()
+				.saturating_add(warm_keys_cost)
+				.saturating_mul(access_list.len() as u64),
+		)?;
+		let (warm, cold): (Vec<_>, Vec<_>) = access_list
+			.into_iter()
+			.partition(|access| W::is_warm(&access.key));
+
+		for access in &cold {
+			sp_io::storage::exists(&access.key);
+		}
+
+		let output = P::execute(handle)?;
+
+		let refund = warm.iter().map(|access| access.proof_size).sum();
+		handle.refund_external_cost(None, Some(refund));
+
+		if !cold.is_empty() {
+			handle.record_cost(
+				crate::prelude::RuntimeHelper::<R>::db_write_gas_cost()
+					.saturating_add(warm_keys_cost.saturating_mul(2)),
+			)?;
+			W::warm(cold.into_iter().map(|access| access.key).collect());
+		}
+
+		Ok(output)
+	}
+}
+
+/// Gas charged per 32 bytes of warm keys decoded or encoded. This is twice the cost of copying a
+/// word of memory in the EVM, to account for the insertion of each decoded key in the set.
+pub const WARM_KEYS_GAS_PER_WORD: u64 = 6;
+
+fn warm_keys_gas_cost(encoded_size: usize) -> u64 {
+	(encoded_size as u64)
+		.saturating_add(31)
+		.saturating_div(32)
+		.saturating_mul(WARM_KEYS_GAS_PER_WORD)
+}
+
 // INDIVIDUAL PRECOMPILE(SET)
 
 /// A fragment of a PrecompileSet. Should be implemented as is it
@@ -36,6 +36,7 @@ pub struct PrecompilesTester<'p, P> {
 	subcall_handle: Option<SubcallHandle>,
 
 	expected_cost: Option<u64>,
+	expected_proof_size: Option<u64>,
 	expected_logs: Option<Vec<PrettyLog>>,
 	static_call: bool,
 }
@@ -67,6 +68,7 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
 			subcall_handle: None,
 
 			expected_cost: None,
+			expected_proof_size: None,
 			expected_logs: None,
 			static_call: false,
 		}
@@ -97,6 +99,11 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
 		self
 	}
 
+	pub fn expect_proof_size(mut self, proof_size: u64) -> Self {
+		self.expected_proof_size = Some(proof_size);
+		self
+	}
+
 	pub fn expect_no_logs(mut self) -> Self {
 		self.expected_logs = Some(vec![]);
 		self
@@ -116,6 +123,10 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
 			assert_eq!(&self.handle.gas_used, cost);
 		}
 
+		if let Some(proof_size) = &self.expected_proof_size {
+			assert_eq!(&self.handle.proof_size_used, proof_size);
+		}
+
 		if let Some(logs) = &self.expected_logs {
 			similar_asserts::assert_eq!(&self.handle.logs, logs);
 		}
@@ -79,6 +79,8 @@ pub type SubcallHandle = Box<dyn SubcallTrait>;
 pub struct MockHandle {
 	pub gas_limit: u64,
 	pub gas_used: u64,
+	/// Proof size recorded as an external cost, net of refunds.
+	pub proof_size_used: u64,
 	pub logs: Vec<PrettyLog>,
 	pub subcall_handle: Option<SubcallHandle>,
 	pub code_address: H160,
@@ -92,6 +94,7 @@ impl MockHandle {
 		Self {
 			gas_limit: u64::MAX,
 			gas_used: 0,
+			proof_size_used: 0,
 			logs: vec![],
 			subcall_handle: None,
 			code_address,
@@ -206,11 +209,14 @@ impl PrecompileHandle for MockHandle {
 	fn record_external_cost(
 		&mut self,
 		_ref_time: Option<u64>,
-		_proof_size: Option<u64>,
+		proof_size: Option<u64>,
 		_storage_growth: Option<u64>,
 	) -> Result<(), ExitError> {
+		self.proof_size_used = self.proof_size_used.saturating_add(proof_size.unwrap_or(0));
 		Ok(())
 	}
 
-	fn refund_external_cost(&mut self, _ref_time: Option<u64>, _proof_size: Option<u64>) {}
+	fn refund_external_cost(&mut self, _ref_time: Option<u64>, proof_size: Option<u64>) {
+		self.proof_size_used = self.proof_size_used.saturating_sub(proof_size.unwrap_or(0));
+	}
 }
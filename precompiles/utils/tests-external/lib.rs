@@ -17,6 +17,7 @@
 #[cfg(test)]
 mod tests {
 	use std::cell::RefCell;
+	use std::collections::{BTreeMap, BTreeSet};
 	use std::rc::Rc;
 
 	use evm::Context;
@@ -25,6 +26,7 @@ mod tests {
 	use frame_support::{construct_runtime, parameter_types, weights::Weight};
 	use pallet_evm::{EnsureAddressNever, EnsureAddressRoot};
 	use precompile_utils::{
+		evm::handle::PrecompileHandleExt,
 		precompile_set::*,
 		solidity::{codec::Writer, revert::revert},
 		testing::*,
@@ -147,6 +149,81 @@ mod tests {
 		}
 	}
 
+	#[derive(Debug, Clone)]
+	pub struct MockPrecompileV2;
+
+	#[precompile_utils::precompile]
+	impl MockPrecompileV2 {
+		// 0b93381b
+		#[precompile::public("success()")]
+		fn success(_: &mut impl PrecompileHandle) -> EvmResult<u32> {
+			Ok(2)
+		}
+	}
+
+	thread_local! {
+		static PINNED_VERSIONS: RefCell<BTreeMap<(H160, H160), u32>> =
+			RefCell::new(BTreeMap::new());
+	}
+
+	pub struct MockVersionStore;
+	impl InterfaceVersionStore for MockVersionStore {
+		const ENTRY_MAX_ENCODED_LEN: usize = 76;
+
+		fn pinned_version(precompile: H160, caller: H160) -> Option<u32> {
+			PINNED_VERSIONS.with(|v| v.borrow().get(&(precompile, caller)).copied())
+		}
+
+		fn pin_version(precompile: H160, caller: H160, version: u32) {
+			PINNED_VERSIONS.with(|v| v.borrow_mut().insert((precompile, caller), version));
+		}
+	}
+
+	#[derive(Debug, Clone)]
+	pub struct MockPreloadedPrecompile;
+
+	#[precompile_utils::precompile]
+	impl MockPreloadedPrecompile {
+		#[precompile::public("read(bytes32)")]
+		#[precompile::view]
+		fn read(handle: &mut impl PrecompileHandle, key: H256) -> EvmResult<bool> {
+			handle.record_db_read::<Runtime>(100)?;
+
+			Ok(sp_io::storage::exists(key.as_bytes()))
+		}
+	}
+
+	impl StorageAccessList for MockPreloadedPrecompile {
+		fn storage_access_list(handle: &mut impl PrecompileHandle) -> Vec<StorageAccess> {
+			match MockPreloadedPrecompileCall::parse_call_data(handle) {
+				Ok(MockPreloadedPrecompileCall::read { key }) => vec![StorageAccess {
+					key: key.as_bytes().to_vec(),
+					proof_size: 100,
+				}],
+				_ => Vec::new(),
+			}
+		}
+	}
+
+	thread_local! {
+		static WARM_KEYS: RefCell<BTreeSet<Vec<u8>>> = RefCell::new(BTreeSet::new());
+	}
+
+	pub struct MockWarmKeys;
+	impl WarmStorageKeys for MockWarmKeys {
+		fn encoded_size() -> usize {
+			WARM_KEYS.with(|k| k.borrow().iter().map(|key| key.len()).sum())
+		}
+
+		fn is_warm(key: &[u8]) -> bool {
+			WARM_KEYS.with(|k| k.borrow().contains(key))
+		}
+
+		fn warm(keys: Vec<Vec<u8>>) {
+			WARM_KEYS.with(|k| k.borrow_mut().extend(keys));
+		}
+	}
+
 	struct MockPrecompileHandle;
 	impl PrecompileHandle for MockPrecompileHandle {
 		fn call(
@@ -217,10 +294,19 @@ mod tests {
 			PrecompileAt<AddressU64<2>, MockPrecompile, CallableByContract>,
 			PrecompileAt<AddressU64<3>, MockPrecompile, CallableByPrecompile>,
 			PrecompileAt<AddressU64<4>, MockPrecompile, SubcallWithMaxNesting<1>>,
+			PrecompileAt<
+				AddressU64<5>,
+				VersionedPrecompile<R, (MockPrecompile, MockPrecompileV2), MockVersionStore>,
+			>,
+			PrecompileAt<
+				AddressU64<6>,
+				AccessListPreloaded<R, MockPreloadedPrecompile, MockWarmKeys>,
+			>,
 		),
 	>;
 
 	pub type PCall = MockPrecompileCall;
+	pub type PCall6 = MockPreloadedPrecompileCall;
 
 	const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
 	/// Block storage limit in bytes. Set to 40 KB.
@@ -456,4 +542,137 @@ mod tests {
 			);
 		})
 	}
+
+	#[test]
+	fn versioned_precompile_dispatches_to_version_1_by_default() {
+		ExtBuilder::default().build().execute_with(|| {
+			precompiles()
+				.prepare_test(Alice, H160::from_low_u64_be(5), PCall::success {})
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					H160::from_low_u64_be(5),
+					Writer::new_with_selector(INTERFACE_VERSION_SELECTOR).build(),
+				)
+				.execute_returns(1u32);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					H160::from_low_u64_be(5),
+					Writer::new_with_selector(LATEST_INTERFACE_VERSION_SELECTOR).build(),
+				)
+				.execute_returns(2u32);
+		})
+	}
+
+	#[test]
+	fn versioned_precompile_dispatches_to_pinned_version() {
+		ExtBuilder::default().build().execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					H160::from_low_u64_be(5),
+					Writer::new_with_selector(PIN_INTERFACE_VERSION_SELECTOR)
+						.write(2u32)
+						.build(),
+				)
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					H160::from_low_u64_be(5),
+					Writer::new_with_selector(INTERFACE_VERSION_SELECTOR).build(),
+				)
+				.execute_returns(2u32);
+
+			precompiles()
+				.prepare_test(Alice, H160::from_low_u64_be(5), PCall::success {})
+				.execute_returns(2u32);
+
+			// Other callers are not affected.
+			precompiles()
+				.prepare_test(Bob, H160::from_low_u64_be(5), PCall::success {})
+				.execute_returns(());
+		})
+	}
+
+	#[test]
+	fn versioned_precompile_rejects_unknown_version() {
+		ExtBuilder::default().build().execute_with(|| {
+			for version in [0u32, 3] {
+				precompiles()
+					.prepare_test(
+						Alice,
+						H160::from_low_u64_be(5),
+						Writer::new_with_selector(PIN_INTERFACE_VERSION_SELECTOR)
+							.write(version)
+							.build(),
+					)
+					.execute_reverts(|r| r == b"version: Unknown interface version");
+			}
+		})
+	}
+
+	#[test]
+	fn preloaded_precompile_refunds_proof_of_warm_keys() {
+		ExtBuilder::default().build().execute_with(|| {
+			let key = H256::repeat_byte(0x11);
+
+			precompiles()
+				.prepare_test(Alice, H160::from_low_u64_be(6), PCall6::read { key })
+				.expect_proof_size(100)
+				.execute_returns(false);
+
+			precompiles()
+				.prepare_test(Alice, H160::from_low_u64_be(6), PCall6::read { key })
+				.expect_proof_size(0)
+				.execute_returns(false);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					H160::from_low_u64_be(6),
+					PCall6::read {
+						key: H256::repeat_byte(0x22),
+					},
+				)
+				.expect_proof_size(100)
+				.execute_returns(false);
+		})
+	}
+
+	#[test]
+	fn preloaded_precompile_charges_by_size_of_warm_keys() {
+		ExtBuilder::default().build().execute_with(|| {
+			let key = H256::repeat_byte(0x11);
+			MockWarmKeys::warm(
+				(0u8..8)
+					.map(|i| H256::repeat_byte(i).as_bytes().to_vec())
+					.collect(),
+			);
+			MockWarmKeys::warm(vec![key.as_bytes().to_vec()]);
+
+			// 9 warm keys of 32 bytes, decoded once to check the key.
+			precompiles()
+				.prepare_test(Alice, H160::from_low_u64_be(6), PCall6::read { key })
+				.expect_cost(9 * WARM_KEYS_GAS_PER_WORD)
+				.execute_returns(false);
+
+			// Decoded once more and re-encoded to mark the cold key as warm.
+			precompiles()
+				.prepare_test(
+					Alice,
+					H160::from_low_u64_be(6),
+					PCall6::read {
+						key: H256::repeat_byte(0x22),
+					},
+				)
+				.expect_cost(3 * 9 * WARM_KEYS_GAS_PER_WORD)
+				.execute_returns(false);
+		})
+	}
 }
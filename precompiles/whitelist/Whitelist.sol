@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Whitelist contract's address.
+address constant WHITELIST_ADDRESS = 0x000000000000000000000000000000000000081F;
+
+/// @dev The Whitelist contract's instance.
+Whitelist constant WHITELIST_CONTRACT = Whitelist(WHITELIST_ADDRESS);
+
+/// @author The Moonbeam Team
+/// @title Pallet Whitelist Interface
+/// @title The interface through which the members of the Open Tech Committee can whitelist calls,
+/// allowing them to be dispatched through the Whitelisted Caller track of OpenGov
+/// @custom:address 0x000000000000000000000000000000000000081F
+interface Whitelist {
+    /// @dev Whitelist the call with the given hash. Only callable by a member of the Open Tech
+    /// Committee.
+    /// @custom:selector fe644b8e
+    /// @param callHash The hash of the call to whitelist
+    function whitelistCall(bytes32 callHash) external;
+
+    /// @dev Remove the call with the given hash from the whitelist. Only callable by a member of
+    /// the Open Tech Committee.
+    /// @custom:selector 6176c662
+    /// @param callHash The hash of the call to remove from the whitelist
+    function removeWhitelistedCall(bytes32 callHash) external;
+
+    /// @dev Dispatch a whitelisted call, given its SCALE encoding. Only callable by a member of
+    /// the Open Tech Committee.
+    /// @custom:selector 0399a2ca
+    /// @param call The SCALE encoded call, whose hash must be whitelisted
+    function dispatchWhitelistedCallWithPreimage(bytes memory call) external;
+
+    /// @dev Whether the call with the given hash is whitelisted
+    /// @custom:selector 01a5e3fe
+    /// @param callHash The hash of the call
+    /// @return Whether the call is whitelisted
+    function isWhitelisted(bytes32 callHash) external view returns (bool);
+}
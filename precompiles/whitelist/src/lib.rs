@@ -0,0 +1,144 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile allowing the members of a collective (the Open Tech Committee) to whitelist calls
+//! through pallet-whitelist, and to dispatch them once whitelisted.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::DecodeLimit as _;
+use precompile_utils::prelude::*;
+use sp_core::{ConstU32, Get, H256};
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Storage growth when whitelisting a call.
+/// WhitelistedCall: Twox128(16) + Twox128(16) + Twox64(8) + Hash(32)
+/// + preimage RequestStatusFor: Twox128(16) + Twox128(16) + Identity(32) + RequestStatus(11)
+pub const WHITELISTED_CALL_STORAGE_GROWTH: u64 = 147;
+
+type CallLimit = ConstU32<{ 2u32.pow(16) }>;
+type DecodeLimit = ConstU32<8>;
+
+/// A precompile dispatching the calls of pallet-whitelist with the collective member origin of
+/// its caller. `Instance` is the instance of the collective whose members are allowed to use it,
+/// the runtime deciding through the pallet-whitelist origins whether a single member is enough.
+pub struct WhitelistPrecompile<Runtime, Instance: 'static>(PhantomData<(Runtime, Instance)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, Instance> WhitelistPrecompile<Runtime, Instance>
+where
+	Instance: 'static,
+	Runtime: pallet_collective::Config<Instance> + pallet_whitelist::Config + pallet_evm::Config,
+	<Runtime as frame_system::Config>::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime as frame_system::Config>::RuntimeCall: From<pallet_whitelist::Call<Runtime>>,
+	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		From<pallet_collective::RawOrigin<Runtime::AccountId, Instance>>,
+	Runtime::Hash: From<H256>,
+{
+	#[precompile::public("whitelistCall(bytes32)")]
+	fn whitelist_call(handle: &mut impl PrecompileHandle, call_hash: H256) -> EvmResult {
+		let origin = Self::ensure_member(handle)?;
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			origin,
+			pallet_whitelist::Call::<Runtime>::whitelist_call {
+				call_hash: call_hash.into(),
+			},
+			WHITELISTED_CALL_STORAGE_GROWTH,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("removeWhitelistedCall(bytes32)")]
+	fn remove_whitelisted_call(handle: &mut impl PrecompileHandle, call_hash: H256) -> EvmResult {
+		let origin = Self::ensure_member(handle)?;
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			origin,
+			pallet_whitelist::Call::<Runtime>::remove_whitelisted_call {
+				call_hash: call_hash.into(),
+			},
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("dispatchWhitelistedCallWithPreimage(bytes)")]
+	fn dispatch_whitelisted_call_with_preimage(
+		handle: &mut impl PrecompileHandle,
+		call: BoundedBytes<CallLimit>,
+	) -> EvmResult {
+		let call: Vec<u8> = call.into();
+		let call = <Runtime as pallet_whitelist::Config>::RuntimeCall::decode_with_depth_limit(
+			DecodeLimit::get(),
+			&mut &*call,
+		)
+		.map_err(|_| RevertReason::custom("Failed to decode call").in_field("call"))?;
+
+		let origin = Self::ensure_member(handle)?;
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			origin,
+			pallet_whitelist::Call::<Runtime>::dispatch_whitelisted_call_with_preimage {
+				call: Box::new(call),
+			},
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("isWhitelisted(bytes32)")]
+	#[precompile::view]
+	fn is_whitelisted(handle: &mut impl PrecompileHandle, call_hash: H256) -> EvmResult<bool> {
+		// WhitelistedCall: Twox64(8) + Hash(32)
+		handle.record_db_read::<Runtime>(40)?;
+
+		Ok(pallet_whitelist::WhitelistedCall::<Runtime>::contains_key(
+			Runtime::Hash::from(call_hash),
+		))
+	}
+
+	/// Returns the collective member origin of the caller, reverting if the caller is not a
+	/// member of the collective.
+	fn ensure_member(
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin>
+	{
+		// Members: Vec(20 * MaxMembers)
+		handle.record_db_read::<Runtime>(
+			20 * (<Runtime as pallet_collective::Config<Instance>>::MaxMembers::get() as usize),
+		)?;
+
+		let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		if !pallet_collective::Pallet::<Runtime, Instance>::is_member(&caller) {
+			return Err(revert("Caller is not a member of the collective"));
+		}
+
+		Ok(pallet_collective::RawOrigin::<Runtime::AccountId, Instance>::Member(caller).into())
+	}
+}
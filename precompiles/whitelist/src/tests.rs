@@ -0,0 +1,175 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use parity_scale_codec::Encode;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{Hasher, H256};
+use sp_runtime::traits::BlakeTwo256;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn remark_call() -> (Vec<u8>, H256) {
+	let call = RuntimeCall::System(frame_system::Call::remark {
+		remark: vec![1, 2, 3],
+	})
+	.encode();
+	let hash = BlakeTwo256::hash(&call);
+	(call, hash)
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(&["Whitelist.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::whitelist_call_selectors().contains(&0xfe644b8e));
+	assert!(PCall::remove_whitelisted_call_selectors().contains(&0x6176c662));
+	assert!(PCall::dispatch_whitelisted_call_with_preimage_selectors().contains(&0x0399a2ca));
+	assert!(PCall::is_whitelisted_selectors().contains(&0x01a5e3fe));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_default_modifier(PCall::whitelist_call_selectors());
+		tester.test_default_modifier(PCall::remove_whitelisted_call_selectors());
+		tester.test_default_modifier(PCall::dispatch_whitelisted_call_with_preimage_selectors());
+		tester.test_view_modifier(PCall::is_whitelisted_selectors());
+	});
+}
+
+#[test]
+fn member_can_whitelist_and_remove_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (_, call_hash) = remark_call();
+
+		precompiles()
+			.prepare_test(Bob, Precompile1, PCall::whitelist_call { call_hash })
+			.expect_no_logs()
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::is_whitelisted { call_hash })
+			.expect_no_logs()
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::remove_whitelisted_call { call_hash },
+			)
+			.expect_no_logs()
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::is_whitelisted { call_hash })
+			.execute_returns(false);
+
+		assert!(events().contains(&RuntimeEvent::Whitelist(
+			pallet_whitelist::Event::CallWhitelisted { call_hash }
+		)));
+		assert!(events().contains(&RuntimeEvent::Whitelist(
+			pallet_whitelist::Event::WhitelistedCallRemoved { call_hash }
+		)));
+	});
+}
+
+#[test]
+fn non_member_cannot_whitelist_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (_, call_hash) = remark_call();
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::whitelist_call { call_hash })
+			.execute_reverts(|output| output == b"Caller is not a member of the collective");
+		assert!(!pallet_whitelist::WhitelistedCall::<Runtime>::contains_key(
+			call_hash
+		));
+	});
+}
+
+#[test]
+fn member_can_dispatch_whitelisted_call_with_preimage() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (call, call_hash) = remark_call();
+
+		precompiles()
+			.prepare_test(Bob, Precompile1, PCall::whitelist_call { call_hash })
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::dispatch_whitelisted_call_with_preimage { call: call.into() },
+			)
+			.expect_no_logs()
+			.execute_returns(());
+
+		assert!(!pallet_whitelist::WhitelistedCall::<Runtime>::contains_key(
+			call_hash
+		));
+		assert!(events().iter().any(|e| matches!(
+			e,
+			RuntimeEvent::Whitelist(pallet_whitelist::Event::WhitelistedCallDispatched {
+				call_hash: hash,
+				result: Ok(_),
+			}) if *hash == call_hash
+		)));
+	});
+}
+
+#[test]
+fn dispatching_call_that_is_not_whitelisted_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (call, _) = remark_call();
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::dispatch_whitelisted_call_with_preimage { call: call.into() },
+			)
+			.execute_reverts(|output| {
+				core::str::from_utf8(output)
+					.unwrap()
+					.contains("CallIsNotWhitelisted")
+			});
+	});
+}
+
+#[test]
+fn dispatching_undecodable_call_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::dispatch_whitelisted_call_with_preimage {
+					call: vec![0xff; 4].into(),
+				},
+			)
+			.execute_reverts(|output| output == b"call: Failed to decode call");
+	});
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Xc20Issuance contract's address.
+address constant XC20_ISSUANCE_ADDRESS = 0x0000000000000000000000000000000000000819;
+
+/// @dev The Xc20Issuance contract's instance.
+Xc20Issuance constant XC20_ISSUANCE_CONTRACT = Xc20Issuance(XC20_ISSUANCE_ADDRESS);
+
+/// @author The Moonbeam Team
+/// @title XC-20 issuance reconciliation interface
+/// @title The interface through which solidity contracts can compare the issuance of a foreign
+/// asset (XC-20) with the amounts minted and burned through XCM
+/// @custom:address 0x0000000000000000000000000000000000000819
+interface Xc20Issuance {
+    /// @dev Issuance figures of a foreign asset, reverts if it never moved through XCM since
+    /// the tracking started.
+    /// @custom:selector e72dffa8
+    /// @param xc20 The address of the foreign asset
+    /// @return baseline Issuance of the asset when the tracking started
+    /// @return xcmMinted Cumulative amount minted by XCM deposits
+    /// @return xcmBurned Cumulative amount burned by XCM withdrawals
+    /// @return expectedIssuance baseline + xcmMinted - xcmBurned
+    /// @return totalIssuance Current total issuance of the asset
+    function issuanceReport(address xc20)
+        external
+        view
+        returns (
+            uint256 baseline,
+            uint256 xcmMinted,
+            uint256 xcmBurned,
+            uint256 expectedIssuance,
+            uint256 totalIssuance
+        );
+
+    /// @dev Whether the total issuance of a tracked foreign asset differs from the issuance
+    /// expected from its XCM mints and burns. Returns false for untracked assets.
+    /// @custom:selector b5942c36
+    /// @param xc20 The address of the foreign asset
+    /// @return Whether the asset is drifting
+    function isDrifting(address xc20) external view returns (bool);
+}
@@ -0,0 +1,104 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile exposing the issuance reconciliation figures of foreign assets (XC-20s) tracked by
+//! pallet-xc20-issuance.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::traits::Get;
+use pallet_evm::AddressMapping;
+use pallet_evm_precompileset_assets_erc20::AccountIdAssetIdConversion;
+use pallet_xc20_issuance::Xc20IssuanceReport;
+use precompile_utils::prelude::*;
+use sp_core::U256;
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type AssetIdOf<Runtime> = <Runtime as pallet_xc20_issuance::Config>::AssetId;
+type BalanceOf<Runtime> = <Runtime as pallet_xc20_issuance::Config>::Balance;
+
+/// A precompile exposing the figures of pallet-xc20-issuance.
+/// `ForeignAssetPrefix` is the address prefix of foreign assets.
+pub struct Xc20IssuancePrecompile<Runtime, ForeignAssetPrefix>(
+	PhantomData<(Runtime, ForeignAssetPrefix)>,
+);
+
+#[precompile_utils::precompile]
+impl<Runtime, ForeignAssetPrefix> Xc20IssuancePrecompile<Runtime, ForeignAssetPrefix>
+where
+	Runtime: pallet_xc20_issuance::Config + pallet_evm::Config + frame_system::Config,
+	Runtime: AccountIdAssetIdConversion<Runtime::AccountId, AssetIdOf<Runtime>>,
+	BalanceOf<Runtime>: Into<U256>,
+	ForeignAssetPrefix: Get<&'static [u8]>,
+{
+	#[precompile::public("issuanceReport(address)")]
+	#[precompile::view]
+	fn issuance_report(
+		handle: &mut impl PrecompileHandle,
+		xc20: Address,
+	) -> EvmResult<(U256, U256, U256, U256, U256)> {
+		let report = Self::read_report(handle, xc20)?
+			.ok_or_else(|| RevertReason::custom("Asset is not tracked").in_field("xc20"))?;
+
+		Ok((
+			report.baseline.into(),
+			report.xcm_minted.into(),
+			report.xcm_burned.into(),
+			report.expected_issuance.into(),
+			report.total_issuance.into(),
+		))
+	}
+
+	#[precompile::public("isDrifting(address)")]
+	#[precompile::view]
+	fn is_drifting(handle: &mut impl PrecompileHandle, xc20: Address) -> EvmResult<bool> {
+		Ok(Self::read_report(handle, xc20)?
+			.map(|report| report.is_drifting())
+			.unwrap_or(false))
+	}
+
+	fn read_report(
+		handle: &mut impl PrecompileHandle,
+		xc20: Address,
+	) -> EvmResult<Option<Xc20IssuanceReport<BalanceOf<Runtime>>>> {
+		// Storage item: IssuanceRecords:
+		// Blake2_128(16) + AssetId(16) + IssuanceRecord(3 * Balance(16))
+		handle.record_db_read::<Runtime>(80)?;
+		// Storage item: Asset:
+		// Blake2_128(16) + AssetId(16) + AssetDetails((4 * AccountId(20)) + (3 * Balance(16)) + 15)
+		handle.record_db_read::<Runtime>(175)?;
+
+		let account = Runtime::AddressMapping::into_account_id(xc20.into());
+		let asset_id = match Runtime::account_to_asset_id(account) {
+			Some((prefix, asset_id)) if prefix == ForeignAssetPrefix::get() => asset_id,
+			_ => {
+				return Err(RevertReason::custom("Not a foreign asset")
+					.in_field("xc20")
+					.into())
+			}
+		};
+
+		Ok(pallet_xc20_issuance::Pallet::<Runtime>::issuance_report(
+			asset_id,
+		))
+	}
+}
@@ -0,0 +1,141 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use frame_support::{assert_ok, traits::fungibles::Mutate};
+use precompile_utils::testing::*;
+
+const ASSET_ID: AssetId = 1;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(&["Xc20Issuance.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::issuance_report_selectors().contains(&0xe72dffa8));
+	assert!(PCall::is_drifting_selectors().contains(&0xb5942c36));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_view_modifier(PCall::issuance_report_selectors());
+		tester.test_view_modifier(PCall::is_drifting_selectors());
+	});
+}
+
+#[test]
+fn issuance_report_works() {
+	ExtBuilder::default()
+		.with_assets(vec![ASSET_ID])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Assets::mint_into(ASSET_ID, &Alice.into(), 100));
+			Xc20Issuance::note_xcm_mint(ASSET_ID, 100);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::issuance_report {
+						xc20: Address(ForeignAssetId(ASSET_ID).into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns((
+					U256::zero(),
+					U256::from(100),
+					U256::zero(),
+					U256::from(100),
+					U256::from(100),
+				));
+		});
+}
+
+#[test]
+fn issuance_report_reverts_for_untracked_asset() {
+	ExtBuilder::default()
+		.with_assets(vec![ASSET_ID])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::issuance_report {
+						xc20: Address(ForeignAssetId(ASSET_ID).into()),
+					},
+				)
+				.execute_reverts(|output| output == b"xc20: Asset is not tracked");
+		});
+}
+
+#[test]
+fn issuance_report_reverts_for_local_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::issuance_report {
+					xc20: Address(LocalAssetId(ASSET_ID).into()),
+				},
+			)
+			.execute_reverts(|output| output == b"xc20: Not a foreign asset");
+	});
+}
+
+#[test]
+fn is_drifting_works() {
+	ExtBuilder::default()
+		.with_assets(vec![ASSET_ID])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Assets::mint_into(ASSET_ID, &Alice.into(), 100));
+			Xc20Issuance::note_xcm_mint(ASSET_ID, 100);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::is_drifting {
+						xc20: Address(ForeignAssetId(ASSET_ID).into()),
+					},
+				)
+				.execute_returns(false);
+
+			// Mint outside of XCM
+			assert_ok!(Assets::mint_into(ASSET_ID, &Alice.into(), 5));
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::is_drifting {
+						xc20: Address(ForeignAssetId(ASSET_ID).into()),
+					},
+				)
+				.execute_returns(true);
+		});
+}
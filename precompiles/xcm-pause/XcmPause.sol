@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The XcmPause contract's address.
+address constant XCM_PAUSE_ADDRESS = 0x000000000000000000000000000000000000081A;
+
+/// @dev The XcmPause contract's instance.
+XcmPause constant XCM_PAUSE_CONTRACT = XcmPause(XCM_PAUSE_ADDRESS);
+
+/// @author The Moonbeam Team
+/// @title Emergency XCM pause interface
+/// @title The interface through which the members of the Open Tech Committee can pause XCM in an
+/// emergency, suspending the XCMP queue and the local execution of XCM messages
+/// @custom:address 0x000000000000000000000000000000000000081A
+interface XcmPause {
+    /// @dev Pause XCM. Only callable by a member of the Open Tech Committee.
+    /// @custom:selector a81c4882
+    function xcmPause() external;
+
+    /// @dev Resume XCM after it has been paused. Only callable by a member of the Open Tech
+    /// Committee.
+    /// @custom:selector 68ccb5d1
+    function xcmResume() external;
+
+    /// @dev Whether XCM is paused
+    /// @custom:selector 43416943
+    /// @return Whether XCM is paused
+    function isXcmPaused() external view returns (bool);
+}
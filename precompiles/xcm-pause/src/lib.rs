@@ -0,0 +1,105 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile allowing the members of a collective (the Open Tech Committee) to pause and resume
+//! XCM through pallet-xcm-filter, so that a compromised channel can be isolated quickly.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::Get;
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Storage growth when pausing or resuming XCM.
+/// XcmPaused: Twox128(16) + Twox128(16) + bool(1)
+pub const XCM_PAUSED_STORAGE_GROWTH: u64 = 33;
+
+/// A precompile dispatching the emergency XCM pause of pallet-xcm-filter with the collective
+/// member origin of its caller. `Instance` is the instance of the collective whose members are
+/// allowed to pause and resume XCM.
+pub struct XcmPausePrecompile<Runtime, Instance: 'static>(PhantomData<(Runtime, Instance)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, Instance> XcmPausePrecompile<Runtime, Instance>
+where
+	Instance: 'static,
+	Runtime: pallet_collective::Config<Instance> + pallet_xcm_filter::Config + pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_xcm_filter::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		From<pallet_collective::RawOrigin<Runtime::AccountId, Instance>>,
+{
+	#[precompile::public("xcmPause()")]
+	fn xcm_pause(handle: &mut impl PrecompileHandle) -> EvmResult {
+		let origin = Self::ensure_member(handle)?;
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			origin,
+			pallet_xcm_filter::Call::<Runtime>::pause_xcm {},
+			XCM_PAUSED_STORAGE_GROWTH,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("xcmResume()")]
+	fn xcm_resume(handle: &mut impl PrecompileHandle) -> EvmResult {
+		let origin = Self::ensure_member(handle)?;
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			origin,
+			pallet_xcm_filter::Call::<Runtime>::resume_xcm {},
+			XCM_PAUSED_STORAGE_GROWTH,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("isXcmPaused()")]
+	#[precompile::view]
+	fn is_xcm_paused(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+		// XcmPaused: bool(1)
+		handle.record_db_read::<Runtime>(1)?;
+
+		Ok(pallet_xcm_filter::Pallet::<Runtime>::is_xcm_paused())
+	}
+
+	/// Returns the collective member origin of the caller, reverting if the caller is not a
+	/// member of the collective.
+	fn ensure_member(
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin> {
+		// Members: Vec(20 * MaxMembers)
+		handle.record_db_read::<Runtime>(
+			20 * (<Runtime as pallet_collective::Config<Instance>>::MaxMembers::get() as usize),
+		)?;
+
+		let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		if !pallet_collective::Pallet::<Runtime, Instance>::is_member(&caller) {
+			return Err(revert("Caller is not a member of the collective"));
+		}
+
+		Ok(pallet_collective::RawOrigin::<Runtime::AccountId, Instance>::Member(caller).into())
+	}
+}
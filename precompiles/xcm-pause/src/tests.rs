@@ -0,0 +1,117 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use precompile_utils::testing::*;
+use std::str::from_utf8;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(&["XcmPause.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::xcm_pause_selectors().contains(&0xa81c4882));
+	assert!(PCall::xcm_resume_selectors().contains(&0x68ccb5d1));
+	assert!(PCall::is_xcm_paused_selectors().contains(&0x43416943));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_default_modifier(PCall::xcm_pause_selectors());
+		tester.test_default_modifier(PCall::xcm_resume_selectors());
+		tester.test_view_modifier(PCall::is_xcm_paused_selectors());
+	});
+}
+
+#[test]
+fn member_can_pause_and_resume_xcm() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Bob, Precompile1, PCall::xcm_pause {})
+			.expect_no_logs()
+			.execute_returns(());
+		assert!(XcmFilter::is_xcm_paused());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::is_xcm_paused {})
+			.expect_no_logs()
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(Bob, Precompile1, PCall::xcm_resume {})
+			.expect_no_logs()
+			.execute_returns(());
+		assert!(!XcmFilter::is_xcm_paused());
+
+		assert!(events().contains(&RuntimeEvent::XcmFilter(
+			pallet_xcm_filter::Event::XcmPaused
+		)));
+		assert!(events().contains(&RuntimeEvent::XcmFilter(
+			pallet_xcm_filter::Event::XcmResumed
+		)));
+	});
+}
+
+#[test]
+fn non_member_cannot_pause_xcm() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::xcm_pause {})
+			.execute_reverts(|output| output == b"Caller is not a member of the collective");
+		assert!(!XcmFilter::is_xcm_paused());
+	});
+}
+
+#[test]
+fn non_member_cannot_resume_xcm() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Bob, Precompile1, PCall::xcm_pause {})
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::xcm_resume {})
+			.execute_reverts(|output| output == b"Caller is not a member of the collective");
+		assert!(XcmFilter::is_xcm_paused());
+	});
+}
+
+#[test]
+fn pausing_twice_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Bob, Precompile1, PCall::xcm_pause {})
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Bob, Precompile1, PCall::xcm_pause {})
+			.execute_reverts(|output| {
+				from_utf8(&output)
+					.unwrap()
+					.contains("Dispatched call failed with error: ")
+					&& from_utf8(&output).unwrap().contains("AlreadyPaused")
+			});
+	});
+}
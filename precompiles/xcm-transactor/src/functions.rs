@@ -23,7 +23,7 @@ use frame_support::{
 };
 use pallet_evm::AddressMapping;
 use pallet_xcm_transactor::{
-	Currency, CurrencyPayment, RemoteTransactInfoWithMaxWeight, TransactWeights,
+	Currency, CurrencyPayment, RemoteTransactInfoWithMaxWeight, TransactOutcome, TransactWeights,
 };
 use precompile_utils::prelude::*;
 use sp_core::{MaxEncodedLen, H160, U256};
@@ -72,6 +72,49 @@ where
 		Ok(account.into())
 	}
 
+	pub(crate) fn transact_status(
+		handle: &mut impl PrecompileHandle,
+		query_id: u64,
+	) -> EvmResult<u8> {
+		// storage item: TransactStatus: Twox64(8) + u64(8) + AccountId(20) + MultiLocation
+		// + TransactOutcome(1)
+		handle.record_db_read::<Runtime>(37 + MultiLocation::max_encoded_len())?;
+
+		// fetch data from pallet
+		let info = pallet_xcm_transactor::Pallet::<Runtime>::transact_status(query_id)
+			.ok_or(revert("No transact status for query"))?;
+
+		Ok(match info.outcome {
+			TransactOutcome::Pending => 0,
+			TransactOutcome::Success => 1,
+			TransactOutcome::Failed => 2,
+		})
+	}
+
+	pub(crate) fn transfer_index(
+		handle: &mut impl PrecompileHandle,
+		index: u16,
+		new_owner: Address,
+	) -> EvmResult {
+		let new_owner = Runtime::AddressMapping::into_account_id(new_owner.0);
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_xcm_transactor::Call::<Runtime>::transfer_index { index, new_owner };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	pub(crate) fn release_index(handle: &mut impl PrecompileHandle, index: u16) -> EvmResult {
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_xcm_transactor::Call::<Runtime>::release_index { index };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
 	pub(crate) fn transact_info(
 		handle: &mut impl PrecompileHandle,
 		multilocation: MultiLocation,
@@ -141,6 +184,45 @@ where
 		Ok(fee_per_second.into())
 	}
 
+	pub(crate) fn estimate_transact_fee(
+		handle: &mut impl PrecompileHandle,
+		dest: MultiLocation,
+		transact_weight: u64,
+		fee_asset: Address,
+	) -> EvmResult<U256> {
+		let to_address: H160 = fee_asset.into();
+		let to_account = Runtime::AddressMapping::into_account_id(to_address);
+
+		// We convert the address into a currency
+		let currency_id: <Runtime as pallet_xcm_transactor::Config>::CurrencyId =
+			Runtime::account_to_currency_id(to_account)
+				.ok_or(revert("cannot convert into currency id"))?;
+
+		// storage item: AssetIdType: Blake2_128(16) + AssetId(16) + MultiLocation
+		handle.record_db_read::<Runtime>(32 + MultiLocation::max_encoded_len())?;
+		let fee_location = pallet_xcm_transactor::Pallet::<Runtime>::currency_to_multilocation(
+			Currency::AsCurrencyId(currency_id),
+		)
+		.ok_or(revert("cannot convert currency id into multilocation"))?;
+
+		// storage item: TransactInfoWithWeightLimit: Blake2_128(16) + MultiLocation
+		// + RemoteTransactInfoWithMaxWeight
+		handle.record_db_read::<Runtime>(
+			16 + MultiLocation::max_encoded_len()
+				+ RemoteTransactInfoWithMaxWeight::max_encoded_len(),
+		)?;
+		// storage item: AssetTypeUnitsPerSecond: Blake2_128(16) + MultiLocation + u128(16)
+		handle.record_db_read::<Runtime>(32 + MultiLocation::max_encoded_len())?;
+		let fee = pallet_xcm_transactor::Pallet::<Runtime>::estimate_transact_fee(
+			dest,
+			Weight::from_parts(transact_weight, DEFAULT_PROOF_SIZE.saturating_div(2)),
+			fee_location,
+		)
+		.map_err(|e| revert(<&'static str>::from(e)))?;
+
+		Ok(fee.into())
+	}
+
 	pub(crate) fn transact_through_derivative_multilocation(
 		handle: &mut impl PrecompileHandle,
 		transactor: u8,
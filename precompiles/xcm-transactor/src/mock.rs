@@ -315,6 +315,13 @@ parameter_types! {
 	pub UniversalLocation: InteriorMultiLocation = Here;
 }
 
+pub struct DummyTransactStatusQuerier;
+impl pallet_xcm_transactor::TransactStatusQuerier for DummyTransactStatusQuerier {
+	fn new_transact_status_query(_responder: MultiLocation, _querier: MultiLocation) -> QueryId {
+		0
+	}
+}
+
 impl pallet_xcm_transactor::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -335,6 +342,10 @@ impl pallet_xcm_transactor::Config for Runtime {
 	type HrmpManipulatorOrigin = frame_system::EnsureRoot<AccountId>;
 	type MaxHrmpFee = ();
 	type HrmpEncoder = ();
+	type TransactStatusQuerier = DummyTransactStatusQuerier;
+	type TransactStatusResponseOrigin = frame_system::EnsureNever<MultiLocation>;
+	type DepositCurrency = Balances;
+	type DerivativeIndexDeposit = frame_support::traits::ConstU128<0>;
 }
 
 // We need to use the encoding from the relay mock runtime
@@ -18,9 +18,10 @@ use crate::mock::{
 	RuntimeOrigin, TransactorV1, TransactorV2, TransactorV3, XcmTransactor,
 };
 
-use frame_support::{assert_ok, dispatch::Weight};
+use frame_support::{assert_ok, dispatch::Weight, weights::constants::WEIGHT_REF_TIME_PER_SECOND};
+use pallet_xcm_transactor::{TransactOutcome, TransactStatusInfo};
 use precompile_utils::{prelude::*, testing::*};
-use sp_core::H160;
+use sp_core::{H160, U256};
 use sp_std::boxed::Box;
 use xcm::latest::MultiLocation;
 
@@ -42,6 +43,7 @@ fn selectors() {
 	assert!(PCallV2::index_to_account_selectors().contains(&0x3fdc4f36));
 	assert!(PCallV2::transact_info_with_signed_selectors().contains(&0xb689e20c));
 	assert!(PCallV2::fee_per_second_selectors().contains(&0x906c9990));
+	assert!(PCallV2::estimate_transact_fee_selectors().contains(&0x0ea5e325));
 	assert!(PCallV2::transact_through_derivative_multilocation_selectors().contains(&0xfe430475));
 	assert!(PCallV2::transact_through_derivative_selectors().contains(&0x185de2ae));
 	assert!(PCallV2::transact_through_signed_multilocation_selectors().contains(&0xd7ab340c));
@@ -54,6 +56,9 @@ fn selectors() {
 	assert!(PCallV3::transact_through_derivative_selectors().contains(&0xca8c82d8));
 	assert!(PCallV3::transact_through_signed_multilocation_selectors().contains(&0x27b1d492));
 	assert!(PCallV3::transact_through_signed_selectors().contains(&0xb18270cf));
+	assert!(PCallV3::transact_status_selectors().contains(&0x30015ade));
+	assert!(PCallV3::transfer_index_selectors().contains(&0x98eb9a65));
+	assert!(PCallV3::release_index_selectors().contains(&0x87a0c367));
 }
 
 #[test]
@@ -76,11 +81,18 @@ fn modifiers() {
 		tester.test_view_modifier(PCallV2::index_to_account_selectors());
 		tester.test_view_modifier(PCallV2::transact_info_with_signed_selectors());
 		tester.test_view_modifier(PCallV2::fee_per_second_selectors());
+		tester.test_view_modifier(PCallV2::estimate_transact_fee_selectors());
 		tester
 			.test_default_modifier(PCallV2::transact_through_derivative_multilocation_selectors());
 		tester.test_default_modifier(PCallV2::transact_through_derivative_selectors());
 		tester.test_default_modifier(PCallV2::transact_through_signed_multilocation_selectors());
 		tester.test_default_modifier(PCallV2::transact_through_signed_selectors());
+
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, TransactorV3);
+
+		tester.test_view_modifier(PCallV3::transact_status_selectors());
+		tester.test_default_modifier(PCallV3::transfer_index_selectors());
+		tester.test_default_modifier(PCallV3::release_index_selectors());
 	});
 }
 
@@ -240,6 +252,48 @@ fn take_fee_per_second() {
 		});
 }
 
+#[test]
+fn take_estimate_transact_fee() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let input: Vec<_> = PCallV2::estimate_transact_fee {
+				dest: MultiLocation::parent(),
+				transact_weight: 1_000,
+				fee_asset: Address(AssetAddress(0).into()),
+			}
+			.into();
+
+			// Assert that errors since no transact info is set
+			precompiles()
+				.prepare_test(Alice, TransactorV2, input.clone())
+				.execute_reverts(|output| output == b"TransactorInfoNotSet");
+
+			// Root can set transact info
+			assert_ok!(XcmTransactor::set_transact_info(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::parent())),
+				100u64.into(),
+				10000u64.into(),
+				None
+			));
+
+			// Root can set fee per second
+			assert_ok!(XcmTransactor::set_fee_per_second(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::parent())),
+				WEIGHT_REF_TIME_PER_SECOND as u128
+			));
+
+			precompiles()
+				.prepare_test(Alice, TransactorV2, input)
+				.expect_cost(3)
+				.expect_no_logs()
+				.execute_returns(U256::from(1_100u64));
+		});
+}
+
 #[test]
 fn test_transact_derivative_multilocation_v2() {
 	ExtBuilder::default()
@@ -371,6 +425,84 @@ fn take_transact_info_with_signed_v3() {
 		});
 }
 
+#[test]
+fn take_transact_status() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let input: Vec<_> = PCallV3::transact_status { query_id: 0 }.into();
+
+			// Assert that errors since no status is tracked
+			precompiles()
+				.prepare_test(Alice, TransactorV3, input.clone())
+				.execute_reverts(|output| output == b"No transact status for query");
+
+			pallet_xcm_transactor::TransactStatus::<Runtime>::insert(
+				0,
+				TransactStatusInfo {
+					sender: Alice.into(),
+					dest: MultiLocation::parent(),
+					outcome: TransactOutcome::Failed,
+				},
+			);
+
+			precompiles()
+				.prepare_test(Alice, TransactorV3, input)
+				.expect_cost(1)
+				.expect_no_logs()
+				.execute_returns(2u8);
+		});
+}
+
+#[test]
+fn transfer_and_release_index() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			// register index
+			assert_ok!(XcmTransactor::register(
+				RuntimeOrigin::root(),
+				Alice.into(),
+				0
+			));
+
+			// Only the owner can transfer the index
+			precompiles()
+				.prepare_test(
+					Bob,
+					TransactorV3,
+					PCallV3::transfer_index {
+						index: 0,
+						new_owner: Address(Bob.into()),
+					},
+				)
+				.execute_reverts(|output| output.ends_with(b"NotOwner\") })"));
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					TransactorV3,
+					PCallV3::transfer_index {
+						index: 0,
+						new_owner: Address(Bob.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+
+			assert_eq!(XcmTransactor::index_to_account(0), Some(Bob.into()));
+
+			precompiles()
+				.prepare_test(Bob, TransactorV3, PCallV3::release_index { index: 0 })
+				.expect_no_logs()
+				.execute_returns(());
+
+			assert!(XcmTransactor::index_to_account(0).is_none());
+		});
+}
+
 #[test]
 fn test_transact_derivative_multilocation() {
 	ExtBuilder::default()
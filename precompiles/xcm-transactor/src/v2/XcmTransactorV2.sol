@@ -53,6 +53,19 @@ interface XcmTransactorV2 {
         view
         returns (uint256 feePerSecond);
 
+    /// Estimate the fee charged for transacting through the derivative or sovereign account
+    /// @custom:selector 0ea5e325
+    /// @param dest The destination chain (as multilocation) where to send the message
+    /// @param transactWeight The weight we want to buy in the destination chain for the call
+    /// @param feeAsset The asset address used to pay the fee
+    /// @return fee The fee, including the transact extra weight of the destination
+    ///
+    function estimateTransactFee(
+        Multilocation memory dest,
+        uint64 transactWeight,
+        address feeAsset
+    ) external view returns (uint256 fee);
+
     /// Transact through XCM using fee based on its multilocation
     /// @custom:selector fe430475
     /// @dev The token transfer burns/transfers the corresponding amount before sending
@@ -63,6 +63,22 @@ where
 		XcmTransactorWrapper::<Runtime>::fee_per_second(handle, multilocation)
 	}
 
+	#[precompile::public("estimateTransactFee((uint8,bytes[]),uint64,address)")]
+	#[precompile::view]
+	fn estimate_transact_fee(
+		handle: &mut impl PrecompileHandle,
+		dest: MultiLocation,
+		transact_weight: u64,
+		fee_asset: Address,
+	) -> EvmResult<U256> {
+		XcmTransactorWrapper::<Runtime>::estimate_transact_fee(
+			handle,
+			dest,
+			transact_weight,
+			fee_asset,
+		)
+	}
+
 	#[precompile::public(
 		"transactThroughDerivativeMultilocation(\
 		uint8,\
@@ -59,6 +59,26 @@ interface XcmTransactorV3 {
         view
         returns (uint256 feePerSecond);
 
+    /// Get the outcome of a transact whose status was requested to be reported back
+    /// @custom:selector 30015ade
+    /// @param queryId The id of the query under which the destination chain reports back
+    /// @return status 0 if still pending, 1 if the call succeeded, 2 if it failed
+    ///
+    function transactStatus(uint64 queryId) external view returns (uint8 status);
+
+    /// Transfer a derivative index owned by the caller, along with its deposit
+    /// @custom:selector 98eb9a65
+    /// @param index The derivative index to transfer
+    /// @param newOwner The account that will own the derivative index
+    ///
+    function transferIndex(uint16 index, address newOwner) external;
+
+    /// Release a derivative index owned by the caller, refunding its deposit
+    /// @custom:selector 87a0c367
+    /// @param index The derivative index to release
+    ///
+    function releaseIndex(uint16 index) external;
+
     /// Transact through XCM using fee based on its multilocation
     /// @custom:selector bdacc26b
     /// @dev The token transfer burns/transfers the corresponding amount before sending
@@ -63,6 +63,26 @@ where
 		XcmTransactorWrapper::<Runtime>::fee_per_second(handle, multilocation)
 	}
 
+	#[precompile::public("transactStatus(uint64)")]
+	#[precompile::view]
+	fn transact_status(handle: &mut impl PrecompileHandle, query_id: u64) -> EvmResult<u8> {
+		XcmTransactorWrapper::<Runtime>::transact_status(handle, query_id)
+	}
+
+	#[precompile::public("transferIndex(uint16,address)")]
+	fn transfer_index(
+		handle: &mut impl PrecompileHandle,
+		index: u16,
+		new_owner: Address,
+	) -> EvmResult {
+		XcmTransactorWrapper::<Runtime>::transfer_index(handle, index, new_owner)
+	}
+
+	#[precompile::public("releaseIndex(uint16)")]
+	fn release_index(handle: &mut impl PrecompileHandle, index: u16) -> EvmResult {
+		XcmTransactorWrapper::<Runtime>::release_index(handle, index)
+	}
+
 	#[precompile::public(
 		"transactThroughDerivativeMultilocation(\
 		uint8,\
@@ -0,0 +1,93 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API exposing the Substrate-level state of an account: the reference counters of
+//! frame_system and the locks, reserves and holds of pallet_balances.
+//!
+//! The balance returned by `eth_getBalance` is the free balance of the account, part of which
+//! may not be transferable (e.g. staking locks, delegation deposits). This API allows wallets to
+//! explain why.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::H160;
+use sp_std::vec::Vec;
+
+/// Operations a balance lock applies to.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub enum LockReasons {
+	/// Paying transaction fees.
+	Fee,
+	/// Any other operation.
+	Misc,
+	/// All operations.
+	All,
+}
+
+/// A lock on the free balance of an account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct BalanceLockInfo<Balance> {
+	/// Identifier of the lock, e.g. `stkngdel` for parachain-staking delegations.
+	pub id: [u8; 8],
+	pub amount: Balance,
+	pub reasons: LockReasons,
+}
+
+/// A named reserve or a hold, its identifier being SCALE encoded.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct BalanceReserveInfo<Balance> {
+	pub id: Vec<u8>,
+	pub amount: Balance,
+}
+
+/// Substrate-level state of an account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct AccountMeta<Index, Balance> {
+	pub nonce: Index,
+	/// Number of other modules that currently depend on this account's existence.
+	pub consumers: u32,
+	/// Number of other modules that allow this account to exist.
+	pub providers: u32,
+	/// Number of modules that allow this account to exist for their own purposes.
+	pub sufficients: u32,
+	pub free: Balance,
+	pub reserved: Balance,
+	/// Part of the free balance that cannot be transferred, the largest of the locks.
+	pub frozen: Balance,
+	/// Part of the free balance that can be transferred.
+	pub transferable: Balance,
+	pub locks: Vec<BalanceLockInfo<Balance>>,
+	/// Named reserves, which make up part of the reserved balance.
+	pub reserves: Vec<BalanceReserveInfo<Balance>>,
+	/// Holds, which make up part of the reserved balance.
+	pub holds: Vec<BalanceReserveInfo<Balance>>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait AccountInfoApi<Index, Balance>
+	where
+		Index: Codec,
+		Balance: Codec,
+	{
+		/// Returns the Substrate-level state of the account of an Ethereum address.
+		fn account_meta(address: H160) -> AccountMeta<Index, Balance>;
+	}
+}
@@ -0,0 +1,61 @@
+// Copyright 2019-2023 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API allowing to enumerate the XC-20s of the chain.
+//!
+//! Foreign assets are split between the asset manager, which knows their location, and
+//! pallet-assets, which holds their metadata. This API gathers both along with the address of
+//! their ERC-20 interface, so explorers and wallets can list bridged assets in a single call.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::H160;
+use sp_std::vec::Vec;
+use xcm::latest::MultiLocation;
+
+/// Metadata of an asset, as set in pallet-assets.
+#[derive(Clone, Default, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct AssetMetadata {
+	pub name: Vec<u8>,
+	pub symbol: Vec<u8>,
+	pub decimals: u8,
+}
+
+/// A foreign asset registered in the asset manager.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct ForeignAsset<AssetId> {
+	pub asset_id: AssetId,
+	/// Location of the asset, relative to this chain.
+	pub location: MultiLocation,
+	/// Address of the ERC-20 interface of the asset.
+	pub address: H160,
+	pub metadata: AssetMetadata,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait AssetsApi<AssetId>
+	where
+		AssetId: Codec,
+	{
+		/// Returns all the foreign assets registered in the asset manager.
+		fn list_foreign_assets() -> Vec<ForeignAsset<AssetId>>;
+	}
+}
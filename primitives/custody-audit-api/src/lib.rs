@@ -0,0 +1,66 @@
+// Copyright 2019-2023 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API allowing proof-of-reserve monitors to audit the custody of bridged assets.
+//!
+//! For each foreign asset (XC-20), the amount custodied on this chain is compared with the amount
+//! recorded as bridged in at XCM ingress, whatever the bridge or the chain the asset comes from.
+//! Monitors can then check the latter against the balance of the sovereign account of this chain
+//! on the reserve, without indexing raw events.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+use xcm::latest::MultiLocation;
+
+/// Custody figures of a single foreign asset.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct CustodyReport<Balance> {
+	/// Location of the reserve chain of the asset, relative to this chain.
+	pub reserve: MultiLocation,
+	/// Amount of the asset currently custodied on this chain, its total issuance.
+	pub custodied: Balance,
+	/// Net amount bridged in, as recorded at XCM ingress and egress. `None` if the asset has not
+	/// moved through XCM since the tracking started.
+	pub bridged_in: Option<Balance>,
+}
+
+impl<Balance: PartialOrd> CustodyReport<Balance> {
+	/// Whether more of the asset is custodied on this chain than was bridged in from the reserve.
+	pub fn is_undercollateralized(&self) -> bool {
+		matches!(&self.bridged_in, Some(bridged_in) if self.custodied > *bridged_in)
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait CustodyAuditApi<AssetId, Balance>
+	where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Returns the custody report of the given foreign asset, or `None` if it is not
+		/// registered.
+		fn custody_report(asset_id: AssetId) -> Option<CustodyReport<Balance>>;
+
+		/// Returns the custody reports of all registered foreign assets.
+		fn custody_reports() -> Vec<(AssetId, CustodyReport<Balance>)>;
+	}
+}
@@ -0,0 +1,62 @@
+// Copyright 2019-2023 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API executing an `eth_call` in an overridden block context.
+//!
+//! The block number, timestamp and base fee seen by the EVM are read from the runtime storage,
+//! so they can only be overridden from within the runtime, before the call is executed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use fp_evm::CallInfo;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256, U256};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+/// Fields of the block context to override, following geth's `blockOverrides`.
+#[derive(Clone, Default, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct BlockOverrides {
+	/// Block number (`NUMBER` opcode).
+	pub number: Option<U256>,
+	/// Block timestamp in seconds (`TIMESTAMP` opcode).
+	pub time: Option<u64>,
+	/// Base fee per gas (`BASEFEE` opcode).
+	pub base_fee: Option<U256>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait EthCallApi {
+		/// Same as `EthereumRuntimeRPCApi::call`, executed after applying the block overrides.
+		/// A missing gas limit defaults to the block gas limit.
+		fn call_with_block_overrides(
+			from: H160,
+			to: H160,
+			data: Vec<u8>,
+			value: U256,
+			gas_limit: Option<U256>,
+			max_fee_per_gas: Option<U256>,
+			max_priority_fee_per_gas: Option<U256>,
+			nonce: Option<U256>,
+			access_list: Option<Vec<(H160, Vec<H256>)>>,
+			block_overrides: BlockOverrides,
+		) -> Result<CallInfo, DispatchError>;
+	}
+}
@@ -0,0 +1,78 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API exposing the contracts hibernated by pallet-evm-hibernation, and the state needed
+//! to wake them.
+//!
+//! The storage of a hibernated contract is removed from the state, only a commitment to it is
+//! kept. The data needed to wake the contract must therefore be read from the state of a block
+//! prior to its hibernation, which requires an archive node.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Codec, Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256};
+use sp_std::vec::Vec;
+
+/// Commitment to the state of a hibernated contract.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub struct HibernatedContract<BlockNumber> {
+	/// Keccak256 hash of the contract code.
+	pub code_hash: H256,
+	/// Commitment to the contract storage, see [`storage_commitment`].
+	pub storage_root: H256,
+	/// Number of storage entries of the contract.
+	pub storage_entries: u32,
+	/// Block in which the contract was hibernated.
+	pub hibernated_at: BlockNumber,
+}
+
+/// Code and storage of a contract.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct ContractState {
+	pub code: Vec<u8>,
+	/// Storage entries, sorted by key.
+	pub storage: Vec<(H256, H256)>,
+}
+
+/// Commitment to the storage of a contract: the keccak256 hash of the concatenation of its
+/// entries (`key ++ value`), sorted by key.
+pub fn storage_commitment(sorted_entries: &[(H256, H256)]) -> H256 {
+	let mut data = Vec::with_capacity(sorted_entries.len() * 64);
+	for (key, value) in sorted_entries {
+		data.extend_from_slice(key.as_bytes());
+		data.extend_from_slice(value.as_bytes());
+	}
+	H256(sp_core::hashing::keccak_256(&data))
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait EvmHibernationApi<BlockNumber>
+	where
+		BlockNumber: Codec,
+	{
+		/// Returns the commitment of a hibernated contract, or `None` if the contract is not
+		/// hibernated.
+		fn hibernated_contract(contract: H160) -> Option<HibernatedContract<BlockNumber>>;
+
+		/// Returns the code and the storage of a contract at this block.
+		fn contract_state(contract: H160) -> ContractState;
+	}
+}
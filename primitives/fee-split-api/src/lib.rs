@@ -0,0 +1,46 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API allowing to query how the transaction fees are split between burning and the
+//! treasury.
+//!
+//! Calling the API at a past block returns the split that was in effect at that block, which lets
+//! indexers attribute the fees of historical blocks.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::Percent;
+
+/// Shares of the transaction fees that are burned and sent to the treasury, adding up to 100%.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub struct FeeSplit {
+	/// Share of the fees that is burned.
+	pub burn: Percent,
+	/// Share of the fees that is sent to the treasury.
+	pub treasury: Percent,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait FeeSplitApi {
+		/// Returns the current split of the transaction fees.
+		fn fee_split() -> FeeSplit;
+	}
+}
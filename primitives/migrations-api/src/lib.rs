@@ -0,0 +1,56 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API exposing the status of the migrations registered in pallet-migrations, so that
+//! block explorers and node operators can check that the migrations of a runtime upgrade
+//! completed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// Weight consumed by a migration.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct MigrationWeight {
+	pub ref_time: u64,
+	pub proof_size: u64,
+}
+
+/// Status of a migration registered in pallet-migrations.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct MigrationStatus {
+	/// Friendly name of the migration, which identifies it in pallet-migrations.
+	pub name: Vec<u8>,
+	/// Whether the migration was started.
+	pub started: bool,
+	/// Whether pallet-migrations marked the migration as done.
+	pub completed: bool,
+	/// Weight consumed by the migration, unknown for the migrations which completed before their
+	/// consumed weight was recorded.
+	pub consumed_weight: Option<MigrationWeight>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait MigrationsApi {
+		/// Returns the status of each migration registered in pallet-migrations.
+		fn migrations_status() -> Vec<MigrationStatus>;
+	}
+}
@@ -0,0 +1,70 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API allowing to audit the rotation of the orbiters program.
+//!
+//! For each orbiter, it summarizes the rounds it was selected to author blocks for and the
+//! rewards it earned, so the fairness of the rotation can be verified from on-chain data.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// Rewards earned by an orbiter for a single round.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct OrbiterRoundRewards<AccountId, RoundIndex, Balance> {
+	/// The round the rewards were paid for.
+	pub round: RoundIndex,
+	/// The collator the orbiter authored blocks for.
+	pub collator: AccountId,
+	/// The rewards paid to the orbiter.
+	pub rewards: Balance,
+}
+
+/// Rotation and rewards history of a single orbiter.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct OrbiterAuditSummary<AccountId, RoundIndex, Balance> {
+	/// Number of rounds the orbiter was selected to author blocks for.
+	pub rounds_served: RoundIndex,
+	/// Number of rounds the orbiter was rewarded for.
+	pub rounds_rewarded: RoundIndex,
+	/// Cumulative rewards paid to the orbiter.
+	pub total_rewards: Balance,
+	/// Most recent rewarded rounds, oldest first.
+	pub recent_rewards: Vec<OrbiterRoundRewards<AccountId, RoundIndex, Balance>>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait OrbitersApi<AccountId, RoundIndex, Balance>
+	where
+		AccountId: Codec,
+		RoundIndex: Codec,
+		Balance: Codec,
+	{
+		/// Returns the audit summary of the given orbiter, or `None` if it was never selected.
+		fn orbiter_audit(
+			orbiter: AccountId,
+		) -> Option<OrbiterAuditSummary<AccountId, RoundIndex, Balance>>;
+
+		/// Returns the audit summaries of all orbiters that were ever selected.
+		fn orbiters_audit() -> Vec<(AccountId, OrbiterAuditSummary<AccountId, RoundIndex, Balance>)>;
+	}
+}
@@ -0,0 +1,38 @@
+// Copyright 2019-2023 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API listing the Ethereum transaction types supported by the runtime.
+//!
+//! Transactions of other types (e.g. EIP-4844 blob or EIP-7702 set code transactions) can't be
+//! decoded nor executed by the runtime, and are rejected by the node before reaching the
+//! transaction pool.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+
+use sp_std::vec::Vec;
+
+/// Type of legacy transactions, which have no EIP-2718 envelope.
+pub const LEGACY_TRANSACTION_TYPE: u8 = 0;
+
+sp_api::decl_runtime_apis! {
+	pub trait TransactionTypesApi {
+		/// EIP-2718 types of the Ethereum transactions supported by the runtime, including
+		/// `LEGACY_TRANSACTION_TYPE` for legacy transactions.
+		fn supported_transaction_types() -> Vec<u8>;
+	}
+}
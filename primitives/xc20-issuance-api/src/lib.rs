@@ -0,0 +1,68 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API allowing to reconcile the issuance of foreign assets (XC-20s) with the amounts
+//! minted and burned through XCM.
+//!
+//! A drift between the expected issuance and the `pallet_assets` total issuance is a hint of an
+//! accounting bug in an asset transactor, or of a reorg on the reserve chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// Issuance figures of a single foreign asset.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct Xc20IssuanceReport<Balance> {
+	/// Issuance of the asset when the tracking started.
+	pub baseline: Balance,
+	/// Cumulative amount minted by XCM deposits since the tracking started.
+	pub xcm_minted: Balance,
+	/// Cumulative amount burned by XCM withdrawals since the tracking started.
+	pub xcm_burned: Balance,
+	/// Issuance expected from the figures above (`baseline + xcm_minted - xcm_burned`).
+	pub expected_issuance: Balance,
+	/// Total issuance reported by `pallet_assets`.
+	pub total_issuance: Balance,
+}
+
+impl<Balance: PartialEq> Xc20IssuanceReport<Balance> {
+	/// Whether the total issuance differs from the issuance expected from XCM flows.
+	pub fn is_drifting(&self) -> bool {
+		self.expected_issuance != self.total_issuance
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait Xc20IssuanceApi<AssetId, Balance>
+	where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Returns the issuance report of the given foreign asset, or `None` if the asset was
+		/// never moved through XCM since the tracking started.
+		fn issuance_report(asset_id: AssetId) -> Option<Xc20IssuanceReport<Balance>>;
+
+		/// Returns the reports of all tracked foreign assets whose total issuance does not match
+		/// the expected issuance.
+		fn drifting_assets() -> Vec<(AssetId, Xc20IssuanceReport<Balance>)>;
+	}
+}
@@ -0,0 +1,69 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API decoding the XCM related events of a block, for the nodes to stream them to
+//! bridges and exchanges watching cross-chain transfers.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256};
+use sp_std::vec::Vec;
+use xcm::latest::MultiLocation;
+
+/// Whether a message was received or sent by this chain.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub enum XcmEventDirection {
+	Inbound,
+	Outbound,
+}
+
+/// Fungible asset transferred by a message.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct XcmAssetTransfer {
+	/// Location of the asset, relative to this chain.
+	pub location: MultiLocation,
+	/// Address of the ERC-20 interface of the asset on this chain, if it has one.
+	pub erc20_address: Option<H160>,
+	pub amount: u128,
+}
+
+/// An XCM message processed or sent in a block.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct XcmEvent {
+	pub direction: XcmEventDirection,
+	/// Hash of the message, when known.
+	pub message_hash: Option<H256>,
+	/// Origin of the message, when known.
+	pub origin: Option<MultiLocation>,
+	/// Destination of the message, when known.
+	pub destination: Option<MultiLocation>,
+	/// Assets deposited (inbound) or sent (outbound) by the message.
+	pub assets: Vec<XcmAssetTransfer>,
+	/// Whether the message was successfully executed (inbound) or sent (outbound).
+	pub success: bool,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait XcmEventsApi {
+		/// Returns the XCM messages processed or sent in this block, in order.
+		fn xcm_events() -> Vec<XcmEvent>;
+	}
+}
@@ -0,0 +1,93 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API allowing to price XCM execution on this chain.
+//!
+//! The API mirrors the `XcmPaymentApi` exposed by the Polkadot SDK, so that wallets and dry-run
+//! tooling can use the same calls (and the same SCALE encoding) against every chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use frame_support::weights::Weight;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+use xcm::{v3, VersionedXcm};
+
+/// A versioned `AssetId`, encoded the same way as the upstream `xcm::VersionedAssetId`.
+///
+/// Only the V3 variant is supported by the XCM version used by this runtime, we keep the
+/// upstream variant index so that clients can share the same types.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub enum VersionedAssetId {
+	#[codec(index = 3)]
+	V3(v3::AssetId),
+}
+
+impl From<v3::AssetId> for VersionedAssetId {
+	fn from(id: v3::AssetId) -> Self {
+		VersionedAssetId::V3(id)
+	}
+}
+
+impl TryFrom<VersionedAssetId> for v3::AssetId {
+	type Error = ();
+	fn try_from(id: VersionedAssetId) -> Result<Self, ()> {
+		match id {
+			VersionedAssetId::V3(id) => Ok(id),
+		}
+	}
+}
+
+/// An error that can be returned by the `XcmPaymentApi` calls.
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub enum Error {
+	/// An API part is unsupported.
+	#[codec(index = 0)]
+	Unimplemented,
+	/// Converting a versioned data structure from one version to another failed.
+	#[codec(index = 1)]
+	VersionedConversionFailed,
+	/// XCM message weight calculation failed.
+	#[codec(index = 2)]
+	WeightNotComputable,
+	/// XCM version not able to be handled.
+	#[codec(index = 3)]
+	UnhandledXcmVersion,
+	/// The given asset is not handled as a fee asset.
+	#[codec(index = 4)]
+	AssetNotFound,
+	/// Destination is known to be unroutable.
+	#[codec(index = 5)]
+	Unroutable,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait XcmPaymentApi {
+		/// Returns a list of acceptable payment assets, encoded with the given XCM version.
+		fn query_acceptable_payment_assets(xcm_version: u32)
+			-> Result<Vec<VersionedAssetId>, Error>;
+
+		/// Returns the weight needed to execute the given XCM message on this chain.
+		fn query_xcm_weight(message: VersionedXcm<()>) -> Result<Weight, Error>;
+
+		/// Converts a weight into a fee for the specified asset.
+		fn query_weight_to_asset_fee(weight: Weight, asset: VersionedAssetId) -> Result<u128, Error>;
+	}
+}
@@ -0,0 +1,40 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API allowing to estimate the fees of the remote transacts made through the
+//! xcm-transactor pallet, from the transact info and fee per second stored on chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use frame_support::weights::Weight;
+use sp_runtime::DispatchError;
+use xcm::VersionedMultiLocation;
+
+sp_api::decl_runtime_apis! {
+	pub trait XcmTransactorApi {
+		/// Returns the amount of `fee_location` charged for transacting `transact_weight` in
+		/// `destination` through the derivative or sovereign account, including the transact
+		/// extra weight of the destination.
+		fn estimate_transact_fee(
+			destination: VersionedMultiLocation,
+			transact_weight: Weight,
+			fee_location: VersionedMultiLocation,
+		) -> Result<u128, DispatchError>;
+	}
+}
@@ -17,7 +17,9 @@
 // We need to know how to charge for incoming assets
 // We assume AssetIdInfoGetter is implemented and is capable of getting how much units we should
 // charge for a given asset
-// This takes the first fungible asset, and takes whatever UnitPerSecondGetter establishes
+// This takes the most preferred fungible asset according to the fee asset preference of
+// AssetIdInfoGetter (or the first fungible asset if none of the preferred ones is present),
+// and takes whatever UnitPerSecondGetter establishes
 // UnitsToWeightRatio trait, which needs to be implemented by AssetIdInfoGetter
 
 use frame_support::{
@@ -26,7 +28,7 @@ use frame_support::{
 	weights::constants::WEIGHT_REF_TIME_PER_SECOND,
 };
 use sp_runtime::traits::Zero;
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 use xcm::latest::{
 	AssetId as xcmAssetId, Error as XcmError, Fungibility, MultiAsset, MultiLocation,
 };
@@ -35,7 +37,7 @@ use xcm_builder::TakeRevenue;
 use xcm_executor::traits::{MatchesFungibles, WeightTrader};
 
 pub struct FirstAssetTrader<
-	AssetType: From<MultiLocation> + Clone,
+	AssetType: From<MultiLocation> + Clone + PartialEq,
 	AssetIdInfoGetter: UnitsToWeightRatio<AssetType>,
 	R: TakeRevenue,
 >(
@@ -44,7 +46,7 @@ pub struct FirstAssetTrader<
 	PhantomData<(AssetType, AssetIdInfoGetter, R)>,
 );
 impl<
-		AssetType: From<MultiLocation> + Clone,
+		AssetType: From<MultiLocation> + Clone + PartialEq,
 		AssetIdInfoGetter: UnitsToWeightRatio<AssetType>,
 		R: TakeRevenue,
 	> WeightTrader for FirstAssetTrader<AssetType, AssetIdInfoGetter, R>
@@ -64,14 +66,24 @@ impl<
 		}
 
 		assert_eq!(self.0, Weight::zero());
-		let first_asset = payment
-			.clone()
-			.fungible_assets_iter()
-			.next()
+		let fungible_assets: Vec<MultiAsset> = payment.fungible_assets_iter().collect();
+
+		// Pick the most preferred supported asset present in the payment. If there is none,
+		// we are only going to check the first asset. This should be sufficient for simple
+		// token transfers.
+		let first_asset = AssetIdInfoGetter::fee_asset_preference()
+			.into_iter()
+			.filter(|preferred| AssetIdInfoGetter::payment_is_supported(preferred.clone()))
+			.find_map(|preferred| {
+				fungible_assets.iter().find(|asset| match &asset.id {
+					xcmAssetId::Concrete(id) => AssetType::from(id.clone()) == preferred,
+					_ => false,
+				})
+			})
+			.or_else(|| fungible_assets.first())
+			.cloned()
 			.ok_or(XcmError::TooExpensive)?;
 
-		// We are only going to check first asset for now. This should be sufficient for simple token
-		// transfers. We will see later if we change this.
 		match (first_asset.id, first_asset.fun) {
 			(xcmAssetId::Concrete(id), Fungibility::Fungible(_)) => {
 				let asset_type: AssetType = id.clone().into();
@@ -139,7 +151,7 @@ impl<
 
 /// Deal with spent fees, deposit them as dictated by R
 impl<
-		AssetType: From<MultiLocation> + Clone,
+		AssetType: From<MultiLocation> + Clone + PartialEq,
 		AssetIdInfoGetter: UnitsToWeightRatio<AssetType>,
 		R: TakeRevenue,
 	> Drop for FirstAssetTrader<AssetType, AssetIdInfoGetter, R>
@@ -187,6 +199,11 @@ pub trait UnitsToWeightRatio<AssetType> {
 	fn payment_is_supported(asset_type: AssetType) -> bool;
 	// Get units per second from asset type
 	fn get_units_per_second(asset_type: AssetType) -> Option<u128>;
+	// Ordered list of the asset types to pay fees with when several are available, the most
+	// preferred first
+	fn fee_asset_preference() -> Vec<AssetType> {
+		Vec::new()
+	}
 	#[cfg(feature = "runtime-benchmarks")]
 	fn set_units_per_second(_asset_type: AssetType, _fee_per_second: u128) {}
 }
@@ -213,6 +230,33 @@ mod test {
 		}
 	}
 
+	const PREFERRED_ID: AssetId = AssetId::Concrete(MultiLocation {
+		parents: 1u8,
+		interior: Junctions::X1(Junction::Parachain(1001)),
+	});
+
+	struct PreferParachain1001;
+	impl UnitsToWeightRatio<MultiLocation> for PreferParachain1001 {
+		fn payment_is_supported(_asset_type: MultiLocation) -> bool {
+			true
+		}
+		fn get_units_per_second(_asset_type: MultiLocation) -> Option<u128> {
+			Some(WEIGHT_REF_TIME_PER_SECOND as u128)
+		}
+		fn fee_asset_preference() -> Vec<MultiLocation> {
+			vec![
+				MultiLocation {
+					parents: 1u8,
+					interior: Junctions::X1(Junction::Parachain(2000)),
+				},
+				MultiLocation {
+					parents: 1u8,
+					interior: Junctions::X1(Junction::Parachain(1001)),
+				},
+			]
+		}
+	}
+
 	#[test]
 	fn test_buy_weight_accounts_weight_properly() {
 		let amount = 1000u128;
@@ -421,4 +465,36 @@ mod test {
 		);
 		assert_eq!(trader.0, 500u64.into()); // still thinks we have unreturned weight
 	}
+
+	#[test]
+	fn buy_weight_follows_fee_asset_preference() {
+		let mut payment = Assets::new();
+		payment.subsume(MultiAsset {
+			id: ARBITRARY_ID,
+			fun: Fungibility::Fungible(1000u128),
+		});
+		payment.subsume(MultiAsset {
+			id: PREFERRED_ID,
+			fun: Fungibility::Fungible(1000u128),
+		});
+
+		// The first asset is used when there is no preference
+		let mut trader: FirstAssetTrader<MultiLocation, (), ()> = FirstAssetTrader::new();
+		trader
+			.buy_weight(100u64.into(), payment.clone())
+			.expect("can buy weight once");
+		assert_eq!(trader.1.clone().unwrap().0, MultiLocation::here());
+
+		// Otherwise the most preferred asset present in the payment is used
+		let mut trader: FirstAssetTrader<MultiLocation, PreferParachain1001, ()> =
+			FirstAssetTrader::new();
+		let unused = trader
+			.buy_weight(100u64.into(), payment.clone())
+			.expect("can buy weight once");
+		assert_eq!(
+			trader.1.clone().unwrap().0,
+			MultiLocation::new(1, Junctions::X1(Junction::Parachain(1001)))
+		);
+		assert_eq!(unused.fungible.get(&PREFERRED_ID), Some(&900u128));
+	}
 }
@@ -568,6 +568,488 @@ macro_rules! impl_runtime_apis_plus_common {
 				}
 			}
 
+			impl xcm_fee_payment_runtime_api::XcmPaymentApi<Block> for Runtime {
+				fn query_acceptable_payment_assets(
+					xcm_version: xcm::Version
+				) -> Result<
+					Vec<xcm_fee_payment_runtime_api::VersionedAssetId>,
+					xcm_fee_payment_runtime_api::Error
+				> {
+					use xcm_fee_payment_runtime_api::Error as XcmPaymentApiError;
+
+					if xcm_version != 3 {
+						return Err(XcmPaymentApiError::UnhandledXcmVersion);
+					}
+
+					// The native token is always accepted, followed by every foreign asset
+					// that has been configured as a fee payment asset in the asset manager.
+					let mut assets: Vec<xcm_fee_payment_runtime_api::VersionedAssetId> = vec![
+						xcm::v3::AssetId::Concrete(xcm_config::SelfReserve::get()).into()
+					];
+					assets.extend(
+						pallet_asset_manager::SupportedFeePaymentAssets::<Runtime>::get()
+							.into_iter()
+							.filter_map(|asset_type| {
+								let location: Option<xcm::v3::MultiLocation> = asset_type.into();
+								location.map(|location| xcm::v3::AssetId::Concrete(location).into())
+							})
+					);
+
+					Ok(assets)
+				}
+
+				fn query_xcm_weight(
+					message: xcm::VersionedXcm<()>
+				) -> Result<Weight, xcm_fee_payment_runtime_api::Error> {
+					use xcm_executor::traits::WeightBounds;
+					use xcm_fee_payment_runtime_api::Error as XcmPaymentApiError;
+
+					let message = xcm::v3::Xcm::<()>::try_from(message)
+						.map_err(|_| XcmPaymentApiError::VersionedConversionFailed)?;
+					let mut message: xcm::v3::Xcm<RuntimeCall> = message.into();
+
+					xcm_config::XcmWeigher::weight(&mut message)
+						.map_err(|_| XcmPaymentApiError::WeightNotComputable)
+				}
+
+				fn query_weight_to_asset_fee(
+					weight: Weight,
+					asset: xcm_fee_payment_runtime_api::VersionedAssetId
+				) -> Result<u128, xcm_fee_payment_runtime_api::Error> {
+					use frame_support::weights::{
+						constants::WEIGHT_REF_TIME_PER_SECOND, WeightToFee as _
+					};
+					use xcm_fee_payment_runtime_api::Error as XcmPaymentApiError;
+					use xcm_primitives::UnitsToWeightRatio;
+
+					let asset: xcm::v3::AssetId = asset
+						.try_into()
+						.map_err(|_| XcmPaymentApiError::VersionedConversionFailed)?;
+					let location = match asset {
+						xcm::v3::AssetId::Concrete(location) => location,
+						_ => return Err(XcmPaymentApiError::AssetNotFound),
+					};
+
+					// The native token is charged the same way as local transactions.
+					if location == xcm_config::SelfReserve::get() {
+						return Ok(
+							<Runtime as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(
+								&weight
+							)
+						);
+					}
+
+					let asset_type = xcm_config::AssetType::from(location);
+					if !xcm_config::ForeignAssetUnitsPerSecond::payment_is_supported(
+						asset_type.clone()
+					) {
+						return Err(XcmPaymentApiError::AssetNotFound);
+					}
+					let units_per_second =
+						xcm_config::ForeignAssetUnitsPerSecond::get_units_per_second(asset_type)
+							.ok_or(XcmPaymentApiError::AssetNotFound)?;
+
+					// Must match the computation done by the `FirstAssetTrader`.
+					Ok(units_per_second.saturating_mul(weight.ref_time() as u128)
+						/ (WEIGHT_REF_TIME_PER_SECOND as u128))
+				}
+			}
+
+			impl xcm_transactor_runtime_api::XcmTransactorApi<Block> for Runtime {
+				fn estimate_transact_fee(
+					destination: xcm::VersionedMultiLocation,
+					transact_weight: Weight,
+					fee_location: xcm::VersionedMultiLocation,
+				) -> Result<u128, sp_runtime::DispatchError> {
+					use xcm::latest::MultiLocation;
+
+					let destination = MultiLocation::try_from(destination)
+						.map_err(|()| pallet_xcm_transactor::Error::<Runtime>::BadVersion)?;
+					let fee_location = MultiLocation::try_from(fee_location)
+						.map_err(|()| pallet_xcm_transactor::Error::<Runtime>::BadVersion)?;
+					XcmTransactor::estimate_transact_fee(destination, transact_weight, fee_location)
+				}
+			}
+
+			impl assets_runtime_api::AssetsApi<Block, AssetId> for Runtime {
+				fn list_foreign_assets() -> Vec<assets_runtime_api::ForeignAsset<AssetId>> {
+					use frame_support::traits::fungibles::metadata::Inspect;
+					use pallet_evm_precompileset_assets_erc20::AccountIdAssetIdConversion;
+
+					pallet_asset_manager::AssetIdType::<Runtime>::iter()
+						.filter_map(|(asset_id, asset_type)| {
+							let location: Option<xcm::v3::MultiLocation> = asset_type.into();
+							let address: H160 = Runtime::asset_id_to_account(
+								FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX,
+								asset_id,
+							)
+							.into();
+
+							Some(assets_runtime_api::ForeignAsset {
+								asset_id,
+								location: location?,
+								address,
+								metadata: assets_runtime_api::AssetMetadata {
+									name: <Assets as Inspect<AccountId>>::name(asset_id),
+									symbol: <Assets as Inspect<AccountId>>::symbol(asset_id),
+									decimals: <Assets as Inspect<AccountId>>::decimals(asset_id),
+								},
+							})
+						})
+						.collect()
+				}
+			}
+
+			impl eth_call_runtime_api::EthCallApi<Block> for Runtime {
+				fn call_with_block_overrides(
+					from: H160,
+					to: H160,
+					data: Vec<u8>,
+					value: U256,
+					gas_limit: Option<U256>,
+					max_fee_per_gas: Option<U256>,
+					max_priority_fee_per_gas: Option<U256>,
+					nonce: Option<U256>,
+					access_list: Option<Vec<(H160, Vec<H256>)>>,
+					block_overrides: eth_call_runtime_api::BlockOverrides,
+				) -> Result<pallet_evm::CallInfo, sp_runtime::DispatchError> {
+					use frame_support::traits::Get;
+					use fp_rpc::runtime_decl_for_ethereum_runtime_rpc_api::EthereumRuntimeRPCApi;
+					use sp_runtime::{traits::One, FixedPointNumber};
+
+					if let Some(number) = block_overrides.number {
+						System::set_block_number(number.min(u32::MAX.into()).low_u32());
+					}
+					if let Some(time) = block_overrides.time {
+						pallet_timestamp::Now::<Runtime>::put(time.saturating_mul(1_000));
+					}
+					if let Some(base_fee) = block_overrides.base_fee {
+						// The base fee is the fee multiplier applied to a constant gas price, so the
+						// multiplier matching the requested base fee is the ratio between both.
+						type NextFeeMultiplier = pallet_transaction_payment::NextFeeMultiplier<Runtime>;
+						NextFeeMultiplier::put(pallet_transaction_payment::Multiplier::one());
+						let (unit_gas_price, _) =
+							<Runtime as pallet_evm::Config>::FeeCalculator::min_gas_price();
+						NextFeeMultiplier::put(
+							pallet_transaction_payment::Multiplier::saturating_from_rational(
+								base_fee.min(u128::MAX.into()).low_u128(),
+								unit_gas_price.low_u128().max(1),
+							),
+						);
+					}
+
+					let gas_limit = gas_limit
+						.unwrap_or_else(<Runtime as pallet_evm::Config>::BlockGasLimit::get);
+
+					<Runtime as EthereumRuntimeRPCApi<Block>>::call(
+						from,
+						to,
+						data,
+						value,
+						gas_limit,
+						max_fee_per_gas,
+						max_priority_fee_per_gas,
+						nonce,
+						false,
+						access_list,
+					)
+				}
+			}
+
+			impl orbiters_runtime_api::OrbitersApi<
+				Block,
+				AccountId,
+				pallet_parachain_staking::RoundIndex,
+				Balance,
+			> for Runtime {
+				fn orbiter_audit(
+					orbiter: AccountId,
+				) -> Option<orbiters_runtime_api::OrbiterAuditSummary<
+					AccountId,
+					pallet_parachain_staking::RoundIndex,
+					Balance,
+				>> {
+					MoonbeamOrbiters::orbiter_audit(orbiter)
+				}
+
+				fn orbiters_audit() -> Vec<(
+					AccountId,
+					orbiters_runtime_api::OrbiterAuditSummary<
+						AccountId,
+						pallet_parachain_staking::RoundIndex,
+						Balance,
+					>,
+				)> {
+					MoonbeamOrbiters::orbiters_audit()
+				}
+			}
+
+			impl xc20_issuance_runtime_api::Xc20IssuanceApi<Block, AssetId, Balance> for Runtime {
+				fn issuance_report(
+					asset_id: AssetId,
+				) -> Option<xc20_issuance_runtime_api::Xc20IssuanceReport<Balance>> {
+					Xc20Issuance::issuance_report(asset_id)
+				}
+
+				fn drifting_assets(
+				) -> Vec<(AssetId, xc20_issuance_runtime_api::Xc20IssuanceReport<Balance>)> {
+					Xc20Issuance::drifting_assets()
+				}
+			}
+
+			impl custody_audit_runtime_api::CustodyAuditApi<Block, AssetId, Balance> for Runtime {
+				fn custody_report(
+					asset_id: AssetId,
+				) -> Option<custody_audit_runtime_api::CustodyReport<Balance>> {
+					use frame_support::traits::fungibles::Inspect;
+
+					let location: Option<xcm::v3::MultiLocation> =
+						pallet_asset_manager::AssetIdType::<Runtime>::get(asset_id)?.into();
+
+					Some(custody_audit_runtime_api::CustodyReport {
+						reserve: location?.chain_location(),
+						custodied: <Assets as Inspect<AccountId>>::total_issuance(asset_id),
+						bridged_in: Xc20Issuance::issuance_record(asset_id)
+							.map(|record| record.expected_issuance()),
+					})
+				}
+
+				fn custody_reports(
+				) -> Vec<(AssetId, custody_audit_runtime_api::CustodyReport<Balance>)> {
+					pallet_asset_manager::AssetIdType::<Runtime>::iter_keys()
+						.filter_map(|asset_id| {
+							Some((asset_id, Self::custody_report(asset_id)?))
+						})
+						.collect()
+				}
+			}
+
+			impl evm_hibernation_runtime_api::EvmHibernationApi<Block, BlockNumber> for Runtime {
+				fn hibernated_contract(
+					contract: H160,
+				) -> Option<evm_hibernation_runtime_api::HibernatedContract<BlockNumber>> {
+					EvmHibernation::hibernated_contract(contract)
+				}
+
+				fn contract_state(contract: H160) -> evm_hibernation_runtime_api::ContractState {
+					EvmHibernation::contract_state(contract)
+				}
+			}
+
+			impl fee_split_runtime_api::FeeSplitApi<Block> for Runtime {
+				fn fee_split() -> fee_split_runtime_api::FeeSplit {
+					FeeSplit::fee_split()
+				}
+			}
+
+			impl migrations_runtime_api::MigrationsApi<Block> for Runtime {
+				fn migrations_status() -> Vec<migrations_runtime_api::MigrationStatus> {
+					use frame_support::traits::Get;
+
+					moonbeam_runtime_common::migration_tracking::MigrationsStatus::<Runtime>::get()
+				}
+			}
+
+			impl account_info_runtime_api::AccountInfoApi<Block, Index, Balance> for Runtime {
+				fn account_meta(
+					address: H160,
+				) -> account_info_runtime_api::AccountMeta<Index, Balance> {
+					use account_info_runtime_api::{
+						AccountMeta, BalanceLockInfo, BalanceReserveInfo, LockReasons,
+					};
+					use frame_support::traits::{
+						fungible::Inspect,
+						tokens::{Fortitude, Preservation},
+					};
+
+					let who = AccountId::from(address);
+					let info = System::account(&who);
+
+					AccountMeta {
+						nonce: info.nonce,
+						consumers: info.consumers,
+						providers: info.providers,
+						sufficients: info.sufficients,
+						free: info.data.free,
+						reserved: info.data.reserved,
+						frozen: info.data.frozen,
+						transferable: <Balances as Inspect<AccountId>>::reducible_balance(
+							&who,
+							Preservation::Expendable,
+							Fortitude::Polite,
+						),
+						locks: Balances::locks(&who)
+							.into_iter()
+							.map(|lock| BalanceLockInfo {
+								id: lock.id,
+								amount: lock.amount,
+								reasons: match lock.reasons {
+									pallet_balances::Reasons::Fee => LockReasons::Fee,
+									pallet_balances::Reasons::Misc => LockReasons::Misc,
+									pallet_balances::Reasons::All => LockReasons::All,
+								},
+							})
+							.collect(),
+						reserves: Balances::reserves(&who)
+							.into_iter()
+							.map(|reserve| BalanceReserveInfo {
+								id: reserve.id.encode(),
+								amount: reserve.amount,
+							})
+							.collect(),
+						holds: pallet_balances::Holds::<Runtime>::get(&who)
+							.into_iter()
+							.map(|hold| BalanceReserveInfo {
+								id: hold.id.encode(),
+								amount: hold.amount,
+							})
+							.collect(),
+					}
+				}
+			}
+
+			impl transaction_types_runtime_api::TransactionTypesApi<Block> for Runtime {
+				fn supported_transaction_types() -> Vec<u8> {
+					// The variants of `pallet_ethereum::Transaction`: legacy, EIP-2930 and
+					// EIP-1559 transactions.
+					vec![transaction_types_runtime_api::LEGACY_TRANSACTION_TYPE, 1, 2]
+				}
+			}
+
+			impl xcm_events_runtime_api::XcmEventsApi<Block> for Runtime {
+				fn xcm_events() -> Vec<xcm_events_runtime_api::XcmEvent> {
+					use pallet_evm_precompileset_assets_erc20::AccountIdAssetIdConversion;
+					use xcm::latest::prelude::*;
+					use xcm_events_runtime_api::{XcmAssetTransfer, XcmEvent, XcmEventDirection};
+
+					type ForeignAssetId = <Runtime as pallet_assets::Config>::AssetId;
+					let foreign_asset_address = |asset_id: ForeignAssetId| -> H160 {
+						Runtime::asset_id_to_account(FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX, asset_id)
+							.into()
+					};
+					let asset_transfer = |asset: MultiAsset| -> Option<XcmAssetTransfer> {
+						let (Concrete(location), Fungible(amount)) = (asset.id, asset.fun) else {
+							return None;
+						};
+						let erc20_address = if location == xcm_config::SelfReserve::get() {
+							Some(H160::from_low_u64_be(2050))
+						} else {
+							AssetManager::asset_type_id(xcm_config::AssetType::from(location))
+								.map(foreign_asset_address)
+						};
+						Some(XcmAssetTransfer { location, erc20_address, amount })
+					};
+
+					let mut xcm_events = Vec::new();
+					// Foreign assets issued since the last processed inbound message of the phase,
+					// attributed to the next one.
+					let mut phase = None;
+					let mut deposits = Vec::new();
+					// Hash of the last message sent through the XCMP queue of the phase.
+					let mut sent_message_hash = None;
+
+					for record in System::read_events_no_consensus() {
+						if phase.as_ref() != Some(&record.phase) {
+							phase = Some(record.phase.clone());
+							deposits.clear();
+							sent_message_hash = None;
+						}
+
+						match record.event {
+							RuntimeEvent::Assets(pallet_assets::Event::Issued {
+								asset_id,
+								amount,
+								..
+							}) => {
+								let location: Option<MultiLocation> =
+									AssetManager::asset_id_type(asset_id).and_then(Into::into);
+								if let Some(location) = location {
+									deposits.push(XcmAssetTransfer {
+										location,
+										erc20_address: Some(foreign_asset_address(asset_id)),
+										amount,
+									});
+								}
+							}
+							RuntimeEvent::XcmpQueue(cumulus_pallet_xcmp_queue::Event::Success {
+								message_hash,
+								..
+							}) => xcm_events.push(XcmEvent {
+								direction: XcmEventDirection::Inbound,
+								message_hash: message_hash.map(H256),
+								origin: None,
+								destination: None,
+								assets: sp_std::mem::take(&mut deposits),
+								success: true,
+							}),
+							RuntimeEvent::XcmpQueue(cumulus_pallet_xcmp_queue::Event::Fail {
+								message_hash,
+								..
+							}) => xcm_events.push(XcmEvent {
+								direction: XcmEventDirection::Inbound,
+								message_hash: message_hash.map(H256),
+								origin: None,
+								destination: None,
+								assets: sp_std::mem::take(&mut deposits),
+								success: false,
+							}),
+							RuntimeEvent::DmpQueue(
+								cumulus_pallet_dmp_queue::Event::ExecutedDownward {
+									message_id,
+									outcome,
+								},
+							) => xcm_events.push(XcmEvent {
+								direction: XcmEventDirection::Inbound,
+								message_hash: Some(H256(message_id)),
+								origin: Some(MultiLocation::parent()),
+								destination: None,
+								assets: sp_std::mem::take(&mut deposits),
+								success: matches!(outcome, Outcome::Complete(_)),
+							}),
+							RuntimeEvent::XcmpQueue(
+								cumulus_pallet_xcmp_queue::Event::XcmpMessageSent { message_hash },
+							) => sent_message_hash = message_hash.map(H256),
+							RuntimeEvent::XTokens(orml_xtokens::Event::TransferredMultiAssets {
+								sender,
+								assets,
+								dest,
+								..
+							}) => xcm_events.push(XcmEvent {
+								direction: XcmEventDirection::Outbound,
+								message_hash: sent_message_hash.take(),
+								origin: Some(MultiLocation::new(
+									0,
+									X1(AccountKey20 {
+										network: None,
+										key: H160::from(sender).0,
+									}),
+								)),
+								destination: Some(dest),
+								assets: assets
+									.into_inner()
+									.into_iter()
+									.filter_map(asset_transfer)
+									.collect(),
+								success: true,
+							}),
+							RuntimeEvent::PolkadotXcm(pallet_xcm::Event::Sent(origin, destination, _)) => {
+								xcm_events.push(XcmEvent {
+									direction: XcmEventDirection::Outbound,
+									message_hash: sent_message_hash.take(),
+									origin: Some(origin),
+									destination: Some(destination),
+									assets: Vec::new(),
+									success: true,
+								})
+							}
+							_ => (),
+						}
+					}
+
+					xcm_events
+				}
+			}
+
 			#[cfg(feature = "runtime-benchmarks")]
 			impl frame_benchmarking::Benchmark<Block> for Runtime {
 
@@ -579,6 +1061,7 @@ macro_rules! impl_runtime_apis_plus_common {
 					use moonbeam_xcm_benchmarks::generic::benchmarking as MoonbeamXcmBenchmarks;
 					use frame_support::traits::StorageInfoTrait;
 					use MoonbeamXcmBenchmarks::XcmGenericBenchmarks as MoonbeamXcmGenericBench;
+					use moonbeam_xcm_benchmarks::erc20::Pallet as MoonbeamXcmErc20Bench;
 
 					let mut list = Vec::<BenchmarkList>::new();
 					list_benchmarks!(list, extra);
@@ -603,6 +1086,7 @@ macro_rules! impl_runtime_apis_plus_common {
 
 					impl moonbeam_xcm_benchmarks::Config for Runtime {}
 					impl moonbeam_xcm_benchmarks::generic::Config for Runtime {}
+					impl moonbeam_xcm_benchmarks::erc20::Config for Runtime {}
 
 					use pallet_asset_manager::Config as PalletAssetManagerConfig;
 					impl pallet_xcm_benchmarks::Config for Runtime {
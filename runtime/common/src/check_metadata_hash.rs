@@ -0,0 +1,125 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The `CheckMetadataHash` signed extension, as defined by
+//! [RFC-0078](https://polkadot-fellows.github.io/RFCs/approved/0078-merkleized-metadata.html).
+//!
+//! Offline signers which don't store the metadata of the chain (e.g. the Polkadot generic Ledger
+//! app) are given a shortened metadata along with the transaction to sign, and sign its hash. When
+//! the signer enables the check, the metadata hash becomes part of the signed payload, so a
+//! signature over a forged metadata is rejected by the runtime.
+//!
+//! The metadata hash is generated when building the runtime with the `metadata-hash` feature,
+//! see `moonbeam-metadata-hash`. Without it, enabling the check makes transactions invalid.
+
+use frame_support::{
+	pallet_prelude::TransactionValidityError, CloneNoBound, EqNoBound, PartialEqNoBound,
+	RuntimeDebugNoBound,
+};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::traits::{DispatchInfoOf, SignedExtension};
+use sp_std::marker::PhantomData;
+
+/// Hash of the metadata of the runtime, set at build time.
+pub const RUNTIME_METADATA_HASH: Option<[u8; 32]> = match option_env!("RUNTIME_METADATA_HASH") {
+	Some(hash) => Some(decode_hex(hash)),
+	None => None,
+};
+
+const fn decode_hex(hex: &str) -> [u8; 32] {
+	let hex = hex.as_bytes();
+	assert!(
+		hex.len() == 66 && hex[0] == b'0' && hex[1] == b'x',
+		"RUNTIME_METADATA_HASH must be a 0x prefixed 32 bytes hex string"
+	);
+
+	let mut hash = [0u8; 32];
+	let mut i = 0;
+	while i < 32 {
+		hash[i] = (hex_digit(hex[2 + 2 * i]) << 4) | hex_digit(hex[3 + 2 * i]);
+		i += 1;
+	}
+	hash
+}
+
+const fn hex_digit(c: u8) -> u8 {
+	match c {
+		b'0'..=b'9' => c - b'0',
+		b'a'..=b'f' => c - b'a' + 10,
+		b'A'..=b'F' => c - b'A' + 10,
+		_ => panic!("RUNTIME_METADATA_HASH must be a 0x prefixed 32 bytes hex string"),
+	}
+}
+
+/// Whether the signer included the metadata hash in the signed payload.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+enum Mode {
+	Disabled,
+	Enabled,
+}
+
+/// Check the metadata hash used by the signer, when enabled.
+#[derive(
+	CloneNoBound, EqNoBound, PartialEqNoBound, Encode, Decode, TypeInfo, RuntimeDebugNoBound,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckMetadataHash<T> {
+	_phantom: PhantomData<T>,
+	mode: Mode,
+}
+
+impl<T> CheckMetadataHash<T> {
+	/// Create the extension, `enable` being whether the metadata hash is part of the signed
+	/// payload.
+	pub fn new(enable: bool) -> Self {
+		Self {
+			_phantom: PhantomData,
+			mode: if enable {
+				Mode::Enabled
+			} else {
+				Mode::Disabled
+			},
+		}
+	}
+}
+
+impl<T: frame_system::Config + Send + Sync> SignedExtension for CheckMetadataHash<T> {
+	const IDENTIFIER: &'static str = "CheckMetadataHash";
+	type AccountId = T::AccountId;
+	type Call = T::RuntimeCall;
+	type AdditionalSigned = Option<[u8; 32]>;
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		// When the runtime doesn't know its metadata hash, a signature including one doesn't
+		// verify.
+		Ok(match self.mode {
+			Mode::Disabled => None,
+			Mode::Enabled => RUNTIME_METADATA_HASH,
+		})
+	}
+
+	fn pre_dispatch(
+		self,
+		_who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Ok(())
+	}
+}
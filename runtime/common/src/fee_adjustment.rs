@@ -0,0 +1,144 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Fee multiplier update driven by the fullness of the normal dispatch class in both weight
+//! dimensions.
+//!
+//! `TargetedFeeAdjustment` only accounts for the ref time consumed by a block, so blocks filling
+//! the PoV with storage-heavy extrinsics leave the fees, and with them the EVM base fee derived
+//! from the multiplier, unchanged. The fullness of a block is here the highest of its ref time
+//! and proof size ratios, the multiplier being then updated with the same formula.
+
+use frame_support::{dispatch::DispatchClass, traits::Get, weights::Weight};
+use pallet_transaction_payment::{Multiplier, MultiplierUpdate};
+use sp_runtime::{
+	traits::{Convert, Saturating},
+	FixedPointNumber, Perquintill,
+};
+use sp_std::marker::PhantomData;
+
+/// `TargetedFeeAdjustment` using the most consumed weight dimension of the normal dispatch class,
+/// `S` being the target block fullness, `V` the adjustment variable, `M` the minimum multiplier
+/// and `X` the maximum multiplier.
+pub struct BlockFullnessFeeAdjustment<T, S, V, M, X>(PhantomData<(T, S, V, M, X)>);
+
+impl<T, S, V, M, X> BlockFullnessFeeAdjustment<T, S, V, M, X>
+where
+	T: frame_system::Config,
+{
+	/// Weight consumed by the normal dispatch class of the current block and its maximum, in the
+	/// dimension (ref time or proof size) filled the most.
+	pub fn normal_block_usage() -> (u64, u64) {
+		let weights = T::BlockWeights::get();
+		let normal_max_weight = weights
+			.get(DispatchClass::Normal)
+			.max_total
+			.unwrap_or(weights.max_block);
+		let normal_block_weight = frame_system::Pallet::<T>::block_weight()
+			.get(DispatchClass::Normal)
+			.min(normal_max_weight);
+
+		Self::most_used_dimension(normal_block_weight, normal_max_weight)
+	}
+
+	fn most_used_dimension(used: Weight, max: Weight) -> (u64, u64) {
+		let ref_time = (used.ref_time(), max.ref_time());
+		let proof_size = (used.proof_size(), max.proof_size());
+		if proof_size.1 == 0 {
+			return ref_time;
+		}
+
+		// Compare used_ref_time / max_ref_time with used_proof_size / max_proof_size.
+		if (proof_size.0 as u128) * (ref_time.1 as u128)
+			> (ref_time.0 as u128) * (proof_size.1 as u128)
+		{
+			proof_size
+		} else {
+			ref_time
+		}
+	}
+}
+
+impl<T, S, V, M, X> Convert<Multiplier, Multiplier> for BlockFullnessFeeAdjustment<T, S, V, M, X>
+where
+	T: frame_system::Config,
+	S: Get<Perquintill>,
+	V: Get<Multiplier>,
+	M: Get<Multiplier>,
+	X: Get<Multiplier>,
+{
+	fn convert(previous: Multiplier) -> Multiplier {
+		let min_multiplier = M::get();
+		let max_multiplier = X::get();
+		let previous = previous.max(min_multiplier);
+
+		let (block_weight, max_weight) = Self::normal_block_usage();
+		let target_weight = (S::get() * max_weight) as u128;
+		let block_weight = block_weight as u128;
+		let v = V::get();
+
+		// determines if the first_term is positive
+		let positive = block_weight >= target_weight;
+		let diff_abs = block_weight.max(target_weight) - block_weight.min(target_weight);
+
+		let diff = Multiplier::saturating_from_rational(diff_abs, max_weight.max(1));
+		let diff_squared = diff.saturating_mul(diff);
+
+		let v_squared_2 = v.saturating_mul(v) / Multiplier::saturating_from_integer(2);
+
+		let first_term = v.saturating_mul(diff);
+		let second_term = v_squared_2.saturating_mul(diff_squared);
+
+		if positive {
+			let excess = first_term
+				.saturating_add(second_term)
+				.saturating_mul(previous);
+			previous
+				.saturating_add(excess)
+				.clamp(min_multiplier, max_multiplier)
+		} else {
+			// first_term > second_term as long as v * diff < 2.
+			let negative = first_term
+				.saturating_sub(second_term)
+				.saturating_mul(previous);
+			previous
+				.saturating_sub(negative)
+				.clamp(min_multiplier, max_multiplier)
+		}
+	}
+}
+
+impl<T, S, V, M, X> MultiplierUpdate for BlockFullnessFeeAdjustment<T, S, V, M, X>
+where
+	T: frame_system::Config,
+	S: Get<Perquintill>,
+	V: Get<Multiplier>,
+	M: Get<Multiplier>,
+	X: Get<Multiplier>,
+{
+	fn min() -> Multiplier {
+		M::get()
+	}
+	fn max() -> Multiplier {
+		X::get()
+	}
+	fn target() -> Perquintill {
+		S::get()
+	}
+	fn variability() -> Multiplier {
+		V::get()
+	}
+}
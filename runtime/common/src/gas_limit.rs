@@ -0,0 +1,63 @@
+// Copyright 2019-2023 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Block gas limit accounting for the proof size available to the transactions.
+//!
+//! The gas used by a transaction is the highest of its ref time and proof size costs, the later
+//! being its proof size multiplied by `GasLimitPovSizeRatio`. A gas limit derived from the ref
+//! time alone would let a block of storage-heavy transactions fit in ref time while overflowing
+//! the PoV, so the limit is capped by the proof size of the normal dispatch class as well.
+
+use frame_support::{dispatch::DispatchClass, traits::Get};
+use sp_core::U256;
+use sp_std::marker::PhantomData;
+
+/// Smallest of `RefTimeGasLimit` and the gas equivalent of the proof size available to the
+/// normal dispatch class.
+pub struct PovAwareBlockGasLimit<Runtime, RefTimeGasLimit>(PhantomData<(Runtime, RefTimeGasLimit)>);
+
+impl<Runtime, RefTimeGasLimit> PovAwareBlockGasLimit<Runtime, RefTimeGasLimit>
+where
+	Runtime: frame_system::Config + pallet_evm::Config,
+{
+	/// Gas equivalent of the proof size available to the normal dispatch class.
+	pub fn proof_size_gas_limit() -> U256 {
+		let block_weights = <Runtime as frame_system::Config>::BlockWeights::get();
+		let max_proof_size = block_weights
+			.get(DispatchClass::Normal)
+			.max_total
+			.unwrap_or(block_weights.max_block)
+			.proof_size();
+
+		U256::from(max_proof_size)
+			.saturating_mul(<Runtime as pallet_evm::Config>::GasLimitPovSizeRatio::get().into())
+	}
+}
+
+impl<Runtime, RefTimeGasLimit> Get<U256> for PovAwareBlockGasLimit<Runtime, RefTimeGasLimit>
+where
+	Runtime: frame_system::Config + pallet_evm::Config,
+	RefTimeGasLimit: Get<U256>,
+{
+	fn get() -> U256 {
+		let proof_size_gas_limit = Self::proof_size_gas_limit();
+		// A ratio of 0 disables the proof size accounting.
+		if proof_size_gas_limit.is_zero() {
+			return RefTimeGasLimit::get();
+		}
+		RefTimeGasLimit::get().min(proof_size_gas_limit)
+	}
+}
@@ -0,0 +1,905 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Integration tests shared by every runtime.
+//!
+//! The macro is expanded from each runtime's `tests/integration_test.rs`, which must provide
+//! the usual `common` fixtures (`ExtBuilder`, `ALICE`, `origin_of`, ...) in the calling scope.
+//! Amounts are derived from the runtime configuration or expressed in the runtime native
+//! `unit`, so the same scenario runs unchanged against Moonbase, Moonriver and Moonbeam.
+
+#[macro_export]
+macro_rules! impl_runtime_integration_tests {
+	{ runtime: $runtime:ident, unit: $unit:ident $(,)? } => {
+		mod shared_governance {
+			use super::*;
+			use frame_support::{
+				assert_ok,
+				traits::{StorageInfo, StorageInfoTrait},
+			};
+			use pallet_collective::{
+				Call as CollectiveCall, Instance1, Instance2, Instance3, Instance4,
+			};
+			use $runtime::{
+				governance::custom_origins::Origin as CustomOrigin, AccountId, CouncilCollective,
+				OpenTechCommitteeCollective, OriginCaller, Runtime, RuntimeEvent, System,
+				TechCommitteeCollective, TreasuryCouncilCollective, Utility,
+			};
+
+			#[test]
+			fn test_collectives_storage_item_prefixes() {
+				for StorageInfo { pallet_name, .. } in
+					<CouncilCollective as StorageInfoTrait>::storage_info()
+				{
+					assert_eq!(pallet_name, b"CouncilCollective".to_vec());
+				}
+
+				for StorageInfo { pallet_name, .. } in
+					<TechCommitteeCollective as StorageInfoTrait>::storage_info()
+				{
+					assert_eq!(pallet_name, b"TechCommitteeCollective".to_vec());
+				}
+
+				for StorageInfo { pallet_name, .. } in
+					<TreasuryCouncilCollective as StorageInfoTrait>::storage_info()
+				{
+					assert_eq!(pallet_name, b"TreasuryCouncilCollective".to_vec());
+				}
+
+				for StorageInfo { pallet_name, .. } in
+					<OpenTechCommitteeCollective as StorageInfoTrait>::storage_info()
+				{
+					assert_eq!(pallet_name, b"OpenTechCommitteeCollective".to_vec());
+				}
+			}
+
+			#[test]
+			fn collective_set_members_root_origin_works() {
+				ExtBuilder::default().build().execute_with(|| {
+					// CouncilCollective
+					assert_ok!(CouncilCollective::set_members(
+						<Runtime as frame_system::Config>::RuntimeOrigin::root(),
+						vec![AccountId::from(ALICE), AccountId::from(BOB)],
+						Some(AccountId::from(ALICE)),
+						2
+					));
+					// TechCommitteeCollective
+					assert_ok!(TechCommitteeCollective::set_members(
+						<Runtime as frame_system::Config>::RuntimeOrigin::root(),
+						vec![AccountId::from(ALICE), AccountId::from(BOB)],
+						Some(AccountId::from(ALICE)),
+						2
+					));
+					// TreasuryCouncilCollective
+					assert_ok!(TreasuryCouncilCollective::set_members(
+						<Runtime as frame_system::Config>::RuntimeOrigin::root(),
+						vec![AccountId::from(ALICE), AccountId::from(BOB)],
+						Some(AccountId::from(ALICE)),
+						2
+					));
+					// OpenTechCommitteeCollective
+					assert_ok!(OpenTechCommitteeCollective::set_members(
+						<Runtime as frame_system::Config>::RuntimeOrigin::root(),
+						vec![AccountId::from(ALICE), AccountId::from(BOB)],
+						Some(AccountId::from(ALICE)),
+						2
+					));
+				});
+			}
+
+			#[test]
+			fn collective_set_members_general_admin_origin_works() {
+				ExtBuilder::default().build().execute_with(|| {
+					let root_caller = <Runtime as frame_system::Config>::RuntimeOrigin::root();
+					let alice = AccountId::from(ALICE);
+
+					// CouncilCollective
+					let _ = Utility::dispatch_as(
+						root_caller.clone(),
+						Box::new(OriginCaller::Origins(CustomOrigin::GeneralAdmin)),
+						Box::new(
+							CollectiveCall::<Runtime, Instance1>::set_members {
+								new_members: vec![alice, AccountId::from(BOB)],
+								prime: Some(alice),
+								old_count: 2,
+							}
+							.into(),
+						),
+					);
+					// TechCommitteeCollective
+					let _ = Utility::dispatch_as(
+						root_caller.clone(),
+						Box::new(OriginCaller::Origins(CustomOrigin::GeneralAdmin)),
+						Box::new(
+							CollectiveCall::<Runtime, Instance2>::set_members {
+								new_members: vec![alice, AccountId::from(BOB)],
+								prime: Some(alice),
+								old_count: 2,
+							}
+							.into(),
+						),
+					);
+					// TreasuryCouncilCollective
+					let _ = Utility::dispatch_as(
+						root_caller.clone(),
+						Box::new(OriginCaller::Origins(CustomOrigin::GeneralAdmin)),
+						Box::new(
+							CollectiveCall::<Runtime, Instance3>::set_members {
+								new_members: vec![alice, AccountId::from(BOB)],
+								prime: Some(alice),
+								old_count: 2,
+							}
+							.into(),
+						),
+					);
+					// OpenTechCommitteeCollective
+					let _ = Utility::dispatch_as(
+						root_caller,
+						Box::new(OriginCaller::Origins(CustomOrigin::GeneralAdmin)),
+						Box::new(
+							CollectiveCall::<Runtime, Instance4>::set_members {
+								new_members: vec![alice, AccountId::from(BOB)],
+								prime: Some(alice),
+								old_count: 2,
+							}
+							.into(),
+						),
+					);
+
+					assert_eq!(
+						System::events()
+							.into_iter()
+							.filter_map(|r| {
+								match r.event {
+									RuntimeEvent::Utility(
+										pallet_utility::Event::DispatchedAs { result }
+									) if result.is_ok() =>
+									{
+										Some(true)
+									}
+									_ => None,
+								}
+							})
+							.collect::<Vec<_>>()
+							.len(),
+						4
+					)
+				});
+			}
+
+			#[test]
+			fn collective_set_members_signed_origin_does_not_work() {
+				let alice = AccountId::from(ALICE);
+				ExtBuilder::default().build().execute_with(|| {
+					// CouncilCollective
+					assert!(CouncilCollective::set_members(
+						<Runtime as frame_system::Config>::RuntimeOrigin::signed(alice),
+						vec![alice, AccountId::from(BOB)],
+						Some(alice),
+						2
+					)
+					.is_err());
+					// TechCommitteeCollective
+					assert!(TechCommitteeCollective::set_members(
+						<Runtime as frame_system::Config>::RuntimeOrigin::signed(alice),
+						vec![AccountId::from(ALICE), AccountId::from(BOB)],
+						Some(AccountId::from(ALICE)),
+						2
+					)
+					.is_err());
+					// TreasuryCouncilCollective
+					assert!(TreasuryCouncilCollective::set_members(
+						<Runtime as frame_system::Config>::RuntimeOrigin::signed(alice),
+						vec![AccountId::from(ALICE), AccountId::from(BOB)],
+						Some(AccountId::from(ALICE)),
+						2
+					)
+					.is_err());
+					// OpenTechCommitteeCollective
+					assert!(OpenTechCommitteeCollective::set_members(
+						<Runtime as frame_system::Config>::RuntimeOrigin::signed(alice),
+						vec![AccountId::from(ALICE), AccountId::from(BOB)],
+						Some(AccountId::from(ALICE)),
+						2
+					)
+					.is_err());
+				});
+			}
+		}
+
+		mod shared_evm {
+			use super::*;
+			use fp_evm::IsPrecompileResult;
+			use precompile_utils::{
+				precompile_set::{is_precompile_or_fail, IsActivePrecompile},
+				testing::{Alice, PrecompileTesterExt},
+			};
+			use frame_support::traits::InstanceFilter;
+			use sp_core::H160;
+			use $runtime::{Executive, Precompiles, ProxyType, Runtime, RuntimeCall};
+
+			fn is_active(precompiles: &Precompiles, address: H160) -> bool {
+				match precompiles.is_active_precompile(address, 100_000u64) {
+					IsPrecompileResult::Answer { is_precompile, .. } => is_precompile,
+					_ => false,
+				}
+			}
+
+			#[test]
+			fn verify_proxy_type_indices() {
+				assert_eq!(ProxyType::Any as u8, 0);
+				assert_eq!(ProxyType::NonTransfer as u8, 1);
+				assert_eq!(ProxyType::Governance as u8, 2);
+				assert_eq!(ProxyType::Staking as u8, 3);
+				assert_eq!(ProxyType::CancelProxy as u8, 4);
+				assert_eq!(ProxyType::Balances as u8, 5);
+				assert_eq!(ProxyType::AuthorMapping as u8, 6);
+				assert_eq!(ProxyType::IdentityJudgement as u8, 7);
+				assert_eq!(ProxyType::ConvictionVoting as u8, 8);
+			}
+
+			#[test]
+			fn conviction_voting_proxy_filter() {
+				let vote = RuntimeCall::ConvictionVoting(
+					pallet_conviction_voting::Call::<Runtime>::remove_vote {
+						class: None,
+						index: 0,
+					},
+				);
+				assert!(ProxyType::ConvictionVoting.filter(&vote));
+				assert!(ProxyType::Governance.filter(&vote));
+				assert!(!ProxyType::Staking.filter(&vote));
+				assert!(ProxyType::Governance.is_superset(&ProxyType::ConvictionVoting));
+				assert!(!ProxyType::ConvictionVoting.is_superset(&ProxyType::Governance));
+			}
+
+			#[test]
+			fn ethereum_invalid_transaction() {
+				ExtBuilder::default().build().execute_with(|| {
+					// Ensure an extrinsic not containing enough gas limit to store the transaction
+					// on chain is rejected.
+					assert_eq!(
+						Executive::apply_extrinsic(unchecked_eth_tx(INVALID_ETH_TX)),
+						Err(
+							sp_runtime::transaction_validity::TransactionValidityError::Invalid(
+								sp_runtime::transaction_validity::InvalidTransaction::Custom(3u8)
+							)
+						)
+					);
+				});
+			}
+
+			#[test]
+			fn removed_precompiles() {
+				ExtBuilder::default().build().execute_with(|| {
+					let precompiles = Precompiles::new();
+					let removed_precompiles = [1025];
+
+					for i in 1..3000 {
+						let address = H160::from_low_u64_be(i);
+
+						if !is_precompile_or_fail::<Runtime>(address, 100_000u64)
+							.expect("to be ok")
+						{
+							continue;
+						}
+
+						if !removed_precompiles.contains(&i) {
+							assert!(
+								is_active(&precompiles, address),
+								"{i} should be an active precompile"
+							);
+							continue;
+						}
+
+						assert!(
+							!is_active(&precompiles, address),
+							"{i} shouldn't be an active precompile"
+						);
+
+						precompiles
+							.prepare_test(Alice, address, [])
+							.execute_reverts(|out| out == b"Removed precompile");
+					}
+				})
+			}
+		}
+
+		mod shared_xcm {
+			use super::*;
+			use frame_support::{
+				assert_noop, assert_ok,
+				traits::EnsureOrigin,
+				weights::constants::WEIGHT_REF_TIME_PER_SECOND,
+			};
+			use moonbeam_xcm_benchmarks::weights::XcmWeight;
+			use parity_scale_codec::Encode;
+			use polkadot_parachain::primitives::Sibling;
+			use precompile_utils::{prelude::Address, testing::PrecompileTesterExt};
+			use sp_core::H160;
+			use xcm::latest::prelude::*;
+			use xcm_builder::{
+				HashedDescriptionDescribeFamilyAllTerminal, ParentIsPreset,
+				SiblingParachainConvertsVia,
+			};
+			use xcm_executor::traits::Convert as XcmConvert;
+			use $runtime::{
+				asset_config::AssetRegistrarMetadata,
+				currency::{WEIGHT_FEE, $unit},
+				xcm_config::{AssetType, CurrencyId, SelfReserve},
+				AccountId, AssetId, PolkadotXcm, Precompiles, Runtime, RuntimeCall, XTokens,
+			};
+
+			type XcmUtilsPCall = pallet_evm_precompile_xcm_utils::XcmUtilsPrecompileCall<
+				Runtime,
+				$runtime::xcm_config::XcmExecutorConfig,
+			>;
+
+			#[test]
+			fn xcmp_queue_controller_origin_is_root() {
+				// important for the XcmExecutionManager impl of PauseExecution which uses root
+				// origin to suspend/resume XCM execution in xcmp_queue::on_idle
+				assert_ok!(
+					<Runtime as cumulus_pallet_xcmp_queue::Config
+					>::ControllerOrigin::ensure_origin(root_origin())
+				);
+			}
+
+			#[test]
+			fn root_can_change_default_xcm_vers() {
+				ExtBuilder::default()
+					.with_balances(vec![
+						(AccountId::from(ALICE), 2_000 * $unit),
+						(AccountId::from(BOB), 1_000 * $unit),
+					])
+					.with_xcm_assets(vec![XcmAssetInitialization {
+						asset_type: AssetType::Xcm(MultiLocation::parent()),
+						metadata: AssetRegistrarMetadata {
+							name: b"RelayToken".to_vec(),
+							symbol: b"Relay".to_vec(),
+							decimals: 12,
+							is_frozen: false,
+						},
+						balances: vec![(AccountId::from(ALICE), 1_000_000_000_000_000)],
+						is_sufficient: true,
+					}])
+					.build()
+					.execute_with(|| {
+						let source_location = AssetType::Xcm(MultiLocation::parent());
+						let dest = MultiLocation {
+							parents: 1,
+							interior: X1(AccountId32 {
+								network: None,
+								id: [1u8; 32],
+							}),
+						};
+						let source_id: AssetId = source_location.clone().into();
+						// Default XCM version is not set yet, so xtokens should fail because it
+						// does not know with which version to send
+						assert_noop!(
+							XTokens::transfer(
+								origin_of(AccountId::from(ALICE)),
+								CurrencyId::ForeignAsset(source_id),
+								100_000_000_000_000,
+								Box::new(xcm::VersionedMultiLocation::V3(dest.clone())),
+								WeightLimit::Limited(4000000000.into())
+							),
+							orml_xtokens::Error::<Runtime>::XcmExecutionFailed
+						);
+
+						// Root sets the defaultXcm
+						assert_ok!(PolkadotXcm::force_default_xcm_version(
+							root_origin(),
+							Some(2)
+						));
+
+						// Now transferring does not fail
+						assert_ok!(XTokens::transfer(
+							origin_of(AccountId::from(ALICE)),
+							CurrencyId::ForeignAsset(source_id),
+							100_000_000_000_000,
+							Box::new(xcm::VersionedMultiLocation::V3(dest)),
+							WeightLimit::Limited(4000000000.into())
+						));
+					})
+			}
+
+			#[test]
+			fn test_xcm_utils_ml_tp_account() {
+				ExtBuilder::default().build().execute_with(|| {
+					let xcm_utils_precompile_address = H160::from_low_u64_be(2060);
+					let expected_address_parent: H160 =
+						ParentIsPreset::<AccountId>::convert_ref(MultiLocation::parent())
+							.unwrap()
+							.into();
+
+					Precompiles::new()
+						.prepare_test(
+							ALICE,
+							xcm_utils_precompile_address,
+							XcmUtilsPCall::multilocation_to_address {
+								multilocation: MultiLocation::parent(),
+							},
+						)
+						.expect_cost(1000)
+						.expect_no_logs()
+						.execute_returns(Address(expected_address_parent));
+
+					let parachain_2000_multilocation = MultiLocation::new(1, X1(Parachain(2000)));
+					let expected_address_parachain: H160 =
+						SiblingParachainConvertsVia::<Sibling, AccountId>::convert_ref(
+							parachain_2000_multilocation.clone(),
+						)
+						.unwrap()
+						.into();
+
+					Precompiles::new()
+						.prepare_test(
+							ALICE,
+							xcm_utils_precompile_address,
+							XcmUtilsPCall::multilocation_to_address {
+								multilocation: parachain_2000_multilocation,
+							},
+						)
+						.expect_cost(1000)
+						.expect_no_logs()
+						.execute_returns(Address(expected_address_parachain));
+
+					let alice_in_parachain_2000_multilocation = MultiLocation::new(
+						1,
+						X2(
+							Parachain(2000),
+							AccountKey20 {
+								network: None,
+								key: ALICE,
+							},
+						),
+					);
+					let expected_address_alice_in_parachain_2000: H160 =
+						HashedDescriptionDescribeFamilyAllTerminal::<AccountId>::convert_ref(
+							alice_in_parachain_2000_multilocation.clone(),
+						)
+						.unwrap()
+						.into();
+
+					Precompiles::new()
+						.prepare_test(
+							ALICE,
+							xcm_utils_precompile_address,
+							XcmUtilsPCall::multilocation_to_address {
+								multilocation: alice_in_parachain_2000_multilocation,
+							},
+						)
+						.expect_cost(1000)
+						.expect_no_logs()
+						.execute_returns(Address(expected_address_alice_in_parachain_2000));
+				});
+			}
+
+			#[test]
+			fn test_xcm_utils_get_units_per_second() {
+				ExtBuilder::default().build().execute_with(|| {
+					let xcm_utils_precompile_address = H160::from_low_u64_be(2060);
+					let multilocation = SelfReserve::get();
+
+					let input = XcmUtilsPCall::get_units_per_second { multilocation };
+
+					let expected_units = WEIGHT_REF_TIME_PER_SECOND as u128 * WEIGHT_FEE;
+
+					Precompiles::new()
+						.prepare_test(ALICE, xcm_utils_precompile_address, input)
+						.expect_cost(1000)
+						.expect_no_logs()
+						.execute_returns(expected_units);
+				});
+			}
+
+			#[test]
+			fn test_xcm_utils_weight_message() {
+				ExtBuilder::default().build().execute_with(|| {
+					let xcm_utils_precompile_address = H160::from_low_u64_be(2060);
+					let expected_weight =
+						XcmWeight::<Runtime, RuntimeCall>::clear_origin().ref_time();
+
+					let message: Vec<u8> =
+						xcm::VersionedXcm::<()>::V3(Xcm(vec![ClearOrigin])).encode();
+
+					let input = XcmUtilsPCall::weight_message {
+						message: message.into(),
+					};
+
+					Precompiles::new()
+						.prepare_test(ALICE, xcm_utils_precompile_address, input)
+						.expect_cost(0)
+						.expect_no_logs()
+						.execute_returns(expected_weight);
+				});
+			}
+		}
+
+		mod shared_staking {
+			use super::*;
+			use frame_support::{assert_noop, assert_ok, traits::Get};
+			use $runtime::{
+				currency::{WEI, $unit},
+				AccountId, Balance, Balances, ParachainStaking, Runtime, RuntimeEvent, System,
+			};
+
+			fn min_candidate_stk() -> Balance {
+				<Runtime as pallet_parachain_staking::Config>::MinCandidateStk::get()
+			}
+
+			#[test]
+			fn transfer_ed_0_substrate() {
+				ExtBuilder::default()
+					.with_balances(vec![
+						(AccountId::from(ALICE), (1 * $unit) + (1 * WEI)),
+						(AccountId::from(BOB), 0),
+					])
+					.build()
+					.execute_with(|| {
+						// Substrate transfer
+						assert_ok!(Balances::transfer(
+							origin_of(AccountId::from(ALICE)),
+							AccountId::from(BOB),
+							1 * $unit,
+						));
+						// 1 WEI is left in the account
+						assert_eq!(Balances::free_balance(AccountId::from(ALICE)), 1 * WEI);
+					});
+			}
+
+			#[test]
+			fn join_collator_candidates() {
+				let bond = min_candidate_stk();
+				let stake = bond / 200;
+				ExtBuilder::default()
+					.with_balances(vec![
+						(AccountId::from(ALICE), 2 * bond),
+						(AccountId::from(BOB), 2 * bond),
+						(AccountId::from(CHARLIE), bond + 2 * stake),
+						(AccountId::from(DAVE), bond),
+					])
+					.with_collators(vec![
+						(AccountId::from(ALICE), bond),
+						(AccountId::from(BOB), bond),
+					])
+					.with_delegations(vec![
+						(AccountId::from(CHARLIE), AccountId::from(ALICE), stake),
+						(AccountId::from(CHARLIE), AccountId::from(BOB), stake),
+					])
+					.build()
+					.execute_with(|| {
+						assert_noop!(
+							ParachainStaking::join_candidates(
+								origin_of(AccountId::from(ALICE)),
+								bond,
+								2u32
+							),
+							pallet_parachain_staking::Error::<Runtime>::CandidateExists
+						);
+						assert_noop!(
+							ParachainStaking::join_candidates(
+								origin_of(AccountId::from(CHARLIE)),
+								bond,
+								2u32
+							),
+							pallet_parachain_staking::Error::<Runtime>::DelegatorExists
+						);
+						assert!(System::events().is_empty());
+						assert_ok!(ParachainStaking::join_candidates(
+							origin_of(AccountId::from(DAVE)),
+							bond,
+							2u32
+						));
+						assert_eq!(
+							last_event(),
+							RuntimeEvent::ParachainStaking(
+								pallet_parachain_staking::Event::JoinedCollatorCandidates {
+									account: AccountId::from(DAVE),
+									amount_locked: bond,
+									new_total_amt_locked: 3 * bond + 2 * stake,
+								}
+							)
+						);
+						let candidates = ParachainStaking::candidate_pool();
+						assert_eq!(candidates.0[0].owner, AccountId::from(ALICE));
+						assert_eq!(candidates.0[0].amount, bond + stake);
+						assert_eq!(candidates.0[1].owner, AccountId::from(BOB));
+						assert_eq!(candidates.0[1].amount, bond + stake);
+						assert_eq!(candidates.0[2].owner, AccountId::from(DAVE));
+						assert_eq!(candidates.0[2].amount, bond);
+					});
+			}
+		}
+
+		mod shared_randomness {
+			use super::*;
+			use frame_support::{assert_ok, traits::Get};
+			use moonbeam_runtime_common::randomness_consumer::Lottery;
+			use pallet_randomness::{RandomnessResults, RequestType, Requests};
+			use sp_core::{H160, H256};
+			use $runtime::{AccountId, Balances, Runtime, System};
+
+			fn request_draw(fee: u128) -> (u64, u32) {
+				let delay = <Runtime as pallet_randomness::Config>::MinBlockDelay::get();
+				let due_block = System::block_number() + delay;
+				let request_id = Lottery::<Runtime>::request_draw(
+					H160::from(ALICE),
+					fee,
+					delay,
+					H256::repeat_byte(0x11),
+				)
+				.expect("request is valid");
+				(request_id, due_block)
+			}
+
+			#[test]
+			fn lottery_draw_is_fulfilled_and_refunded() {
+				let deposit = <Runtime as pallet_randomness::Config>::Deposit::get();
+				let fee = $unit;
+				ExtBuilder::default()
+					.with_balances(vec![(AccountId::from(ALICE), deposit + 10 * $unit)])
+					.build()
+					.execute_with(|| {
+						let alice = AccountId::from(ALICE);
+						let participants = [H160::from(ALICE), H160::from(BOB)];
+						let (request_id, due_block) = request_draw(fee);
+						assert_eq!(Balances::reserved_balance(alice), deposit + fee);
+
+						// The randomness is not available before the due block.
+						assert!(Lottery::<Runtime>::draw(
+							request_id,
+							&participants,
+							&H160::from(BOB)
+						)
+						.is_err());
+
+						System::set_block_number(due_block);
+						let mut result = RandomnessResults::<Runtime>::get(
+							RequestType::Local(due_block),
+						)
+						.expect("result is initialized by the request");
+						result.randomness = Some(H256::repeat_byte(0x22));
+						RandomnessResults::<Runtime>::insert(RequestType::Local(due_block), result);
+
+						let winner = Lottery::<Runtime>::draw(
+							request_id,
+							&participants,
+							&H160::from(BOB),
+						)
+						.expect("draw can be fulfilled");
+						assert!(participants.contains(&winner));
+
+						// The request is removed and both the deposit and the fee are refunded.
+						assert!(Requests::<Runtime>::get(request_id).is_none());
+						assert_eq!(Balances::reserved_balance(alice), 0);
+						assert_eq!(Balances::free_balance(alice), deposit + 10 * $unit);
+					});
+			}
+
+			#[test]
+			fn expired_lottery_draw_can_be_purged() {
+				let deposit = <Runtime as pallet_randomness::Config>::Deposit::get();
+				let fee = $unit;
+				ExtBuilder::default()
+					.with_balances(vec![
+						(AccountId::from(ALICE), deposit + 10 * $unit),
+						(AccountId::from(BOB), $unit),
+					])
+					.build()
+					.execute_with(|| {
+						let alice = AccountId::from(ALICE);
+						let (request_id, due_block) = request_draw(fee);
+
+						// Not expired yet.
+						assert!(
+							Lottery::<Runtime>::purge_expired_draw(&H160::from(BOB), request_id)
+								.is_err()
+						);
+
+						let expiration_delay =
+							<Runtime as pallet_randomness::Config>::BlockExpirationDelay::get();
+						System::set_block_number(due_block + expiration_delay + 1);
+						assert_ok!(Lottery::<Runtime>::purge_expired_draw(
+							&H160::from(BOB),
+							request_id
+						));
+
+						// The fee goes to the caller and the deposit back to the organizer.
+						assert!(Requests::<Runtime>::get(request_id).is_none());
+						assert_eq!(Balances::reserved_balance(alice), 0);
+						assert_eq!(Balances::free_balance(alice), deposit + 9 * $unit);
+						assert_eq!(Balances::free_balance(AccountId::from(BOB)), 2 * $unit);
+					});
+			}
+		}
+
+		mod shared_scenarios {
+			use super::*;
+			use frame_support::{
+				assert_noop, assert_ok,
+				traits::{schedule::DispatchTime, Bounded, Get},
+			};
+			use pallet_conviction_voting::{AccountVote, Conviction, Vote};
+			use parity_scale_codec::Encode;
+			use xcm::latest::prelude::*;
+			use $runtime::{
+				xcm_config::CurrencyId, AccountId, Balance, Balances, ConvictionVoting,
+				OriginCaller, ParachainStaking, PolkadotXcm, Referenda, Runtime, RuntimeCall,
+				RuntimeEvent, System, XTokens,
+			};
+
+			fn lock_ids(who: AccountId) -> Vec<[u8; 8]> {
+				pallet_balances::Locks::<Runtime>::get(who)
+					.into_iter()
+					.map(|lock| lock.id)
+					.collect()
+			}
+
+			#[test]
+			fn staked_funds_can_vote_and_only_free_funds_leave_through_xcm() {
+				let bond = <Runtime as pallet_parachain_staking::Config>::MinCandidateStk::get();
+				let stake: Balance =
+					10 * <Runtime as pallet_parachain_staking::Config>::MinDelegation::get();
+				let bob_balance = 4 * stake;
+				ExtBuilder::default()
+					.with_balances(vec![
+						(AccountId::from(ALICE), 2 * bond),
+						(AccountId::from(BOB), bob_balance),
+					])
+					.with_collators(vec![(AccountId::from(ALICE), bond)])
+					.build()
+					.execute_with(|| {
+						let bob = AccountId::from(BOB);
+
+						// Stake: BOB delegates to the only collator.
+						assert_ok!(ParachainStaking::delegate(
+							origin_of(bob),
+							AccountId::from(ALICE),
+							stake,
+							0u32,
+							0u32
+						));
+
+						// Vote: ALICE opens a root referendum and BOB backs it with the very same
+						// funds that are already staked.
+						let proposal: RuntimeCall =
+							frame_system::Call::remark { remark: vec![1u8] }.into();
+						assert_ok!(Referenda::submit(
+							origin_of(AccountId::from(ALICE)),
+							Box::new(OriginCaller::system(frame_system::RawOrigin::Root)),
+							Bounded::Inline(proposal.encode().try_into().unwrap()),
+							DispatchTime::After(1u32),
+						));
+						let poll_index = pallet_referenda::ReferendumCount::<Runtime>::get() - 1;
+						assert_ok!(ConvictionVoting::vote(
+							origin_of(bob),
+							poll_index,
+							AccountVote::Standard {
+								vote: Vote {
+									aye: true,
+									conviction: Conviction::Locked1x,
+								},
+								balance: stake,
+							}
+						));
+
+						// Staking and voting locks overlap instead of adding up.
+						let locks = lock_ids(bob);
+						assert!(locks.contains(&pallet_parachain_staking::DELEGATOR_LOCK_ID));
+						assert!(locks.contains(b"pyconvot"));
+						assert_eq!(Balances::usable_balance(bob), bob_balance - stake);
+
+						// XCM: only the unlocked remainder can be sent to the relay chain.
+						assert_ok!(PolkadotXcm::force_default_xcm_version(
+							root_origin(),
+							Some(3)
+						));
+						let dest = MultiLocation {
+							parents: 1,
+							interior: X1(AccountId32 {
+								network: None,
+								id: [1u8; 32],
+							}),
+						};
+						assert_noop!(
+							XTokens::transfer(
+								origin_of(bob),
+								CurrencyId::SelfReserve,
+								bob_balance,
+								Box::new(xcm::VersionedMultiLocation::V3(dest.clone())),
+								WeightLimit::Limited(4_000_000_000.into())
+							),
+							orml_xtokens::Error::<Runtime>::XcmExecutionFailed
+						);
+
+						let sent = (bob_balance - stake) / 2;
+						assert_ok!(XTokens::transfer(
+							origin_of(bob),
+							CurrencyId::SelfReserve,
+							sent,
+							Box::new(xcm::VersionedMultiLocation::V3(dest)),
+							WeightLimit::Limited(4_000_000_000.into())
+						));
+						assert!(System::events().iter().any(|r| matches!(
+							r.event,
+							RuntimeEvent::XTokens(
+								orml_xtokens::Event::TransferredMultiAssets { .. }
+							)
+						)));
+
+						// The delegation and the vote are left untouched.
+						assert_eq!(Balances::free_balance(bob), bob_balance - sent);
+						assert_eq!(Balances::usable_balance(bob), bob_balance - stake - sent);
+						assert_eq!(
+							ParachainStaking::delegator_state(bob)
+								.expect("BOB is a delegator")
+								.total(),
+							stake
+						);
+						assert_eq!(lock_ids(bob).len(), 2);
+					});
+			}
+		}
+
+		mod shared_signed_extensions {
+			use super::*;
+			use moonbeam_runtime_common::check_metadata_hash::{
+				CheckMetadataHash, RUNTIME_METADATA_HASH,
+			};
+			use sp_runtime::traits::SignedExtension;
+			use $runtime::{Runtime, SignedExtra};
+
+			#[test]
+			fn signed_extra_includes_check_metadata_hash() {
+				assert!(<SignedExtra as SignedExtension>::metadata()
+					.iter()
+					.any(|extension| extension.identifier == "CheckMetadataHash"));
+			}
+
+			#[test]
+			fn metadata_hash_is_signed_only_when_enabled() {
+				assert_eq!(
+					CheckMetadataHash::<Runtime>::new(false).additional_signed(),
+					Ok(None)
+				);
+				assert_eq!(
+					CheckMetadataHash::<Runtime>::new(true).additional_signed(),
+					Ok(RUNTIME_METADATA_HASH)
+				);
+			}
+
+			#[test]
+			fn metadata_hash_of_the_runtime_metadata_commits_to_its_version() {
+				let metadata = Runtime::metadata().1;
+				let version = $runtime::VERSION;
+				let hash = |spec_version| {
+					moonbeam_metadata_hash::runtime_metadata_hash(
+						&metadata,
+						spec_version,
+						&version.spec_name,
+						"UNIT",
+						18,
+					)
+				};
+
+				assert_eq!(hash(version.spec_version), hash(version.spec_version));
+				assert_ne!(hash(version.spec_version), hash(version.spec_version + 1));
+			}
+		}
+	};
+}
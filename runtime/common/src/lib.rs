@@ -17,10 +17,18 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod apis;
+pub mod check_metadata_hash;
+pub mod fee_adjustment;
+pub mod gas_limit;
 mod impl_moonbeam_xcm_call;
 mod impl_moonbeam_xcm_call_tracing;
 mod impl_on_charge_evm_transaction;
 mod impl_self_contained_call;
 mod impl_xcm_evm_runner;
+mod integration_tests;
+pub mod migration_tracking;
 pub mod migrations;
+#[cfg(feature = "randomness-consumer")]
+pub mod randomness_consumer;
 pub mod weights;
+pub mod xc20;
@@ -0,0 +1,131 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Migration tracking
+//!
+//! pallet-migrations only records whether each migration is done. [`TrackedMigrations`] wraps the
+//! migrations registered in it to also record that they were started and the weight they
+//! consumed, which [`MigrationsStatus`] exposes to the `MigrationsApi` runtime API and to the
+//! migrations precompile.
+
+use frame_support::{storage_alias, traits::Get, weights::Weight, RuntimeDebug, Twox64Concat};
+use migrations_runtime_api::{MigrationStatus, MigrationWeight};
+use pallet_migrations::{GetMigrations, Migration};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// A run of a migration by pallet-migrations.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MigrationRun {
+	/// Weight consumed by the migration, `None` while it runs.
+	pub consumed_weight: Option<Weight>,
+}
+
+/// Runs of the migrations, by friendly name.
+#[storage_alias]
+pub type MigrationRuns = StorageMap<MigrationTracking, Twox64Concat, Vec<u8>, MigrationRun>;
+
+/// Wraps the migrations of `Migrations` to record their runs in [`MigrationRuns`].
+pub struct TrackedMigrations<Runtime, Migrations>(PhantomData<(Runtime, Migrations)>);
+
+impl<Runtime, Migrations> GetMigrations for TrackedMigrations<Runtime, Migrations>
+where
+	Runtime: frame_system::Config,
+	Migrations: GetMigrations,
+{
+	fn get_migrations() -> Vec<Box<dyn Migration>> {
+		Migrations::get_migrations()
+			.into_iter()
+			.map(|migration| {
+				Box::new(TrackedMigration::<Runtime> {
+					inner: migration,
+					_phantom: PhantomData,
+				}) as Box<dyn Migration>
+			})
+			.collect()
+	}
+}
+
+struct TrackedMigration<Runtime> {
+	inner: Box<dyn Migration>,
+	_phantom: PhantomData<Runtime>,
+}
+
+impl<Runtime: frame_system::Config> Migration for TrackedMigration<Runtime> {
+	fn friendly_name(&self) -> &str {
+		self.inner.friendly_name()
+	}
+
+	fn migrate(&self, available_weight: Weight) -> Weight {
+		let name = self.inner.friendly_name().as_bytes().to_vec();
+
+		MigrationRuns::insert(
+			&name,
+			MigrationRun {
+				consumed_weight: None,
+			},
+		);
+		let consumed_weight = self.inner.migrate(available_weight);
+		MigrationRuns::insert(
+			&name,
+			MigrationRun {
+				consumed_weight: Some(consumed_weight),
+			},
+		);
+
+		consumed_weight.saturating_add(Runtime::DbWeight::get().writes(2))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade(&self) -> Result<Vec<u8>, sp_runtime::DispatchError> {
+		self.inner.pre_upgrade()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(&self, state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		self.inner.post_upgrade(state)
+	}
+}
+
+/// Status of each migration registered in pallet-migrations.
+pub struct MigrationsStatus<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime: pallet_migrations::Config> Get<Vec<MigrationStatus>> for MigrationsStatus<Runtime> {
+	fn get() -> Vec<MigrationStatus> {
+		<Runtime as pallet_migrations::Config>::MigrationsList::get_migrations()
+			.iter()
+			.map(|migration| {
+				let name = migration.friendly_name().as_bytes().to_vec();
+				let completed = pallet_migrations::Pallet::<Runtime>::migration_state(&name);
+				let run = MigrationRuns::get(&name);
+
+				MigrationStatus {
+					// Migrations completed before their runs were recorded have no run.
+					started: completed || run.is_some(),
+					completed,
+					consumed_weight: run.and_then(|run| run.consumed_weight).map(|weight| {
+						MigrationWeight {
+							ref_time: weight.ref_time(),
+							proof_size: weight.proof_size(),
+						}
+					}),
+					name,
+				}
+			})
+			.collect()
+	}
+}
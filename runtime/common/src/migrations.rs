@@ -21,11 +21,13 @@
 
 use frame_support::{
 	dispatch::GetStorageVersion,
-	traits::{Hash as PreimageHash, OnRuntimeUpgrade, PalletInfoAccess},
+	traits::{Get, Hash as PreimageHash, OnRuntimeUpgrade, PalletInfoAccess},
 	weights::Weight,
 };
 use pallet_author_slot_filter::Config as AuthorSlotFilterConfig;
 use pallet_migrations::{GetMigrations, Migration};
+use sp_core::H160;
+use sp_runtime::traits::Convert;
 use sp_std::{marker::PhantomData, prelude::*};
 
 pub struct PreimageMigrationHashToBoundedCall<T>(PhantomData<T>);
@@ -80,6 +82,94 @@ where
 	}
 }
 
+pub struct ParachainStakingMigrateParachainBondConfig<T>(pub PhantomData<T>);
+impl<T> Migration for ParachainStakingMigrateParachainBondConfig<T>
+where
+	T: pallet_parachain_staking::Config,
+{
+	fn friendly_name(&self) -> &str {
+		"MM_ParachainStakingMigrateParachainBondConfig"
+	}
+
+	fn migrate(&self, _available_weight: Weight) -> Weight {
+		pallet_parachain_staking::migrations::MigrateParachainBondConfig::<T>::on_runtime_upgrade()
+	}
+
+	/// Run a standard pre-runtime test. This works the same way as in a normal runtime upgrade.
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade(&self) -> Result<Vec<u8>, sp_runtime::DispatchError> {
+		pallet_parachain_staking::migrations::MigrateParachainBondConfig::<T>::pre_upgrade()
+	}
+
+	/// Run a standard post-runtime test. This works the same way as in a normal runtime upgrade.
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(&self, state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		pallet_parachain_staking::migrations::MigrateParachainBondConfig::<T>::post_upgrade(state)
+	}
+}
+
+/// Insert the XC-20 revert code at the address of every registered foreign asset which does not
+/// have it yet, as the code was not inserted when creating foreign assets before.
+pub struct AssetManagerInsertForeignAssetsRevertCode<Runtime, AssetAddress>(
+	pub PhantomData<(Runtime, AssetAddress)>,
+);
+impl<Runtime, AssetAddress> Migration
+	for AssetManagerInsertForeignAssetsRevertCode<Runtime, AssetAddress>
+where
+	Runtime: pallet_asset_manager::Config + pallet_evm::Config,
+	AssetAddress: Convert<Runtime::AssetId, H160>,
+{
+	fn friendly_name(&self) -> &str {
+		"MM_AssetManagerInsertForeignAssetsRevertCode"
+	}
+
+	fn migrate(&self, _available_weight: Weight) -> Weight {
+		let mut reads = 0u64;
+		let mut writes = 0u64;
+
+		for asset_id in pallet_asset_manager::AssetIdType::<Runtime>::iter_keys() {
+			// one read for the asset, one for its code
+			reads += 2;
+			if crate::xc20::insert_revert_code::<Runtime>(AssetAddress::convert(asset_id)) {
+				writes += 1;
+			}
+		}
+
+		log::info!(
+			target: "migration",
+			"AssetManagerInsertForeignAssetsRevertCode: inserted {} revert codes",
+			writes,
+		);
+
+		<Runtime as frame_system::Config>::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(&self, _state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		for asset_id in pallet_asset_manager::AssetIdType::<Runtime>::iter_keys() {
+			frame_support::ensure!(
+				pallet_evm::AccountCodes::<Runtime>::contains_key(AssetAddress::convert(asset_id)),
+				"foreign asset without code"
+			);
+		}
+		Ok(())
+	}
+}
+
+pub struct Xc20Migrations<Runtime, AssetAddress>(PhantomData<(Runtime, AssetAddress)>);
+
+impl<Runtime, AssetAddress> GetMigrations for Xc20Migrations<Runtime, AssetAddress>
+where
+	Runtime: pallet_asset_manager::Config + pallet_evm::Config,
+	AssetAddress: Convert<Runtime::AssetId, H160> + 'static,
+{
+	fn get_migrations() -> Vec<Box<dyn Migration>> {
+		let asset_manager_insert_foreign_assets_revert_code =
+			AssetManagerInsertForeignAssetsRevertCode::<Runtime, AssetAddress>(Default::default());
+		vec![Box::new(asset_manager_insert_foreign_assets_revert_code)]
+	}
+}
+
 pub struct ReferendaMigrations<Runtime, Council, Tech>(PhantomData<(Runtime, Council, Tech)>);
 
 impl<Runtime, Council, Tech> GetMigrations for ReferendaMigrations<Runtime, Council, Tech>
@@ -181,6 +271,8 @@ where
 		//	PalletAssetManagerMigrateXcmV2ToV3::<Runtime>(Default::default());
 		//let xcm_transactor_to_xcm_v3 =
 		//	PalletXcmTransactorMigrateXcmV2ToV3::<Runtime>(Default::default());
+		let parachain_staking_migrate_parachain_bond_config =
+			ParachainStakingMigrateParachainBondConfig::<Runtime>(Default::default());
 		vec![
 			// completed in runtime 800
 			// Box::new(migration_author_mapping_twox_to_blake),
@@ -227,6 +319,7 @@ where
 			//Box::new(preimage_migration_hash_to_bounded_call),
 			//Box::new(asset_manager_to_xcm_v3),
 			//Box::new(xcm_transactor_to_xcm_v3),
+			Box::new(parachain_staking_migrate_parachain_bond_config),
 		]
 	}
 }
@@ -0,0 +1,102 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Reference consumer of pallet-randomness.
+//!
+//! A minimal lottery driving the whole lifecycle of a local VRF request (request, fulfillment,
+//! expiration and the matching refunds) through the pallet API, the same way the randomness
+//! precompile does. It is only compiled with the `randomness-consumer` feature and exercised by
+//! the shared runtime integration tests, so that the end-to-end flow keeps being validated
+//! against the actual runtime configuration.
+
+use frame_support::ensure;
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_randomness::{BalanceOf, FulfillArgs, Pallet, Request, RequestType};
+use sp_core::{H160, H256};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchError, DispatchResult,
+};
+use sp_std::marker::PhantomData;
+
+/// A lottery whose winner is drawn from the local VRF randomness of a future block.
+pub struct Lottery<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Lottery<Runtime>
+where
+	Runtime: pallet_randomness::Config,
+{
+	/// Requests the randomness of the block `delay` blocks ahead. The `organizer` pays the
+	/// request deposit and `fee`, and gets back what is left of them once the draw is done.
+	pub fn request_draw(
+		organizer: H160,
+		fee: BalanceOf<Runtime>,
+		delay: BlockNumberFor<Runtime>,
+		salt: H256,
+	) -> Result<u64, DispatchError> {
+		let due_block = frame_system::Pallet::<Runtime>::block_number().saturating_add(delay);
+
+		Pallet::<Runtime>::request_randomness(Request {
+			refund_address: organizer,
+			contract_address: organizer,
+			fee,
+			gas_limit: 0,
+			num_words: 1,
+			salt,
+			info: RequestType::Local(due_block),
+		})
+	}
+
+	/// Fulfills the draw, returning the winner among `participants`. The lottery performs no
+	/// EVM execution, so the `fulfiller` is not paid and the whole fee is refunded.
+	pub fn draw(
+		request_id: u64,
+		participants: &[H160],
+		fulfiller: &H160,
+	) -> Result<H160, DispatchError> {
+		ensure!(
+			!participants.is_empty(),
+			DispatchError::Other("No participants")
+		);
+
+		let FulfillArgs {
+			request,
+			deposit,
+			randomness,
+		} = Pallet::<Runtime>::prepare_fulfillment(request_id)?;
+		let word = randomness
+			.first()
+			.ok_or(DispatchError::Other("No random word"))?;
+		let mut seed = [0u8; 8];
+		seed.copy_from_slice(&word[..8]);
+		let winner = participants[(u64::from_le_bytes(seed) % participants.len() as u64) as usize];
+
+		Pallet::<Runtime>::finish_fulfillment(
+			request_id,
+			request,
+			deposit,
+			fulfiller,
+			Zero::zero(),
+		);
+
+		Ok(winner)
+	}
+
+	/// Removes a draw that was not fulfilled in time, paying its fee to `caller`.
+	pub fn purge_expired_draw(caller: &H160, request_id: u64) -> DispatchResult {
+		Pallet::<Runtime>::execute_request_expiration(caller, request_id)
+	}
+}
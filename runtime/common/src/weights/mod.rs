@@ -20,6 +20,7 @@ pub mod cumulus_pallet_xcmp_queue;
 pub mod moonbeam_xcm_benchmarks_weights_generic;
 pub mod pallet_asset_manager;
 pub mod pallet_assets;
+pub mod pallet_author_filter_schedule;
 pub mod pallet_author_inherent;
 pub mod pallet_author_mapping;
 pub mod pallet_author_slot_filter;
@@ -28,20 +29,38 @@ pub mod pallet_collective;
 pub mod pallet_conviction_voting;
 pub mod pallet_crowdloan_rewards;
 pub mod pallet_democracy;
+pub mod pallet_democracy_lock_migration;
+pub mod pallet_eip712_dispatch;
+pub mod pallet_erc20_xcm_bridge;
 pub mod pallet_evm;
+pub mod pallet_evm_code_size_limit;
+pub mod pallet_evm_contract_metadata;
+pub mod pallet_evm_deployer_filter;
+pub mod pallet_evm_hibernation;
+pub mod pallet_evm_operators;
+pub mod pallet_evm_sanctions_filter;
+pub mod pallet_evm_sponsorship;
+pub mod pallet_fee_split;
+pub mod pallet_governance_origin_registry;
 pub mod pallet_identity;
 pub mod pallet_moonbeam_orbiters;
 pub mod pallet_multisig;
 pub mod pallet_parachain_staking;
+pub mod pallet_precompile_benchmarks;
 pub mod pallet_preimage;
+pub mod pallet_price_oracle;
 pub mod pallet_proxy;
 pub mod pallet_randomness;
 pub mod pallet_referenda;
 pub mod pallet_scheduler;
 pub mod pallet_sudo;
 pub mod pallet_timestamp;
+pub mod pallet_token_route_registry;
 pub mod pallet_treasury;
 pub mod pallet_utility;
 pub mod pallet_whitelist;
+pub mod pallet_xc20_issuance;
 pub mod pallet_xcm;
+pub mod pallet_xcm_filter;
 pub mod pallet_xcm_transactor;
+pub mod pallet_xcm_weight_trader;
@@ -165,4 +165,54 @@ impl<T: frame_system::Config> pallet_asset_manager::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().writes(4))
 			.saturating_add(Weight::from_parts(0, 10).saturating_mul(x.into()))
 	}
+	/// Storage: AssetManager SupportedFeePaymentAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager SupportedFeePaymentAssets (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdType (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeId (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeUnitsPerSecond (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeAliasRetirements (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAliasRetirements (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeAlias (r:0 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAlias (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[5, 100]`.
+	fn migrate_existing_asset_type(x: u32, ) -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(47_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4462))
+			// Standard Error: 2_531
+			.saturating_add(Weight::from_parts(610_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(Weight::from_parts(0, 13).saturating_mul(x.into()))
+	}
+	/// Storage: AssetManager AssetTypeAlias (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAlias (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeId (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager SupportedFeePaymentAssets (r:1 w:1)
+	/// Proof Skipped: AssetManager SupportedFeePaymentAssets (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeUnitsPerSecond (r:0 w:1)
+	/// Proof Skipped: AssetManager AssetTypeUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	fn retire_asset_type_alias() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(37_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5477))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}	/// Storage: AssetManager FeeAssetPreference (r:0 w:1)
+	/// Proof Skipped: AssetManager FeeAssetPreference (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[1, 100]`.
+	fn set_fee_asset_preference(x: u32, ) -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 842
+			.saturating_add(Weight::from_parts(760_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
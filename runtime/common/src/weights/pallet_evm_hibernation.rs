@@ -0,0 +1,86 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+//! Placeholder weights for `pallet_evm_hibernation`
+//!
+//! These weights have not been benchmarked yet. They are hand-written, deliberately
+//! conservative estimates whose proof sizes follow the storage bounds; regenerate them
+//! with `moonbeam benchmark pallet` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_evm_hibernation`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_evm_hibernation::WeightInfo for WeightInfo<T> {
+	/// Storage: EvmHibernation DormancyPeriod (r:1 w:1)
+	/// Proof: EvmHibernation DormancyPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: EvmHibernation TrackingStart (r:0 w:1)
+	/// Proof: EvmHibernation TrackingStart (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_dormancy_period() -> Weight {
+		Weight::from_parts(20_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: EvmHibernation DormancyPeriod (r:1 w:0)
+	/// Proof: EvmHibernation DormancyPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: EvmHibernation HibernatedContracts (r:1 w:1)
+	/// Proof: EvmHibernation HibernatedContracts (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: EvmHibernation TrackingStart (r:1 w:0)
+	/// Proof: EvmHibernation TrackingStart (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: EvmHibernation LastAccess (r:1 w:1)
+	/// Proof: EvmHibernation LastAccess (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodes (r:1 w:1)
+	/// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: EVM AccountStorages (r:{s} w:{s})
+	/// Proof Skipped: EVM AccountStorages (max_values: None, max_size: None, mode: Measured)
+	/// Storage: EVM AccountCodesMetadata (r:0 w:1)
+	/// Proof Skipped: EVM AccountCodesMetadata (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `s` is `[0, 1000]`.
+	fn hibernate_contract(s: u32, ) -> Weight {
+		Weight::from_parts(200_000_000, 28245)
+			.saturating_add(Weight::from_parts(9_900_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2586).saturating_mul(s.into()))
+	}
+	/// Storage: EvmHibernation HibernatedContracts (r:1 w:1)
+	/// Proof: EvmHibernation HibernatedContracts (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodes (r:2 w:1)
+	/// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: EvmHibernation DormancyPeriod (r:1 w:0)
+	/// Proof: EvmHibernation DormancyPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: EvmHibernation LastAccess (r:1 w:1)
+	/// Proof: EvmHibernation LastAccess (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodesMetadata (r:0 w:1)
+	/// Proof Skipped: EVM AccountCodesMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: EVM AccountStorages (r:0 w:{s})
+	/// Proof Skipped: EVM AccountStorages (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `s` is `[0, 1000]`.
+	fn wake_contract(s: u32, ) -> Weight {
+		Weight::from_parts(170_000_000, 3677)
+			.saturating_add(Weight::from_parts(6_400_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+	}
+}
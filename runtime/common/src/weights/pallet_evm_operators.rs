@@ -0,0 +1,61 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+//! Placeholder weights for `pallet_evm_operators`
+//!
+//! These weights have not been benchmarked yet. They are hand-written, deliberately
+//! conservative estimates whose proof sizes follow the storage bounds; regenerate them
+//! with `moonbeam benchmark pallet` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_evm_operators`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_evm_operators::WeightInfo for WeightInfo<T> {
+	/// Storage: EvmOperators Operators (r:0 w:1)
+	/// Proof: EvmOperators Operators (max_values: None, max_size: Some(489), added: 2964, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 16]`.
+	fn add_operator(c: u32, ) -> Weight {
+		Weight::from_parts(27_000_000, 0)
+			.saturating_add(Weight::from_parts(130_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: EvmOperators Operators (r:1 w:1)
+	/// Proof: EvmOperators Operators (max_values: None, max_size: Some(489), added: 2964, mode: MaxEncodedLen)
+	fn remove_operator() -> Weight {
+		Weight::from_parts(31_000_000, 3954)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: EvmOperators Operators (r:1 w:1)
+	/// Proof: EvmOperators Operators (max_values: None, max_size: Some(489), added: 2964, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodes (r:1 w:0)
+	/// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `c` is `[1, 16]`.
+	fn operator_call(c: u32, ) -> Weight {
+		Weight::from_parts(98_000_000, 6236)
+			.saturating_add(Weight::from_parts(120_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
@@ -0,0 +1,43 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+//! Placeholder weights for `pallet_governance_origin_registry`
+//!
+//! These weights have not been benchmarked yet. They are hand-written, deliberately
+//! conservative estimates whose proof sizes follow the storage bounds; regenerate them
+//! with `moonbeam benchmark pallet` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_governance_origin_registry`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_governance_origin_registry::WeightInfo for WeightInfo<T> {
+	/// Storage: GovernanceOriginRegistry AddressOrigins (r:1 w:2)
+	/// Proof: GovernanceOriginRegistry AddressOrigins (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: GovernanceOriginRegistry OriginAddresses (r:1 w:1)
+	/// Proof: GovernanceOriginRegistry OriginAddresses (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	fn set_origin_address() -> Weight {
+		Weight::from_parts(39_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3502))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
@@ -179,6 +179,8 @@ impl<T: frame_system::Config> pallet_moonbeam_orbiters::WeightInfo for WeightInf
 	/// Proof Skipped: MoonbeamOrbiters OrbiterPerRound (max_values: None, max_size: None, mode: Measured)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: MoonbeamOrbiters OrbiterAudit (r:1 w:1)
+	/// Proof Skipped: MoonbeamOrbiters OrbiterAudit (max_values: None, max_size: None, mode: Measured)
 	fn distribute_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `260`
@@ -186,8 +188,8 @@ impl<T: frame_system::Config> pallet_moonbeam_orbiters::WeightInfo for WeightInf
 		// Minimum execution time: 14_098_000 picoseconds.
 		Weight::from_parts(14_656_000, 0)
 			.saturating_add(Weight::from_parts(0, 3725))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: MoonbeamOrbiters ForceRotation (r:1 w:1)
 	/// Proof Skipped: MoonbeamOrbiters ForceRotation (max_values: Some(1), max_size: None, mode: Measured)
@@ -199,6 +201,8 @@ impl<T: frame_system::Config> pallet_moonbeam_orbiters::WeightInfo for WeightInf
 	/// Proof Skipped: MoonbeamOrbiters AccountLookupOverride (max_values: None, max_size: None, mode: Measured)
 	/// Storage: MoonbeamOrbiters CurrentRound (r:0 w:1)
 	/// Proof Skipped: MoonbeamOrbiters CurrentRound (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: MoonbeamOrbiters OrbiterAudit (r:1 w:1)
+	/// Proof Skipped: MoonbeamOrbiters OrbiterAudit (max_values: None, max_size: None, mode: Measured)
 	fn on_new_round() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `218`
@@ -206,7 +210,7 @@ impl<T: frame_system::Config> pallet_moonbeam_orbiters::WeightInfo for WeightInf
 		// Minimum execution time: 16_840_000 picoseconds.
 		Weight::from_parts(17_649_000, 0)
 			.saturating_add(Weight::from_parts(0, 6158))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(9))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 }
@@ -0,0 +1,84 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+//! Placeholder weights for `pallet_precompile_benchmarks`
+//!
+//! These weights have not been benchmarked yet. They are hand-written, deliberately
+//! conservative estimates whose proof sizes follow the storage bounds; regenerate them
+//! with `moonbeam benchmark pallet` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_precompile_benchmarks`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_precompile_benchmarks::WeightInfo for WeightInfo<T> {
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(174), added: 2649, mode: MaxEncodedLen)
+	fn precompile_registry_is_precompile() -> Weight {
+		Weight::from_parts(15_000_000, 3639)
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(174), added: 2649, mode: MaxEncodedLen)
+	fn precompile_registry_is_active_precompile() -> Weight {
+		Weight::from_parts(15_000_000, 3639)
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(174), added: 2649, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodes (r:1 w:1)
+	/// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodesMetadata (r:0 w:1)
+	/// Proof Skipped: EVM AccountCodesMetadata (max_values: None, max_size: None, mode: Measured)
+	fn precompile_registry_update_account_code() -> Weight {
+		Weight::from_parts(51_000_000, 3639)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: PrecompileVersions PinnedVersions (r:1 w:0)
+	/// Proof: PrecompileVersions PinnedVersions (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: PrecompileWarmKeys WarmKeys (r:1 w:1)
+	/// Proof: PrecompileWarmKeys WarmKeys (max_values: Some(1), max_size: Some(8194), added: 8689, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:0)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 100]`.
+	fn parachain_staking_is_candidate(n: u32, ) -> Weight {
+		Weight::from_parts(19_000_000, 9679)
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: PrecompileVersions PinnedVersions (r:1 w:0)
+	/// Proof: PrecompileVersions PinnedVersions (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: PrecompileWarmKeys WarmKeys (r:1 w:1)
+	/// Proof: PrecompileWarmKeys WarmKeys (max_values: Some(1), max_size: Some(8194), added: 8689, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 100]`.
+	fn parachain_staking_is_delegator(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 9679)
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
@@ -0,0 +1,79 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+//! Placeholder weights for `pallet_price_oracle`
+//!
+//! These weights have not been benchmarked yet. They are hand-written, deliberately
+//! conservative estimates whose proof sizes follow the storage bounds; regenerate them
+//! with `moonbeam benchmark pallet` before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_price_oracle`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_price_oracle::WeightInfo for WeightInfo<T> {
+	/// Storage: PriceOracle Feeds (r:1 w:1)
+	/// Proof: PriceOracle Feeds (max_values: None, max_size: Some(3283), added: 5758, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn create_feed(x: u32, ) -> Weight {
+		Weight::from_parts(33_000_000, 6748)
+			.saturating_add(Weight::from_parts(170_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: PriceOracle Feeds (r:1 w:1)
+	/// Proof: PriceOracle Feeds (max_values: None, max_size: Some(3283), added: 5758, mode: MaxEncodedLen)
+	/// Storage: PriceOracle PendingRounds (r:0 w:1)
+	/// Proof: PriceOracle PendingRounds (max_values: None, max_size: Some(1233), added: 3708, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn set_feeders(x: u32, ) -> Weight {
+		Weight::from_parts(38_000_000, 6748)
+			.saturating_add(Weight::from_parts(160_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: PriceOracle Feeds (r:1 w:1)
+	/// Proof: PriceOracle Feeds (max_values: None, max_size: Some(3283), added: 5758, mode: MaxEncodedLen)
+	/// Storage: PriceOracle LatestRound (r:0 w:1)
+	/// Proof: PriceOracle LatestRound (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: PriceOracle PendingRounds (r:0 w:1)
+	/// Proof: PriceOracle PendingRounds (max_values: None, max_size: Some(1233), added: 3708, mode: MaxEncodedLen)
+	fn remove_feed() -> Weight {
+		Weight::from_parts(44_000_000, 6748)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: PriceOracle Feeds (r:1 w:0)
+	/// Proof: PriceOracle Feeds (max_values: None, max_size: Some(3283), added: 5758, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: PriceOracle PendingRounds (r:1 w:1)
+	/// Proof: PriceOracle PendingRounds (max_values: None, max_size: Some(1233), added: 3708, mode: MaxEncodedLen)
+	/// Storage: PriceOracle LatestRound (r:1 w:1)
+	/// Proof: PriceOracle LatestRound (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn submit(x: u32, ) -> Weight {
+		Weight::from_parts(56_000_000, 6748)
+			.saturating_add(Weight::from_parts(830_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
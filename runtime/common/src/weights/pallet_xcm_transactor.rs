@@ -47,26 +47,29 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_xcm_transactor::WeightInfo for WeightInfo<T> {
 	/// Storage: XcmTransactor IndexToAccount (r:1 w:1)
 	/// Proof Skipped: XcmTransactor IndexToAccount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: XcmTransactor IndexDeposit (r:0 w:1)
+	/// Proof Skipped: XcmTransactor IndexDeposit (max_values: None, max_size: None, mode: Measured)
 	fn register() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `3541`
-		// Minimum execution time: 6_573_000 picoseconds.
-		Weight::from_parts(6_939_000, 0)
-			.saturating_add(Weight::from_parts(0, 3541))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(31_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3581))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	/// Storage: XcmTransactor IndexToAccount (r:0 w:1)
+	/// Storage: XcmTransactor IndexToAccount (r:1 w:1)
 	/// Proof Skipped: XcmTransactor IndexToAccount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor IndexDeposit (r:1 w:1)
+	/// Proof Skipped: XcmTransactor IndexDeposit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	fn deregister() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 4_153_000 picoseconds.
-		Weight::from_parts(4_278_000, 0)
-			.saturating_add(Weight::from_parts(0, 0))
-			.saturating_add(T::DbWeight::get().writes(1))
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(29_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3768))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: XcmTransactor TransactInfoWithWeightLimit (r:0 w:1)
 	/// Proof Skipped: XcmTransactor TransactInfoWithWeightLimit (max_values: None, max_size: None, mode: Measured)
@@ -193,4 +196,68 @@ impl<T: frame_system::Config> pallet_xcm_transactor::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactInfoWithWeightLimit (r:1 w:0)
+	/// Proof Skipped: XcmTransactor TransactInfoWithWeightLimit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor DestinationAssetFeePerSecond (r:1 w:0)
+	/// Proof Skipped: XcmTransactor DestinationAssetFeePerSecond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	/// Proof Skipped: PolkadotXcm QueryCounter (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	/// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	/// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	/// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm Queries (r:0 w:1)
+	/// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactStatus (r:0 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatus (max_values: None, max_size: None, mode: Measured)
+	fn transact_through_signed_with_report() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(69_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3898))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: XcmTransactor TransactStatus (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatus (max_values: None, max_size: None, mode: Measured)
+	fn transact_status_notify() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3708))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmTransactor IndexToAccount (r:1 w:1)
+	/// Proof Skipped: XcmTransactor IndexToAccount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor IndexDeposit (r:1 w:0)
+	/// Proof Skipped: XcmTransactor IndexDeposit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn transfer_index() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(39_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6172))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: XcmTransactor IndexToAccount (r:1 w:1)
+	/// Proof Skipped: XcmTransactor IndexToAccount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor IndexDeposit (r:1 w:1)
+	/// Proof Skipped: XcmTransactor IndexDeposit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn release_index() -> Weight {
+		// Placeholder estimate, not benchmarked.
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3768))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
@@ -0,0 +1,42 @@
+// Copyright 2019-2023 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Helpers managing the EVM code stored at the address of XC-20 precompiles.
+//!
+//! Solidity checks that the target of a call has some code, so each XC-20 address holds a small
+//! code that always reverts. It must be inserted when the asset is created and removed when the
+//! asset is destroyed, so that calls to a destroyed asset fail instead of reaching stale state.
+
+use sp_core::H160;
+
+/// Code stored at the address of XC-20 precompiles: `PUSH1 0x00 PUSH1 0x00 REVERT`.
+pub const XC20_REVERT_CODE: [u8; 5] = [0x60, 0x00, 0x60, 0x00, 0xfd];
+
+/// Insert the revert code at `address`, unless it already holds some code.
+/// Returns whether the code was inserted, which makes it safe to call from migrations.
+pub fn insert_revert_code<Runtime: pallet_evm::Config>(address: H160) -> bool {
+	if pallet_evm::AccountCodes::<Runtime>::contains_key(address) {
+		return false;
+	}
+
+	pallet_evm::AccountCodes::<Runtime>::insert(address, XC20_REVERT_CODE.to_vec());
+	true
+}
+
+/// Remove the code at `address`. This does nothing if there is no code at this address.
+pub fn remove_revert_code<Runtime: pallet_evm::Config>(address: H160) {
+	pallet_evm::AccountCodes::<Runtime>::remove(address);
+}
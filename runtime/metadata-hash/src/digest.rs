@@ -0,0 +1,507 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Metadata digest of RFC-0078, computed from the V14 metadata served by the runtime.
+//!
+//! The types reachable from the extrinsic are converted to the types of the RFC, each variant of
+//! an enumeration being a type of its own, and are the leaves of a merkle tree. The root of the
+//! tree, the hash of the extrinsic metadata and the chain specific information make the digest,
+//! whose hash is the metadata hash.
+
+use frame_metadata::{
+	v14::{ExtrinsicMetadata, SignedExtensionMetadata},
+	RuntimeMetadata,
+};
+use parity_scale_codec::{Compact, Encode};
+use scale_info::{
+	form::PortableForm, interner::UntrackedSymbol, Field, PortableRegistry, Type, TypeDef,
+	TypeDefPrimitive, Variant,
+};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+type Hash = [u8; 32];
+
+fn blake3(data: &[u8]) -> Hash {
+	blake3::hash(data).into()
+}
+
+/// Chain specific information included in the digest.
+#[derive(Clone, Debug)]
+pub struct ExtraInfo {
+	/// Spec version of the runtime.
+	pub spec_version: u32,
+	/// Spec name of the runtime.
+	pub spec_name: String,
+	/// SS58 prefix of the addresses.
+	pub base58_prefix: u16,
+	/// Decimals of the native token.
+	pub decimals: u8,
+	/// Symbol of the native token.
+	pub token_symbol: String,
+}
+
+/// Reference to a type of the digest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode)]
+enum TypeRef {
+	#[codec(index = 0)]
+	Bool,
+	#[codec(index = 1)]
+	Char,
+	#[codec(index = 2)]
+	Str,
+	#[codec(index = 3)]
+	U8,
+	#[codec(index = 4)]
+	U16,
+	#[codec(index = 5)]
+	U32,
+	#[codec(index = 6)]
+	U64,
+	#[codec(index = 7)]
+	U128,
+	#[codec(index = 8)]
+	U256,
+	#[codec(index = 9)]
+	I8,
+	#[codec(index = 10)]
+	I16,
+	#[codec(index = 11)]
+	I32,
+	#[codec(index = 12)]
+	I64,
+	#[codec(index = 13)]
+	I128,
+	#[codec(index = 14)]
+	I256,
+	#[codec(index = 15)]
+	CompactU8,
+	#[codec(index = 16)]
+	CompactU16,
+	#[codec(index = 17)]
+	CompactU32,
+	#[codec(index = 18)]
+	CompactU64,
+	#[codec(index = 19)]
+	CompactU128,
+	#[codec(index = 20)]
+	CompactU256,
+	#[codec(index = 21)]
+	Void,
+	#[codec(index = 22)]
+	ById(Compact<u32>),
+}
+
+/// Definition of a type of the digest.
+#[derive(Clone, Debug, Encode)]
+enum DigestTypeDef {
+	#[codec(index = 0)]
+	Composite(Vec<DigestField>),
+	#[codec(index = 1)]
+	Enumeration(DigestVariant),
+	#[codec(index = 2)]
+	Sequence(TypeRef),
+	#[codec(index = 3)]
+	Array { len: u32, type_param: TypeRef },
+	#[codec(index = 4)]
+	Tuple(Vec<TypeRef>),
+	#[codec(index = 5)]
+	BitSequence {
+		num_bytes: u8,
+		least_significant_bit_first: bool,
+	},
+}
+
+#[derive(Clone, Debug, Encode)]
+struct DigestField {
+	name: Option<String>,
+	ty: TypeRef,
+	type_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Encode)]
+struct DigestVariant {
+	name: String,
+	fields: Vec<DigestField>,
+	index: Compact<u32>,
+}
+
+/// Type of the digest, a leaf of the merkle tree.
+#[derive(Clone, Debug, Encode)]
+struct DigestType {
+	path: Vec<String>,
+	type_def: DigestTypeDef,
+	type_id: Compact<u32>,
+}
+
+#[derive(Encode)]
+struct DigestExtrinsicMetadata {
+	version: u8,
+	address_ty: TypeRef,
+	call_ty: TypeRef,
+	signature_ty: TypeRef,
+	signed_extensions: Vec<DigestSignedExtension>,
+}
+
+#[derive(Encode)]
+struct DigestSignedExtension {
+	identifier: String,
+	included_in_extrinsic: TypeRef,
+	included_in_signed_data: TypeRef,
+}
+
+/// Digest whose hash is the metadata hash. Index 0 is the disabled digest of the RFC.
+#[derive(Encode)]
+enum MetadataDigest {
+	#[codec(index = 1)]
+	V1 {
+		types_tree_root: Hash,
+		extrinsic_metadata_hash: Hash,
+		spec_version: u32,
+		spec_name: String,
+		base58_prefix: u16,
+		decimals: u8,
+		token_symbol: String,
+	},
+}
+
+/// Compute the metadata hash of the given V14 metadata.
+pub fn generate_metadata_hash(
+	metadata: &RuntimeMetadata,
+	extra_info: ExtraInfo,
+) -> Result<Hash, String> {
+	let RuntimeMetadata::V14(metadata) = metadata else {
+		return Err("Only supports metadata V14".into());
+	};
+	let registry = &metadata.types;
+	let extrinsic = &metadata.extrinsic;
+
+	// The types of the address, call and signature are the parameters of the extrinsic type.
+	let extrinsic_param = |name: &str| {
+		registry
+			.resolve(extrinsic.ty.id)
+			.and_then(|ty| ty.type_params.iter().find(|param| param.name == name))
+			.and_then(|param| param.ty)
+			.ok_or_else(|| format!("Extrinsic type has no `{}` parameter", name))
+	};
+	let address_ty = extrinsic_param("Address")?;
+	let call_ty = extrinsic_param("Call")?;
+	let signature_ty = extrinsic_param("Signature")?;
+
+	let mut accessible = BTreeSet::new();
+	for ty in [address_ty.id, call_ty.id, signature_ty.id] {
+		collect_accessible_types(ty, &mut accessible, registry)?;
+	}
+	for extension in &extrinsic.signed_extensions {
+		collect_accessible_types(extension.ty.id, &mut accessible, registry)?;
+		collect_accessible_types(extension.additional_signed.id, &mut accessible, registry)?;
+	}
+
+	// Types only referenced inline, such as primitives, get no id.
+	let ids: BTreeMap<u32, u32> = accessible
+		.iter()
+		.filter(|id| registry.resolve(**id).map_or(false, is_basic_type))
+		.enumerate()
+		.map(|(new_id, id)| (*id, new_id as u32))
+		.collect();
+	let context = Context {
+		registry,
+		ids: &ids,
+	};
+
+	let mut leaves = Vec::new();
+	for (frame_id, id) in &ids {
+		for mut ty in context.as_digest_types(context.get(*frame_id)?)? {
+			ty.type_id = (*id).into();
+			leaves.push(ty);
+		}
+	}
+
+	let extrinsic_metadata =
+		context.as_digest_extrinsic(extrinsic, [address_ty, call_ty, signature_ty])?;
+
+	let digest = MetadataDigest::V1 {
+		types_tree_root: merkle_root(leaves.iter().map(|ty| blake3(&ty.encode())).collect()),
+		extrinsic_metadata_hash: blake3(&extrinsic_metadata.encode()),
+		spec_version: extra_info.spec_version,
+		spec_name: extra_info.spec_name,
+		base58_prefix: extra_info.base58_prefix,
+		decimals: extra_info.decimals,
+		token_symbol: extra_info.token_symbol,
+	};
+
+	Ok(blake3(&digest.encode()))
+}
+
+/// Root of the complete binary tree whose leaves are the given hashes, in order.
+fn merkle_root(leaves: VecDeque<Hash>) -> Hash {
+	let mut nodes = leaves;
+	while nodes.len() > 1 {
+		let right = nodes.pop_back().expect("there is more than one node");
+		let left = nodes.pop_back().expect("there is more than one node");
+		nodes.push_front(blake3(&(left, right).encode()));
+	}
+	nodes.pop_back().unwrap_or_default()
+}
+
+fn collect_accessible_types(
+	id: u32,
+	accessible: &mut BTreeSet<u32>,
+	registry: &PortableRegistry,
+) -> Result<(), String> {
+	if !accessible.insert(id) {
+		return Ok(());
+	}
+
+	let ty = registry
+		.resolve(id)
+		.ok_or_else(|| format!("Could not find type with id `{}` in the registry", id))?;
+
+	match &ty.type_def {
+		TypeDef::Composite(c) => c
+			.fields
+			.iter()
+			.try_for_each(|f| collect_accessible_types(f.ty.id, accessible, registry)),
+		TypeDef::Variant(v) => v.variants.iter().try_for_each(|v| {
+			v.fields
+				.iter()
+				.try_for_each(|f| collect_accessible_types(f.ty.id, accessible, registry))
+		}),
+		TypeDef::Sequence(s) => collect_accessible_types(s.type_param.id, accessible, registry),
+		TypeDef::Array(a) => collect_accessible_types(a.type_param.id, accessible, registry),
+		TypeDef::Tuple(t) => t
+			.fields
+			.iter()
+			.try_for_each(|t| collect_accessible_types(t.id, accessible, registry)),
+		// Primitives and compacts are inlined, and the store and order of bit sequences are
+		// described by the bit sequence itself.
+		TypeDef::Primitive(_) | TypeDef::Compact(_) | TypeDef::BitSequence(_) => Ok(()),
+	}
+}
+
+/// Whether the type is a type of the digest, rather than inlined or void.
+fn is_basic_type(ty: &Type<PortableForm>) -> bool {
+	match &ty.type_def {
+		TypeDef::Compact(_) | TypeDef::Primitive(_) => false,
+		TypeDef::Variant(v) => !v.variants.is_empty(),
+		TypeDef::Composite(c) => !c.fields.is_empty(),
+		TypeDef::Tuple(t) => !t.fields.is_empty(),
+		_ => true,
+	}
+}
+
+#[derive(Clone, Copy)]
+struct Context<'a> {
+	registry: &'a PortableRegistry,
+	ids: &'a BTreeMap<u32, u32>,
+}
+
+impl<'a> Context<'a> {
+	fn get(&self, id: u32) -> Result<&'a Type<PortableForm>, String> {
+		self.registry
+			.resolve(id)
+			.ok_or_else(|| format!("Could not find type for id `{}`", id))
+	}
+
+	/// Primitives found in the type, which compacts and bit sequence stores are made of.
+	fn primitives(&self, id: u32) -> Result<Vec<TypeDefPrimitive>, String> {
+		let mut found = Vec::new();
+		let mut visited = BTreeSet::new();
+		let mut pending = vec![id];
+		while let Some(id) = pending.pop() {
+			if !visited.insert(id) {
+				continue;
+			}
+			match &self.get(id)?.type_def {
+				TypeDef::Primitive(p) => found.push(p.clone()),
+				TypeDef::Composite(c) => pending.extend(c.fields.iter().map(|f| f.ty.id)),
+				TypeDef::Variant(v) => pending.extend(
+					v.variants
+						.iter()
+						.flat_map(|v| v.fields.iter().map(|f| f.ty.id)),
+				),
+				TypeDef::Sequence(s) => pending.push(s.type_param.id),
+				TypeDef::Array(a) => pending.push(a.type_param.id),
+				TypeDef::Tuple(t) => pending.extend(t.fields.iter().map(|t| t.id)),
+				TypeDef::Compact(c) => pending.push(c.type_param.id),
+				TypeDef::BitSequence(b) => {
+					pending.push(b.bit_order_type.id);
+					pending.push(b.bit_store_type.id);
+				}
+			}
+		}
+		Ok(found)
+	}
+
+	fn as_type_ref(&self, symbol: &UntrackedSymbol<std::any::TypeId>) -> Result<TypeRef, String> {
+		Ok(match &self.get(symbol.id)?.type_def {
+			TypeDef::Primitive(p) => match p {
+				TypeDefPrimitive::Bool => TypeRef::Bool,
+				TypeDefPrimitive::Char => TypeRef::Char,
+				TypeDefPrimitive::Str => TypeRef::Str,
+				TypeDefPrimitive::U8 => TypeRef::U8,
+				TypeDefPrimitive::U16 => TypeRef::U16,
+				TypeDefPrimitive::U32 => TypeRef::U32,
+				TypeDefPrimitive::U64 => TypeRef::U64,
+				TypeDefPrimitive::U128 => TypeRef::U128,
+				TypeDefPrimitive::U256 => TypeRef::U256,
+				TypeDefPrimitive::I8 => TypeRef::I8,
+				TypeDefPrimitive::I16 => TypeRef::I16,
+				TypeDefPrimitive::I32 => TypeRef::I32,
+				TypeDefPrimitive::I64 => TypeRef::I64,
+				TypeDefPrimitive::I128 => TypeRef::I128,
+				TypeDefPrimitive::I256 => TypeRef::I256,
+			},
+			TypeDef::Compact(_) => match self.primitives(symbol.id)?[..] {
+				[] => TypeRef::Void,
+				[TypeDefPrimitive::U8] => TypeRef::CompactU8,
+				[TypeDefPrimitive::U16] => TypeRef::CompactU16,
+				[TypeDefPrimitive::U32] => TypeRef::CompactU32,
+				[TypeDefPrimitive::U64] => TypeRef::CompactU64,
+				[TypeDefPrimitive::U128] => TypeRef::CompactU128,
+				[TypeDefPrimitive::U256] => TypeRef::CompactU256,
+				ref found => return Err(format!("Unsupported `Compact` of {:?}", found)),
+			},
+			TypeDef::Variant(v) if v.variants.is_empty() => TypeRef::Void,
+			TypeDef::Composite(c) if c.fields.is_empty() => TypeRef::Void,
+			TypeDef::Tuple(t) if t.fields.is_empty() => TypeRef::Void,
+			_ => TypeRef::ById(
+				(*self
+					.ids
+					.get(&symbol.id)
+					.ok_or_else(|| format!("Type `{}` is not accessible", symbol.id))?)
+				.into(),
+			),
+		})
+	}
+
+	fn as_digest_field(&self, field: &Field<PortableForm>) -> Result<DigestField, String> {
+		Ok(DigestField {
+			name: field.name.clone(),
+			ty: self.as_type_ref(&field.ty)?,
+			type_name: field.type_name.clone(),
+		})
+	}
+
+	fn as_digest_variant(&self, variant: &Variant<PortableForm>) -> Result<DigestVariant, String> {
+		Ok(DigestVariant {
+			name: variant.name.clone(),
+			fields: variant
+				.fields
+				.iter()
+				.map(|f| self.as_digest_field(f))
+				.collect::<Result<_, _>>()?,
+			index: (variant.index as u32).into(),
+		})
+	}
+
+	/// Types of the digest of the given type, one per variant for an enumeration.
+	fn as_digest_types(&self, ty: &Type<PortableForm>) -> Result<Vec<DigestType>, String> {
+		let path = ty.path.segments.clone();
+		let type_def = match &ty.type_def {
+			_ if !is_basic_type(ty) => return Ok(Vec::new()),
+			TypeDef::Variant(v) => {
+				let mut variants: Vec<_> = v.variants.iter().collect();
+				variants.sort_by_key(|v| v.index);
+
+				return variants
+					.into_iter()
+					.map(|v| {
+						Ok(DigestType {
+							path: path.clone(),
+							type_def: DigestTypeDef::Enumeration(self.as_digest_variant(v)?),
+							type_id: 0u32.into(),
+						})
+					})
+					.collect();
+			}
+			TypeDef::Composite(c) => DigestTypeDef::Composite(
+				c.fields
+					.iter()
+					.map(|f| self.as_digest_field(f))
+					.collect::<Result<_, _>>()?,
+			),
+			TypeDef::Sequence(s) => DigestTypeDef::Sequence(self.as_type_ref(&s.type_param)?),
+			TypeDef::Array(a) => DigestTypeDef::Array {
+				len: a.len,
+				type_param: self.as_type_ref(&a.type_param)?,
+			},
+			TypeDef::Tuple(t) => DigestTypeDef::Tuple(
+				t.fields
+					.iter()
+					.map(|t| self.as_type_ref(t))
+					.collect::<Result<_, _>>()?,
+			),
+			TypeDef::BitSequence(b) => {
+				let num_bytes = match self.primitives(b.bit_store_type.id)?[..] {
+					[TypeDefPrimitive::U8] => 1,
+					[TypeDefPrimitive::U16] => 2,
+					[TypeDefPrimitive::U32] => 4,
+					[TypeDefPrimitive::U64] => 8,
+					ref found => {
+						return Err(format!("Unsupported `BitSequence` store {:?}", found))
+					}
+				};
+				let order = self
+					.get(b.bit_order_type.id)?
+					.path
+					.segments
+					.iter()
+					.find(|segment| *segment == "Lsb0" || *segment == "Msb0")
+					.ok_or("`BitSequence` order should be either `Lsb0` or `Msb0`")?;
+				DigestTypeDef::BitSequence {
+					num_bytes,
+					least_significant_bit_first: order == "Lsb0",
+				}
+			}
+			TypeDef::Primitive(_) | TypeDef::Compact(_) => return Ok(Vec::new()),
+		};
+
+		Ok(vec![DigestType {
+			path,
+			type_def,
+			type_id: 0u32.into(),
+		}])
+	}
+
+	fn as_digest_extrinsic(
+		&self,
+		extrinsic: &ExtrinsicMetadata<PortableForm>,
+		[address_ty, call_ty, signature_ty]: [UntrackedSymbol<std::any::TypeId>; 3],
+	) -> Result<DigestExtrinsicMetadata, String> {
+		Ok(DigestExtrinsicMetadata {
+			version: extrinsic.version,
+			address_ty: self.as_type_ref(&address_ty)?,
+			call_ty: self.as_type_ref(&call_ty)?,
+			signature_ty: self.as_type_ref(&signature_ty)?,
+			signed_extensions: extrinsic
+				.signed_extensions
+				.iter()
+				.map(|extension| self.as_digest_signed_extension(extension))
+				.collect::<Result<_, _>>()?,
+		})
+	}
+
+	fn as_digest_signed_extension(
+		&self,
+		extension: &SignedExtensionMetadata<PortableForm>,
+	) -> Result<DigestSignedExtension, String> {
+		Ok(DigestSignedExtension {
+			identifier: extension.identifier.clone(),
+			included_in_extrinsic: self.as_type_ref(&extension.ty)?,
+			included_in_signed_data: self.as_type_ref(&extension.additional_signed)?,
+		})
+	}
+}
@@ -0,0 +1,166 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Build-time generation of the metadata hash of a runtime, as defined by
+//! [RFC-0078](https://polkadot-fellows.github.io/RFCs/approved/0078-merkleized-metadata.html).
+//!
+//! Offline signers (e.g. the Polkadot generic Ledger app) sign transactions against a shortened
+//! metadata, and include its hash in the signed payload through the `CheckMetadataHash` signed
+//! extension. The runtime must know the hash of its own metadata to check it, which requires
+//! building the runtime twice: once to extract its metadata, and once more with the
+//! `RUNTIME_METADATA_HASH` environment variable set.
+//!
+//! The hash is computed from the V14 metadata, which is the latest stable metadata served by the
+//! runtime.
+
+mod digest;
+
+pub use digest::{generate_metadata_hash, ExtraInfo};
+
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use parity_scale_codec::Decode;
+use sc_executor::WasmExecutor;
+use sp_core::traits::{CallContext, CodeExecutor, RuntimeCode, WrappedRuntimeCode};
+use std::{
+	env, fs,
+	path::{Path, PathBuf},
+};
+
+/// Name of the environment variable holding the hex encoded metadata hash.
+pub const METADATA_HASH_ENV: &str = "RUNTIME_METADATA_HASH";
+
+/// Build the runtime with `build`, compute the hash of its metadata and build it again with
+/// `RUNTIME_METADATA_HASH` set, for both the wasm and the native runtime.
+///
+/// `token_symbol` and `decimals` describe the native token, as displayed by offline signers.
+pub fn build_with_metadata_hash(build: impl Fn(), token_symbol: &str, decimals: u8) {
+	build();
+
+	// The wasm build is skipped, e.g. with `SKIP_WASM_BUILD`.
+	let Some(wasm) = built_wasm_path() else {
+		return;
+	};
+
+	let hash = metadata_hash(&wasm, token_symbol, decimals);
+	let hash = format!("0x{}", sp_core::hexdisplay::HexDisplay::from(&hash));
+
+	// The wasm is built by a child cargo process, which inherits the environment.
+	env::set_var(METADATA_HASH_ENV, &hash);
+	println!("cargo:rustc-env={}={}", METADATA_HASH_ENV, hash);
+
+	build();
+}
+
+/// Path of the compressed wasm included by `wasm_binary.rs`, if the wasm was built.
+fn built_wasm_path() -> Option<PathBuf> {
+	let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set for build scripts");
+	let wasm_binary = fs::read_to_string(PathBuf::from(out_dir).join("wasm_binary.rs"))
+		.expect("wasm_binary.rs was just written by the wasm builder");
+
+	let start = wasm_binary.find("include_bytes!(\"")? + "include_bytes!(\"".len();
+	let end = start + wasm_binary[start..].find('"')?;
+
+	Some(PathBuf::from(&wasm_binary[start..end]))
+}
+
+/// Compute the metadata hash of the given runtime wasm.
+pub fn metadata_hash(wasm: &Path, token_symbol: &str, decimals: u8) -> [u8; 32] {
+	let code = fs::read(wasm).expect("the wasm was just built");
+	let code = sp_maybe_compressed_blob::decompress(&code, usize::MAX)
+		.expect("the wasm is a valid compressed blob")
+		.into_owned();
+
+	let executor = WasmExecutor::<sp_io::SubstrateHostFunctions>::builder()
+		.with_allow_missing_host_functions(true)
+		.build();
+	let runtime_code = RuntimeCode {
+		code_fetcher: &WrappedRuntimeCode(code.into()),
+		heap_pages: None,
+		hash: vec![1, 2, 3],
+	};
+	let call = |method: &str, data: &[u8]| {
+		executor
+			.call(
+				&mut sp_io::TestExternalities::default().ext(),
+				&runtime_code,
+				method,
+				data,
+				false,
+				CallContext::Offchain,
+			)
+			.0
+			.unwrap_or_else(|e| panic!("{} failed: {:?}", method, e))
+	};
+
+	let metadata = call("Metadata_metadata", &[]);
+	let metadata = Vec::<u8>::decode(&mut &metadata[..]).expect("metadata is correctly encoded");
+	let metadata = RuntimeMetadataPrefixed::decode(&mut &metadata[..])
+		.expect("metadata is correctly encoded")
+		.1;
+
+	let version = call("Core_version", &[]);
+	let version = sp_version::RuntimeVersion::decode(&mut &version[..])
+		.expect("runtime version is correctly encoded");
+
+	runtime_metadata_hash(
+		&metadata,
+		version.spec_version,
+		&version.spec_name,
+		token_symbol,
+		decimals,
+	)
+}
+
+/// Compute the metadata hash of the given runtime metadata, for the given runtime version.
+pub fn runtime_metadata_hash(
+	metadata: &RuntimeMetadata,
+	spec_version: u32,
+	spec_name: &str,
+	token_symbol: &str,
+	decimals: u8,
+) -> [u8; 32] {
+	let extra_info = ExtraInfo {
+		spec_version,
+		spec_name: spec_name.into(),
+		base58_prefix: ss58_prefix(metadata),
+		decimals,
+		token_symbol: token_symbol.into(),
+	};
+
+	generate_metadata_hash(metadata, extra_info).expect("the metadata hash can be generated")
+}
+
+/// SS58 prefix of the runtime, read from the `SS58Prefix` constant of frame_system.
+fn ss58_prefix(metadata: &RuntimeMetadata) -> u16 {
+	let RuntimeMetadata::V14(metadata) = metadata else {
+		panic!("metadata V14 was requested");
+	};
+
+	metadata
+		.pallets
+		.iter()
+		.find(|pallet| pallet.name == "System")
+		.and_then(|pallet| {
+			pallet
+				.constants
+				.iter()
+				.find(|constant| constant.name == "SS58Prefix")
+		})
+		.map(|constant| {
+			u16::decode(&mut &constant.value[..]).expect("SS58Prefix is encoded as u16")
+		})
+		.expect("frame_system exposes its SS58Prefix")
+}
@@ -19,13 +19,16 @@
 
 use super::{
 	currency, governance, xcm_config, AccountId, AssetId, AssetManager, Assets, Balance, Balances,
-	CouncilInstance, LocalAssets, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
+	CouncilInstance, LocalAssets, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, DAYS,
 	FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX, LOCAL_ASSET_PRECOMPILE_ADDRESS_PREFIX,
+	NFT_COLLECTION_PRECOMPILE_ADDRESS_PREFIX,
 };
 
-use moonbeam_runtime_common::weights as moonbeam_weights;
+use moonbeam_runtime_common::{weights as moonbeam_weights, xc20};
+use pallet_evm_precompile_asset_manager::ForeignAssetMetadata;
 use pallet_evm_precompileset_assets_erc20::AccountIdAssetIdConversion;
-use sp_runtime::traits::Hash as THash;
+use pallet_evm_precompileset_uniques_erc721::AccountIdCollectionIdConversion;
+use sp_runtime::traits::{Convert, Hash as THash};
 
 use frame_support::{
 	dispatch::GetDispatchInfo,
@@ -34,7 +37,7 @@ use frame_support::{
 	weights::Weight,
 };
 
-use frame_system::{EnsureNever, EnsureRoot};
+use frame_system::{EnsureNever, EnsureRoot, EnsureSigned};
 use parity_scale_codec::{Compact, Decode, Encode};
 use scale_info::TypeInfo;
 use sp_core::{H160, H256};
@@ -162,14 +165,8 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 			min_balance,
 		)?;
 
-		// TODO uncomment when we feel comfortable
-		/*
 		// The asset has been created. Let's put the revert code in the precompile address
-		let precompile_address = Runtime::asset_id_to_account(ASSET_PRECOMPILE_ADDRESS_PREFIX, asset);
-		pallet_evm::AccountCodes::<Runtime>::insert(
-			precompile_address,
-			vec![0x60, 0x00, 0x60, 0x00, 0xfd],
-		);*/
+		xc20::insert_revert_code::<Runtime>(ForeignAssetPrecompileAddress::convert(asset));
 
 		// Lastly, the metadata
 		Assets::force_set_metadata(
@@ -203,14 +200,10 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 
 		// No metadata needs to be set, as this can be set through regular calls
 
-		// TODO: should we put the revert code?
 		// The asset has been created. Let's put the revert code in the precompile address
 		let precompile_address: H160 =
 			Runtime::asset_id_to_account(LOCAL_ASSET_PRECOMPILE_ADDRESS_PREFIX, asset).into();
-		pallet_evm::AccountCodes::<Runtime>::insert(
-			precompile_address,
-			vec![0x60, 0x00, 0x60, 0x00, 0xfd],
-		);
+		xc20::insert_revert_code::<Runtime>(precompile_address);
 		Ok(())
 	}
 
@@ -220,10 +213,7 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 		Assets::start_destroy(RuntimeOrigin::root(), asset.into())?;
 
 		// We remove the EVM revert code
-		// This does not panick even if there is no code in the address
-		let precompile_address: H160 =
-			Runtime::asset_id_to_account(FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX, asset).into();
-		pallet_evm::AccountCodes::<Runtime>::remove(precompile_address);
+		xc20::remove_revert_code::<Runtime>(ForeignAssetPrecompileAddress::convert(asset));
 		Ok(())
 	}
 
@@ -233,10 +223,9 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 		LocalAssets::start_destroy(RuntimeOrigin::root(), asset.into())?;
 
 		// We remove the EVM revert code
-		// This does not panick even if there is no code in the address
 		let precompile_address: H160 =
 			Runtime::asset_id_to_account(LOCAL_ASSET_PRECOMPILE_ADDRESS_PREFIX, asset).into();
-		pallet_evm::AccountCodes::<Runtime>::remove(precompile_address);
+		xc20::remove_revert_code::<Runtime>(precompile_address);
 		Ok(())
 	}
 
@@ -262,6 +251,14 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 	}
 }
 
+/// Address of the XC-20 precompile of a foreign asset.
+pub struct ForeignAssetPrecompileAddress;
+impl Convert<AssetId, H160> for ForeignAssetPrecompileAddress {
+	fn convert(asset: AssetId) -> H160 {
+		Runtime::asset_id_to_account(FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX, asset).into()
+	}
+}
+
 pub struct LocalAssetIdCreator;
 impl pallet_asset_manager::LocalAssetIdCreator<Runtime> for LocalAssetIdCreator {
 	fn create_asset_id_from_metadata(local_asset_counter: u128) -> AssetId {
@@ -283,6 +280,17 @@ pub struct AssetRegistrarMetadata {
 	pub is_frozen: bool,
 }
 
+impl From<ForeignAssetMetadata> for AssetRegistrarMetadata {
+	fn from(metadata: ForeignAssetMetadata) -> Self {
+		Self {
+			name: metadata.name,
+			symbol: metadata.symbol,
+			decimals: metadata.decimals,
+			is_frozen: false,
+		}
+	}
+}
+
 pub type ForeignAssetModifierOrigin = EitherOfDiverse<
 	EnsureRoot<AccountId>,
 	EitherOfDiverse<
@@ -310,9 +318,19 @@ impl pallet_asset_manager::Config for Runtime {
 	type LocalAssetIdCreator = LocalAssetIdCreator;
 	type Currency = Balances;
 	type LocalAssetDeposit = AssetDeposit;
+	type AssetTypeAliasPeriod = ConstU32<{ DAYS }>;
 	type WeightInfo = moonbeam_weights::pallet_asset_manager::WeightInfo<Runtime>;
 }
 
+impl pallet_xc20_issuance::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type Assets = Assets;
+	type ResetOrigin = ForeignAssetModifierOrigin;
+	type WeightInfo = moonbeam_weights::pallet_xc20_issuance::WeightInfo<Runtime>;
+}
+
 // Instruct how to go from an H160 to an AssetID
 // We just take the lowest 128 bits
 impl AccountIdAssetIdConversion<AccountId, AssetId> for Runtime {
@@ -341,3 +359,63 @@ impl AccountIdAssetIdConversion<AccountId, AssetId> for Runtime {
 		AccountId::from(data)
 	}
 }
+
+/// Identifier of the NFT collections.
+pub type CollectionId = u128;
+
+// NFT collections, exposed as ERC-721 contracts by the NFT collection precompiles. Similar
+// parameters to those of local assets, such that anyone can create a collection.
+parameter_types! {
+	pub const CollectionDeposit: Balance = 100 * currency::UNIT * currency::SUPPLY_FACTOR;
+	pub const ItemDeposit: Balance = currency::deposit(1, 0);
+	pub const UniquesMetadataDepositBase: Balance = currency::deposit(1, 129);
+	pub const UniquesAttributeDepositBase: Balance = currency::deposit(1, 0);
+	pub const UniquesDepositPerByte: Balance = currency::deposit(0, 1);
+}
+
+impl pallet_uniques::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = CollectionId;
+	type ItemId = u128;
+	type Currency = Balances;
+	type ForceOrigin = AssetsForceOrigin;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type Locker = ();
+	type CollectionDeposit = CollectionDeposit;
+	type ItemDeposit = ItemDeposit;
+	type MetadataDepositBase = UniquesMetadataDepositBase;
+	type AttributeDepositBase = UniquesAttributeDepositBase;
+	type DepositPerByte = UniquesDepositPerByte;
+	type StringLimit = ConstU32<128>;
+	type KeyLimit = ConstU32<32>;
+	type ValueLimit = ConstU32<64>;
+	type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
+// Instruct how to go from an H160 to a CollectionId
+// We just take the lowest 128 bits
+impl AccountIdCollectionIdConversion<AccountId, CollectionId> for Runtime {
+	/// The way to convert an account to collectionId is by ensuring that the prefix is 0XFFFFFFFD
+	/// and by taking the lowest 128 bits as the collectionId
+	fn account_to_collection_id(account: AccountId) -> Option<CollectionId> {
+		let h160_account: H160 = account.into();
+		let mut data = [0u8; 16];
+		let (prefix_part, id_part) = h160_account.as_fixed_bytes().split_at(4);
+		if prefix_part == NFT_COLLECTION_PRECOMPILE_ADDRESS_PREFIX {
+			data.copy_from_slice(id_part);
+			Some(u128::from_be_bytes(data))
+		} else {
+			None
+		}
+	}
+
+	// The opposite conversion
+	fn collection_id_to_account(collection_id: CollectionId) -> AccountId {
+		let mut data = [0u8; 20];
+		data[0..4].copy_from_slice(NFT_COLLECTION_PRECOMPILE_ADDRESS_PREFIX);
+		data[4..20].copy_from_slice(&collection_id.to_be_bytes());
+		AccountId::from(data)
+	}
+}
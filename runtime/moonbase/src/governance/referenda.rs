@@ -22,7 +22,7 @@
 
 use super::*;
 use crate::currency::*;
-use frame_support::traits::{EitherOf, MapSuccess};
+use frame_support::traits::{EitherOf, EitherOfDiverse, MapSuccess};
 use frame_system::EnsureRootWithSuccess;
 use moonbeam_runtime_common::weights as moonbeam_weights;
 use sp_runtime::traits::Replace;
@@ -59,19 +59,30 @@ impl pallet_whitelist::Config for Runtime {
 	type WeightInfo = moonbeam_weights::pallet_whitelist::WeightInfo<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
+	// On Moonbase, a single member of the Open Tech Committee can also whitelist calls and dispatch
+	// them, so that the whole OpenGov pipeline can be driven from the whitelist precompile.
 	type WhitelistOrigin = EitherOf<
 		EnsureRootWithSuccess<Self::AccountId, ConstU16<65535>>,
 		MapSuccess<
-			pallet_collective::EnsureProportionAtLeast<
-				Self::AccountId,
-				OpenTechCommitteeInstance,
-				5,
-				9,
+			EitherOfDiverse<
+				pallet_collective::EnsureProportionAtLeast<
+					Self::AccountId,
+					OpenTechCommitteeInstance,
+					5,
+					9,
+				>,
+				pallet_collective::EnsureMember<Self::AccountId, OpenTechCommitteeInstance>,
 			>,
 			Replace<ConstU16<6>>,
 		>,
 	>;
-	type DispatchWhitelistedOrigin = EitherOf<EnsureRoot<Self::AccountId>, WhitelistedCaller>;
+	type DispatchWhitelistedOrigin = EitherOfDiverse<
+		EnsureRoot<Self::AccountId>,
+		EitherOfDiverse<
+			WhitelistedCaller,
+			pallet_collective::EnsureMember<Self::AccountId, OpenTechCommitteeInstance>,
+		>,
+	>;
 	type Preimages = Preimage;
 }
 
@@ -50,7 +50,7 @@ use frame_support::{
 	},
 	weights::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
-		ConstantMultiplier, Weight, WeightToFeeCoefficient, WeightToFeeCoefficients,
+		ConstantMultiplier, Weight, WeightToFee, WeightToFeeCoefficient, WeightToFeeCoefficients,
 		WeightToFeePolynomial,
 	},
 	PalletId,
@@ -64,7 +64,10 @@ pub use moonbeam_core_primitives::{
 	Index, Signature,
 };
 use moonbeam_rpc_primitives_txpool::TxPoolResponse;
-use moonbeam_runtime_common::weights as moonbeam_weights;
+use moonbeam_runtime_common::{
+	fee_adjustment::BlockFullnessFeeAdjustment, weights as moonbeam_weights,
+};
+use pallet_author_filter_schedule::RandomnessSourceKind;
 pub use pallet_author_slot_filter::EligibilityValue;
 use pallet_balances::NegativeImbalance;
 use pallet_ethereum::Call::transact;
@@ -75,7 +78,7 @@ use pallet_evm::{
 	OnChargeEVMTransaction as OnChargeEVMTransactionT, Runner,
 };
 pub use pallet_parachain_staking::{InflationInfo, Range};
-use pallet_transaction_payment::{CurrencyAdapter, Multiplier, TargetedFeeAdjustment};
+use pallet_transaction_payment::{CurrencyAdapter, Multiplier};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_api::impl_runtime_apis;
@@ -91,7 +94,7 @@ use sp_runtime::{
 	transaction_validity::{
 		InvalidTransaction, TransactionSource, TransactionValidity, TransactionValidityError,
 	},
-	ApplyExtrinsicResult, FixedPointNumber, Perbill, Permill, Perquintill,
+	ApplyExtrinsicResult, FixedPointNumber, Perbill, Percent, Permill, Perquintill,
 };
 use sp_std::{
 	convert::{From, Into},
@@ -106,7 +109,7 @@ use nimbus_primitives::CanAuthor;
 mod precompiles;
 pub use precompiles::{
 	MoonbasePrecompiles, PrecompileName, FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX,
-	LOCAL_ASSET_PRECOMPILE_ADDRESS_PREFIX,
+	LOCAL_ASSET_PRECOMPILE_ADDRESS_PREFIX, NFT_COLLECTION_PRECOMPILE_ADDRESS_PREFIX,
 };
 
 use smallvec::smallvec;
@@ -314,14 +317,15 @@ impl pallet_balances::Config for Runtime {
 pub struct DealWithFees<R>(sp_std::marker::PhantomData<R>);
 impl<R> OnUnbalanced<NegativeImbalance<R>> for DealWithFees<R>
 where
-	R: pallet_balances::Config + pallet_treasury::Config,
+	R: pallet_balances::Config + pallet_treasury::Config + pallet_fee_split::Config,
 	pallet_treasury::Pallet<R>: OnUnbalanced<NegativeImbalance<R>>,
 {
 	// this seems to be called for substrate-based transactions
 	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance<R>>) {
 		if let Some(fees) = fees_then_tips.next() {
-			// for fees, 80% are burned, 20% to the treasury
-			let (_, to_treasury) = fees.ration(80, 20);
+			// for fees, the burned share is set by governance, the rest goes to the treasury
+			let (burn, treasury) = pallet_fee_split::Pallet::<R>::ration();
+			let (_, to_treasury) = fees.ration(burn, treasury);
 			// Balances pallet automatically burns dropped Negative Imbalances by decreasing
 			// total_supply accordingly
 			<pallet_treasury::Pallet<R> as OnUnbalanced<_>>::on_unbalanced(to_treasury);
@@ -329,7 +333,7 @@ where
 			// handle tip if there is one
 			if let Some(tip) = fees_then_tips.next() {
 				// for now we use the same burn/treasury strategy used for regular fees
-				let (_, to_treasury) = tip.ration(80, 20);
+				let (_, to_treasury) = tip.ration(burn, treasury);
 				<pallet_treasury::Pallet<R> as OnUnbalanced<_>>::on_unbalanced(to_treasury);
 			}
 		}
@@ -340,11 +344,36 @@ where
 	fn on_nonzero_unbalanced(amount: NegativeImbalance<R>) {
 		// Balances pallet automatically burns dropped Negative Imbalances by decreasing
 		// total_supply accordingly
-		let (_, to_treasury) = amount.ration(80, 20);
+		let (burn, treasury) = pallet_fee_split::Pallet::<R>::ration();
+		let (_, to_treasury) = amount.ration(burn, treasury);
 		<pallet_treasury::Pallet<R> as OnUnbalanced<_>>::on_unbalanced(to_treasury);
 	}
 }
 
+parameter_types! {
+	pub const MinFeeBurnPercent: Percent = Percent::from_percent(20);
+	pub const MaxFeeBurnPercent: Percent = Percent::from_percent(100);
+	pub const DefaultFeeBurnPercent: Percent = Percent::from_percent(80);
+}
+
+impl pallet_fee_split::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type SetOrigin = MonetaryGovernanceOrigin;
+	type MinBurnPercent = MinFeeBurnPercent;
+	type MaxBurnPercent = MaxFeeBurnPercent;
+	/// 80% of the fees are burned, 20% go to the treasury
+	type DefaultBurnPercent = DefaultFeeBurnPercent;
+	type WeightInfo = moonbeam_weights::pallet_fee_split::WeightInfo<Runtime>;
+}
+
+impl pallet_precompile_versions::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+impl pallet_precompile_warm_keys::Config for Runtime {
+	type MaxWarmKeys = ConstU32<256>;
+}
+
 pub struct LengthToFee;
 impl WeightToFeePolynomial for LengthToFee {
 	type Balance = Balance;
@@ -395,8 +424,12 @@ pub const GAS_PER_SECOND: u64 = 40_000_000;
 pub const WEIGHT_PER_GAS: u64 = WEIGHT_REF_TIME_PER_SECOND / GAS_PER_SECOND;
 /// The highest amount of new storage that can be created in a block (40KB).
 pub const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
+/// Block gas limit, capped by the gas equivalent of the proof size available to transactions.
+pub type BlockGasLimit =
+	moonbeam_runtime_common::gas_limit::PovAwareBlockGasLimit<Runtime, RefTimeBlockGasLimit>;
 parameter_types! {
-	pub BlockGasLimit: U256
+	/// The gas limit allowed by the ref time available to transactions.
+	pub RefTimeBlockGasLimit: U256
 		= U256::from(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT.ref_time() / WEIGHT_PER_GAS);
 	/// The portion of the `NORMAL_DISPATCH_RATIO` that we adjust the fees with. Blocks filled less
 	/// than this will decrease the weight and more will increase.
@@ -461,7 +494,10 @@ impl FeeCalculator for TransactionPaymentAsGasPrice {
 ///     where: v is AdjustmentVariable
 ///            target is TargetBlockFullness
 ///            min is MinimumMultiplier
-pub type FastAdjustingFeeUpdate<R> = TargetedFeeAdjustment<
+///
+/// The block weight is taken in the dimension (ref time or proof size) filled the most, so that
+/// blocks filling the PoV also raise the fees.
+pub type FastAdjustingFeeUpdate<R> = BlockFullnessFeeAdjustment<
 	R,
 	TargetBlockFullness,
 	AdjustmentVariable,
@@ -498,7 +534,19 @@ impl pallet_evm::Config for Runtime {
 	type AddressMapping = IdentityAddressMapping;
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
-	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type Runner = pallet_evm_sanctions_filter::SanctionsFilterRunner<
+		Self,
+		pallet_evm_deployer_filter::DeployerFilterRunner<
+			Self,
+			pallet_evm_code_size_limit::CodeSizeLimitRunner<
+				Self,
+				pallet_evm_hibernation::HibernationRunner<
+					Self,
+					pallet_evm::runner::stack::Runner<Self>,
+				>,
+			>,
+		>,
+	>;
 	type PrecompilesType = MoonbasePrecompiles<Self>;
 	type PrecompilesValue = PrecompilesValue;
 	type ChainId = EthereumChainId;
@@ -512,6 +560,125 @@ impl pallet_evm::Config for Runtime {
 	type WeightInfo = moonbeam_weights::pallet_evm::WeightInfo<Runtime>;
 }
 
+type DeployerManagerOrigin =
+	EitherOfDiverse<EnsureRoot<AccountId>, governance::custom_origins::GeneralAdmin>;
+
+impl pallet_evm_deployer_filter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type DeployerManagerOrigin = DeployerManagerOrigin;
+	type WeightInfo = moonbeam_weights::pallet_evm_deployer_filter::WeightInfo<Runtime>;
+}
+
+impl pallet_evm_code_size_limit::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CodeSizeLimitOrigin = DeployerManagerOrigin;
+	type MaxCodeSizeLimit = ConstU32<{ 96 * 1024 }>;
+	type WeightInfo = moonbeam_weights::pallet_evm_code_size_limit::WeightInfo<Runtime>;
+}
+
+impl pallet_evm_sanctions_filter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type SanctionsManagerOrigin = governance::referenda::GeneralAdminOrRoot;
+	type WeightInfo = moonbeam_weights::pallet_evm_sanctions_filter::WeightInfo<Runtime>;
+}
+
+impl pallet_token_route_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RouteManagerOrigin = governance::referenda::GeneralAdminOrRoot;
+	type MaxHops = ConstU32<4>;
+	type WeightInfo = moonbeam_weights::pallet_token_route_registry::WeightInfo<Runtime>;
+}
+
+impl pallet_price_oracle::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type FeedManagerOrigin = governance::referenda::GeneralAdminOrRoot;
+	type MaxFeeders = ConstU32<16>;
+	type MaxDescriptionLength = ConstU32<64>;
+	type Time = Timestamp;
+	type WeightInfo = moonbeam_weights::pallet_price_oracle::WeightInfo<Runtime>;
+}
+
+impl pallet_governance_origin_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RegistrarOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = moonbeam_weights::pallet_governance_origin_registry::WeightInfo<Runtime>;
+}
+
+impl pallet_evm_contract_metadata::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddressMapping = sp_runtime::traits::ConvertInto;
+	type MetadataManagerOrigin = governance::referenda::GeneralAdminOrRoot;
+	type MaxCidLength = ConstU32<128>;
+	type WeightInfo = moonbeam_weights::pallet_evm_contract_metadata::WeightInfo<Runtime>;
+}
+
+impl pallet_evm_operators::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AccountIdToH160 = sp_runtime::traits::ConvertInto;
+	type EvmRunner = <Self as pallet_evm::Config>::Runner;
+	type MaxContractsPerOperator = ConstU32<16>;
+	type WeightInfo = moonbeam_weights::pallet_evm_operators::WeightInfo<Runtime>;
+}
+
+impl pallet_evm_sponsorship::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type EvmRunner = <Self as pallet_evm::Config>::Runner;
+	type OnSponsoredFee = DealWithFees<Runtime>;
+	// Sponsored transactions pay the minimum gas price, like ethereum transactions without tip.
+	type UnsignedPriority = ConstU64<0>;
+	type WeightInfo = moonbeam_weights::pallet_evm_sponsorship::WeightInfo<Runtime>;
+}
+
+/// Calls that accounts holding only an Ethereum key can dispatch with an EIP-712 signature.
+pub struct Eip712DispatchFilter;
+impl Contains<RuntimeCall> for Eip712DispatchFilter {
+	fn contains(c: &RuntimeCall) -> bool {
+		matches!(
+			c,
+			RuntimeCall::ParachainStaking(..)
+				| RuntimeCall::CrowdloanRewards(pallet_crowdloan_rewards::Call::claim { .. })
+		)
+	}
+}
+
+/// Fee of the weight of the calls dispatched with an EIP-712 signature, adjusted by the fee
+/// multiplier like the fee of signed extrinsics.
+pub struct Eip712DispatchWeightToFee;
+impl WeightToFee for Eip712DispatchWeightToFee {
+	type Balance = Balance;
+
+	fn weight_to_fee(weight: &Weight) -> Balance {
+		TransactionPayment::next_fee_multiplier().saturating_mul_int(
+			<Runtime as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(weight),
+		)
+	}
+}
+
+impl pallet_eip712_dispatch::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type CallFilter = Eip712DispatchFilter;
+	type WeightToFee = Eip712DispatchWeightToFee;
+	type LengthToFee = <Runtime as pallet_transaction_payment::Config>::LengthToFee;
+	type OnChargeFee = DealWithFees<Runtime>;
+	// Signed calls have the priority of signed extrinsics without tip.
+	type UnsignedPriority = ConstU64<0>;
+	type WeightInfo = moonbeam_weights::pallet_eip712_dispatch::WeightInfo<Runtime>;
+}
+
+impl pallet_evm_hibernation::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type HibernationManagerOrigin = governance::referenda::GeneralAdminOrRoot;
+	type MaxStorageEntries = ConstU32<1_000>;
+	type WeightInfo = moonbeam_weights::pallet_evm_hibernation::WeightInfo<Runtime>;
+}
+
+impl pallet_precompile_benchmarks::Config for Runtime {
+	type PrecompileRegistryAddress = precompile_utils::precompile_set::AddressU64<2069>;
+	type ParachainStakingAddress = precompile_utils::precompile_set::AddressU64<2048>;
+	type WeightInfo = moonbeam_weights::pallet_precompile_benchmarks::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = NORMAL_DISPATCH_RATIO * RuntimeBlockWeights::get().max_block;
 	pub const NoPreimagePostponement: Option<u32> = Some(10);
@@ -523,7 +690,8 @@ impl pallet_scheduler::Config for Runtime {
 	type PalletsOrigin = OriginCaller;
 	type RuntimeCall = RuntimeCall;
 	type MaximumWeight = MaximumSchedulerWeight;
-	type ScheduleOrigin = EnsureRoot<AccountId>;
+	// The general admin schedules calls through the scheduler precompile
+	type ScheduleOrigin = governance::referenda::GeneralAdminOrRoot;
 	type MaxScheduledPerBlock = ConstU32<50>;
 	type WeightInfo = moonbeam_weights::pallet_scheduler::WeightInfo<Runtime>;
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
@@ -693,6 +861,7 @@ pub struct OnNewRound;
 impl pallet_parachain_staking::OnNewRound for OnNewRound {
 	fn on_new_round(round_index: pallet_parachain_staking::RoundIndex) -> Weight {
 		MoonbeamOrbiters::on_new_round(round_index)
+			.saturating_add(AuthorFilterSchedule::on_new_round(round_index))
 	}
 }
 pub struct PayoutCollatorOrOrbiterReward;
@@ -714,6 +883,11 @@ impl pallet_parachain_staking::PayoutCollatorReward<Runtime> for PayoutCollatorO
 	}
 }
 
+parameter_types! {
+	/// Maximum weight spent executing due delegation revocations, 5% of the block
+	pub const MaxExitQueueWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(20);
+}
+
 type MonetaryGovernanceOrigin =
 	EitherOfDiverse<EnsureRoot<AccountId>, governance::custom_origins::GeneralAdmin>;
 
@@ -745,6 +919,8 @@ impl pallet_parachain_staking::Config for Runtime {
 	type MaxDelegationsPerDelegator = ConstU32<100>;
 	/// Minimum stake required to be reserved to be a candidate
 	type MinCandidateStk = ConstU128<{ 500 * currency::UNIT * currency::SUPPLY_FACTOR }>;
+	/// Rounds candidates below a raised minimum stake have to top it up
+	type MinCandidateStkGracePeriod = ConstU32<2>;
 	/// Minimum stake required to be reserved to be a delegator
 	type MinDelegation = ConstU128<{ 1 * currency::UNIT * currency::SUPPLY_FACTOR }>;
 	type BlockAuthor = AuthorInherent;
@@ -753,6 +929,9 @@ impl pallet_parachain_staking::Config for Runtime {
 	type OnNewRound = OnNewRound;
 	type WeightInfo = moonbeam_weights::pallet_parachain_staking::WeightInfo<Runtime>;
 	type MaxCandidates = ConstU32<200>;
+	type MaxExitQueueWeight = MaxExitQueueWeight;
+	/// Maximum delegation switches per delegator per round
+	type MaxDelegationSwitchesPerRound = ConstU32<2>;
 }
 
 impl pallet_author_inherent::Config for Runtime {
@@ -765,11 +944,45 @@ impl pallet_author_inherent::Config for Runtime {
 
 impl pallet_author_slot_filter::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type RandomnessSource = Randomness;
+	type RandomnessSource = AuthorFilterSchedule;
 	type PotentialAuthors = ParachainStaking;
 	type WeightInfo = moonbeam_weights::pallet_author_slot_filter::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	/// Randomness source of the author filter.
+	pub const AuthorFilterRandomness: RandomnessSourceKind = RandomnessSourceKind::RelayBabe;
+}
+
+/// BABE randomness of the previous relay chain epoch, as found in the relay chain state proof.
+pub struct RelayEpochRandomness;
+impl Get<Option<Hash>> for RelayEpochRandomness {
+	fn get() -> Option<Hash> {
+		use pallet_randomness::GetBabeData;
+		BabeDataGetter::get_epoch_randomness()
+	}
+}
+
+/// Current round of the parachain staking.
+pub struct StakingRound;
+impl Get<pallet_parachain_staking::RoundIndex> for StakingRound {
+	fn get() -> pallet_parachain_staking::RoundIndex {
+		ParachainStaking::round().current
+	}
+}
+
+impl pallet_author_filter_schedule::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ScheduleOrigin = governance::referenda::GeneralAdminOrRoot;
+	type CurrentRound = StakingRound;
+	/// Eligibility changes can't be applied before the round after the next one
+	type MinActivationDelay = ConstU32<2>;
+	type FilterRandomness = AuthorFilterRandomness;
+	type RelayEpochRandomness = RelayEpochRandomness;
+	type LocalRandomness = Randomness;
+	type WeightInfo = moonbeam_weights::pallet_author_filter_schedule::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const InitializationPayment: Perbill = Perbill::from_percent(30);
 	pub const RelaySignaturesThreshold: Perbill = Perbill::from_percent(100);
@@ -827,6 +1040,8 @@ pub enum ProxyType {
 	AuthorMapping = 6,
 	/// Allow extrinsic related to IdentityJudgement.
 	IdentityJudgement = 7,
+	/// Allow extrinsic related to ConvictionVoting.
+	ConvictionVoting = 8,
 }
 
 impl Default for ProxyType {
@@ -845,10 +1060,91 @@ fn is_governance_precompile(precompile_name: &precompiles::PrecompileName) -> bo
 			| PrecompileName::ReferendaPrecompile
 			| PrecompileName::ConvictionVotingPrecompile
 			| PrecompileName::PreimagePrecompile
-			| PrecompileName::OpenTechCommitteeInstance,
+			| PrecompileName::OpenTechCommitteeInstance
+			| PrecompileName::XcmPausePrecompile,
 	)
 }
 
+impl ProxyType {
+	/// Whether a value-less call to the precompile at `address` falls within the scope of this
+	/// proxy type, matching the pallet calls allowed by the substrate filter.
+	fn is_precompile_allowed(&self, address: H160) -> bool {
+		let Some(precompile) = PrecompileName::from_address(address) else {
+			return false;
+		};
+		match self {
+			ProxyType::NonTransfer => {
+				matches!(
+					precompile,
+					PrecompileName::AuthorMappingPrecompile
+						| PrecompileName::IdentityPrecompile
+						| PrecompileName::ParachainStakingPrecompile
+				) || is_governance_precompile(&precompile)
+			}
+			ProxyType::Governance => is_governance_precompile(&precompile),
+			ProxyType::Staking => matches!(
+				precompile,
+				PrecompileName::AuthorMappingPrecompile
+					| PrecompileName::ParachainStakingPrecompile
+			),
+			ProxyType::AuthorMapping => {
+				matches!(precompile, PrecompileName::AuthorMappingPrecompile)
+			}
+			ProxyType::ConvictionVoting => {
+				matches!(precompile, PrecompileName::ConvictionVotingPrecompile)
+			}
+			ProxyType::Any
+			| ProxyType::CancelProxy
+			| ProxyType::Balances
+			| ProxyType::IdentityJudgement => false,
+		}
+	}
+
+	/// Selectors of the precompile at `address` that a proxy of this type may call through
+	/// `proxyPrecompile` of the proxy precompile.
+	fn precompile_selectors(&self, address: H160) -> &'static [u32] {
+		use governance::custom_origins::Origin as GovOrigin;
+		use pallet_evm_precompile_author_mapping::AuthorMappingPrecompileCall;
+		use pallet_evm_precompile_conviction_voting::ConvictionVotingPrecompileCall;
+		use pallet_evm_precompile_parachain_staking::ParachainStakingPrecompileCall;
+		use pallet_evm_precompile_preimage::PreimagePrecompileCall;
+		use pallet_evm_precompile_referenda::ReferendaPrecompileCall;
+
+		let Some(precompile) = PrecompileName::from_address(address) else {
+			return &[];
+		};
+		match (self, precompile) {
+			(
+				ProxyType::Any | ProxyType::NonTransfer | ProxyType::Staking,
+				PrecompileName::ParachainStakingPrecompile,
+			) => ParachainStakingPrecompileCall::<Runtime>::selectors(),
+			(
+				ProxyType::Any
+				| ProxyType::NonTransfer
+				| ProxyType::Staking
+				| ProxyType::AuthorMapping,
+				PrecompileName::AuthorMappingPrecompile,
+			) => AuthorMappingPrecompileCall::<Runtime>::selectors(),
+			(
+				ProxyType::Any
+				| ProxyType::NonTransfer
+				| ProxyType::Governance
+				| ProxyType::ConvictionVoting,
+				PrecompileName::ConvictionVotingPrecompile,
+			) => ConvictionVotingPrecompileCall::<Runtime>::selectors(),
+			(
+				ProxyType::Any | ProxyType::NonTransfer | ProxyType::Governance,
+				PrecompileName::ReferendaPrecompile,
+			) => ReferendaPrecompileCall::<Runtime, GovOrigin>::selectors(),
+			(
+				ProxyType::Any | ProxyType::NonTransfer | ProxyType::Governance,
+				PrecompileName::PreimagePrecompile,
+			) => PreimagePrecompileCall::<Runtime>::selectors(),
+			_ => &[],
+		}
+	}
+}
+
 // Be careful: Each time this filter is modified, the substrate filter must also be modified
 // consistently.
 impl pallet_evm_precompile_proxy::EvmProxyCallFilter for ProxyType {
@@ -860,34 +1156,12 @@ impl pallet_evm_precompile_proxy::EvmProxyCallFilter for ProxyType {
 	) -> precompile_utils::EvmResult<bool> {
 		Ok(match self {
 			ProxyType::Any => true,
-			ProxyType::NonTransfer => {
-				call.value == U256::zero()
-					&& match PrecompileName::from_address(call.to.0) {
-						Some(
-							PrecompileName::AuthorMappingPrecompile
-							| PrecompileName::IdentityPrecompile
-							| PrecompileName::ParachainStakingPrecompile,
-						) => true,
-						Some(ref precompile) if is_governance_precompile(precompile) => true,
-						_ => false,
-					}
-			}
-			ProxyType::Governance => {
-				call.value == U256::zero()
-					&& matches!(
-						PrecompileName::from_address(call.to.0),
-						Some(ref precompile) if is_governance_precompile(precompile)
-					)
-			}
-			ProxyType::Staking => {
-				call.value == U256::zero()
-					&& matches!(
-						PrecompileName::from_address(call.to.0),
-						Some(
-							PrecompileName::AuthorMappingPrecompile
-								| PrecompileName::ParachainStakingPrecompile
-						)
-					)
+			ProxyType::NonTransfer
+			| ProxyType::Governance
+			| ProxyType::Staking
+			| ProxyType::AuthorMapping
+			| ProxyType::ConvictionVoting => {
+				call.value == U256::zero() && self.is_precompile_allowed(call.to.0)
 			}
 			// The proxy precompile does not contain method cancel_proxy
 			ProxyType::CancelProxy => false,
@@ -900,17 +1174,14 @@ impl pallet_evm_precompile_proxy::EvmProxyCallFilter for ProxyType {
 						call.to.0, gas,
 					)?
 			}
-			ProxyType::AuthorMapping => {
-				call.value == U256::zero()
-					&& matches!(
-						PrecompileName::from_address(call.to.0),
-						Some(PrecompileName::AuthorMappingPrecompile)
-					)
-			}
 			// There is no identity precompile
 			ProxyType::IdentityJudgement => false,
 		})
 	}
+
+	fn is_evm_proxy_precompile_call_allowed(&self, precompile: H160, selector: u32) -> bool {
+		self.precompile_selectors(precompile).contains(&selector)
+	}
 }
 
 // Be careful: Each time this filter is modified, the EVM filter must also be modified consistently.
@@ -935,7 +1206,8 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 						| RuntimeCall::OpenTechCommitteeCollective(..)
 						| RuntimeCall::Identity(..)
 						| RuntimeCall::Utility(..)
-						| RuntimeCall::Proxy(..) | RuntimeCall::AuthorMapping(..)
+						| RuntimeCall::Proxy(..)
+						| RuntimeCall::AuthorMapping(..)
 						| RuntimeCall::CrowdloanRewards(
 							pallet_crowdloan_rewards::Call::claim { .. }
 						)
@@ -973,6 +1245,10 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				RuntimeCall::Identity(pallet_identity::Call::provide_judgement { .. })
 					| RuntimeCall::Utility(..)
 			),
+			ProxyType::ConvictionVoting => matches!(
+				c,
+				RuntimeCall::ConvictionVoting(..) | RuntimeCall::Utility(..)
+			),
 		}
 	}
 
@@ -981,6 +1257,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(x, y) if x == y => true,
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
+			(ProxyType::Governance | ProxyType::NonTransfer, ProxyType::ConvictionVoting) => true,
 			_ => false,
 		}
 	}
@@ -1011,18 +1288,25 @@ impl pallet_migrations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	// TODO wire up our correct list of migrations here. Maybe this shouldn't be in
 	// `moonbeam_runtime_common`.
-	type MigrationsList = (
-		moonbeam_runtime_common::migrations::CommonMigrations<
-			Runtime,
-			CouncilCollective,
-			TechCommitteeCollective,
-		>,
-		moonbeam_runtime_common::migrations::ReferendaMigrations<
-			Runtime,
-			CouncilCollective,
-			TechCommitteeCollective,
-		>,
-	);
+	type MigrationsList = moonbeam_runtime_common::migration_tracking::TrackedMigrations<
+		Runtime,
+		(
+			moonbeam_runtime_common::migrations::CommonMigrations<
+				Runtime,
+				CouncilCollective,
+				TechCommitteeCollective,
+			>,
+			moonbeam_runtime_common::migrations::ReferendaMigrations<
+				Runtime,
+				CouncilCollective,
+				TechCommitteeCollective,
+			>,
+			moonbeam_runtime_common::migrations::Xc20Migrations<
+				Runtime,
+				asset_config::ForeignAssetPrecompileAddress,
+			>,
+		),
+	>;
 	type XcmExecutionManager = XcmExecutionManager;
 }
 
@@ -1106,6 +1390,25 @@ impl moonkit_xcm_primitives::PauseXcmExecution for XcmExecutionManager {
 		XcmpQueue::suspend_xcm_execution(RuntimeOrigin::root())
 	}
 	fn resume_xcm_execution() -> DispatchResult {
+		// XCM must stay suspended until the emergency pause is lifted
+		if XcmFilter::is_xcm_paused() {
+			return Ok(());
+		}
+		XcmpQueue::resume_xcm_execution(RuntimeOrigin::root())
+	}
+}
+
+/// Suspends XCM execution for the emergency XCM pause of pallet_xcm_filter.
+pub struct EmergencyXcmExecutionManager;
+impl moonkit_xcm_primitives::PauseXcmExecution for EmergencyXcmExecutionManager {
+	fn suspend_xcm_execution() -> DispatchResult {
+		XcmpQueue::suspend_xcm_execution(RuntimeOrigin::root())
+	}
+	fn resume_xcm_execution() -> DispatchResult {
+		// XCM must stay suspended until the maintenance mode is exited
+		if MaintenanceMode::maintenance_mode() {
+			return Ok(());
+		}
 		XcmpQueue::resume_xcm_execution(RuntimeOrigin::root())
 	}
 }
@@ -1192,6 +1495,20 @@ impl OffchainWorker<BlockNumber> for MaintenanceHooks {
 	}
 }
 
+/// Emits the EVM logs of the freezes of the XC-20s, whatever dispatched them.
+type Xc20FreezeLogs = (
+	pallet_evm_precompileset_assets_erc20::FreezeLogs<
+		Runtime,
+		asset_config::ForeignAssetInstance,
+		precompiles::ForeignAssetPrefix,
+	>,
+	pallet_evm_precompileset_assets_erc20::FreezeLogs<
+		Runtime,
+		asset_config::LocalAssetInstance,
+		precompiles::LocalAssetPrefix,
+	>,
+);
+
 impl pallet_maintenance_mode::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type NormalCallFilter = NormalFilter;
@@ -1202,9 +1519,9 @@ impl pallet_maintenance_mode::Config for Runtime {
 	type NormalDmpHandler = NormalDmpHandler;
 	type MaintenanceDmpHandler = MaintenanceDmpHandler;
 	// We use AllPalletsWithSystem because we dont want to change the hooks in normal
-	// operation
-	type NormalExecutiveHooks = AllPalletsWithSystem;
-	type MaintenanceExecutiveHooks = MaintenanceHooks;
+	// operation, the freeze logs of the XC-20s being emitted after the hooks of the pallets
+	type NormalExecutiveHooks = (AllPalletsWithSystem, Xc20FreezeLogs);
+	type MaintenanceExecutiveHooks = (MaintenanceHooks, Xc20FreezeLogs);
 }
 
 impl pallet_proxy_genesis_companion::Config for Runtime {
@@ -1226,6 +1543,8 @@ impl pallet_moonbeam_orbiters::Config for Runtime {
 	type AddCollatorOrigin = AddCollatorOrigin;
 	type Currency = Balances;
 	type DelCollatorOrigin = DelCollatorOrigin;
+	/// Maximum number of rewarded rounds kept in the audit history of each orbiter
+	type MaxAuditRecords = ConstU32<16>;
 	/// Maximum number of orbiters per collator
 	type MaxPoolSize = ConstU32<8>;
 	/// Maximum number of round to keep on storage
@@ -1372,8 +1691,29 @@ construct_runtime! {
 		OpenTechCommitteeCollective:
 			pallet_collective::<Instance4>::{Pallet, Call, Storage, Event<T>, Origin<T>, Config<T>} = 46,
 		RootTesting: pallet_root_testing::{Pallet, Call, Storage} = 47,
-		Erc20XcmBridge: pallet_erc20_xcm_bridge::{Pallet} = 48,
+		Erc20XcmBridge: pallet_erc20_xcm_bridge::{Pallet, Call, Storage, Event<T>} = 48,
 		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>} = 49,
+		Xc20Issuance: pallet_xc20_issuance::{Pallet, Call, Storage, Event<T>} = 50,
+		XcmFilter: pallet_xcm_filter::{Pallet, Call, Storage, Event<T>} = 51,
+		EvmDeployerFilter: pallet_evm_deployer_filter::{Pallet, Call, Storage, Event<T>} = 52,
+		EvmContractMetadata: pallet_evm_contract_metadata::{Pallet, Call, Storage, Event<T>} = 53,
+		EvmHibernation: pallet_evm_hibernation::{Pallet, Call, Storage, Event<T>} = 54,
+		AuthorFilterSchedule: pallet_author_filter_schedule::{Pallet, Call, Storage, Event<T>} = 55,
+		PrecompileBenchmarks: pallet_precompile_benchmarks::{Pallet} = 56,
+		EvmOperators: pallet_evm_operators::{Pallet, Call, Storage, Event<T>} = 57,
+		EvmSponsorship: pallet_evm_sponsorship::{Pallet, Call, Event<T>, ValidateUnsigned} = 58,
+		FeeSplit: pallet_fee_split::{Pallet, Call, Storage, Event<T>} = 59,
+		PrecompileVersions: pallet_precompile_versions::{Pallet, Storage, Event<T>} = 60,
+		PrecompileWarmKeys: pallet_precompile_warm_keys::{Pallet, Storage} = 61,
+		DemocracyLockMigration: pallet_democracy_lock_migration::{Pallet, Call, Storage, Event<T>} = 62,
+		Eip712Dispatch: pallet_eip712_dispatch::{Pallet, Call, Event<T>, ValidateUnsigned} = 63,
+		GovernanceOriginRegistry: pallet_governance_origin_registry::{Pallet, Call, Storage, Event<T>} = 64,
+		EvmSanctionsFilter: pallet_evm_sanctions_filter::{Pallet, Call, Storage, Event<T>} = 65,
+		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>} = 66,
+		TokenRouteRegistry: pallet_token_route_registry::{Pallet, Call, Storage, Event<T>} = 67,
+		XcmWeightTrader: pallet_xcm_weight_trader::{Pallet, Call, Storage, Event<T>} = 68,
+		PriceOracle: pallet_price_oracle::{Pallet, Call, Storage, Event<T>} = 69,
+		EvmCodeSizeLimit: pallet_evm_code_size_limit::{Pallet, Call, Storage, Event<T>} = 70,
 	}
 }
 
@@ -1394,6 +1734,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	moonbeam_runtime_common::check_metadata_hash::CheckMetadataHash<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -1412,6 +1753,7 @@ pub type Executive = frame_executive::Executive<
 
 #[cfg(feature = "runtime-benchmarks")]
 use {
+	moonbeam_xcm_benchmarks::erc20::Pallet as MoonbeamXcmErc20Bench,
 	moonbeam_xcm_benchmarks::generic::benchmarking as MoonbeamXcmBenchmarks,
 	MoonbeamXcmBenchmarks::XcmGenericBenchmarks as MoonbeamXcmGenericBench,
 };
@@ -1438,8 +1780,28 @@ mod benches {
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 		[pallet_xcm, PolkadotXcm]
 		[pallet_asset_manager, AssetManager]
+		[pallet_uniques, Uniques]
+		[pallet_xc20_issuance, Xc20Issuance]
+		[pallet_xcm_filter, XcmFilter]
+		[pallet_evm_deployer_filter, EvmDeployerFilter]
+		[pallet_evm_code_size_limit, EvmCodeSizeLimit]
+		[pallet_evm_sanctions_filter, EvmSanctionsFilter]
+		[pallet_token_route_registry, TokenRouteRegistry]
+		[pallet_xcm_weight_trader, XcmWeightTrader]
+		[pallet_price_oracle, PriceOracle]
+		[pallet_evm_contract_metadata, EvmContractMetadata]
+		[pallet_evm_hibernation, EvmHibernation]
+		[pallet_evm_operators, EvmOperators]
+		[pallet_evm_sponsorship, EvmSponsorship]
+		[pallet_eip712_dispatch, Eip712Dispatch]
+		[pallet_erc20_xcm_bridge, Erc20XcmBridge]
 		[pallet_xcm_transactor, XcmTransactor]
 		[pallet_moonbeam_orbiters, MoonbeamOrbiters]
+		[pallet_author_filter_schedule, AuthorFilterSchedule]
+		[pallet_fee_split, FeeSplit]
+		[pallet_governance_origin_registry, GovernanceOriginRegistry]
+		[pallet_democracy_lock_migration, DemocracyLockMigration]
+		[pallet_precompile_benchmarks, PrecompileBenchmarks]
 		[pallet_randomness, Randomness]
 		[pallet_conviction_voting, ConvictionVoting]
 		[pallet_referenda, Referenda]
@@ -1447,6 +1809,7 @@ mod benches {
 		[pallet_whitelist, Whitelist]
 		[pallet_multisig, Multisig]
 		[moonbeam_xcm_benchmarks::weights::generic, MoonbeamXcmGenericBench::<Runtime>]
+		[moonbeam_xcm_benchmarks::weights::erc20, MoonbeamXcmErc20Bench::<Runtime>]
 	);
 }
 
@@ -1744,6 +2107,16 @@ mod tests {
 		assert!(base_extrinsic.ref_time() <= min_ethereum_transaction_weight.ref_time());
 	}
 
+	#[test]
+	fn block_gas_limit_fits_in_proof_size() {
+		let proof_size_gas_limit = moonbeam_runtime_common::gas_limit::PovAwareBlockGasLimit::<
+			Runtime,
+			RefTimeBlockGasLimit,
+		>::proof_size_gas_limit();
+		assert!(BlockGasLimit::get() <= proof_size_gas_limit);
+		assert!(BlockGasLimit::get() <= RefTimeBlockGasLimit::get());
+	}
+
 	#[test]
 	fn test_storage_growth_ratio_is_correct() {
 		let expected_storage_growth_ratio = BlockGasLimit::get()
@@ -15,24 +15,37 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-	asset_config::{ForeignAssetInstance, LocalAssetInstance},
-	xcm_config::XcmExecutorConfig,
-	CouncilInstance, OpenTechCommitteeInstance, TechCommitteeInstance, TreasuryCouncilInstance,
+	asset_config::{AssetsStringLimit, ForeignAssetInstance, LocalAssetInstance},
+	governance,
+	xcm_config::{LocationToAccountId, XcmExecutorConfig},
+	CouncilInstance, OpenTechCommitteeInstance, RuntimeOrigin, TechCommitteeInstance, Treasury,
+	TreasuryCouncilInstance,
 };
 use frame_support::parameter_types;
 use moonbeam_relay_encoder::westend::WestendEncoder;
+use moonbeam_runtime_common::migration_tracking::MigrationsStatus;
+use pallet_evm_precompile_account_delegation::AccountDelegationPrecompile;
+use pallet_evm_precompile_address_conversion::AddressConversionPrecompile;
+use pallet_evm_precompile_asset_manager::AssetManagerPrecompile;
 use pallet_evm_precompile_author_mapping::AuthorMappingPrecompile;
 use pallet_evm_precompile_balances_erc20::{Erc20BalancesPrecompile, Erc20Metadata};
 use pallet_evm_precompile_batch::BatchPrecompile;
 use pallet_evm_precompile_blake2::Blake2F;
 use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
 use pallet_evm_precompile_call_permit::CallPermitPrecompile;
+use pallet_evm_precompile_chain_info::{ChainInfoPrecompile, RelayParentProvider};
 use pallet_evm_precompile_collective::CollectivePrecompile;
+use pallet_evm_precompile_contract_metadata::ContractMetadataPrecompile;
 use pallet_evm_precompile_conviction_voting::ConvictionVotingPrecompile;
 use pallet_evm_precompile_crowdloan_rewards::CrowdloanRewardsPrecompile;
 use pallet_evm_precompile_democracy::DemocracyPrecompile;
+use pallet_evm_precompile_deployer_filter::DeployerFilterPrecompile;
 use pallet_evm_precompile_gmp::GmpPrecompile;
+use pallet_evm_precompile_governance_origins::GovernanceOriginsPrecompile;
 use pallet_evm_precompile_identity::IdentityPrecompile;
+use pallet_evm_precompile_inflation::InflationPrecompile;
+use pallet_evm_precompile_local_asset_factory::LocalAssetFactoryPrecompile;
+use pallet_evm_precompile_migrations::MigrationsPrecompile;
 use pallet_evm_precompile_modexp::Modexp;
 use pallet_evm_precompile_parachain_staking::ParachainStakingPrecompile;
 use pallet_evm_precompile_preimage::PreimagePrecompile;
@@ -41,15 +54,29 @@ use pallet_evm_precompile_randomness::RandomnessPrecompile;
 use pallet_evm_precompile_referenda::ReferendaPrecompile;
 use pallet_evm_precompile_registry::PrecompileRegistry;
 use pallet_evm_precompile_relay_encoder::RelayEncoderPrecompile;
+use pallet_evm_precompile_sanctions_filter::SanctionsFilterPrecompile;
+use pallet_evm_precompile_scheduler::SchedulerPrecompile;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
+use pallet_evm_precompile_signature_aggregator::SignatureAggregatorPrecompile;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use pallet_evm_precompile_sovereign_accounts::SovereignAccountsPrecompile;
+use pallet_evm_precompile_token_route_registry::TokenRouteRegistryPrecompile;
+use pallet_evm_precompile_whitelist::WhitelistPrecompile;
+use pallet_evm_precompile_xc20_issuance::Xc20IssuancePrecompile;
+use pallet_evm_precompile_xcm_pause::XcmPausePrecompile;
 use pallet_evm_precompile_xcm_transactor::{
 	v1::XcmTransactorPrecompileV1, v2::XcmTransactorPrecompileV2, v3::XcmTransactorPrecompileV3,
 };
 use pallet_evm_precompile_xcm_utils::{AllExceptXcmExecute, XcmUtilsPrecompile};
 use pallet_evm_precompile_xtokens::XtokensPrecompile;
 use pallet_evm_precompileset_assets_erc20::{Erc20AssetsPrecompileSet, IsForeign, IsLocal};
+use pallet_evm_precompileset_price_feeds::PriceFeedsPrecompileSet;
+use pallet_evm_precompileset_uniques_erc721::Erc721CollectionsPrecompileSet;
+use pallet_governance_origin_registry::GovernanceOrigin;
 use precompile_utils::precompile_set::*;
+use sp_core::{H160, H256};
+use sp_runtime::traits::Convert;
+use sp_std::vec::Vec;
 
 /// ERC20 metadata for the native token.
 pub struct NativeErc20Metadata;
@@ -83,10 +110,79 @@ pub const FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[255u8; 4];
 /// The asset precompile address prefix. Addresses that match against this prefix will be routed
 /// to Erc20AssetsPrecompileSet being marked as local
 pub const LOCAL_ASSET_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[255u8, 255u8, 255u8, 254u8];
+/// The NFT collection precompile address prefix. Addresses that match against this prefix will be
+/// routed to Erc721CollectionsPrecompileSet
+pub const NFT_COLLECTION_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[255u8, 255u8, 255u8, 253u8];
+/// The price feed precompile address prefix. Addresses that match against this prefix will be
+/// routed to PriceFeedsPrecompileSet
+pub const PRICE_FEED_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[255u8, 255u8, 255u8, 252u8];
 
 parameter_types! {
 	pub ForeignAssetPrefix: &'static [u8] = FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX;
 	pub LocalAssetPrefix: &'static [u8] = LOCAL_ASSET_PRECOMPILE_ADDRESS_PREFIX;
+	pub NftCollectionPrefix: &'static [u8] = NFT_COLLECTION_PRECOMPILE_ADDRESS_PREFIX;
+	pub PriceFeedPrefix: &'static [u8] = PRICE_FEED_PRECOMPILE_ADDRESS_PREFIX;
+	/// Governance transfers funds out of sovereign accounts with EVM calls from the treasury
+	/// account, which only the root origin can dispatch.
+	pub TreasuryAddress: H160 = Treasury::account_id().into();
+}
+
+/// Relay parent of the current block, as set by the parachain system inherent.
+pub struct RelayParent;
+
+impl RelayParentProvider for RelayParent {
+	fn relay_parent() -> Option<(u32, H256)> {
+		crate::ParachainSystem::validation_data()
+			.map(|data| (data.relay_parent_number, data.relay_parent_storage_root))
+	}
+}
+
+/// Runtime origins of the governance origins allowed to manage assets through the asset manager
+/// precompile and to schedule calls through the scheduler precompile.
+pub struct GovernanceOriginsAsRuntimeOrigins;
+
+impl Convert<GovernanceOrigin, Option<RuntimeOrigin>> for GovernanceOriginsAsRuntimeOrigins {
+	fn convert(origin: GovernanceOrigin) -> Option<RuntimeOrigin> {
+		match origin {
+			GovernanceOrigin::Root => Some(RuntimeOrigin::root()),
+			GovernanceOrigin::GeneralAdmin => {
+				Some(governance::custom_origins::Origin::GeneralAdmin.into())
+			}
+			GovernanceOrigin::Treasurer => None,
+		}
+	}
+}
+
+/// Interface versions of the versioned precompiles, pinned by each caller.
+pub struct PinnedInterfaceVersions;
+
+impl InterfaceVersionStore for PinnedInterfaceVersions {
+	const ENTRY_MAX_ENCODED_LEN: usize = pallet_precompile_versions::PINNED_VERSION_MAX_ENCODED_LEN;
+
+	fn pinned_version(precompile: H160, caller: H160) -> Option<u32> {
+		crate::PrecompileVersions::pinned_version(precompile, caller)
+	}
+
+	fn pin_version(precompile: H160, caller: H160, version: u32) {
+		crate::PrecompileVersions::pin_version(precompile, caller, version)
+	}
+}
+
+/// Storage keys already read by preloaded precompiles in the current block.
+pub struct PrecompileWarmKeys;
+
+impl WarmStorageKeys for PrecompileWarmKeys {
+	fn encoded_size() -> usize {
+		crate::PrecompileWarmKeys::encoded_size()
+	}
+
+	fn is_warm(key: &[u8]) -> bool {
+		crate::PrecompileWarmKeys::is_warm(key)
+	}
+
+	fn warm(keys: Vec<Vec<u8>>) {
+		crate::PrecompileWarmKeys::warm(keys)
+	}
 }
 
 type EthereumPrecompilesChecks = (AcceptDelegateCall, CallableByContract, CallableByPrecompile);
@@ -111,7 +207,11 @@ type MoonbasePrecompilesAt<R> = (
 	// Moonbeam specific precompiles:
 	PrecompileAt<
 		AddressU64<2048>,
-		ParachainStakingPrecompile<R>,
+		VersionedPrecompile<
+			R,
+			(AccessListPreloaded<R, ParachainStakingPrecompile<R>, PrecompileWarmKeys>,),
+			PinnedInterfaceVersions,
+		>,
 		(CallableByContract, CallableByPrecompile),
 	>,
 	PrecompileAt<
@@ -232,7 +332,11 @@ type MoonbasePrecompilesAt<R> = (
 		PrecompileRegistry<R>,
 		(CallableByContract, CallableByPrecompile),
 	>,
-	PrecompileAt<AddressU64<2070>, GmpPrecompile<R>, SubcallWithMaxNesting<0>>,
+	PrecompileAt<
+		AddressU64<2070>,
+		GmpPrecompile<R, pallet_token_route_registry::Pallet<R>>,
+		(SubcallWithMaxNesting<0>, CallableByContract),
+	>,
 	PrecompileAt<
 		AddressU64<2071>,
 		XcmTransactorPrecompileV3<R>,
@@ -243,6 +347,88 @@ type MoonbasePrecompilesAt<R> = (
 		IdentityPrecompile<R>,
 		(CallableByContract, CallableByPrecompile),
 	>,
+	PrecompileAt<
+		AddressU64<2073>,
+		Xc20IssuancePrecompile<R, ForeignAssetPrefix>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<AddressU64<2074>, XcmPausePrecompile<R, OpenTechCommitteeInstance>>,
+	PrecompileAt<
+		AddressU64<2075>,
+		DeployerFilterPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2076>,
+		ContractMetadataPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2077>,
+		SignatureAggregatorPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2078>,
+		ChainInfoPrecompile<R, RelayParent>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<AddressU64<2079>, WhitelistPrecompile<R, OpenTechCommitteeInstance>>,
+	PrecompileAt<
+		AddressU64<2080>,
+		InflationPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2081>,
+		AddressConversionPrecompile<R, LocationToAccountId>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2082>,
+		MigrationsPrecompile<R, MigrationsStatus<R>>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2083>,
+		AccountDelegationPrecompile<R>,
+		(CallableByContract, SubcallWithMaxNesting<1>),
+	>,
+	PrecompileAt<
+		AddressU64<2084>,
+		LocalAssetFactoryPrecompile<R, LocalAssetPrefix>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2085>,
+		SovereignAccountsPrecompile<R, LocationToAccountId, TreasuryAddress>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2086>,
+		GovernanceOriginsPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2087>,
+		AssetManagerPrecompile<R, GovernanceOriginsAsRuntimeOrigins, AssetsStringLimit>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2088>,
+		SanctionsFilterPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2089>,
+		TokenRouteRegistryPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2090>,
+		SchedulerPrecompile<R, GovernanceOriginsAsRuntimeOrigins>,
+		(CallableByContract, CallableByPrecompile),
+	>,
 );
 
 /// The PrecompileSet installed in the Moonbase runtime.
@@ -257,7 +443,7 @@ pub type MoonbasePrecompiles<R> = PrecompileSetBuilder<
 	(
 		// Skip precompiles if out of range.
 		PrecompilesInRangeInclusive<(AddressU64<1>, AddressU64<4095>), MoonbasePrecompilesAt<R>>,
-		// Prefixed precompile sets (XC20)
+		// Prefixed precompile sets (XC20, NFT collections and price feeds)
 		PrecompileSetStartingWith<
 			ForeignAssetPrefix,
 			Erc20AssetsPrecompileSet<R, IsForeign, ForeignAssetInstance>,
@@ -268,5 +454,15 @@ pub type MoonbasePrecompiles<R> = PrecompileSetBuilder<
 			Erc20AssetsPrecompileSet<R, IsLocal, LocalAssetInstance>,
 			(CallableByContract, CallableByPrecompile),
 		>,
+		PrecompileSetStartingWith<
+			NftCollectionPrefix,
+			Erc721CollectionsPrecompileSet<R>,
+			(CallableByContract, CallableByPrecompile),
+		>,
+		PrecompileSetStartingWith<
+			PriceFeedPrefix,
+			PriceFeedsPrecompileSet<R>,
+			(CallableByContract, CallableByPrecompile),
+		>,
 	),
 >;
@@ -18,9 +18,10 @@
 //!
 
 use super::{
-	governance, AccountId, AssetId, AssetManager, Assets, Balance, Balances, DealWithFees,
-	Erc20XcmBridge, LocalAssets, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall,
-	RuntimeEvent, RuntimeOrigin, Treasury, XcmpQueue, FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX,
+	governance, AccountId, AssetId, AssetManager, Assets, Balance, Balances, BlockNumber,
+	DealWithFees, Erc20XcmBridge, LocalAssets, ParachainInfo, ParachainSystem, PolkadotXcm,
+	Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Timestamp, Treasury, XcmWeightTrader,
+	XcmpQueue, FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX, HOURS,
 };
 use moonbeam_runtime_common::weights as moonbeam_weights;
 use pallet_evm_precompileset_assets_erc20::AccountIdAssetIdConversion;
@@ -32,7 +33,8 @@ use sp_runtime::{
 use frame_support::{
 	dispatch::Weight,
 	parameter_types,
-	traits::{EitherOfDiverse, Everything, Nothing, PalletInfoAccess},
+	traits::{EitherOfDiverse, Everything, Get, Nothing, PalletInfoAccess},
+	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, WeightToFee as _},
 };
 
 use frame_system::{EnsureRoot, RawOrigin};
@@ -125,21 +127,18 @@ impl xcm_executor::traits::Convert<MultiLocation, H160> for LocationToH160 {
 	}
 }
 
+// Matches the foreign assets registered in pallet-asset-manager
+pub type ForeignAssetsMatcher =
+	ConvertedConcreteId<AssetId, Balance, AsAssetType<AssetId, AssetType, AssetManager>, JustTry>;
+
 // The non-reserve fungible transactor type
 // It will use pallet-assets, and the Id will be matched against AsAssetType
 // This is intended to match FOREIGN ASSETS
-pub type ForeignFungiblesTransactor = FungiblesAdapter<
+pub type ForeignFungiblesAdapter = FungiblesAdapter<
 	// Use this fungibles implementation:
 	Assets,
 	// Use this currency when it is a fungible asset matching the given location or name:
-	(
-		ConvertedConcreteId<
-			AssetId,
-			Balance,
-			AsAssetType<AssetId, AssetType, AssetManager>,
-			JustTry,
-		>,
-	),
+	(ForeignAssetsMatcher,),
 	// Do a simple punn to convert an AccountId20 MultiLocation into a native chain account ID:
 	LocationToAccountId,
 	// Our chain's account ID type (we can't get away without mentioning it explicitly):
@@ -150,6 +149,14 @@ pub type ForeignFungiblesTransactor = FungiblesAdapter<
 	(),
 >;
 
+// Records the XCM mints and burns of foreign assets, so that they can be reconciled with the
+// assets total issuance
+pub type ForeignFungiblesTransactor = pallet_xc20_issuance::IssuanceTrackingTransactor<
+	Runtime,
+	ForeignAssetsMatcher,
+	ForeignFungiblesAdapter,
+>;
+
 /// The transactor for our own chain currency.
 pub type LocalAssetTransactor = XcmCurrencyAdapter<
 	// Use this currency:
@@ -239,22 +246,27 @@ pub type XcmWeigher = WeightInfoBounds<
 	MaxInstructions,
 >;
 
-pub type XcmBarrier = (
-	// Weight that is paid for may be consumed.
-	TakeWeightCredit,
-	// Expected responses are OK.
-	AllowKnownQueryResponses<PolkadotXcm>,
-	WithComputedOrigin<
-		(
-			// If the message is one that immediately attemps to pay for execution, then allow it.
-			AllowTopLevelPaidExecutionFrom<Everything>,
-			// Subscriptions for version tracking are OK.
-			AllowSubscriptionsFrom<Everything>,
-		),
-		UniversalLocation,
-		ConstU32<8>,
-	>,
-);
+/// The barriers configured here are applied after the origin filters set by governance in
+/// `pallet_xcm_filter`.
+pub type XcmBarrier = pallet_xcm_filter::XcmFilterBarrier<
+	Runtime,
+	(
+		// Weight that is paid for may be consumed.
+		TakeWeightCredit,
+		// Expected responses are OK.
+		AllowKnownQueryResponses<PolkadotXcm>,
+		WithComputedOrigin<
+			(
+				// If the message is one that immediately attemps to pay for execution, then allow it.
+				AllowTopLevelPaidExecutionFrom<Everything>,
+				// Subscriptions for version tracking are OK.
+				AllowSubscriptionsFrom<Everything>,
+			),
+			UniversalLocation,
+			ConstU32<8>,
+		>,
+	),
+>;
 
 parameter_types! {
 	/// Xcm fees will go to the treasury account
@@ -278,6 +290,33 @@ pub type XcmFeesToAccount = xcm_primitives::XcmFeesToAccount<
 	XcmFeesAccount,
 >;
 
+/// Units of the native token charged per second of XCM execution, the same way as local
+/// transactions.
+pub struct NativeUnitsPerSecond;
+impl Get<u128> for NativeUnitsPerSecond {
+	fn get() -> u128 {
+		<Runtime as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(
+			&Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND, 0),
+		)
+	}
+}
+
+impl pallet_xcm_weight_trader::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetType = AssetType;
+	type FeedId = pallet_price_oracle::FeedId;
+	type PriceFeed = pallet_price_oracle::RelativePriceFeed<Runtime>;
+	type Fallback = AssetManager;
+	type NativeUnitsPerSecond = NativeUnitsPerSecond;
+	type Time = Timestamp;
+	type PriceManagerOrigin = GeneralAdminOrRoot;
+	type WeightInfo = moonbeam_weights::pallet_xcm_weight_trader::WeightInfo<Runtime>;
+}
+
+/// Units per second charged for XCM execution paid in foreign assets, priced from the price feed
+/// when fresh prices are available.
+pub type ForeignAssetUnitsPerSecond = XcmWeightTrader;
+
 // Our implementation of the Moonbeam Call
 // Attachs the right origin in case the call is made to pallet-ethereum-xcm
 #[cfg(not(feature = "evm-tracing"))]
@@ -318,8 +357,9 @@ impl xcm_executor::Config for XcmExecutorConfig {
 	// We use two traders
 	// When we receive the relative representation of the self-reserve asset,
 	// we use UsingComponents and the local way of handling fees
-	// When we receive a non-reserve asset, we use AssetManager to fetch how many
-	// units per second we should charge
+	// When we receive a non-reserve asset, we use XcmWeightTrader to fetch how many
+	// units per second we should charge, and AssetManager to decide in which asset according
+	// to its fee asset preference when the message holds several of them
 	type Trader = (
 		UsingComponents<
 			<Runtime as pallet_transaction_payment::Config>::WeightToFee,
@@ -328,7 +368,7 @@ impl xcm_executor::Config for XcmExecutorConfig {
 			Balances,
 			DealWithFees<Runtime>,
 		>,
-		FirstAssetTrader<AssetType, AssetManager, XcmFeesToAccount>,
+		FirstAssetTrader<AssetType, ForeignAssetUnitsPerSecond, XcmFeesToAccount>,
 	);
 	type ResponseHandler = PolkadotXcm;
 	type SubscriptionService = PolkadotXcm;
@@ -625,6 +665,32 @@ impl XcmTransact for Transactors {
 pub type DerivativeAddressRegistrationOrigin =
 	EitherOfDiverse<EnsureRoot<AccountId>, governance::custom_origins::GeneralAdmin>;
 
+parameter_types! {
+	/// Blocks during which a transact status query waits for the destination chain to respond
+	pub const TransactStatusQueryTimeout: BlockNumber = HOURS;
+}
+
+/// Registers the transact status queries in pallet-xcm, which notifies the xcm-transactor
+/// pallet once the destination chain responds
+pub struct TransactStatusQuerier;
+impl pallet_xcm_transactor::TransactStatusQuerier for TransactStatusQuerier {
+	fn new_transact_status_query(responder: MultiLocation, querier: MultiLocation) -> QueryId {
+		let notify = pallet_xcm_transactor::Call::<Runtime>::transact_status_notify {
+			query_id: Default::default(),
+			response: Response::Null,
+		};
+		let timeout = frame_system::Pallet::<Runtime>::block_number()
+			.saturating_add(TransactStatusQueryTimeout::get());
+		PolkadotXcm::new_notify_query(responder, notify, timeout, querier)
+	}
+}
+
+parameter_types! {
+	// IndexToAccount: Blake2_128(16) + u16(2) + AccountId(20)
+	// IndexDeposit: Blake2_128(16) + u16(2) + Balance(16)
+	pub const DerivativeIndexDeposit: Balance = super::currency::deposit(2, 72);
+}
+
 impl pallet_xcm_transactor::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -646,6 +712,10 @@ impl pallet_xcm_transactor::Config for Runtime {
 	type HrmpManipulatorOrigin = GeneralAdminOrRoot;
 	type MaxHrmpFee = xcm_builder::Case<MaxHrmpRelayFee>;
 	type HrmpEncoder = moonbeam_relay_encoder::westend::WestendEncoder;
+	type TransactStatusQuerier = TransactStatusQuerier;
+	type TransactStatusResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+	type DepositCurrency = Balances;
+	type DerivativeIndexDeposit = DerivativeIndexDeposit;
 }
 
 parameter_types! {
@@ -662,13 +732,47 @@ parameter_types! {
 	// To be able to support almost all erc20 implementations,
 	// we provide a sufficiently hight gas limit.
 	pub Erc20XcmBridgeTransferGasLimit: u64 = 200_000;
+
+	// Number of consecutive failed transfers after which an erc20 token is quarantined.
+	pub const Erc20XcmBridgeMaxConsecutiveTransferFailures: u32 = 5;
 }
 
 impl pallet_erc20_xcm_bridge::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type AccountIdConverter = LocationToH160;
 	type Erc20MultilocationPrefix = Erc20XcmBridgePalletLocation;
 	type Erc20TransferGasLimit = Erc20XcmBridgeTransferGasLimit;
 	type EvmRunner = EvmRunnerPrecompileOrEthXcm<MoonbeamCall, Self>;
+	type Erc20ManagerOrigin = GeneralAdminOrRoot;
+	type MaxConsecutiveTransferFailures = Erc20XcmBridgeMaxConsecutiveTransferFailures;
+	type WeightInfo = moonbeam_weights::pallet_erc20_xcm_bridge::WeightInfo<Runtime>;
+}
+
+/// Governance can suspend or restrict inbound XCM from an origin, the Open Tech Committee being
+/// able to react quickly to a misbehaving channel.
+pub type XcmFilterOrigin = EitherOfDiverse<
+	GeneralAdminOrRoot,
+	pallet_collective::EnsureProportionAtLeast<
+		AccountId,
+		governance::councils::OpenTechCommitteeInstance,
+		5,
+		9,
+	>,
+>;
+
+/// Any member of the Open Tech Committee can pause XCM in an emergency, through the XCM pause
+/// precompile or a collective proposal.
+pub type XcmPauseOrigin = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureMember<AccountId, governance::councils::OpenTechCommitteeInstance>,
+>;
+
+impl pallet_xcm_filter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type FilterOrigin = XcmFilterOrigin;
+	type PauseOrigin = XcmPauseOrigin;
+	type XcmExecutionManager = crate::EmergencyXcmExecutionManager;
+	type WeightInfo = moonbeam_weights::pallet_xcm_filter::WeightInfo<Runtime>;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
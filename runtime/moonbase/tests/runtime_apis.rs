@@ -20,15 +20,30 @@ mod common;
 use common::*;
 
 use fp_evm::GenesisAccount;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, weights::Weight};
+use moonbase_runtime::TransactionPayment;
 use nimbus_primitives::NimbusId;
 use pallet_evm::{Account as EVMAccount, AddressMapping, FeeCalculator};
 use sp_core::{ByteArray, H160, H256, U256};
-
+use sp_runtime::Percent;
+
+use account_info_runtime_api::{
+	runtime_decl_for_account_info_api::AccountInfoApi, BalanceLockInfo, LockReasons,
+};
+use assets_runtime_api::runtime_decl_for_assets_api::AssetsApi;
+use custody_audit_runtime_api::runtime_decl_for_custody_audit_api::CustodyAuditApi;
+use eth_call_runtime_api::runtime_decl_for_eth_call_api::EthCallApi;
+use evm_hibernation_runtime_api::runtime_decl_for_evm_hibernation_api::EvmHibernationApi;
+use fee_split_runtime_api::{runtime_decl_for_fee_split_api::FeeSplitApi, FeeSplit};
 use fp_rpc::runtime_decl_for_ethereum_runtime_rpc_api::EthereumRuntimeRPCApi;
 use moonbeam_rpc_primitives_txpool::runtime_decl_for_tx_pool_runtime_api::TxPoolRuntimeApi;
 use nimbus_primitives::runtime_decl_for_nimbus_api::NimbusApi;
+use orbiters_runtime_api::runtime_decl_for_orbiters_api::OrbitersApi;
 use std::{collections::BTreeMap, str::FromStr};
+use transaction_types_runtime_api::runtime_decl_for_transaction_types_api::TransactionTypesApi;
+use xc20_issuance_runtime_api::runtime_decl_for_xc20_issuance_api::Xc20IssuanceApi;
+use xcm_fee_payment_runtime_api::runtime_decl_for_xcm_payment_api::XcmPaymentApi;
+use xcm_transactor_runtime_api::runtime_decl_for_xcm_transactor_api::XcmTransactorApi;
 
 #[test]
 fn ethereum_runtime_rpc_api_chain_id() {
@@ -167,6 +182,44 @@ fn ethereum_runtime_rpc_api_call() {
 		});
 }
 
+#[test]
+fn eth_call_api_call_with_block_overrides() {
+	ExtBuilder::default()
+		.with_balances(vec![(AccountId::from(ALICE), 2_000 * UNIT)])
+		.build()
+		.execute_with(|| {
+			// Returns the block number followed by the timestamp.
+			let contract = H160::repeat_byte(0x42);
+			pallet_evm::Pallet::<Runtime>::create_account(
+				contract,
+				hex::decode("436000524260205260406000f3").unwrap(),
+			);
+
+			let execution_result = Runtime::call_with_block_overrides(
+				H160::from(ALICE),           // from
+				contract,                    // to
+				Vec::new(),                  // data
+				U256::zero(),                // value
+				Some(U256::from(100000u64)), // gas_limit
+				None,                        // max_fee_per_gas
+				None,                        // max_priority_fee_per_gas
+				None,                        // nonce
+				None,                        // access_list
+				eth_call_runtime_api::BlockOverrides {
+					number: Some(U256::from(1234u64)),
+					time: Some(5678),
+					base_fee: None,
+				},
+			)
+			.expect("call succeeds");
+
+			let mut expected = [0u8; 64];
+			U256::from(1234u64).to_big_endian(&mut expected[..32]);
+			U256::from(5678u64).to_big_endian(&mut expected[32..]);
+			assert_eq!(execution_result.value, expected.to_vec());
+		});
+}
+
 #[test]
 fn ethereum_runtime_rpc_api_create() {
 	ExtBuilder::default()
@@ -389,9 +442,283 @@ fn can_author_when_selected_is_empty() {
 		});
 }
 
+#[test]
+fn xcm_payment_runtime_api_native_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		let native: xcm_fee_payment_runtime_api::VersionedAssetId =
+			xcm::v3::AssetId::Concrete(moonbase_runtime::xcm_config::SelfReserve::get()).into();
+
+		let assets = Runtime::query_acceptable_payment_assets(3).unwrap();
+		assert_eq!(assets, vec![native.clone()]);
+
+		assert_eq!(
+			Runtime::query_acceptable_payment_assets(2),
+			Err(xcm_fee_payment_runtime_api::Error::UnhandledXcmVersion)
+		);
+
+		let weight = Weight::from_parts(1_000_000_000, 0);
+		assert_eq!(
+			Runtime::query_weight_to_asset_fee(weight, native),
+			Ok(TransactionPayment::weight_to_fee(weight))
+		);
+	});
+}
+
+#[test]
+fn xcm_payment_runtime_api_unknown_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		let unknown: xcm_fee_payment_runtime_api::VersionedAssetId =
+			xcm::v3::AssetId::Concrete(xcm::v3::MultiLocation::parent()).into();
+
+		assert_eq!(
+			Runtime::query_weight_to_asset_fee(Weight::from_parts(1_000_000_000, 0), unknown),
+			Err(xcm_fee_payment_runtime_api::Error::AssetNotFound)
+		);
+	});
+}
+
+#[test]
+fn xcm_transactor_runtime_api_estimate_transact_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		let relay: xcm::VersionedMultiLocation = xcm::v3::MultiLocation::parent().into();
+		assert_eq!(
+			Runtime::estimate_transact_fee(
+				relay.clone(),
+				Weight::from_parts(1_000_000_000, 0),
+				relay
+			),
+			Err(pallet_xcm_transactor::Error::<Runtime>::TransactorInfoNotSet.into())
+		);
+	});
+}
+
+#[test]
+fn xcm_payment_runtime_api_query_xcm_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		let message =
+			xcm::VersionedXcm::<()>::V3(xcm::v3::Xcm(vec![xcm::v3::Instruction::ClearOrigin]));
+		assert!(Runtime::query_xcm_weight(message).is_ok());
+	});
+}
+
+#[test]
+fn xc20_issuance_runtime_api_untracked_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Runtime::issuance_report(1), None);
+		assert!(Runtime::drifting_assets().is_empty());
+	});
+}
+
+#[test]
+fn custody_audit_runtime_api_compares_custody_with_xcm_ingress() {
+	use frame_support::traits::fungibles::Mutate;
+	use moonbase_runtime::{asset_config::AssetRegistrarMetadata, xcm_config::AssetType};
+	use xcm::v3::{Junction, Junctions, MultiLocation};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let location = MultiLocation {
+			parents: 1,
+			interior: Junctions::X2(Junction::Parachain(1000), Junction::GeneralIndex(1)),
+		};
+		let asset_type = AssetType::Xcm(location);
+		let asset_id: AssetId = asset_type.clone().into();
+		assert_eq!(Runtime::custody_report(asset_id), None);
+
+		assert_ok!(AssetManager::register_foreign_asset(
+			moonbase_runtime::RuntimeOrigin::root(),
+			asset_type,
+			AssetRegistrarMetadata {
+				name: b"SiblingToken".to_vec(),
+				symbol: b"Sibling".to_vec(),
+				decimals: 12,
+				is_frozen: false,
+			},
+			1u128,
+			true,
+		));
+
+		let report = custody_audit_runtime_api::CustodyReport {
+			reserve: MultiLocation::new(1, Junctions::X1(Junction::Parachain(1000))),
+			custodied: 0,
+			bridged_in: None,
+		};
+		assert_eq!(Runtime::custody_report(asset_id), Some(report.clone()));
+		assert_eq!(Runtime::custody_reports(), vec![(asset_id, report)]);
+
+		// Deposited through XCM
+		assert_ok!(Assets::mint_into(asset_id, &AccountId::from(ALICE), 100));
+		moonbase_runtime::Xc20Issuance::note_xcm_mint(asset_id, 100);
+
+		let report = Runtime::custody_report(asset_id).unwrap();
+		assert_eq!((report.custodied, report.bridged_in), (100, Some(100)));
+		assert!(!report.is_undercollateralized());
+
+		// Minted without being bridged in
+		assert_ok!(Assets::mint_into(asset_id, &AccountId::from(ALICE), 10));
+
+		let report = Runtime::custody_report(asset_id).unwrap();
+		assert_eq!((report.custodied, report.bridged_in), (110, Some(100)));
+		assert!(report.is_undercollateralized());
+	});
+}
+
+#[test]
+fn assets_runtime_api_list_foreign_assets() {
+	use moonbase_runtime::{
+		asset_config::AssetRegistrarMetadata, xcm_config::AssetType,
+		FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX,
+	};
+	use pallet_evm_precompileset_assets_erc20::AccountIdAssetIdConversion;
+
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(Runtime::list_foreign_assets().is_empty());
+
+		let location = xcm::v3::MultiLocation::parent();
+		let asset_type = AssetType::Xcm(location);
+		let asset_id: AssetId = asset_type.clone().into();
+		assert_ok!(AssetManager::register_foreign_asset(
+			moonbase_runtime::RuntimeOrigin::root(),
+			asset_type,
+			AssetRegistrarMetadata {
+				name: b"RelayToken".to_vec(),
+				symbol: b"Relay".to_vec(),
+				decimals: 12,
+				is_frozen: false,
+			},
+			1u128,
+			true,
+		));
+
+		assert_eq!(
+			Runtime::list_foreign_assets(),
+			vec![assets_runtime_api::ForeignAsset {
+				asset_id,
+				location,
+				address: Runtime::asset_id_to_account(
+					FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX,
+					asset_id
+				)
+				.into(),
+				metadata: assets_runtime_api::AssetMetadata {
+					name: b"RelayToken".to_vec(),
+					symbol: b"Relay".to_vec(),
+					decimals: 12,
+				},
+			}]
+		);
+	});
+}
+
+#[test]
+fn orbiters_runtime_api_unselected_orbiter() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Runtime::orbiter_audit(AccountId::from(ALICE)), None);
+		assert!(Runtime::orbiters_audit().is_empty());
+	});
+}
+
+#[test]
+fn fee_split_runtime_api_follows_governance() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			Runtime::fee_split(),
+			FeeSplit {
+				burn: Percent::from_percent(80),
+				treasury: Percent::from_percent(20),
+			}
+		);
+
+		assert_ok!(moonbase_runtime::FeeSplit::set_burn_percent(
+			root_origin(),
+			Percent::from_percent(60)
+		));
+		assert_eq!(
+			Runtime::fee_split(),
+			FeeSplit {
+				burn: Percent::from_percent(60),
+				treasury: Percent::from_percent(40),
+			}
+		);
+	});
+}
+
+#[test]
+fn account_info_runtime_api_reports_staking_locks() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(AccountId::from(ALICE), 2_000 * UNIT),
+			(AccountId::from(BOB), 2_000 * UNIT),
+		])
+		.with_collators(vec![(AccountId::from(ALICE), 1_000 * UNIT)])
+		.with_delegations(vec![(
+			AccountId::from(BOB),
+			AccountId::from(ALICE),
+			500 * UNIT,
+		)])
+		.build()
+		.execute_with(|| {
+			let meta = Runtime::account_meta(H160::from(BOB));
+
+			assert_eq!(meta.providers, 1);
+			assert_eq!(meta.free, 2_000 * UNIT);
+			assert_eq!(meta.reserved, 0);
+			assert_eq!(meta.frozen, 500 * UNIT);
+			assert_eq!(meta.transferable, 1_500 * UNIT);
+			assert_eq!(
+				meta.locks,
+				vec![BalanceLockInfo {
+					id: *b"stkngdel",
+					amount: 500 * UNIT,
+					reasons: LockReasons::All,
+				}]
+			);
+			assert!(meta.reserves.is_empty());
+			assert!(meta.holds.is_empty());
+		});
+}
+
+#[test]
+fn evm_hibernation_runtime_api_contract_state() {
+	let contract = H160::repeat_byte(0xcc);
+	ExtBuilder::default()
+		.with_evm_accounts({
+			let mut map = BTreeMap::new();
+			map.insert(
+				contract,
+				GenesisAccount {
+					balance: U256::zero(),
+					code: vec![0x00],
+					nonce: Default::default(),
+					storage: [(H256::repeat_byte(0x01), H256::repeat_byte(0x11))]
+						.into_iter()
+						.collect(),
+				},
+			);
+			map
+		})
+		.build()
+		.execute_with(|| {
+			assert_eq!(Runtime::hibernated_contract(contract), None);
+			assert_eq!(
+				Runtime::contract_state(contract),
+				evm_hibernation_runtime_api::ContractState {
+					code: vec![0x00],
+					storage: vec![(H256::repeat_byte(0x01), H256::repeat_byte(0x11))],
+				}
+			);
+		});
+}
+
 // Some Priority-related test ideas
 // 1. Eth balance transfer with various gas prices. Priority == gas price
 // 2. Eth contract call with various gas prices. Priority == gas price
 // 3. System remark with no tip -> calculate expected priority from gas weight mapping
 // 4. System remark with tip.
 // 5. Operational dispatch has higher priority than normal for otherwise same transactions
+
+#[test]
+fn transaction_types_runtime_api_excludes_blob_and_set_code_types() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Runtime::supported_transaction_types(), vec![0, 1, 2]);
+	});
+}
@@ -17,6 +17,7 @@
 pub mod parachain;
 pub mod relay_chain;
 pub mod statemint_like;
+pub mod wormhole;
 use cumulus_primitives_core::ParaId;
 use sp_runtime::traits::AccountIdConversion;
 use sp_runtime::AccountId32;
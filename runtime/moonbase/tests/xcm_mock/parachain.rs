@@ -913,6 +913,7 @@ impl pallet_asset_manager::Config for Runtime {
 	type LocalAssetIdCreator = LocalAssetIdCreator;
 	type Currency = Balances;
 	type LocalAssetDeposit = AssetDeposit;
+	type AssetTypeAliasPeriod = ConstU32<10>;
 	type WeightInfo = ();
 }
 
@@ -921,6 +922,22 @@ parameter_types! {
 	pub MaxHrmpRelayFee: MultiAsset = (MultiLocation::parent(), 1_000_000_000_000u128).into();
 }
 
+parameter_types! {
+	pub const TransactStatusQueryTimeout: BlockNumber = 100;
+}
+
+pub struct TransactStatusQuerier;
+impl pallet_xcm_transactor::TransactStatusQuerier for TransactStatusQuerier {
+	fn new_transact_status_query(responder: MultiLocation, querier: MultiLocation) -> QueryId {
+		let notify = pallet_xcm_transactor::Call::<Runtime>::transact_status_notify {
+			query_id: Default::default(),
+			response: Response::Null,
+		};
+		let timeout = System::block_number().saturating_add(TransactStatusQueryTimeout::get());
+		PolkadotXcm::new_notify_query(responder, notify, timeout, querier)
+	}
+}
+
 impl pallet_xcm_transactor::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -942,6 +959,10 @@ impl pallet_xcm_transactor::Config for Runtime {
 	type HrmpManipulatorOrigin = EnsureRoot<AccountId>;
 	type MaxHrmpFee = xcm_builder::Case<MaxHrmpRelayFee>;
 	type HrmpEncoder = MockHrmpEncoder;
+	type TransactStatusQuerier = TransactStatusQuerier;
+	type TransactStatusResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+	type DepositCurrency = Balances;
+	type DerivativeIndexDeposit = frame_support::traits::ConstU128<0>;
 }
 
 parameter_types! {
@@ -954,7 +975,88 @@ impl pallet_timestamp::Config for Runtime {
 	type WeightInfo = ();
 }
 
-use sp_core::U256;
+use super::wormhole::{MockWormholeBridge, MockWormholeCore, MockWrappedNative};
+use pallet_evm_precompile_gmp::GmpPrecompile;
+use pallet_evm_precompileset_assets_erc20::{
+	AccountIdAssetIdConversion, Erc20AssetsPrecompileSet, IsForeign,
+};
+use precompile_utils::precompile_set::*;
+use sp_core::{H160, U256};
+use xcm_primitives::AccountIdToCurrencyId;
+
+pub const FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[255u8; 4];
+pub const GMP_PRECOMPILE_ADDRESS: u64 = 2070;
+pub const WORMHOLE_CORE_ADDRESS: u64 = 4000;
+pub const WORMHOLE_BRIDGE_ADDRESS: u64 = 4001;
+pub const WRAPPED_NATIVE_ADDRESS: u64 = 4002;
+
+parameter_types! {
+	pub ForeignAssetPrefix: &'static [u8] = FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX;
+}
+
+/// The GMP precompile as configured in Moonbase, next to mock Wormhole contracts, a wrapped
+/// native token and the ERC-20 interface of foreign assets it relies on.
+pub type Precompiles<R> = PrecompileSetBuilder<
+	R,
+	(
+		PrecompileAt<
+			AddressU64<GMP_PRECOMPILE_ADDRESS>,
+			GmpPrecompile<R>,
+			SubcallWithMaxNesting<0>,
+		>,
+		PrecompileAt<AddressU64<WORMHOLE_CORE_ADDRESS>, MockWormholeCore<R>, CallableByPrecompile>,
+		PrecompileAt<
+			AddressU64<WORMHOLE_BRIDGE_ADDRESS>,
+			MockWormholeBridge<R>,
+			(CallableByPrecompile, SubcallWithMaxNesting<0>),
+		>,
+		PrecompileAt<
+			AddressU64<WRAPPED_NATIVE_ADDRESS>,
+			MockWrappedNative<R>,
+			CallableByPrecompile,
+		>,
+		PrecompileSetStartingWith<
+			ForeignAssetPrefix,
+			Erc20AssetsPrecompileSet<R, IsForeign, ForeignAssetInstance>,
+			(CallableByContract, CallableByPrecompile),
+		>,
+	),
+>;
+
+impl AccountIdAssetIdConversion<AccountId, AssetId> for Runtime {
+	/// The way to convert an account to assetId is by ensuring that the prefix is 0XFFFFFFFF
+	/// and by taking the lowest 128 bits as the assetId
+	fn account_to_asset_id(account: AccountId) -> Option<(Vec<u8>, AssetId)> {
+		let h160_account: H160 = account.into();
+		let mut data = [0u8; 16];
+		let (prefix_part, id_part) = h160_account.as_fixed_bytes().split_at(4);
+		if prefix_part == FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX {
+			data.copy_from_slice(id_part);
+			Some((prefix_part.to_vec(), u128::from_be_bytes(data)))
+		} else {
+			None
+		}
+	}
+
+	// The opposite conversion
+	fn asset_id_to_account(prefix: &[u8], asset_id: AssetId) -> AccountId {
+		let mut data = [0u8; 20];
+		data[0..4].copy_from_slice(prefix);
+		data[4..20].copy_from_slice(&asset_id.to_be_bytes());
+		AccountId::from(data)
+	}
+}
+
+impl AccountIdToCurrencyId<AccountId, CurrencyId> for Runtime {
+	fn account_to_currency_id(account: AccountId) -> Option<CurrencyId> {
+		// the self-reserve currency is identified by the pallet-balances address
+		if account == H160::from_low_u64_be(2050).into() {
+			return Some(CurrencyId::SelfReserve);
+		}
+		Runtime::account_to_asset_id(account)
+			.map(|(_prefix, asset_id)| CurrencyId::ForeignAsset(asset_id))
+	}
+}
 
 const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
 /// Block storage limit in bytes. Set to 40 KB.
@@ -962,6 +1064,7 @@ const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
 
 parameter_types! {
 	pub BlockGasLimit: U256 = U256::from(u64::MAX);
+	pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
 	pub WeightPerGas: Weight = Weight::from_parts(1, 0);
 	pub GasLimitPovSizeRatio: u64 = {
 		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
@@ -984,8 +1087,8 @@ impl pallet_evm::Config for Runtime {
 	type Runner = pallet_evm::runner::stack::Runner<Self>;
 
 	type RuntimeEvent = RuntimeEvent;
-	type PrecompilesType = ();
-	type PrecompilesValue = ();
+	type PrecompilesType = Precompiles<Self>;
+	type PrecompilesValue = PrecompilesValue;
 	type ChainId = ();
 	type BlockGasLimit = BlockGasLimit;
 	type OnChargeTransaction = ();
@@ -0,0 +1,236 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Stand-ins for the Wormhole core and token bridge contracts, implemented as precompiles so
+//! the GMP precompile can be driven end-to-end inside the mock network.
+//!
+//! Mock VAAs follow the Wormhole wire format but carry no guardian signature: the core contract
+//! only checks their structure, and the bridge releases the tokens it holds in custody through
+//! the ERC-20 interface of the wrapped asset. A minimal wrapped native token backed by the
+//! native balance of its own address is provided as well.
+
+use fp_evm::{Context, ExitReason, PrecompileHandle};
+use frame_support::{
+	storage::types::{StorageMap, ValueQuery},
+	traits::{Currency, ExistenceRequirement, StorageInstance},
+	Blake2_128Concat,
+};
+use pallet_evm::AddressMapping;
+use pallet_evm_precompile_gmp::{
+	types::{
+		WormholeTransferWithPayloadData, WormholeVM, WormholeVaa, TRANSFER_WITH_PAYLOAD_ID,
+		VAA_VERSION,
+	},
+	CALL_DATA_LIMIT,
+};
+use precompile_utils::prelude::*;
+use sp_core::{ConstU32, H160, H256, U256};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+type GetCallDataLimit = ConstU32<CALL_DATA_LIMIT>;
+
+const TRANSFER_SELECTOR: u32 = 0xa9059cbb_u32;
+
+/// Builds an unsigned VAA carrying the given transfer.
+pub fn encode_vaa(transfer: WormholeTransferWithPayloadData) -> Vec<u8> {
+	let mut vaa = Vec::new();
+	vaa.push(VAA_VERSION);
+	// guardian set index and signatures count
+	vaa.extend_from_slice(&0u32.to_be_bytes());
+	vaa.push(0);
+
+	// timestamp, nonce, emitter chain and address, sequence and consistency level
+	vaa.extend_from_slice(&0u32.to_be_bytes());
+	vaa.extend_from_slice(&0u32.to_be_bytes());
+	vaa.extend_from_slice(&transfer.token_chain.to_be_bytes());
+	vaa.extend_from_slice(H256::zero().as_bytes());
+	vaa.extend_from_slice(&0u64.to_be_bytes());
+	vaa.push(1);
+
+	vaa.push(TRANSFER_WITH_PAYLOAD_ID);
+	let mut amount = [0u8; 32];
+	transfer.amount.to_big_endian(&mut amount);
+	vaa.extend_from_slice(&amount);
+	vaa.extend_from_slice(transfer.token_address.as_bytes());
+	vaa.extend_from_slice(&transfer.token_chain.to_be_bytes());
+	vaa.extend_from_slice(transfer.to.as_bytes());
+	vaa.extend_from_slice(&transfer.to_chain.to_be_bytes());
+	vaa.extend_from_slice(transfer.from_address.as_bytes());
+	vaa.extend_from_slice(transfer.payload.as_bytes());
+	vaa
+}
+
+fn parse_transfer(encoded_vm: &[u8]) -> EvmResult<WormholeTransferWithPayloadData> {
+	let vaa = WormholeVaa::parse(encoded_vm)?;
+	Ok(WormholeTransferWithPayloadData::parse(vaa.payload)?)
+}
+
+pub struct MockWormholeCore<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> MockWormholeCore<Runtime>
+where
+	Runtime: pallet_evm::Config,
+{
+	#[precompile::public("parseVM(bytes)")]
+	#[precompile::view]
+	fn parse_vm(
+		_handle: &mut impl PrecompileHandle,
+		encoded_vm: BoundedBytes<GetCallDataLimit>,
+	) -> EvmResult<WormholeVM> {
+		let vaa = WormholeVaa::parse(encoded_vm.as_bytes())?;
+		let hash = vaa.hash();
+
+		Ok(WormholeVM {
+			version: vaa.version,
+			timestamp: vaa.timestamp,
+			nonce: vaa.nonce,
+			emitter_chain_id: vaa.emitter_chain_id,
+			emitter_address: vaa.emitter_address,
+			sequence: vaa.sequence,
+			consistency_level: vaa.consistency_level,
+			payload: vaa.payload.into(),
+			guardian_set_index: vaa.guardian_set_index,
+			signatures: vaa.signatures,
+			hash,
+		})
+	}
+}
+
+pub struct MockWormholeBridge<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> MockWormholeBridge<Runtime>
+where
+	Runtime: pallet_evm::Config,
+{
+	#[precompile::public("parseTransferWithPayload(bytes)")]
+	#[precompile::view]
+	fn parse_transfer_with_payload(
+		_handle: &mut impl PrecompileHandle,
+		encoded: BoundedBytes<GetCallDataLimit>,
+	) -> EvmResult<WormholeTransferWithPayloadData> {
+		Ok(WormholeTransferWithPayloadData::parse(encoded.as_bytes())?)
+	}
+
+	/// Every token is treated as native to the current chain, so the wrapped asset is the
+	/// token address itself.
+	#[precompile::public("wrappedAsset(uint16,bytes32)")]
+	#[precompile::view]
+	fn wrapped_asset(
+		_handle: &mut impl PrecompileHandle,
+		_token_chain: u16,
+		token_address: H256,
+	) -> EvmResult<Address> {
+		Ok(Address(token_address.into()))
+	}
+
+	#[precompile::public("completeTransferWithPayload(bytes)")]
+	fn complete_transfer_with_payload(
+		handle: &mut impl PrecompileHandle,
+		encoded_vm: BoundedBytes<GetCallDataLimit>,
+	) -> EvmResult {
+		let transfer = parse_transfer(encoded_vm.as_bytes())?;
+
+		// As the real bridge, only the designated recipient can redeem a transfer with payload.
+		let caller = handle.context().caller;
+		if H160::from(transfer.to) != caller {
+			return Err(revert("invalid sender"));
+		}
+
+		let token: H160 = transfer.token_address.into();
+		let sub_context = Context {
+			caller: handle.code_address(),
+			address: token,
+			apparent_value: U256::zero(),
+		};
+		let (reason, _) = handle.call(
+			token,
+			None,
+			solidity::encode_with_selector(TRANSFER_SELECTOR, (Address(caller), transfer.amount)),
+			None,
+			false,
+			&sub_context,
+		);
+
+		match reason {
+			ExitReason::Succeed(_) => Ok(()),
+			_ => Err(revert("custody transfer failed")),
+		}
+	}
+}
+
+pub struct WrappedNativeBalancesStorageInstance;
+impl StorageInstance for WrappedNativeBalancesStorageInstance {
+	const STORAGE_PREFIX: &'static str = "Balances";
+	fn pallet_prefix() -> &'static str {
+		"MockWrappedNative"
+	}
+}
+pub type WrappedNativeBalances =
+	StorageMap<WrappedNativeBalancesStorageInstance, Blake2_128Concat, H160, U256, ValueQuery>;
+
+/// WETH9-like wrapped native token. Wrapped balances must be backed by the native balance of the
+/// precompile address, which is what `withdraw` sends back to the caller.
+pub struct MockWrappedNative<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> MockWrappedNative<Runtime>
+where
+	Runtime: pallet_evm::Config + pallet_balances::Config<Balance = u128>,
+{
+	#[precompile::public("balanceOf(address)")]
+	#[precompile::view]
+	fn balance_of(_handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+		Ok(WrappedNativeBalances::get(H160::from(owner)))
+	}
+
+	#[precompile::public("transfer(address,uint256)")]
+	fn transfer(handle: &mut impl PrecompileHandle, to: Address, value: U256) -> EvmResult<bool> {
+		let caller = handle.context().caller;
+		let balance = WrappedNativeBalances::get(caller);
+		if balance < value {
+			return Err(revert("insufficient balance"));
+		}
+
+		WrappedNativeBalances::insert(caller, balance - value);
+		WrappedNativeBalances::mutate(H160::from(to), |balance| *balance += value);
+		Ok(true)
+	}
+
+	#[precompile::public("withdraw(uint256)")]
+	fn withdraw(handle: &mut impl PrecompileHandle, value: U256) -> EvmResult {
+		let caller = handle.context().caller;
+		let balance = WrappedNativeBalances::get(caller);
+		if balance < value {
+			return Err(revert("insufficient balance"));
+		}
+		WrappedNativeBalances::insert(caller, balance - value);
+
+		let amount: u128 = value
+			.try_into()
+			.map_err(|_| revert("value overflows balance"))?;
+		<pallet_balances::Pallet<Runtime> as Currency<_>>::transfer(
+			&Runtime::AddressMapping::into_account_id(handle.code_address()),
+			&Runtime::AddressMapping::into_account_id(caller),
+			amount,
+			ExistenceRequirement::AllowDeath,
+		)
+		.map_err(|_| revert("native transfer failed"))?;
+
+		Ok(())
+	}
+}
@@ -22,11 +22,11 @@ use frame_support::{
 	dispatch::Weight,
 	traits::{ConstU32, PalletInfo, PalletInfoAccess},
 	weights::constants::WEIGHT_REF_TIME_PER_SECOND,
-	BoundedVec,
+	BoundedVec, StorageHasher,
 };
 use pallet_asset_manager::LocalAssetIdCreator;
 use pallet_xcm_transactor::{
-	Currency, CurrencyPayment, HrmpInitParams, HrmpOperation, TransactWeights,
+	Currency, CurrencyPayment, HrmpInitParams, HrmpOperation, TransactOutcome, TransactWeights,
 };
 use sp_std::boxed::Box;
 use xcm::latest::prelude::*;
@@ -39,6 +39,17 @@ use xcm_simulator::TestExt;
 mod common;
 use common::ExtBuilder;
 use cumulus_primitives_core::relay_chain::HrmpChannelId;
+use fp_evm::ExitReason;
+use pallet_evm::Runner;
+use pallet_evm_precompile_gmp::{
+	types::{
+		VersionedUserAction, WormholeTransferWithPayloadData, XcmRoutingUserAction,
+		XcmRoutingUserActionWithFee,
+	},
+	GmpPrecompileCall,
+};
+use pallet_evm_precompileset_assets_erc20::AccountIdAssetIdConversion;
+use sp_core::{H160, H256, U256};
 // Send a relay asset (like DOT) to a parachain A
 #[test]
 fn receive_relay_asset_from_relay() {
@@ -3093,6 +3104,110 @@ fn transact_through_signed_multilocation_custom_fee_and_weight_refund() {
 	});
 }
 
+#[test]
+fn transact_through_signed_multilocation_with_report() {
+	MockNet::reset();
+	let mut ancestry = MultiLocation::parent();
+
+	ParaA::execute_with(|| {
+		ancestry = parachain::UniversalLocation::get().into();
+	});
+
+	// Let's construct the Junction that we will append with DescendOrigin
+	let signed_origin: Junctions = X1(AccountKey20 {
+		network: None,
+		key: PARAALICE,
+	});
+
+	let mut descend_origin_multilocation = parachain::SelfLocation::get();
+	descend_origin_multilocation
+		.append_with(signed_origin)
+		.unwrap();
+
+	// To convert it to what the relay will see instead of us
+	descend_origin_multilocation
+		.reanchor(&MultiLocation::parent(), ancestry.interior)
+		.unwrap();
+
+	let derived = xcm_builder::Account32Hash::<
+		relay_chain::KusamaNetwork,
+		relay_chain::AccountId,
+	>::convert_ref(descend_origin_multilocation)
+	.unwrap();
+
+	Relay::execute_with(|| {
+		// free execution, full amount received
+		assert_ok!(RelayBalances::transfer(
+			relay_chain::RuntimeOrigin::signed(RELAYALICE),
+			derived.clone(),
+			4000009100u128,
+		));
+		// derived account has all funds
+		assert!(RelayBalances::free_balance(&derived) == 4000009100);
+		// sovereign account has 0 funds
+		assert!(RelayBalances::free_balance(&para_a_account()) == 0);
+	});
+
+	// Encode the call. Balances transact to para_a_account
+	// First index
+	let mut encoded: Vec<u8> = Vec::new();
+	let index = <relay_chain::Runtime as frame_system::Config>::PalletInfo::index::<
+		relay_chain::Balances,
+	>()
+	.unwrap() as u8;
+
+	encoded.push(index);
+
+	// Then call bytes
+	let mut call_bytes = pallet_balances::Call::<relay_chain::Runtime>::transfer {
+		// 100 to sovereign
+		dest: para_a_account(),
+		value: 100u32.into(),
+	}
+	.encode();
+	encoded.append(&mut call_bytes);
+
+	let total_weight = 4000009000u64;
+	ParaA::execute_with(|| {
+		assert_ok!(XcmTransactor::transact_through_signed_with_report(
+			parachain::RuntimeOrigin::signed(PARAALICE.into()),
+			Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::parent())),
+			CurrencyPayment {
+				currency: Currency::AsMultiLocation(Box::new(xcm::VersionedMultiLocation::V3(
+					MultiLocation::parent()
+				))),
+				fee_amount: Some(total_weight as u128)
+			},
+			encoded,
+			// 4000000000 for transfer + 9000 for XCM
+			TransactWeights {
+				transact_required_weight_at_most: 4000000000.into(),
+				overall_weight: Some(total_weight.into())
+			},
+			false
+		));
+
+		// The transact is pending until the relay reports back
+		let (_, info) = pallet_xcm_transactor::TransactStatus::<parachain::Runtime>::iter()
+			.next()
+			.unwrap();
+		assert_eq!(info.outcome, TransactOutcome::Pending);
+	});
+
+	Relay::execute_with(|| {
+		// 100 transferred
+		assert_eq!(RelayBalances::free_balance(&para_a_account()), 100);
+	});
+
+	ParaA::execute_with(|| {
+		// The relay reported back the successful dispatch
+		let (_, info) = pallet_xcm_transactor::TransactStatus::<parachain::Runtime>::iter()
+			.next()
+			.unwrap();
+		assert_eq!(info.outcome, TransactOutcome::Success);
+	});
+}
+
 #[test]
 fn transact_through_signed_multilocation_para_to_para() {
 	MockNet::reset();
@@ -3830,6 +3945,334 @@ fn hrmp_close_works() {
 	});
 }
 
+const GMP_GAS_LIMIT: u64 = 10_000_000_000_000;
+const GMP_RECIPIENT: [u8; 20] = [5u8; 20];
+
+// Enables the GMP precompile in para A and points it to the mock Wormhole contracts
+fn enable_gmp() {
+	put_gmp_storage(b"PrecompileEnabled", true.encode());
+	put_gmp_storage(
+		b"CoreAddress",
+		H160::from_low_u64_be(parachain::WORMHOLE_CORE_ADDRESS).encode(),
+	);
+	put_gmp_storage(
+		b"BridgeAddress",
+		H160::from_low_u64_be(parachain::WORMHOLE_BRIDGE_ADDRESS).encode(),
+	);
+}
+
+fn put_gmp_storage(item: &[u8], value: Vec<u8>) {
+	frame_support::storage::unhashed::put_raw(
+		&frame_support::storage::storage_prefix(b"gmp", item),
+		&value,
+	);
+}
+
+// Registers the para B token in para A and moves `amount` of it into the custody of the mock
+// Wormhole bridge, returning the asset id and its ERC-20 address in para A
+fn setup_gmp_custody(amount: u128) -> (parachain::AssetId, H160) {
+	let para_b_balances = MultiLocation::new(1, X2(Parachain(2), PalletInstance(1u8)));
+	let source_location = parachain::AssetType::Xcm(para_b_balances);
+	let source_id: parachain::AssetId = source_location.clone().into();
+	let bridge = H160::from_low_u64_be(parachain::WORMHOLE_BRIDGE_ADDRESS);
+
+	let asset_metadata = parachain::AssetMetadata {
+		name: b"ParaBToken".to_vec(),
+		symbol: b"ParaB".to_vec(),
+		decimals: 18,
+	};
+
+	ParaA::execute_with(|| {
+		assert_ok!(AssetManager::register_foreign_asset(
+			parachain::RuntimeOrigin::root(),
+			source_location.clone(),
+			asset_metadata,
+			1u128,
+			true
+		));
+		assert_ok!(AssetManager::set_asset_units_per_second(
+			parachain::RuntimeOrigin::root(),
+			source_location,
+			0u128,
+			0
+		));
+
+		enable_gmp();
+	});
+
+	let dest = MultiLocation {
+		parents: 1,
+		interior: X2(
+			Parachain(1),
+			AccountKey20 {
+				network: None,
+				key: bridge.into(),
+			},
+		),
+	};
+	ParaB::execute_with(|| {
+		assert_ok!(XTokens::transfer(
+			parachain::RuntimeOrigin::signed(PARAALICE.into()),
+			parachain::CurrencyId::SelfReserve,
+			amount,
+			Box::new(VersionedMultiLocation::V3(dest)),
+			WeightLimit::Limited(Weight::from_parts(80u64, DEFAULT_PROOF_SIZE))
+		));
+	});
+
+	ParaA::execute_with(|| {
+		assert_eq!(Assets::balance(source_id, &bridge.into()), amount);
+	});
+
+	let token = <parachain::Runtime as AccountIdAssetIdConversion<_, _>>::asset_id_to_account(
+		parachain::FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX,
+		source_id,
+	);
+	(source_id, token.into())
+}
+
+fn gmp_vaa(token: H160, amount: u128, to: H160, user_action: VersionedUserAction) -> Vec<u8> {
+	wormhole::encode_vaa(WormholeTransferWithPayloadData {
+		payload_id: 3,
+		amount: amount.into(),
+		token_address: token.into(),
+		token_chain: 16,
+		to: to.into(),
+		to_chain: 16,
+		from_address: H256::zero(),
+		payload: user_action.encode().into(),
+	})
+}
+
+fn gmp_recipient_on_para_b() -> VersionedMultiLocation {
+	VersionedMultiLocation::V3(MultiLocation {
+		parents: 1,
+		interior: X2(
+			Parachain(2),
+			AccountKey20 {
+				network: None,
+				key: GMP_RECIPIENT,
+			},
+		),
+	})
+}
+
+fn call_gmp(vaa: Vec<u8>) -> ExitReason {
+	let input: Vec<u8> = GmpPrecompileCall::<parachain::Runtime>::wormhole_transfer_erc20 {
+		wormhole_vaa: vaa.into(),
+	}
+	.into();
+
+	<parachain::Runtime as pallet_evm::Config>::Runner::call(
+		PARAALICE.into(),
+		H160::from_low_u64_be(parachain::GMP_PRECOMPILE_ADDRESS),
+		input,
+		U256::zero(),
+		GMP_GAS_LIMIT,
+		None,
+		None,
+		None,
+		Vec::new(),
+		false,
+		false,
+		None,
+		None,
+		<parachain::Runtime as pallet_evm::Config>::config(),
+	)
+	.expect("the runner does not fail")
+	.exit_reason
+}
+
+#[test]
+fn gmp_wormhole_transfer_erc20_reaches_sibling() {
+	MockNet::reset();
+
+	let (source_id, token) = setup_gmp_custody(100);
+	let gmp: parachain::AccountId = H160::from_low_u64_be(parachain::GMP_PRECOMPILE_ADDRESS).into();
+	let bridge: parachain::AccountId =
+		H160::from_low_u64_be(parachain::WORMHOLE_BRIDGE_ADDRESS).into();
+
+	ParaA::execute_with(|| {
+		let user_action = VersionedUserAction::V1(XcmRoutingUserAction {
+			destination: gmp_recipient_on_para_b(),
+		});
+		let exit_reason = call_gmp(gmp_vaa(token, 100, gmp.into(), user_action));
+		assert!(exit_reason.is_succeed(), "{:?}", exit_reason);
+
+		// The bridge released its custody to the precompile, which forwarded it all
+		assert_eq!(Assets::balance(source_id, &bridge), 0);
+		assert_eq!(Assets::balance(source_id, &gmp), 0);
+	});
+
+	ParaB::execute_with(|| {
+		// Weight used is 4
+		assert_eq!(ParaBalances::free_balance(&GMP_RECIPIENT.into()), 96);
+	});
+}
+
+#[test]
+fn gmp_wormhole_transfer_erc20_with_fee_pays_the_relayer() {
+	MockNet::reset();
+
+	let (source_id, token) = setup_gmp_custody(100);
+	let gmp = H160::from_low_u64_be(parachain::GMP_PRECOMPILE_ADDRESS);
+
+	ParaA::execute_with(|| {
+		let user_action = VersionedUserAction::V2(XcmRoutingUserActionWithFee {
+			destination: gmp_recipient_on_para_b(),
+			fee: 10u128.into(),
+		});
+		let exit_reason = call_gmp(gmp_vaa(token, 100, gmp, user_action));
+		assert!(exit_reason.is_succeed(), "{:?}", exit_reason);
+
+		// The caller relaying the VAA keeps the fee on para A
+		assert_eq!(Assets::balance(source_id, &PARAALICE.into()), 10);
+		assert_eq!(Assets::balance(source_id, &gmp.into()), 0);
+	});
+
+	ParaB::execute_with(|| {
+		// Weight used is 4
+		assert_eq!(ParaBalances::free_balance(&GMP_RECIPIENT.into()), 86);
+	});
+}
+
+#[test]
+fn gmp_wormhole_transfer_erc20_unwraps_wrapped_native_token() {
+	MockNet::reset();
+
+	// this represents the native token of para A in para B
+	let para_a_balances = MultiLocation::new(1, X2(Parachain(1), PalletInstance(1u8)));
+	let source_location = parachain::AssetType::Xcm(para_a_balances);
+	let source_id: parachain::AssetId = source_location.clone().into();
+
+	let asset_metadata = parachain::AssetMetadata {
+		name: b"ParaAToken".to_vec(),
+		symbol: b"ParaA".to_vec(),
+		decimals: 18,
+	};
+
+	ParaB::execute_with(|| {
+		assert_ok!(AssetManager::register_foreign_asset(
+			parachain::RuntimeOrigin::root(),
+			source_location.clone(),
+			asset_metadata,
+			1u128,
+			true
+		));
+		assert_ok!(AssetManager::set_asset_units_per_second(
+			parachain::RuntimeOrigin::root(),
+			source_location,
+			0u128,
+			0
+		));
+	});
+
+	let gmp = H160::from_low_u64_be(parachain::GMP_PRECOMPILE_ADDRESS);
+	let bridge = H160::from_low_u64_be(parachain::WORMHOLE_BRIDGE_ADDRESS);
+	let wrapped_native = H160::from_low_u64_be(parachain::WRAPPED_NATIVE_ADDRESS);
+
+	ParaA::execute_with(|| {
+		enable_gmp();
+		put_gmp_storage(b"WrappedNativeAddress", wrapped_native.encode());
+
+		// The bridge holds 100 wrapped tokens in custody, backed by native balance
+		wormhole::WrappedNativeBalances::insert(bridge, U256::from(100));
+		assert_ok!(ParaBalances::transfer(
+			parachain::RuntimeOrigin::signed(PARAALICE.into()),
+			wrapped_native.into(),
+			100
+		));
+
+		let user_action = VersionedUserAction::V2(XcmRoutingUserActionWithFee {
+			destination: gmp_recipient_on_para_b(),
+			fee: 10u128.into(),
+		});
+		let exit_reason = call_gmp(gmp_vaa(wrapped_native, 100, gmp, user_action));
+		assert!(exit_reason.is_succeed(), "{:?}", exit_reason);
+
+		// The fee is paid in wrapped tokens, the rest is unwrapped and sent as native tokens
+		assert_eq!(
+			wormhole::WrappedNativeBalances::get(H160::from(PARAALICE)),
+			U256::from(10)
+		);
+		assert_eq!(wormhole::WrappedNativeBalances::get(gmp), U256::zero());
+		assert_eq!(ParaBalances::free_balance(&wrapped_native.into()), 10);
+		assert_eq!(
+			ParaBalances::free_balance(&pallet_evm_precompile_gmp::native_escrow().into()),
+			0
+		);
+	});
+
+	ParaB::execute_with(|| {
+		// Free execution, full amount received
+		assert_eq!(Assets::balance(source_id, &GMP_RECIPIENT.into()), 90);
+	});
+}
+
+#[test]
+fn gmp_wormhole_transfer_erc20_not_addressed_to_gmp_is_rejected() {
+	MockNet::reset();
+
+	let (source_id, token) = setup_gmp_custody(100);
+	let bridge: parachain::AccountId =
+		H160::from_low_u64_be(parachain::WORMHOLE_BRIDGE_ADDRESS).into();
+
+	ParaA::execute_with(|| {
+		let user_action = VersionedUserAction::V1(XcmRoutingUserAction {
+			destination: gmp_recipient_on_para_b(),
+		});
+		let exit_reason = call_gmp(gmp_vaa(token, 100, PARAALICE.into(), user_action));
+		assert!(!exit_reason.is_succeed());
+
+		// Nothing left the bridge custody
+		assert_eq!(Assets::balance(source_id, &bridge), 100);
+	});
+
+	ParaB::execute_with(|| {
+		assert_eq!(ParaBalances::free_balance(&GMP_RECIPIENT.into()), 0);
+	});
+}
+
+#[test]
+fn gmp_wormhole_transfer_erc20_below_minimum_is_rejected() {
+	MockNet::reset();
+
+	let (source_id, token) = setup_gmp_custody(100);
+	let gmp = H160::from_low_u64_be(parachain::GMP_PRECOMPILE_ADDRESS);
+	let bridge: parachain::AccountId =
+		H160::from_low_u64_be(parachain::WORMHOLE_BRIDGE_ADDRESS).into();
+
+	ParaA::execute_with(|| {
+		let mut item = b"MinimumTransferAmount".to_vec();
+		item.extend(frame_support::Blake2_128Concat::hash(&token.encode()));
+		put_gmp_storage(&item, U256::from(91).encode());
+
+		// 90 would be sent once the fee is paid
+		let user_action = VersionedUserAction::V2(XcmRoutingUserActionWithFee {
+			destination: gmp_recipient_on_para_b(),
+			fee: 10u128.into(),
+		});
+		let exit_reason = call_gmp(gmp_vaa(token, 100, gmp, user_action));
+		assert!(!exit_reason.is_succeed());
+
+		// Nothing left the bridge custody, the VAA can be redeemed once the minimum is lowered
+		assert_eq!(Assets::balance(source_id, &bridge), 100);
+
+		put_gmp_storage(&item, U256::from(90).encode());
+		let user_action = VersionedUserAction::V2(XcmRoutingUserActionWithFee {
+			destination: gmp_recipient_on_para_b(),
+			fee: 10u128.into(),
+		});
+		let exit_reason = call_gmp(gmp_vaa(token, 100, gmp, user_action));
+		assert!(exit_reason.is_succeed(), "{:?}", exit_reason);
+	});
+
+	ParaB::execute_with(|| {
+		// Weight used is 4
+		assert_eq!(ParaBalances::free_balance(&GMP_RECIPIENT.into()), 86);
+	});
+}
+
 use parity_scale_codec::{Decode, Encode};
 use sp_io::hashing::blake2_256;
 
@@ -16,10 +16,20 @@
 
 use substrate_wasm_builder::WasmBuilder;
 
-fn main() {
+fn build() {
 	WasmBuilder::new()
 		.with_current_project()
 		.export_heap_base()
 		.import_memory()
 		.build()
 }
+
+#[cfg(not(feature = "metadata-hash"))]
+fn main() {
+	build()
+}
+
+#[cfg(feature = "metadata-hash")]
+fn main() {
+	moonbeam_metadata_hash::build_with_metadata_hash(build, "GLMR", 18)
+}
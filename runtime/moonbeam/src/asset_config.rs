@@ -19,7 +19,7 @@
 
 use super::{
 	currency, governance, xcm_config, AccountId, AssetId, AssetManager, Assets, Balance, Balances,
-	CouncilInstance, LocalAssets, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
+	CouncilInstance, LocalAssets, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, DAYS,
 	FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX, LOCAL_ASSET_PRECOMPILE_ADDRESS_PREFIX,
 };
 
@@ -29,9 +29,9 @@ use frame_support::{
 	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, EitherOfDiverse},
 	weights::Weight,
 };
-use moonbeam_runtime_common::weights as moonbeam_weights;
+use moonbeam_runtime_common::{weights as moonbeam_weights, xc20};
 use pallet_evm_precompileset_assets_erc20::AccountIdAssetIdConversion;
-use sp_runtime::traits::Hash as THash;
+use sp_runtime::traits::{Convert, Hash as THash};
 
 use frame_system::{EnsureNever, EnsureRoot};
 use sp_core::{H160, H256};
@@ -162,14 +162,8 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 			min_balance,
 		)?;
 
-		// TODO uncomment when we feel comfortable
-		/*
 		// The asset has been created. Let's put the revert code in the precompile address
-		let precompile_address = Runtime::asset_id_to_account(ASSET_PRECOMPILE_ADDRESS_PREFIX, asset);
-		pallet_evm::AccountCodes::<Runtime>::insert(
-			precompile_address,
-			vec![0x60, 0x00, 0x60, 0x00, 0xfd],
-		);*/
+		xc20::insert_revert_code::<Runtime>(ForeignAssetPrecompileAddress::convert(asset));
 
 		// Lastly, the metadata
 		Assets::force_set_metadata(
@@ -206,10 +200,7 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 		// The asset has been created. Let's put the revert code in the precompile address
 		let precompile_address: H160 =
 			Runtime::asset_id_to_account(LOCAL_ASSET_PRECOMPILE_ADDRESS_PREFIX, asset).into();
-		pallet_evm::AccountCodes::<Runtime>::insert(
-			precompile_address,
-			vec![0x60, 0x00, 0x60, 0x00, 0xfd],
-		);
+		xc20::insert_revert_code::<Runtime>(precompile_address);
 		Ok(())
 	}
 
@@ -219,10 +210,7 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 		Assets::start_destroy(RuntimeOrigin::root(), asset.into())?;
 
 		// We remove the EVM revert code
-		// This does not panick even if there is no code in the address
-		let precompile_address: H160 =
-			Runtime::asset_id_to_account(FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX, asset).into();
-		pallet_evm::AccountCodes::<Runtime>::remove(precompile_address);
+		xc20::remove_revert_code::<Runtime>(ForeignAssetPrecompileAddress::convert(asset));
 		Ok(())
 	}
 
@@ -232,10 +220,9 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 		LocalAssets::start_destroy(RuntimeOrigin::root(), asset.into())?;
 
 		// We remove the EVM revert code
-		// This does not panick even if there is no code in the address
 		let precompile_address: H160 =
 			Runtime::asset_id_to_account(LOCAL_ASSET_PRECOMPILE_ADDRESS_PREFIX, asset).into();
-		pallet_evm::AccountCodes::<Runtime>::remove(precompile_address);
+		xc20::remove_revert_code::<Runtime>(precompile_address);
 		Ok(())
 	}
 
@@ -261,6 +248,14 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 	}
 }
 
+/// Address of the XC-20 precompile of a foreign asset.
+pub struct ForeignAssetPrecompileAddress;
+impl Convert<AssetId, H160> for ForeignAssetPrecompileAddress {
+	fn convert(asset: AssetId) -> H160 {
+		Runtime::asset_id_to_account(FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX, asset).into()
+	}
+}
+
 pub struct LocalAssetIdCreator;
 impl pallet_asset_manager::LocalAssetIdCreator<Runtime> for LocalAssetIdCreator {
 	fn create_asset_id_from_metadata(local_asset_counter: u128) -> AssetId {
@@ -309,9 +304,19 @@ impl pallet_asset_manager::Config for Runtime {
 	type LocalAssetIdCreator = LocalAssetIdCreator;
 	type Currency = Balances;
 	type LocalAssetDeposit = AssetDeposit;
+	type AssetTypeAliasPeriod = ConstU32<{ 7 * DAYS }>;
 	type WeightInfo = moonbeam_weights::pallet_asset_manager::WeightInfo<Runtime>;
 }
 
+impl pallet_xc20_issuance::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type Assets = Assets;
+	type ResetOrigin = ForeignAssetModifierOrigin;
+	type WeightInfo = moonbeam_weights::pallet_xc20_issuance::WeightInfo<Runtime>;
+}
+
 // Instruct how to go from an H160 to an AssetID
 // We just take the lowest 128 bits
 impl AccountIdAssetIdConversion<AccountId, AssetId> for Runtime {
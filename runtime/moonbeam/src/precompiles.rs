@@ -42,6 +42,8 @@ use pallet_evm_precompile_registry::PrecompileRegistry;
 use pallet_evm_precompile_relay_encoder::RelayEncoderPrecompile;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use pallet_evm_precompile_xc20_issuance::Xc20IssuancePrecompile;
+use pallet_evm_precompile_xcm_pause::XcmPausePrecompile;
 use pallet_evm_precompile_xcm_transactor::{
 	v1::XcmTransactorPrecompileV1, v2::XcmTransactorPrecompileV2,
 };
@@ -240,6 +242,12 @@ type MoonbeamPrecompilesAt<R> = (
 		XcmTransactorPrecompileV3<R>,
 		(CallableByContract, CallableByPrecompile),
 	>, */
+	PrecompileAt<
+		AddressU64<2073>,
+		Xc20IssuancePrecompile<R, ForeignAssetPrefix>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<AddressU64<2074>, XcmPausePrecompile<R, OpenTechCommitteeInstance>>,
 );
 
 /// The PrecompileSet installed in the Moonbeam runtime.
@@ -18,9 +18,10 @@
 //!
 
 use super::{
-	governance, AccountId, AssetId, AssetManager, Assets, Balance, Balances, DealWithFees,
-	Erc20XcmBridge, LocalAssets, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall,
-	RuntimeEvent, RuntimeOrigin, Treasury, XcmpQueue, FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX,
+	governance, AccountId, AssetId, AssetManager, Assets, Balance, Balances, BlockNumber,
+	DealWithFees, Erc20XcmBridge, LocalAssets, ParachainInfo, ParachainSystem, PolkadotXcm,
+	Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Treasury, XcmpQueue,
+	FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX, HOURS,
 };
 
 use moonbeam_runtime_common::weights as moonbeam_weights;
@@ -123,20 +124,17 @@ impl xcm_executor::traits::Convert<MultiLocation, H160> for LocationToH160 {
 	}
 }
 
+// Matches the foreign assets registered in pallet-asset-manager
+pub type ForeignAssetsMatcher =
+	ConvertedConcreteId<AssetId, Balance, AsAssetType<AssetId, AssetType, AssetManager>, JustTry>;
+
 // The non-reserve fungible transactor type
 // It will use pallet-assets, and the Id will be matched against AsAssetType
-pub type ForeignFungiblesTransactor = FungiblesAdapter<
+pub type ForeignFungiblesAdapter = FungiblesAdapter<
 	// Use this fungibles implementation:
 	Assets,
 	// Use this currency when it is a fungible asset matching the given location or name:
-	(
-		ConvertedConcreteId<
-			AssetId,
-			Balance,
-			AsAssetType<AssetId, AssetType, AssetManager>,
-			JustTry,
-		>,
-	),
+	(ForeignAssetsMatcher,),
 	// Do a simple punn to convert an AccountId20 MultiLocation into a native chain account ID:
 	LocationToAccountId,
 	// Our chain's account ID type (we can't get away without mentioning it explicitly):
@@ -147,6 +145,14 @@ pub type ForeignFungiblesTransactor = FungiblesAdapter<
 	(),
 >;
 
+// Records the XCM mints and burns of foreign assets, so that they can be reconciled with the
+// assets total issuance
+pub type ForeignFungiblesTransactor = pallet_xc20_issuance::IssuanceTrackingTransactor<
+	Runtime,
+	ForeignAssetsMatcher,
+	ForeignFungiblesAdapter,
+>;
+
 /// The transactor for our own chain currency.
 pub type LocalAssetTransactor = XcmCurrencyAdapter<
 	// Use this currency:
@@ -231,22 +237,27 @@ pub type XcmWeigher = WeightInfoBounds<
 	MaxInstructions,
 >;
 
-pub type XcmBarrier = (
-	// Weight that is paid for may be consumed.
-	TakeWeightCredit,
-	// Expected responses are OK.
-	AllowKnownQueryResponses<PolkadotXcm>,
-	WithComputedOrigin<
-		(
-			// If the message is one that immediately attemps to pay for execution, then allow it.
-			AllowTopLevelPaidExecutionFrom<Everything>,
-			// Subscriptions for version tracking are OK.
-			AllowSubscriptionsFrom<Everything>,
-		),
-		UniversalLocation,
-		ConstU32<8>,
-	>,
-);
+/// The barriers configured here are applied after the origin filters set by governance in
+/// `pallet_xcm_filter`.
+pub type XcmBarrier = pallet_xcm_filter::XcmFilterBarrier<
+	Runtime,
+	(
+		// Weight that is paid for may be consumed.
+		TakeWeightCredit,
+		// Expected responses are OK.
+		AllowKnownQueryResponses<PolkadotXcm>,
+		WithComputedOrigin<
+			(
+				// If the message is one that immediately attemps to pay for execution, then allow it.
+				AllowTopLevelPaidExecutionFrom<Everything>,
+				// Subscriptions for version tracking are OK.
+				AllowSubscriptionsFrom<Everything>,
+			),
+			UniversalLocation,
+			ConstU32<8>,
+		>,
+	),
+>;
 
 parameter_types! {
 	/// Xcm fees will go to the treasury account
@@ -270,6 +281,10 @@ pub type XcmFeesToAccount = xcm_primitives::XcmFeesToAccount<
 	XcmFeesAccount,
 >;
 
+/// Units per second of the foreign assets quoted by the XCM payment runtime API, which must be
+/// the ones charged by the `FirstAssetTrader`.
+pub type ForeignAssetUnitsPerSecond = AssetManager;
+
 pub struct SafeCallFilter;
 impl frame_support::traits::Contains<RuntimeCall> for SafeCallFilter {
 	fn contains(_call: &RuntimeCall) -> bool {
@@ -311,7 +326,8 @@ impl xcm_executor::Config for XcmExecutorConfig {
 	// When we receive the relative representation of the self-reserve asset,
 	// we use UsingComponents and the local way of handling fees
 	// When we receive a non-reserve asset, we use AssetManager to fetch how many
-	// units per second we should charge
+	// units per second we should charge, and in which asset according to its fee asset
+	// preference when the message holds several of them
 	type Trader = (
 		UsingComponents<
 			<Runtime as pallet_transaction_payment::Config>::WeightToFee,
@@ -616,6 +632,32 @@ impl XcmTransact for Transactors {
 pub type DerivativeAddressRegistrationOrigin =
 	EitherOfDiverse<EnsureRoot<AccountId>, governance::custom_origins::GeneralAdmin>;
 
+parameter_types! {
+	/// Blocks during which a transact status query waits for the destination chain to respond
+	pub const TransactStatusQueryTimeout: BlockNumber = HOURS;
+}
+
+/// Registers the transact status queries in pallet-xcm, which notifies the xcm-transactor
+/// pallet once the destination chain responds
+pub struct TransactStatusQuerier;
+impl pallet_xcm_transactor::TransactStatusQuerier for TransactStatusQuerier {
+	fn new_transact_status_query(responder: MultiLocation, querier: MultiLocation) -> QueryId {
+		let notify = pallet_xcm_transactor::Call::<Runtime>::transact_status_notify {
+			query_id: Default::default(),
+			response: Response::Null,
+		};
+		let timeout = frame_system::Pallet::<Runtime>::block_number()
+			.saturating_add(TransactStatusQueryTimeout::get());
+		PolkadotXcm::new_notify_query(responder, notify, timeout, querier)
+	}
+}
+
+parameter_types! {
+	// IndexToAccount: Blake2_128(16) + u16(2) + AccountId(20)
+	// IndexDeposit: Blake2_128(16) + u16(2) + Balance(16)
+	pub const DerivativeIndexDeposit: Balance = super::currency::deposit(2, 72);
+}
+
 impl pallet_xcm_transactor::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -637,6 +679,10 @@ impl pallet_xcm_transactor::Config for Runtime {
 	type HrmpManipulatorOrigin = GeneralAdminOrRoot;
 	type MaxHrmpFee = xcm_builder::Case<MaxHrmpRelayFee>;
 	type HrmpEncoder = moonbeam_relay_encoder::polkadot::PolkadotEncoder;
+	type TransactStatusQuerier = TransactStatusQuerier;
+	type TransactStatusResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+	type DepositCurrency = Balances;
+	type DerivativeIndexDeposit = DerivativeIndexDeposit;
 }
 
 parameter_types! {
@@ -653,13 +699,47 @@ parameter_types! {
 	// To be able to support almost all erc20 implementations,
 	// we provide a sufficiently hight gas limit.
 	pub Erc20XcmBridgeTransferGasLimit: u64 = 200_000;
+
+	// Number of consecutive failed transfers after which an erc20 token is quarantined.
+	pub const Erc20XcmBridgeMaxConsecutiveTransferFailures: u32 = 5;
 }
 
 impl pallet_erc20_xcm_bridge::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type AccountIdConverter = LocationToH160;
 	type Erc20MultilocationPrefix = Erc20XcmBridgePalletLocation;
 	type Erc20TransferGasLimit = Erc20XcmBridgeTransferGasLimit;
 	type EvmRunner = EvmRunnerPrecompileOrEthXcm<MoonbeamCall, Self>;
+	type Erc20ManagerOrigin = GeneralAdminOrRoot;
+	type MaxConsecutiveTransferFailures = Erc20XcmBridgeMaxConsecutiveTransferFailures;
+	type WeightInfo = moonbeam_weights::pallet_erc20_xcm_bridge::WeightInfo<Runtime>;
+}
+
+/// Governance can suspend or restrict inbound XCM from an origin, the Open Tech Committee being
+/// able to react quickly to a misbehaving channel.
+pub type XcmFilterOrigin = EitherOfDiverse<
+	GeneralAdminOrRoot,
+	pallet_collective::EnsureProportionAtLeast<
+		AccountId,
+		governance::councils::OpenTechCommitteeInstance,
+		5,
+		9,
+	>,
+>;
+
+/// Any member of the Open Tech Committee can pause XCM in an emergency, through the XCM pause
+/// precompile or a collective proposal.
+pub type XcmPauseOrigin = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureMember<AccountId, governance::councils::OpenTechCommitteeInstance>,
+>;
+
+impl pallet_xcm_filter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type FilterOrigin = XcmFilterOrigin;
+	type PauseOrigin = XcmPauseOrigin;
+	type XcmExecutionManager = crate::EmergencyXcmExecutionManager;
+	type WeightInfo = moonbeam_weights::pallet_xcm_filter::WeightInfo<Runtime>;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
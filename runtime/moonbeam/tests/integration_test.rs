@@ -21,15 +21,15 @@
 mod common;
 use common::*;
 
-use fp_evm::{Context, IsPrecompileResult};
+use fp_evm::Context;
 use frame_support::{
 	assert_noop, assert_ok,
 	dispatch::{DispatchClass, Dispatchable},
 	traits::{
 		fungible::Inspect, fungibles::Inspect as FungiblesInspect, Currency as CurrencyT,
-		EnsureOrigin, PalletInfo, StorageInfo, StorageInfoTrait,
+		PalletInfo, StorageInfo, StorageInfoTrait,
 	},
-	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
+	weights::Weight,
 	StorageHasher, Twox128,
 };
 use moonbeam_runtime::{
@@ -42,7 +42,6 @@ use moonbeam_runtime::{
 	XTokens, XcmTransactor, FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX,
 	LOCAL_ASSET_PRECOMPILE_ADDRESS_PREFIX,
 };
-use moonbeam_xcm_benchmarks::weights::XcmWeight;
 use nimbus_primitives::NimbusId;
 use pallet_evm::PrecompileSet;
 use pallet_evm_precompileset_assets_erc20::{
@@ -51,28 +50,17 @@ use pallet_evm_precompileset_assets_erc20::{
 use pallet_transaction_payment::Multiplier;
 use pallet_xcm_transactor::{Currency, CurrencyPayment, TransactWeights};
 use parity_scale_codec::Encode;
-use polkadot_parachain::primitives::Sibling;
-use precompile_utils::{
-	precompile_set::{is_precompile_or_fail, IsActivePrecompile},
-	prelude::*,
-	testing::*,
-};
+use precompile_utils::{precompile_set::is_precompile_or_fail, prelude::*, testing::*};
 use sha3::{Digest, Keccak256};
 use sp_core::{ByteArray, Pair, H160, U256};
 use sp_runtime::{traits::Convert, DispatchError, ModuleError, TokenError};
 use std::str::from_utf8;
 use xcm::latest::prelude::*;
 use xcm::{VersionedMultiAsset, VersionedMultiAssets, VersionedMultiLocation};
-use xcm_builder::{ParentIsPreset, SiblingParachainConvertsVia};
-use xcm_executor::traits::Convert as XcmConvert;
 
 type BatchPCall = pallet_evm_precompile_batch::BatchPrecompileCall<Runtime>;
 type CrowdloanRewardsPCall =
 	pallet_evm_precompile_crowdloan_rewards::CrowdloanRewardsPrecompileCall<Runtime>;
-type XcmUtilsPCall = pallet_evm_precompile_xcm_utils::XcmUtilsPrecompileCall<
-	Runtime,
-	moonbeam_runtime::xcm_config::XcmExecutorConfig,
->;
 type XtokensPCall = pallet_evm_precompile_xtokens::XtokensPrecompileCall<Runtime>;
 type LocalAssetsPCall = pallet_evm_precompileset_assets_erc20::Erc20AssetsPrecompileSetCall<
 	Runtime,
@@ -84,14 +72,9 @@ type XcmTransactorV2PCall =
 
 const BASE_FEE_GENESIS: u128 = 10000 * GIGAWEI;
 
-#[test]
-fn xcmp_queue_controller_origin_is_root() {
-	// important for the XcmExecutionManager impl of PauseExecution which uses root origin
-	// to suspend/resume XCM execution in xcmp_queue::on_idle
-	assert_ok!(
-		<moonbeam_runtime::Runtime as cumulus_pallet_xcmp_queue::Config
-		>::ControllerOrigin::ensure_origin(root_origin())
-	);
+moonbeam_runtime_common::impl_runtime_integration_tests! {
+	runtime: moonbeam_runtime,
+	unit: GLMR,
 }
 
 #[test]
@@ -148,11 +131,18 @@ fn verify_pallet_prefixes() {
 	is_pallet_prefix::<moonbeam_runtime::Assets>("Assets");
 	is_pallet_prefix::<moonbeam_runtime::XTokens>("XTokens");
 	is_pallet_prefix::<moonbeam_runtime::AssetManager>("AssetManager");
+	is_pallet_prefix::<moonbeam_runtime::Xc20Issuance>("Xc20Issuance");
+	is_pallet_prefix::<moonbeam_runtime::XcmFilter>("XcmFilter");
+	is_pallet_prefix::<moonbeam_runtime::EvmCodeSizeLimit>("EvmCodeSizeLimit");
+	is_pallet_prefix::<moonbeam_runtime::EvmHibernation>("EvmHibernation");
+	is_pallet_prefix::<moonbeam_runtime::Erc20XcmBridge>("Erc20XcmBridge");
 	is_pallet_prefix::<moonbeam_runtime::Migrations>("Migrations");
 	is_pallet_prefix::<moonbeam_runtime::XcmTransactor>("XcmTransactor");
 	is_pallet_prefix::<moonbeam_runtime::ProxyGenesisCompanion>("ProxyGenesisCompanion");
 	is_pallet_prefix::<moonbeam_runtime::LocalAssets>("LocalAssets");
 	is_pallet_prefix::<moonbeam_runtime::MoonbeamOrbiters>("MoonbeamOrbiters");
+	is_pallet_prefix::<moonbeam_runtime::AuthorFilterSchedule>("AuthorFilterSchedule");
+	is_pallet_prefix::<moonbeam_runtime::FeeSplit>("FeeSplit");
 	is_pallet_prefix::<moonbeam_runtime::TreasuryCouncilCollective>("TreasuryCouncilCollective");
 	let prefix = |pallet_name, storage_name| {
 		let mut res = [0u8; 32];
@@ -264,189 +254,6 @@ fn verify_pallet_prefixes() {
 	);
 }
 
-#[test]
-fn test_collectives_storage_item_prefixes() {
-	for StorageInfo { pallet_name, .. } in
-		<moonbeam_runtime::CouncilCollective as StorageInfoTrait>::storage_info()
-	{
-		assert_eq!(pallet_name, b"CouncilCollective".to_vec());
-	}
-
-	for StorageInfo { pallet_name, .. } in
-		<moonbeam_runtime::TechCommitteeCollective as StorageInfoTrait>::storage_info()
-	{
-		assert_eq!(pallet_name, b"TechCommitteeCollective".to_vec());
-	}
-
-	for StorageInfo { pallet_name, .. } in
-		<moonbeam_runtime::TreasuryCouncilCollective as StorageInfoTrait>::storage_info()
-	{
-		assert_eq!(pallet_name, b"TreasuryCouncilCollective".to_vec());
-	}
-
-	for StorageInfo { pallet_name, .. } in
-		<moonbeam_runtime::OpenTechCommitteeCollective as StorageInfoTrait>::storage_info()
-	{
-		assert_eq!(pallet_name, b"OpenTechCommitteeCollective".to_vec());
-	}
-}
-
-#[test]
-fn collective_set_members_root_origin_works() {
-	ExtBuilder::default().build().execute_with(|| {
-		// CouncilCollective
-		assert_ok!(CouncilCollective::set_members(
-			<Runtime as frame_system::Config>::RuntimeOrigin::root(),
-			vec![AccountId::from(ALICE), AccountId::from(BOB)],
-			Some(AccountId::from(ALICE)),
-			2
-		));
-		// TechCommitteeCollective
-		assert_ok!(TechCommitteeCollective::set_members(
-			<Runtime as frame_system::Config>::RuntimeOrigin::root(),
-			vec![AccountId::from(ALICE), AccountId::from(BOB)],
-			Some(AccountId::from(ALICE)),
-			2
-		));
-		// TreasuryCouncilCollective
-		assert_ok!(TreasuryCouncilCollective::set_members(
-			<Runtime as frame_system::Config>::RuntimeOrigin::root(),
-			vec![AccountId::from(ALICE), AccountId::from(BOB)],
-			Some(AccountId::from(ALICE)),
-			2
-		));
-		// OpenTechCommitteeCollective
-		assert_ok!(OpenTechCommitteeCollective::set_members(
-			<Runtime as frame_system::Config>::RuntimeOrigin::root(),
-			vec![AccountId::from(ALICE), AccountId::from(BOB)],
-			Some(AccountId::from(ALICE)),
-			2
-		));
-	});
-}
-
-#[test]
-fn collective_set_members_general_admin_origin_works() {
-	use moonbeam_runtime::{
-		governance::custom_origins::Origin as CustomOrigin, OriginCaller, Utility,
-	};
-
-	ExtBuilder::default().build().execute_with(|| {
-		let root_caller = <Runtime as frame_system::Config>::RuntimeOrigin::root();
-		let alice = AccountId::from(ALICE);
-
-		// CouncilCollective
-		let _ = Utility::dispatch_as(
-			root_caller.clone(),
-			Box::new(OriginCaller::Origins(CustomOrigin::GeneralAdmin)),
-			Box::new(
-				pallet_collective::Call::<Runtime, pallet_collective::Instance1>::set_members {
-					new_members: vec![alice, AccountId::from(BOB)],
-					prime: Some(alice),
-					old_count: 2,
-				}
-				.into(),
-			),
-		);
-		// TechCommitteeCollective
-		let _ = Utility::dispatch_as(
-			root_caller.clone(),
-			Box::new(OriginCaller::Origins(CustomOrigin::GeneralAdmin)),
-			Box::new(
-				pallet_collective::Call::<Runtime, pallet_collective::Instance2>::set_members {
-					new_members: vec![alice, AccountId::from(BOB)],
-					prime: Some(alice),
-					old_count: 2,
-				}
-				.into(),
-			),
-		);
-		// TreasuryCouncilCollective
-		let _ = Utility::dispatch_as(
-			root_caller.clone(),
-			Box::new(OriginCaller::Origins(CustomOrigin::GeneralAdmin)),
-			Box::new(
-				pallet_collective::Call::<Runtime, pallet_collective::Instance3>::set_members {
-					new_members: vec![alice, AccountId::from(BOB)],
-					prime: Some(alice),
-					old_count: 2,
-				}
-				.into(),
-			),
-		);
-		// OpenTechCommitteeCollective
-		let _ = Utility::dispatch_as(
-			root_caller,
-			Box::new(OriginCaller::Origins(CustomOrigin::GeneralAdmin)),
-			Box::new(
-				pallet_collective::Call::<Runtime, pallet_collective::Instance4>::set_members {
-					new_members: vec![alice, AccountId::from(BOB)],
-					prime: Some(alice),
-					old_count: 2,
-				}
-				.into(),
-			),
-		);
-
-		assert_eq!(
-			System::events()
-				.into_iter()
-				.filter_map(|r| {
-					match r.event {
-						RuntimeEvent::Utility(pallet_utility::Event::DispatchedAs { result })
-							if result.is_ok() =>
-						{
-							Some(true)
-						}
-						_ => None,
-					}
-				})
-				.collect::<Vec<_>>()
-				.len(),
-			4
-		)
-	});
-}
-
-#[test]
-fn collective_set_members_signed_origin_does_not_work() {
-	let alice = AccountId::from(ALICE);
-	ExtBuilder::default().build().execute_with(|| {
-		// CouncilCollective
-		assert!(CouncilCollective::set_members(
-			<Runtime as frame_system::Config>::RuntimeOrigin::signed(alice),
-			vec![alice, AccountId::from(BOB)],
-			Some(alice),
-			2
-		)
-		.is_err());
-		// TechCommitteeCollective
-		assert!(TechCommitteeCollective::set_members(
-			<Runtime as frame_system::Config>::RuntimeOrigin::signed(alice),
-			vec![AccountId::from(ALICE), AccountId::from(BOB)],
-			Some(AccountId::from(ALICE)),
-			2
-		)
-		.is_err());
-		// TreasuryCouncilCollective
-		assert!(TreasuryCouncilCollective::set_members(
-			<Runtime as frame_system::Config>::RuntimeOrigin::signed(alice),
-			vec![AccountId::from(ALICE), AccountId::from(BOB)],
-			Some(AccountId::from(ALICE)),
-			2
-		)
-		.is_err());
-		// OpenTechCommitteeCollective
-		assert!(OpenTechCommitteeCollective::set_members(
-			<Runtime as frame_system::Config>::RuntimeOrigin::signed(alice),
-			vec![AccountId::from(ALICE), AccountId::from(BOB)],
-			Some(AccountId::from(ALICE)),
-			2
-		)
-		.is_err());
-	});
-}
-
 #[test]
 fn verify_pallet_indices() {
 	fn is_pallet_index<P: 'static>(index: usize) {
@@ -470,6 +277,7 @@ fn verify_pallet_indices() {
 	is_pallet_index::<moonbeam_runtime::AuthorFilter>(22);
 	is_pallet_index::<moonbeam_runtime::AuthorMapping>(23);
 	is_pallet_index::<moonbeam_runtime::MoonbeamOrbiters>(24);
+	is_pallet_index::<moonbeam_runtime::AuthorFilterSchedule>(25);
 	// Handy utilities
 	is_pallet_index::<moonbeam_runtime::Utility>(30);
 	is_pallet_index::<moonbeam_runtime::Proxy>(31);
@@ -503,6 +311,7 @@ fn verify_pallet_indices() {
 	is_pallet_index::<moonbeam_runtime::XTokens>(106);
 	is_pallet_index::<moonbeam_runtime::XcmTransactor>(107);
 	is_pallet_index::<moonbeam_runtime::LocalAssets>(108);
+	is_pallet_index::<moonbeam_runtime::FeeSplit>(115);
 }
 
 #[test]
@@ -524,83 +333,6 @@ fn verify_reserved_indices() {
 	assert!(reserved.iter().all(|index| !existing.contains(index)));
 }
 
-#[test]
-fn verify_proxy_type_indices() {
-	assert_eq!(moonbeam_runtime::ProxyType::Any as u8, 0);
-	assert_eq!(moonbeam_runtime::ProxyType::NonTransfer as u8, 1);
-	assert_eq!(moonbeam_runtime::ProxyType::Governance as u8, 2);
-	assert_eq!(moonbeam_runtime::ProxyType::Staking as u8, 3);
-	assert_eq!(moonbeam_runtime::ProxyType::CancelProxy as u8, 4);
-	assert_eq!(moonbeam_runtime::ProxyType::Balances as u8, 5);
-	assert_eq!(moonbeam_runtime::ProxyType::AuthorMapping as u8, 6);
-	assert_eq!(moonbeam_runtime::ProxyType::IdentityJudgement as u8, 7);
-}
-
-#[test]
-fn join_collator_candidates() {
-	ExtBuilder::default()
-		.with_balances(vec![
-			(AccountId::from(ALICE), 10_000_000 * GLMR),
-			(AccountId::from(BOB), 10_000_000 * GLMR),
-			(AccountId::from(CHARLIE), 10_000_000 * GLMR),
-			(AccountId::from(DAVE), 10_000_000 * GLMR),
-		])
-		.with_collators(vec![
-			(AccountId::from(ALICE), 2_000_000 * GLMR),
-			(AccountId::from(BOB), 2_000_000 * GLMR),
-		])
-		.with_delegations(vec![
-			(
-				AccountId::from(CHARLIE),
-				AccountId::from(ALICE),
-				5_000 * GLMR,
-			),
-			(AccountId::from(CHARLIE), AccountId::from(BOB), 5_000 * GLMR),
-		])
-		.build()
-		.execute_with(|| {
-			assert_noop!(
-				ParachainStaking::join_candidates(
-					origin_of(AccountId::from(ALICE)),
-					2_000_000 * GLMR,
-					2u32
-				),
-				pallet_parachain_staking::Error::<Runtime>::CandidateExists
-			);
-			assert_noop!(
-				ParachainStaking::join_candidates(
-					origin_of(AccountId::from(CHARLIE)),
-					2_000_000 * GLMR,
-					2u32
-				),
-				pallet_parachain_staking::Error::<Runtime>::DelegatorExists
-			);
-			assert!(System::events().is_empty());
-			assert_ok!(ParachainStaking::join_candidates(
-				origin_of(AccountId::from(DAVE)),
-				2_000_000 * GLMR,
-				2u32
-			));
-			assert_eq!(
-				last_event(),
-				RuntimeEvent::ParachainStaking(
-					pallet_parachain_staking::Event::JoinedCollatorCandidates {
-						account: AccountId::from(DAVE),
-						amount_locked: 2_000_000 * GLMR,
-						new_total_amt_locked: 6_010_000 * GLMR
-					}
-				)
-			);
-			let candidates = ParachainStaking::candidate_pool();
-			assert_eq!(candidates.0[0].owner, AccountId::from(ALICE));
-			assert_eq!(candidates.0[0].amount, 2_005_000 * GLMR);
-			assert_eq!(candidates.0[1].owner, AccountId::from(BOB));
-			assert_eq!(candidates.0[1].amount, 2_005_000 * GLMR);
-			assert_eq!(candidates.0[2].owner, AccountId::from(DAVE));
-			assert_eq!(candidates.0[2].amount, 2_000_000 * GLMR);
-		});
-}
-
 #[test]
 fn transfer_through_evm_to_stake() {
 	ExtBuilder::default()
@@ -1397,22 +1129,6 @@ fn multiplier_can_grow_from_zero() {
 	})
 }
 
-#[test]
-fn ethereum_invalid_transaction() {
-	ExtBuilder::default().build().execute_with(|| {
-		// Ensure an extrinsic not containing enough gas limit to store the transaction
-		// on chain is rejected.
-		assert_eq!(
-			Executive::apply_extrinsic(unchecked_eth_tx(INVALID_ETH_TX)),
-			Err(
-				sp_runtime::transaction_validity::TransactionValidityError::Invalid(
-					sp_runtime::transaction_validity::InvalidTransaction::Custom(3u8)
-				)
-			)
-		);
-	});
-}
-
 #[test]
 fn initial_gas_fee_is_correct() {
 	use fp_evm::FeeCalculator;
@@ -1453,26 +1169,6 @@ fn min_gas_fee_is_correct() {
 	});
 }
 
-#[test]
-fn transfer_ed_0_substrate() {
-	ExtBuilder::default()
-		.with_balances(vec![
-			(AccountId::from(ALICE), (1 * GLMR) + (1 * WEI)),
-			(AccountId::from(BOB), 0),
-		])
-		.build()
-		.execute_with(|| {
-			// Substrate transfer
-			assert_ok!(Balances::transfer(
-				origin_of(AccountId::from(ALICE)),
-				AccountId::from(BOB),
-				1 * GLMR,
-			));
-			// 1 WEI is left in the account
-			assert_eq!(Balances::free_balance(AccountId::from(ALICE)), 1 * WEI);
-		});
-}
-
 #[test]
 fn transfer_ed_0_evm() {
 	ExtBuilder::default()
@@ -1643,66 +1339,29 @@ fn total_issuance_after_evm_transaction_without_priority_fee() {
 }
 
 #[test]
-fn root_can_change_default_xcm_vers() {
-	ExtBuilder::default()
-		.with_balances(vec![
-			(AccountId::from(ALICE), 2_000 * GLMR),
-			(AccountId::from(BOB), 1_000 * GLMR),
-		])
-		.with_xcm_assets(vec![XcmAssetInitialization {
-			asset_type: AssetType::Xcm(MultiLocation::parent()),
-			metadata: AssetRegistrarMetadata {
-				name: b"RelayToken".to_vec(),
-				symbol: b"Relay".to_vec(),
-				decimals: 12,
-				is_frozen: false,
-			},
-			balances: vec![(AccountId::from(ALICE), 1_000_000_000_000_000)],
-			is_sufficient: true,
-		}])
-		.build()
-		.execute_with(|| {
-			let source_location = AssetType::Xcm(MultiLocation::parent());
-			let dest = MultiLocation {
-				parents: 1,
-				interior: X1(AccountId32 {
-					network: None,
-					id: [1u8; 32],
-				}),
-			};
-			let source_id: moonbeam_runtime::AssetId = source_location.clone().into();
-			// Default XCM version is not set yet, so xtokens should fail because it does not
-			// know with which version to send
-			assert_noop!(
-				XTokens::transfer(
-					origin_of(AccountId::from(ALICE)),
-					CurrencyId::ForeignAsset(source_id),
-					100_000_000_000_000,
-					Box::new(xcm::VersionedMultiLocation::V3(dest.clone())),
-					WeightLimit::Limited(4000000000.into())
-				),
-				orml_xtokens::Error::<Runtime>::XcmExecutionFailed
-			);
-
-			// Root sets the defaultXcm
-			assert_ok!(PolkadotXcm::force_default_xcm_version(
-				root_origin(),
-				Some(2)
-			));
-
-			// Now transferring does not fail
-			assert_ok!(XTokens::transfer(
-				origin_of(AccountId::from(ALICE)),
-				CurrencyId::ForeignAsset(source_id),
-				100_000_000_000_000,
-				Box::new(xcm::VersionedMultiLocation::V3(dest)),
-				WeightLimit::Limited(4000000000.into())
-			));
-		})
+fn asset_can_be_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		let source_location = AssetType::Xcm(MultiLocation::parent());
+		let source_id: moonbeam_runtime::AssetId = source_location.clone().into();
+		let asset_metadata = AssetRegistrarMetadata {
+			name: b"RelayToken".to_vec(),
+			symbol: b"Relay".to_vec(),
+			decimals: 12,
+			is_frozen: false,
+		};
+		assert_ok!(AssetManager::register_foreign_asset(
+			moonbeam_runtime::RuntimeOrigin::root(),
+			source_location,
+			asset_metadata,
+			1u128,
+			true
+		));
+		assert!(AssetManager::asset_id_type(source_id).is_some());
+	});
 }
 
 #[test]
-fn asset_can_be_registered() {
+fn foreign_asset_revert_code_follows_asset_lifecycle() {
 	ExtBuilder::default().build().execute_with(|| {
 		let source_location = AssetType::Xcm(MultiLocation::parent());
 		let source_id: moonbeam_runtime::AssetId = source_location.clone().into();
@@ -1712,6 +1371,9 @@ fn asset_can_be_registered() {
 			decimals: 12,
 			is_frozen: false,
 		};
+		let precompile_address: H160 =
+			Runtime::asset_id_to_account(FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX, source_id).into();
+
 		assert_ok!(AssetManager::register_foreign_asset(
 			moonbeam_runtime::RuntimeOrigin::root(),
 			source_location,
@@ -1719,7 +1381,19 @@ fn asset_can_be_registered() {
 			1u128,
 			true
 		));
-		assert!(AssetManager::asset_id_type(source_id).is_some());
+		assert_eq!(
+			pallet_evm::AccountCodes::<Runtime>::get(precompile_address),
+			moonbeam_runtime_common::xc20::XC20_REVERT_CODE.to_vec()
+		);
+
+		assert_ok!(AssetManager::destroy_foreign_asset(
+			moonbeam_runtime::RuntimeOrigin::root(),
+			source_id,
+			1
+		));
+		assert!(!pallet_evm::AccountCodes::<Runtime>::contains_key(
+			precompile_address
+		));
 	});
 }
 
@@ -2839,118 +2513,6 @@ fn call_xtokens_with_fee() {
 		});
 }
 
-#[test]
-fn test_xcm_utils_ml_tp_account() {
-	ExtBuilder::default().build().execute_with(|| {
-		let xcm_utils_precompile_address = H160::from_low_u64_be(2060);
-		let expected_address_parent: H160 =
-			ParentIsPreset::<AccountId>::convert_ref(MultiLocation::parent())
-				.unwrap()
-				.into();
-
-		Precompiles::new()
-			.prepare_test(
-				ALICE,
-				xcm_utils_precompile_address,
-				XcmUtilsPCall::multilocation_to_address {
-					multilocation: MultiLocation::parent(),
-				},
-			)
-			.expect_cost(1000)
-			.expect_no_logs()
-			.execute_returns(Address(expected_address_parent));
-
-		let parachain_2000_multilocation = MultiLocation::new(1, X1(Parachain(2000)));
-		let expected_address_parachain: H160 =
-			SiblingParachainConvertsVia::<Sibling, AccountId>::convert_ref(
-				parachain_2000_multilocation.clone(),
-			)
-			.unwrap()
-			.into();
-
-		Precompiles::new()
-			.prepare_test(
-				ALICE,
-				xcm_utils_precompile_address,
-				XcmUtilsPCall::multilocation_to_address {
-					multilocation: parachain_2000_multilocation,
-				},
-			)
-			.expect_cost(1000)
-			.expect_no_logs()
-			.execute_returns(Address(expected_address_parachain));
-
-		let alice_in_parachain_2000_multilocation = MultiLocation::new(
-			1,
-			X2(
-				Parachain(2000),
-				AccountKey20 {
-					network: None,
-					key: ALICE,
-				},
-			),
-		);
-		let expected_address_alice_in_parachain_2000: H160 =
-			xcm_builder::HashedDescriptionDescribeFamilyAllTerminal::<AccountId>::convert_ref(
-				alice_in_parachain_2000_multilocation.clone(),
-			)
-			.unwrap()
-			.into();
-
-		Precompiles::new()
-			.prepare_test(
-				ALICE,
-				xcm_utils_precompile_address,
-				XcmUtilsPCall::multilocation_to_address {
-					multilocation: alice_in_parachain_2000_multilocation,
-				},
-			)
-			.expect_cost(1000)
-			.expect_no_logs()
-			.execute_returns(Address(expected_address_alice_in_parachain_2000));
-	});
-}
-
-#[test]
-fn test_xcm_utils_weight_message() {
-	ExtBuilder::default().build().execute_with(|| {
-		let xcm_utils_precompile_address = H160::from_low_u64_be(2060);
-		let expected_weight =
-			XcmWeight::<moonbeam_runtime::Runtime, RuntimeCall>::clear_origin().ref_time();
-
-		let message: Vec<u8> = xcm::VersionedXcm::<()>::V3(Xcm(vec![ClearOrigin])).encode();
-
-		let input = XcmUtilsPCall::weight_message {
-			message: message.into(),
-		};
-
-		Precompiles::new()
-			.prepare_test(ALICE, xcm_utils_precompile_address, input)
-			.expect_cost(0)
-			.expect_no_logs()
-			.execute_returns(expected_weight);
-	});
-}
-
-#[test]
-fn test_xcm_utils_get_units_per_second() {
-	ExtBuilder::default().build().execute_with(|| {
-		let xcm_utils_precompile_address = H160::from_low_u64_be(2060);
-		let multilocation = SelfReserve::get();
-
-		let input = XcmUtilsPCall::get_units_per_second { multilocation };
-
-		let expected_units =
-			WEIGHT_REF_TIME_PER_SECOND as u128 * moonbeam_runtime::currency::WEIGHT_FEE;
-
-		Precompiles::new()
-			.prepare_test(ALICE, xcm_utils_precompile_address, input)
-			.expect_cost(1000)
-			.expect_no_logs()
-			.execute_returns(expected_units);
-	});
-}
-
 #[test]
 fn precompile_existence() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -2958,7 +2520,7 @@ fn precompile_existence() {
 		let precompile_addresses: std::collections::BTreeSet<_> = vec![
 			1, 2, 3, 4, 5, 6, 7, 8, 9, 1024, 1025, 1026, 2048, 2049, 2050, 2051, 2052, 2053, 2054,
 			2055, 2056, 2057, 2058, 2059, 2060, 2061, 2062, 2063, 2064, 2065, 2066, 2067, 2068,
-			2069, 2070,
+			2069, 2070, 2073, 2074,
 		]
 		.into_iter()
 		.map(H160::from_low_u64_be)
@@ -3014,45 +2576,6 @@ fn precompile_existence() {
 	});
 }
 
-#[test]
-fn removed_precompiles() {
-	ExtBuilder::default().build().execute_with(|| {
-		let precompiles = Precompiles::new();
-		let removed_precompiles = [1025];
-
-		for i in 1..3000 {
-			let address = H160::from_low_u64_be(i);
-
-			if !is_precompile_or_fail::<Runtime>(address, 100_000u64).expect("to be ok") {
-				continue;
-			}
-
-			if !removed_precompiles.contains(&i) {
-				assert!(
-					match precompiles.is_active_precompile(address, 100_000u64) {
-						IsPrecompileResult::Answer { is_precompile, .. } => is_precompile,
-						_ => false,
-					},
-					"{i} should be an active precompile"
-				);
-				continue;
-			}
-
-			assert!(
-				!match precompiles.is_active_precompile(address, 100_000u64) {
-					IsPrecompileResult::Answer { is_precompile, .. } => is_precompile,
-					_ => false,
-				},
-				"{i} shouldn't be an active precompile"
-			);
-
-			precompiles
-				.prepare_test(Alice, address, [])
-				.execute_reverts(|out| out == b"Removed precompile");
-		}
-	})
-}
-
 #[test]
 fn deal_with_fees_handles_tip() {
 	use frame_support::traits::OnUnbalanced;
@@ -893,6 +893,7 @@ impl pallet_asset_manager::Config for Runtime {
 	type LocalAssetIdCreator = LocalAssetIdCreator;
 	type Currency = Balances;
 	type LocalAssetDeposit = AssetDeposit;
+	type AssetTypeAliasPeriod = ConstU32<10>;
 	type WeightInfo = ();
 }
 
@@ -901,6 +902,22 @@ parameter_types! {
 	pub MaxHrmpRelayFee: MultiAsset = (MultiLocation::parent(), 1_000_000_000_000u128).into();
 }
 
+parameter_types! {
+	pub const TransactStatusQueryTimeout: BlockNumber = 100;
+}
+
+pub struct TransactStatusQuerier;
+impl pallet_xcm_transactor::TransactStatusQuerier for TransactStatusQuerier {
+	fn new_transact_status_query(responder: MultiLocation, querier: MultiLocation) -> QueryId {
+		let notify = pallet_xcm_transactor::Call::<Runtime>::transact_status_notify {
+			query_id: Default::default(),
+			response: Response::Null,
+		};
+		let timeout = System::block_number().saturating_add(TransactStatusQueryTimeout::get());
+		PolkadotXcm::new_notify_query(responder, notify, timeout, querier)
+	}
+}
+
 impl pallet_xcm_transactor::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -922,6 +939,10 @@ impl pallet_xcm_transactor::Config for Runtime {
 	type HrmpManipulatorOrigin = EnsureRoot<AccountId>;
 	type MaxHrmpFee = xcm_builder::Case<MaxHrmpRelayFee>;
 	type HrmpEncoder = MockHrmpEncoder;
+	type TransactStatusQuerier = TransactStatusQuerier;
+	type TransactStatusResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+	type DepositCurrency = Balances;
+	type DerivativeIndexDeposit = frame_support::traits::ConstU128<0>;
 }
 
 parameter_types! {
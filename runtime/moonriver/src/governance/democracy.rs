@@ -75,3 +75,10 @@ impl pallet_democracy::Config for Runtime {
 	type MaxBlacklisted = ConstU32<100>;
 	type SubmitOrigin = EnsureSigned<AccountId>;
 }
+
+impl pallet_democracy_lock_migration::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MigrationManagerOrigin = governance::referenda::GeneralAdminOrRoot;
+	type MaxTracks = ConstU32<16>;
+	type WeightInfo = moonbeam_weights::pallet_democracy_lock_migration::WeightInfo<Runtime>;
+}
@@ -20,7 +20,8 @@ mod common;
 use common::*;
 
 use fp_evm::GenesisAccount;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, weights::Weight};
+use moonriver_runtime::TransactionPayment;
 use nimbus_primitives::NimbusId;
 use pallet_evm::{Account as EVMAccount, AddressMapping, FeeCalculator};
 use sp_core::{ByteArray, H160, H256, U256};
@@ -29,6 +30,7 @@ use fp_rpc::runtime_decl_for_ethereum_runtime_rpc_api::EthereumRuntimeRPCApi;
 use moonbeam_rpc_primitives_txpool::runtime_decl_for_tx_pool_runtime_api::TxPoolRuntimeApi;
 use nimbus_primitives::runtime_decl_for_nimbus_api::NimbusApi;
 use std::{collections::BTreeMap, str::FromStr};
+use xcm_fee_payment_runtime_api::runtime_decl_for_xcm_payment_api::XcmPaymentApi;
 
 #[test]
 fn ethereum_runtime_rpc_api_chain_id() {
@@ -394,3 +396,47 @@ fn can_author_when_selected_is_empty() {
 			assert!(can_author_block);
 		});
 }
+
+#[test]
+fn xcm_payment_runtime_api_native_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		let native: xcm_fee_payment_runtime_api::VersionedAssetId =
+			xcm::v3::AssetId::Concrete(moonriver_runtime::xcm_config::SelfReserve::get()).into();
+
+		let assets = Runtime::query_acceptable_payment_assets(3).unwrap();
+		assert_eq!(assets, vec![native.clone()]);
+
+		assert_eq!(
+			Runtime::query_acceptable_payment_assets(2),
+			Err(xcm_fee_payment_runtime_api::Error::UnhandledXcmVersion)
+		);
+
+		let weight = Weight::from_parts(1_000_000_000, 0);
+		assert_eq!(
+			Runtime::query_weight_to_asset_fee(weight, native),
+			Ok(TransactionPayment::weight_to_fee(weight))
+		);
+	});
+}
+
+#[test]
+fn xcm_payment_runtime_api_unknown_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		let unknown: xcm_fee_payment_runtime_api::VersionedAssetId =
+			xcm::v3::AssetId::Concrete(xcm::v3::MultiLocation::parent()).into();
+
+		assert_eq!(
+			Runtime::query_weight_to_asset_fee(Weight::from_parts(1_000_000_000, 0), unknown),
+			Err(xcm_fee_payment_runtime_api::Error::AssetNotFound)
+		);
+	});
+}
+
+#[test]
+fn xcm_payment_runtime_api_query_xcm_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		let message =
+			xcm::VersionedXcm::<()>::V3(xcm::v3::Xcm(vec![xcm::v3::Instruction::ClearOrigin]));
+		assert!(Runtime::query_xcm_weight(message).is_ok());
+	});
+}
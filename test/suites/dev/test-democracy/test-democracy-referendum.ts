@@ -53,9 +53,9 @@ describeSuite({
         expect(finishedReferendum.isFinished).to.be.true;
         expect(finishedReferendum.asFinished.approved.isTrue).to.be.true;
 
-        const parachainBondInfo = await context
+        const [parachainBondInfo] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
+          .query.parachainStaking.inflationDistributionInfo();
         expect(parachainBondInfo.account.toString()).to.equal(randomAddress);
       },
     });
@@ -90,9 +90,9 @@ describeSuite({
         expect(finishedReferendum.isFinished).to.be.true;
         expect(finishedReferendum.asFinished.approved.isFalse).to.be.true;
 
-        const parachainBondInfo = await context
+        const [parachainBondInfo] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
+          .query.parachainStaking.inflationDistributionInfo();
         expect(parachainBondInfo.account.toString()).not.toBe(randomAddress);
       },
     });
@@ -26,9 +26,9 @@ describeSuite({
       id: "T02",
       title: "should check initial state - 0x0 ParachainBondAccount",
       test: async function () {
-        const parachainBondInfo = await context
+        const [parachainBondInfo] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
+          .query.parachainStaking.inflationDistributionInfo();
         expect(parachainBondInfo.account.toString()).to.equal(ZERO_ADDRESS);
       },
     });
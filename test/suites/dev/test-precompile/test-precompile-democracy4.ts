@@ -138,9 +138,9 @@ describeSuite({
           await context.createBlock();
         }
 
-        const parachainBondInfo = await context
+        const [parachainBondInfo] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
+          .query.parachainStaking.inflationDistributionInfo();
 
         const referendumDone = await context.polkadotJs().query.democracy.referendumInfoOf(0);
 
@@ -13,9 +13,9 @@ describeSuite({
       id: "T01",
       title: "should be initialized at address zero",
       test: async function () {
-        const parachainBondInfo = await context
+        const [parachainBondInfo] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
+          .query.parachainStaking.inflationDistributionInfo();
         expect(parachainBondInfo.account.toString()).to.equal(ZERO_ADDRESS);
         expect(parachainBondInfo.percent.toNumber()).to.equal(30);
       },
@@ -35,9 +35,9 @@ describeSuite({
         );
         expect(result!.successful).to.be.true;
 
-        const parachainBondInfo = await context
+        const [parachainBondInfo] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
+          .query.parachainStaking.inflationDistributionInfo();
         expect(parachainBondInfo.account.toString()).to.equal(alith.address);
         expect(parachainBondInfo.percent.toNumber()).to.equal(30);
       },
@@ -72,9 +72,9 @@ describeSuite({
         );
         expect(result!.successful).to.be.true;
 
-        const parachainBondInfo = await context
+        const [parachainBondInfo] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
+          .query.parachainStaking.inflationDistributionInfo();
         expect(parachainBondInfo.percent.toBigInt()).to.equal(20n);
       },
     });
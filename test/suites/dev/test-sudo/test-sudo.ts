@@ -27,9 +27,9 @@ describeSuite({
               context.polkadotJs().tx.parachainStaking.setParachainBondAccount(alith.address)
             )
         );
-        const parachainBondInfo = await context
+        const [parachainBondInfo] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
+          .query.parachainStaking.inflationDistributionInfo();
 
         expect(parachainBondInfo.account.toString()).to.equal(alith.address);
         expect(parachainBondInfo.percent.toNumber()).to.equal(30);
@@ -71,9 +71,10 @@ describeSuite({
       id: "T03",
       title: "should NOT be able to call sudo with another account than sudo account",
       test: async function () {
-        const parachainBondAccount = (
-          await context.polkadotJs().query.parachainStaking.parachainBondInfo()
-        ).account.toString();
+        const [parachainBondInfo] = await context
+          .polkadotJs()
+          .query.parachainStaking.inflationDistributionInfo();
+        const parachainBondAccount = parachainBondInfo.account.toString();
 
         const { result } = await context.createBlock(
           context
@@ -87,9 +88,9 @@ describeSuite({
           { allowFailures: true }
         );
 
-        const parachainBondInfo = await context
+        const [parachainBondInfo] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
+          .query.parachainStaking.inflationDistributionInfo();
         expect(parachainBondInfo.account.toString()).to.equal(parachainBondAccount);
         expect(parachainBondInfo.percent.toNumber()).to.equal(30);
 
@@ -115,9 +116,10 @@ describeSuite({
       title: "should not be able to call sudo with no funds",
       test: async function () {
         const newSigner = generateKeyringPair();
-        const parachainBondAccount = (
-          await context.polkadotJs().query.parachainStaking.parachainBondInfo()
-        ).account.toString();
+        const [parachainBondInfo] = await context
+          .polkadotJs()
+          .query.parachainStaking.inflationDistributionInfo();
+        const parachainBondAccount = parachainBondInfo.account.toString();
 
         await context.createBlock(context.polkadotJs().tx.sudo.setKey(newSigner.address), {
           allowFailures: false,
@@ -141,9 +143,9 @@ describeSuite({
           "1010: Invalid Transaction: Inability to pay some fees , e.g. account balance too low"
         );
 
-        const parachainBondInfo = await context
+        const [parachainBondInfo] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
+          .query.parachainStaking.inflationDistributionInfo();
         expect(parachainBondInfo.account.toString()).to.equal(parachainBondAccount);
       },
     });
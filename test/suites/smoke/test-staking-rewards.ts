@@ -490,7 +490,9 @@ describeSuite({
       );
 
       // calculate reward amounts
-      const parachainBondInfo = await apiAtPriorRewarded.query.parachainStaking.parachainBondInfo();
+      // the parachain bond is the first inflation distribution account
+      const [parachainBondInfo] =
+        await apiAtPriorRewarded.query.parachainStaking.inflationDistributionInfo();
       const parachainBondPercent = new Percent(parachainBondInfo.percent);
       const totalStaked = await apiAtPriorRewarded.query.parachainStaking.staked(
         originalRoundNumber
@@ -530,8 +532,11 @@ describeSuite({
           continue;
         }
         // only deduct parachainBondReward if it was transferred (event must exist)
-        if (apiAtRewarded.events.parachainStaking.ReservedForParachainBond.is(event)) {
-          reservedForParachainBond = event.data[1] as any;
+        if (
+          apiAtRewarded.events.parachainStaking.InflationDistributed.is(event) &&
+          event.data.index.toNumber() == 0
+        ) {
+          reservedForParachainBond = event.data.value as any;
           break;
         }
       }